@@ -1,16 +1,3 @@
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    /// Creates a new `ListNode` with the given value and no next node.
-    pub fn new(val: i32) -> Self {
-        Self { val, next: None }
-    }
-}
-
 impl Solution {
     /// Adds two numbers represented as linked lists in reverse order.
     ///
@@ -49,54 +36,35 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        let mut current = None;
-        for &val in vec.iter().rev() {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = current;
-            current = Some(node);
-        }
-        current
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut vals = Vec::new();
-        let mut node = list;
-        while let Some(n) = node {
-            vals.push(n.val);
-            node = n.next;
-        }
-        vals
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_add_two_numbers_basic() {
-        let l1 = to_list(&[2, 4, 3]);
-        let l2 = to_list(&[5, 6, 4]);
+        let l1 = List::from(vec![2, 4, 3]).into_inner();
+        let l2 = List::from(vec![5, 6, 4]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(from_list(result), vec![7, 0, 8]);
+        assert_eq!(List::from(result).to_vec(), vec![7, 0, 8]);
     }
 
     #[test]
     fn test_add_two_numbers_carry() {
-        let l1 = to_list(&[9, 9, 9]);
-        let l2 = to_list(&[1]);
+        let l1 = List::from(vec![9, 9, 9]).into_inner();
+        let l2 = List::from(vec![1]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(from_list(result), vec![0, 0, 0, 1]);
+        assert_eq!(List::from(result).to_vec(), vec![0, 0, 0, 1]);
     }
 
     #[test]
     fn test_add_two_numbers_diff_lengths() {
-        let l1 = to_list(&[2, 4]);
-        let l2 = to_list(&[5, 6, 4]);
+        let l1 = List::from(vec![2, 4]).into_inner();
+        let l2 = List::from(vec![5, 6, 4]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(from_list(result), vec![7, 0, 5]);
+        assert_eq!(List::from(result).to_vec(), vec![7, 0, 5]);
     }
 
     #[test]
     fn test_add_two_numbers_both_none() {
         let result = Solution::add_two_numbers(None, None);
-        assert_eq!(from_list(result), Vec::<i32>::new());
+        assert_eq!(List::from(result).to_vec(), Vec::<i32>::new());
     }
 }
@@ -21,7 +21,7 @@ impl Solution {
         }
 
         let (m, n) = (nums1.len(), nums2.len());
-        let half_length = (m + n + 1) / 2;
+        let half_length = (m + n).div_ceil(2);
         let (mut left, mut right) = (0, m);
 
         while left <= right {
@@ -69,6 +69,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::differential;
+    use crate::random::RandomSource;
 
     #[test]
     fn odd_total_length() {
@@ -103,4 +105,42 @@ mod tests {
     fn single_elements() {
         assert_eq!(Solution::find_median_sorted_arrays(vec![1], vec![2]), 1.5);
     }
+
+    /// Merges both arrays by sorting their concatenation and reads off the
+    /// middle.
+    fn brute_force(nums1: &[i32], nums2: &[i32]) -> f64 {
+        let mut merged = [nums1, nums2].concat();
+        merged.sort_unstable();
+        let n = merged.len();
+        if n % 2 == 1 {
+            merged[n / 2] as f64
+        } else {
+            (merged[n / 2 - 1] as f64 + merged[n / 2] as f64) / 2.0
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let value = |rng: &mut crate::random::SeededRng| match rng.in_range(0..10) {
+            0 => i32::MIN,
+            1 => i32::MAX,
+            _ => rng.in_range(-20..=20),
+        };
+        let sorted = |v: &Vec<i32>| {
+            let mut v = v.clone();
+            v.sort_unstable();
+            v
+        };
+        differential::check(
+            |rng| {
+                (
+                    differential::vec_with(rng, 8, value),
+                    differential::vec_with(rng, 8, value),
+                )
+            },
+            |(a, b)| !a.is_empty() || !b.is_empty(),
+            |(a, b)| Solution::find_median_sorted_arrays(sorted(a), sorted(b)),
+            |(a, b)| brute_force(a, b),
+        );
+    }
 }
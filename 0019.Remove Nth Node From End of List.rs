@@ -53,45 +53,41 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn remove_from_middle() {
-        let head = to_list(&[1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(
-            from_list(Solution::remove_nth_from_end(head, 2)),
+            List::from(Solution::remove_nth_from_end(head, 2)).to_vec(),
             vec![1, 2, 3, 5]
         );
     }
 
     #[test]
     fn remove_only_element() {
-        let head = to_list(&[1]);
-        assert_eq!(from_list(Solution::remove_nth_from_end(head, 1)), vec![]);
+        let head = List::from(vec![1]).into_inner();
+        assert_eq!(
+            List::from(Solution::remove_nth_from_end(head, 1)).to_vec(),
+            vec![]
+        );
     }
 
     #[test]
     fn remove_head() {
-        let head = to_list(&[1, 2]);
-        assert_eq!(from_list(Solution::remove_nth_from_end(head, 2)), vec![2]);
+        let head = List::from(vec![1, 2]).into_inner();
+        assert_eq!(
+            List::from(Solution::remove_nth_from_end(head, 2)).to_vec(),
+            vec![2]
+        );
     }
 
     #[test]
     fn remove_tail() {
-        let head = to_list(&[1, 2]);
-        assert_eq!(from_list(Solution::remove_nth_from_end(head, 1)), vec![1]);
+        let head = List::from(vec![1, 2]).into_inner();
+        assert_eq!(
+            List::from(Solution::remove_nth_from_end(head, 1)).to_vec(),
+            vec![1]
+        );
     }
 }
@@ -49,39 +49,32 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn both_non_empty() {
-        let l1 = to_list(&[1, 2, 4]);
-        let l2 = to_list(&[1, 3, 4]);
+        let l1 = List::from(vec![1, 2, 4]).into_inner();
+        let l2 = List::from(vec![1, 3, 4]).into_inner();
         assert_eq!(
-            from_list(Solution::merge_two_lists(l1, l2)),
+            List::from(Solution::merge_two_lists(l1, l2)).to_vec(),
             vec![1, 1, 2, 3, 4, 4]
         );
     }
 
     #[test]
     fn both_empty() {
-        assert_eq!(from_list(Solution::merge_two_lists(None, None)), vec![]);
+        assert_eq!(
+            List::from(Solution::merge_two_lists(None, None)).to_vec(),
+            vec![]
+        );
     }
 
     #[test]
     fn one_empty() {
-        let l1 = to_list(&[0]);
-        assert_eq!(from_list(Solution::merge_two_lists(None, l1)), vec![0]);
+        let l1 = List::from(vec![0]).into_inner();
+        assert_eq!(
+            List::from(Solution::merge_two_lists(None, l1)).to_vec(),
+            vec![0]
+        );
     }
 }
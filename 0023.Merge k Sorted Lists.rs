@@ -61,37 +61,31 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn three_sorted_lists() {
-        let lists = vec![to_list(&[1, 4, 5]), to_list(&[1, 3, 4]), to_list(&[2, 6])];
+        let lists = vec![
+            List::from(vec![1, 4, 5]).into_inner(),
+            List::from(vec![1, 3, 4]).into_inner(),
+            List::from(vec![2, 6]).into_inner(),
+        ];
         assert_eq!(
-            from_list(Solution::merge_k_lists(lists)),
+            List::from(Solution::merge_k_lists(lists)).to_vec(),
             vec![1, 1, 2, 3, 4, 4, 5, 6]
         );
     }
 
     #[test]
     fn empty_input() {
-        assert_eq!(from_list(Solution::merge_k_lists(vec![])), vec![]);
+        assert_eq!(List::from(Solution::merge_k_lists(vec![])).to_vec(), vec![]);
     }
 
     #[test]
     fn single_empty_list() {
-        assert_eq!(from_list(Solution::merge_k_lists(vec![None])), vec![]);
+        assert_eq!(
+            List::from(Solution::merge_k_lists(vec![None])).to_vec(),
+            vec![]
+        );
     }
 }
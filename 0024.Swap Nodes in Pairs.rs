@@ -47,53 +47,40 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |node| node.next.as_ref())
-            .map(|node| node.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_swap_pairs_even_length() {
-        let head = to_list(&[1, 2, 3, 4]);
+        let head = List::from(vec![1, 2, 3, 4]).into_inner();
         let result = Solution::swap_pairs(head);
-        assert_eq!(from_list(result), vec![2, 1, 4, 3]);
+        assert_eq!(List::from(result).to_vec(), vec![2, 1, 4, 3]);
     }
 
     #[test]
     fn test_swap_pairs_odd_length() {
-        let head = to_list(&[1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
         let result = Solution::swap_pairs(head);
-        assert_eq!(from_list(result), vec![2, 1, 3]);
+        assert_eq!(List::from(result).to_vec(), vec![2, 1, 3]);
     }
 
     #[test]
     fn test_swap_pairs_single_element() {
-        let head = to_list(&[1]);
+        let head = List::from(vec![1]).into_inner();
         let result = Solution::swap_pairs(head);
-        assert_eq!(from_list(result), vec![1]);
+        assert_eq!(List::from(result).to_vec(), vec![1]);
     }
 
     #[test]
     fn test_swap_pairs_empty() {
-        let head = to_list(&[]);
+        let head = List::from(vec![]).into_inner();
         let result = Solution::swap_pairs(head);
-        assert_eq!(from_list(result), Vec::<i32>::new());
+        assert_eq!(List::from(result).to_vec(), Vec::<i32>::new());
     }
 
     #[test]
     fn test_swap_pairs_two_elements() {
-        let head = to_list(&[1, 2]);
+        let head = List::from(vec![1, 2]).into_inner();
         let result = Solution::swap_pairs(head);
-        assert_eq!(from_list(result), vec![2, 1]);
+        assert_eq!(List::from(result).to_vec(), vec![2, 1]);
     }
 }
@@ -76,48 +76,41 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn reverse_by_two() {
-        let head = to_list(&[1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(
-            from_list(Solution::reverse_k_group(head, 2)),
+            List::from(Solution::reverse_k_group(head, 2)).to_vec(),
             vec![2, 1, 4, 3, 5]
         );
     }
 
     #[test]
     fn reverse_by_three() {
-        let head = to_list(&[1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(
-            from_list(Solution::reverse_k_group(head, 3)),
+            List::from(Solution::reverse_k_group(head, 3)).to_vec(),
             vec![3, 2, 1, 4, 5]
         );
     }
 
     #[test]
     fn k_equals_one() {
-        let head = to_list(&[1, 2, 3]);
-        assert_eq!(from_list(Solution::reverse_k_group(head, 1)), vec![1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::reverse_k_group(head, 1)).to_vec(),
+            vec![1, 2, 3]
+        );
     }
 
     #[test]
     fn k_equals_length() {
-        let head = to_list(&[1, 2, 3]);
-        assert_eq!(from_list(Solution::reverse_k_group(head, 3)), vec![3, 2, 1]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::reverse_k_group(head, 3)).to_vec(),
+            vec![3, 2, 1]
+        );
     }
 }
@@ -18,7 +18,7 @@ impl Solution {
     /// - Time: O(n) — single pass with two converging pointers
     /// - Space: O(1) — only scalar variables
     pub fn trap(height: Vec<i32>) -> i32 {
-        let (mut left, mut right) = (0, height.len().wrapping_sub(1));
+        let (mut left, mut right) = (0, height.len().saturating_sub(1));
         let (mut left_max, mut right_max) = (0, 0);
         let mut trapped = 0;
 
@@ -1,35 +1,31 @@
+use crate::interval_set::IntervalSet;
+use std::ops::Bound::Included;
+
 impl Solution {
-    /// Sort-then-merge for overlapping interval consolidation.
+    /// Coalescing interval set for overlapping interval consolidation.
     ///
     /// # Intuition
-    /// Sorting intervals by start time ensures that overlapping intervals
-    /// are adjacent. A single pass can then merge them by extending the
-    /// current interval's end whenever overlap is detected.
+    /// Merging is exactly what an interval set does on insert: a new closed
+    /// interval swallows every stored one it overlaps or touches, so the set
+    /// always holds the merged answer.
     ///
     /// # Approach
-    /// Sort by start value. Initialize with the first interval. For each
-    /// subsequent interval, if it overlaps with the last merged interval
-    /// (start <= previous end), extend the end. Otherwise, push a new
-    /// merged interval.
+    /// Insert every `[start, end]` into an `IntervalSet`, then read the stored
+    /// intervals back in ascending order. Closed intervals sharing an endpoint
+    /// merge, while `[1, 2]` and `[3, 4]` stay apart.
     ///
     /// # Complexity
-    /// - Time: O(n log n) — dominated by sorting
-    /// - Space: O(log n) — sorting stack (excluding output)
-    pub fn merge(mut intervals: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
-        intervals.sort_unstable_by_key(|interval| interval[0]);
-        let mut merged: Vec<Vec<i32>> = Vec::with_capacity(intervals.len());
-
-        for interval in intervals {
-            if let Some(last) = merged.last_mut() {
-                if interval[0] <= last[1] {
-                    last[1] = last[1].max(interval[1]);
-                    continue;
-                }
-            }
-            merged.push(interval);
-        }
-
+    /// - Time: O(n log n) — one ordered-map insert per interval
+    /// - Space: O(n) — the interval set
+    pub fn merge(intervals: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+        let merged: IntervalSet<i32> = intervals.iter().map(|i| i[0]..=i[1]).collect();
         merged
+            .iter()
+            .map(|bounds| match bounds {
+                (Included(&start), Included(&end)) => vec![start, end],
+                _ => unreachable!("closed intervals merge into closed intervals"),
+            })
+            .collect()
     }
 }
 
@@ -58,6 +54,14 @@ mod tests {
         assert_eq!(Solution::merge(vec![vec![1, 1]]), vec![vec![1, 1]]);
     }
 
+    #[test]
+    fn adjacent_integers_stay_apart() {
+        assert_eq!(
+            Solution::merge(vec![vec![3, 4], vec![1, 2], vec![2, 2]]),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+    }
+
     #[test]
     fn no_overlap() {
         assert_eq!(
@@ -67,44 +67,37 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn rotate_by_two() {
-        let head = to_list(&[1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(
-            from_list(Solution::rotate_right(head, 2)),
+            List::from(Solution::rotate_right(head, 2)).to_vec(),
             vec![4, 5, 1, 2, 3]
         );
     }
 
     #[test]
     fn rotate_by_four() {
-        let head = to_list(&[0, 1, 2]);
-        assert_eq!(from_list(Solution::rotate_right(head, 4)), vec![2, 0, 1]);
+        let head = List::from(vec![0, 1, 2]).into_inner();
+        assert_eq!(
+            List::from(Solution::rotate_right(head, 4)).to_vec(),
+            vec![2, 0, 1]
+        );
     }
 
     #[test]
     fn empty_list() {
-        assert_eq!(from_list(Solution::rotate_right(None, 1)), vec![]);
+        assert_eq!(List::from(Solution::rotate_right(None, 1)).to_vec(), vec![]);
     }
 
     #[test]
     fn rotate_by_length() {
-        let head = to_list(&[1, 2, 3]);
-        assert_eq!(from_list(Solution::rotate_right(head, 3)), vec![1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::rotate_right(head, 3)).to_vec(),
+            vec![1, 2, 3]
+        );
     }
 }
@@ -19,22 +19,26 @@ impl Solution {
             return;
         }
         let (m, n) = (matrix.len(), matrix[0].len());
-        let first_row_has_zero = matrix[0].iter().any(|value| *value == 0);
+        let first_row_has_zero = matrix[0].contains(&0);
         let first_col_has_zero = matrix.iter().any(|row| row[0] == 0);
 
-        (1..m).for_each(|i| {
-            (1..n).filter(|&j| matrix[i][j] == 0).for_each(|j| {
-                matrix[i][0] = 0;
-                matrix[0][j] = 0;
-            });
-        });
+        for i in 1..m {
+            for j in 1..n {
+                if matrix[i][j] == 0 {
+                    matrix[i][0] = 0;
+                    matrix[0][j] = 0;
+                }
+            }
+        }
 
-        (1..m).for_each(|i| {
+        for i in 1..m {
             let zero_row = matrix[i][0] == 0;
-            (1..n)
-                .filter(|&j| zero_row || matrix[0][j] == 0)
-                .for_each(|j| matrix[i][j] = 0);
-        });
+            for j in 1..n {
+                if zero_row || matrix[0][j] == 0 {
+                    matrix[i][j] = 0;
+                }
+            }
+        }
 
         if first_row_has_zero {
             matrix[0].fill(0);
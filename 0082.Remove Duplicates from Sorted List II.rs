@@ -38,7 +38,7 @@ impl Solution {
         while let Some(mut node) = current {
             current = node.next.take();
             let is_dup =
-                node.val == prev_val || current.as_ref().map_or(false, |next| next.val == node.val);
+                node.val == prev_val || current.as_ref().is_some_and(|next| next.val == node.val);
             prev_val = node.val;
 
             if !is_dup {
@@ -54,41 +54,40 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn remove_duplicates() {
-        let head = to_list(&[1, 2, 3, 3, 4, 4, 5]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![1, 2, 5]);
+        let head = List::from(vec![1, 2, 3, 3, 4, 4, 5]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![1, 2, 5]
+        );
     }
 
     #[test]
     fn all_duplicates() {
-        let head = to_list(&[1, 1, 1, 2, 3]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![2, 3]);
+        let head = List::from(vec![1, 1, 1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![2, 3]
+        );
     }
 
     #[test]
     fn no_duplicates() {
-        let head = to_list(&[1, 2, 3]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![1, 2, 3]
+        );
     }
 
     #[test]
     fn empty_list() {
-        assert_eq!(from_list(Solution::delete_duplicates(None)), vec![]);
+        assert_eq!(
+            List::from(Solution::delete_duplicates(None)).to_vec(),
+            vec![]
+        );
     }
 }
@@ -47,41 +47,40 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn with_duplicates() {
-        let head = to_list(&[1, 1, 2]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![1, 2]);
+        let head = List::from(vec![1, 1, 2]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![1, 2]
+        );
     }
 
     #[test]
     fn multiple_groups() {
-        let head = to_list(&[1, 1, 2, 3, 3]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![1, 2, 3]);
+        let head = List::from(vec![1, 1, 2, 3, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![1, 2, 3]
+        );
     }
 
     #[test]
     fn no_duplicates() {
-        let head = to_list(&[1, 2, 3]);
-        assert_eq!(from_list(Solution::delete_duplicates(head)), vec![1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_duplicates(head)).to_vec(),
+            vec![1, 2, 3]
+        );
     }
 
     #[test]
     fn empty_list() {
-        assert_eq!(from_list(Solution::delete_duplicates(None)), vec![]);
+        assert_eq!(
+            List::from(Solution::delete_duplicates(None)).to_vec(),
+            vec![]
+        );
     }
 }
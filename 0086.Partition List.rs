@@ -55,44 +55,37 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn standard_case() {
-        let head = to_list(&[1, 4, 3, 2, 5, 2]);
+        let head = List::from(vec![1, 4, 3, 2, 5, 2]).into_inner();
         assert_eq!(
-            from_list(Solution::partition(head, 3)),
+            List::from(Solution::partition(head, 3)).to_vec(),
             vec![1, 2, 2, 4, 3, 5]
         );
     }
 
     #[test]
     fn all_less() {
-        let head = to_list(&[1, 2]);
-        assert_eq!(from_list(Solution::partition(head, 3)), vec![1, 2]);
+        let head = List::from(vec![1, 2]).into_inner();
+        assert_eq!(
+            List::from(Solution::partition(head, 3)).to_vec(),
+            vec![1, 2]
+        );
     }
 
     #[test]
     fn all_greater() {
-        let head = to_list(&[3, 4]);
-        assert_eq!(from_list(Solution::partition(head, 2)), vec![3, 4]);
+        let head = List::from(vec![3, 4]).into_inner();
+        assert_eq!(
+            List::from(Solution::partition(head, 2)).to_vec(),
+            vec![3, 4]
+        );
     }
 
     #[test]
     fn empty_list() {
-        assert_eq!(from_list(Solution::partition(None, 1)), vec![]);
+        assert_eq!(List::from(Solution::partition(None, 1)).to_vec(), vec![]);
     }
 }
@@ -62,41 +62,31 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn to_list(vec: &[i32]) -> Option<Box<ListNode>> {
-        vec.iter().rev().fold(None, |next, &val| {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = next;
-            Some(node)
-        })
-    }
-
-    fn from_list(list: Option<Box<ListNode>>) -> Vec<i32> {
-        std::iter::successors(list.as_ref(), |n| n.next.as_ref())
-            .map(|n| n.val)
-            .collect()
-    }
+    use crate::list_node::List;
 
     #[test]
     fn reverse_middle() {
-        let head = to_list(&[1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(
-            from_list(Solution::reverse_between(head, 2, 4)),
+            List::from(Solution::reverse_between(head, 2, 4)).to_vec(),
             vec![1, 4, 3, 2, 5]
         );
     }
 
     #[test]
     fn reverse_single() {
-        let head = to_list(&[5]);
-        assert_eq!(from_list(Solution::reverse_between(head, 1, 1)), vec![5]);
+        let head = List::from(vec![5]).into_inner();
+        assert_eq!(
+            List::from(Solution::reverse_between(head, 1, 1)).to_vec(),
+            vec![5]
+        );
     }
 
     #[test]
     fn reverse_entire() {
-        let head = to_list(&[1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
         assert_eq!(
-            from_list(Solution::reverse_between(head, 1, 3)),
+            List::from(Solution::reverse_between(head, 1, 3)).to_vec(),
             vec![3, 2, 1]
         );
     }
@@ -68,41 +68,11 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(Rc::clone(&root));
-        let mut i = 1;
-        while i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().left = Some(Rc::clone(&left));
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().right = Some(Rc::clone(&right));
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_standard_case() {
-        let root = build_tree(&[Some(1), None, Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::inorder_traversal(root), vec![1, 3, 2]);
     }
 
@@ -113,25 +83,25 @@ mod tests {
 
     #[test]
     fn test_single_node() {
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::inorder_traversal(root), vec![1]);
     }
 
     #[test]
     fn test_left_skewed() {
-        let root = build_tree(&[Some(3), Some(2), None, Some(1)]);
+        let root = Tree::from(vec![Some(3), Some(2), None, Some(1)]).into_inner();
         assert_eq!(Solution::inorder_traversal(root), vec![1, 2, 3]);
     }
 
     #[test]
     fn test_right_skewed() {
-        let root = build_tree(&[Some(1), None, Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         assert_eq!(Solution::inorder_traversal(root), vec![1, 2, 3]);
     }
 
     #[test]
     fn test_balanced() {
-        let root = build_tree(&[Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         assert_eq!(Solution::inorder_traversal(root), vec![1, 2, 3]);
     }
 }
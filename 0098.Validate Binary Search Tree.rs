@@ -38,7 +38,7 @@ impl Solution {
                 if !dfs(&n.left, prev) {
                     return false;
                 }
-                if prev.map_or(false, |p| p >= n.val) {
+                if prev.is_some_and(|p| p >= n.val) {
                     return false;
                 }
                 *prev = Some(n.val);
@@ -73,47 +73,26 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(Rc::clone(&root));
-        let mut i = 1;
-        while i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().left = Some(Rc::clone(&left));
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().right = Some(Rc::clone(&right));
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_valid_bst() {
-        let root = build_tree(&[Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         assert!(Solution::is_valid_bst(root));
     }
 
     #[test]
     fn test_invalid_bst() {
-        let root = build_tree(&[Some(5), Some(1), Some(4), None, None, Some(3), Some(6)]);
+        let root = Tree::from(vec![
+            Some(5),
+            Some(1),
+            Some(4),
+            None,
+            None,
+            Some(3),
+            Some(6),
+        ])
+        .into_inner();
         assert!(!Solution::is_valid_bst(root));
     }
 
@@ -124,25 +103,25 @@ mod tests {
 
     #[test]
     fn test_single_node() {
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::is_valid_bst(root));
     }
 
     #[test]
     fn test_equal_values_invalid() {
-        let root = build_tree(&[Some(2), Some(2), Some(2)]);
+        let root = Tree::from(vec![Some(2), Some(2), Some(2)]).into_inner();
         assert!(!Solution::is_valid_bst(root));
     }
 
     #[test]
     fn test_left_only_valid() {
-        let root = build_tree(&[Some(2), Some(1)]);
+        let root = Tree::from(vec![Some(2), Some(1)]).into_inner();
         assert!(Solution::is_valid_bst(root));
     }
 
     #[test]
     fn test_right_only_valid() {
-        let root = build_tree(&[Some(1), None, Some(2)]);
+        let root = Tree::from(vec![Some(1), None, Some(2)]).into_inner();
         assert!(Solution::is_valid_bst(root));
     }
 
@@ -150,7 +129,16 @@ mod tests {
     fn test_subtree_violation() {
         // Tree: 5 -> (4, 6) where 6 -> (3, 7)
         // The 3 under 6 violates BST property (3 < 5 but is in right subtree)
-        let root = build_tree(&[Some(5), Some(4), Some(6), None, None, Some(3), Some(7)]);
+        let root = Tree::from(vec![
+            Some(5),
+            Some(4),
+            Some(6),
+            None,
+            None,
+            Some(3),
+            Some(7),
+        ])
+        .into_inner();
         assert!(!Solution::is_valid_bst(root));
     }
 }
@@ -70,56 +70,26 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(Rc::clone(&root));
-        let mut i = 1;
-        while i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().left = Some(Rc::clone(&left));
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-                if i < vals.len() {
-                    if let Some(v) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(v)));
-                        node.borrow_mut().right = Some(Rc::clone(&right));
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_same_trees() {
-        let p = build_tree(&[Some(1), Some(2), Some(3)]);
-        let q = build_tree(&[Some(1), Some(2), Some(3)]);
+        let p = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
+        let q = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert!(Solution::is_same_tree(p, q));
     }
 
     #[test]
     fn test_different_structure() {
-        let p = build_tree(&[Some(1), Some(2)]);
-        let q = build_tree(&[Some(1), None, Some(2)]);
+        let p = Tree::from(vec![Some(1), Some(2)]).into_inner();
+        let q = Tree::from(vec![Some(1), None, Some(2)]).into_inner();
         assert!(!Solution::is_same_tree(p, q));
     }
 
     #[test]
     fn test_different_values() {
-        let p = build_tree(&[Some(1), Some(2), Some(1)]);
-        let q = build_tree(&[Some(1), Some(1), Some(2)]);
+        let p = Tree::from(vec![Some(1), Some(2), Some(1)]).into_inner();
+        let q = Tree::from(vec![Some(1), Some(1), Some(2)]).into_inner();
         assert!(!Solution::is_same_tree(p, q));
     }
 
@@ -130,21 +100,21 @@ mod tests {
 
     #[test]
     fn test_one_empty() {
-        let p = build_tree(&[Some(1)]);
+        let p = Tree::from(vec![Some(1)]).into_inner();
         assert!(!Solution::is_same_tree(p, None));
     }
 
     #[test]
     fn test_single_node_same() {
-        let p = build_tree(&[Some(1)]);
-        let q = build_tree(&[Some(1)]);
+        let p = Tree::from(vec![Some(1)]).into_inner();
+        let q = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::is_same_tree(p, q));
     }
 
     #[test]
     fn test_single_node_different() {
-        let p = build_tree(&[Some(1)]);
-        let q = build_tree(&[Some(2)]);
+        let p = Tree::from(vec![Some(1)]).into_inner();
+        let q = Tree::from(vec![Some(2)]).into_inner();
         assert!(!Solution::is_same_tree(p, q));
     }
 }
@@ -60,42 +60,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_from_vec(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(values.len());
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_symmetric_tree() {
@@ -105,7 +70,7 @@ mod tests {
         //      2     2
         //     / \   / \
         //    3   4 4   3
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(2),
@@ -113,7 +78,8 @@ mod tests {
             Some(4),
             Some(4),
             Some(3),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::is_symmetric(root));
     }
 
@@ -125,7 +91,7 @@ mod tests {
         //      2     2
         //       \     \
         //        3     3
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(2),
@@ -133,19 +99,20 @@ mod tests {
             Some(3),
             None,
             Some(3),
-        ]);
+        ])
+        .into_inner();
         assert!(!Solution::is_symmetric(root));
     }
 
     #[test]
     fn test_empty_tree() {
-        let root = tree_from_vec(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         assert!(Solution::is_symmetric(root));
     }
 
     #[test]
     fn test_single_node() {
-        let root = tree_from_vec(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::is_symmetric(root));
     }
 
@@ -155,7 +122,7 @@ mod tests {
         //      1
         //     / \
         //    2   2
-        let root = tree_from_vec(vec![Some(1), Some(2), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(2)]).into_inner();
         assert!(Solution::is_symmetric(root));
     }
 
@@ -165,7 +132,7 @@ mod tests {
         //      1
         //     / \
         //    2   3
-        let root = tree_from_vec(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert!(!Solution::is_symmetric(root));
     }
 }
@@ -51,42 +51,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_from_vec(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(values.len());
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_tree() {
@@ -97,7 +62,7 @@ mod tests {
         //        /  \
         //       15   7
         // Depth: 3
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(3),
             Some(9),
             Some(20),
@@ -105,19 +70,20 @@ mod tests {
             None,
             Some(15),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::max_depth(root), 3);
     }
 
     #[test]
     fn test_single_node() {
-        let root = tree_from_vec(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::max_depth(root), 1);
     }
 
     #[test]
     fn test_empty_tree() {
-        let root = tree_from_vec(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         assert_eq!(Solution::max_depth(root), 0);
     }
 
@@ -127,7 +93,7 @@ mod tests {
         //       1
         //      /
         //     2
-        let root = tree_from_vec(vec![Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(2)]).into_inner();
         assert_eq!(Solution::max_depth(root), 2);
     }
 
@@ -170,7 +136,7 @@ mod tests {
         //     / \   / \
         //    4   5 6   7
         // Depth: 3
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -178,7 +144,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::max_depth(root), 3);
     }
 }
@@ -75,37 +75,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::new();
-        if root.is_none() {
-            return result;
-        }
-
-        let mut queue = VecDeque::new();
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            if let Some(node_opt) = queue.pop_front() {
-                if let Some(node) = node_opt {
-                    let borrowed = node.borrow();
-                    result.push(Some(borrowed.val));
-                    queue.push_back(borrowed.left.clone());
-                    queue.push_back(borrowed.right.clone());
-                } else {
-                    result.push(None);
-                }
-            }
-        }
-
-        // Trim trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_tree() {
@@ -115,7 +85,7 @@ mod tests {
         let preorder = vec![3, 9, 20, 15, 7];
         let inorder = vec![9, 3, 15, 20, 7];
         let tree = Solution::build_tree(preorder, inorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(
             result,
             vec![Some(3), Some(9), Some(20), None, None, Some(15), Some(7)]
@@ -127,7 +97,7 @@ mod tests {
         let preorder = vec![1];
         let inorder = vec![1];
         let tree = Solution::build_tree(preorder, inorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1)]);
     }
 
@@ -152,7 +122,7 @@ mod tests {
         let preorder = vec![1, 2, 3];
         let inorder = vec![3, 2, 1];
         let tree = Solution::build_tree(preorder, inorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1), Some(2), None, Some(3)]);
     }
 
@@ -169,7 +139,7 @@ mod tests {
         let preorder = vec![1, 2, 3];
         let inorder = vec![1, 2, 3];
         let tree = Solution::build_tree(preorder, inorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1), None, Some(2), None, Some(3)]);
     }
 
@@ -186,7 +156,7 @@ mod tests {
         let preorder = vec![1, 2, 4, 5, 3, 6, 7];
         let inorder = vec![4, 2, 5, 1, 6, 3, 7];
         let tree = Solution::build_tree(preorder, inorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(
             result,
             vec![
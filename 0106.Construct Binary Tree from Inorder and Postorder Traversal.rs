@@ -79,37 +79,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::new();
-        if root.is_none() {
-            return result;
-        }
-
-        let mut queue = VecDeque::new();
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            if let Some(node_opt) = queue.pop_front() {
-                if let Some(node) = node_opt {
-                    let borrowed = node.borrow();
-                    result.push(Some(borrowed.val));
-                    queue.push_back(borrowed.left.clone());
-                    queue.push_back(borrowed.right.clone());
-                } else {
-                    result.push(None);
-                }
-            }
-        }
-
-        // Trim trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_tree() {
@@ -119,7 +89,7 @@ mod tests {
         let inorder = vec![9, 3, 15, 20, 7];
         let postorder = vec![9, 15, 7, 20, 3];
         let tree = Solution::build_tree(inorder, postorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(
             result,
             vec![Some(3), Some(9), Some(20), None, None, Some(15), Some(7)]
@@ -131,7 +101,7 @@ mod tests {
         let inorder = vec![1];
         let postorder = vec![1];
         let tree = Solution::build_tree(inorder, postorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1)]);
     }
 
@@ -156,7 +126,7 @@ mod tests {
         let inorder = vec![3, 2, 1];
         let postorder = vec![3, 2, 1];
         let tree = Solution::build_tree(inorder, postorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1), Some(2), None, Some(3)]);
     }
 
@@ -173,7 +143,7 @@ mod tests {
         let inorder = vec![1, 2, 3];
         let postorder = vec![3, 2, 1];
         let tree = Solution::build_tree(inorder, postorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(result, vec![Some(1), None, Some(2), None, Some(3)]);
     }
 
@@ -190,7 +160,7 @@ mod tests {
         let inorder = vec![4, 2, 5, 1, 6, 3, 7];
         let postorder = vec![4, 5, 2, 6, 7, 3, 1];
         let tree = Solution::build_tree(inorder, postorder);
-        let result = tree_to_vec(tree);
+        let result = Tree::from(tree).to_vec();
         assert_eq!(
             result,
             vec![
@@ -59,6 +59,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tree_node::Tree;
 
     fn is_valid_bst(root: &Option<Rc<RefCell<TreeNode>>>, min: i64, max: i64) -> bool {
         match root {
@@ -98,23 +99,13 @@ mod tests {
         }
     }
 
-    fn count_nodes(root: &Option<Rc<RefCell<TreeNode>>>) -> usize {
-        match root {
-            None => 0,
-            Some(node) => {
-                let node = node.borrow();
-                1 + count_nodes(&node.left) + count_nodes(&node.right)
-            }
-        }
-    }
-
     #[test]
     fn test_example() {
         let nums = vec![-10, -3, 0, 5, 9];
         let tree = Solution::sorted_array_to_bst(nums.clone());
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), nums.len());
+        assert_eq!(Tree::from(tree.clone()).node_count(), nums.len());
     }
 
     #[test]
@@ -123,7 +114,7 @@ mod tests {
         let tree = Solution::sorted_array_to_bst(nums);
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), 1);
+        assert_eq!(Tree::from(tree.clone()).node_count(), 1);
     }
 
     #[test]
@@ -132,7 +123,7 @@ mod tests {
         let tree = Solution::sorted_array_to_bst(nums.clone());
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), nums.len());
+        assert_eq!(Tree::from(tree.clone()).node_count(), nums.len());
     }
 
     #[test]
@@ -148,7 +139,7 @@ mod tests {
         let tree = Solution::sorted_array_to_bst(nums.clone());
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), nums.len());
+        assert_eq!(Tree::from(tree.clone()).node_count(), nums.len());
     }
 
     #[test]
@@ -157,6 +148,6 @@ mod tests {
         let tree = Solution::sorted_array_to_bst(nums.clone());
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), nums.len());
+        assert_eq!(Tree::from(tree.clone()).node_count(), nums.len());
     }
 }
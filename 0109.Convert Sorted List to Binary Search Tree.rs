@@ -1,19 +1,6 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 // #[derive(Debug, PartialEq, Eq)]
 // pub struct TreeNode {
 //     pub val: i32,
@@ -81,16 +68,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(values: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for val in values.into_iter().rev() {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = head;
-            head = Some(node);
-        }
-        head
-    }
+    use crate::list_node::List;
+    use crate::tree_node::Tree;
 
     fn is_valid_bst(root: &Option<Rc<RefCell<TreeNode>>>, min: i64, max: i64) -> bool {
         match root {
@@ -130,56 +109,46 @@ mod tests {
         }
     }
 
-    fn count_nodes(root: &Option<Rc<RefCell<TreeNode>>>) -> usize {
-        match root {
-            None => 0,
-            Some(node) => {
-                let node = node.borrow();
-                1 + count_nodes(&node.left) + count_nodes(&node.right)
-            }
-        }
-    }
-
     #[test]
     fn test_example() {
-        let list = vec_to_list(vec![-10, -3, 0, 5, 9]);
+        let list = List::from(vec![-10, -3, 0, 5, 9]).into_inner();
         let tree = Solution::sorted_list_to_bst(list);
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), 5);
+        assert_eq!(Tree::from(tree.clone()).node_count(), 5);
     }
 
     #[test]
     fn test_single_element() {
-        let list = vec_to_list(vec![1]);
+        let list = List::from(vec![1]).into_inner();
         let tree = Solution::sorted_list_to_bst(list);
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), 1);
+        assert_eq!(Tree::from(tree.clone()).node_count(), 1);
     }
 
     #[test]
     fn test_two_elements() {
-        let list = vec_to_list(vec![1, 3]);
+        let list = List::from(vec![1, 3]).into_inner();
         let tree = Solution::sorted_list_to_bst(list);
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), 2);
+        assert_eq!(Tree::from(tree.clone()).node_count(), 2);
     }
 
     #[test]
     fn test_empty() {
-        let list = vec_to_list(vec![]);
+        let list = List::from(vec![]).into_inner();
         let tree = Solution::sorted_list_to_bst(list);
         assert!(tree.is_none());
     }
 
     #[test]
     fn test_longer_list() {
-        let list = vec_to_list(vec![1, 2, 3, 4, 5, 6, 7]);
+        let list = List::from(vec![1, 2, 3, 4, 5, 6, 7]).into_inner();
         let tree = Solution::sorted_list_to_bst(list);
         assert!(is_valid_bst(&tree, i64::MIN, i64::MAX));
         assert!(is_balanced(&tree));
-        assert_eq!(count_nodes(&tree), 7);
+        assert_eq!(Tree::from(tree.clone()).node_count(), 7);
     }
 }
@@ -41,42 +41,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_from_vec(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(values.len());
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_balanced_tree() {
@@ -86,7 +51,7 @@ mod tests {
         //     9   20
         //        /  \
         //       15   7
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(3),
             Some(9),
             Some(20),
@@ -94,7 +59,8 @@ mod tests {
             None,
             Some(15),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::is_balanced(root));
     }
 
@@ -108,7 +74,7 @@ mod tests {
         //     3   3
         //    / \
         //   4   4
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(2),
@@ -118,19 +84,20 @@ mod tests {
             None,
             Some(4),
             Some(4),
-        ]);
+        ])
+        .into_inner();
         assert!(!Solution::is_balanced(root));
     }
 
     #[test]
     fn test_empty_tree() {
-        let root = tree_from_vec(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         assert!(Solution::is_balanced(root));
     }
 
     #[test]
     fn test_single_node() {
-        let root = tree_from_vec(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::is_balanced(root));
     }
 
@@ -142,7 +109,7 @@ mod tests {
         //      2     3
         //     / \   / \
         //    4   5 6   7
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -150,7 +117,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::is_balanced(root));
     }
 
@@ -162,7 +130,7 @@ mod tests {
         //     2
         //    /
         //   3
-        let root = tree_from_vec(vec![Some(1), Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), None, Some(3)]).into_inner();
         assert!(!Solution::is_balanced(root));
     }
 
@@ -174,7 +142,7 @@ mod tests {
         //     2
         //      \
         //       3
-        let root = tree_from_vec(vec![Some(1), None, Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         assert!(!Solution::is_balanced(root));
     }
 
@@ -187,7 +155,7 @@ mod tests {
         //    /
         //   4
         // This is balanced (height diff is 1 at root)
-        let root = tree_from_vec(vec![Some(1), Some(2), Some(3), Some(4)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4)]).into_inner();
         assert!(Solution::is_balanced(root));
     }
 }
@@ -57,41 +57,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_min_depth_balanced_tree() {
@@ -102,7 +68,16 @@ mod tests {
         //        /  \
         //       15   7
         // Minimum depth = 2 (path: 3->9)
-        let root = build_tree(&[Some(3), Some(9), Some(20), None, None, Some(15), Some(7)]);
+        let root = Tree::from(vec![
+            Some(3),
+            Some(9),
+            Some(20),
+            None,
+            None,
+            Some(15),
+            Some(7),
+        ])
+        .into_inner();
         assert_eq!(Solution::min_depth(root), 2);
     }
 
@@ -119,7 +94,7 @@ mod tests {
         //            \
         //             6
         // Minimum depth = 5
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(2),
             None,
             Some(3),
@@ -129,7 +104,8 @@ mod tests {
             Some(5),
             None,
             Some(6),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::min_depth(root), 5);
     }
 
@@ -137,7 +113,7 @@ mod tests {
     fn test_min_depth_single_node() {
         // Tree: [1]
         // Minimum depth = 1
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::min_depth(root), 1);
     }
 
@@ -145,7 +121,7 @@ mod tests {
     fn test_min_depth_empty_tree() {
         // Empty tree
         // Minimum depth = 0
-        let root = build_tree(&[]);
+        let root = Tree::from(vec![]).into_inner();
         assert_eq!(Solution::min_depth(root), 0);
     }
 
@@ -160,7 +136,7 @@ mod tests {
         //  /
         // 4
         // Minimum depth = 4
-        let root = build_tree(&[Some(1), Some(2), None, Some(3), None, Some(4)]);
+        let root = Tree::from(vec![Some(1), Some(2), None, Some(3), None, Some(4)]).into_inner();
         assert_eq!(Solution::min_depth(root), 4);
     }
 }
@@ -53,41 +53,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_has_path_sum_true() {
@@ -100,7 +66,7 @@ mod tests {
         //   / \      \
         //  7   2      1
         // Target: 22 (path: 5->4->11->2)
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(5),
             Some(4),
             Some(8),
@@ -114,7 +80,8 @@ mod tests {
             None,
             None,
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::has_path_sum(root, 22));
     }
 
@@ -125,7 +92,7 @@ mod tests {
         //      / \
         //     2   3
         // Target: 5 (no path sums to 5)
-        let root = build_tree(&[Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert!(!Solution::has_path_sum(root, 5));
     }
 
@@ -133,7 +100,7 @@ mod tests {
     fn test_has_path_sum_empty_tree() {
         // Empty tree
         // Target: 0
-        let root = build_tree(&[]);
+        let root = Tree::from(vec![]).into_inner();
         assert!(!Solution::has_path_sum(root, 0));
     }
 
@@ -141,7 +108,7 @@ mod tests {
     fn test_has_path_sum_single_node_true() {
         // Tree: [1]
         // Target: 1
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::has_path_sum(root, 1));
     }
 
@@ -149,7 +116,7 @@ mod tests {
     fn test_has_path_sum_single_node_false() {
         // Tree: [1]
         // Target: 2
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert!(!Solution::has_path_sum(root, 2));
     }
 
@@ -164,7 +131,7 @@ mod tests {
         //           /
         //         -2
         // Target: -1 (path: 1->-3->4->-2)
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(-3),
@@ -175,7 +142,8 @@ mod tests {
             None,
             None,
             Some(-2),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::has_path_sum(root, 0));
     }
 }
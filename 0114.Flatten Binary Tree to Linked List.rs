@@ -83,46 +83,10 @@ impl Solution {
 //     }
 // }
 
-/// LeetCode solution container.
-
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     fn tree_to_list(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<i32> {
         let mut result = Vec::new();
@@ -145,7 +109,16 @@ mod tests {
     fn test_flatten_example1() {
         // Tree: [1,2,5,3,4,null,6]
         // After flattening: 1->2->3->4->5->6
-        let mut tree = build_tree(&[Some(1), Some(2), Some(5), Some(3), Some(4), None, Some(6)]);
+        let mut tree = Tree::from(vec![
+            Some(1),
+            Some(2),
+            Some(5),
+            Some(3),
+            Some(4),
+            None,
+            Some(6),
+        ])
+        .into_inner();
         Solution::flatten(&mut tree);
         let result = tree_to_list(&tree);
         assert_eq!(result, vec![1, 2, 3, 4, 5, 6]);
@@ -160,7 +133,7 @@ mod tests {
 
     #[test]
     fn test_flatten_single_node() {
-        let mut tree = build_tree(&[Some(1)]);
+        let mut tree = Tree::from(vec![Some(1)]).into_inner();
         Solution::flatten(&mut tree);
         let result = tree_to_list(&tree);
         assert_eq!(result, vec![1]);
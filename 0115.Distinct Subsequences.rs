@@ -1,5 +1,3 @@
-/// LeetCode solution container.
-
 impl Solution {
     /// 1D dynamic programming for counting distinct subsequences.
     ///
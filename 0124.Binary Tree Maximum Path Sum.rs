@@ -59,41 +59,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_simple_tree() {
@@ -102,7 +68,7 @@ mod tests {
         //    / \
         //   2   3
         // Max path: 2 + 1 + 3 = 6
-        let root = build_tree(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::max_path_sum(root), 6);
     }
 
@@ -115,7 +81,7 @@ mod tests {
         //         /  \
         //        15   7
         // Max path: 15 + 20 + 7 = 42
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(-10),
             Some(9),
             Some(20),
@@ -123,7 +89,8 @@ mod tests {
             None,
             Some(15),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::max_path_sum(root), 42);
     }
 
@@ -131,7 +98,7 @@ mod tests {
     fn test_single_node_negative() {
         // Test tree: [-3]
         // Max path: -3
-        let root = build_tree(vec![Some(-3)]);
+        let root = Tree::from(vec![Some(-3)]).into_inner();
         assert_eq!(Solution::max_path_sum(root), -3);
     }
 
@@ -142,7 +109,7 @@ mod tests {
         //    /  \
         //   -1  -3
         // Max path: -1 (single node with max value)
-        let root = build_tree(vec![Some(-2), Some(-1), Some(-3)]);
+        let root = Tree::from(vec![Some(-2), Some(-1), Some(-3)]).into_inner();
         assert_eq!(Solution::max_path_sum(root), -1);
     }
 
@@ -157,7 +124,7 @@ mod tests {
         //    / \       \
         //   7   2       1
         // Max path: 7 + 11 + 4 + 5 + 8 + 13 = 48
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(5),
             Some(4),
             Some(8),
@@ -171,7 +138,8 @@ mod tests {
             None,
             None,
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::max_path_sum(root), 48);
     }
 }
@@ -73,48 +73,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_sum_numbers_example1() {
         // Tree: [1,2,3]
         // Paths: 1->2 = 12, 1->3 = 13
         // Sum: 12 + 13 = 25
-        let tree = build_tree(&[Some(1), Some(2), Some(3)]);
+        let tree = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::sum_numbers(tree), 25);
     }
 
@@ -123,7 +89,7 @@ mod tests {
         // Tree: [4,9,0,5,1]
         // Paths: 4->9->5 = 495, 4->9->1 = 491, 4->0 = 40
         // Sum: 495 + 491 + 40 = 1026
-        let tree = build_tree(&[Some(4), Some(9), Some(0), Some(5), Some(1)]);
+        let tree = Tree::from(vec![Some(4), Some(9), Some(0), Some(5), Some(1)]).into_inner();
         assert_eq!(Solution::sum_numbers(tree), 1026);
     }
 
@@ -132,7 +98,7 @@ mod tests {
         // Tree: [5]
         // Path: 5
         // Sum: 5
-        let tree = build_tree(&[Some(5)]);
+        let tree = Tree::from(vec![Some(5)]).into_inner();
         assert_eq!(Solution::sum_numbers(tree), 5);
     }
 }
@@ -1,16 +1,3 @@
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 use std::collections::VecDeque;
 
 impl Solution {
@@ -55,62 +42,44 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::list_node::List;
 
     /// Helper function to create a linked list from a vector
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for val in vec.into_iter().rev() {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = head;
-            head = Some(node);
-        }
-        head
-    }
-
     /// Helper function to convert a linked list to a vector
-    fn list_to_vec(mut head: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut result = Vec::new();
-        while let Some(node) = head {
-            result.push(node.val);
-            head = node.next;
-        }
-        result
-    }
-
     #[test]
     fn test_reorder_list_example1() {
         // Input: [1,2,3,4]
         // Expected: [1,4,2,3]
-        let mut head = vec_to_list(vec![1, 2, 3, 4]);
+        let mut head = List::from(vec![1, 2, 3, 4]).into_inner();
         Solution::reorder_list(&mut head);
-        assert_eq!(list_to_vec(head), vec![1, 4, 2, 3]);
+        assert_eq!(List::from(head).to_vec(), vec![1, 4, 2, 3]);
     }
 
     #[test]
     fn test_reorder_list_example2() {
         // Input: [1,2,3,4,5]
         // Expected: [1,5,2,4,3]
-        let mut head = vec_to_list(vec![1, 2, 3, 4, 5]);
+        let mut head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         Solution::reorder_list(&mut head);
-        assert_eq!(list_to_vec(head), vec![1, 5, 2, 4, 3]);
+        assert_eq!(List::from(head).to_vec(), vec![1, 5, 2, 4, 3]);
     }
 
     #[test]
     fn test_reorder_list_single_node() {
         // Input: [1]
         // Expected: [1]
-        let mut head = vec_to_list(vec![1]);
+        let mut head = List::from(vec![1]).into_inner();
         Solution::reorder_list(&mut head);
-        assert_eq!(list_to_vec(head), vec![1]);
+        assert_eq!(List::from(head).to_vec(), vec![1]);
     }
 
     #[test]
     fn test_reorder_list_two_nodes() {
         // Input: [1,2]
         // Expected: [1,2]
-        let mut head = vec_to_list(vec![1, 2]);
+        let mut head = List::from(vec![1, 2]).into_inner();
         Solution::reorder_list(&mut head);
-        assert_eq!(list_to_vec(head), vec![1, 2]);
+        assert_eq!(List::from(head).to_vec(), vec![1, 2]);
     }
 
     #[test]
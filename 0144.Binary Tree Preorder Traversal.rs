@@ -50,80 +50,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    // TreeNode definition for tests
-    #[derive(Debug, PartialEq, Eq)]
-    pub struct TreeNode {
-        pub val: i32,
-        pub left: Option<Rc<RefCell<TreeNode>>>,
-        pub right: Option<Rc<RefCell<TreeNode>>>,
-    }
-
-    impl TreeNode {
-        #[inline]
-        pub fn new(val: i32) -> Self {
-            TreeNode {
-                val,
-                left: None,
-                right: None,
-            }
-        }
-    }
-
-    struct Solution;
-
-    impl Solution {
-        pub fn preorder_traversal(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<i32> {
-            let mut result = Vec::new();
-            Self::dfs(&root, &mut result);
-            result
-        }
-
-        fn dfs(root: &Option<Rc<RefCell<TreeNode>>>, result: &mut Vec<i32>) {
-            if let Some(node) = root {
-                let node = node.borrow();
-                result.push(node.val);
-                Self::dfs(&node.left, result);
-                Self::dfs(&node.right, result);
-            }
-        }
-    }
-
-    // Helper function to create a tree from array representation
-    fn tree_from_vec(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_tree() {
@@ -148,7 +75,7 @@ mod tests {
     #[test]
     fn test_empty_tree() {
         // Tree: []
-        let root = tree_from_vec(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         let result = Solution::preorder_traversal(root);
         assert_eq!(result, Vec::<i32>::new());
     }
@@ -156,7 +83,7 @@ mod tests {
     #[test]
     fn test_single_node() {
         // Tree: [1]
-        let root = tree_from_vec(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::preorder_traversal(root);
         assert_eq!(result, vec![1]);
     }
@@ -170,7 +97,7 @@ mod tests {
         //     / \   / \
         //    4   5 6   7
         // Preorder: 1 -> 2 -> 4 -> 5 -> 3 -> 6 -> 7
-        let root = tree_from_vec(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -178,7 +105,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::preorder_traversal(root);
         assert_eq!(result, vec![1, 2, 4, 5, 3, 6, 7]);
     }
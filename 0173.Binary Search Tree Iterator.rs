@@ -68,41 +68,7 @@ impl BSTIterator {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_basic_bst_iteration() {
@@ -112,7 +78,16 @@ mod tests {
         //     3  15
         //        / \
         //       9  20
-        let tree = build_tree(&[Some(7), Some(3), Some(15), None, None, Some(9), Some(20)]);
+        let tree = Tree::from(vec![
+            Some(7),
+            Some(3),
+            Some(15),
+            None,
+            None,
+            Some(9),
+            Some(20),
+        ])
+        .into_inner();
         let mut iterator = BSTIterator::new(tree);
 
         assert!(iterator.has_next());
@@ -130,7 +105,7 @@ mod tests {
 
     #[test]
     fn test_single_node_tree() {
-        let tree = build_tree(&[Some(42)]);
+        let tree = Tree::from(vec![Some(42)]).into_inner();
         let mut iterator = BSTIterator::new(tree);
 
         assert!(iterator.has_next());
@@ -26,7 +26,7 @@ impl Solution {
         // Swap bytes
         n = ((n & 0xFF00FF00) >> 8) | ((n & 0x00FF00FF) << 8);
         // Swap half-words (16 bits)
-        n = (n >> 16) | (n << 16);
+        n = n.rotate_left(16);
         n as i32
     }
 }
@@ -66,41 +66,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_regular_tree() {
@@ -111,7 +77,7 @@ mod tests {
         //    \   \
         //     5   4
         // Right side view: [1,3,4]
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -119,7 +85,8 @@ mod tests {
             Some(5),
             None,
             Some(4),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::right_side_view(root), vec![1, 3, 4]);
     }
 
@@ -132,14 +99,14 @@ mod tests {
         //  /
         // 5
         // Right side view: [1,2,5]
-        let root = build_tree(vec![Some(1), Some(2), None, Some(5)]);
+        let root = Tree::from(vec![Some(1), Some(2), None, Some(5)]).into_inner();
         assert_eq!(Solution::right_side_view(root), vec![1, 2, 5]);
     }
 
     #[test]
     fn test_empty_tree() {
         // Empty tree
-        let root = build_tree(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         assert_eq!(Solution::right_side_view(root), vec![]);
     }
 
@@ -147,7 +114,7 @@ mod tests {
     fn test_single_node() {
         // Test tree: [1]
         // Right side view: [1]
-        let root = build_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::right_side_view(root), vec![1]);
     }
 
@@ -160,7 +127,7 @@ mod tests {
         //    / \ / \
         //   4  5 6  7
         // Right side view: [1,3,7]
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -168,7 +135,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::right_side_view(root), vec![1, 3, 7]);
     }
 
@@ -183,7 +151,7 @@ mod tests {
         //    \
         //     6
         // Right side view: [1,3,5,6]
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -193,7 +161,8 @@ mod tests {
             Some(5),
             None,
             Some(6),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::right_side_view(root), vec![1, 3, 5, 6]);
     }
 }
@@ -1,67 +1,40 @@
-struct TrieNode {
-    children: [Option<Box<TrieNode>>; 26],
-    is_end: bool,
-}
-
-impl TrieNode {
-    fn new() -> Self {
-        Self {
-            children: Default::default(),
-            is_end: false,
-        }
-    }
-}
+use crate::trie::{self, Lowercase};
 
 struct Trie {
-    root: TrieNode,
+    words: trie::Trie<Lowercase>,
 }
 
 impl Trie {
-    /// Trie (prefix tree) using array-based children for O(1) character lookup.
+    /// Trie (prefix tree) over the shared arena-backed trie.
     ///
     /// # Intuition
     /// A trie stores strings character-by-character in a tree structure where
     /// each node has up to 26 children (for lowercase English letters).
     ///
     /// # Approach
-    /// Each node contains an array of 26 optional children and a boolean flag
-    /// marking end-of-word. Insert, search, and starts_with all traverse the
-    /// trie following the character path.
+    /// Delegate to `trie::Trie<Lowercase>`, whose nodes hold 26 child slots
+    /// and count the words ending at them. Insert, search, and starts_with
+    /// all traverse the trie following the character path.
     ///
     /// # Complexity
     /// - Time: O(m) per operation where m is the word/prefix length
     /// - Space: O(n * 26) where n is total characters inserted
     fn new() -> Self {
         Self {
-            root: TrieNode::new(),
+            words: trie::Trie::new(),
         }
     }
 
     fn insert(&mut self, word: String) {
-        let mut node = &mut self.root;
-        for idx in word.bytes().map(|b| (b - b'a') as usize) {
-            node = node.children[idx].get_or_insert_with(|| Box::new(TrieNode::new()));
-        }
-        node.is_end = true;
+        self.words.insert(word.bytes());
     }
 
     fn search(&self, word: String) -> bool {
-        self.find_node(&word).map_or(false, |node| node.is_end)
+        self.words.contains(word.bytes())
     }
 
     fn starts_with(&self, prefix: String) -> bool {
-        self.find_node(&prefix).is_some()
-    }
-
-    fn find_node(&self, s: &str) -> Option<&TrieNode> {
-        let mut node = &self.root;
-        for idx in s.bytes().map(|b| (b - b'a') as usize) {
-            match &node.children[idx] {
-                Some(child) => node = child,
-                None => return None,
-            }
-        }
-        Some(node)
+        self.words.find(prefix.bytes()).is_some()
     }
 }
 
@@ -21,7 +21,7 @@ impl Solution {
         lines.sort_unstable();
 
         let mut heap = BinaryHeap::new();
-        let mut result = Vec::with_capacity(lines.len());
+        let mut result: Vec<Vec<i32>> = Vec::with_capacity(lines.len());
         let mut idx = 0;
         let n = buildings.len();
 
@@ -38,10 +38,10 @@ impl Solution {
                 }
             }
             let height = heap.peek().map_or(0, |&(h, _)| h);
-            if result.is_empty() || result.last().unwrap() != &vec![line, height] {
-                if result.is_empty() || result.last().unwrap()[1] != height {
-                    result.push(vec![line, height]);
-                }
+            if (result.is_empty() || result.last().unwrap() != &vec![line, height])
+                && (result.is_empty() || result.last().unwrap()[1] != height)
+            {
+                result.push(vec![line, height]);
             }
         }
         result
@@ -51,6 +51,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::differential;
+    use crate::random::RandomSource;
 
     #[test]
     fn standard_skyline() {
@@ -80,4 +82,43 @@ mod tests {
             vec![vec![0, 3], vec![2, 0]]
         );
     }
+
+    /// Samples the height of every unit strip and records each change.
+    fn brute_force(buildings: &[(i32, i32, i32)]) -> Vec<Vec<i32>> {
+        let end = buildings.iter().map(|&(_, r, _)| r).max().unwrap_or(0);
+        let mut skyline = Vec::new();
+        let mut previous = 0;
+        for x in 0..=end {
+            let height = buildings
+                .iter()
+                .filter(|&&(l, r, _)| l <= x && x < r)
+                .map(|&(_, _, h)| h)
+                .max()
+                .unwrap_or(0);
+            if height != previous {
+                skyline.push(vec![x, height]);
+                previous = height;
+            }
+        }
+        skyline
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            |rng| {
+                differential::vec_with(rng, 6, |rng| {
+                    let left = rng.in_range(0..12);
+                    (left, left + rng.in_range(1..6), rng.in_range(1..6))
+                })
+            },
+            |buildings| buildings.iter().all(|&(l, r, h)| 0 <= l && l < r && h > 0),
+            |buildings| {
+                let mut buildings = buildings.clone();
+                buildings.sort_unstable();
+                Solution::get_skyline(buildings.iter().map(|&(l, r, h)| vec![l, r, h]).collect())
+            },
+            |buildings| brute_force(buildings),
+        );
+    }
 }
@@ -63,42 +63,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_complete_tree_6_nodes() {
@@ -109,14 +74,15 @@ mod tests {
         //    / \ /
         //   4  5 6
         // Count: 6
-        let root = build_tree(vec![Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+        let root =
+            Tree::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]).into_inner();
         assert_eq!(Solution::count_nodes(root), 6);
     }
 
     #[test]
     fn test_empty_tree() {
         // Empty tree
-        let root = build_tree(vec![]);
+        let root = Tree::from(vec![]).into_inner();
         assert_eq!(Solution::count_nodes(root), 0);
     }
 
@@ -124,7 +90,7 @@ mod tests {
     fn test_single_node() {
         // Test tree: [1]
         // Count: 1
-        let root = build_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::count_nodes(root), 1);
     }
 
@@ -137,7 +103,7 @@ mod tests {
         //    / \ / \
         //   4  5 6  7
         // Count: 7 (2^3 - 1)
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -145,7 +111,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::count_nodes(root), 7);
     }
 
@@ -153,7 +120,7 @@ mod tests {
     fn test_perfect_tree_4_levels() {
         // Test tree: Perfect binary tree with 4 levels
         // Count: 15 (2^4 - 1)
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -169,7 +136,8 @@ mod tests {
             Some(13),
             Some(14),
             Some(15),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::count_nodes(root), 15);
     }
 
@@ -184,7 +152,7 @@ mod tests {
         //   / \ / \
         //  8  9 10 11
         // Count: 11
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -196,7 +164,8 @@ mod tests {
             Some(9),
             Some(10),
             Some(11),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::count_nodes(root), 11);
     }
 
@@ -207,7 +176,7 @@ mod tests {
         //    / \
         //   2   3
         // Count: 3
-        let root = build_tree(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::count_nodes(root), 3);
     }
 }
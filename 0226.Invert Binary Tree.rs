@@ -48,70 +48,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::new();
-        if root.is_none() {
-            return result;
-        }
-
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                let n = n.borrow();
-                result.push(Some(n.val));
-                queue.push_back(n.left.clone());
-                queue.push_back(n.right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_invert_tree_balanced() {
@@ -122,7 +59,7 @@ mod tests {
         //     / \ / \
         //    1  3 6  9
         // Inverted: [4,7,2,9,6,3,1]
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(4),
             Some(2),
             Some(7),
@@ -130,9 +67,10 @@ mod tests {
             Some(3),
             Some(6),
             Some(9),
-        ]);
+        ])
+        .into_inner();
         let inverted = Solution::invert_tree(root);
-        let expected = build_tree(&[
+        let expected = Tree::from(vec![
             Some(4),
             Some(7),
             Some(2),
@@ -140,8 +78,12 @@ mod tests {
             Some(6),
             Some(3),
             Some(1),
-        ]);
-        assert_eq!(tree_to_vec(&inverted), tree_to_vec(&expected));
+        ])
+        .into_inner();
+        assert_eq!(
+            Tree::from(inverted.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
@@ -151,16 +93,19 @@ mod tests {
         //      / \
         //     1   3
         // Inverted: [2,3,1]
-        let root = build_tree(&[Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         let inverted = Solution::invert_tree(root);
-        let expected = build_tree(&[Some(2), Some(3), Some(1)]);
-        assert_eq!(tree_to_vec(&inverted), tree_to_vec(&expected));
+        let expected = Tree::from(vec![Some(2), Some(3), Some(1)]).into_inner();
+        assert_eq!(
+            Tree::from(inverted.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
     fn test_invert_tree_empty() {
         // Empty tree
-        let root = build_tree(&[]);
+        let root = Tree::from(vec![]).into_inner();
         let inverted = Solution::invert_tree(root);
         assert!(inverted.is_none());
     }
@@ -168,10 +113,13 @@ mod tests {
     #[test]
     fn test_invert_tree_single_node() {
         // Tree: [1]
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let inverted = Solution::invert_tree(root);
-        let expected = build_tree(&[Some(1)]);
-        assert_eq!(tree_to_vec(&inverted), tree_to_vec(&expected));
+        let expected = Tree::from(vec![Some(1)]).into_inner();
+        assert_eq!(
+            Tree::from(inverted.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
@@ -183,9 +131,12 @@ mod tests {
         //    /
         //   3
         // Inverted: [1,null,2,null,3]
-        let root = build_tree(&[Some(1), Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), None, Some(3)]).into_inner();
         let inverted = Solution::invert_tree(root);
-        let expected = build_tree(&[Some(1), None, Some(2), None, Some(3)]);
-        assert_eq!(tree_to_vec(&inverted), tree_to_vec(&expected));
+        let expected = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
+        assert_eq!(
+            Tree::from(inverted.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 }
@@ -61,42 +61,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(vals.len());
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_kth_smallest_example1() {
@@ -107,7 +72,7 @@ mod tests {
         //      \
         //       2
         // k=1 -> 1
-        let root = build_tree(vec![Some(3), Some(1), Some(4), None, Some(2)]);
+        let root = Tree::from(vec![Some(3), Some(1), Some(4), None, Some(2)]).into_inner();
         assert_eq!(Solution::kth_smallest(root, 1), 1);
     }
 
@@ -122,7 +87,7 @@ mod tests {
         //    /
         //   1
         // k=3 -> 3
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(5),
             Some(3),
             Some(6),
@@ -131,7 +96,8 @@ mod tests {
             None,
             None,
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::kth_smallest(root, 3), 3);
     }
 
@@ -139,7 +105,7 @@ mod tests {
     fn test_kth_smallest_single_node() {
         // BST: [1]
         // k=1 -> 1
-        let root = build_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::kth_smallest(root, 1), 1);
     }
 
@@ -150,7 +116,7 @@ mod tests {
         //      /
         //     1
         // k=2 -> 2
-        let root = build_tree(vec![Some(2), Some(1)]);
+        let root = Tree::from(vec![Some(2), Some(1)]).into_inner();
         assert_eq!(Solution::kth_smallest(root, 2), 2);
     }
 
@@ -163,7 +129,7 @@ mod tests {
         //          \
         //           3
         // k=2 -> 2
-        let root = build_tree(vec![Some(1), None, Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         assert_eq!(Solution::kth_smallest(root, 2), 2);
     }
 
@@ -176,7 +142,7 @@ mod tests {
         //    /
         //   1
         // k=3 -> 3
-        let root = build_tree(vec![Some(3), Some(2), None, Some(1)]);
+        let root = Tree::from(vec![Some(3), Some(2), None, Some(1)]).into_inner();
         assert_eq!(Solution::kth_smallest(root, 3), 3);
     }
 }
@@ -61,51 +61,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
-
-    fn build_tree_with_map(
-        vals: Vec<Option<i32>>,
-    ) -> (
-        Option<Rc<RefCell<TreeNode>>>,
-        HashMap<i32, Rc<RefCell<TreeNode>>>,
-    ) {
-        let mut map = HashMap::new();
-        if vals.is_empty() || vals[0].is_none() {
-            return (None, map);
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        map.insert(vals[0].unwrap(), Rc::clone(&root));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    map.insert(val, Rc::clone(&left));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    map.insert(val, Rc::clone(&right));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        (Some(root), map)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_lca_in_different_subtrees() {
@@ -118,22 +74,10 @@ mod tests {
         //     / \
         //    7   4
         // LCA of 5 and 1 is 3
-        let (root, map) = build_tree_with_map(vec![
-            Some(3),
-            Some(5),
-            Some(1),
-            Some(6),
-            Some(2),
-            Some(0),
-            Some(8),
-            None,
-            None,
-            Some(7),
-            Some(4),
-        ]);
-        let p = Some(Rc::clone(&map[&5]));
-        let q = Some(Rc::clone(&map[&1]));
-        let result = Solution::lowest_common_ancestor(root, p, q);
+        let tree: Tree = "[3,5,1,6,2,0,8,null,null,7,4]".parse().unwrap();
+        let p = tree.find(5);
+        let q = tree.find(1);
+        let result = Solution::lowest_common_ancestor(tree.into_inner(), p, q);
         assert_eq!(result.unwrap().borrow().val, 3);
     }
 
@@ -141,22 +85,10 @@ mod tests {
     fn test_lca_ancestor_descendant() {
         // Test tree: [3,5,1,6,2,0,8,null,null,7,4]
         // LCA of 5 and 4 is 5 (5 is ancestor of 4)
-        let (root, map) = build_tree_with_map(vec![
-            Some(3),
-            Some(5),
-            Some(1),
-            Some(6),
-            Some(2),
-            Some(0),
-            Some(8),
-            None,
-            None,
-            Some(7),
-            Some(4),
-        ]);
-        let p = Some(Rc::clone(&map[&5]));
-        let q = Some(Rc::clone(&map[&4]));
-        let result = Solution::lowest_common_ancestor(root, p, q);
+        let tree: Tree = "[3,5,1,6,2,0,8,null,null,7,4]".parse().unwrap();
+        let p = tree.find(5);
+        let q = tree.find(4);
+        let result = Solution::lowest_common_ancestor(tree.into_inner(), p, q);
         assert_eq!(result.unwrap().borrow().val, 5);
     }
 
@@ -167,10 +99,10 @@ mod tests {
         //    /
         //   2
         // LCA of 1 and 2 is 1
-        let (root, map) = build_tree_with_map(vec![Some(1), Some(2)]);
-        let p = Some(Rc::clone(&map[&1]));
-        let q = Some(Rc::clone(&map[&2]));
-        let result = Solution::lowest_common_ancestor(root, p, q);
+        let tree: Tree = "[1,2]".parse().unwrap();
+        let p = tree.find(1);
+        let q = tree.find(2);
+        let result = Solution::lowest_common_ancestor(tree.into_inner(), p, q);
         assert_eq!(result.unwrap().borrow().val, 1);
     }
 
@@ -185,22 +117,10 @@ mod tests {
         //     / \
         //    3   5
         // LCA of 3 and 5 is 4
-        let (root, map) = build_tree_with_map(vec![
-            Some(6),
-            Some(2),
-            Some(8),
-            Some(0),
-            Some(4),
-            Some(7),
-            Some(9),
-            None,
-            None,
-            Some(3),
-            Some(5),
-        ]);
-        let p = Some(Rc::clone(&map[&3]));
-        let q = Some(Rc::clone(&map[&5]));
-        let result = Solution::lowest_common_ancestor(root, p, q);
+        let tree: Tree = "[6,2,8,0,4,7,9,null,null,3,5]".parse().unwrap();
+        let p = tree.find(3);
+        let q = tree.find(5);
+        let result = Solution::lowest_common_ancestor(tree.into_inner(), p, q);
         assert_eq!(result.unwrap().borrow().val, 4);
     }
 
@@ -215,19 +135,10 @@ mod tests {
         //  /
         // 5
         // LCA of 5 and 3 is 1
-        let (root, map) = build_tree_with_map(vec![
-            Some(1),
-            Some(2),
-            Some(3),
-            Some(4),
-            None,
-            None,
-            None,
-            Some(5),
-        ]);
-        let p = Some(Rc::clone(&map[&5]));
-        let q = Some(Rc::clone(&map[&3]));
-        let result = Solution::lowest_common_ancestor(root, p, q);
+        let tree: Tree = "[1,2,3,4,null,null,null,5]".parse().unwrap();
+        let p = tree.find(5);
+        let q = tree.find(3);
+        let result = Solution::lowest_common_ancestor(tree.into_inner(), p, q);
         assert_eq!(result.unwrap().borrow().val, 1);
     }
 }
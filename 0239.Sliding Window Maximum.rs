@@ -22,10 +22,10 @@ impl Solution {
         let mut deque: VecDeque<usize> = VecDeque::new();
 
         for (i, &value) in nums.iter().enumerate() {
-            if let Some(&front) = deque.front() {
-                if i >= front + k {
-                    deque.pop_front();
-                }
+            if let Some(&front) = deque.front()
+                && i >= front + k
+            {
+                deque.pop_front();
             }
             while let Some(&back) = deque.back() {
                 if nums[back] <= value {
@@ -1,38 +1,23 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::interval_set::max_overlap;
 
 impl Solution {
-    /// Finds the minimum number of meeting rooms required using a min-heap.
+    /// Finds the minimum number of meeting rooms as the maximum overlap depth.
     ///
     /// # Intuition
-    /// Sort meetings by start time. Use a min-heap to track the earliest ending
-    /// meeting. If a new meeting starts after the earliest ends, reuse that room.
+    /// The rooms needed equal the most meetings in progress at any instant.
+    /// That depth only changes where a meeting starts or ends.
     ///
     /// # Approach
-    /// 1. Sort intervals by start time.
-    /// 2. Push the first meeting's end time into a min-heap.
-    /// 3. For each subsequent meeting, if it starts after the heap's min end time,
-    ///    pop that room and push the new end time. Otherwise, add a new room.
-    /// 4. The heap size is the answer.
+    /// Treat each meeting as the half-open range `[start, end)` and sweep its
+    /// start and end events in order with `max_overlap`. Ends sort before
+    /// starts at the same time, so a room freed at `t` can host a meeting
+    /// starting at `t`.
     ///
     /// # Complexity
     /// - Time: O(n log n)
-    /// - Space: O(n) for the heap
-    pub fn min_meeting_rooms(mut intervals: Vec<Vec<i32>>) -> i32 {
-        intervals.sort_unstable_by_key(|i| i[0]);
-        let mut heap = BinaryHeap::new();
-        heap.push(Reverse(intervals[0][1]));
-
-        for interval in intervals.iter().skip(1) {
-            if let Some(&Reverse(earliest_end)) = heap.peek() {
-                if earliest_end <= interval[0] {
-                    heap.pop();
-                }
-            }
-            heap.push(Reverse(interval[1]));
-        }
-
-        heap.len() as i32
+    /// - Space: O(n) for the events
+    pub fn min_meeting_rooms(intervals: Vec<Vec<i32>>) -> i32 {
+        max_overlap(intervals.iter().map(|i| i[0]..i[1])) as i32
     }
 }
 
@@ -1,6 +1,14 @@
-// The API isBadVersion is defined for you.
-// isBadVersion(version:i32)-> bool;
-// to call it use self.isBadVersion(version)
+/// LeetCode's harness object; `isBadVersion` is the API it defines for you.
+pub struct Solution {
+    bad_version: i32,
+}
+
+impl Solution {
+    #[allow(non_snake_case)]
+    fn isBadVersion(&self, version: i32) -> bool {
+        version >= self.bad_version
+    }
+}
 
 impl Solution {
     /// Finds the first bad version using binary search.
@@ -38,52 +46,28 @@ impl Solution {
 mod tests {
     use super::*;
 
-    struct TestSolution {
-        bad_version: i32,
-    }
-
-    impl TestSolution {
-        #[allow(non_snake_case)]
-        fn isBadVersion(&self, version: i32) -> bool {
-            version >= self.bad_version
-        }
-
-        fn first_bad_version(&self, n: i32) -> i32 {
-            let (mut left, mut right) = (1, n);
-            while left < right {
-                let mid = left + (right - left) / 2;
-                if self.isBadVersion(mid) {
-                    right = mid;
-                } else {
-                    left = mid + 1;
-                }
-            }
-            left
-        }
-    }
-
     #[test]
     fn test_first_bad_middle() {
-        let solution = TestSolution { bad_version: 4 };
+        let solution = Solution { bad_version: 4 };
         assert_eq!(solution.first_bad_version(5), 4);
     }
 
     #[test]
     fn test_first_bad_at_start() {
-        let solution = TestSolution { bad_version: 1 };
+        let solution = Solution { bad_version: 1 };
         assert_eq!(solution.first_bad_version(1), 1);
         assert_eq!(solution.first_bad_version(10), 1);
     }
 
     #[test]
     fn test_first_bad_at_end() {
-        let solution = TestSolution { bad_version: 10 };
+        let solution = Solution { bad_version: 10 };
         assert_eq!(solution.first_bad_version(10), 10);
     }
 
     #[test]
     fn test_large_input() {
-        let solution = TestSolution {
+        let solution = Solution {
             bad_version: 1702766719,
         };
         assert_eq!(solution.first_bad_version(2126753390), 1702766719);
@@ -91,7 +75,7 @@ mod tests {
 
     #[test]
     fn test_two_versions() {
-        let solution = TestSolution { bad_version: 2 };
+        let solution = Solution { bad_version: 2 };
         assert_eq!(solution.first_bad_version(2), 2);
     }
 }
@@ -11,12 +11,16 @@ impl MedianFinder {
     ///
     /// # Intuition
     /// A max-heap holds the smaller half and a min-heap holds the larger half.
-    /// The median is derived from the tops of both heaps.
+    /// The median is derived from the tops of both heaps. Only the median is
+    /// asked for, so the heaps beat an order-statistics structure such as
+    /// `SortedList`, which pays for removal and arbitrary percentiles.
     ///
     /// # Approach
     /// 1. Push each number through the max-heap, then move its top to the min-heap.
     /// 2. If the min-heap grows too large, rebalance by moving its top to the max-heap.
-    /// 3. The median is the min-heap top (odd count) or the average of both tops (even).
+    /// 3. The median is the min-heap top (odd count) or the average of both tops
+    ///    (even), taken in `f64` so extreme values cannot overflow; with no
+    ///    numbers it is NaN.
     ///
     /// # Complexity
     /// - Time: O(log n) per add, O(1) per find_median
@@ -37,10 +41,12 @@ impl MedianFinder {
     }
 
     fn find_median(&self) -> f64 {
-        if self.min_heap.len() == self.max_heap.len() {
-            (self.min_heap.peek().unwrap().0 + self.max_heap.peek().unwrap()) as f64 / 2.0
-        } else {
-            self.min_heap.peek().unwrap().0 as f64
+        match (self.min_heap.peek(), self.max_heap.peek()) {
+            (None, _) => f64::NAN,
+            (Some(&Reverse(upper)), Some(&lower)) if self.min_heap.len() == self.max_heap.len() => {
+                (f64::from(lower) + f64::from(upper)) / 2.0
+            }
+            (Some(&Reverse(upper)), _) => f64::from(upper),
         }
     }
 }
@@ -58,4 +64,24 @@ mod tests {
         mf.add_num(3);
         assert!((mf.find_median() - 2.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn extreme_values_do_not_overflow() {
+        let mut mf = MedianFinder::new();
+        mf.add_num(i32::MAX);
+        mf.add_num(i32::MAX - 2);
+        assert!((mf.find_median() - (i32::MAX - 1) as f64).abs() < f64::EPSILON);
+        for _ in 0..3000 {
+            mf.add_num(-5);
+        }
+        assert!((mf.find_median() + 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn empty_finder_has_no_median() {
+        let mut mf = MedianFinder::new();
+        assert!(mf.find_median().is_nan());
+        mf.add_num(-7);
+        assert!((mf.find_median() + 7.0).abs() < f64::EPSILON);
+    }
 }
@@ -1,5 +1,7 @@
+use crate::range_query::{PrefixSum, RangeQuery};
+
 struct NumArray {
-    prefix: Vec<i32>,
+    prefix: PrefixSum<i32>,
 }
 
 impl NumArray {
@@ -16,18 +18,13 @@ impl NumArray {
     /// - Time: O(n) construction, O(1) per query
     /// - Space: O(n)
     fn new(nums: Vec<i32>) -> Self {
-        let mut prefix = Vec::with_capacity(nums.len() + 1);
-        prefix.push(0);
-        let mut sum = 0;
-        for num in nums {
-            sum += num;
-            prefix.push(sum);
+        Self {
+            prefix: PrefixSum::from(nums),
         }
-        Self { prefix }
     }
 
     fn sum_range(&self, left: i32, right: i32) -> i32 {
-        self.prefix[(right + 1) as usize] - self.prefix[left as usize]
+        self.prefix.query(left as usize..=right as usize)
     }
 }
 
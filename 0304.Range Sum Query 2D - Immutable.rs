@@ -1,5 +1,7 @@
+use crate::range_query::{PrefixSum2D, RangeQuery};
+
 struct NumMatrix {
-    prefix: Vec<Vec<i32>>,
+    prefix: PrefixSum2D<i32>,
 }
 
 impl NumMatrix {
@@ -17,28 +19,14 @@ impl NumMatrix {
     /// - Time: O(m * n) construction, O(1) per query
     /// - Space: O(m * n)
     fn new(matrix: Vec<Vec<i32>>) -> Self {
-        if matrix.is_empty() || matrix[0].is_empty() {
-            return Self {
-                prefix: vec![vec![0]],
-            };
-        }
-        let m = matrix.len();
-        let n = matrix[0].len();
-        let mut prefix = Vec::with_capacity(m + 1);
-        prefix.resize(m + 1, vec![0; n + 1]);
-        for i in 0..m {
-            for j in 0..n {
-                prefix[i + 1][j + 1] =
-                    prefix[i][j + 1] + prefix[i + 1][j] - prefix[i][j] + matrix[i][j];
-            }
+        Self {
+            prefix: PrefixSum2D::from(matrix),
         }
-        Self { prefix }
     }
 
     fn sum_region(&self, row1: i32, col1: i32, row2: i32, col2: i32) -> i32 {
         let (r1, c1, r2, c2) = (row1 as usize, col1 as usize, row2 as usize, col2 as usize);
-        self.prefix[r2 + 1][c2 + 1] - self.prefix[r2 + 1][c1] - self.prefix[r1][c2 + 1]
-            + self.prefix[r1][c1]
+        self.prefix.query((r1, c1)..=(r2, c2))
     }
 }
 
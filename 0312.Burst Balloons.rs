@@ -37,6 +37,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::differential;
+    use crate::random::RandomSource;
 
     #[test]
     fn standard_case() {
@@ -65,4 +67,27 @@ mod tests {
     fn empty_input() {
         assert_eq!(Solution::max_coins(vec![]), 0);
     }
+
+    /// Tries every balloon as the next one to burst.
+    fn brute_force(nums: &[i32]) -> i32 {
+        (0..nums.len())
+            .map(|i| {
+                let left = if i > 0 { nums[i - 1] } else { 1 };
+                let right = nums.get(i + 1).copied().unwrap_or(1);
+                let rest = [&nums[..i], &nums[i + 1..]].concat();
+                left * nums[i] * right + brute_force(&rest)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            |rng| differential::vec_with(rng, 6, |rng| rng.in_range(0..=100)),
+            |nums| nums.iter().all(|&x| (0..=100).contains(&x)),
+            |nums| Solution::max_coins(nums.clone()),
+            |nums| brute_force(nums),
+        );
+    }
 }
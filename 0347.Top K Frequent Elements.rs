@@ -67,7 +67,7 @@ mod tests {
 
     #[test]
     fn negative_numbers() {
-        let mut result = Solution::top_k_frequent(vec![-1, -1, -2, -2, -2], 1);
+        let result = Solution::top_k_frequent(vec![-1, -1, -2, -2, -2], 1);
         assert_eq!(result, vec![-2]);
     }
 
@@ -1,36 +1,41 @@
+use crate::rate_limit::{Limiter, SlidingWindowCounter};
+
 struct HitCounter {
-    timestamps: Vec<i32>,
+    buckets: SlidingWindowCounter,
 }
 
 impl HitCounter {
-    /// Hit counter that counts hits in the last 300 seconds using binary search.
+    /// Hit counter over the last 300 seconds, as one-second buckets.
     ///
     /// # Intuition
-    /// Store all timestamps sorted. Use binary search to find the first timestamp
-    /// within the 300-second window.
+    /// Timestamps are whole seconds, so counting hits per second over a ring
+    /// of 300 buckets is exact, and memory no longer grows with the hits.
     ///
     /// # Approach
-    /// 1. Append timestamps to a vector (already sorted by arrival order).
-    /// 2. On getHits, binary search for the first timestamp >= `timestamp - 299`.
-    /// 3. The count is `total - index`.
+    /// 1. Configure a sliding-window counter with 300 buckets of one second
+    ///    and no limit.
+    /// 2. A hit records into the bucket for its second, reclaiming the slots
+    ///    of the seconds that have left the window and keeping a running
+    ///    total.
+    /// 3. getHits is that total, less the buckets that would leave the
+    ///    window by `timestamp`.
     ///
     /// # Complexity
-    /// - Time: O(1) hit, O(log n) getHits
-    /// - Space: O(n)
+    /// - Time: O(1) hit and getHits, plus O(1) per second elapsed since the
+    ///   latest hit, at most O(300)
+    /// - Space: O(300)
     fn new() -> Self {
         Self {
-            timestamps: Vec::new(),
+            buckets: SlidingWindowCounter::new(1, 300, u64::MAX),
         }
     }
 
     fn hit(&mut self, timestamp: i32) {
-        self.timestamps.push(timestamp);
+        self.buckets.try_acquire(timestamp as u64);
     }
 
     fn get_hits(&self, timestamp: i32) -> i32 {
-        let target = timestamp - 299;
-        let idx = self.timestamps.partition_point(|&t| t < target);
-        (self.timestamps.len() - idx) as i32
+        self.buckets.count(timestamp as u64) as i32
     }
 }
 
@@ -1,6 +1,23 @@
-// Forward declaration - the actual implementation is provided by the system
-extern "C" {
-    fn guess(num: i32) -> i32;
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+// The guess API is provided by LeetCode; this stand-in answers for the number
+// stored with `set_pick` on the current thread.
+thread_local! {
+    static PICK: Cell<i32> = const { Cell::new(0) };
+}
+
+#[cfg(test)]
+fn set_pick(value: i32) {
+    PICK.with(|pick| pick.set(value));
+}
+
+fn guess(num: i32) -> i32 {
+    match num.cmp(&PICK.with(Cell::get)) {
+        Ordering::Greater => -1,
+        Ordering::Less => 1,
+        Ordering::Equal => 0,
+    }
 }
 
 impl Solution {
@@ -29,7 +46,7 @@ impl Solution {
         let (mut left, mut right) = (1, n);
         loop {
             let mid = left + (right - left) / 2;
-            match unsafe { guess(mid) } {
+            match guess(mid) {
                 -1 => right = mid - 1,
                 1 => left = mid + 1,
                 _ => break mid,
@@ -40,74 +57,45 @@ impl Solution {
 
 #[cfg(test)]
 mod tests {
-    use std::cmp::Ordering;
-    use std::sync::atomic::{AtomicI32, Ordering as AtomicOrdering};
-
-    // Thread-safe mock for the picked number
-    static PICK: AtomicI32 = AtomicI32::new(0);
-
-    fn set_pick(value: i32) {
-        PICK.store(value, AtomicOrdering::SeqCst);
-    }
-
-    fn guess(num: i32) -> i32 {
-        let pick = PICK.load(AtomicOrdering::SeqCst);
-        match num.cmp(&pick) {
-            Ordering::Greater => -1,
-            Ordering::Less => 1,
-            Ordering::Equal => 0,
-        }
-    }
-
-    fn guess_number(n: i32) -> i32 {
-        let (mut left, mut right) = (1, n);
-        loop {
-            let mid = left + (right - left) / 2;
-            match guess(mid) {
-                -1 => right = mid - 1,
-                1 => left = mid + 1,
-                _ => return mid,
-            }
-        }
-    }
+    use super::*;
 
     #[test]
     fn test_guess_middle() {
         set_pick(6);
-        assert_eq!(guess_number(10), 6);
+        assert_eq!(Solution::guessNumber(10), 6);
     }
 
     #[test]
     fn test_guess_first() {
         set_pick(1);
-        assert_eq!(guess_number(1), 1);
-        assert_eq!(guess_number(10), 1);
+        assert_eq!(Solution::guessNumber(1), 1);
+        assert_eq!(Solution::guessNumber(10), 1);
     }
 
     #[test]
     fn test_guess_last() {
         set_pick(10);
-        assert_eq!(guess_number(10), 10);
+        assert_eq!(Solution::guessNumber(10), 10);
     }
 
     #[test]
     fn test_guess_two_elements() {
         set_pick(1);
-        assert_eq!(guess_number(2), 1);
+        assert_eq!(Solution::guessNumber(2), 1);
 
         set_pick(2);
-        assert_eq!(guess_number(2), 2);
+        assert_eq!(Solution::guessNumber(2), 2);
     }
 
     #[test]
     fn test_large_range() {
         set_pick(1_234_567);
-        assert_eq!(guess_number(2_000_000_000), 1_234_567);
+        assert_eq!(Solution::guessNumber(2_000_000_000), 1_234_567);
     }
 
     #[test]
     fn test_pick_near_max() {
         set_pick(i32::MAX - 1);
-        assert_eq!(guess_number(i32::MAX), i32::MAX - 1);
+        assert_eq!(Solution::guessNumber(i32::MAX), i32::MAX - 1);
     }
 }
@@ -1,8 +1,11 @@
+use crate::random::{RandomSource, SeededRng};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 struct RandomizedSet {
     vals: Vec<i32>,
     indices: HashMap<i32, usize>,
+    rng: RefCell<Box<dyn RandomSource>>,
 }
 
 impl RandomizedSet {
@@ -15,15 +18,21 @@ impl RandomizedSet {
     /// # Approach
     /// 1. Insert: append to vector, store index in map.
     /// 2. Remove: swap with last element, update map, pop last.
-    /// 3. GetRandom: pick a random index from the vector.
+    /// 3. GetRandom: draw a uniform index from the injected random source,
+    ///    a fixed-seed generator unless one is supplied.
     ///
     /// # Complexity
     /// - Time: O(1) per operation
     /// - Space: O(n)
     fn new() -> Self {
+        Self::with_rng(SeededRng::default())
+    }
+
+    fn with_rng(rng: impl RandomSource + 'static) -> Self {
         Self {
             vals: Vec::with_capacity(16),
             indices: HashMap::with_capacity(16),
+            rng: RefCell::new(Box::new(rng)),
         }
     }
 
@@ -50,13 +59,7 @@ impl RandomizedSet {
     }
 
     fn get_random(&self) -> i32 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        use std::time::SystemTime;
-        let mut hasher = DefaultHasher::new();
-        SystemTime::now().hash(&mut hasher);
-        let idx = hasher.finish() as usize % self.vals.len();
-        self.vals[idx]
+        self.vals[self.rng.borrow_mut().index(self.vals.len())]
     }
 }
 
@@ -123,6 +126,34 @@ mod tests {
         assert!((6..=10).contains(&val));
     }
 
+    #[test]
+    fn same_seed_repeats_draws() {
+        let draws = |seed| {
+            let mut set = RandomizedSet::with_rng(SeededRng::new(seed));
+            (0..50).for_each(|i| assert!(set.insert(i)));
+            (0..20).map(|_| set.get_random()).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(380), draws(380));
+        assert_ne!(draws(380), draws(381));
+    }
+
+    #[test]
+    fn get_random_is_uniform_after_removals() {
+        use crate::random::{chi_square, chi_square_critical};
+
+        let mut set = RandomizedSet::new();
+        (0..30).for_each(|i| assert!(set.insert(i)));
+        (0..30).step_by(3).for_each(|i| assert!(set.remove(i)));
+        let mut counts = [0u64; 30];
+        for _ in 0..40_000 {
+            counts[set.get_random() as usize] += 1;
+        }
+        // removed values are never drawn, the other 20 equally often
+        let kept: Vec<u64> = (0..30).filter(|i| i % 3 != 0).map(|i| counts[i]).collect();
+        assert_eq!(kept.iter().sum::<u64>(), 40_000);
+        assert!(chi_square(&kept, &[0.05; 20]) < chi_square_critical(19));
+    }
+
     #[test]
     fn negative_values() {
         let mut set = RandomizedSet::new();
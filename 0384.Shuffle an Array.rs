@@ -1,7 +1,9 @@
-use rand::Rng;
+use crate::random::{RandomSource, SeededRng};
+use std::cell::RefCell;
 
 struct Solution {
     nums: Vec<i32>,
+    rng: RefCell<Box<dyn RandomSource>>,
 }
 
 impl Solution {
@@ -13,14 +15,22 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Store the original array for reset.
-    /// 2. On shuffle, clone the array and perform Fisher-Yates swaps.
+    /// 2. On shuffle, clone the array and perform Fisher-Yates swaps with
+    ///    the injected random source, a fixed-seed generator by default.
     /// 3. On reset, return a clone of the original.
     ///
     /// # Complexity
     /// - Time: O(n) per shuffle/reset
     /// - Space: O(n)
     fn new(nums: Vec<i32>) -> Self {
-        Self { nums }
+        Self::with_rng(nums, SeededRng::default())
+    }
+
+    fn with_rng(nums: Vec<i32>, rng: impl RandomSource + 'static) -> Self {
+        Self {
+            nums,
+            rng: RefCell::new(Box::new(rng)),
+        }
     }
 
     fn reset(&self) -> Vec<i32> {
@@ -29,15 +39,7 @@ impl Solution {
 
     fn shuffle(&self) -> Vec<i32> {
         let mut result = self.nums.clone();
-        let n = result.len();
-        if n <= 1 {
-            return result;
-        }
-        let mut rng = rand::thread_rng();
-        for i in (1..n).rev() {
-            let j = rng.gen_range(0..=i);
-            result.swap(i, j);
-        }
+        self.rng.borrow_mut().shuffle(&mut result);
         result
     }
 }
@@ -133,4 +135,19 @@ mod tests {
             "Should see both permutations with two elements"
         );
     }
+
+    #[test]
+    fn test_every_permutation_equally_likely() {
+        use crate::random::{chi_square, chi_square_critical};
+        use std::collections::HashMap;
+
+        let solution = Solution::with_rng(vec![1, 2, 3, 4], SeededRng::new(384));
+        let mut counts: HashMap<Vec<i32>, u64> = HashMap::new();
+        for _ in 0..48_000 {
+            *counts.entry(solution.shuffle()).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 24);
+        let observed: Vec<u64> = counts.into_values().collect();
+        assert!(chi_square(&observed, &[1.0 / 24.0; 24]) < chi_square_critical(23));
+    }
 }
@@ -77,7 +77,8 @@ mod tests {
         assert_eq!(result.len(), 100);
         assert_eq!(result[0], 1);
         assert_eq!(result[1], 10);
-        assert_eq!(result[11], 2);
+        assert_eq!(result[2], 100);
+        assert_eq!(result[12], 2);
         assert_eq!(result[99], 99);
     }
 
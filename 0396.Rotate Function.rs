@@ -50,7 +50,7 @@ mod tests {
 
     #[test]
     fn test_negative() {
-        assert_eq!(Solution::max_rotate_function(vec![-1, -2, -3]), -4);
+        assert_eq!(Solution::max_rotate_function(vec![-1, -2, -3]), -5);
     }
 
     #[test]
@@ -1,63 +1,6 @@
 use std::collections::HashMap;
 
-struct UnionFind {
-    parent: HashMap<String, String>,
-    weight: HashMap<String, f64>,
-}
-
-impl UnionFind {
-    fn new(equations: &[Vec<String>]) -> Self {
-        let (parent, weight) = equations.iter().flat_map(|eq| eq.iter()).fold(
-            (
-                HashMap::with_capacity(equations.len() * 2),
-                HashMap::with_capacity(equations.len() * 2),
-            ),
-            |(mut parent, mut weight), var| {
-                let parent_key = var.to_owned();
-                parent.entry(parent_key.clone()).or_insert(parent_key);
-                weight.entry(var.to_owned()).or_insert(1.0);
-                (parent, weight)
-            },
-        );
-        Self { parent, weight }
-    }
-
-    fn find(&mut self, x: &str) -> String {
-        let p = self.parent[x].clone();
-        if p == x {
-            return p;
-        }
-        let root = self.find(&p);
-        let pw = self.weight[&p];
-        self.weight.entry(x.to_string()).and_modify(|w| *w *= pw);
-        self.parent.insert(x.to_string(), root.clone());
-        root
-    }
-
-    fn union(&mut self, a: &str, b: &str, value: f64) {
-        let ra = self.find(a);
-        let rb = self.find(b);
-        if ra == rb {
-            return;
-        }
-        let (wa, wb) = (self.weight[a], self.weight[b]);
-        self.parent.insert(ra.clone(), rb);
-        self.weight.insert(ra, wb * value / wa);
-    }
-
-    fn query(&mut self, a: &str, b: &str) -> f64 {
-        if !self.parent.contains_key(a) || !self.parent.contains_key(b) {
-            return -1.0;
-        }
-        let ra = self.find(a);
-        let rb = self.find(b);
-        if ra != rb {
-            -1.0
-        } else {
-            self.weight[a] / self.weight[b]
-        }
-    }
-}
+use crate::union_find::UnionFind;
 
 impl Solution {
     /// Evaluates division queries using a weighted Union-Find.
@@ -68,8 +11,9 @@ impl Solution {
     /// enabling O(alpha(n)) query evaluation.
     ///
     /// # Approach
-    /// 1. Build a weighted Union-Find from the equations.
-    /// 2. For each query, find both roots; if they match, compute weight_a / weight_b.
+    /// 1. Number the variables and build a Union-Find weighted by `f64` ratios.
+    /// 2. For each query, the ratio between two connected variables composes
+    ///    their ratios to the shared root.
     /// 3. Return -1.0 for disconnected or unknown variables.
     ///
     /// # Complexity
@@ -80,11 +24,25 @@ impl Solution {
         values: Vec<f64>,
         queries: Vec<Vec<String>>,
     ) -> Vec<f64> {
-        let mut uf = UnionFind::new(&equations);
+        let mut index: HashMap<&str, usize> = HashMap::with_capacity(equations.len() * 2);
+        for var in equations.iter().flatten() {
+            let next = index.len();
+            index.entry(var).or_insert(next);
+        }
+
+        let mut uf = UnionFind::<f64>::weighted(index.len());
         for (eq, &val) in equations.iter().zip(values.iter()) {
-            uf.union(&eq[0], &eq[1], val);
+            uf.union_with(index[eq[0].as_str()], index[eq[1].as_str()], val);
         }
-        queries.iter().map(|q| uf.query(&q[0], &q[1])).collect()
+        queries
+            .iter()
+            .map(
+                |q| match (index.get(q[0].as_str()), index.get(q[1].as_str())) {
+                    (Some(&a), Some(&b)) => uf.relation(a, b).unwrap_or(-1.0),
+                    _ => -1.0,
+                },
+            )
+            .collect()
     }
 }
 
@@ -105,7 +63,7 @@ mod tests {
         let values = vec![2.0, 3.0];
         let queries = make_eq(&[("a", "c"), ("b", "a"), ("a", "e"), ("a", "a"), ("x", "x")]);
         let result = Solution::calc_equation(equations, values, queries);
-        let expected = vec![6.0, 0.5, -1.0, 1.0, -1.0];
+        let expected = [6.0, 0.5, -1.0, 1.0, -1.0];
         result.iter().zip(expected.iter()).for_each(|(r, e)| {
             assert!((r - e).abs() < 1e-5, "expected {e}, got {r}");
         });
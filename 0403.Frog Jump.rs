@@ -39,7 +39,7 @@ impl Solution {
                 j > 0
                     && pos
                         .get(&(stones[idx] + j))
-                        .map_or(false, |&next| dfs(next, j as usize, n, stones, pos, memo))
+                        .is_some_and(|&next| dfs(next, j as usize, n, stones, pos, memo))
             });
             memo[idx][k] = found as i8;
             found
@@ -75,48 +75,23 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_sum_of_left_leaves_example1() {
         // Tree: [3,9,20,null,null,15,7]
         // Left leaves: 9, 15
         // Sum: 9 + 15 = 24
-        let tree = build_tree(&[Some(3), Some(9), Some(20), None, None, Some(15), Some(7)]);
+        let tree = Tree::from(vec![
+            Some(3),
+            Some(9),
+            Some(20),
+            None,
+            None,
+            Some(15),
+            Some(7),
+        ])
+        .into_inner();
         assert_eq!(Solution::sum_of_left_leaves(tree), 24);
     }
 
@@ -125,7 +100,7 @@ mod tests {
         // Tree: [1]
         // No left leaves
         // Sum: 0
-        let tree = build_tree(&[Some(1)]);
+        let tree = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::sum_of_left_leaves(tree), 0);
     }
 
@@ -134,7 +109,7 @@ mod tests {
         // Tree: [1,2]
         // Left leaf: 2
         // Sum: 2
-        let tree = build_tree(&[Some(1), Some(2)]);
+        let tree = Tree::from(vec![Some(1), Some(2)]).into_inner();
         assert_eq!(Solution::sum_of_left_leaves(tree), 2);
     }
 }
@@ -1,38 +1,4 @@
-struct Trie {
-    children: [Option<Box<Trie>>; 2],
-}
-
-impl Trie {
-    fn new() -> Self {
-        Self {
-            children: [None, None],
-        }
-    }
-
-    fn insert(&mut self, x: i32) {
-        let mut node = self;
-        for i in (0..=30).rev() {
-            let bit = ((x >> i) & 1) as usize;
-            node = node.children[bit].get_or_insert_with(|| Box::new(Trie::new()));
-        }
-    }
-
-    fn max_xor(&self, x: i32) -> i32 {
-        let mut node = self;
-        let mut result = 0;
-        for i in (0..=30).rev() {
-            let bit = ((x >> i) & 1) as usize;
-            let preferred = bit ^ 1;
-            if let Some(child) = &node.children[preferred] {
-                result |= 1 << i;
-                node = child;
-            } else {
-                node = node.children[bit].as_ref().unwrap();
-            }
-        }
-        result
-    }
-}
+use crate::trie::{Bits, Trie};
 
 impl Solution {
     /// Finds the maximum XOR of any two numbers using a bitwise trie.
@@ -52,10 +18,10 @@ impl Solution {
     /// - Time: O(31 · n) = O(n)
     /// - Space: O(31 · n) for trie nodes
     pub fn find_maximum_xor(nums: Vec<i32>) -> i32 {
-        let mut trie = Trie::new();
+        let mut trie: Trie<Bits> = Trie::new();
         nums.iter().fold(0, |best, &x| {
-            trie.insert(x);
-            best.max(trie.max_xor(x))
+            trie.insert(Bits::msb_first(x as u64, 31));
+            best.max(trie.max_xor(x as u64, 31).unwrap_or(0) as i32)
         })
     }
 }
@@ -1,17 +1,3 @@
-// Definition for singly-linked list.
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 impl Solution {
     /// Adds two numbers represented as linked lists (most significant digit first).
     ///
@@ -71,33 +57,17 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
-
-    fn list_to_vec(mut list: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut vec = Vec::new();
-        while let Some(node) = list {
-            vec.push(node.val);
-            list = node.next;
-        }
-        vec
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_add_two_numbers_example1() {
         // Input: l1 = [7,2,4,3], l2 = [5,6,4]
         // Output: [7,8,0,7]
         // Explanation: 7243 + 564 = 7807
-        let l1 = vec_to_list(vec![7, 2, 4, 3]);
-        let l2 = vec_to_list(vec![5, 6, 4]);
+        let l1 = List::from(vec![7, 2, 4, 3]).into_inner();
+        let l2 = List::from(vec![5, 6, 4]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(list_to_vec(result), vec![7, 8, 0, 7]);
+        assert_eq!(List::from(result).to_vec(), vec![7, 8, 0, 7]);
     }
 
     #[test]
@@ -105,10 +75,10 @@ mod tests {
         // Input: l1 = [2,4,3], l2 = [5,6,4]
         // Output: [8,0,7]
         // Explanation: 243 + 564 = 807
-        let l1 = vec_to_list(vec![2, 4, 3]);
-        let l2 = vec_to_list(vec![5, 6, 4]);
+        let l1 = List::from(vec![2, 4, 3]).into_inner();
+        let l2 = List::from(vec![5, 6, 4]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(list_to_vec(result), vec![8, 0, 7]);
+        assert_eq!(List::from(result).to_vec(), vec![8, 0, 7]);
     }
 
     #[test]
@@ -116,10 +86,10 @@ mod tests {
         // Input: l1 = [9,9,9], l2 = [1]
         // Output: [1,0,0,0]
         // Explanation: 999 + 1 = 1000
-        let l1 = vec_to_list(vec![9, 9, 9]);
-        let l2 = vec_to_list(vec![1]);
+        let l1 = List::from(vec![9, 9, 9]).into_inner();
+        let l2 = List::from(vec![1]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(list_to_vec(result), vec![1, 0, 0, 0]);
+        assert_eq!(List::from(result).to_vec(), vec![1, 0, 0, 0]);
     }
 
     #[test]
@@ -127,10 +97,10 @@ mod tests {
         // Input: l1 = [0], l2 = [0]
         // Output: [0]
         // Explanation: 0 + 0 = 0
-        let l1 = vec_to_list(vec![0]);
-        let l2 = vec_to_list(vec![0]);
+        let l1 = List::from(vec![0]).into_inner();
+        let l2 = List::from(vec![0]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(list_to_vec(result), vec![0]);
+        assert_eq!(List::from(result).to_vec(), vec![0]);
     }
 
     #[test]
@@ -138,9 +108,9 @@ mod tests {
         // Input: l1 = [1,0,0,0,0], l2 = [9,9]
         // Output: [1,0,0,9,9]
         // Explanation: 10000 + 99 = 10099
-        let l1 = vec_to_list(vec![1, 0, 0, 0, 0]);
-        let l2 = vec_to_list(vec![9, 9]);
+        let l1 = List::from(vec![1, 0, 0, 0, 0]).into_inner();
+        let l2 = List::from(vec![9, 9]).into_inner();
         let result = Solution::add_two_numbers(l1, l2);
-        assert_eq!(list_to_vec(result), vec![1, 0, 0, 9, 9]);
+        assert_eq!(List::from(result).to_vec(), vec![1, 0, 0, 9, 9]);
     }
 }
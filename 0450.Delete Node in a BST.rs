@@ -88,104 +88,81 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::with_capacity(32);
-        let mut queue = VecDeque::with_capacity(32);
-        queue.push_back(root);
-
-        while let Some(node_opt) = queue.pop_front() {
-            match node_opt {
-                Some(node) => {
-                    let n = node.borrow();
-                    result.push(Some(n.val));
-                    queue.push_back(n.left.clone());
-                    queue.push_back(n.right.clone());
-                }
-                None => result.push(None),
-            }
-        }
-
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_delete_leaf_node() {
-        let tree = build_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
+        let tree = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
         let result = Solution::delete_node(tree, 7);
-        let vals = tree_to_vec(result);
+        let vals = Tree::from(result).to_vec();
         assert!(!vals.contains(&Some(7)));
     }
 
     #[test]
     fn test_delete_node_with_one_child() {
-        let tree = build_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
+        let tree = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
         let result = Solution::delete_node(tree, 6);
-        let vals = tree_to_vec(result);
+        let vals = Tree::from(result).to_vec();
         assert!(!vals.contains(&Some(6)));
     }
 
     #[test]
     fn test_delete_node_with_two_children() {
-        let tree = build_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
+        let tree = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
         let result = Solution::delete_node(tree, 3);
-        let vals = tree_to_vec(result);
+        let vals = Tree::from(result).to_vec();
         assert!(!vals.contains(&Some(3)));
     }
 
     #[test]
     fn test_delete_root() {
-        let tree = build_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
+        let tree = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
         let result = Solution::delete_node(tree, 5);
-        let vals = tree_to_vec(result);
+        let vals = Tree::from(result).to_vec();
         assert!(!vals.contains(&Some(5)));
     }
 
     #[test]
     fn test_key_not_found() {
-        let tree = build_tree(&[Some(5), Some(3), Some(6)]);
+        let tree = Tree::from(vec![Some(5), Some(3), Some(6)]).into_inner();
         let result = Solution::delete_node(tree.clone(), 10);
-        assert_eq!(tree_to_vec(result), vec![Some(5), Some(3), Some(6)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(5), Some(3), Some(6)]);
     }
 
     #[test]
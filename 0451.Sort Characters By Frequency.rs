@@ -25,7 +25,7 @@ impl Solution {
                 .filter(|(_, count)| **count > 0)
                 .map(|(i, count)| (*count, i as u8)),
         );
-        pairs.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        pairs.sort_unstable_by_key(|x| std::cmp::Reverse(x.0));
         let mut result = String::with_capacity(s.len());
         for (count, byte) in pairs {
             for _ in 0..count {
@@ -23,7 +23,7 @@ impl Solution {
             if val < candidate {
                 return true;
             }
-            while stack.last().map_or(false, |&top| top < val) {
+            while stack.last().is_some_and(|&top| top < val) {
                 candidate = stack.pop().unwrap();
             }
             stack.push(val);
@@ -1,95 +1,7 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
-
-struct Node {
-    key: i32,
-    value: i32,
-    freq: i32,
-    prev: Option<Rc<RefCell<Node>>>,
-    next: Option<Rc<RefCell<Node>>>,
-}
-
-impl Node {
-    fn new(key: i32, value: i32) -> Self {
-        Self {
-            key,
-            value,
-            freq: 1,
-            prev: None,
-            next: None,
-        }
-    }
-}
-
-struct LinkedList {
-    head: Option<Rc<RefCell<Node>>>,
-    tail: Option<Rc<RefCell<Node>>>,
-}
-
-impl LinkedList {
-    fn new() -> Self {
-        Self {
-            head: None,
-            tail: None,
-        }
-    }
-
-    fn push_front(&mut self, node: &Rc<RefCell<Node>>) {
-        match self.head.take() {
-            Some(head) => {
-                head.borrow_mut().prev = Some(Rc::clone(node));
-                node.borrow_mut().prev = None;
-                node.borrow_mut().next = Some(head);
-                self.head = Some(Rc::clone(node));
-            }
-            None => {
-                node.borrow_mut().prev = None;
-                node.borrow_mut().next = None;
-                self.head = Some(Rc::clone(node));
-                self.tail = Some(Rc::clone(node));
-            }
-        }
-    }
-
-    fn remove(&mut self, node: &Rc<RefCell<Node>>) {
-        match (node.borrow().prev.as_ref(), node.borrow().next.as_ref()) {
-            (None, None) => {
-                self.head = None;
-                self.tail = None;
-            }
-            (None, Some(next)) => {
-                self.head = Some(Rc::clone(next));
-                next.borrow_mut().prev = None;
-            }
-            (Some(prev), None) => {
-                self.tail = Some(Rc::clone(prev));
-                prev.borrow_mut().next = None;
-            }
-            (Some(prev), Some(next)) => {
-                next.borrow_mut().prev = Some(Rc::clone(prev));
-                prev.borrow_mut().next = Some(Rc::clone(next));
-            }
-        }
-    }
-
-    fn pop_back(&mut self) -> Option<Rc<RefCell<Node>>> {
-        self.tail.take().map(|tail| {
-            self.remove(&tail);
-            tail
-        })
-    }
-
-    fn is_empty(&self) -> bool {
-        self.head.is_none()
-    }
-}
+use crate::lfu_cache::LfuCache;
 
 struct LFUCache {
-    cache: HashMap<i32, Rc<RefCell<Node>>>,
-    freq_map: HashMap<i32, LinkedList>,
-    min_freq: i32,
-    capacity: usize,
+    cache: LfuCache<i32, i32>,
 }
 
 impl LFUCache {
@@ -100,87 +12,34 @@ impl LFUCache {
     /// lists for O(1) eviction. Track the minimum frequency to find the LFU victim.
     ///
     /// # Approach
-    /// 1. Store nodes in a HashMap for O(1) access by key.
-    /// 2. Group nodes by frequency in separate doubly-linked lists.
-    /// 3. On access, move the node to the next frequency bucket.
-    /// 4. On eviction, remove from the tail of the min-frequency list.
+    /// Delegate to the generic `LfuCache`:
+    /// 1. Nodes live in a slab, indexed by key through a HashMap.
+    /// 2. Nodes are grouped by frequency in index-linked recency lists.
+    /// 3. On access, a node moves to the front of the next frequency's list.
+    /// 4. On eviction, the tail of the min-frequency list goes.
     ///
     /// # Complexity
     /// - Time: O(1) for both get and put operations
     /// - Space: O(capacity) for storing cache entries
     fn new(capacity: i32) -> Self {
-        let capacity = capacity as usize;
         Self {
-            cache: HashMap::with_capacity(capacity),
-            freq_map: HashMap::with_capacity(capacity),
-            min_freq: 0,
-            capacity,
+            cache: LfuCache::new(capacity as usize),
         }
     }
 
     fn get(&mut self, key: i32) -> i32 {
-        if self.capacity == 0 {
-            return -1;
-        }
-        match self.cache.get(&key) {
-            Some(node) => {
-                let node = Rc::clone(node);
-                self.incr_freq(&node);
-                node.borrow().value
-            }
-            None => -1,
-        }
+        self.cache.get(&key).copied().unwrap_or(-1)
     }
 
     fn put(&mut self, key: i32, value: i32) {
-        if self.capacity == 0 {
-            return;
-        }
-        match self.cache.get(&key) {
-            Some(node) => {
-                let node = Rc::clone(node);
-                node.borrow_mut().value = value;
-                self.incr_freq(&node);
-            }
-            None => {
-                if self.cache.len() == self.capacity {
-                    let list = self.freq_map.get_mut(&self.min_freq).unwrap();
-                    self.cache.remove(&list.pop_back().unwrap().borrow().key);
-                }
-                let node = Rc::new(RefCell::new(Node::new(key, value)));
-                self.add_node(&node);
-                self.cache.insert(key, node);
-                self.min_freq = 1;
-            }
-        }
-    }
-
-    fn incr_freq(&mut self, node: &Rc<RefCell<Node>>) {
-        let freq = node.borrow().freq;
-        let list = self.freq_map.get_mut(&freq).unwrap();
-        list.remove(node);
-        if list.is_empty() {
-            self.freq_map.remove(&freq);
-            if freq == self.min_freq {
-                self.min_freq += 1;
-            }
-        }
-        node.borrow_mut().freq += 1;
-        self.add_node(node);
-    }
-
-    fn add_node(&mut self, node: &Rc<RefCell<Node>>) {
-        let freq = node.borrow().freq;
-        self.freq_map
-            .entry(freq)
-            .or_insert_with(LinkedList::new)
-            .push_front(node);
+        self.cache.put(key, value);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::replay::{Script, check};
 
     #[test]
     fn test_lfu_cache_basic() {
@@ -238,4 +97,16 @@ mod tests {
         assert_eq!(cache.get(1), -1);
         assert_eq!(cache.get(2), 2);
     }
+
+    #[test]
+    fn test_lfu_cache_replays_leetcode_example() {
+        let script: Script = r#"
+            ["LFUCache","put","put","get","put","get","get","put","get","get","get"]
+            [[2],[1,1],[2,2],[1],[3,3],[2],[3],[4,4],[1],[3],[4]]
+            [null,null,null,1,null,-1,3,null,-1,3,4]
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(check::<LFUCache>(&script), Ok(vec![]));
+    }
 }
@@ -34,7 +34,7 @@ impl Solution {
                     && p.len() <= 3
                     && !(p.len() > 1 && p.starts_with('0'))
                     && p.chars().all(|c| c.is_ascii_digit())
-                    && p.parse::<u16>().map_or(false, |v| v <= 255)
+                    && p.parse::<u16>().is_ok_and(|v| v <= 255)
             })
     }
 
@@ -37,6 +37,6 @@ mod tests {
 
     #[test]
     fn test_two_elements() {
-        assert_eq!(Solution::total_hamming_distance(vec![1, 4]), 3);
+        assert_eq!(Solution::total_hamming_distance(vec![1, 4]), 2);
     }
 }
@@ -27,7 +27,7 @@ impl Solution {
             s.extend((0..count).map(|_| cur));
             i += 1;
         }
-        s.iter().take(n).filter(|x| *x == 1).count() as i32
+        s.iter().take(n).filter(|&&x| x == 1).count() as i32
     }
 }
 
@@ -47,6 +47,6 @@ mod tests {
 
     #[test]
     fn test_ten() {
-        assert_eq!(Solution::magical_string(10), 4);
+        assert_eq!(Solution::magical_string(10), 5);
     }
 }
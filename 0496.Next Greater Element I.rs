@@ -19,7 +19,7 @@ impl Solution {
         let mut stack = Vec::with_capacity(nums2.len());
         let mut map = HashMap::with_capacity(nums2.len());
         for &x in nums2.iter().rev() {
-            while stack.last().map_or(false, |&top| top <= x) {
+            while stack.last().is_some_and(|&top| top <= x) {
                 stack.pop();
             }
             if let Some(&top) = stack.last() {
@@ -20,7 +20,7 @@ impl Solution {
         (0..m * n)
             .map(|_| {
                 let val = mat[i][j];
-                if (i + j) % 2 == 0 {
+                if (i + j).is_multiple_of(2) {
                     if j == n - 1 {
                         i += 1;
                     } else if i == 0 {
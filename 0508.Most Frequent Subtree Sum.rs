@@ -62,37 +62,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -101,7 +71,7 @@ mod tests {
         //      / \
         //     2  -3
         // Subtree sums: 2 (left leaf), -3 (right leaf), 4 (root: 5+2-3)
-        let root = create_tree(vec![Some(5), Some(2), Some(-3)]);
+        let root = Tree::from(vec![Some(5), Some(2), Some(-3)]).into_inner();
         let mut result = Solution::find_frequent_tree_sum(root);
         result.sort_unstable();
         let mut expected = vec![2, -3, 4];
@@ -117,7 +87,7 @@ mod tests {
         //     2  -5
         // Subtree sums: 2 (left leaf), -5 (right leaf), 2 (root: 5+2-5)
         // Frequency: 2 appears twice, -5 appears once
-        let root = create_tree(vec![Some(5), Some(2), Some(-5)]);
+        let root = Tree::from(vec![Some(5), Some(2), Some(-5)]).into_inner();
         let result = Solution::find_frequent_tree_sum(root);
         assert_eq!(result, vec![2]);
     }
@@ -125,7 +95,7 @@ mod tests {
     #[test]
     fn test_case_3() {
         // Single node tree
-        let root = create_tree(vec![Some(10)]);
+        let root = Tree::from(vec![Some(10)]).into_inner();
         let result = Solution::find_frequent_tree_sum(root);
         assert_eq!(result, vec![10]);
     }
@@ -145,7 +115,7 @@ mod tests {
         //      / \
         //     1   1
         // Subtree sums: 1 (left leaf), 1 (right leaf), 3 (root: 1+1+1)
-        let root = create_tree(vec![Some(1), Some(1), Some(1)]);
+        let root = Tree::from(vec![Some(1), Some(1), Some(1)]).into_inner();
         let result = Solution::find_frequent_tree_sum(root);
         assert_eq!(result, vec![1]);
     }
@@ -37,13 +37,13 @@ impl Solution {
     /// - Time: O(n) where n is the number of nodes
     /// - Space: O(w) where w is the maximum width of the tree
     pub fn find_bottom_left_value(root: Option<Rc<RefCell<TreeNode>>>) -> i32 {
-        let mut queue = VecDeque::from([root]);
+        let mut queue: VecDeque<_> = root.into_iter().collect();
         let mut result = 0;
-        while !queue.is_empty() {
-            result = queue.front().unwrap().as_ref().unwrap().borrow().val;
+        while let Some(front) = queue.front() {
+            result = front.borrow().val;
             (0..queue.len()).for_each(|_| {
                 let node = queue.pop_front().unwrap();
-                let inner = node.as_ref().unwrap().borrow();
+                let inner = node.borrow();
                 queue.extend(inner.left.clone());
                 queue.extend(inner.right.clone());
             });
@@ -55,36 +55,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::from([root.clone()]);
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -93,7 +64,7 @@ mod tests {
         //      / \
         //     1   3
         // Bottom left value is 1
-        let root = create_tree(vec![Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         assert_eq!(Solution::find_bottom_left_value(root), 1);
     }
 
@@ -108,7 +79,7 @@ mod tests {
         //         /
         //        7
         // Bottom left value is 7
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -121,14 +92,15 @@ mod tests {
             None,
             None,
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::find_bottom_left_value(root), 7);
     }
 
     #[test]
     fn test_case_3() {
         // Single node tree
-        let root = create_tree(vec![Some(5)]);
+        let root = Tree::from(vec![Some(5)]).into_inner();
         assert_eq!(Solution::find_bottom_left_value(root), 5);
     }
 
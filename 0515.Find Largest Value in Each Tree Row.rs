@@ -64,37 +64,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -105,7 +75,7 @@ mod tests {
         //     / \     \
         //    5   3     9
         // Level maximums: [1, 3, 9]
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(3),
             Some(2),
@@ -113,7 +83,8 @@ mod tests {
             Some(3),
             None,
             Some(9),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::largest_values(root), vec![1, 3, 9]);
     }
 
@@ -124,14 +95,14 @@ mod tests {
         //      / \
         //     2   3
         // Level maximums: [1, 3]
-        let root = create_tree(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::largest_values(root), vec![1, 3]);
     }
 
     #[test]
     fn test_case_3() {
         // Single node tree
-        let root = create_tree(vec![Some(42)]);
+        let root = Tree::from(vec![Some(42)]).into_inner();
         assert_eq!(Solution::largest_values(root), vec![42]);
     }
 
@@ -151,7 +122,7 @@ mod tests {
         //     / \    /  \
         //   -6  -2  -4  -10
         // Level maximums: [-1, -3, -2]
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(-1),
             Some(-5),
             Some(-3),
@@ -159,7 +130,8 @@ mod tests {
             Some(-2),
             Some(-4),
             Some(-10),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::largest_values(root), vec![-1, -3, -2]);
     }
 
@@ -1,147 +1,138 @@
-use rand::{thread_rng, Rng};
+use crate::random::{RandomSource, SeededRng};
+use crate::sampling::{PrefixSampler, WeightedSampler};
+use std::cell::RefCell;
 
 struct Solution {
-    prefix: Vec<i32>,
+    sampler: Box<dyn WeightedSampler>,
+    rng: RefCell<Box<dyn RandomSource>>,
 }
 
 impl Solution {
+    /// Weighted random index picker using prefix sums and binary search.
+    ///
+    /// # Intuition
+    /// Transform weights into cumulative distribution. Binary search locates
+    /// the index corresponding to a random value in the total weight range.
+    ///
+    /// # Approach
+    /// 1. Precompute prefix sums of weights.
+    /// 2. Draw a uniform point below the total weight from the injected
+    ///    random source, a fixed-seed generator unless one is supplied.
+    /// 3. Binary search for the first prefix sum exceeding the point.
+    ///
+    /// Any other `WeightedSampler` backend can stand in for the prefix sums,
+    /// such as an alias table for O(1) picks.
+    ///
+    /// # Complexity
+    /// - Time: O(n) for construction, O(log n) per pick
+    /// - Space: O(n)
     fn new(w: Vec<i32>) -> Self {
-        let mut prefix = Vec::with_capacity(w.len());
-        let mut sum = 0;
-        for &weight in &w {
-            sum += weight;
-            prefix.push(sum);
+        Self::with_rng(w, SeededRng::default())
+    }
+
+    fn with_rng(w: Vec<i32>, rng: impl RandomSource + 'static) -> Self {
+        let weights: Vec<f64> = w.iter().map(|&weight| f64::from(weight)).collect();
+        let sampler = PrefixSampler::new(&weights).expect("weights are positive");
+        Self::with_sampler(sampler, rng)
+    }
+
+    fn with_sampler(
+        sampler: impl WeightedSampler + 'static,
+        rng: impl RandomSource + 'static,
+    ) -> Self {
+        Self {
+            sampler: Box::new(sampler),
+            rng: RefCell::new(Box::new(rng)),
         }
-        Self { prefix }
     }
 
     fn pick_index(&self) -> i32 {
-        let total = *self.prefix.last().unwrap();
-        let target = thread_rng().gen_range(1..=total);
-        match self.prefix.binary_search(&target) {
-            Ok(i) => i as i32,
-            Err(i) => i as i32,
-        }
+        let mut rng = self.rng.borrow_mut();
+        self.sampler.sample(&mut *rng) as i32
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashMap;
-
-    // Test version with deterministic random number
-    struct TestSolution {
-        prefix: Vec<i32>,
-    }
-
-    impl TestSolution {
-        /// Weighted random index picker using prefix sums and binary search.
-        ///
-        /// # Intuition
-        /// Transform weights into cumulative distribution. Binary search locates
-        /// the index corresponding to a random value in the total weight range.
-        ///
-        /// # Approach
-        /// 1. Precompute prefix sums of weights.
-        /// 2. Generate random number in [1, total_weight].
-        /// 3. Binary search for the first prefix sum >= target.
-        ///
-        /// # Complexity
-        /// - Time: O(n) for construction, O(log n) per pick
-        /// - Space: O(n)
-        fn new(w: Vec<i32>) -> Self {
-            let mut prefix = Vec::with_capacity(w.len());
-            let mut sum = 0;
-            for &weight in &w {
-                sum += weight;
-                prefix.push(sum);
-            }
-            Self { prefix }
-        }
-
-        fn pick_index_with_target(&self, target: i32) -> i32 {
-            match self.prefix.binary_search(&target) {
-                Ok(i) => i as i32,
-                Err(i) => i as i32,
-            }
-        }
-
-        fn get_total(&self) -> i32 {
-            *self.prefix.last().unwrap()
+    use crate::random::{chi_square, chi_square_critical};
+    use crate::sampling::AliasTable;
+
+    /// Picks `draws` times and checks the counts against the weights.
+    fn assert_picks_in_proportion(solution: &Solution, weights: &[i32], draws: usize) {
+        let total: i32 = weights.iter().sum();
+        let mut counts = vec![0u64; weights.len()];
+        for _ in 0..draws {
+            counts[solution.pick_index() as usize] += 1;
         }
+        let expected: Vec<f64> = weights.iter().map(|&w| w as f64 / total as f64).collect();
+        let statistic = chi_square(&counts, &expected);
+        assert!(
+            statistic < chi_square_critical(weights.len() - 1),
+            "{weights:?}: {counts:?}"
+        );
     }
 
     #[test]
     fn test_single_weight() {
-        let solution = TestSolution::new(vec![1]);
-        assert_eq!(solution.pick_index_with_target(1), 0);
+        let solution = Solution::with_rng(vec![1], SeededRng::new(1));
+        assert!((0..100).all(|_| solution.pick_index() == 0));
     }
 
     #[test]
     fn test_equal_weights() {
-        let solution = TestSolution::new(vec![1, 1, 1]);
-        assert_eq!(solution.pick_index_with_target(1), 0);
-        assert_eq!(solution.pick_index_with_target(2), 1);
-        assert_eq!(solution.pick_index_with_target(3), 2);
+        let weights = vec![1, 1, 1];
+        let solution = Solution::with_rng(weights.clone(), SeededRng::new(2));
+        assert_picks_in_proportion(&solution, &weights, 30_000);
     }
 
     #[test]
     fn test_different_weights() {
-        let solution = TestSolution::new(vec![1, 3, 2]);
-        // Prefix: [1, 4, 6]
-        assert_eq!(solution.pick_index_with_target(1), 0); // Weight 1: target 1
-        assert_eq!(solution.pick_index_with_target(2), 1); // Weight 3: targets 2-4
-        assert_eq!(solution.pick_index_with_target(3), 1);
-        assert_eq!(solution.pick_index_with_target(4), 1);
-        assert_eq!(solution.pick_index_with_target(5), 2); // Weight 2: targets 5-6
-        assert_eq!(solution.pick_index_with_target(6), 2);
+        let weights = vec![1, 3, 2];
+        let solution = Solution::with_rng(weights.clone(), SeededRng::new(3));
+        assert_picks_in_proportion(&solution, &weights, 30_000);
     }
 
     #[test]
     fn test_large_weight_difference() {
-        let solution = TestSolution::new(vec![1, 99]);
-        // Prefix: [1, 100]
-        assert_eq!(solution.pick_index_with_target(1), 0);
-        assert_eq!(solution.pick_index_with_target(2), 1);
-        assert_eq!(solution.pick_index_with_target(50), 1);
-        assert_eq!(solution.pick_index_with_target(100), 1);
+        let weights = vec![1, 99];
+        let solution = Solution::with_rng(weights.clone(), SeededRng::new(4));
+        assert_picks_in_proportion(&solution, &weights, 50_000);
     }
 
     #[test]
     fn test_boundary_cases() {
-        let solution = TestSolution::new(vec![3, 14, 1, 7]);
-        // Prefix: [3, 17, 18, 25]
-        assert_eq!(solution.pick_index_with_target(1), 0);
-        assert_eq!(solution.pick_index_with_target(3), 0);
-        assert_eq!(solution.pick_index_with_target(4), 1);
-        assert_eq!(solution.pick_index_with_target(17), 1);
-        assert_eq!(solution.pick_index_with_target(18), 2);
-        assert_eq!(solution.pick_index_with_target(19), 3);
-        assert_eq!(solution.pick_index_with_target(25), 3);
+        let weights = vec![3, 14, 1, 7];
+        let solution = Solution::with_rng(weights.clone(), SeededRng::new(5));
+        assert_picks_in_proportion(&solution, &weights, 50_000);
     }
 
     #[test]
     fn test_distribution() {
-        // Test that the actual implementation produces reasonable distribution
-        let solution = Solution::new(vec![1, 3]);
-        let mut counts = HashMap::new();
-
-        for _ in 0..1000 {
-            let idx = solution.pick_index();
-            *counts.entry(idx).or_insert(0) += 1;
-        }
-
-        // With weights [1, 3], we expect roughly 25% index 0, 75% index 1
-        // Allow for statistical variation
-        assert!(counts.contains_key(&0));
-        assert!(counts.contains_key(&1));
+        // the default generator is seeded too
+        let weights = vec![1, 3];
+        assert_picks_in_proportion(&Solution::new(weights.clone()), &weights, 10_000);
+        let first: Vec<i32> = {
+            let solution = Solution::new(weights.clone());
+            (0..20).map(|_| solution.pick_index()).collect()
+        };
+        let solution = Solution::new(weights);
+        assert!(first.iter().all(|&i| solution.pick_index() == i));
+    }
 
-        let count_0 = counts[&0];
-        let count_1 = counts[&1];
+    #[test]
+    fn test_picks_in_proportion_to_weight() {
+        let weights = vec![1, 5, 10, 3, 1, 30];
+        let solution = Solution::with_rng(weights.clone(), SeededRng::new(528));
+        assert_picks_in_proportion(&solution, &weights, 50_000);
+    }
 
-        // Very loose bounds to avoid flaky tests
-        assert!(count_0 > 100 && count_0 < 400, "Index 0 count: {}", count_0);
-        assert!(count_1 > 600 && count_1 < 900, "Index 1 count: {}", count_1);
+    #[test]
+    fn test_alias_backend_picks_in_proportion() {
+        let weights = vec![2, 7, 1, 10];
+        let table: Vec<f64> = weights.iter().map(|&w| f64::from(w)).collect();
+        let table = AliasTable::new(&table).unwrap();
+        let solution = Solution::with_sampler(table, SeededRng::new(1));
+        assert_picks_in_proportion(&solution, &weights, 50_000);
     }
 }
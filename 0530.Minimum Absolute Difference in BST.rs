@@ -57,37 +57,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -98,7 +68,7 @@ mod tests {
         //    / \
         //   1   3
         // In-order: 1, 2, 3, 4, 6 -> min diff = 1
-        let root = create_tree(vec![Some(4), Some(2), Some(6), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(4), Some(2), Some(6), Some(1), Some(3)]).into_inner();
         assert_eq!(Solution::get_minimum_difference(root), 1);
     }
 
@@ -111,7 +81,7 @@ mod tests {
         //          /  \
         //         12  49
         // In-order: 0, 1, 12, 48, 49 -> min diff = 1
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(0),
             Some(48),
@@ -119,7 +89,8 @@ mod tests {
             None,
             Some(12),
             Some(49),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::get_minimum_difference(root), 1);
     }
 
@@ -130,7 +101,7 @@ mod tests {
         //    /
         //   1
         // In-order: 1, 2 -> min diff = 1
-        let root = create_tree(vec![Some(2), Some(1)]);
+        let root = Tree::from(vec![Some(2), Some(1)]).into_inner();
         assert_eq!(Solution::get_minimum_difference(root), 1);
     }
 
@@ -143,7 +114,7 @@ mod tests {
         //       \     \
         //       227   911
         // In-order: 104, 227, 236, 701, 911 -> min diff = 9 (236-227)
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(236),
             Some(104),
             Some(701),
@@ -151,7 +122,8 @@ mod tests {
             Some(227),
             None,
             Some(911),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::get_minimum_difference(root), 9);
     }
 
@@ -56,41 +56,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_diameter_balanced_tree() {
@@ -101,7 +67,7 @@ mod tests {
         //    / \
         //   4   5
         // Diameter: 3 (path: 4->2->1->3 or 5->2->1->3)
-        let root = build_tree(&[Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]).into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 3);
     }
 
@@ -112,7 +78,7 @@ mod tests {
         //      /
         //     2
         // Diameter: 1 (path: 2->1)
-        let root = build_tree(&[Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(2)]).into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 1);
     }
 
@@ -120,7 +86,7 @@ mod tests {
     fn test_diameter_single_node() {
         // Tree: [1]
         // Diameter: 0 (no path with two nodes)
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 0);
     }
 
@@ -128,7 +94,7 @@ mod tests {
     fn test_diameter_empty_tree() {
         // Empty tree
         // Diameter: 0
-        let root = build_tree(&[]);
+        let root = Tree::from(vec![]).into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 0);
     }
 
@@ -143,7 +109,7 @@ mod tests {
         // The maximum diameter is the longest path, which is 3 edges (4->2->1->3 or 5->2->1->3)
         // Note: This tree has depth 2 on left (through node 2) and depth 1 on right (node 3)
         // The diameter through root 1 is 2 + 1 = 3
-        let root = build_tree(&[Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]).into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 3);
     }
 
@@ -158,7 +124,16 @@ mod tests {
         //  / \
         // 5   6
         // Diameter: 3 (path: 5->3->2->4)
-        let root = build_tree(&[Some(1), Some(2), None, Some(3), Some(4), Some(5), Some(6)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+        ])
+        .into_inner();
         assert_eq!(Solution::diameter_of_binary_tree(root), 3);
     }
 
@@ -66,37 +66,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -111,8 +81,8 @@ mod tests {
         //     / \
         //    1   2
         // Result: true
-        let root = create_tree(vec![Some(3), Some(4), Some(5), Some(1), Some(2)]);
-        let sub_root = create_tree(vec![Some(4), Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(3), Some(4), Some(5), Some(1), Some(2)]).into_inner();
+        let sub_root = Tree::from(vec![Some(4), Some(1), Some(2)]).into_inner();
         assert!(Solution::is_subtree(root, sub_root));
     }
 
@@ -131,7 +101,7 @@ mod tests {
         //     / \
         //    1   2
         // Result: false (root's subtree has extra node 0)
-        let root = create_tree(vec![
+        let root = Tree::from(vec![
             Some(3),
             Some(4),
             Some(5),
@@ -142,23 +112,24 @@ mod tests {
             None,
             None,
             Some(0),
-        ]);
-        let sub_root = create_tree(vec![Some(4), Some(1), Some(2)]);
+        ])
+        .into_inner();
+        let sub_root = Tree::from(vec![Some(4), Some(1), Some(2)]).into_inner();
         assert!(!Solution::is_subtree(root, sub_root));
     }
 
     #[test]
     fn test_case_3() {
         // Both trees are identical single nodes
-        let root = create_tree(vec![Some(1)]);
-        let sub_root = create_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
+        let sub_root = Tree::from(vec![Some(1)]).into_inner();
         assert!(Solution::is_subtree(root, sub_root));
     }
 
     #[test]
     fn test_case_4() {
         // Empty subtree (should return false)
-        let root = create_tree(vec![Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(2)]).into_inner();
         let sub_root = None;
         assert!(!Solution::is_subtree(root, sub_root));
     }
@@ -166,8 +137,8 @@ mod tests {
     #[test]
     fn test_case_5() {
         // Subtree is larger than main tree
-        let root = create_tree(vec![Some(1)]);
-        let sub_root = create_tree(vec![Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
+        let sub_root = Tree::from(vec![Some(1), Some(2)]).into_inner();
         assert!(!Solution::is_subtree(root, sub_root));
     }
 
@@ -48,7 +48,7 @@ mod tests {
 
     #[test]
     fn test_root() {
-        let mut result = Solution::kill_process(vec![1], vec![0], 1);
+        let result = Solution::kill_process(vec![1], vec![0], 1);
         assert_eq!(result, vec![1]);
     }
 
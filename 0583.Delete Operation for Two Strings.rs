@@ -93,11 +93,11 @@ mod tests {
 
     #[test]
     fn test_leetcode_etco() {
-        // LCS of "leetcode" and "etco" is "etc" (length 3)
-        // Delete: 8 - 3 + 4 - 3 = 6
+        // "etco" is itself a subsequence of "leetcode" (LCS length 4)
+        // Delete: 8 - 4 + 4 - 4 = 4
         assert_eq!(
             Solution::min_distance("leetcode".to_string(), "etco".to_string()),
-            6
+            4
         );
     }
 }
@@ -19,7 +19,7 @@ impl Solution {
     pub fn is_valid(code: String) -> bool {
         fn check_tag(tag: &str) -> bool {
             let n = tag.len();
-            n >= 1 && n <= 9 && tag.bytes().all(|b| b.is_ascii_uppercase())
+            (1..=9).contains(&n) && tag.bytes().all(|b| b.is_ascii_uppercase())
         }
 
         let mut stack: Vec<&str> = Vec::with_capacity(code.len());
@@ -78,8 +78,8 @@ mod tests {
     }
 
     #[test]
-    fn test_invalid_cdata() {
-        assert!(!Solution::is_valid(
+    fn test_cdata_hides_unmatched_brackets() {
+        assert!(Solution::is_valid(
             "<DIV>>>  ![cdata[]] <![CDATA[<div>]>]]>]]>>]</DIV>".to_string()
         ));
     }
@@ -65,37 +65,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_case_1() {
@@ -106,7 +76,7 @@ mod tests {
         //    /
         //   4
         // Expected: "1(2(4))(3)"
-        let root = create_tree(vec![Some(1), Some(2), Some(3), Some(4)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4)]).into_inner();
         assert_eq!(Solution::tree2str(root), "1(2(4))(3)");
     }
 
@@ -119,14 +89,14 @@ mod tests {
         //      \
         //       4
         // Expected: "1(2()(4))(3)"
-        let root = create_tree(vec![Some(1), Some(2), Some(3), None, Some(4)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), None, Some(4)]).into_inner();
         assert_eq!(Solution::tree2str(root), "1(2()(4))(3)");
     }
 
     #[test]
     fn test_case_3() {
         // Single node tree
-        let root = create_tree(vec![Some(5)]);
+        let root = Tree::from(vec![Some(5)]).into_inner();
         assert_eq!(Solution::tree2str(root), "5");
     }
 
@@ -143,7 +113,7 @@ mod tests {
         //    1
         //   /
         //  2
-        let root = create_tree(vec![Some(1), Some(2), None]);
+        let root = Tree::from(vec![Some(1), Some(2), None]).into_inner();
         assert_eq!(Solution::tree2str(root), "1(2)");
     }
 
@@ -153,7 +123,7 @@ mod tests {
         //    1
         //     \
         //      3
-        let root = create_tree(vec![Some(1), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(3)]).into_inner();
         assert_eq!(Solution::tree2str(root), "1()(3)");
     }
 
@@ -163,7 +133,7 @@ mod tests {
         //      -1
         //     /  \
         //   -2    -3
-        let root = create_tree(vec![Some(-1), Some(-2), Some(-3)]);
+        let root = Tree::from(vec![Some(-1), Some(-2), Some(-3)]).into_inner();
         assert_eq!(Solution::tree2str(root), "-1(-2)(-3)");
     }
 }
@@ -58,70 +58,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::new();
-        if root.is_none() {
-            return result;
-        }
-
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                let n = n.borrow();
-                result.push(Some(n.val));
-                queue.push_back(n.left.clone());
-                queue.push_back(n.right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_merge_trees_full_overlap() {
@@ -143,11 +80,32 @@ mod tests {
         //      4   5
         //     / \   \
         //    5   4   7
-        let root1 = build_tree(&[Some(1), Some(3), Some(2), Some(5)]);
-        let root2 = build_tree(&[Some(2), Some(1), Some(3), None, Some(4), None, Some(7)]);
+        let root1 = Tree::from(vec![Some(1), Some(3), Some(2), Some(5)]).into_inner();
+        let root2 = Tree::from(vec![
+            Some(2),
+            Some(1),
+            Some(3),
+            None,
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
         let merged = Solution::merge_trees(root1, root2);
-        let expected = build_tree(&[Some(3), Some(4), Some(5), Some(5), Some(4), None, Some(7)]);
-        assert_eq!(tree_to_vec(&merged), tree_to_vec(&expected));
+        let expected = Tree::from(vec![
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(5),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
+        assert_eq!(
+            Tree::from(merged.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
@@ -155,11 +113,14 @@ mod tests {
         // Tree1: [1]
         // Tree2: []
         // Merged: [1]
-        let root1 = build_tree(&[Some(1)]);
-        let root2 = build_tree(&[]);
+        let root1 = Tree::from(vec![Some(1)]).into_inner();
+        let root2 = Tree::from(vec![]).into_inner();
         let merged = Solution::merge_trees(root1, root2);
-        let expected = build_tree(&[Some(1)]);
-        assert_eq!(tree_to_vec(&merged), tree_to_vec(&expected));
+        let expected = Tree::from(vec![Some(1)]).into_inner();
+        assert_eq!(
+            Tree::from(merged.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
@@ -167,8 +128,8 @@ mod tests {
         // Tree1: []
         // Tree2: []
         // Merged: []
-        let root1 = build_tree(&[]);
-        let root2 = build_tree(&[]);
+        let root1 = Tree::from(vec![]).into_inner();
+        let root2 = Tree::from(vec![]).into_inner();
         let merged = Solution::merge_trees(root1, root2);
         assert!(merged.is_none());
     }
@@ -193,11 +154,23 @@ mod tests {
         //      2   2
         //     /     \
         //    3       3
-        let root1 = build_tree(&[Some(1), Some(2), None, Some(3)]);
-        let root2 = build_tree(&[Some(1), None, Some(2), None, Some(3)]);
+        let root1 = Tree::from(vec![Some(1), Some(2), None, Some(3)]).into_inner();
+        let root2 = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         let merged = Solution::merge_trees(root1, root2);
-        let expected = build_tree(&[Some(2), Some(2), Some(2), Some(3), None, None, Some(3)]);
-        assert_eq!(tree_to_vec(&merged), tree_to_vec(&expected));
+        let expected = Tree::from(vec![
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(3),
+            None,
+            None,
+            Some(3),
+        ])
+        .into_inner();
+        assert_eq!(
+            Tree::from(merged.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 
     #[test]
@@ -205,10 +178,13 @@ mod tests {
         // Tree1: [5]
         // Tree2: [10]
         // Merged: [15]
-        let root1 = build_tree(&[Some(5)]);
-        let root2 = build_tree(&[Some(10)]);
+        let root1 = Tree::from(vec![Some(5)]).into_inner();
+        let root2 = Tree::from(vec![Some(10)]).into_inner();
         let merged = Solution::merge_trees(root1, root2);
-        let expected = build_tree(&[Some(15)]);
-        assert_eq!(tree_to_vec(&merged), tree_to_vec(&expected));
+        let expected = Tree::from(vec![Some(15)]).into_inner();
+        assert_eq!(
+            Tree::from(merged.clone()).to_vec(),
+            Tree::from(expected.clone()).to_vec()
+        );
     }
 }
@@ -66,41 +66,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -110,7 +76,16 @@ mod tests {
         //     9   20
         //        /  \
         //       15   7
-        let root = create_tree(&[Some(3), Some(9), Some(20), None, None, Some(15), Some(7)]);
+        let root = Tree::from(vec![
+            Some(3),
+            Some(9),
+            Some(20),
+            None,
+            None,
+            Some(15),
+            Some(7),
+        ])
+        .into_inner();
         let result = Solution::average_of_levels(root);
         assert_eq!(result, vec![3.0, 14.5, 11.0]);
     }
@@ -123,7 +98,7 @@ mod tests {
         //     9   20
         //    / \
         //   15  7
-        let root = create_tree(&[Some(3), Some(9), Some(20), Some(15), Some(7)]);
+        let root = Tree::from(vec![Some(3), Some(9), Some(20), Some(15), Some(7)]).into_inner();
         let result = Solution::average_of_levels(root);
         assert_eq!(result, vec![3.0, 14.5, 11.0]);
     }
@@ -131,7 +106,7 @@ mod tests {
     #[test]
     fn test_single_node() {
         // Tree: [1]
-        let root = create_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::average_of_levels(root);
         assert_eq!(result, vec![1.0]);
     }
@@ -140,7 +115,8 @@ mod tests {
     fn test_large_values() {
         // Test with large values to verify i64 sum handling
         // Tree: [2147483647,2147483647,2147483647]
-        let root = create_tree(&[Some(2147483647), Some(2147483647), Some(2147483647)]);
+        let root =
+            Tree::from(vec![Some(2147483647), Some(2147483647), Some(2147483647)]).into_inner();
         let result = Solution::average_of_levels(root);
         assert_eq!(result, vec![2147483647.0, 2147483647.0]);
     }
@@ -153,7 +129,7 @@ mod tests {
         //     -2    -3
         //     / \
         //   -4  -5
-        let root = create_tree(&[Some(-1), Some(-2), Some(-3), Some(-4), Some(-5)]);
+        let root = Tree::from(vec![Some(-1), Some(-2), Some(-3), Some(-4), Some(-5)]).into_inner();
         let result = Solution::average_of_levels(root);
         assert_eq!(result, vec![-1.0, -2.5, -4.5]);
     }
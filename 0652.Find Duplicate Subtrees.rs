@@ -74,42 +74,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     fn create_tree_manual() -> Option<Rc<RefCell<TreeNode>>> {
         // Manually create a tree with duplicate [2,4] subtrees
@@ -145,7 +110,7 @@ mod tests {
         //   4   2   4
         //      /
         //     4
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -158,12 +123,13 @@ mod tests {
             None,
             None,
             Some(4),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // The actual LeetCode example would find [4] and [2,4] as duplicates
         // But based on the tree structure, only [4] appears multiple times
-        assert!(result.len() >= 1);
+        assert!(!result.is_empty());
     }
 
     #[test]
@@ -187,7 +153,7 @@ mod tests {
         //       2
         //      / \
         //     1   1
-        let root = create_tree(&[Some(2), Some(1), Some(1)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(1)]).into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // Should find one duplicate subtree [1]
@@ -203,7 +169,16 @@ mod tests {
         //     2   2
         //    /   /
         //   3   3
-        let root = create_tree(&[Some(2), Some(2), Some(2), Some(3), None, Some(3), None]);
+        let root = Tree::from(vec![
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(3),
+            None,
+            Some(3),
+            None,
+        ])
+        .into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // Should find duplicate subtrees [3] and [2,3]
@@ -218,7 +193,7 @@ mod tests {
         //     2   3
         //    / \ / \
         //   4  5 6  7
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -226,7 +201,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // No duplicates should be found
@@ -236,7 +212,7 @@ mod tests {
     #[test]
     fn test_single_node() {
         // Tree: [1]
-        let root = create_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // No duplicates in a single node tree
@@ -252,10 +228,19 @@ mod tests {
         //     1   1
         //    /     \
         //   1       1
-        let root = create_tree(&[Some(1), Some(1), Some(1), Some(1), None, None, Some(1)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+            None,
+            Some(1),
+        ])
+        .into_inner();
         let result = Solution::find_duplicate_subtrees(root);
 
         // Should find some duplicate leaf nodes with value 1
-        assert!(result.len() >= 1);
+        assert!(!result.is_empty());
     }
 }
@@ -58,41 +58,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -102,8 +68,17 @@ mod tests {
         //     3   6
         //    / \   \
         //   2   4   7
-        let root = create_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
-        assert_eq!(Solution::find_target(root, 9), true);
+        let root = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
+        assert!(Solution::find_target(root, 9));
     }
 
     #[test]
@@ -114,15 +89,24 @@ mod tests {
         //     3   6
         //    / \   \
         //   2   4   7
-        let root = create_tree(&[Some(5), Some(3), Some(6), Some(2), Some(4), None, Some(7)]);
-        assert_eq!(Solution::find_target(root, 28), false);
+        let root = Tree::from(vec![
+            Some(5),
+            Some(3),
+            Some(6),
+            Some(2),
+            Some(4),
+            None,
+            Some(7),
+        ])
+        .into_inner();
+        assert!(!Solution::find_target(root, 28));
     }
 
     #[test]
     fn test_single_node() {
         // Tree: [1], k = 2
-        let root = create_tree(&[Some(1)]);
-        assert_eq!(Solution::find_target(root, 2), false);
+        let root = Tree::from(vec![Some(1)]).into_inner();
+        assert!(!Solution::find_target(root, 2));
     }
 
     #[test]
@@ -131,8 +115,8 @@ mod tests {
         //       2
         //      / \
         //     1   3
-        let root = create_tree(&[Some(2), Some(1), Some(3)]);
-        assert_eq!(Solution::find_target(root, 4), true); // 1 + 3 = 4
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
+        assert!(Solution::find_target(root, 4)); // 1 + 3 = 4
     }
 
     #[test]
@@ -143,7 +127,7 @@ mod tests {
         //    -2   2
         //    / \   \
         //  -4  -1   3
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(0),
             Some(-2),
             Some(2),
@@ -151,8 +135,9 @@ mod tests {
             Some(-1),
             None,
             Some(3),
-        ]);
-        assert_eq!(Solution::find_target(root, -1), true); // -4 + 3 = -1
+        ])
+        .into_inner();
+        assert!(Solution::find_target(root, -1)); // -4 + 3 = -1
     }
 
     #[test]
@@ -163,7 +148,7 @@ mod tests {
         //     1   3
         //      \
         //       1
-        let root = create_tree(&[Some(2), Some(1), Some(3), None, Some(1)]);
-        assert_eq!(Solution::find_target(root, 2), true); // 1 + 1 = 2
+        let root = Tree::from(vec![Some(2), Some(1), Some(3), None, Some(1)]).into_inner();
+        assert!(Solution::find_target(root, 2)); // 1 + 1 = 2
     }
 }
@@ -54,36 +54,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_to_vec(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        let mut result = Vec::new();
-        if root.is_none() {
-            return result;
-        }
-
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                let n = n.borrow();
-                result.push(Some(n.val));
-                queue.push_back(n.left.clone());
-                queue.push_back(n.right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -98,7 +69,7 @@ mod tests {
         //          1
         let nums = vec![3, 2, 1, 6, 0, 5];
         let result = Solution::construct_maximum_binary_tree(nums);
-        let output = tree_to_vec(&result);
+        let output = Tree::from(result.clone()).to_vec();
         assert_eq!(
             output,
             vec![
@@ -126,7 +97,7 @@ mod tests {
         //             1
         let nums = vec![3, 2, 1];
         let result = Solution::construct_maximum_binary_tree(nums);
-        let output = tree_to_vec(&result);
+        let output = Tree::from(result.clone()).to_vec();
         assert_eq!(output, vec![Some(3), None, Some(2), None, Some(1)]);
     }
 
@@ -144,7 +115,7 @@ mod tests {
         // Sorted ascending creates a right-skewed tree
         let nums = vec![1, 2, 3, 4, 5];
         let result = Solution::construct_maximum_binary_tree(nums);
-        let output = tree_to_vec(&result);
+        let output = Tree::from(result.clone()).to_vec();
         assert_eq!(
             output,
             vec![
@@ -165,7 +136,7 @@ mod tests {
         // Sorted descending creates a left-skewed tree
         let nums = vec![5, 4, 3, 2, 1];
         let result = Solution::construct_maximum_binary_tree(nums);
-        let output = tree_to_vec(&result);
+        let output = Tree::from(result.clone()).to_vec();
         assert_eq!(
             output,
             vec![
@@ -206,7 +177,7 @@ mod tests {
     fn test_max_at_beginning() {
         let nums = vec![9, 1, 2, 3];
         let result = Solution::construct_maximum_binary_tree(nums);
-        let output = tree_to_vec(&result);
+        let output = Tree::from(result.clone()).to_vec();
         assert_eq!(output, vec![Some(9), None, Some(3), Some(2), None, Some(1)]);
     }
 }
@@ -65,6 +65,9 @@ impl Solution {
         }
 
         let h = height(&root);
+        if h == 0 {
+            return vec![];
+        }
         let rows = h as usize;
         let cols = (1usize << h) - 1;
         let mut grid = vec![vec![String::new(); cols]; rows];
@@ -76,42 +79,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -122,7 +90,7 @@ mod tests {
         // Expected:
         // [["", "1", ""],
         //  ["2", "", ""]]
-        let root = create_tree(&[Some(1), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(2)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(
             result,
@@ -142,7 +110,7 @@ mod tests {
         //      \
         //       4
         // Expected: 7 columns wide, 3 rows
-        let root = create_tree(&[Some(1), Some(2), Some(3), None, Some(4)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), None, Some(4)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(result.len(), 3);
         assert_eq!(result[0].len(), 7);
@@ -154,7 +122,7 @@ mod tests {
 
     #[test]
     fn test_single_node() {
-        let root = create_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(result, vec![vec!["1".to_string()]]);
     }
@@ -165,7 +133,7 @@ mod tests {
         //       1
         //      / \
         //     2   3
-        let root = create_tree(&[Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(
             result,
@@ -184,7 +152,7 @@ mod tests {
         //         2
         //          \
         //           3
-        let root = create_tree(&[Some(1), None, Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(result.len(), 3);
         assert_eq!(result[0].len(), 7);
@@ -199,7 +167,7 @@ mod tests {
     #[test]
     fn test_negative_values() {
         // Tree: [-1,-2,-3]
-        let root = create_tree(&[Some(-1), Some(-2), Some(-3)]);
+        let root = Tree::from(vec![Some(-1), Some(-2), Some(-3)]).into_inner();
         let result = Solution::print_tree(root);
         assert_eq!(result[0][1], "-1");
         assert_eq!(result[1][0], "-2");
@@ -210,6 +178,6 @@ mod tests {
     fn test_empty_tree() {
         let root: Option<Rc<RefCell<TreeNode>>> = None;
         let result = Solution::print_tree(root);
-        assert!(result.is_empty() || (result.len() == 0));
+        assert!(result.is_empty() || (result.is_empty()));
     }
 }
@@ -65,71 +65,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_array(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        if root.is_none() {
-            return vec![];
-        }
-
-        let mut result = Vec::new();
-        let mut queue = VecDeque::new();
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(rc) = node {
-                let inner = rc.borrow();
-                result.push(Some(inner.val));
-                queue.push_back(inner.left.clone());
-                queue.push_back(inner.right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -138,9 +74,9 @@ mod tests {
         //      / \
         //     0   2
         // Expected: [1,null,2]
-        let root = create_tree(&[Some(1), Some(0), Some(2)]);
+        let root = Tree::from(vec![Some(1), Some(0), Some(2)]).into_inner();
         let result = Solution::trim_bst(root, 1, 2);
-        let output = tree_to_array(result);
+        let output = Tree::from(result).to_vec();
         assert_eq!(output, vec![Some(1), None, Some(2)]);
     }
 
@@ -155,7 +91,7 @@ mod tests {
         //      /
         //     1
         // Expected: [3,2,null,1]
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(3),
             Some(0),
             Some(4),
@@ -164,7 +100,8 @@ mod tests {
             None,
             None,
             Some(1),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::trim_bst(root, 1, 3);
 
         // Verify the root is 3
@@ -187,7 +124,7 @@ mod tests {
     fn test_all_nodes_removed() {
         // Tree: [1], low = 2, high = 3
         // All nodes should be removed
-        let root = create_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::trim_bst(root, 2, 3);
         assert!(result.is_none());
     }
@@ -199,9 +136,9 @@ mod tests {
         //      / \
         //     1   3
         // No trimming needed
-        let root = create_tree(&[Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         let result = Solution::trim_bst(root, 1, 3);
-        let output = tree_to_array(result);
+        let output = Tree::from(result).to_vec();
         assert_eq!(output, vec![Some(2), Some(1), Some(3)]);
     }
 
@@ -214,7 +151,7 @@ mod tests {
         //    / \ / \
         //   1  4 6  8
         // Expected: [7,6,8]
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(5),
             Some(3),
             Some(7),
@@ -222,7 +159,8 @@ mod tests {
             Some(4),
             Some(6),
             Some(8),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::trim_bst(root, 6, 8);
 
         // Verify root is 7
@@ -1,52 +1,24 @@
-struct Trie {
-    children: [Option<Box<Trie>>; 26],
-    is_end: bool,
-}
-
-impl Trie {
-    fn new() -> Self {
-        Self {
-            children: Default::default(),
-            is_end: false,
-        }
-    }
-
-    fn insert(&mut self, word: &str) {
-        let mut node = self;
-        for &b in word.as_bytes() {
-            let idx = (b - b'a') as usize;
-            node = node.children[idx].get_or_insert_with(|| Box::new(Trie::new()));
-        }
-        node.is_end = true;
-    }
-
-    fn search_with_diff(&self, word: &[u8], diff: i32) -> bool {
-        if word.is_empty() {
-            return diff == 1 && self.is_end;
-        }
-        let idx = (word[0] - b'a') as usize;
-        if let Some(child) = &self.children[idx] {
-            if child.search_with_diff(&word[1..], diff) {
-                return true;
-            }
-        }
-        if diff == 0 {
-            for (i, child) in self.children.iter().enumerate() {
-                if i != idx {
-                    if let Some(c) = child {
-                        if c.search_with_diff(&word[1..], 1) {
-                            return true;
-                        }
-                    }
-                }
-            }
-        }
-        false
+use crate::trie::{self, Lowercase, Trie};
+
+/// Whether a word below `node` matches `word` with exactly one substitution
+/// overall, given the `diff` substitutions already made above `node`.
+fn search_with_diff(trie: &Trie<Lowercase>, node: usize, word: &[u8], diff: i32) -> bool {
+    let Some((&first, rest)) = word.split_first() else {
+        return diff == 1 && trie.ends(node) > 0;
+    };
+    if let Some(child) = trie.child(node, first)
+        && search_with_diff(trie, child, rest, diff)
+    {
+        return true;
     }
+    diff == 0
+        && trie
+            .children(node)
+            .any(|(symbol, child)| symbol != first && search_with_diff(trie, child, rest, 1))
 }
 
 struct MagicDictionary {
-    trie: Trie,
+    trie: Trie<Lowercase>,
 }
 
 impl MagicDictionary {
@@ -57,7 +29,7 @@ impl MagicDictionary {
     /// character from the query.
     ///
     /// # Approach
-    /// Build a trie from the dictionary words. During search, recursively explore
+    /// Build a `Trie<Lowercase>` from the dictionary words. During search, recursively explore
     /// the trie tracking the number of character differences. Accept paths with
     /// exactly one difference.
     ///
@@ -71,12 +43,12 @@ impl MagicDictionary {
 
     fn build_dict(&mut self, dictionary: Vec<String>) {
         for word in &dictionary {
-            self.trie.insert(word);
+            self.trie.insert(word.bytes());
         }
     }
 
     fn search(&self, search_word: String) -> bool {
-        self.trie.search_with_diff(search_word.as_bytes(), 0)
+        search_with_diff(&self.trie, trie::ROOT, search_word.as_bytes(), 0)
     }
 }
 
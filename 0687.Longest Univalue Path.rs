@@ -65,42 +65,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn create_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(left.clone());
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(right.clone());
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -111,7 +76,16 @@ mod tests {
         //    / \   \
         //   1   1   5
         // Path: 5 -> 5 -> 5 (length = 2)
-        let root = create_tree(&[Some(5), Some(4), Some(5), Some(1), Some(1), None, Some(5)]);
+        let root = Tree::from(vec![
+            Some(5),
+            Some(4),
+            Some(5),
+            Some(1),
+            Some(1),
+            None,
+            Some(5),
+        ])
+        .into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 2);
     }
 
@@ -124,7 +98,16 @@ mod tests {
         //    / \   \
         //   4   4   5
         // Path: 4 -> 4 -> 4 (length = 2)
-        let root = create_tree(&[Some(1), Some(4), Some(5), Some(4), Some(4), None, Some(5)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(4),
+            Some(5),
+            Some(4),
+            Some(4),
+            None,
+            Some(5),
+        ])
+        .into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 2);
     }
 
@@ -132,7 +115,7 @@ mod tests {
     fn test_single_node() {
         // Tree: [1]
         // No path with more than one node
-        let root = create_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 0);
     }
 
@@ -145,7 +128,7 @@ mod tests {
         //    / \ / \
         //   1  1 1  1
         // Path goes through root: 1 -> 1 -> 1 -> 1 -> 1 (length = 4)
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(1),
             Some(1),
@@ -153,7 +136,8 @@ mod tests {
             Some(1),
             Some(1),
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 4);
     }
 
@@ -166,7 +150,7 @@ mod tests {
         //    / \ / \
         //   4  5 6  7
         // All values different, no path
-        let root = create_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -174,7 +158,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 0);
     }
 
@@ -187,7 +172,8 @@ mod tests {
         //    / \ /
         //   2  2 2
         // Best path is in left subtree: 2 -> 2 -> 2 (length = 2)
-        let root = create_tree(&[Some(1), Some(2), Some(2), Some(2), Some(2), Some(2)]);
+        let root =
+            Tree::from(vec![Some(1), Some(2), Some(2), Some(2), Some(2), Some(2)]).into_inner();
         assert_eq!(Solution::longest_univalue_path(root), 2);
     }
 }
@@ -1,9 +1,3 @@
-#[derive(Default)]
-struct ListNode {
-    val: i32,
-    next: Option<Box<ListNode>>,
-}
-
 #[derive(Default)]
 struct MyLinkedList {
     head: Option<Box<ListNode>>,
@@ -26,7 +26,7 @@ impl Solution {
                 product /= nums[left];
                 left += 1;
             }
-            count += (right - left + 1) as i32;
+            count += (right + 1 - left) as i32;
         }
         count
     }
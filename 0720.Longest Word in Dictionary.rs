@@ -1,37 +1,4 @@
-struct Trie {
-    children: [Option<Box<Trie>>; 26],
-    is_end: bool,
-}
-
-impl Trie {
-    fn new() -> Self {
-        Self {
-            children: Default::default(),
-            is_end: false,
-        }
-    }
-
-    fn insert(&mut self, word: &str) {
-        let mut node = self;
-        for b in word.bytes() {
-            let idx = (b - b'a') as usize;
-            node = node.children[idx].get_or_insert_with(|| Box::new(Trie::new()));
-        }
-        node.is_end = true;
-    }
-
-    fn has_all_prefixes(&self, word: &str) -> bool {
-        let mut node = self;
-        for b in word.bytes() {
-            let idx = (b - b'a') as usize;
-            match node.children[idx].as_ref() {
-                Some(child) if child.is_end => node = child,
-                _ => return false,
-            }
-        }
-        true
-    }
-}
+use crate::trie::{Lowercase, Trie};
 
 impl Solution {
     /// Finds the longest word buildable one character at a time using a trie.
@@ -49,14 +16,19 @@ impl Solution {
     /// - Time: O(n * L) where n is word count and L is max word length
     /// - Space: O(n * L) for the trie
     pub fn longest_word(words: Vec<String>) -> String {
-        let mut trie = Trie::new();
+        let mut trie: Trie<Lowercase> = Trie::new();
         for w in &words {
-            trie.insert(w);
+            trie.insert(w.bytes());
         }
 
         words
             .into_iter()
-            .filter(|w| trie.has_all_prefixes(w))
+            .filter(|w| {
+                trie.path(w.bytes())
+                    .take_while(|&node| trie.ends(node) > 0)
+                    .count()
+                    == w.len()
+            })
             .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
             .unwrap_or_default()
     }
@@ -1,38 +1,36 @@
-use std::collections::BTreeMap;
+use crate::interval_set::IntervalSet;
 
 struct MyCalendar {
-    intervals: BTreeMap<i32, i32>,
+    booked: IntervalSet<i32>,
 }
 
 impl MyCalendar {
-    /// Calendar that prevents double-booking using a balanced BST.
+    /// Calendar that prevents double-booking with an interval set.
     ///
     /// # Intuition
-    /// Store intervals keyed by end time. For a new booking, check if any
-    /// existing interval overlaps by querying the tree for the nearest
-    /// entry after the start time.
+    /// A booking is allowed exactly when `[start, end)` shares no time with
+    /// what is already booked, which an interval set answers by looking at
+    /// the single stored interval that could reach into it.
     ///
     /// # Approach
-    /// Use a `BTreeMap<end, start>`. For a new `[start, end)`, look at the
-    /// first entry with key > start. If that entry's value (start of existing
-    /// interval) is less than the new end, there is an overlap.
+    /// Keep the booked time in an `IntervalSet`. For a new `[start, end)`,
+    /// reject it if it overlaps the set; otherwise insert it, letting back to
+    /// back bookings coalesce.
     ///
     /// # Complexity
     /// - Time: O(log n) per booking
     /// - Space: O(n) for stored intervals
     fn new() -> Self {
         Self {
-            intervals: BTreeMap::new(),
+            booked: IntervalSet::new(),
         }
     }
 
     fn book(&mut self, start_time: i32, end_time: i32) -> bool {
-        if let Some((&_end, &existing_start)) = self.intervals.range(start_time + 1..).next() {
-            if existing_start < end_time {
-                return false;
-            }
+        if self.booked.overlaps(start_time..end_time) {
+            return false;
         }
-        self.intervals.insert(end_time, start_time);
+        self.booked.insert(start_time..end_time);
         true
     }
 }
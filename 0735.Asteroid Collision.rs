@@ -26,7 +26,7 @@ impl Solution {
                 }
                 if stack.last().is_some_and(|&top| top == -asteroid) {
                     stack.pop();
-                } else if !stack.last().is_some_and(|&top| top > 0) {
+                } else if stack.last().is_none_or(|&top| top <= 0) {
                     stack.push(asteroid);
                 }
             }
@@ -23,7 +23,7 @@ impl Solution {
             freq
         });
 
-        if freq.iter().any(|&f| 2 * f - 1 > n) {
+        if freq.iter().any(|&f| f > n.div_ceil(2)) {
             return String::new();
         }
 
@@ -39,10 +39,10 @@ impl Solution {
 
         while let Some((count, ch)) = heap.pop() {
             result.push(ch as char);
-            if let Some(p) = prev.take() {
-                if p.0 > 0 {
-                    heap.push(p);
-                }
+            if let Some(p) = prev.take()
+                && p.0 > 0
+            {
+                heap.push(p);
             }
             prev = Some((count - 1, ch));
         }
@@ -1,58 +1,40 @@
+use crate::graph::{Bottleneck, Graph};
+
 impl Solution {
-    /// Finds minimum time to swim from top-left to bottom-right using Union-Find.
+    /// Finds minimum time to swim from top-left to bottom-right as a minimax path.
     ///
     /// # Intuition
-    /// At time t, all cells with elevation <= t are accessible. Incrementally
-    /// union adjacent accessible cells until top-left and bottom-right are connected.
+    /// Reaching a cell takes until the water covers it, so a route costs the
+    /// highest elevation along it. Dijkstra works unchanged when path weights
+    /// combine by `max` instead of `+`, since extending a route never lowers it.
     ///
     /// # Approach
-    /// Sort cells by elevation. Process cells in increasing elevation order,
-    /// unioning each newly accessible cell with its already-accessible neighbors.
-    /// Return the elevation when source and sink become connected.
+    /// Number the cells row by row and give each move the elevation of the
+    /// cell entered, wrapped in `Bottleneck` so paths weigh their heaviest
+    /// edge. Run Dijkstra from the top-left cell; the answer is that bottleneck
+    /// or the starting elevation, whichever is higher.
     ///
     /// # Complexity
-    /// - Time: O(n^2 * alpha(n^2)) effectively O(n^2)
-    /// - Space: O(n^2) for the union-find structure
+    /// - Time: O(n^2 log n)
+    /// - Space: O(n^2) for the graph
     pub fn swim_in_water(grid: Vec<Vec<i32>>) -> i32 {
         let n = grid.len();
-        let total = n * n;
-        let mut parent: Vec<usize> = (0..total).collect();
-
-        fn find(parent: &mut [usize], x: usize) -> usize {
-            if parent[x] != x {
-                parent[x] = find(parent, parent[x]);
-            }
-            parent[x]
-        }
-
-        fn union(parent: &mut [usize], x: usize, y: usize) {
-            let px = find(parent, x);
-            let py = find(parent, y);
-            parent[px] = py;
-        }
-
-        let mut order: Vec<(i32, usize, usize)> = grid
-            .iter()
-            .enumerate()
-            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, v)| (*v, i, j)))
-            .collect();
-        order.sort_unstable();
-
-        let mut accessible = vec![vec![false; n]; n];
-        for &(elevation, r, c) in &order {
-            accessible[r][c] = true;
-            for (dr, dc) in [(!0usize, 0), (1, 0), (0, !0usize), (0, 1)] {
-                let nr = r.wrapping_add(dr);
-                let nc = c.wrapping_add(dc);
-                if nr < n && nc < n && accessible[nr][nc] {
-                    union(&mut parent, r * n + c, nr * n + nc);
+        let mut edges = Vec::with_capacity(4 * n * n);
+        for r in 0..n {
+            for c in 0..n {
+                for (dr, dc) in [(usize::MAX, 0), (1, 0), (0, usize::MAX), (0, 1)] {
+                    let (nr, nc) = (r.wrapping_add(dr), c.wrapping_add(dc));
+                    if nr < n && nc < n {
+                        edges.push((r * n + c, nr * n + nc, Bottleneck(grid[nr][nc])));
+                    }
                 }
             }
-            if find(&mut parent, 0) == find(&mut parent, total - 1) {
-                return elevation;
-            }
         }
-        0
+        let Bottleneck(highest) = Graph::directed(n * n, edges)
+            .dijkstra(0)
+            .dist(n * n - 1)
+            .unwrap();
+        highest.max(grid[0][0])
     }
 }
 
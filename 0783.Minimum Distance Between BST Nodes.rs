@@ -59,37 +59,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_basic_bst() {
@@ -99,7 +69,7 @@ mod tests {
         //     2   6
         //    / \
         //   1   3
-        let root = build_tree(&[Some(4), Some(2), Some(6), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(4), Some(2), Some(6), Some(1), Some(3)]).into_inner();
         assert_eq!(Solution::min_diff_in_bst(root), 1);
     }
 
@@ -111,13 +81,22 @@ mod tests {
         //     0   48
         //        /  \
         //       12  49
-        let root = build_tree(&[Some(1), Some(0), Some(48), None, None, Some(12), Some(49)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(0),
+            Some(48),
+            None,
+            None,
+            Some(12),
+            Some(49),
+        ])
+        .into_inner();
         assert_eq!(Solution::min_diff_in_bst(root), 1);
     }
 
     #[test]
     fn test_linear_bst() {
-        // Tree: [90, 69, null, 49, null, 52]
+        // Tree: [90, 69, null, 49, null, null, 52]
         //       90
         //      /
         //     69
@@ -125,18 +104,16 @@ mod tests {
         //   49
         //    \
         //     52
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(90),
             Some(69),
             None,
             Some(49),
             None,
             None,
-            None,
-            None,
-            None,
             Some(52),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::min_diff_in_bst(root), 3); // 52-49=3
     }
 
@@ -146,7 +123,7 @@ mod tests {
         //       5
         //      /
         //     3
-        let root = build_tree(&[Some(5), Some(3)]);
+        let root = Tree::from(vec![Some(5), Some(3)]).into_inner();
         assert_eq!(Solution::min_diff_in_bst(root), 2);
     }
 
@@ -158,7 +135,7 @@ mod tests {
         //     5
         //    /
         //   2
-        let root = build_tree(&[Some(10), Some(5), None, Some(2)]);
+        let root = Tree::from(vec![Some(10), Some(5), None, Some(2)]).into_inner();
         assert_eq!(Solution::min_diff_in_bst(root), 3); // 5-2=3
     }
 }
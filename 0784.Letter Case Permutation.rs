@@ -27,8 +27,8 @@ impl Solution {
             }
         }
 
-        let mut chars = s.into_bytes();
         let mut result = Vec::with_capacity(s.len());
+        let mut chars = s.into_bytes();
         backtrack(&mut chars, 0, &mut result);
         result
     }
@@ -55,7 +55,7 @@ mod tests {
     #[test]
     fn test_large_pour() {
         let result = Solution::champagne_tower(100000009, 33, 17);
-        assert!(result >= 0.0 && result <= 1.0);
+        assert!((0.0..=1.0).contains(&result));
     }
 
     #[test]
@@ -50,64 +50,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        if root.is_none() {
-            return vec![];
-        }
-
-        let mut result = Vec::new();
-        let mut queue = std::collections::VecDeque::with_capacity(16);
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                result.push(Some(n.borrow().val));
-                queue.push_back(n.borrow().left.clone());
-                queue.push_back(n.borrow().right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing Nones
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_prune_zeros() {
@@ -117,10 +60,10 @@ mod tests {
         //         0              0
         //        / \              \
         //       0   1              1
-        let root = build_tree(&[Some(1), None, Some(0), Some(0), Some(1)]);
+        let root = Tree::from(vec![Some(1), None, Some(0), Some(0), Some(1)]).into_inner();
         let pruned = Solution::prune_tree(root);
         let expected = vec![Some(1), None, Some(0), None, Some(1)];
-        assert_eq!(tree_to_vec(pruned), expected);
+        assert_eq!(Tree::from(pruned).to_vec(), expected);
     }
 
     #[test]
@@ -131,7 +74,7 @@ mod tests {
         //     0   1              1
         //    / \ / \              \
         //   0  0 0  1              1
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(0),
             Some(1),
@@ -139,16 +82,17 @@ mod tests {
             Some(0),
             Some(0),
             Some(1),
-        ]);
+        ])
+        .into_inner();
         let pruned = Solution::prune_tree(root);
         let expected = vec![Some(1), None, Some(1), None, Some(1)];
-        assert_eq!(tree_to_vec(pruned), expected);
+        assert_eq!(Tree::from(pruned).to_vec(), expected);
     }
 
     #[test]
     fn test_prune_all_zeros() {
         // Tree with all zeros should return None
-        let root = build_tree(&[Some(0), Some(0), Some(0)]);
+        let root = Tree::from(vec![Some(0), Some(0), Some(0)]).into_inner();
         let pruned = Solution::prune_tree(root);
         assert_eq!(pruned, None);
     }
@@ -159,17 +103,17 @@ mod tests {
         //       1
         //      / \
         //     1   1
-        let root = build_tree(&[Some(1), Some(1), Some(1)]);
+        let root = Tree::from(vec![Some(1), Some(1), Some(1)]).into_inner();
         let pruned = Solution::prune_tree(root);
         let expected = vec![Some(1), Some(1), Some(1)];
-        assert_eq!(tree_to_vec(pruned), expected);
+        assert_eq!(Tree::from(pruned).to_vec(), expected);
     }
 
     #[test]
     fn test_single_one() {
         // Single node with value 1
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let pruned = Solution::prune_tree(root);
-        assert_eq!(tree_to_vec(pruned), vec![Some(1)]);
+        assert_eq!(Tree::from(pruned).to_vec(), vec![Some(1)]);
     }
 }
@@ -1,17 +1,3 @@
-// Definition for singly-linked list.
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 use std::collections::HashSet;
 
 impl Solution {
@@ -55,21 +41,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_num_components_example1() {
         // Input: head = [0,1,2,3], nums = [0,1,3]
         // Output: 2
         // Explanation: 0 and 1 are connected, 3 is a separate component
-        let head = vec_to_list(vec![0, 1, 2, 3]);
+        let head = List::from(vec![0, 1, 2, 3]).into_inner();
         let nums = vec![0, 1, 3];
         assert_eq!(Solution::num_components(head, nums), 2);
     }
@@ -79,7 +58,7 @@ mod tests {
         // Input: head = [0,1,2,3,4], nums = [0,3,1,4]
         // Output: 2
         // Explanation: 0 and 1 are connected, 3 and 4 are connected
-        let head = vec_to_list(vec![0, 1, 2, 3, 4]);
+        let head = List::from(vec![0, 1, 2, 3, 4]).into_inner();
         let nums = vec![0, 3, 1, 4];
         assert_eq!(Solution::num_components(head, nums), 2);
     }
@@ -89,7 +68,7 @@ mod tests {
         // Input: head = [0,1,2,3], nums = [0,1,2,3]
         // Output: 1
         // Explanation: All nodes form one component
-        let head = vec_to_list(vec![0, 1, 2, 3]);
+        let head = List::from(vec![0, 1, 2, 3]).into_inner();
         let nums = vec![0, 1, 2, 3];
         assert_eq!(Solution::num_components(head, nums), 1);
     }
@@ -99,7 +78,7 @@ mod tests {
         // Input: head = [0,1,2,3,4], nums = [0,2,4]
         // Output: 3
         // Explanation: Each node is a separate component
-        let head = vec_to_list(vec![0, 1, 2, 3, 4]);
+        let head = List::from(vec![0, 1, 2, 3, 4]).into_inner();
         let nums = vec![0, 2, 4];
         assert_eq!(Solution::num_components(head, nums), 3);
     }
@@ -108,7 +87,7 @@ mod tests {
     fn test_num_components_single_node() {
         // Input: head = [0], nums = [0]
         // Output: 1
-        let head = vec_to_list(vec![0]);
+        let head = List::from(vec![0]).into_inner();
         let nums = vec![0];
         assert_eq!(Solution::num_components(head, nums), 1);
     }
@@ -27,7 +27,7 @@ impl Solution {
                     result.push(word.as_bytes()[0] as char);
                 }
                 result.push_str("ma");
-                result.extend(std::iter::repeat('a').take(i + 1));
+                result.extend(std::iter::repeat_n('a', i + 1));
                 result
             })
             .collect();
@@ -43,7 +43,7 @@ mod tests {
     fn test_basic() {
         assert_eq!(
             Solution::to_goat_latin("I speak Goat Latin".to_string()),
-            "Imaa teleakspaa oatGmaaa atinLmaaaa"
+            "Imaa peaksmaaa oatGmaaaa atinLmaaaaa"
         );
     }
 
@@ -51,7 +51,7 @@ mod tests {
     fn test_vowel_start() {
         assert_eq!(
             Solution::to_goat_latin("Each word".to_string()),
-            "Eachma ordwmaa"
+            "Eachmaa ordwmaaa"
         );
     }
 }
@@ -65,47 +65,13 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> TreeLink {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while i < vals.len() {
-            if let Some(current) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        current.borrow_mut().left = Some(Rc::clone(&left));
-                        queue.push_back(left);
-                    }
-                }
-                i += 1;
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        current.borrow_mut().right = Some(Rc::clone(&right));
-                        queue.push_back(right);
-                    }
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
         // Input: root = [3,5,1,6,2,0,8,null,null,7,4]
         // Output: [2,7,4]
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(3),
             Some(5),
             Some(1),
@@ -117,7 +83,8 @@ mod tests {
             None,
             Some(7),
             Some(4),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::subtree_with_all_deepest(root);
         assert_eq!(result.as_ref().unwrap().borrow().val, 2);
     }
@@ -126,7 +93,7 @@ mod tests {
     fn test_example_2() {
         // Input: root = [1]
         // Output: [1]
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::subtree_with_all_deepest(root);
         assert_eq!(result.as_ref().unwrap().borrow().val, 1);
     }
@@ -135,7 +102,7 @@ mod tests {
     fn test_example_3() {
         // Input: root = [0,1,3,null,2]
         // Output: [2]
-        let root = build_tree(&[Some(0), Some(1), Some(3), None, Some(2)]);
+        let root = Tree::from(vec![Some(0), Some(1), Some(3), None, Some(2)]).into_inner();
         let result = Solution::subtree_with_all_deepest(root);
         assert_eq!(result.as_ref().unwrap().borrow().val, 2);
     }
@@ -143,7 +110,7 @@ mod tests {
     #[test]
     fn test_balanced_deep_leaves() {
         // When multiple leaves at same deepest level share a common ancestor
-        let root = build_tree(&[Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]).into_inner();
         let result = Solution::subtree_with_all_deepest(root);
         // Nodes 4 and 5 are deepest, their LCA is node 2
         assert_eq!(result.as_ref().unwrap().borrow().val, 2);
@@ -62,44 +62,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_leaf_similar_trees() {
         // Tree1: [3, 5, 1, 6, 2, 9, 8, null, null, 7, 4]
         // Tree2: [3, 5, 1, 6, 7, 4, 2, null, null, null, null, null, null, 9, 8]
         // Both have leaf sequence: [6, 7, 4, 9, 8]
-        let root1 = build_tree(&[
+        let root1 = Tree::from(vec![
             Some(3),
             Some(5),
             Some(1),
@@ -111,8 +81,9 @@ mod tests {
             None,
             Some(7),
             Some(4),
-        ]);
-        let root2 = build_tree(&[
+        ])
+        .into_inner();
+        let root2 = Tree::from(vec![
             Some(3),
             Some(5),
             Some(1),
@@ -128,7 +99,8 @@ mod tests {
             None,
             Some(9),
             Some(8),
-        ]);
+        ])
+        .into_inner();
         assert!(Solution::leaf_similar(root1, root2));
     }
 
@@ -136,16 +108,16 @@ mod tests {
     fn test_leaf_different_trees() {
         // Tree1: [1, 2, 3] - leaves: [2, 3]
         // Tree2: [1, 3, 2] - leaves: [3, 2]
-        let root1 = build_tree(&[Some(1), Some(2), Some(3)]);
-        let root2 = build_tree(&[Some(1), Some(3), Some(2)]);
+        let root1 = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
+        let root2 = Tree::from(vec![Some(1), Some(3), Some(2)]).into_inner();
         assert!(!Solution::leaf_similar(root1, root2));
     }
 
     #[test]
     fn test_single_node_trees() {
         // Both trees have single node with same value
-        let root1 = build_tree(&[Some(5)]);
-        let root2 = build_tree(&[Some(5)]);
+        let root1 = Tree::from(vec![Some(5)]).into_inner();
+        let root2 = Tree::from(vec![Some(5)]).into_inner();
         assert!(Solution::leaf_similar(root1, root2));
     }
 
@@ -163,8 +135,8 @@ mod tests {
         //         2
         //          \
         //           3
-        let root1 = build_tree(&[Some(1), Some(2), None, Some(3)]);
-        let root2 = build_tree(&[Some(1), None, Some(2), None, None, None, Some(3)]);
+        let root1 = Tree::from(vec![Some(1), Some(2), None, Some(3)]).into_inner();
+        let root2 = Tree::from(vec![Some(1), None, Some(2), None, Some(3)]).into_inner();
         assert!(Solution::leaf_similar(root1, root2));
     }
 
@@ -172,7 +144,7 @@ mod tests {
     fn test_empty_vs_nonempty() {
         // One empty tree, one non-empty
         let root1 = None;
-        let root2 = build_tree(&[Some(1)]);
+        let root2 = Tree::from(vec![Some(1)]).into_inner();
         assert!(!Solution::leaf_similar(root1, root2));
     }
 }
@@ -28,11 +28,11 @@ impl Solution {
         for i in 2..n {
             for j in 1..i {
                 let target = arr[i] - arr[j];
-                if let Some(&k) = index.get(&target) {
-                    if k < j {
-                        dp[i][j] = dp[i][j].max(dp[j][k] + 1);
-                        max_len = max_len.max(dp[i][j]);
-                    }
+                if let Some(&k) = index.get(&target)
+                    && k < j
+                {
+                    dp[i][j] = dp[i][j].max(dp[j][k] + 1);
+                    max_len = max_len.max(dp[i][j]);
                 }
             }
         }
@@ -1,16 +1,3 @@
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 impl Solution {
     /// Finds the middle node using the slow/fast pointer technique.
     ///
@@ -38,88 +25,70 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::list_node::List;
 
     /// Helper function to create a linked list from a vector
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for val in vec.into_iter().rev() {
-            let mut node = Box::new(ListNode::new(val));
-            node.next = head;
-            head = Some(node);
-        }
-        head
-    }
-
     /// Helper function to convert a linked list to a vector
-    fn list_to_vec(mut head: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut result = Vec::new();
-        while let Some(node) = head {
-            result.push(node.val);
-            head = node.next;
-        }
-        result
-    }
-
     #[test]
     fn test_middle_node_example1() {
         // Input: [1,2,3,4,5]
         // Expected: [3,4,5] (middle node is 3)
-        let head = vec_to_list(vec![1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![3, 4, 5]);
+        assert_eq!(List::from(middle).to_vec(), vec![3, 4, 5]);
     }
 
     #[test]
     fn test_middle_node_example2() {
         // Input: [1,2,3,4,5,6]
         // Expected: [4,5,6] (second middle node is 4)
-        let head = vec_to_list(vec![1, 2, 3, 4, 5, 6]);
+        let head = List::from(vec![1, 2, 3, 4, 5, 6]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![4, 5, 6]);
+        assert_eq!(List::from(middle).to_vec(), vec![4, 5, 6]);
     }
 
     #[test]
     fn test_middle_node_single() {
         // Input: [1]
         // Expected: [1]
-        let head = vec_to_list(vec![1]);
+        let head = List::from(vec![1]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![1]);
+        assert_eq!(List::from(middle).to_vec(), vec![1]);
     }
 
     #[test]
     fn test_middle_node_two_elements() {
         // Input: [1,2]
         // Expected: [2] (second middle node)
-        let head = vec_to_list(vec![1, 2]);
+        let head = List::from(vec![1, 2]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![2]);
+        assert_eq!(List::from(middle).to_vec(), vec![2]);
     }
 
     #[test]
     fn test_middle_node_three_elements() {
         // Input: [1,2,3]
         // Expected: [2,3] (middle node is 2)
-        let head = vec_to_list(vec![1, 2, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![2, 3]);
+        assert_eq!(List::from(middle).to_vec(), vec![2, 3]);
     }
 
     #[test]
     fn test_middle_node_four_elements() {
         // Input: [1,2,3,4]
         // Expected: [3,4] (second middle node is 3)
-        let head = vec_to_list(vec![1, 2, 3, 4]);
+        let head = List::from(vec![1, 2, 3, 4]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![3, 4]);
+        assert_eq!(List::from(middle).to_vec(), vec![3, 4]);
     }
 
     #[test]
     fn test_middle_node_large_list() {
         // Input: [1,2,3,4,5,6,7,8,9]
         // Expected: [5,6,7,8,9] (middle node is 5)
-        let head = vec_to_list(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let head = List::from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).into_inner();
         let middle = Solution::middle_node(head);
-        assert_eq!(list_to_vec(middle), vec![5, 6, 7, 8, 9]);
+        assert_eq!(List::from(middle).to_vec(), vec![5, 6, 7, 8, 9]);
     }
 }
@@ -76,16 +76,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn count_nodes(root: &Option<Rc<RefCell<TreeNode>>>) -> i32 {
-        match root {
-            None => 0,
-            Some(node) => {
-                let n = node.borrow();
-                1 + count_nodes(&n.left) + count_nodes(&n.right)
-            }
-        }
-    }
+    use crate::tree_node::Tree;
 
     fn is_full_binary_tree(root: &Option<Rc<RefCell<TreeNode>>>) -> bool {
         match root {
@@ -110,7 +101,7 @@ mod tests {
         // Only one possible tree: single node
         let trees = Solution::all_possible_fbt(1);
         assert_eq!(trees.len(), 1);
-        assert_eq!(count_nodes(&trees[0]), 1);
+        assert_eq!(Tree::from(trees[0].clone()).node_count(), 1);
     }
 
     #[test]
@@ -121,7 +112,7 @@ mod tests {
         //   0   0
         let trees = Solution::all_possible_fbt(3);
         assert_eq!(trees.len(), 1);
-        assert_eq!(count_nodes(&trees[0]), 3);
+        assert_eq!(Tree::from(trees[0].clone()).node_count(), 3);
         assert!(is_full_binary_tree(&trees[0]));
     }
 
@@ -131,7 +122,7 @@ mod tests {
         let trees = Solution::all_possible_fbt(5);
         assert_eq!(trees.len(), 2);
         for tree in &trees {
-            assert_eq!(count_nodes(tree), 5);
+            assert_eq!(Tree::from(tree.clone()).node_count(), 5);
             assert!(is_full_binary_tree(tree));
         }
     }
@@ -142,7 +133,7 @@ mod tests {
         let trees = Solution::all_possible_fbt(7);
         assert_eq!(trees.len(), 5);
         for tree in &trees {
-            assert_eq!(count_nodes(tree), 7);
+            assert_eq!(Tree::from(tree.clone()).node_count(), 7);
             assert!(is_full_binary_tree(tree));
         }
     }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Sums the minimum of every subarray using monotonic stack contribution.
     ///
@@ -15,7 +17,6 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(n) for the stacks and boundary arrays
     pub fn sum_subarray_mins(arr: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let n = arr.len();
         let mut left = vec![-1i32; n];
         let mut right = vec![n as i32; n];
@@ -42,13 +43,14 @@ impl Solution {
             stack.push(i);
         }
 
-        (0..n)
+        let total: Mint = (0..n)
             .map(|i| {
-                let l = (i as i32 - left[i]) as i64;
-                let r = (right[i] - i as i32) as i64;
-                arr[i] as i64 * l % MOD * r % MOD
+                let l = i as i32 - left[i];
+                let r = right[i] - i as i32;
+                Mint::from(arr[i]) * l * r
             })
-            .sum::<i64>() as i32
+            .sum();
+        total.value() as i32
     }
 }
 
@@ -51,7 +51,7 @@ mod tests {
     fn test_special_chars() {
         assert_eq!(
             Solution::reverse_only_letters("Test1ng-Leet=code-Q!".to_string()),
-            "Qedo1teleC-worGn=deba-T!".to_string()
+            "Qedo1ct-eeLg=ntse-T!".to_string()
         );
     }
 }
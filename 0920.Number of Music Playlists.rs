@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts distinct playlists of length goal from n songs with gap constraint k.
     ///
@@ -14,21 +16,20 @@ impl Solution {
     /// - Time: O(goal * n)
     /// - Space: O(goal * n) for the DP table
     pub fn num_music_playlists(n: i32, goal: i32, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let (n, goal, k) = (n as usize, goal as usize, k as usize);
-        let mut dp = vec![vec![0i64; n + 1]; goal + 1];
-        dp[0][0] = 1;
+        let mut dp = vec![vec![Mint::ZERO; n + 1]; goal + 1];
+        dp[0][0] = Mint::ONE;
 
         for i in 1..=goal {
             for j in 1..=n {
-                dp[i][j] = dp[i - 1][j - 1] * (n - j + 1) as i64 % MOD;
+                dp[i][j] = dp[i - 1][j - 1] * (n - j + 1);
                 if j > k {
-                    dp[i][j] = (dp[i][j] + dp[i - 1][j] * (j - k) as i64) % MOD;
+                    dp[i][j] = dp[i][j] + dp[i - 1][j] * (j - k);
                 }
             }
         }
 
-        dp[goal][n] as i32
+        dp[goal][n].value() as i32
     }
 }
 
@@ -18,9 +18,8 @@ impl Solution {
         emails
             .iter()
             .map(|email| {
-                let mut parts = email.splitn(2, '@');
-                let local = parts.next().unwrap();
-                let domain = parts.next().unwrap();
+                let (local, domain) = email.split_once('@').unwrap();
+
                 let normalized: String = local
                     .bytes()
                     .take_while(|&b| b != b'+')
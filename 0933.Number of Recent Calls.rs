@@ -1,34 +1,33 @@
-use std::collections::VecDeque;
+use crate::rate_limit::{Limiter, SlidingLog};
 
 struct RecentCounter {
-    queue: VecDeque<i32>,
+    log: SlidingLog,
 }
 
 impl RecentCounter {
-    /// Queue-based recent call counter within a 3000ms window.
+    /// Recent call counter as a sliding log over a 3000ms window.
     ///
     /// # Intuition
-    /// Keep a queue of timestamps. On each ping, remove timestamps older than
-    /// `t - 3000` and return the queue length.
+    /// Keep the timestamps of recent calls. On each ping, drop those older
+    /// than `t - 3000` and count the rest.
     ///
     /// # Approach
-    /// Push new timestamp, pop from front while front < t - 3000.
+    /// Configure a sliding log with no limit whose window spans the 3001
+    /// ticks `t - 3000..=t`; a ping records itself, then counts the log.
     ///
     /// # Complexity
     /// - Time: O(1) amortized per ping
     /// - Space: O(W) where W is the window size (at most 3001 elements)
     fn new() -> Self {
         Self {
-            queue: VecDeque::with_capacity(3001), // Maximum window size
+            log: SlidingLog::new(3001, u64::MAX),
         }
     }
 
     fn ping(&mut self, t: i32) -> i32 {
-        self.queue.push_back(t);
-        while self.queue.front().is_some_and(|&v| v < t - 3000) {
-            self.queue.pop_front();
-        }
-        self.queue.len() as i32
+        let now = t as u64;
+        self.log.try_acquire(now);
+        self.log.count(now) as i32
     }
 }
 
@@ -148,7 +147,7 @@ mod tests {
         }
 
         // All 3001 pings should be in the window
-        assert_eq!(counter.queue.len(), 3001);
+        assert_eq!(counter.log.count(3000), 3001);
 
         // Next ping at 3001: removes ping at 0 (0 < 3001-3000=1)
         // Keeps pings from 1 to 3000, plus new ping at 3001
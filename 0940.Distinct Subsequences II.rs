@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts distinct non-empty subsequences modulo 10^9 + 7.
     ///
@@ -14,13 +16,12 @@ impl Solution {
     /// - Time: O(26 * n) = O(n)
     /// - Space: O(1) — fixed 26-element array
     pub fn distinct_subseq_ii(s: String) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-        let mut dp = [0i64; 26];
+        let mut dp = [Mint::ZERO; 26];
         for b in s.bytes() {
             let idx = (b - b'a') as usize;
-            dp[idx] = (dp.iter().sum::<i64>() + 1) % MOD;
+            dp[idx] = dp.iter().sum::<Mint>() + 1;
         }
-        (dp.iter().sum::<i64>() % MOD) as i32
+        dp.iter().sum::<Mint>().value() as i32
     }
 }
 
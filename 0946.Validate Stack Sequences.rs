@@ -18,7 +18,7 @@ impl Solution {
         let mut j = 0;
         for &x in &pushed {
             stack.push(x);
-            while stack.last() == Some(&popped[j]) {
+            while !stack.is_empty() && stack.last() == popped.get(j) {
                 stack.pop();
                 j += 1;
             }
@@ -39,7 +39,7 @@ impl Solution {
     /// - Space: O(h) where h is the tree height (recursion stack)
     pub fn is_unival_tree(root: Option<Rc<RefCell<TreeNode>>>) -> bool {
         fn dfs(node: &Option<Rc<RefCell<TreeNode>>>, target: i32) -> bool {
-            node.as_ref().map_or(true, |n| {
+            node.as_ref().is_none_or(|n| {
                 let borrowed = n.borrow();
                 borrowed.val == target
                     && dfs(&borrowed.left, target)
@@ -47,44 +47,14 @@ impl Solution {
             })
         }
 
-        root.as_ref().map_or(true, |r| dfs(&root, r.borrow().val))
+        root.as_ref().is_none_or(|r| dfs(&root, r.borrow().val))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(vals.len());
-        queue.push_back(root.clone());
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() && vals[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < vals.len() && vals[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(vals[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_unival_tree() {
@@ -94,7 +64,16 @@ mod tests {
         //     1   1
         //    / \   \
         //   1   1   1
-        let root = build_tree(&[Some(1), Some(1), Some(1), Some(1), Some(1), None, Some(1)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+            Some(1),
+            None,
+            Some(1),
+        ])
+        .into_inner();
         assert!(Solution::is_unival_tree(root));
     }
 
@@ -106,14 +85,14 @@ mod tests {
         //     2   2
         //    / \
         //   5   2
-        let root = build_tree(&[Some(2), Some(2), Some(2), Some(5), Some(2)]);
+        let root = Tree::from(vec![Some(2), Some(2), Some(2), Some(5), Some(2)]).into_inner();
         assert!(!Solution::is_unival_tree(root));
     }
 
     #[test]
     fn test_single_node() {
         // Single node is always unival
-        let root = build_tree(&[Some(42)]);
+        let root = Tree::from(vec![Some(42)]).into_inner();
         assert!(Solution::is_unival_tree(root));
     }
 
@@ -125,7 +104,7 @@ mod tests {
         //     0   0
         //    / \
         //   0   0
-        let root = build_tree(&[Some(0), Some(0), Some(0), Some(0), Some(0)]);
+        let root = Tree::from(vec![Some(0), Some(0), Some(0), Some(0), Some(0)]).into_inner();
         assert!(Solution::is_unival_tree(root));
     }
 
@@ -135,7 +114,7 @@ mod tests {
         //       1
         //      / \
         //     2   1
-        let root = build_tree(&[Some(1), Some(2), Some(1)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(1)]).into_inner();
         assert!(!Solution::is_unival_tree(root));
     }
 
@@ -149,7 +128,17 @@ mod tests {
         //   7
         //  /
         // 7
-        let root = build_tree(&[Some(7), Some(7), None, Some(7), None, None, None, Some(7)]);
+        let root = Tree::from(vec![
+            Some(7),
+            Some(7),
+            None,
+            Some(7),
+            None,
+            None,
+            None,
+            Some(7),
+        ])
+        .into_inner();
         assert!(Solution::is_unival_tree(root));
     }
 }
@@ -1,36 +1,36 @@
+use crate::interval_set::IntervalSet;
+use std::ops::Bound::Included;
+
 impl Solution {
-    /// Finds intersections of two sorted interval lists using two pointers.
+    /// Intersects two interval lists as interval sets.
     ///
     /// # Intuition
-    /// Two sorted interval lists can be merged by advancing the pointer
-    /// with the earlier ending interval after checking for overlap.
+    /// Each list is a set of points, and the answer is their intersection.
+    /// Intersecting two sorted, disjoint lists is a merge walk that advances
+    /// whichever interval ends first.
     ///
     /// # Approach
-    /// At each step, compute the overlap as `[max(starts), min(ends)]`.
-    /// If valid, add to results. Advance the pointer whose interval ends first.
+    /// Build an `IntervalSet` from each list and take their intersection,
+    /// whose walk emits `[max(starts), min(ends)]` whenever that is non-empty.
+    /// Read the result back as closed intervals.
     ///
     /// # Complexity
-    /// - Time: O(n + m)
-    /// - Space: O(n + m) for the result
+    /// - Time: O((n + m) log(n + m)) for building the sets; the walk is linear
+    /// - Space: O(n + m) for the sets and the result
     pub fn interval_intersection(
         first_list: Vec<Vec<i32>>,
         second_list: Vec<Vec<i32>>,
     ) -> Vec<Vec<i32>> {
-        let mut result = Vec::with_capacity(first_list.len().min(second_list.len()));
-        let (mut i, mut j) = (0, 0);
-        while i < first_list.len() && j < second_list.len() {
-            let start = first_list[i][0].max(second_list[j][0]);
-            let end = first_list[i][1].min(second_list[j][1]);
-            if start <= end {
-                result.push(vec![start, end]);
-            }
-            if first_list[i][1] < second_list[j][1] {
-                i += 1;
-            } else {
-                j += 1;
-            }
-        }
-        result
+        let to_set =
+            |list: &[Vec<i32>]| -> IntervalSet<i32> { list.iter().map(|i| i[0]..=i[1]).collect() };
+        to_set(&first_list)
+            .intersection(&to_set(&second_list))
+            .iter()
+            .map(|bounds| match bounds {
+                (Included(&start), Included(&end)) => vec![start, end],
+                _ => unreachable!("closed intervals intersect in closed intervals"),
+            })
+            .collect()
     }
 }
 
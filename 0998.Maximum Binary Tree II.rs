@@ -59,72 +59,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(values.len());
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while i < values.len() && !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() && values[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < values.len() && values[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        if root.is_none() {
-            return vec![];
-        }
-
-        let mut result = Vec::with_capacity(values.len() * 2); // Account for potential nulls
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                result.push(Some(n.borrow().val));
-                queue.push_back(n.borrow().left.clone());
-                queue.push_back(n.borrow().right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_insert_larger_than_root() {
         // Tree: [4,1,3,null,null,2]
         // Insert: 5
         // Expected: [5,4,null,1,3,null,null,2]
-        let root = build_tree(vec![Some(4), Some(1), Some(3), None, None, Some(2)]);
+        let root = Tree::from(vec![Some(4), Some(1), Some(3), None, None, Some(2)]).into_inner();
         let result = Solution::insert_into_max_tree(root, 5);
         let expected = vec![
             Some(5),
@@ -136,7 +78,7 @@ mod tests {
             None,
             Some(2),
         ];
-        assert_eq!(tree_to_vec(result), expected);
+        assert_eq!(Tree::from(result).to_vec(), expected);
     }
 
     #[test]
@@ -144,10 +86,10 @@ mod tests {
         // Tree: [5,2,4,null,1]
         // Insert: 3
         // Expected: [5,2,4,null,1,null,3]
-        let root = build_tree(vec![Some(5), Some(2), Some(4), None, Some(1)]);
+        let root = Tree::from(vec![Some(5), Some(2), Some(4), None, Some(1)]).into_inner();
         let result = Solution::insert_into_max_tree(root, 3);
         let expected = vec![Some(5), Some(2), Some(4), None, Some(1), None, Some(3)];
-        assert_eq!(tree_to_vec(result), expected);
+        assert_eq!(Tree::from(result).to_vec(), expected);
     }
 
     #[test]
@@ -157,7 +99,7 @@ mod tests {
         // Expected: [1]
         let root = None;
         let result = Solution::insert_into_max_tree(root, 1);
-        assert_eq!(tree_to_vec(result), vec![Some(1)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(1)]);
     }
 
     #[test]
@@ -165,8 +107,8 @@ mod tests {
         // Tree: [3]
         // Insert: 5
         // Expected: [5,3]
-        let root = build_tree(vec![Some(3)]);
+        let root = Tree::from(vec![Some(3)]).into_inner();
         let result = Solution::insert_into_max_tree(root, 5);
-        assert_eq!(tree_to_vec(result), vec![Some(5), Some(3)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(5), Some(3)]);
     }
 }
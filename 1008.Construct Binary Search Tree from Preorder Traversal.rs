@@ -81,15 +81,15 @@ mod tests {
                 None => true,
                 Some(n) => {
                     let n = n.borrow();
-                    if let Some(min_val) = min {
-                        if n.val <= min_val {
-                            return false;
-                        }
+                    if let Some(min_val) = min
+                        && n.val <= min_val
+                    {
+                        return false;
                     }
-                    if let Some(max_val) = max {
-                        if n.val >= max_val {
-                            return false;
-                        }
+                    if let Some(max_val) = max
+                        && n.val >= max_val
+                    {
+                        return false;
                     }
                     dfs(&n.left, min, Some(n.val)) && dfs(&n.right, Some(n.val), max)
                 }
@@ -1,17 +1,3 @@
-// Definition for singly-linked list.
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 impl Solution {
     /// Finds the next greater value for each node using a monotonic stack.
     ///
@@ -59,21 +45,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_next_larger_nodes_example1() {
         // Input: head = [2,1,5]
         // Output: [5,5,0]
         // Explanation: For node 2, next greater is 5. For node 1, next greater is 5. For node 5, no greater element.
-        let head = vec_to_list(vec![2, 1, 5]);
+        let head = List::from(vec![2, 1, 5]).into_inner();
         assert_eq!(Solution::next_larger_nodes(head), vec![5, 5, 0]);
     }
 
@@ -81,7 +60,7 @@ mod tests {
     fn test_next_larger_nodes_example2() {
         // Input: head = [2,7,4,3,5]
         // Output: [7,0,5,5,0]
-        let head = vec_to_list(vec![2, 7, 4, 3, 5]);
+        let head = List::from(vec![2, 7, 4, 3, 5]).into_inner();
         assert_eq!(Solution::next_larger_nodes(head), vec![7, 0, 5, 5, 0]);
     }
 
@@ -90,7 +69,7 @@ mod tests {
         // Input: head = [5,4,3,2,1]
         // Output: [0,0,0,0,0]
         // Explanation: No next greater element for any node
-        let head = vec_to_list(vec![5, 4, 3, 2, 1]);
+        let head = List::from(vec![5, 4, 3, 2, 1]).into_inner();
         assert_eq!(Solution::next_larger_nodes(head), vec![0, 0, 0, 0, 0]);
     }
 
@@ -99,7 +78,7 @@ mod tests {
         // Input: head = [1,2,3,4,5]
         // Output: [2,3,4,5,0]
         // Explanation: Each element's next greater is the next element
-        let head = vec_to_list(vec![1, 2, 3, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
         assert_eq!(Solution::next_larger_nodes(head), vec![2, 3, 4, 5, 0]);
     }
 
@@ -107,7 +86,7 @@ mod tests {
     fn test_next_larger_nodes_single() {
         // Input: head = [1]
         // Output: [0]
-        let head = vec_to_list(vec![1]);
+        let head = List::from(vec![1]).into_inner();
         assert_eq!(Solution::next_larger_nodes(head), vec![0]);
     }
 
@@ -115,7 +94,7 @@ mod tests {
     fn test_next_larger_nodes_duplicates() {
         // Input: head = [1,7,5,1,9,2,5,1]
         // Output: [7,9,9,9,0,5,0,0]
-        let head = vec_to_list(vec![1, 7, 5, 1, 9, 2, 5, 1]);
+        let head = List::from(vec![1, 7, 5, 1, 9, 2, 5, 1]).into_inner();
         assert_eq!(
             Solution::next_larger_nodes(head),
             vec![7, 9, 9, 9, 0, 5, 0, 0]
@@ -51,44 +51,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(values.len());
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while i < values.len() && !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() && values[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < values.len() && values[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_basic_tree() {
         // Tree: [1,0,1,0,1,0,1]
         // Paths: 100=4, 101=5, 110=6, 111=7
         // Sum: 4+5+6+7=22
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(0),
             Some(1),
@@ -96,7 +66,8 @@ mod tests {
             Some(1),
             Some(0),
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::sum_root_to_leaf(root), 22);
     }
 
@@ -105,7 +76,7 @@ mod tests {
         // Tree: [0]
         // Path: 0
         // Sum: 0
-        let root = build_tree(vec![Some(0)]);
+        let root = Tree::from(vec![Some(0)]).into_inner();
         assert_eq!(Solution::sum_root_to_leaf(root), 0);
     }
 
@@ -114,7 +85,7 @@ mod tests {
         // Tree: [1]
         // Path: 1
         // Sum: 1
-        let root = build_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::sum_root_to_leaf(root), 1);
     }
 
@@ -123,7 +94,7 @@ mod tests {
         // Tree: [1,1,0]
         // Paths: 11=3, 10=2
         // Sum: 3+2=5
-        let root = build_tree(vec![Some(1), Some(1), Some(0)]);
+        let root = Tree::from(vec![Some(1), Some(1), Some(0)]).into_inner();
         assert_eq!(Solution::sum_root_to_leaf(root), 5);
     }
 
@@ -132,7 +103,7 @@ mod tests {
         // Tree: [1,0,null,1]
         // Path: 101=5
         // Sum: 5
-        let root = build_tree(vec![Some(1), Some(0), None, Some(1)]);
+        let root = Tree::from(vec![Some(1), Some(0), None, Some(1)]).into_inner();
         assert_eq!(Solution::sum_root_to_leaf(root), 5);
     }
 
@@ -39,10 +39,8 @@ impl Solution {
             for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
                 let (nx, ny) = (x + dx, y + dy);
                 let key = Self::encode_point(nx, ny);
-                if nx >= 0
-                    && nx < BOUND
-                    && ny >= 0
-                    && ny < BOUND
+                if (0..BOUND).contains(&nx)
+                    && (0..BOUND).contains(&ny)
                     && !visited.contains(&key)
                     && !block.contains(&key)
                 {
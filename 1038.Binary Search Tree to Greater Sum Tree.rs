@@ -58,71 +58,13 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(values.len());
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while i < values.len() && !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() && values[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < values.len() && values[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
-
-    fn tree_to_vec(root: Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
-        if root.is_none() {
-            return vec![];
-        }
-
-        let mut result = Vec::with_capacity(values.len() * 2); // Account for potential nulls
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(root);
-
-        while !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-            if let Some(n) = node {
-                result.push(Some(n.borrow().val));
-                queue.push_back(n.borrow().left.clone());
-                queue.push_back(n.borrow().right.clone());
-            } else {
-                result.push(None);
-            }
-        }
-
-        // Remove trailing None values
-        while result.last() == Some(&None) {
-            result.pop();
-        }
-
-        result
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_basic_bst() {
         // Input: [4,1,6,0,2,5,7,null,null,null,3,null,null,null,8]
         // Output: [30,36,21,36,35,26,15,null,null,null,33,null,null,null,8]
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(4),
             Some(1),
             Some(6),
@@ -138,7 +80,8 @@ mod tests {
             None,
             None,
             Some(8),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::bst_to_gst(root);
         let expected = vec![
             Some(30),
@@ -157,32 +100,32 @@ mod tests {
             None,
             Some(8),
         ];
-        assert_eq!(tree_to_vec(result), expected);
+        assert_eq!(Tree::from(result).to_vec(), expected);
     }
 
     #[test]
     fn test_single_node() {
-        let root = build_tree(vec![Some(5)]);
+        let root = Tree::from(vec![Some(5)]).into_inner();
         let result = Solution::bst_to_gst(root);
-        assert_eq!(tree_to_vec(result), vec![Some(5)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(5)]);
     }
 
     #[test]
     fn test_two_nodes() {
         // Input: [1,null,2]
         // Output: [3,null,2]
-        let root = build_tree(vec![Some(1), None, Some(2)]);
+        let root = Tree::from(vec![Some(1), None, Some(2)]).into_inner();
         let result = Solution::bst_to_gst(root);
-        assert_eq!(tree_to_vec(result), vec![Some(3), None, Some(2)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(3), None, Some(2)]);
     }
 
     #[test]
     fn test_three_nodes() {
         // Input: [2,1,3]
         // Output: [5,6,3]
-        let root = build_tree(vec![Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         let result = Solution::bst_to_gst(root);
-        assert_eq!(tree_to_vec(result), vec![Some(5), Some(6), Some(3)]);
+        assert_eq!(Tree::from(result).to_vec(), vec![Some(5), Some(6), Some(3)]);
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Finds the greatest common divisor of two strings.
     ///
@@ -17,15 +19,6 @@ impl Solution {
             return String::new();
         }
 
-        fn gcd(mut a: usize, mut b: usize) -> usize {
-            while b != 0 {
-                let t = b;
-                b = a % b;
-                a = t;
-            }
-            a
-        }
-
         str1[..gcd(str1.len(), str2.len())].to_string()
     }
 }
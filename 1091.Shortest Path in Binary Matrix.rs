@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use crate::graph::Graph;
 
 impl Solution {
     /// Finds shortest clear path in a binary matrix using BFS.
@@ -8,45 +8,37 @@ impl Solution {
     /// an unweighted grid. Move in all 8 directions.
     ///
     /// # Approach
-    /// Start BFS from (0,0) if clear. Mark cells as visited by setting to 1.
-    /// Expand in 8 directions. Return path length when reaching (n-1, n-1).
+    /// Number the cells row by row and build an unweighted graph with an edge
+    /// between every pair of 8-adjacent clear cells. Run BFS from cell 0;
+    /// the path length counts cells, one more than the edges.
     ///
     /// # Complexity
     /// - Time: O(n^2)
-    /// - Space: O(n^2) for the queue
-    pub fn shortest_path_binary_matrix(mut grid: Vec<Vec<i32>>) -> i32 {
+    /// - Space: O(n^2) for the graph
+    pub fn shortest_path_binary_matrix(grid: Vec<Vec<i32>>) -> i32 {
         let n = grid.len();
         if grid[0][0] == 1 || grid[n - 1][n - 1] == 1 {
             return -1;
         }
-        let mut queue = VecDeque::with_capacity(n * n);
-        queue.push_back((0, 0));
-        grid[0][0] = 1;
-        let mut steps = 1;
-
-        while !queue.is_empty() {
-            for _ in 0..queue.len() {
-                let (r, c) = queue.pop_front().unwrap();
-                if r == n - 1 && c == n - 1 {
-                    return steps;
+        let clear = |r: usize, c: usize| r < n && c < n && grid[r][c] == 0;
+        let mut edges = Vec::new();
+        for r in 0..n {
+            for c in 0..n {
+                if !clear(r, c) {
+                    continue;
                 }
-                for dr in -1i32..=1 {
-                    for dc in -1i32..=1 {
-                        let nr = r as i32 + dr;
-                        let nc = c as i32 + dc;
-                        if nr >= 0 && nr < n as i32 && nc >= 0 && nc < n as i32 {
-                            let (nr, nc) = (nr as usize, nc as usize);
-                            if grid[nr][nc] == 0 {
-                                grid[nr][nc] = 1;
-                                queue.push_back((nr, nc));
-                            }
-                        }
+                for (dr, dc) in [(0, 1), (1, usize::MAX), (1, 0), (1, 1)] {
+                    let (nr, nc) = (r + dr, c.wrapping_add(dc));
+                    if clear(nr, nc) {
+                        edges.push((r * n + c, nr * n + nc, ()));
                     }
                 }
             }
-            steps += 1;
         }
-        -1
+        Graph::undirected(n * n, edges)
+            .bfs(0)
+            .dist(n * n - 1)
+            .map_or(-1, |steps| steps as i32 + 1)
     }
 }
 
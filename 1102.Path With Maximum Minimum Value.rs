@@ -1,42 +1,4 @@
-struct UnionFind {
-    parent: Vec<usize>,
-    size: Vec<usize>,
-}
-
-impl UnionFind {
-    fn new(n: usize) -> Self {
-        let mut parent = Vec::with_capacity(n);
-        parent.extend(0..n);
-        Self {
-            parent,
-            size: vec![1; n],
-        }
-    }
-
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    fn union(&mut self, a: usize, b: usize) {
-        let (pa, pb) = (self.find(a), self.find(b));
-        if pa != pb {
-            if self.size[pa] >= self.size[pb] {
-                self.parent[pb] = pa;
-                self.size[pa] += self.size[pb];
-            } else {
-                self.parent[pa] = pb;
-                self.size[pb] += self.size[pa];
-            }
-        }
-    }
-
-    fn connected(&mut self, a: usize, b: usize) -> bool {
-        self.find(a) == self.find(b)
-    }
-}
+use crate::union_find::UnionFind;
 
 impl Solution {
     /// Finds the path maximizing the minimum cell value using sorted Union-Find.
@@ -62,10 +24,10 @@ impl Solution {
                 .enumerate()
                 .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, v)| (*v, i, j))),
         );
-        cells.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        cells.sort_unstable_by_key(|x| std::cmp::Reverse(x.0));
 
         let mut visited = vec![vec![false; n]; m];
-        for (val, r, c) in &cells {
+        for &(val, r, c) in &cells {
             visited[r][c] = true;
             for (dr, dc) in [(!0usize, 0), (1, 0), (0, !0usize), (0, 1)] {
                 let nr = r.wrapping_add(dr);
@@ -75,7 +37,7 @@ impl Solution {
                 }
             }
             if uf.connected(0, m * n - 1) {
-                return *val;
+                return val;
             }
         }
         0
@@ -59,7 +59,7 @@ mod tests {
 
     #[test]
     fn test_not() {
-        assert!(!Solution::parse_bool_expr("!(f)".to_string()));
+        assert!(Solution::parse_bool_expr("!(f)".to_string()));
     }
 
     #[test]
@@ -59,44 +59,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = std::collections::VecDeque::with_capacity(values.len());
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-        while i < values.len() && !queue.is_empty() {
-            let node = queue.pop_front().unwrap();
-
-            if i < values.len() && values[i].is_some() {
-                let left = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().left = Some(left.clone());
-                queue.push_back(left);
-            }
-            i += 1;
-
-            if i < values.len() && values[i].is_some() {
-                let right = Rc::new(RefCell::new(TreeNode::new(values[i].unwrap())));
-                node.borrow_mut().right = Some(right.clone());
-                queue.push_back(right);
-            }
-            i += 1;
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_basic_tree() {
         // Tree: [3,5,1,6,2,0,8,null,null,7,4]
         // Deepest leaves: 7, 4
         // LCA: 2
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(3),
             Some(5),
             Some(1),
@@ -108,7 +78,8 @@ mod tests {
             None,
             Some(7),
             Some(4),
-        ]);
+        ])
+        .into_inner();
         let result = Solution::lca_deepest_leaves(root);
         assert!(result.is_some());
         assert_eq!(result.unwrap().borrow().val, 2);
@@ -119,7 +90,7 @@ mod tests {
         // Tree: [1]
         // Deepest leaves: 1
         // LCA: 1
-        let root = build_tree(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let result = Solution::lca_deepest_leaves(root);
         assert!(result.is_some());
         assert_eq!(result.unwrap().borrow().val, 1);
@@ -130,7 +101,7 @@ mod tests {
         // Tree: [1,2,null,3]
         // Deepest leaf: 3
         // LCA: 3
-        let root = build_tree(vec![Some(1), Some(2), None, Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), None, Some(3)]).into_inner();
         let result = Solution::lca_deepest_leaves(root);
         assert!(result.is_some());
         assert_eq!(result.unwrap().borrow().val, 3);
@@ -141,7 +112,7 @@ mod tests {
         // Tree: [1,2,3,4,5]
         // Deepest leaves: 4, 5
         // LCA: 2
-        let root = build_tree(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3), Some(4), Some(5)]).into_inner();
         let result = Solution::lca_deepest_leaves(root);
         assert!(result.is_some());
         assert_eq!(result.unwrap().borrow().val, 2);
@@ -158,7 +129,7 @@ mod tests {
         // Tree: [1,2,3]
         // Deepest leaves: 2, 3
         // LCA: 1
-        let root = build_tree(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         let result = Solution::lca_deepest_leaves(root);
         assert!(result.is_some());
         assert_eq!(result.unwrap().borrow().val, 1);
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts ways to roll n dice with k faces to reach a target sum.
     ///
@@ -13,20 +15,19 @@ impl Solution {
     /// - Time: O(n * target * k)
     /// - Space: O(n * target)
     pub fn num_rolls_to_target(n: i32, k: i32, target: i32) -> i32 {
-        const MOD: i32 = 1_000_000_007;
         let (n, k, target) = (n as usize, k as usize, target as usize);
-        let mut dp = vec![vec![0i32; target + 1]; n + 1];
-        dp[0][0] = 1;
+        let mut dp = vec![vec![Mint::ZERO; target + 1]; n + 1];
+        dp[0][0] = Mint::ONE;
 
         for i in 1..=n {
             for j in 1..=target.min(i * k) {
                 for face in 1..=j.min(k) {
-                    dp[i][j] = (dp[i][j] + dp[i - 1][j - face]) % MOD;
+                    dp[i][j] = dp[i][j] + dp[i - 1][j - face];
                 }
             }
         }
 
-        dp[n][target]
+        dp[n][target].value() as i32
     }
 }
 
@@ -1,40 +1,4 @@
-struct UnionFind {
-    parent: Vec<usize>,
-    size: Vec<usize>,
-}
-
-impl UnionFind {
-    fn new(n: usize) -> Self {
-        let mut parent = Vec::with_capacity(n);
-        parent.extend(0..n);
-        Self {
-            parent,
-            size: vec![1; n],
-        }
-    }
-
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    fn union(&mut self, a: usize, b: usize) -> bool {
-        let (pa, pb) = (self.find(a), self.find(b));
-        if pa == pb {
-            return false;
-        }
-        if self.size[pa] >= self.size[pb] {
-            self.parent[pb] = pa;
-            self.size[pa] += self.size[pb];
-        } else {
-            self.parent[pa] = pb;
-            self.size[pb] += self.size[pa];
-        }
-        true
-    }
-}
+use crate::union_find::UnionFind;
 
 impl Solution {
     /// Finds minimum cost to supply water using Kruskal's MST with virtual node.
@@ -1,17 +1,3 @@
-// Definition for singly-linked list.
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 impl Solution {
     /// Removes consecutive nodes that sum to zero using prefix sums.
     ///
@@ -73,32 +59,16 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
-
-    fn list_to_vec(mut list: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut vec = Vec::new();
-        while let Some(node) = list {
-            vec.push(node.val);
-            list = node.next;
-        }
-        vec
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_remove_zero_sum_example1() {
         // Input: head = [1,2,-3,3,1]
         // Output: [3,1]
         // Explanation: 1+2-3=0, so remove those nodes
-        let head = vec_to_list(vec![1, 2, -3, 3, 1]);
+        let head = List::from(vec![1, 2, -3, 3, 1]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![3, 1]);
+        assert_eq!(List::from(result).to_vec(), vec![3, 1]);
     }
 
     #[test]
@@ -106,9 +76,9 @@ mod tests {
         // Input: head = [1,2,3,-3,4]
         // Output: [1,2,4]
         // Explanation: 3-3=0, so remove those nodes
-        let head = vec_to_list(vec![1, 2, 3, -3, 4]);
+        let head = List::from(vec![1, 2, 3, -3, 4]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![1, 2, 4]);
+        assert_eq!(List::from(result).to_vec(), vec![1, 2, 4]);
     }
 
     #[test]
@@ -116,9 +86,9 @@ mod tests {
         // Input: head = [1,2,3,-3,-2]
         // Output: [1]
         // Explanation: 2+3-3-2=0, so remove those nodes
-        let head = vec_to_list(vec![1, 2, 3, -3, -2]);
+        let head = List::from(vec![1, 2, 3, -3, -2]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![1]);
+        assert_eq!(List::from(result).to_vec(), vec![1]);
     }
 
     #[test]
@@ -126,9 +96,9 @@ mod tests {
         // Input: head = [0,0,0]
         // Output: []
         // Explanation: All nodes sum to zero
-        let head = vec_to_list(vec![0, 0, 0]);
+        let head = List::from(vec![0, 0, 0]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![]);
+        assert_eq!(List::from(result).to_vec(), vec![]);
     }
 
     #[test]
@@ -136,9 +106,9 @@ mod tests {
         // Input: head = [1,2,3,4]
         // Output: [1,2,3,4]
         // Explanation: No zero-sum subarrays
-        let head = vec_to_list(vec![1, 2, 3, 4]);
+        let head = List::from(vec![1, 2, 3, 4]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![1, 2, 3, 4]);
+        assert_eq!(List::from(result).to_vec(), vec![1, 2, 3, 4]);
     }
 
     #[test]
@@ -146,8 +116,8 @@ mod tests {
         // Input: head = [1,-1]
         // Output: []
         // Explanation: Entire list sums to zero
-        let head = vec_to_list(vec![1, -1]);
+        let head = List::from(vec![1, -1]).into_inner();
         let result = Solution::remove_zero_sum_sublists(head);
-        assert_eq!(list_to_vec(result), vec![]);
+        assert_eq!(List::from(result).to_vec(), vec![]);
     }
 }
@@ -19,7 +19,7 @@ impl Solution {
         }
         let counts = [
             freq[(b'b' - b'a') as usize],
-            freq[(b'a' - b'a') as usize],
+            freq[0],
             freq[(b'l' - b'a') as usize] / 2,
             freq[(b'o' - b'a') as usize] / 2,
             freq[(b'n' - b'a') as usize],
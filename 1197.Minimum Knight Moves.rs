@@ -41,7 +41,7 @@ impl Solution {
                 for (dx, dy) in MOVES {
                     let nx = cx as i32 + dx;
                     let ny = cy as i32 + dy;
-                    if nx >= 0 && nx < 618 && ny >= 0 && ny < 618 {
+                    if (0..618).contains(&nx) && (0..618).contains(&ny) {
                         let (nx, ny) = (nx as usize, ny as usize);
                         if !visited[nx][ny] {
                             visited[nx][ny] = true;
@@ -40,7 +40,7 @@ impl Solution {
             }
         }
 
-        false
+        n <= 1
     }
 }
 
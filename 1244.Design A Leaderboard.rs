@@ -1,45 +1,43 @@
-use std::collections::HashMap;
+use crate::leaderboard;
 
 struct Leaderboard {
-    scores: HashMap<i32, i32>,
+    board: leaderboard::Leaderboard<i32, i32>,
 }
 
 impl Leaderboard {
-    /// Hash map leaderboard with on-demand sorting for top-K queries.
+    /// Order-statistics leaderboard answering top-K without sorting.
     ///
     /// # Intuition
-    /// A HashMap provides O(1) score updates and resets. For top-K queries,
-    /// collecting and partially sorting scores is sufficient since K is
-    /// typically small relative to the total player count.
+    /// Sorting every score per `top` query costs O(n log n) no matter how
+    /// small K is. Keeping the standings sorted in a size-augmented treap
+    /// lets `top` read the K best directly.
     ///
     /// # Approach
-    /// - `add_score`: accumulate scores per player using entry API
-    /// - `top`: collect all scores, sort descending, sum the first K
-    /// - `reset`: remove the player entirely from the map
+    /// - `add_score`: move the player's standing to its new position
+    /// - `top`: walk the first K standings in order and sum their scores
+    /// - `reset`: remove the player's standing entirely
     ///
     /// # Complexity
-    /// - add_score: O(1) amortized
-    /// - top: O(n log n) for sorting
-    /// - reset: O(1)
-    /// - Space: O(n) for the player map
+    /// - add_score: O(log n) expected
+    /// - top: O(K + log n)
+    /// - reset: O(log n) expected
+    /// - Space: O(n) for the standings and the player map
     fn new() -> Self {
         Self {
-            scores: HashMap::new(),
+            board: leaderboard::Leaderboard::new(),
         }
     }
 
     fn add_score(&mut self, player_id: i32, score: i32) {
-        *self.scores.entry(player_id).or_insert(0) += score;
+        self.board.add_score(player_id, score);
     }
 
     fn top(&self, k: i32) -> i32 {
-        let mut vals: Vec<i32> = self.scores.values().copied().collect();
-        vals.sort_unstable_by(|a, b| b.cmp(a));
-        vals.iter().take(k as usize).sum()
+        self.board.top(k as usize).map(|(_, score)| score).sum()
     }
 
     fn reset(&mut self, player_id: i32) {
-        self.scores.remove(&player_id);
+        self.board.remove(&player_id);
     }
 }
 
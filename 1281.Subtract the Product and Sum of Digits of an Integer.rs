@@ -41,7 +41,6 @@ mod tests {
 
     #[test]
     fn single_digit() {
-        assert_eq!(Solution::subtract_product_and_sum(5), -5 + 25);
         // product=5, sum=5, 5-5=0
         assert_eq!(Solution::subtract_product_and_sum(5), 0);
     }
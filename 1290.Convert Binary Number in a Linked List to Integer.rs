@@ -39,47 +39,37 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[derive(PartialEq, Eq, Clone, Debug)]
-    pub struct ListNode {
-        pub val: i32,
-        pub next: Option<Box<ListNode>>,
-    }
-
-    impl ListNode {
-        fn new(val: i32) -> Self {
-            ListNode { next: None, val }
-        }
-    }
-
-    fn from_vec(vals: &[i32]) -> Option<Box<ListNode>> {
-        vals.iter()
-            .rev()
-            .fold(None, |next, &val| Some(Box::new(ListNode { val, next })))
-    }
-
-    fn get_decimal_value(head: Option<Box<ListNode>>) -> i32 {
-        std::iter::successors(head.as_ref(), |node| node.next.as_ref())
-            .fold(0, |acc, node| (acc << 1) | node.val)
-    }
+    use crate::list_node::List;
 
     #[test]
     fn binary_101() {
-        assert_eq!(get_decimal_value(from_vec(&[1, 0, 1])), 5);
+        assert_eq!(
+            Solution::get_decimal_value(List::from(vec![1, 0, 1]).into_inner()),
+            5
+        );
     }
 
     #[test]
     fn single_zero() {
-        assert_eq!(get_decimal_value(from_vec(&[0])), 0);
+        assert_eq!(
+            Solution::get_decimal_value(List::from(vec![0]).into_inner()),
+            0
+        );
     }
 
     #[test]
     fn single_one() {
-        assert_eq!(get_decimal_value(from_vec(&[1])), 1);
+        assert_eq!(
+            Solution::get_decimal_value(List::from(vec![1]).into_inner()),
+            1
+        );
     }
 
     #[test]
     fn all_ones() {
-        assert_eq!(get_decimal_value(from_vec(&[1, 1, 1, 1])), 15);
+        assert_eq!(
+            Solution::get_decimal_value(List::from(vec![1, 1, 1, 1]).into_inner()),
+            15
+        );
     }
 }
@@ -65,41 +65,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn build_tree(vals: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(Rc::clone(&root));
-
-        let mut i = 1;
-        while !queue.is_empty() && i < vals.len() {
-            let node = queue.pop_front().unwrap();
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-
-            if i < vals.len() {
-                if let Some(val) = vals[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_regular_tree() {
@@ -112,7 +78,7 @@ mod tests {
         //   /           \
         //  7             8
         // Deepest leaves: 7 + 8 = 15
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -126,7 +92,8 @@ mod tests {
             None,
             None,
             Some(8),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), 15);
     }
 
@@ -141,7 +108,7 @@ mod tests {
         //   /   / \     \
         //  9   1   4     5
         // Deepest leaves: 9 + 1 + 4 + 5 = 19
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(6),
             Some(7),
             Some(8),
@@ -157,7 +124,8 @@ mod tests {
             None,
             None,
             Some(5),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), 19);
     }
 
@@ -165,7 +133,7 @@ mod tests {
     fn test_single_node() {
         // Test tree: [50]
         // Deepest leaves: 50
-        let root = build_tree(vec![Some(50)]);
+        let root = Tree::from(vec![Some(50)]).into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), 50);
     }
 
@@ -176,7 +144,7 @@ mod tests {
         //    / \
         //   2   3
         // Deepest leaves: 2 + 3 = 5
-        let root = build_tree(vec![Some(1), Some(2), Some(3)]);
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), 5);
     }
 
@@ -193,7 +161,7 @@ mod tests {
         // /
         //5
         // Deepest leaves: 5
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             None,
@@ -202,7 +170,8 @@ mod tests {
             Some(4),
             None,
             Some(5),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), 5);
     }
 
@@ -215,7 +184,7 @@ mod tests {
         //    / \   \
         //   4  -5  -6
         // Deepest leaves: 4 + (-5) + (-6) = -7
-        let root = build_tree(vec![
+        let root = Tree::from(vec![
             Some(1),
             Some(-2),
             Some(3),
@@ -223,7 +192,8 @@ mod tests {
             Some(-5),
             None,
             Some(-6),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::deepest_leaves_sum(root), -7);
     }
 }
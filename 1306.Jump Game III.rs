@@ -11,6 +11,7 @@ impl Solution {
     /// 1. Return `true` if `arr[i] == 0`.
     /// 2. Skip (return `false`) if already visited (value < 0) or out of bounds.
     /// 3. Negate `arr[i]` to mark visited, then recurse on both neighbours.
+    ///
     /// The negation trick means visited checks are a single comparison with no
     /// extra allocation — the array itself is the visited set.
     ///
@@ -16,7 +16,7 @@ impl Solution {
     /// - Space: O(sum of frequencies) for the output
     pub fn decompress_rl_elist(nums: Vec<i32>) -> Vec<i32> {
         nums.chunks(2)
-            .flat_map(|pair| std::iter::repeat(pair[1]).take(pair[0] as usize))
+            .flat_map(|pair| std::iter::repeat_n(pair[1], pair[0] as usize))
             .collect()
     }
 }
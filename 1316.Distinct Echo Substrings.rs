@@ -37,7 +37,7 @@ impl Solution {
         let mut seen = HashSet::with_capacity(n);
         for i in 0..n - 1 {
             for j in i + 1..n {
-                if (j - i) % 2 != 0 {
+                if (j - i) % 2 == 0 {
                     continue;
                 }
                 let mid = i + (j - i) / 2;
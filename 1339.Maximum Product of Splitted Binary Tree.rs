@@ -74,43 +74,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -124,7 +88,16 @@ mod tests {
         // Best split: remove edge between 3 and 6 -> 15 * 6 = 90
         // Or remove edge between 1 and 3 -> 12 * 9 = 108
         // Or remove edge between 1 and 2 -> 10 * 11 = 110
-        let root = build_tree(&[Some(1), Some(2), Some(3), Some(4), Some(5), None, Some(6)]);
+        let root = Tree::from(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            None,
+            Some(6),
+        ])
+        .into_inner();
         assert_eq!(Solution::max_product(root), 110);
     }
 
@@ -140,7 +113,7 @@ mod tests {
         //         5   6
         // Total sum = 21
         // Best split: remove edge between 2 and 4 -> 6 * 15 = 90
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             None,
             Some(2),
@@ -150,7 +123,8 @@ mod tests {
             None,
             Some(5),
             Some(6),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::max_product(root), 90);
     }
 
@@ -163,7 +137,7 @@ mod tests {
         // Total sum = 6
         // Split at left: 1 * 5 = 5
         // Split at right: 3 * 3 = 9
-        let root = build_tree(&[Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         assert_eq!(Solution::max_product(root), 9);
     }
 
@@ -171,7 +145,7 @@ mod tests {
     fn test_single_node() {
         // Tree: [1]
         // No edge to remove, product = 0
-        let root = build_tree(&[Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         assert_eq!(Solution::max_product(root), 0);
     }
 
@@ -185,8 +159,8 @@ mod tests {
         // Total = 120000
         // Split at left: 30000 * 90000 = 2700000000
         // Split at right: 40000 * 80000 = 3200000000
-        let root = build_tree(&[Some(50000), Some(30000), Some(40000)]);
-        // 3200000000 % 1000000007 = 199999986
-        assert_eq!(Solution::max_product(root), 199999986);
+        let root = Tree::from(vec![Some(50000), Some(30000), Some(40000)]).into_inner();
+        // 3200000000 % 1000000007 = 199999979
+        assert_eq!(Solution::max_product(root), 199999979);
     }
 }
@@ -59,6 +59,6 @@ mod tests {
 
     #[test]
     fn test_repeated_pattern() {
-        assert_eq!(Solution::number_of_substrings("abcabcabc".to_string()), 21);
+        assert_eq!(Solution::number_of_substrings("abcabcabc".to_string()), 28);
     }
 }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Combinatorial counting of valid pickup-delivery orderings.
     ///
@@ -14,12 +16,11 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(1)
     pub fn count_orders(n: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-        let mut result = 1i64;
+        let mut result = Mint::ONE;
         for i in 2..=n as i64 {
-            result = result * i % MOD * (2 * i - 1) % MOD;
+            result = result * i * (2 * i - 1);
         }
-        result as i32
+        result.value() as i32
     }
 }
 
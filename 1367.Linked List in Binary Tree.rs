@@ -1,20 +1,6 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-// Definition for singly-linked list.
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct ListNode {
-    pub val: i32,
-    pub next: Option<Box<ListNode>>,
-}
-
-impl ListNode {
-    #[inline]
-    pub fn new(val: i32) -> Self {
-        ListNode { next: None, val }
-    }
-}
-
 // Definition for a binary tree node.
 // #[derive(Debug, PartialEq, Eq)]
 // pub struct TreeNode {
@@ -58,7 +44,7 @@ impl Solution {
             None => false,
             Some(node) => {
                 let node = node.borrow();
-                Self::matches(head, &Some(Rc::clone(&root.as_ref().unwrap())))
+                Self::matches(head, &Some(Rc::clone(root.as_ref().unwrap())))
                     || Self::check(head, &node.left)
                     || Self::check(head, &node.right)
             }
@@ -84,59 +70,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_list(vals: &[i32]) -> Option<Box<ListNode>> {
-        if vals.is_empty() {
-            return None;
-        }
-
-        let mut head = Box::new(ListNode::new(vals[0]));
-        let mut current = &mut head;
-
-        for &val in &vals[1..] {
-            current.next = Some(Box::new(ListNode::new(val)));
-            current = current.next.as_mut().unwrap();
-        }
-
-        Some(head)
-    }
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::list_node::List;
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -152,8 +87,8 @@ mod tests {
         //            \
         //             8
         // Path exists: 4->2->8
-        let head = build_list(&[4, 2, 8]);
-        let root = build_tree(&[
+        let head = List::from(vec![4, 2, 8]).into_inner();
+        let root = Tree::from(vec![
             Some(1),
             Some(4),
             Some(4),
@@ -171,8 +106,9 @@ mod tests {
             None,
             Some(1),
             Some(3),
-        ]);
-        assert_eq!(Solution::is_sub_path(head, root), true);
+        ])
+        .into_inner();
+        assert!(Solution::is_sub_path(head, root));
     }
 
     #[test]
@@ -180,8 +116,8 @@ mod tests {
         // List: [1,4,2,6]
         // Tree: [1,4,4,null,2,2,null,1,null,6,8,null,null,null,null,1,3]
         // Path exists: 1->4->2->6
-        let head = build_list(&[1, 4, 2, 6]);
-        let root = build_tree(&[
+        let head = List::from(vec![1, 4, 2, 6]).into_inner();
+        let root = Tree::from(vec![
             Some(1),
             Some(4),
             Some(4),
@@ -199,8 +135,9 @@ mod tests {
             None,
             Some(1),
             Some(3),
-        ]);
-        assert_eq!(Solution::is_sub_path(head, root), true);
+        ])
+        .into_inner();
+        assert!(Solution::is_sub_path(head, root));
     }
 
     #[test]
@@ -208,8 +145,8 @@ mod tests {
         // List: [1,4,2,6,8]
         // Tree: [1,4,4,null,2,2,null,1,null,6,8,null,null,null,null,1,3]
         // No path exists for the full list
-        let head = build_list(&[1, 4, 2, 6, 8]);
-        let root = build_tree(&[
+        let head = List::from(vec![1, 4, 2, 6, 8]).into_inner();
+        let root = Tree::from(vec![
             Some(1),
             Some(4),
             Some(4),
@@ -227,33 +164,34 @@ mod tests {
             None,
             Some(1),
             Some(3),
-        ]);
-        assert_eq!(Solution::is_sub_path(head, root), false);
+        ])
+        .into_inner();
+        assert!(!Solution::is_sub_path(head, root));
     }
 
     #[test]
     fn test_single_node_match() {
         // List: [1]
         // Tree: [1]
-        let head = build_list(&[1]);
-        let root = build_tree(&[Some(1)]);
-        assert_eq!(Solution::is_sub_path(head, root), true);
+        let head = List::from(vec![1]).into_inner();
+        let root = Tree::from(vec![Some(1)]).into_inner();
+        assert!(Solution::is_sub_path(head, root));
     }
 
     #[test]
     fn test_single_node_no_match() {
         // List: [2]
         // Tree: [1]
-        let head = build_list(&[2]);
-        let root = build_tree(&[Some(1)]);
-        assert_eq!(Solution::is_sub_path(head, root), false);
+        let head = List::from(vec![2]).into_inner();
+        let root = Tree::from(vec![Some(1)]).into_inner();
+        assert!(!Solution::is_sub_path(head, root));
     }
 
     #[test]
     fn test_empty_list() {
         // Empty list should always return true
-        let head = build_list(&[]);
-        let root = build_tree(&[Some(1), Some(2), Some(3)]);
-        assert_eq!(Solution::is_sub_path(head, root), true);
+        let head = List::from(vec![]).into_inner();
+        let root = Tree::from(vec![Some(1), Some(2), Some(3)]).into_inner();
+        assert!(Solution::is_sub_path(head, root));
     }
 }
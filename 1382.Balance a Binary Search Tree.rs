@@ -67,37 +67,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn tree_from_vec(values: Vec<Option<i32>>) -> Option<Rc<RefCell<TreeNode>>> {
-        if values.is_empty() || values[0].is_none() {
-            return None;
-        }
-        let root = Rc::new(RefCell::new(TreeNode::new(values[0].unwrap())));
-        let mut queue = VecDeque::with_capacity(values.len());
-        queue.push_back(Rc::clone(&root));
-        let mut i = 1;
-        while !queue.is_empty() && i < values.len() {
-            let node = queue.pop_front().unwrap();
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().left = Some(Rc::clone(&left));
-                    queue.push_back(left);
-                }
-                i += 1;
-            }
-            if i < values.len() {
-                if let Some(val) = values[i] {
-                    let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                    node.borrow_mut().right = Some(Rc::clone(&right));
-                    queue.push_back(right);
-                }
-                i += 1;
-            }
-        }
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     fn height(root: &Option<Rc<RefCell<TreeNode>>>) -> i32 {
         match root {
@@ -149,7 +119,8 @@ mod tests {
     #[test]
     fn test_example1() {
         // [1,null,2,null,3,null,4] -> balanced BST with same values
-        let root = tree_from_vec(vec![Some(1), None, Some(2), None, Some(3), None, Some(4)]);
+        let root =
+            Tree::from(vec![Some(1), None, Some(2), None, Some(3), None, Some(4)]).into_inner();
         let balanced = Solution::balance_bst(root);
         let values = collect_inorder(&balanced);
         assert_eq!(values, vec![1, 2, 3, 4]);
@@ -160,7 +131,7 @@ mod tests {
     #[test]
     fn test_example2() {
         // [2,1,3] already balanced
-        let root = tree_from_vec(vec![Some(2), Some(1), Some(3)]);
+        let root = Tree::from(vec![Some(2), Some(1), Some(3)]).into_inner();
         let balanced = Solution::balance_bst(root);
         let values = collect_inorder(&balanced);
         assert_eq!(values, vec![1, 2, 3]);
@@ -170,7 +141,7 @@ mod tests {
 
     #[test]
     fn test_single_node() {
-        let root = tree_from_vec(vec![Some(1)]);
+        let root = Tree::from(vec![Some(1)]).into_inner();
         let balanced = Solution::balance_bst(root);
         assert_eq!(collect_inorder(&balanced), vec![1]);
         assert!(is_balanced(&balanced));
@@ -178,7 +149,7 @@ mod tests {
 
     #[test]
     fn test_two_nodes() {
-        let root = tree_from_vec(vec![Some(1), None, Some(2)]);
+        let root = Tree::from(vec![Some(1), None, Some(2)]).into_inner();
         let balanced = Solution::balance_bst(root);
         assert_eq!(collect_inorder(&balanced), vec![1, 2]);
         assert!(is_bst(&balanced, i64::MIN, i64::MAX));
@@ -20,8 +20,9 @@ impl Solution {
         let mut running = 0;
         nums.into_iter()
             .take_while(|&num| {
+                let taken = running;
                 running += num;
-                running <= total - running + num
+                taken <= total - taken
             })
             .collect()
     }
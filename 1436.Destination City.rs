@@ -18,9 +18,9 @@ impl Solution {
     pub fn dest_city(paths: Vec<Vec<String>>) -> String {
         let sources: HashSet<&str> = paths.iter().map(|p| p[0].as_str()).collect();
         paths
-            .into_iter()
+            .iter()
             .find(|p| !sources.contains(p[1].as_str()))
-            .and_then(|p| p.into_iter().nth(1))
+            .map(|p| p[1].clone())
             .unwrap_or_default()
     }
 }
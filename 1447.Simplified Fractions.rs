@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Enumerate all simplified fractions with denominator up to n.
     ///
@@ -14,10 +16,6 @@ impl Solution {
     /// - Time: O(n² log n) accounting for GCD computations
     /// - Space: O(n²) worst case for the result
     pub fn simplified_fractions(n: i32) -> Vec<String> {
-        fn gcd(a: i32, b: i32) -> i32 {
-            if b == 0 { a } else { gcd(b, a % b) }
-        }
-
         (2..=n)
             .flat_map(|j| {
                 (1..j)
@@ -16,7 +16,7 @@ impl Solution {
         start_time
             .iter()
             .zip(end_time.iter())
-            .filter(|(s, e)| *s <= query_time && query_time <= *e)
+            .filter(|&(&s, &e)| s <= query_time && query_time <= e)
             .count() as i32
     }
 }
@@ -59,43 +59,7 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::VecDeque;
-
-    fn build_tree(vals: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
-        if vals.is_empty() || vals[0].is_none() {
-            return None;
-        }
-
-        let root = Rc::new(RefCell::new(TreeNode::new(vals[0].unwrap())));
-        let mut queue = VecDeque::new();
-        queue.push_back(root.clone());
-
-        let mut i = 1;
-
-        while !queue.is_empty() && i < vals.len() {
-            if let Some(node) = queue.pop_front() {
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let left = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().left = Some(left.clone());
-                        queue.push_back(left);
-                    }
-                    i += 1;
-                }
-
-                if i < vals.len() {
-                    if let Some(val) = vals[i] {
-                        let right = Rc::new(RefCell::new(TreeNode::new(val)));
-                        node.borrow_mut().right = Some(right.clone());
-                        queue.push_back(right);
-                    }
-                    i += 1;
-                }
-            }
-        }
-
-        Some(root)
-    }
+    use crate::tree_node::Tree;
 
     #[test]
     fn test_example_1() {
@@ -108,7 +72,16 @@ mod tests {
         // Paths: [2,3,3], [2,3,1], [2,1,1]
         // Pseudo-palindromic: [2,3,3] (3,3,2), [2,1,1] (1,1,2)
         // Count: 2
-        let root = build_tree(&[Some(2), Some(3), Some(1), Some(3), Some(1), None, Some(1)]);
+        let root = Tree::from(vec![
+            Some(2),
+            Some(3),
+            Some(1),
+            Some(3),
+            Some(1),
+            None,
+            Some(1),
+        ])
+        .into_inner();
         assert_eq!(Solution::pseudo_palindromic_paths(root), 2);
     }
 
@@ -125,7 +98,7 @@ mod tests {
         // Paths: [2,1,1], [2,1,3,1]
         // Pseudo-palindromic: [2,1,1] (can be 1,2,1), [2,1,3,1] (can be 1,3,2,1)
         // Count: 1
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(2),
             Some(1),
             Some(1),
@@ -137,7 +110,8 @@ mod tests {
             None,
             None,
             Some(1),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::pseudo_palindromic_paths(root), 1);
     }
 
@@ -145,7 +119,7 @@ mod tests {
     fn test_single_node() {
         // Tree: [9]
         // Single node is always pseudo-palindromic
-        let root = build_tree(&[Some(9)]);
+        let root = Tree::from(vec![Some(9)]).into_inner();
         assert_eq!(Solution::pseudo_palindromic_paths(root), 1);
     }
 
@@ -158,8 +132,8 @@ mod tests {
         //    / \
         //   1   1
         // All paths have only 1s, always pseudo-palindromic
-        let root = build_tree(&[Some(1), Some(1), Some(1), Some(1), Some(1)]);
-        assert_eq!(Solution::pseudo_palindromic_paths(root), 2);
+        let root = Tree::from(vec![Some(1), Some(1), Some(1), Some(1), Some(1)]).into_inner();
+        assert_eq!(Solution::pseudo_palindromic_paths(root), 3);
     }
 
     #[test]
@@ -172,7 +146,7 @@ mod tests {
         //   4  5 6  7
         // Paths: [1,2,4], [1,2,5], [1,3,6], [1,3,7]
         // None are pseudo-palindromic (all have 3 different values)
-        let root = build_tree(&[
+        let root = Tree::from(vec![
             Some(1),
             Some(2),
             Some(3),
@@ -180,7 +154,8 @@ mod tests {
             Some(5),
             Some(6),
             Some(7),
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::pseudo_palindromic_paths(root), 0);
     }
 }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Prefix sum parity counting for odd-sum subarrays.
     ///
@@ -16,18 +18,17 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(1)
     pub fn num_of_subarrays(arr: Vec<i32>) -> i32 {
-        const MOD: i32 = 1_000_000_007;
         let mut parity_count = [1, 0];
         let mut sum = 0;
-        let mut result = 0;
+        let mut result = Mint::ZERO;
 
         for &x in &arr {
             sum += x;
-            result = (result + parity_count[((sum & 1) ^ 1) as usize]) % MOD;
+            result += parity_count[((sum & 1) ^ 1) as usize];
             parity_count[(sum & 1) as usize] += 1;
         }
 
-        result
+        result.value() as i32
     }
 }
 
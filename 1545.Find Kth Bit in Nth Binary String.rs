@@ -69,8 +69,8 @@ mod tests {
 
     #[test]
     fn test_first_and_last_bits() {
-        // For any n, first bit is always '0' and last bit is always '1'
-        for n in 1..=5 {
+        // For any n > 1, first bit is always '0' and last bit is always '1'
+        for n in 2..=5 {
             let len = (1 << n) - 1;
             assert_eq!(Solution::find_kth_bit(n, 1), '0');
             assert_eq!(Solution::find_kth_bit(n, len), '1');
@@ -79,8 +79,8 @@ mod tests {
 
     #[test]
     fn test_middle_bits() {
-        // Middle bit (2^(n-1)) is always '1'
-        for n in 1..=5 {
+        // Middle bit (2^(n-1)) is always '1' once S_n has been inverted once
+        for n in 2..=5 {
             let mid = 1 << (n - 1);
             assert_eq!(Solution::find_kth_bit(n, mid), '1');
         }
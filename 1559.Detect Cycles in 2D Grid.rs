@@ -1,42 +1,4 @@
-struct UnionFind {
-    parent: Vec<usize>,
-    size: Vec<usize>,
-}
-
-impl UnionFind {
-    fn new(n: usize) -> Self {
-        let mut parent = Vec::with_capacity(n);
-        parent.extend(0..n);
-        Self {
-            parent,
-            size: vec![1; n],
-        }
-    }
-
-    fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    /// Returns `true` when `a` and `b` were merged, `false` when they already
-    /// shared a root (i.e. adding this edge closes a cycle).
-    fn union(&mut self, a: usize, b: usize) -> bool {
-        let (ra, rb) = (self.find(a), self.find(b));
-        if ra == rb {
-            return false;
-        }
-        if self.size[ra] >= self.size[rb] {
-            self.parent[rb] = ra;
-            self.size[ra] += self.size[rb];
-        } else {
-            self.parent[ra] = rb;
-            self.size[rb] += self.size[ra];
-        }
-        true
-    }
-}
+use crate::union_find::UnionFind;
 
 impl Solution {
     /// Union-Find cycle detection on a 2D grid of same-character regions.
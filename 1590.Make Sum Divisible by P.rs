@@ -57,9 +57,14 @@ mod tests {
         assert_eq!(Solution::min_subarray(vec![3, 1, 4, 2], 6), 1);
     }
 
+    #[test]
+    fn remove_middle_pair() {
+        assert_eq!(Solution::min_subarray(vec![6, 3, 5, 2], 9), 2);
+    }
+
     #[test]
     fn already_divisible() {
-        assert_eq!(Solution::min_subarray(vec![6, 3, 5, 2], 9), 0);
+        assert_eq!(Solution::min_subarray(vec![1, 2, 3], 3), 0);
     }
 
     #[test]
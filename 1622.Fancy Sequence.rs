@@ -1,4 +1,4 @@
-const MOD: i64 = 1_000_000_007;
+use crate::mod_int::Mint;
 
 /// Fancy sequence with lazy propagation of add/mult operations.
 ///
@@ -21,84 +21,53 @@ const MOD: i64 = 1_000_000_007;
 /// - getIndex: O(1)
 /// - Space: O(n)
 struct Fancy {
-    data: Vec<i64>, // stored original values
-    add: i64,       // cumulative addition
-    mult: i64,      // cumulative multiplication
+    data: Vec<Mint>, // stored original values
+    add: Mint,       // cumulative addition
+    mult: Mint,      // cumulative multiplication
 }
 
 impl Fancy {
     fn new() -> Self {
         Fancy {
             data: Vec::new(),
-            add: 0,
-            mult: 1,
+            add: Mint::ZERO,
+            mult: Mint::ONE,
         }
     }
 
-    /// Modular exponentiation: base^exp mod MOD
-    fn mod_pow(base: i64, exp: i64) -> i64 {
-        fn mod_mul(a: i64, b: i64) -> i64 {
-            ((a as u128 * b as u128) % (MOD as u128)) as i64
-        }
-
-        let mut result: i64 = 1;
-        let mut b = (base % MOD + MOD) % MOD;
-        let mut e = exp;
-
-        while e > 0 {
-            if e & 1 == 1 {
-                result = mod_mul(result, b);
-            }
-            b = mod_mul(b, b);
-            e >>= 1;
-        }
-        result
-    }
-
-    /// Modular inverse using Fermat's little theorem: x^(-1) ≡ x^(MOD-2) mod MOD
-    fn mod_inv(x: i64) -> i64 {
-        Self::mod_pow(x, MOD - 2)
-    }
-
     /// Appends an integer val to the end of the sequence.
     ///
     /// To store the value correctly for lazy operations, we reverse-transform:
     /// stored = (val - add) * inv(mult) mod MOD
     /// This ensures getIndex returns: stored * mult + add = val
     fn append(&mut self, val: i32) {
-        let val = val as i64;
-        // Reverse the cumulative operations to store original value
-        let adjusted = ((val - self.add) % MOD + MOD) % MOD;
-        let stored = ((adjusted as u128 * Self::mod_inv(self.mult) as u128) % (MOD as u128)) as i64;
-        self.data.push(stored);
+        self.data.push((Mint::from(val) - self.add) / self.mult);
     }
 
     /// Increments all existing values in the sequence by inc.
     fn add_all(&mut self, inc: i32) {
-        self.add = (self.add + inc as i64) % MOD;
+        self.add += inc;
     }
 
     /// Multiplies all existing values in the sequence by m.
     fn mult_all(&mut self, m: i32) {
-        let m = m as i64;
-        self.add = ((self.add as u128 * m as u128) % (MOD as u128)) as i64;
-        self.mult = ((self.mult as u128 * m as u128) % (MOD as u128)) as i64;
+        self.add *= m;
+        self.mult *= m;
     }
 
     /// Gets the current value at index idx. Returns -1 if idx is out of bounds.
     fn get_index(&self, idx: i32) -> i32 {
-        if idx < 0 || idx as usize >= self.data.len() {
-            return -1;
+        match usize::try_from(idx).ok().and_then(|i| self.data.get(i)) {
+            Some(&stored) => (stored * self.mult + self.add).value() as i32,
+            None => -1,
         }
-        let val = ((self.data[idx as usize] as u128 * self.mult as u128 + self.add as u128)
-            % (MOD as u128)) as i64;
-        val as i32
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mod_int::MOD;
 
     #[test]
     fn test_example() {
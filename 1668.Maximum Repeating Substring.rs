@@ -23,7 +23,7 @@ impl Solution {
 
         let mut dp = vec![0i32; n - m + 1];
         for i in 0..=n - m {
-            if &sequence[i..i + m] == word {
+            if sequence[i..i + m] == word {
                 dp[i] = if i >= m { dp[i - m] } else { 0 } + 1;
             }
         }
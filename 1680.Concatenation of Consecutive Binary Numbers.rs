@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Build the concatenated value using modular left shifts.
     ///
@@ -15,20 +17,19 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(1)
     pub fn concatenated_binary(n: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
-        (1_i64..=n as i64)
-            .fold((0_i64, 0_u32), |(ans, bit_len), value| {
+        (1_u64..=n as u64)
+            .fold((Mint::ZERO, 0_u32), |(ans, bit_len), value| {
                 let next_bit_len = if value & (value - 1) == 0 {
                     bit_len + 1
                 } else {
                     bit_len
                 };
 
-                let next_ans = ((ans << next_bit_len) + value) % MOD;
+                let next_ans = ans * (1_u64 << next_bit_len) + value;
                 (next_ans, next_bit_len)
             })
-            .0 as i32
+            .0
+            .value() as i32
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts homogenous substrings by tracking consecutive character runs.
     ///
@@ -15,17 +17,16 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(1)
     pub fn count_homogenous(s: String) -> i32 {
-        const MOD: usize = 1_000_000_007;
         let bytes = s.as_bytes();
         let mut start = 0;
-        let mut ans = 0usize;
+        let mut ans = Mint::ZERO;
         for j in 0..bytes.len() {
             if bytes[j] != bytes[start] {
                 start = j;
             }
-            ans = (ans + j - start + 1) % MOD;
+            ans += j - start + 1;
         }
-        ans as i32
+        ans.value() as i32
     }
 }
 
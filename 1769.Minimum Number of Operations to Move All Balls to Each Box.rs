@@ -62,7 +62,7 @@ mod tests {
     fn test_example_two() {
         assert_eq!(
             Solution::min_operations("001011".to_string()),
-            vec![11, 8, 5, 4, 3, 1]
+            vec![11, 8, 5, 4, 3, 4]
         );
     }
 }
@@ -23,7 +23,7 @@ mod tests {
 
     #[test]
     fn test_single_segment() {
-        assert!(Solution::check_ones_segment("1001".to_string()) == false);
+        assert!(!Solution::check_ones_segment("1001".to_string()));
     }
 
     #[test]
@@ -24,12 +24,12 @@ mod tests {
 
     #[test]
     fn test_white_square() {
-        assert!(Solution::square_is_white("a1".to_string()));
+        assert!(Solution::square_is_white("a2".to_string()));
     }
 
     #[test]
     fn test_black_square() {
-        assert!(!Solution::square_is_white("a2".to_string()));
+        assert!(!Solution::square_is_white("a1".to_string()));
     }
 
     #[test]
@@ -93,10 +93,9 @@ mod tests {
 
     #[test]
     fn test_single_tight_restriction_at_end() {
-        // n=5, restriction [5,0] forces all to be 0 except building 1 which is already 0
-        // Possible: [0,0,0,0,0] → max = 0? No: restriction says ≤0 and adjacency forces descent.
-        // Actually [0,0,0,0,0] is valid → max = 0
-        assert_eq!(Solution::max_building(5, vec![vec![5, 0]]), 0);
+        // n=5, restriction [5,0]: heights rise from building 1 and fall back to 0
+        // Best: [0,1,2,1,0] → max = 2
+        assert_eq!(Solution::max_building(5, vec![vec![5, 0]]), 2);
     }
 
     #[test]
@@ -1,43 +1,4 @@
-/// Trie node for prefix-complete word search.
-struct Trie {
-    children: [Option<Box<Trie>>; 26],
-    is_end: bool,
-}
-
-impl Trie {
-    fn new() -> Self {
-        Self {
-            children: Default::default(),
-            is_end: false,
-        }
-    }
-
-    fn insert(&mut self, word: &str) {
-        let mut node = self;
-        for b in word.bytes() {
-            let idx = (b - b'a') as usize;
-            node = node.children[idx].get_or_insert_with(|| Box::new(Trie::new()));
-        }
-        node.is_end = true;
-    }
-
-    fn all_prefixes_exist(&self, word: &str) -> bool {
-        let mut node = self;
-        for b in word.bytes() {
-            let idx = (b - b'a') as usize;
-            match &node.children[idx] {
-                Some(child) => {
-                    node = child.as_ref();
-                    if !node.is_end {
-                        return false;
-                    }
-                }
-                None => return false,
-            }
-        }
-        true
-    }
-}
+use crate::trie::{Lowercase, Trie};
 
 impl Solution {
     /// Finds the longest word where every prefix is also in the dictionary.
@@ -56,14 +17,20 @@ impl Solution {
     /// - Time: O(n * L) where L is average word length
     /// - Space: O(n * L)
     pub fn longest_word(words: Vec<String>) -> String {
-        let mut trie = Trie::new();
+        let mut trie: Trie<Lowercase> = Trie::new();
         for word in &words {
-            trie.insert(word);
+            trie.insert(word.bytes());
         }
+        let all_prefixes_exist = |word: &str| {
+            trie.path(word.bytes())
+                .take_while(|&node| trie.ends(node) > 0)
+                .count()
+                == word.len()
+        };
 
         let mut best_idx: Option<usize> = None;
         for (idx, word) in words.iter().enumerate() {
-            if !trie.all_prefixes_exist(word) {
+            if !all_prefixes_exist(word) {
                 continue;
             }
 
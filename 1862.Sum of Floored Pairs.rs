@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Computes the sum of floor(nums[i] / nums[j]) for all pairs.
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(max_val * H(max_val)) ≈ O(max_val * log(max_val))
     /// - Space: O(max_val)
     pub fn sum_of_floored_pairs(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let max_val = *nums.iter().max().unwrap() as usize;
 
         let mut count = vec![0i64; max_val + 1];
@@ -29,20 +30,19 @@ impl Solution {
             prefix[i] = prefix[i - 1] + count[i];
         }
 
-        let mut result = 0i64;
+        let mut result = Mint::ZERO;
         for y in 1..=max_val {
             if count[y] > 0 {
                 let mut d = 1;
                 while d * y <= max_val {
                     let hi = (d * y + y - 1).min(max_val);
-                    result =
-                        (result + count[y] * d as i64 * (prefix[hi] - prefix[d * y - 1])) % MOD;
+                    result += count[y] * d as i64 * (prefix[hi] - prefix[d * y - 1]);
                     d += 1;
                 }
             }
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -35,7 +35,7 @@ impl Solution {
 
         let (mut lo, mut hi) = (0, removable.len());
         while lo < hi {
-            let mid = lo + (hi - lo + 1) / 2;
+            let mid = lo + (hi - lo).div_ceil(2);
             if is_subsequence(mid) {
                 lo = mid;
             } else {
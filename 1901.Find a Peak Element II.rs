@@ -52,7 +52,7 @@ mod tests {
     fn test_basic() {
         let result = Solution::find_peak_grid(vec![vec![1, 4], vec![3, 2]]);
         let (r, c) = (result[0] as usize, result[1] as usize);
-        let mat = vec![vec![1, 4], vec![3, 2]];
+        let mat = [vec![1, 4], vec![3, 2]];
         let val = mat[r][c];
         assert!(r == 0 || val > mat[r - 1][c]);
         assert!(r == mat.len() - 1 || val > mat[r + 1][c]);
@@ -16,7 +16,7 @@ impl Solution {
     /// - Space: O(n)
     pub fn min_operations(nums: Vec<i32>) -> i32 {
         let n = nums.len();
-        let mut sorted: Vec<i32> = {
+        let sorted: Vec<i32> = {
             let mut set = std::collections::BTreeSet::new();
             for &x in &nums {
                 set.insert(x);
@@ -45,7 +45,7 @@ mod tests {
 
     #[test]
     fn test_infeasible() {
-        assert_eq!(Solution::missing_rolls(vec![1, 5, 6], 3, 4), vec![]);
+        assert_eq!(Solution::missing_rolls(vec![1, 2, 3, 4], 6, 4), vec![]);
     }
 
     #[test]
@@ -25,6 +25,7 @@ impl Robot {
     /// Ring of length `2*(w + h - 2)` split into four segments:
     /// - `[0, w)` East, `[w, w+h-1)` North, `[w+h-1, 2w+h-2)` West,
     ///   `[2w+h-2, perimeter)` South.
+    ///
     /// Position 0 after moving means the robot completed a full lap (South).
     ///
     /// # Complexity
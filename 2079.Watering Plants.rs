@@ -46,6 +46,6 @@ mod tests {
 
     #[test]
     fn test_refill_every_time() {
-        assert_eq!(Solution::watering_plants(vec![7, 7, 7, 7], 8), 30);
+        assert_eq!(Solution::watering_plants(vec![7, 7, 7, 7], 8), 16);
     }
 }
@@ -63,30 +63,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
-
-    fn list_to_vec(mut head: Option<Box<ListNode>>) -> Vec<i32> {
-        let mut result = Vec::new();
-        while let Some(node) = head {
-            result.push(node.val);
-            head = node.next;
-        }
-        result
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_example1_seven_nodes() {
         // [1,3,4,7,1,2,6] → remove index 3 (value 7) → [1,3,4,1,2,6]
-        let head = vec_to_list(vec![1, 3, 4, 7, 1, 2, 6]);
+        let head = List::from(vec![1, 3, 4, 7, 1, 2, 6]).into_inner();
         assert_eq!(
-            list_to_vec(Solution::delete_middle(head)),
+            List::from(Solution::delete_middle(head)).to_vec(),
             vec![1, 3, 4, 1, 2, 6]
         );
     }
@@ -94,44 +78,53 @@ mod tests {
     #[test]
     fn test_example2_four_nodes() {
         // [1,2,3,4] → remove index 2 (value 3) → [1,2,4]
-        let head = vec_to_list(vec![1, 2, 3, 4]);
-        assert_eq!(list_to_vec(Solution::delete_middle(head)), vec![1, 2, 4]);
+        let head = List::from(vec![1, 2, 3, 4]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_middle(head)).to_vec(),
+            vec![1, 2, 4]
+        );
     }
 
     #[test]
     fn test_example3_two_nodes() {
         // [2,1] → remove index 1 (value 1) → [2]
-        let head = vec_to_list(vec![2, 1]);
-        assert_eq!(list_to_vec(Solution::delete_middle(head)), vec![2]);
+        let head = List::from(vec![2, 1]).into_inner();
+        assert_eq!(List::from(Solution::delete_middle(head)).to_vec(), vec![2]);
     }
 
     #[test]
     fn test_single_node() {
         // n=1: the only node is the middle; result is empty.
-        let head = vec_to_list(vec![5]);
-        assert_eq!(list_to_vec(Solution::delete_middle(head)), vec![]);
+        let head = List::from(vec![5]).into_inner();
+        assert_eq!(List::from(Solution::delete_middle(head)).to_vec(), vec![]);
     }
 
     #[test]
     fn test_three_nodes() {
         // [1,2,3] → remove index 1 (value 2) → [1,3]
-        let head = vec_to_list(vec![1, 2, 3]);
-        assert_eq!(list_to_vec(Solution::delete_middle(head)), vec![1, 3]);
+        let head = List::from(vec![1, 2, 3]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_middle(head)).to_vec(),
+            vec![1, 3]
+        );
     }
 
     #[test]
     fn test_five_nodes() {
         // [1,2,3,4,5] → remove index 2 (value 3) → [1,2,4,5]
-        let head = vec_to_list(vec![1, 2, 3, 4, 5]);
-        assert_eq!(list_to_vec(Solution::delete_middle(head)), vec![1, 2, 4, 5]);
+        let head = List::from(vec![1, 2, 3, 4, 5]).into_inner();
+        assert_eq!(
+            List::from(Solution::delete_middle(head)).to_vec(),
+            vec![1, 2, 4, 5]
+        );
     }
 
     #[test]
     fn test_six_nodes() {
         // [1,2,3,4,5,6] → remove index 3 (value 4) → [1,2,3,5,6]
-        let head = vec_to_list(vec![1, 2, 3, 4, 5, 6]);
+        let head = List::from(vec![1, 2, 3, 4, 5, 6]).into_inner();
         assert_eq!(
-            list_to_vec(Solution::delete_middle(head)),
+            List::from(Solution::delete_middle(head)).to_vec(),
             vec![1, 2, 3, 5, 6]
         );
     }
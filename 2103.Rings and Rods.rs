@@ -43,7 +43,7 @@ mod tests {
 
     #[test]
     fn test_multiple_rods() {
-        assert_eq!(Solution::count_points("B0B6G0R6R0R6G6".to_string()), 1);
+        assert_eq!(Solution::count_points("B0B6G0R6R0R6G9".to_string()), 1);
     }
 
     #[test]
@@ -51,21 +51,14 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    fn vec_to_list(vec: Vec<i32>) -> Option<Box<ListNode>> {
-        let mut head = None;
-        for &val in vec.iter().rev() {
-            head = Some(Box::new(ListNode { val, next: head }));
-        }
-        head
-    }
+    use crate::list_node::List;
 
     #[test]
     fn test_pair_sum_example1() {
         // Input: head = [5,4,2,1]
         // Output: 6
         // Explanation: Twins are (5,1)=6 and (4,2)=6. Maximum is 6.
-        let head = vec_to_list(vec![5, 4, 2, 1]);
+        let head = List::from(vec![5, 4, 2, 1]).into_inner();
         assert_eq!(Solution::pair_sum(head), 6);
     }
 
@@ -74,7 +67,7 @@ mod tests {
         // Input: head = [4,2,2,3]
         // Output: 7
         // Explanation: Twins are (4,3)=7 and (2,2)=4. Maximum is 7.
-        let head = vec_to_list(vec![4, 2, 2, 3]);
+        let head = List::from(vec![4, 2, 2, 3]).into_inner();
         assert_eq!(Solution::pair_sum(head), 7);
     }
 
@@ -83,7 +76,7 @@ mod tests {
         // Input: head = [1,100000]
         // Output: 100001
         // Explanation: Only one twin pair (1,100000)=100001
-        let head = vec_to_list(vec![1, 100000]);
+        let head = List::from(vec![1, 100000]).into_inner();
         assert_eq!(Solution::pair_sum(head), 100001);
     }
 
@@ -92,7 +85,7 @@ mod tests {
         // Input: head = [5,5,5,5]
         // Output: 10
         // Explanation: All twin pairs sum to 10
-        let head = vec_to_list(vec![5, 5, 5, 5]);
+        let head = List::from(vec![5, 5, 5, 5]).into_inner();
         assert_eq!(Solution::pair_sum(head), 10);
     }
 
@@ -101,7 +94,7 @@ mod tests {
         // Input: head = [1,2,3,4,5,6]
         // Output: 7
         // Explanation: Twins are (1,6)=7, (2,5)=7, (3,4)=7. Maximum is 7.
-        let head = vec_to_list(vec![1, 2, 3, 4, 5, 6]);
+        let head = List::from(vec![1, 2, 3, 4, 5, 6]).into_inner();
         assert_eq!(Solution::pair_sum(head), 7);
     }
 
@@ -109,10 +102,11 @@ mod tests {
     fn test_pair_sum_large_values() {
         // Input: head = [47,22,81,46,94,95,90,22,55,91,6,83,49,65,10,32,41,26,83,99,14,85,42,99]
         // Output: 182
-        let head = vec_to_list(vec![
+        let head = List::from(vec![
             47, 22, 81, 46, 94, 95, 90, 22, 55, 91, 6, 83, 49, 65, 10, 32, 41, 26, 83, 99, 14, 85,
             42, 99,
-        ]);
+        ])
+        .into_inner();
         assert_eq!(Solution::pair_sum(head), 193);
     }
 }
@@ -32,11 +32,11 @@ impl Solution {
         }
 
         let mut result = 0i64;
-        if count[0] > 0 {
-            if let Some(first) = (1..10).find(|&d| count[d] > 0) {
-                count[first] -= 1;
-                result = first as i64;
-            }
+        if count[0] > 0
+            && let Some(first) = (1..10).find(|&d| count[d] > 0)
+        {
+            count[first] -= 1;
+            result = first as i64;
         }
 
         (0..10).fold(result, |acc, d| {
@@ -13,10 +13,11 @@ impl Solution {
     pub fn count_pairs(nums: Vec<i32>, k: i32) -> i32 {
         (1..nums.len())
             .flat_map(|j| {
+                let nums = &nums;
                 nums[..j]
                     .iter()
                     .enumerate()
-                    .filter(move |(i, x)| *x == nums[j] && (i * j) as i32 % k == 0)
+                    .filter(move |&(i, &x)| x == nums[j] && (i * j) as i32 % k == 0)
             })
             .count() as i32
     }
@@ -1,3 +1,5 @@
+use crate::graph::Graph;
+
 impl Solution {
     /// Find the minimum weight subgraph containing paths from src1 and src2 to dest.
     ///
@@ -6,7 +8,7 @@ impl Solution {
     /// then sharing a path from m to dest, minimizing total weight.
     ///
     /// # Approach
-    /// 1. Build CSR (Compressed Sparse Row) format graphs for cache efficiency.
+    /// 1. Build the directed graph in CSR (Compressed Sparse Row) form, and its reverse.
     /// 2. Run Dijkstra from src1, src2 on the forward graph, and from dest on the reverse graph.
     /// 3. Find the minimum of dist(src1,m) + dist(src2,m) + dist(m,dest) over all nodes m.
     ///
@@ -14,77 +16,21 @@ impl Solution {
     /// - Time: O(E log V)
     /// - Space: O(V + E)
     pub fn minimum_weight(n: i32, edges: Vec<Vec<i32>>, src1: i32, src2: i32, dest: i32) -> i64 {
-        use std::cmp::Reverse;
-        use std::collections::BinaryHeap;
-
         let n = n as usize;
-        let m = edges.len();
-
-        let mut fwd_deg = vec![0u32; n + 1];
-        let mut rev_deg = vec![0u32; n + 1];
-
-        for e in &edges {
-            fwd_deg[e[0] as usize + 1] += 1;
-            rev_deg[e[1] as usize + 1] += 1;
-        }
-
-        for i in 1..=n {
-            fwd_deg[i] += fwd_deg[i - 1];
-            rev_deg[i] += rev_deg[i - 1];
-        }
-
-        let mut fwd_edges = vec![(0u32, 0u32); m];
-        let mut rev_edges = vec![(0u32, 0u32); m];
-        let mut fwd_idx = fwd_deg.clone();
-        let mut rev_idx = rev_deg.clone();
-
-        for e in &edges {
-            let (u, v, w) = (e[0] as usize, e[1] as u32, e[2] as u32);
-            let i = fwd_idx[u] as usize;
-            fwd_edges[i] = (v, w);
-            fwd_idx[u] += 1;
-
-            let j = rev_idx[v as usize] as usize;
-            rev_edges[j] = (u as u32, w);
-            rev_idx[v as usize] += 1;
-        }
-
-        let dijkstra = |offsets: &[u32], edge_list: &[(u32, u32)], start: usize| -> Vec<u64> {
-            let mut dist = vec![u64::MAX; n];
-            let mut heap = BinaryHeap::new();
-
-            dist[start] = 0;
-            heap.push(Reverse((0u64, start as u32)));
-
-            while let Some(Reverse((d, u))) = heap.pop() {
-                let u = u as usize;
-                if d > dist[u] {
-                    continue;
-                }
-
-                let (lo, hi) = (offsets[u] as usize, offsets[u + 1] as usize);
-                for &(v, w) in &edge_list[lo..hi] {
-                    let nd = d + w as u64;
-                    if nd < dist[v as usize] {
-                        dist[v as usize] = nd;
-                        heap.push(Reverse((nd, v)));
-                    }
-                }
-            }
-            dist
-        };
-
-        let d1 = dijkstra(&fwd_deg, &fwd_edges, src1 as usize);
-        let d2 = dijkstra(&fwd_deg, &fwd_edges, src2 as usize);
-        let d3 = dijkstra(&rev_deg, &rev_edges, dest as usize);
+        let graph = Graph::directed(
+            n,
+            edges
+                .iter()
+                .map(|e| (e[0] as usize, e[1] as usize, e[2] as i64)),
+        );
+        let d1 = graph.dijkstra(src1 as usize);
+        let d2 = graph.dijkstra(src2 as usize);
+        let d3 = graph.reversed().dijkstra(dest as usize);
 
-        let ans = (0..n)
-            .filter(|&i| d1[i] < u64::MAX && d2[i] < u64::MAX && d3[i] < u64::MAX)
-            .map(|i| d1[i] + d2[i] + d3[i])
+        (0..n)
+            .filter_map(|m| Some(d1.dist(m)? + d2.dist(m)? + d3.dist(m)?))
             .min()
-            .unwrap_or(u64::MAX);
-
-        if ans == u64::MAX { -1 } else { ans as i64 }
+            .unwrap_or(-1)
     }
 }
 
@@ -34,7 +34,7 @@ impl Solution {
     /// - Time: O(1)
     /// - Space: O(1)
     pub fn check_tree(root: Option<Rc<RefCell<TreeNode>>>) -> bool {
-        root.as_ref().map_or(false, |node| {
+        root.as_ref().is_some_and(|node| {
             let node = node.borrow();
             let left_val = node.left.as_ref().map_or(0, |left| left.borrow().val);
             let right_val = node.right.as_ref().map_or(0, |right| right.borrow().val);
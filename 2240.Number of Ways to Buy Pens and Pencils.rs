@@ -40,6 +40,6 @@ mod tests {
 
     #[test]
     fn test_equal_costs() {
-        assert_eq!(Solution::ways_to_buy_pens_pencils(4, 2, 2), 5);
+        assert_eq!(Solution::ways_to_buy_pens_pencils(4, 2, 2), 6);
     }
 }
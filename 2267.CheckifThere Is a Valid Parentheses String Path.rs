@@ -19,7 +19,7 @@ impl Solution {
         let n = grid[0].len();
 
         // Path length is m + n - 1, must be even for valid parentheses
-        if (m + n) % 2 == 0 {
+        if (m + n).is_multiple_of(2) {
             return false;
         }
 
@@ -1,55 +1,49 @@
-use std::collections::BTreeMap;
+use crate::interval_set::IntervalSet;
+use std::ops::Bound::{self, Included};
 
 struct CountIntervals {
-    intervals: BTreeMap<i32, i32>,
+    intervals: IntervalSet<i32>,
     count: i32,
 }
 
+/// Number of integers in a closed interval.
+fn span(lo: Bound<&i32>, hi: Bound<&i32>) -> i32 {
+    match (lo, hi) {
+        (Included(lo), Included(hi)) => hi - lo + 1,
+        _ => unreachable!("only closed intervals are inserted"),
+    }
+}
+
 impl CountIntervals {
     /// Count Integers in Intervals
     ///
     /// # Intuition
-    /// Maintain non-overlapping intervals in a BTreeMap, merging on insertion.
-    /// Track running count to avoid O(n) recalculation.
+    /// Maintain non-overlapping intervals in an interval set, merging on
+    /// insertion. Track running count to avoid O(n) recalculation.
     ///
     /// # Approach
-    /// Use `BTreeMap<i32, i32>` keyed by left endpoint. When adding [left, right]:
-    /// 1. Iterate backwards from intervals with l <= new_right
-    /// 2. Stop early when r < new_left (no more overlaps possible)
-    /// 3. Remove overlapping intervals in-place, updating bounds
-    /// 4. Insert merged interval
+    /// Insert each `[left, right]` into an `IntervalSet`, which coalesces it
+    /// with every interval it overlaps and hands those back. The count grows
+    /// by the size of the merged interval minus the sizes of the absorbed
+    /// ones.
     ///
     /// # Complexity
     /// - Time: O(k log n) per add where k = overlapping intervals, O(1) for count
     /// - Space: O(n) where n = number of non-overlapping intervals
     fn new() -> Self {
         Self {
-            intervals: BTreeMap::new(),
+            intervals: IntervalSet::new(),
             count: 0,
         }
     }
 
     fn add(&mut self, left: i32, right: i32) {
-        let mut new_left = left;
-        let mut new_right = right;
-
-        // Iterate backwards from the largest l <= new_right
-        // Remove overlapping intervals in-place for efficiency
-        while let Some((&l, &r)) = self.intervals.range(..=new_right).next_back() {
-            if r < new_left {
-                // No overlap; all earlier intervals also won't overlap
-                break;
-            }
-            // Merge this overlapping interval
-            self.count -= r - l + 1;
-            new_left = new_left.min(l);
-            new_right = new_right.max(r);
-            self.intervals.remove(&l);
+        let absorbed = self.intervals.insert(left..=right);
+        let (lo, hi) = self.intervals.get(&left).unwrap();
+        self.count += span(lo, hi);
+        for (lo, hi) in &absorbed {
+            self.count -= span(lo.as_ref(), hi.as_ref());
         }
-
-        // Insert the merged interval
-        self.intervals.insert(new_left, new_right);
-        self.count += new_right - new_left + 1;
     }
 
     fn count(&self) -> i32 {
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Sum of Total Strength of Wizards
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(n) - single pass for monotonic stack, single pass for contributions
     /// - Space: O(n) - for prefix arrays and stack
     pub fn total_strength(strength: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let n = strength.len();
 
         if n == 0 {
@@ -49,19 +50,19 @@ impl Solution {
 
         // Build prefix sum array: prefix[i] = sum of strength[0..i]
         // prefix has length n+1, prefix[0] = 0
-        let mut prefix = vec![0i64; n + 1];
+        let mut prefix = vec![Mint::ZERO; n + 1];
         for i in 0..n {
-            prefix[i + 1] = (prefix[i] + strength[i] as i64) % MOD;
+            prefix[i + 1] = prefix[i] + strength[i];
         }
 
         // Build prefix sum of prefix sums: prefix_prefix[i] = sum of prefix[0..i]
         // prefix_prefix has length n+2, prefix_prefix[0] = 0
-        let mut prefix_prefix = vec![0i64; n + 2];
+        let mut prefix_prefix = vec![Mint::ZERO; n + 2];
         for i in 0..=n {
-            prefix_prefix[i + 1] = (prefix_prefix[i] + prefix[i]) % MOD;
+            prefix_prefix[i + 1] = prefix_prefix[i] + prefix[i];
         }
 
-        let mut result = 0i64;
+        let mut result = Mint::ZERO;
 
         // For each element, compute its contribution as the minimum
         for i in 0..n {
@@ -75,25 +76,23 @@ impl Solution {
             let right_count = r - i as i64;
 
             // Sum of prefix[i+1..=r] = prefix_prefix[r+1] - prefix_prefix[i+1]
-            let right_prefix_sum =
-                (prefix_prefix[(r + 1) as usize] - prefix_prefix[i + 1] + MOD) % MOD;
+            let right_prefix_sum = prefix_prefix[(r + 1) as usize] - prefix_prefix[i + 1];
 
             // Sum of prefix[l+1..=i] = prefix_prefix[i+1] - prefix_prefix[(l+1)]
-            let left_prefix_sum =
-                (prefix_prefix[i + 1] - prefix_prefix[(l + 1) as usize] + MOD) % MOD;
+            let left_prefix_sum = prefix_prefix[i + 1] - prefix_prefix[(l + 1) as usize];
 
             // Contribution formula:
             // strength[i] * (left_count * right_prefix_sum - right_count * left_prefix_sum)
-            let positive_term = (left_count % MOD * right_prefix_sum) % MOD;
-            let negative_term = (right_count % MOD * left_prefix_sum) % MOD;
+            let positive_term = right_prefix_sum * left_count;
+            let negative_term = left_prefix_sum * right_count;
 
-            let subarray_sum_contribution = (positive_term - negative_term % MOD + MOD) % MOD;
-            let contribution = (strength[i] as i64 * subarray_sum_contribution) % MOD;
+            let subarray_sum_contribution = positive_term - negative_term;
+            let contribution = subarray_sum_contribution * strength[i];
 
-            result = (result + contribution) % MOD;
+            result += contribution;
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -18,7 +18,7 @@ impl Solution {
             .fold((0.0f64, 0i32), |(tax, prev), bracket| {
                 let upper = bracket[0];
                 let rate = bracket[1];
-                let taxable = income.min(upper) - prev;
+                let taxable = (income.min(upper) - prev).max(0);
                 (tax + f64::from(taxable) * f64::from(rate) * 0.01, upper)
             })
             .0
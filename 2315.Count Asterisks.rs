@@ -32,7 +32,7 @@ mod tests {
     fn test_basic_with_segments() {
         assert_eq!(
             Solution::count_asterisks("l]|**et*|c]od*e|*".to_string()),
-            2
+            1
         );
     }
 
@@ -52,14 +52,13 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_two_components() {
+    fn test_triangle_with_isolated_nodes() {
         let edges = vec![vec![0, 1], vec![0, 2], vec![1, 2]];
-        assert_eq!(Solution::count_pairs(7, edges), 14);
+        assert_eq!(Solution::count_pairs(7, edges), 18);
     }
 
     #[test]
     fn test_fully_connected() {
-        let edges = vec![vec![0, 2], vec![0, 5], vec![2, 4], vec![1, 6], vec![5, 4]];
         assert_eq!(
             Solution::count_pairs(3, vec![vec![0, 1], vec![0, 2], vec![1, 2]]),
             0
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts distinct dice roll sequences with GCD and gap constraints.
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(n * 6³) = O(n)
     /// - Space: O(6²) = O(1)
     pub fn distinct_sequences(n: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         const VALID_NEXT: [&[usize]; 6] = [
             &[1, 2, 3, 4, 5], // From 1: can go to 2,3,4,5,6
             &[0, 2, 4],       // From 2: can go to 1,3,5
@@ -33,31 +34,31 @@ impl Solution {
 
         // dp[prev][curr] = count of sequences ending with (prev+1, curr+1)
         // Index 6 is sentinel for "no previous roll"
-        let mut dp = [[0i64; 6]; 7];
+        let mut dp = [[Mint::ZERO; 6]; 7];
         for curr in 0..6 {
-            dp[6][curr] = 1;
+            dp[6][curr] = Mint::ONE;
         }
 
         // Build sequences of length 2
-        let mut new_dp = [[0i64; 6]; 7];
+        let mut new_dp = [[Mint::ZERO; 6]; 7];
         for curr in 0..6 {
             for &next in VALID_NEXT[curr] {
-                new_dp[curr][next] = (new_dp[curr][next] + dp[6][curr]) % MOD;
+                new_dp[curr][next] += dp[6][curr];
             }
         }
         dp = new_dp;
 
         // Build sequences of length 3..=n
         for _ in 3..=n {
-            new_dp = [[0i64; 6]; 7];
+            new_dp = [[Mint::ZERO; 6]; 7];
             for prev in 0..6 {
                 for curr in 0..6 {
-                    if dp[prev][curr] == 0 {
+                    if dp[prev][curr] == Mint::ZERO {
                         continue;
                     }
                     for &next in VALID_NEXT[curr] {
                         if next != prev {
-                            new_dp[curr][next] = (new_dp[curr][next] + dp[prev][curr]) % MOD;
+                            new_dp[curr][next] += dp[prev][curr];
                         }
                     }
                 }
@@ -65,9 +66,7 @@ impl Solution {
             dp = new_dp;
         }
 
-        dp.iter()
-            .flat_map(|row| row.iter())
-            .fold(0i64, |acc, &v| (acc + v) % MOD) as i32
+        dp.iter().flatten().sum::<Mint>().value() as i32
     }
 }
 
@@ -56,7 +56,7 @@ mod tests {
 
     #[test]
     fn test_invalid_nonzero_off_diagonal() {
-        let grid = vec![vec![1, 1], vec![1, 1]];
+        let grid = vec![vec![1, 0, 1], vec![0, 1, 0], vec![1, 1, 1]];
         assert!(!Solution::check_x_matrix(grid));
     }
 
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts all strictly increasing paths in a grid with 4-directional movement.
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(m * n * log(m * n)) for sorting, O(m * n) for DP traversal
     /// - Space: O(m * n) for dp array and sorted cells vector
     pub fn count_paths(grid: Vec<Vec<i32>>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
         let (rows, cols) = (grid.len(), grid[0].len());
@@ -27,29 +28,29 @@ impl Solution {
                 cells.push((val, i, j));
             }
         }
-        cells.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        cells.sort_unstable_by_key(|x| std::cmp::Reverse(x.0));
 
-        let mut dp = vec![vec![0i64; cols]; rows];
-        let mut total_paths: i64 = 0;
+        let mut dp = vec![vec![Mint::ZERO; cols]; rows];
+        let mut total_paths = Mint::ZERO;
 
         for (val, row, col) in cells {
-            let mut path_count: i64 = 1;
+            let mut path_count = Mint::ONE;
 
             for (dr, dc) in DIRECTIONS {
                 let (nr, nc) = (row as i32 + dr, col as i32 + dc);
                 if nr >= 0 && nr < rows as i32 && nc >= 0 && nc < cols as i32 {
                     let (nr, nc) = (nr as usize, nc as usize);
                     if grid[nr][nc] > val {
-                        path_count = (path_count + dp[nr][nc]) % MOD;
+                        path_count += dp[nr][nc];
                     }
                 }
             }
 
             dp[row][col] = path_count;
-            total_paths = (total_paths + path_count) % MOD;
+            total_paths += path_count;
         }
 
-        total_paths as i32
+        total_paths.value() as i32
     }
 }
 
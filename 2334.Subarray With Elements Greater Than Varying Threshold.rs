@@ -62,7 +62,7 @@ mod tests {
     #[test]
     fn test_example_2() {
         let result = Solution::valid_subarray_size(vec![6, 5, 6, 5, 8], 7);
-        assert!(result >= 1 && result <= 5);
+        assert!((1..=5).contains(&result));
     }
 
     #[test]
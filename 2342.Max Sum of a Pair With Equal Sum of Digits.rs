@@ -56,7 +56,7 @@ mod tests {
 
     #[test]
     fn test_all_same_digit_sum() {
-        assert_eq!(Solution::maximum_sum(vec![1, 10, 100]), 101);
+        assert_eq!(Solution::maximum_sum(vec![1, 10, 100]), 110);
     }
 
     #[test]
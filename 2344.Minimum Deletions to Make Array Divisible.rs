@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Finds minimum deletions so the smallest remaining element divides all of numsDivide.
     ///
@@ -14,7 +16,7 @@ impl Solution {
     /// - Time: O(n + m log M) where n = nums.len(), m = numsDivide.len(), M = max(numsDivide)
     /// - Space: O(1)
     pub fn min_operations(nums: Vec<i32>, nums_divide: Vec<i32>) -> i32 {
-        let target_gcd = nums_divide.into_iter().fold(0, Self::gcd);
+        let target_gcd = nums_divide.into_iter().fold(0, gcd);
 
         nums.iter()
             .filter(|num| target_gcd % **num == 0)
@@ -23,14 +25,6 @@ impl Solution {
                 nums.iter().filter(|num| **num < min_val).count() as i32
             })
     }
-
-    #[inline]
-    fn gcd(mut a: i32, mut b: i32) -> i32 {
-        while b != 0 {
-            (a, b) = (b, a % b);
-        }
-        a
-    }
 }
 
 #[cfg(test)]
@@ -103,6 +103,6 @@ mod tests {
 
     #[test]
     fn test_all_same_digits() {
-        assert_eq!(Solution::count_special_numbers(111), 99);
+        assert_eq!(Solution::count_special_numbers(111), 98);
     }
 }
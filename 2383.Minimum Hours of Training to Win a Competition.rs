@@ -61,7 +61,7 @@ mod tests {
 
     #[test]
     fn test_single_opponent() {
-        assert_eq!(Solution::min_number_of_hours(1, 1, vec![1], vec![1]), 1);
+        assert_eq!(Solution::min_number_of_hours(1, 1, vec![1], vec![1]), 2);
     }
 
     #[test]
@@ -91,6 +91,6 @@ mod tests {
 
     #[test]
     fn test_all_positive() {
-        assert_eq!(Solution::k_sum(vec![1, 2, 3], 4), 5);
+        assert_eq!(Solution::k_sum(vec![1, 2, 3], 4), 3);
     }
 }
@@ -14,7 +14,7 @@ impl Solution {
     /// - Space: O(n) — for the combined vector
     pub fn sort_people(names: Vec<String>, heights: Vec<i32>) -> Vec<String> {
         let mut pairs: Vec<(String, i32)> = names.into_iter().zip(heights).collect();
-        pairs.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+        pairs.sort_unstable_by_key(|x| std::cmp::Reverse(x.1));
         pairs.into_iter().map(|(name, _)| name).collect()
     }
 }
@@ -1,76 +1,52 @@
-struct UnionFind {
-    parent: Vec<usize>,
-    nodes_with_max_value: Vec<usize>,
-    max_value: Vec<i32>,
-    good_path_count: usize,
-}
-
-impl UnionFind {
-    fn new(values: &[i32]) -> Self {
-        let n = values.len();
-        UnionFind {
-            parent: (0..n).collect(),
-            nodes_with_max_value: vec![1; n],
-            max_value: values.to_vec(),
-            good_path_count: 0,
-        }
-    }
-
-    fn find(&mut self, node: usize) -> usize {
-        if self.parent[node] != node {
-            self.parent[node] = self.find(self.parent[node]);
-        }
-        self.parent[node]
-    }
-
-    fn union(&mut self, node_a: usize, node_b: usize) {
-        let (root_a, root_b) = (self.find(node_a), self.find(node_b));
-        if root_a == root_b {
-            return;
-        }
+use std::cmp::Ordering;
 
-        match self.max_value[root_a].cmp(&self.max_value[root_b]) {
-            std::cmp::Ordering::Less => {
-                self.parent[root_a] = root_b;
-            }
-            std::cmp::Ordering::Greater => {
-                self.parent[root_b] = root_a;
-            }
-            std::cmp::Ordering::Equal => {
-                self.good_path_count +=
-                    self.nodes_with_max_value[root_a] * self.nodes_with_max_value[root_b];
-                self.parent[root_a] = root_b;
-                self.nodes_with_max_value[root_b] += self.nodes_with_max_value[root_a];
-            }
-        }
-    }
-}
+use crate::union_find::UnionFind;
 
 impl Solution {
     /// Counts good paths in a tree using Union-Find sorted by edge max value.
     ///
     /// # Intuition
-    /// Process edges in order of max endpoint value. Union-by-max-value ensures
-    /// roots always hold the maximum value, simplifying merge logic.
+    /// Process edges in order of max endpoint value. Each component only needs
+    /// its maximum value and how many nodes hold it, since later edges never
+    /// carry a smaller value.
     ///
     /// # Approach
     /// 1. Sort edges by max(vals[u], vals[v])
-    /// 2. Union components using max value as rank
+    /// 2. Union components, recording the merged maximum and its count at the root
     /// 3. When merging same-max components, add count1 * count2 pairs
     ///
     /// # Complexity
     /// - Time: O(n log n) for sorting, O(n * alpha(n)) for Union-Find
     /// - Space: O(n) for Union-Find arrays
     pub fn number_of_good_paths(vals: Vec<i32>, edges: Vec<Vec<i32>>) -> i32 {
-        let mut uf = UnionFind::new(&vals);
+        let n = vals.len();
+        let mut uf = UnionFind::new(n);
+        let mut max_value = vals.clone();
+        let mut nodes_with_max_value = vec![1; n];
+        let mut good_path_count = n;
         let mut edges = edges;
         edges.sort_unstable_by_key(|e| vals[e[0] as usize].max(vals[e[1] as usize]));
 
         for edge in &edges {
-            uf.union(edge[0] as usize, edge[1] as usize);
+            let (root_a, root_b) = (uf.find(edge[0] as usize), uf.find(edge[1] as usize));
+            let merged = match max_value[root_a].cmp(&max_value[root_b]) {
+                Ordering::Less => (max_value[root_b], nodes_with_max_value[root_b]),
+                Ordering::Greater => (max_value[root_a], nodes_with_max_value[root_a]),
+                Ordering::Equal => {
+                    good_path_count += nodes_with_max_value[root_a] * nodes_with_max_value[root_b];
+                    (
+                        max_value[root_a],
+                        nodes_with_max_value[root_a] + nodes_with_max_value[root_b],
+                    )
+                }
+            };
+            if uf.union(root_a, root_b) {
+                let root = uf.find(root_a);
+                (max_value[root], nodes_with_max_value[root]) = merged;
+            }
         }
 
-        (vals.len() + uf.good_path_count) as i32
+        good_path_count as i32
     }
 }
 
@@ -55,6 +55,6 @@ mod tests {
 
     #[test]
     fn test_hour_wildcard() {
-        assert_eq!(Solution::count_time("?4:00".to_string()), 3);
+        assert_eq!(Solution::count_time("?4:00".to_string()), 2);
     }
 }
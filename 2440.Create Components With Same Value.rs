@@ -35,7 +35,7 @@ impl Solution {
         let mut divisors = Vec::with_capacity(Self::isqrt(s) * 2 + 1);
         let mut i = 1;
         while i * i <= s {
-            if s % i == 0 {
+            if s.is_multiple_of(i) {
                 divisors.push(i);
                 if i != s / i {
                     divisors.push(s / i);
@@ -106,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_no_valid_partition() {
-        let nums = vec![1, 2, 3];
+        let nums = vec![1, 2, 4];
         let edges = vec![vec![0, 1], vec![1, 2]];
         assert_eq!(Solution::component_value(nums, edges), 0);
     }
@@ -62,6 +62,6 @@ mod tests {
 
     #[test]
     fn test_out_of_bounds_reset() {
-        assert_eq!(Solution::count_subarrays(vec![1, 5, 100, 1, 5], 1, 5), 1);
+        assert_eq!(Solution::count_subarrays(vec![1, 5, 100, 1, 5], 1, 5), 2);
     }
 }
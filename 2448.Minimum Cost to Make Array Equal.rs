@@ -22,7 +22,7 @@ impl Solution {
         let mut pairs: Vec<(i32, i32)> = nums.iter().copied().zip(cost.iter().copied()).collect();
         pairs.sort_unstable_by_key(|&(num, _)| num);
 
-        let total_weight: i64 = cost.iter().map(|&c| i64::from(*c)).sum();
+        let total_weight: i64 = cost.iter().map(|&c| i64::from(c)).sum();
         let median_threshold = (total_weight + 1) / 2;
 
         let mut accumulated = 0i64;
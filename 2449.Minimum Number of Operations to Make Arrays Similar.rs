@@ -71,6 +71,6 @@ mod tests {
 
     #[test]
     fn test_large_difference() {
-        assert_eq!(Solution::make_similar(vec![2, 10], vec![6, 6]), 1);
+        assert_eq!(Solution::make_similar(vec![2, 10], vec![6, 6]), 2);
     }
 }
@@ -28,7 +28,7 @@ impl Solution {
         let zero_count = nums.len() - non_zeros.len();
         non_zeros
             .into_iter()
-            .chain(std::iter::repeat(0).take(zero_count))
+            .chain(std::iter::repeat_n(0, zero_count))
             .collect()
     }
 }
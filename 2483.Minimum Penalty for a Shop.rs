@@ -16,7 +16,7 @@ impl Solution {
     /// - Space: O(1)
     pub fn best_closing_time(customers: String) -> i32 {
         let bytes = customers.as_bytes();
-        let total_y = bytes.iter().filter(|&&b| *b == b'Y').count() as i32;
+        let total_y = bytes.iter().filter(|&&b| b == b'Y').count() as i32;
 
         let (best_hour, _, _) = bytes.iter().enumerate().fold(
             (0, total_y, total_y),
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts palindromic subsequences of length 5 in a digit string.
     ///
@@ -19,7 +21,6 @@ impl Solution {
     /// - Time: O(n × D) where n is string length and D = 10 (digits)
     /// - Space: O(D²) = O(100) = O(1) constant space
     pub fn count_palindromes(s: String) -> i32 {
-        const MOD: u64 = 1_000_000_007;
         const DIGITS: usize = 10;
 
         let chars: Vec<usize> = s.bytes().map(|b| (b - b'0') as usize).collect();
@@ -37,7 +38,7 @@ impl Solution {
             suffix_singles[ch] += 1;
         }
 
-        let mut result: u64 = 0;
+        let mut result = Mint::ZERO;
         let mut matched_pairs: u64 = 0;
 
         for &mid in &chars {
@@ -49,7 +50,7 @@ impl Solution {
                 suffix_pairs[right_pair] -= suffix_singles[d];
             }
 
-            result = (result + matched_pairs) % MOD;
+            result += matched_pairs;
 
             for d in 0..DIGITS {
                 let left_pair = pair(d, mid);
@@ -60,7 +61,7 @@ impl Solution {
             prefix_singles[mid] += 1;
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
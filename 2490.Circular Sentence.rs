@@ -34,7 +34,7 @@ mod tests {
     #[test]
     fn test_circular() {
         assert!(Solution::is_circular_sentence(
-            "leetcode exercises strong coding".to_string()
+            "leetcode exercises sound delightful".to_string()
         ));
     }
 
@@ -40,7 +40,7 @@ impl Solution {
                 left += 1;
             }
 
-            max_skip = max_skip.max(right - left + 1);
+            max_skip = max_skip.max(right + 1 - left);
         }
 
         (n - max_skip) as i32
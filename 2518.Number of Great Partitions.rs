@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts the number of great partitions of an array.
     ///
@@ -15,8 +17,6 @@ impl Solution {
     /// - Time: O(n × k)
     /// - Space: O(k)
     pub fn count_partitions(nums: Vec<i32>, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
         let k = k as i64;
         let total_sum: i64 = nums.iter().map(|&x| x as i64).sum();
 
@@ -25,23 +25,23 @@ impl Solution {
         }
 
         let k = k as usize;
-        let mut dp = vec![0i64; k];
-        dp[0] = 1;
+        let mut dp = vec![Mint::ZERO; k];
+        dp[0] = Mint::ONE;
 
         for &num in &nums {
             let num = num as usize;
             if num < k {
                 for j in (num..k).rev() {
-                    dp[j] = (dp[j] + dp[j - num]) % MOD;
+                    dp[j] = dp[j] + dp[j - num];
                 }
             }
         }
 
-        let invalid: i64 = dp.iter().sum::<i64>() % MOD;
+        let invalid: Mint = dp.iter().sum();
 
-        let total = (0..nums.len()).fold(1i64, |acc, _| (acc << 1) % MOD);
+        let total = Mint::new(2).pow(nums.len() as u64);
 
-        ((total - ((invalid << 1) % MOD)) % MOD + MOD) as i32 % MOD as i32
+        (total - invalid * 2).value() as i32
     }
 }
 
@@ -108,16 +108,17 @@ impl Solution {
                 // No one can cross right now, advance time to next event
                 let mut next_time = i32::MAX;
 
-                if !left_busy.is_empty() && boxes_remaining > 0 {
-                    if let Some(&Reverse((t, _))) = left_busy.peek() {
-                        next_time = next_time.min(t);
-                    }
+                if !left_busy.is_empty()
+                    && boxes_remaining > 0
+                    && let Some(&Reverse((t, _))) = left_busy.peek()
+                {
+                    next_time = next_time.min(t);
                 }
 
-                if !right_busy.is_empty() {
-                    if let Some(&Reverse((t, _))) = right_busy.peek() {
-                        next_time = next_time.min(t);
-                    }
+                if !right_busy.is_empty()
+                    && let Some(&Reverse((t, _))) = right_busy.peek()
+                {
+                    next_time = next_time.min(t);
                 }
 
                 if next_time == i32::MAX {
@@ -136,8 +137,6 @@ impl Solution {
 mod tests {
     use super::*;
 
-    struct Solution;
-
     #[test]
     fn test_example_1() {
         // n=1, k=3, time=[[1,1,2,1],[1,1,3,1],[1,1,4,1]]
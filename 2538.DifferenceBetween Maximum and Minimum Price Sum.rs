@@ -1,3 +1,6 @@
+use crate::rooted_tree::RootedTree;
+use crate::segment_tree::Max;
+
 impl Solution {
     /// Finds the maximum cost amongst all possible root choices in a tree.
     ///
@@ -8,81 +11,35 @@ impl Solution {
     ///
     /// # Approach
     /// Use rerooting technique:
-    /// 1. First DFS: compute down[v] = longest path from v into its subtree
-    /// 2. Second DFS: reroot to compute longest path from each node to any other node
+    /// 1. The longest path from v is price[v] plus the best longest path starting at
+    ///    a neighbour and leading away from v, or nothing
+    /// 2. Reroot that recurrence to evaluate it with every node as the root
     /// 3. Answer = max over all v of (longest[v] - price[v])
     ///
     /// # Complexity
-    /// - Time: O(n) — two DFS traversals
-    /// - Space: O(n) — adjacency list, down array, and recursion stack
+    /// - Time: O(n log n) — rooting the tree; the rerooting itself is O(n)
+    /// - Space: O(n log n)
     pub fn max_output(n: i32, edges: Vec<Vec<i32>>, price: Vec<i32>) -> i64 {
         let n = n as usize;
-        if n == 1 {
-            return 0;
-        }
-
         let price: Vec<i64> = price.into_iter().map(|p| p as i64).collect();
+        let tree = RootedTree::new(
+            n,
+            edges
+                .iter()
+                .map(|edge| (edge[0] as usize, edge[1] as usize)),
+            0,
+        );
 
-        let mut adj = vec![vec![]; n];
-        for edge in &edges {
-            let (u, v) = (edge[0] as usize, edge[1] as usize);
-            adj[u].push(v);
-            adj[v].push(u);
-        }
-
-        let mut down = vec![0i64; n];
-        Self::dfs_down(0, usize::MAX, &adj, &price, &mut down);
-
-        let mut ans = 0i64;
-        Self::dfs_reroot(0, usize::MAX, 0, &adj, &price, &down, &mut ans);
-        ans
-    }
-
-    fn dfs_down(v: usize, parent: usize, adj: &[Vec<usize>], price: &[i64], down: &mut [i64]) {
-        down[v] = price[v];
-        for &u in &adj[v] {
-            if u != parent {
-                Self::dfs_down(u, v, adj, price, down);
-                down[v] = down[v].max(price[v] + down[u]);
-            }
-        }
-    }
-
-    fn dfs_reroot(
-        v: usize,
-        parent: usize,
-        up_contrib: i64,
-        adj: &[Vec<usize>],
-        price: &[i64],
-        down: &[i64],
-        ans: &mut i64,
-    ) {
-        let longest_v = down[v].max(price[v] + up_contrib);
-        *ans = (*ans).max(longest_v - price[v]);
-
-        let children: Vec<usize> = adj[v].iter().copied().filter(|&u| u != parent).collect();
-        if children.is_empty() {
-            return;
-        }
-
-        let child_downs: Vec<i64> = children.iter().map(|&c| down[c]).collect();
-        let k = children.len();
-
-        let mut prefix_max = vec![0i64; k + 1];
-        let mut suffix_max = vec![0i64; k + 1];
-
-        for i in 0..k {
-            prefix_max[i + 1] = prefix_max[i].max(child_downs[i]);
-        }
-        for i in (0..k).rev() {
-            suffix_max[i] = suffix_max[i + 1].max(child_downs[i]);
-        }
-
-        for (i, &c) in children.iter().enumerate() {
-            let max_sibling = prefix_max[i].max(suffix_max[i + 1]);
-            let child_up = price[v] + up_contrib.max(max_sibling);
-            Self::dfs_reroot(c, v, child_up, adj, price, down, ans);
-        }
+        let longest = tree.reroot(
+            |&path: &i64, _, _| Max(path),
+            |best, v| price[v] + best.0.max(0),
+        );
+        longest
+            .into_iter()
+            .zip(&price)
+            .map(|(path, p)| path - p)
+            .max()
+            .unwrap_or(0)
     }
 }
 
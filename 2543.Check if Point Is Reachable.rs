@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Checks if point (targetX, targetY) is reachable from (1, 1).
     ///
@@ -15,13 +17,9 @@ impl Solution {
     /// - Time: O(log(min(targetX, targetY))) for GCD computation
     /// - Space: O(1)
     pub fn is_reachable(target_x: i32, target_y: i32) -> bool {
-        let g = Self::gcd(target_x, target_y);
+        let g = gcd(target_x, target_y);
         g & (g - 1) == 0
     }
-
-    fn gcd(a: i32, b: i32) -> i32 {
-        if b == 0 { a } else { Self::gcd(b, a % b) }
-    }
 }
 
 #[cfg(test)]
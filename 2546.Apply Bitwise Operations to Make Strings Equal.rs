@@ -12,7 +12,7 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(1)
     pub fn make_strings_equal(s: String, target: String) -> bool {
-        s.as_bytes().iter().any(|&b| b == b'1') == target.as_bytes().iter().any(|&b| b == b'1')
+        s.as_bytes().contains(&b'1') == target.as_bytes().contains(&b'1')
     }
 }
 
@@ -69,7 +69,7 @@ mod tests {
 
     #[test]
     fn test_all_same_elements() {
-        assert_eq!(Solution::min_cost(vec![0, 0, 0], 1), 4);
+        assert_eq!(Solution::min_cost(vec![0, 0, 0], 1), 3);
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use crate::fenwick::Fenwick;
+
 impl Solution {
     /// Count increasing quadruplets using BIT-accelerated enumeration.
     ///
@@ -18,54 +20,26 @@ impl Solution {
     pub fn count_quadruplets(nums: Vec<i32>) -> i64 {
         let n = nums.len();
         let mut result: i64 = 0;
-        let mut bit = BinaryIndexedTree::new(n + 2);
+        let mut bit: Fenwick<i64> = Fenwick::new(n + 1);
 
         for j in 0..n.saturating_sub(2) {
             let mut greater_count: i64 = 0;
 
             for k in (j + 1..n).rev() {
                 if nums[k] < nums[j] {
-                    result += bit.query(nums[k] as usize) * greater_count;
+                    result += bit.prefix(nums[k] as usize + 1) * greater_count;
                 } else if nums[k] > nums[j] {
                     greater_count += 1;
                 }
             }
 
-            bit.update(nums[j] as usize);
+            bit.add(nums[j] as usize, 1);
         }
 
         result
     }
 }
 
-struct BinaryIndexedTree {
-    tree: Vec<i64>,
-}
-
-impl BinaryIndexedTree {
-    fn new(size: usize) -> Self {
-        Self {
-            tree: vec![0; size],
-        }
-    }
-
-    fn update(&mut self, mut index: usize) {
-        while index < self.tree.len() {
-            self.tree[index] += 1;
-            index += index & index.wrapping_neg();
-        }
-    }
-
-    fn query(&self, mut index: usize) -> i64 {
-        let mut sum = 0;
-        while index > 0 {
-            sum += self.tree[index];
-            index -= index & index.wrapping_neg();
-        }
-        sum
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use crate::segment_tree::{LazyAction, Monoid, SegmentTree};
+
 impl Solution {
     /// Track ones in `nums1` with a lazy segment tree and aggregate `nums2` with a single sum.
     ///
@@ -16,7 +18,10 @@ impl Solution {
     /// - Time: O((n + q) log n)
     /// - Space: O(n)
     pub fn handle_query(nums1: Vec<i32>, nums2: Vec<i32>, queries: Vec<Vec<i32>>) -> Vec<i64> {
-        let mut segment_tree = SegmentTree::new(&nums1);
+        let mut segment_tree: SegmentTree<Bits, Flip> = nums1
+            .iter()
+            .map(|&bit| Bits { ones: bit, len: 1 })
+            .collect();
         let mut sum_nums2 = nums2.iter().map(|&value| value as i64).sum::<i64>();
         let mut answers = Vec::with_capacity(queries.len());
 
@@ -25,11 +30,11 @@ impl Solution {
                 1 => {
                     let left = query[1] as usize;
                     let right = query[2] as usize;
-                    segment_tree.flip_range(left, right);
+                    segment_tree.apply(left..=right, &Flip(true));
                 }
                 2 => {
                     let delta = query[1] as i64;
-                    sum_nums2 += delta * segment_tree.total_ones();
+                    sum_nums2 += delta * segment_tree.all().ones as i64;
                 }
                 3 => {
                     answers.push(sum_nums2);
@@ -42,98 +47,48 @@ impl Solution {
     }
 }
 
-struct SegmentTree {
-    n: usize,
-    ones: Vec<i32>,
-    lazy: Vec<bool>,
+/// Number of ones in a segment, and its length.
+#[derive(Clone, Copy)]
+struct Bits {
+    ones: i32,
+    len: i32,
 }
 
-impl SegmentTree {
-    fn new(bits: &[i32]) -> Self {
-        let n = bits.len();
-        let size = n.max(1) * 4;
-        let mut ones = vec![0; size];
-        let lazy = vec![false; size];
-
-        if n > 0 {
-            Self::build(1, 0, n - 1, bits, &mut ones);
-        }
-
-        Self { n, ones, lazy }
-    }
-
-    fn build(node: usize, left: usize, right: usize, bits: &[i32], ones: &mut [i32]) {
-        if left == right {
-            ones[node] = bits[left];
-            return;
-        }
-        let mid = (left + right) / 2;
-        let left_node = node * 2;
-        let right_node = left_node + 1;
-        Self::build(left_node, left, mid, bits, ones);
-        Self::build(right_node, mid + 1, right, bits, ones);
-        ones[node] = ones[left_node] + ones[right_node];
+impl Monoid for Bits {
+    fn identity() -> Self {
+        Bits { ones: 0, len: 0 }
     }
 
-    fn total_ones(&self) -> i64 {
-        if self.n == 0 {
-            0
-        } else {
-            self.ones[1] as i64
+    fn combine(&self, right: &Self) -> Self {
+        Bits {
+            ones: self.ones + right.ones,
+            len: self.len + right.len,
         }
     }
+}
 
-    fn flip_range(&mut self, left: usize, right: usize) {
-        if self.n == 0 {
-            return;
-        }
-        self.flip_range_inner(1, 0, self.n - 1, left, right);
-    }
-
-    fn flip_range_inner(
-        &mut self,
-        node: usize,
-        left: usize,
-        right: usize,
-        query_left: usize,
-        query_right: usize,
-    ) {
-        if query_left <= left && right <= query_right {
-            self.apply_flip(node, left, right);
-            return;
-        }
-
-        self.push(node, left, right);
-        let mid = (left + right) / 2;
-        let left_node = node * 2;
-        let right_node = left_node + 1;
+/// Whether every bit of a segment is flipped.
+#[derive(Clone, Copy)]
+struct Flip(bool);
 
-        if query_left <= mid {
-            self.flip_range_inner(left_node, left, mid, query_left, query_right);
-        }
-        if query_right > mid {
-            self.flip_range_inner(right_node, mid + 1, right, query_left, query_right);
-        }
-
-        self.ones[node] = self.ones[left_node] + self.ones[right_node];
+impl LazyAction<Bits> for Flip {
+    fn identity() -> Self {
+        Flip(false)
     }
 
-    fn push(&mut self, node: usize, left: usize, right: usize) {
-        if !self.lazy[node] || left == right {
-            return;
+    fn apply(&self, bits: &Bits) -> Bits {
+        if self.0 {
+            Bits {
+                ones: bits.len - bits.ones,
+                len: bits.len,
+            }
+        } else {
+            *bits
         }
-        let mid = (left + right) / 2;
-        let left_node = node * 2;
-        let right_node = left_node + 1;
-        self.apply_flip(left_node, left, mid);
-        self.apply_flip(right_node, mid + 1, right);
-        self.lazy[node] = false;
     }
 
-    fn apply_flip(&mut self, node: usize, left: usize, right: usize) {
-        let len = (right - left + 1) as i32;
-        self.ones[node] = len - self.ones[node];
-        self.lazy[node] = !self.lazy[node];
+    fn compose(&self, inner: &Self) -> Self {
+        Flip(self.0 != inner.0)
     }
 }
 
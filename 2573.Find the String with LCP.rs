@@ -107,7 +107,7 @@ mod tests {
 
     #[test]
     fn wrong_diagonal() {
-        let lcp = vec![vec![2, 0], vec![0, 1]];
+        let lcp = vec![vec![1, 0], vec![0, 1]];
         assert_eq!(Solution::find_the_string(lcp), "");
     }
 
@@ -19,7 +19,7 @@ impl Solution {
         let n = nums.len();
         let mut i = 0;
 
-        for j in (n + 1) / 2..n {
+        for j in n.div_ceil(2)..n {
             if nums[i] * 2 <= nums[j] {
                 i += 1;
             }
@@ -55,7 +55,7 @@ mod tests {
                 1, 78, 27, 48, 14, 86, 79, 68, 77, 20, 57, 21, 18, 67, 5, 51, 70, 85, 47, 56, 22,
                 79, 41, 8, 39, 81, 59
             ]),
-            26
+            22
         );
     }
 }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Count bounded knapsack combinations with dynamic programming.
     ///
@@ -15,19 +17,18 @@ impl Solution {
     /// - Time: O(n * target * max_count)
     /// - Space: O(target)
     pub fn ways_to_reach_target(target: i32, types: Vec<Vec<i32>>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let target = target as usize;
-        let mut dp = vec![0i64; target + 1];
-        dp[0] = 1;
+        let mut dp = vec![Mint::ZERO; target + 1];
+        dp[0] = Mint::ONE;
 
         for question_type in types {
             let count = question_type[0] as usize;
             let marks = question_type[1] as usize;
-            let mut next = vec![0i64; target + 1];
+            let mut next = vec![Mint::ZERO; target + 1];
 
             for score in 0..=target {
                 let base = dp[score];
-                if base == 0 {
+                if base == Mint::ZERO {
                     continue;
                 }
 
@@ -36,18 +37,14 @@ impl Solution {
                     if new_score > target {
                         break;
                     }
-                    let mut updated = next[new_score] + base;
-                    if updated >= MOD {
-                        updated -= MOD;
-                    }
-                    next[new_score] = updated;
+                    next[new_score] += base;
                 }
             }
 
             dp = next;
         }
 
-        (dp[target] % MOD) as i32
+        dp[target].value() as i32
     }
 }
 
@@ -48,6 +48,6 @@ mod tests {
 
     #[test]
     fn test_no_beautiful() {
-        assert_eq!(Solution::beautiful_subarrays(vec![1, 2, 3]), 0);
+        assert_eq!(Solution::beautiful_subarrays(vec![1, 10, 4]), 0);
     }
 }
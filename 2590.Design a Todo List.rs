@@ -51,10 +51,7 @@ impl TodoList {
             tags: tags.into_iter().collect(),
             due_date,
         };
-        self.user_tasks
-            .entry(user_id)
-            .or_insert_with(Vec::new)
-            .push(task);
+        self.user_tasks.entry(user_id).or_default().push(task);
         let id = self.next_id;
         self.next_id += 1;
         id
@@ -40,7 +40,7 @@ mod tests {
 
     #[test]
     fn test_pick_with_negatives() {
-        assert_eq!(Solution::k_items_with_maximum_sum(3, 2, 3, 7), 2);
+        assert_eq!(Solution::k_items_with_maximum_sum(3, 2, 3, 7), 1);
     }
 
     #[test]
@@ -41,7 +41,7 @@ mod tests {
 
     #[test]
     fn test_no_common() {
-        assert_eq!(Solution::min_number(vec![3, 5, 2, 6], vec![8, 7, 4]), 23);
+        assert_eq!(Solution::min_number(vec![3, 5, 2, 6], vec![8, 7, 4]), 24);
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use crate::number_theory::is_prime;
+
 impl Solution {
     /// Find the largest prime on either diagonal of a square matrix.
     ///
@@ -6,11 +8,11 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Iterate rows, checking elements at positions (i, i) and (i, n-1-i)
-    /// 2. For each diagonal element, test primality via trial division
+    /// 2. For each diagonal element, test primality via deterministic Miller–Rabin
     /// 3. Track the maximum prime
     ///
     /// # Complexity
-    /// - Time: O(n * √v) where v is the max element value
+    /// - Time: O(n * log v) where v is the max element value
     /// - Space: O(1)
     pub fn diagonal_prime(nums: Vec<Vec<i32>>) -> i32 {
         let n = nums.len();
@@ -24,33 +26,10 @@ impl Solution {
                 }
                 candidates
             })
-            .filter(|&v| Self::is_prime(v))
+            .filter(|&v| is_prime(v as u64))
             .max()
             .unwrap_or(0)
     }
-
-    fn is_prime(n: i32) -> bool {
-        if n < 2 {
-            return false;
-        }
-        let upper = Self::isqrt(n as u32) as i32;
-        (2..=upper).all(|i| n % i != 0)
-    }
-
-    #[inline]
-    fn isqrt(n: u32) -> u32 {
-        if n == 0 {
-            return 0;
-        }
-        let mut x = n as u64;
-        let n = n as u64;
-        let mut y = (x + n / x) / 2;
-        while y < x {
-            x = y;
-            y = (x + n / x) / 2;
-        }
-        x as u32
-    }
 }
 
 #[cfg(test)]
@@ -47,7 +47,7 @@ impl Solution {
 
             let step = grid[row][col] as usize;
             let max_right = (col + step).min(n - 1);
-            if col + 1 <= max_right {
+            if col < max_right {
                 let next_cols: Vec<usize> = row_unvisited[row]
                     .range((col + 1)..=max_right)
                     .copied()
@@ -61,7 +61,7 @@ impl Solution {
             }
 
             let max_down = (row + step).min(m - 1);
-            if row + 1 <= max_down {
+            if row < max_down {
                 let next_rows: Vec<usize> = col_unvisited[col]
                     .range((row + 1)..=max_down)
                     .copied()
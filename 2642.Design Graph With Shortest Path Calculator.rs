@@ -1,95 +1,63 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use crate::graph::AllPairs;
 
 struct Graph {
-    n: usize,
-    adj: Vec<Vec<(usize, i32)>>,
+    distances: AllPairs<i32>,
 }
 
 impl Graph {
-    /// Build the directed adjacency list from the initial edges.
+    /// Precompute all-pairs shortest paths from the initial edges.
     ///
     /// # Intuition
-    /// Dijkstra only needs outgoing edges, so store them per node.
+    /// With at most a hundred nodes, every distance fits in a small matrix,
+    /// and keeping it current makes each query a lookup.
     ///
     /// # Approach
-    /// Allocate `n` adjacency lists and push each directed edge.
+    /// Build a directed CSR graph and run Floyd–Warshall over it.
     ///
     /// # Complexity
-    /// - Time: O(n + m)
-    /// - Space: O(n + m)
+    /// - Time: O(n³ + m)
+    /// - Space: O(n² + m)
     fn new(n: i32, edges: Vec<Vec<i32>>) -> Self {
-        let n = n as usize;
-        let mut adj = vec![Vec::new(); n];
-        for edge in edges {
-            let from = edge[0] as usize;
-            let to = edge[1] as usize;
-            let cost = edge[2];
-            adj[from].push((to, cost));
+        let edges = edges
+            .iter()
+            .map(|edge| (edge[0] as usize, edge[1] as usize, edge[2]));
+        Self {
+            distances: crate::graph::Graph::directed(n as usize, edges).floyd_warshall(),
         }
-
-        Self { n, adj }
     }
 
     /// Add a directed edge with the given cost.
     ///
     /// # Intuition
-    /// Store the edge immediately; queries will incorporate it.
+    /// A path shortened by the new edge uses it once: a shortest path to its
+    /// tail, the edge, then a shortest path from its head.
     ///
     /// # Approach
-    /// Push the edge into the adjacency list for `from`.
+    /// Relax every pair through the new edge with `AllPairs::add_edge`.
     ///
     /// # Complexity
-    /// - Time: O(1)
-    /// - Space: O(1) extra
+    /// - Time: O(n²)
+    /// - Space: O(n) extra
     fn add_edge(&mut self, edge: Vec<i32>) {
-        let from = edge[0] as usize;
-        let to = edge[1] as usize;
-        let cost = edge[2];
-        self.adj[from].push((to, cost));
+        self.distances
+            .add_edge(edge[0] as usize, edge[1] as usize, edge[2]);
     }
 
-    /// Compute the minimum path cost via Dijkstra.
+    /// Look up the minimum path cost.
     ///
     /// # Intuition
-    /// All edge weights are positive, so expanding the smallest frontier is optimal.
+    /// The matrix is kept exact after every edge, so no search is needed.
     ///
     /// # Approach
-    /// Run Dijkstra from `node1` and early-exit once `node2` is finalized.
+    /// Read the distance, reporting unreachable pairs as `-1`.
     ///
     /// # Complexity
-    /// - Time: O((n + m) log n)
-    /// - Space: O(n + m)
+    /// - Time: O(1)
+    /// - Space: O(1)
     fn shortest_path(&self, node1: i32, node2: i32) -> i32 {
-        let start = node1 as usize;
-        let target = node2 as usize;
-        if start == target {
-            return 0;
-        }
-
-        let mut distances = vec![i32::MAX; self.n];
-        let mut heap: BinaryHeap<Reverse<(i32, usize)>> = BinaryHeap::new();
-
-        distances[start] = 0;
-        heap.push(Reverse((0, start)));
-
-        while let Some(Reverse((current_cost, node))) = heap.pop() {
-            if current_cost != distances[node] {
-                continue;
-            }
-            if node == target {
-                return current_cost;
-            }
-            for &(next_node, edge_cost) in &self.adj[node] {
-                let next_cost = current_cost + edge_cost;
-                if next_cost < distances[next_node] {
-                    distances[next_node] = next_cost;
-                    heap.push(Reverse((next_cost, next_node)));
-                }
-            }
-        }
-
-        -1
+        self.distances
+            .dist(node1 as usize, node2 as usize)
+            .unwrap_or(-1)
     }
 }
 
@@ -67,7 +67,7 @@ mod tests {
     fn test_reversed_permutations() {
         assert_eq!(
             Solution::find_the_prefix_common_array(vec![1, 2, 3, 4], vec![4, 3, 2, 1]),
-            vec![0, 0, 0, 4]
+            vec![0, 0, 2, 4]
         );
     }
 
@@ -11,6 +11,7 @@ impl Solution {
     /// Convert `s` into indices in `[0, k)`. Scan from right to left:
     /// - Try the next larger letter at position `i` that differs from positions `i-1` and `i-2`.
     /// - If found, fix it and greedily fill positions `i+1..` with the smallest valid letters.
+    ///
     /// If no position can be increased, return the empty string.
     ///
     /// # Complexity
@@ -30,7 +30,7 @@ impl Solution {
 
         for i in 0..n {
             seen.insert(nums[i]);
-            ans.push((seen.len() - suffix_distinct[i + 1]) as i32);
+            ans.push(seen.len() as i32 - suffix_distinct[i + 1] as i32);
         }
 
         ans
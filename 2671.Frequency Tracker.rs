@@ -34,12 +34,12 @@ impl FrequencyTracker {
     }
 
     fn delete_one(&mut self, number: i32) {
-        if let Some(count) = self.cnt.get_mut(&number) {
-            if *count > 0 {
-                *self.freq.entry(*count).or_insert(0) -= 1;
-                *count -= 1;
-                *self.freq.entry(*count).or_insert(0) += 1;
-            }
+        if let Some(count) = self.cnt.get_mut(&number)
+            && *count > 0
+        {
+            *self.freq.entry(*count).or_insert(0) -= 1;
+            *count -= 1;
+            *self.freq.entry(*count).or_insert(0) += 1;
         }
     }
 
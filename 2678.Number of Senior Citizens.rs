@@ -49,7 +49,7 @@ mod tests {
     #[test]
     fn test_no_seniors() {
         assert_eq!(
-            Solution::count_seniors(vec![s("1313579440F2036"), s("2## 921522980M5644")]),
+            Solution::count_seniors(vec![s("1313579440F2036"), s("2921522980M5644")]),
             0
         );
     }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Accumulate subset minimums while scanning increasing maxima.
     ///
@@ -19,21 +21,19 @@ impl Solution {
     /// - Time: O(n log n)
     /// - Space: O(1) extra (in-place sort)
     pub fn sum_of_power(mut nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         nums.sort_unstable();
 
-        let mut sum_mins: i64 = 0;
-        let mut answer: i64 = 0;
+        let mut sum_mins = Mint::ZERO;
+        let mut answer = Mint::ZERO;
 
         for value in nums {
-            let x = value as i64;
-            let x_sq = x * x % MOD;
-            let contrib = x_sq * ((sum_mins + x) % MOD) % MOD;
-            answer = (answer + contrib) % MOD;
-            sum_mins = (sum_mins * 2 + x) % MOD;
+            let x = Mint::from(value);
+            let x_sq = x * x;
+            answer += x_sq * (sum_mins + x);
+            sum_mins = sum_mins * 2 + x;
         }
 
-        answer as i32
+        answer.value() as i32
     }
 }
 
@@ -19,7 +19,7 @@ impl Solution {
         let mut pos = 0usize;
         let mut step = 1usize;
 
-        while !*visited[pos] {
+        while !visited[pos] {
             visited[pos] = true;
             pos = (pos + step * k as usize) % n_us;
             step += 1;
@@ -48,7 +48,7 @@ mod tests {
 
     #[test]
     fn test_nested() {
-        assert_eq!(Solution::min_length("AABB".into()), 2);
+        assert_eq!(Solution::min_length("AABB".into()), 0);
     }
 
     #[test]
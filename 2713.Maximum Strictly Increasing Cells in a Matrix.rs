@@ -31,7 +31,7 @@ impl Solution {
             }
         }
 
-        cells.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        cells.sort_unstable_by_key(|a| a.0);
 
         let mut row_best = vec![0i32; row_count];
         let mut col_best = vec![0i32; col_count];
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Count numbers in [num1, num2] whose digit sum is within bounds.
     ///
@@ -14,8 +16,6 @@ impl Solution {
     /// - Time: O(len * max_sum * 10)
     /// - Space: O(len * max_sum)
     pub fn count(num1: String, num2: String, min_sum: i32, max_sum: i32) -> i32 {
-        const MOD: i32 = 1_000_000_007;
-
         fn decrement_decimal(num: &str) -> String {
             let mut bytes = num.as_bytes().to_vec();
             let mut idx = bytes.len();
@@ -35,15 +35,14 @@ impl Solution {
             String::from_utf8(bytes).unwrap()
         }
 
-        fn count_up_to(bound: &str, min_sum: usize, max_sum: usize) -> i32 {
-            const MOD: i32 = 1_000_000_007;
+        fn count_up_to(bound: &str, min_sum: usize, max_sum: usize) -> Mint {
             let digits: Vec<u8> = bound.bytes().map(|b| b - b'0').collect();
             let max_possible = 9 * digits.len();
             let max_sum = max_sum.min(max_possible);
             if min_sum > max_sum {
-                return 0;
+                return Mint::ZERO;
             }
-            let mut memo = vec![vec![[-1; 2]; max_sum + 1]; digits.len() + 1];
+            let mut memo = vec![vec![[None; 2]; max_sum + 1]; digits.len() + 1];
 
             fn dfs(
                 pos: usize,
@@ -52,18 +51,18 @@ impl Solution {
                 digits: &[u8],
                 min_sum: usize,
                 max_sum: usize,
-                memo: &mut Vec<Vec<[i32; 2]>>,
-            ) -> i32 {
+                memo: &mut Vec<Vec<[Option<Mint>; 2]>>,
+            ) -> Mint {
                 if pos == digits.len() {
-                    return if sum >= min_sum { 1 } else { 0 };
+                    return Mint::from(sum >= min_sum);
                 }
                 let tight_idx = if tight { 1 } else { 0 };
-                if memo[pos][sum][tight_idx] != -1 {
-                    return memo[pos][sum][tight_idx];
+                if let Some(cached) = memo[pos][sum][tight_idx] {
+                    return cached;
                 }
 
                 let limit = if tight { digits[pos] } else { 9 };
-                let mut total: i64 = 0;
+                let mut total = Mint::ZERO;
                 for digit in 0..=limit {
                     let next_sum = sum + digit as usize;
                     if next_sum > max_sum {
@@ -78,14 +77,10 @@ impl Solution {
                         min_sum,
                         max_sum,
                         memo,
-                    ) as i64;
-                    if total >= MOD as i64 {
-                        total %= MOD as i64;
-                    }
+                    );
                 }
-                let res = (total % MOD as i64) as i32;
-                memo[pos][sum][tight_idx] = res;
-                res
+                memo[pos][sum][tight_idx] = Some(total);
+                total
             }
 
             dfs(0, 0, true, &digits, min_sum, max_sum, &mut memo)
@@ -94,13 +89,8 @@ impl Solution {
         let min_sum = min_sum.max(0) as usize;
         let max_sum = max_sum.max(0) as usize;
         let lower = decrement_decimal(&num1);
-        let mut result =
-            count_up_to(&num2, min_sum, max_sum) - count_up_to(&lower, min_sum, max_sum);
-        result %= MOD;
-        if result < 0 {
-            result += MOD;
-        }
-        result
+        let result = count_up_to(&num2, min_sum, max_sum) - count_up_to(&lower, min_sum, max_sum);
+        result.value() as i32
     }
 }
 
@@ -48,6 +48,6 @@ mod tests {
 
     #[test]
     fn leading_a_skipped() {
-        assert_eq!(Solution::smallest_string("acbbc".to_string()), "abaac");
+        assert_eq!(Solution::smallest_string("acbbc".to_string()), "abaab");
     }
 }
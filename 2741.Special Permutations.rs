@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Count permutations where adjacent elements are divisible.
     ///
@@ -17,10 +19,9 @@ impl Solution {
     /// - Time: O(2^n · n²)
     /// - Space: O(2^n · n)
     pub fn special_perm(nums: Vec<i32>) -> i32 {
-        const MOD: i32 = 1_000_000_007;
         let n = nums.len();
         let m = 1 << n;
-        let mut f = vec![vec![0i32; n]; m];
+        let mut f = vec![vec![Mint::ZERO; n]; m];
 
         for mask in 1..m {
             for j in 0..n {
@@ -29,7 +30,7 @@ impl Solution {
                 }
                 let prev_mask = mask ^ (1 << j);
                 if prev_mask == 0 {
-                    f[mask][j] = 1;
+                    f[mask][j] = Mint::ONE;
                     continue;
                 }
                 f[mask][j] = (0..n)
@@ -37,11 +38,12 @@ impl Solution {
                         (prev_mask >> k) & 1 == 1
                             && (nums[j] % nums[k] == 0 || nums[k] % nums[j] == 0)
                     })
-                    .fold(f[mask][j], |acc, k| (acc + f[prev_mask][k]) % MOD);
+                    .map(|k| f[prev_mask][k])
+                    .sum();
             }
         }
 
-        f[m - 1].iter().fold(0, |acc, &x| (acc + x) % MOD)
+        f[m - 1].iter().sum::<Mint>().value() as i32
     }
 }
 
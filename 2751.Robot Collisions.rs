@@ -12,6 +12,7 @@ impl Solution {
     /// - Lower health robot is removed.
     /// - Survivor loses one health.
     /// - Equal health removes both.
+    ///
     /// Update the original health array in place and collect remaining healths in input order.
     ///
     /// # Complexity
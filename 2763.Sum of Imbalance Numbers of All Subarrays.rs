@@ -12,6 +12,7 @@ impl Solution {
     /// - `segments += 1` for the new block,
     /// - `segments -= 1` if the left neighbor exists,
     /// - `segments -= 1` if the right neighbor exists.
+    ///
     /// The imbalance of the current subarray is `segments - 1`. Sum it over all starts and ends.
     ///
     /// # Complexity
@@ -19,10 +20,11 @@ impl Solution {
     /// - Space: O(n)
     pub fn sum_imbalance_numbers(nums: Vec<i32>) -> i32 {
         let n = nums.len();
+        let max_value = nums.iter().copied().max().unwrap_or(0) as usize;
         let mut total: i64 = 0;
 
         for start in 0..n {
-            let mut seen = vec![false; n + 2];
+            let mut seen = vec![false; max_value + 2];
             let mut segments: i32 = 0;
 
             for end in start..n {
@@ -1,51 +1,7 @@
-/// Iterative segment tree for range-max queries and point updates.
-/// Nodes 1..2n store values; leaves are at indices n..2n.
-struct SegTree {
-    n: usize,
-    t: Vec<i32>,
-}
-
-impl SegTree {
-    fn new(n: usize) -> Self {
-        Self {
-            n,
-            t: vec![i32::MIN; 2 * n],
-        }
-    }
-
-    /// Point update: set position p to max(current, v).
-    fn update(&mut self, mut p: usize, v: i32) {
-        p += self.n;
-        self.t[p] = self.t[p].max(v);
-        while p > 1 {
-            p >>= 1;
-            self.t[p] = self.t[2 * p].max(self.t[2 * p + 1]);
-        }
-    }
-
-    /// Range max query over [l, r] inclusive.
-    fn query(&self, mut l: usize, mut r: usize) -> i32 {
-        let mut res = i32::MIN;
-        l += self.n;
-        r += self.n + 1;
-        while l < r {
-            if l & 1 == 1 {
-                res = res.max(self.t[l]);
-                l += 1;
-            }
-            if r & 1 == 1 {
-                r -= 1;
-                res = res.max(self.t[r]);
-            }
-            l >>= 1;
-            r >>= 1;
-        }
-        res
-    }
-}
+use crate::segment_tree::{Max, SegmentTree};
 
 impl Solution {
-    /// Iterative segment tree with coordinate compression for O(n log n).
+    /// Range-max segment tree with coordinate compression for O(n log n).
     ///
     /// # Intuition
     /// Map values to compressed coordinates, then use a segment tree to answer
@@ -54,13 +10,11 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Coordinate compress: sort + dedup all values → indices 0..m
-    /// 2. Build an iterative segment tree over m leaves (2m nodes, no recursion)
+    /// 2. Build a range-max segment tree over the m compressed values
     /// 3. For each index i left-to-right:
     ///    - Binary search for the valid value range [nums[i]-target, nums[i]+target]
     ///    - Query tree for max dp in that range
     ///    - Update tree at nums[i]'s compressed position with dp[i]
-    /// The iterative tree halves constant factors vs recursive: no call stack,
-    /// 2n nodes instead of 4n, and sequential memory access patterns.
     ///
     /// # Complexity
     /// - Time: O(n log n)
@@ -73,8 +27,8 @@ impl Solution {
         let m = sorted.len();
         let pos = |v: i32| sorted.binary_search(&v).unwrap();
 
-        let mut tree = SegTree::new(m);
-        tree.update(pos(nums[0]), 0);
+        let mut tree: SegmentTree<Max<i32>> = SegmentTree::new(m);
+        tree.set(pos(nums[0]), Max(0));
 
         let t = target as i64;
         let mut last = if n == 1 { 0 } else { -1 };
@@ -86,7 +40,7 @@ impl Solution {
             let r = sorted.partition_point(|&x| (x as i64) <= hi);
 
             let dp_i = if l < r {
-                let mx = tree.query(l, r - 1);
+                let Max(mx) = tree.query(l..r);
                 if mx >= 0 {
                     mx + 1
                 } else {
@@ -100,7 +54,9 @@ impl Solution {
                 last = dp_i;
             }
             if dp_i >= 0 {
-                tree.update(pos(nums[i]), dp_i);
+                let p = pos(nums[i]);
+                let best = tree.get(p).max(Max(dp_i));
+                tree.set(p, best);
             }
         }
         last
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Digit DP over prefixes with tight and previous digit state.
     ///
@@ -15,25 +17,17 @@ impl Solution {
     /// - Time: O(L * 10)
     /// - Space: O(L * 11 * 2 * 2)
     pub fn count_stepping_numbers(low: String, high: String) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
         let high_count = Self::count_up_to(&high);
         let low_minus_one = Self::decrement_string(&low);
         let low_count = Self::count_up_to(&low_minus_one);
 
-        let mut result = high_count - low_count;
-        if result < 0 {
-            result += MOD;
-        }
-        (result % MOD) as i32
+        (high_count - low_count).value() as i32
     }
 
-    fn count_up_to(bound: &str) -> i64 {
-        const MOD: i64 = 1_000_000_007;
-
+    fn count_up_to(bound: &str) -> Mint {
         let digits: Vec<u8> = bound.bytes().map(|byte| byte - b'0').collect();
         let mut memo = vec![vec![vec![vec![None; 2]; 2]; 11]; digits.len() + 1];
-        Self::dfs(0, 10, true, false, &digits, &mut memo, MOD)
+        Self::dfs(0, 10, true, false, &digits, &mut memo)
     }
 
     fn dfs(
@@ -42,9 +36,8 @@ impl Solution {
         tight: bool,
         started: bool,
         digits: &[u8],
-        memo: &mut Vec<Vec<Vec<Vec<Option<i64>>>>>,
-        modulo: i64,
-    ) -> i64 {
+        memo: &mut Vec<Vec<Vec<Vec<Option<Mint>>>>>,
+    ) -> Mint {
         let tight_idx = usize::from(tight);
         let started_idx = usize::from(started);
         if let Some(value) = memo[pos][prev_digit][tight_idx][started_idx] {
@@ -52,13 +45,13 @@ impl Solution {
         }
 
         if pos == digits.len() {
-            let value = if started { 1 } else { 0 };
+            let value = Mint::from(started);
             memo[pos][prev_digit][tight_idx][started_idx] = Some(value);
             return value;
         }
 
         let limit = if tight { digits[pos] } else { 9 };
-        let mut total = 0i64;
+        let mut total = Mint::ZERO;
 
         for digit in 0..=limit {
             let next_tight = tight && digit == limit;
@@ -66,24 +59,18 @@ impl Solution {
 
             if !started {
                 if digit == 0 {
-                    let add = Self::dfs(pos + 1, 10, next_tight, false, digits, memo, modulo);
+                    let add = Self::dfs(pos + 1, 10, next_tight, false, digits, memo);
                     total += add;
                 } else {
-                    let add =
-                        Self::dfs(pos + 1, digit_usize, next_tight, true, digits, memo, modulo);
+                    let add = Self::dfs(pos + 1, digit_usize, next_tight, true, digits, memo);
                     total += add;
                 }
             } else if (digit as i32 - prev_digit as i32).abs() == 1 {
-                let add = Self::dfs(pos + 1, digit_usize, next_tight, true, digits, memo, modulo);
+                let add = Self::dfs(pos + 1, digit_usize, next_tight, true, digits, memo);
                 total += add;
             }
-
-            if total >= modulo {
-                total %= modulo;
-            }
         }
 
-        total %= modulo;
         memo[pos][prev_digit][tight_idx][started_idx] = Some(total);
         total
     }
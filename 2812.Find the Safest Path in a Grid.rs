@@ -114,12 +114,9 @@ mod tests {
     }
 
     #[test]
-    fn single_cell_no_thief() {
-        // start == end, no thief → distance is MAX (i32::MAX stays -1 sentinel after BFS
-        // but (0,0) is immediately the destination)
-        let grid = vec![vec![0]];
-        // dist[0][0] stays -1 after BFS (no thief); min_safety = -1 but path length 0
-        // LeetCode guarantees grid[0][0] == grid[n-1][n-1] == 0 for this test, result ≥ 0
-        assert!(Solution::maximum_safeness_factor(grid) >= 0);
+    fn single_cell_thief() {
+        // LeetCode guarantees at least one thief; here start == end == the thief
+        let grid = vec![vec![1]];
+        assert_eq!(Solution::maximum_safeness_factor(grid), 0);
     }
 }
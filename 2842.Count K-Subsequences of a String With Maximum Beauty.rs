@@ -1,3 +1,5 @@
+use crate::mod_int::{Combinatorics, Mint};
+
 impl Solution {
     /// Counts maximum-beauty k-subsequences by selecting the highest frequencies.
     ///
@@ -18,8 +20,6 @@ impl Solution {
     /// - Time: O(n + A log A), where A <= 26.
     /// - Space: O(A).
     pub fn count_k_subsequences_with_max_beauty(s: String, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
         let k = k as usize;
         let mut counts = [0_i64; 26];
         for &byte in s.as_bytes() {
@@ -37,52 +37,15 @@ impl Solution {
         let equal = freqs.iter().filter(|count| **count == threshold).count();
         let need = k - higher;
 
-        let product_highers = freqs
+        let product_highers: Mint = freqs
             .iter()
             .take(higher)
-            .fold(1_i64, |acc, count| (acc * (*count % MOD)) % MOD);
-
-        let ways_equal = Self::n_choose_k(equal, need);
-        let pow_equal = Self::mod_pow(threshold % MOD, need as i64);
-        let result = (((product_highers * ways_equal) % MOD) * pow_equal) % MOD;
-
-        result as i32
-    }
-
-    fn n_choose_k(n: usize, k: usize) -> i64 {
-        const MOD: i64 = 1_000_000_007;
-        if k > n {
-            return 0;
-        }
-
-        let mut fact = vec![1_i64; n + 1];
-        for i in 1..=n {
-            fact[i] = (fact[i - 1] * i as i64) % MOD;
-        }
-
-        let mut inv_fact = vec![1_i64; n + 1];
-        inv_fact[n] = Self::mod_pow(fact[n], MOD - 2);
-        for i in (1..=n).rev() {
-            inv_fact[i - 1] = (inv_fact[i] * i as i64) % MOD;
-        }
-
-        (((fact[n] * inv_fact[k]) % MOD) * inv_fact[n - k]) % MOD
-    }
-
-    fn mod_pow(mut base: i64, mut exp: i64) -> i64 {
-        const MOD: i64 = 1_000_000_007;
-        let mut result = 1_i64;
-        base %= MOD;
-
-        while exp > 0 {
-            if exp & 1 == 1 {
-                result = ((result as i128 * base as i128) % MOD as i128) as i64;
-            }
-            base = ((base as i128 * base as i128) % MOD as i128) as i64;
-            exp >>= 1;
-        }
+            .map(|&count| Mint::from(count))
+            .product();
+        let ways_equal = Combinatorics::with_capacity(equal).choose(equal, need);
+        let pow_equal = Mint::from(threshold).pow(need as u64);
 
-        result
+        (product_highers * ways_equal * pow_equal).value() as i32
     }
 }
 
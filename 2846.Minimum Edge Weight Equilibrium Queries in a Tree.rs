@@ -1,78 +1,21 @@
-struct SparseTable<T: Copy, F: Fn(T, T) -> T> {
-    table: Vec<Vec<T>>,
-    op: F,
-}
-
-impl<T: Copy, F: Fn(T, T) -> T> SparseTable<T, F> {
-    fn new(values: Vec<T>, op: F) -> Self {
-        let n = values.len();
-        let max_pow = n.ilog2() as usize;
-        let mut table = Vec::with_capacity(max_pow + 1);
-        table.push(values);
-
-        for k in 1..=max_pow {
-            let size = n + 1 - (1 << k);
-            let mut level = Vec::with_capacity(size);
-            let step = 1 << (k - 1);
-            for (&left, &right) in table[k - 1]
-                .iter()
-                .zip(table[k - 1].iter().skip(step))
-                .take(size)
-            {
-                let combined = op(left, right);
-                level.push(combined);
-            }
-            table.push(level);
-        }
-
-        Self { table, op }
-    }
-
-    #[inline]
-    fn query(&self, l: usize, r: usize) -> T {
-        let k = (r - l + 1).ilog2() as usize;
-        (self.op)(self.table[k][l], self.table[k][r + 1 - (1 << k)])
-    }
-}
-
-fn build_euler_tour(
-    node: usize,
-    parent: usize,
-    depth: usize,
-    graph: &[Vec<(usize, u8)>],
-    euler: &mut Vec<(usize, usize)>,
-    first: &mut [usize],
-    counter: &mut [[u16; 26]],
-) {
-    first[node] = euler.len();
-    euler.push((depth, node));
-    for &(next, weight) in &graph[node] {
-        if next == parent {
-            continue;
-        }
-        counter[next] = counter[node];
-        counter[next][weight as usize] += 1;
-        build_euler_tour(next, node, depth + 1, graph, euler, first, counter);
-        euler.push((depth, node));
-    }
-}
+use crate::rooted_tree::RootedTree;
 
 impl Solution {
-    /// Answers minimum operations per query using Euler tour RMQ for LCA.
+    /// Answers minimum operations per query with root-path weight counts and
+    /// binary-lifting LCA.
     ///
     /// # Intuition
     /// Along any path, keeping the most frequent weight and changing the rest minimizes operations.
     ///
     /// # Approach
-    /// - Build a weighted adjacency list.
-    /// - Run an Euler tour from root `0` to record depths and first visits, while tracking
-    ///   prefix counts of edge weights along the root path.
-    /// - Build a sparse table over the Euler tour to answer LCA queries in O(1).
-    /// - For each query, compute weight counts on the path via prefix differences; answer is
-    ///   `path_len - max_count`.
+    /// - Root the tree at `0`.
+    /// - In preorder, extend each parent's counts of edge weights on the root path by the
+    ///   weight of the edge to the child.
+    /// - For each query, compute weight counts on the path via prefix differences at the LCA;
+    ///   answer is `path_len - max_count`.
     ///
     /// # Complexity
-    /// - Time: O(n log n + 26m)
+    /// - Time: O(n log n + m (26 + log n))
     /// - Space: O(n log n + 26n)
     pub fn min_operations_queries(
         n: i32,
@@ -84,39 +27,17 @@ impl Solution {
             return Vec::with_capacity(0);
         }
 
-        let mut degrees = vec![0_usize; node_count];
-        for edge in &edges {
-            let u = edge[0] as usize;
-            let v = edge[1] as usize;
-            degrees[u] += 1;
-            degrees[v] += 1;
-        }
-        let mut graph: Vec<Vec<(usize, u8)>> = degrees
-            .into_iter()
-            .map(|degree| Vec::with_capacity(degree))
-            .collect();
-        for edge in edges {
-            let u = edge[0] as usize;
-            let v = edge[1] as usize;
-            let w = (edge[2] - 1) as u8;
-            graph[u].push((v, w));
-            graph[v].push((u, w));
-        }
-
-        let mut counter = vec![[0_u16; 26]; node_count];
-        let mut first = vec![0_usize; node_count];
-        let mut euler = Vec::with_capacity(2 * node_count - 1);
-        build_euler_tour(
-            0,
-            usize::MAX,
+        let tree = RootedTree::new(
+            node_count,
+            edges.iter().map(|e| (e[0] as usize, e[1] as usize)),
             0,
-            &graph,
-            &mut euler,
-            &mut first,
-            &mut counter,
         );
-
-        let rmq = SparseTable::new(euler, |a, b| if a.0 <= b.0 { a } else { b });
+        let mut counter = vec![[0_u16; 26]; node_count];
+        for &v in &tree.preorder()[1..] {
+            let (parent, edge) = (tree.parent(v).unwrap(), tree.parent_edge(v).unwrap());
+            counter[v] = counter[parent];
+            counter[v][(edges[edge][2] - 1) as usize] += 1;
+        }
 
         let mut answers = Vec::with_capacity(queries.len());
         for query in queries {
@@ -126,9 +47,7 @@ impl Solution {
                 answers.push(0);
                 continue;
             }
-            let left = first[a].min(first[b]);
-            let right = first[a].max(first[b]);
-            let lca = rmq.query(left, right).1;
+            let lca = tree.lca(a, b);
 
             let mut total = 0_i32;
             let mut max_count = 0_i32;
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts the number of length-`k` rotations that turn `s` into `t`.
     ///
@@ -16,8 +18,6 @@ impl Solution {
     /// - Time: O(n + log k)
     /// - Space: O(n)
     pub fn number_of_ways(s: String, t: String, k: i64) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
         let n = s.len();
         if n == 0 {
             return 0;
@@ -25,9 +25,9 @@ impl Solution {
 
         let (match_zero, match_non_zero) = Self::rotation_match_counts(&s, &t);
         let (same_count, other_count) = Self::shift_counts(n as i64, k);
-        let total = (match_zero * same_count + match_non_zero * other_count) % MOD;
+        let total = same_count * match_zero + other_count * match_non_zero;
 
-        total as i32
+        total.value() as i32
     }
 
     fn rotation_match_counts(s: &str, t: &str) -> (i64, i64) {
@@ -88,17 +88,18 @@ impl Solution {
         prefix
     }
 
-    fn shift_counts(n: i64, k: i64) -> (i64, i64) {
-        const MOD: i64 = 1_000_000_007;
-
-        let base = [[0_i64, (n - 1) % MOD], [1_i64, (n - 2).rem_euclid(MOD)]];
+    fn shift_counts(n: i64, k: i64) -> (Mint, Mint) {
+        let base = [
+            [Mint::ZERO, Mint::from(n - 1)],
+            [Mint::ONE, Mint::from(n - 2)],
+        ];
         let power = Self::matrix_pow(base, k);
 
         (power[0][0], power[1][0])
     }
 
-    fn matrix_pow(mut base: [[i64; 2]; 2], mut exp: i64) -> [[i64; 2]; 2] {
-        let mut result = [[1_i64, 0_i64], [0_i64, 1_i64]];
+    fn matrix_pow(mut base: [[Mint; 2]; 2], mut exp: i64) -> [[Mint; 2]; 2] {
+        let mut result = [[Mint::ONE, Mint::ZERO], [Mint::ZERO, Mint::ONE]];
 
         while exp > 0 {
             if exp & 1 == 1 {
@@ -111,14 +112,12 @@ impl Solution {
         result
     }
 
-    fn matrix_mul(a: [[i64; 2]; 2], b: [[i64; 2]; 2]) -> [[i64; 2]; 2] {
-        const MOD: i64 = 1_000_000_007;
-        let mut result = [[0_i64; 2]; 2];
+    fn matrix_mul(a: [[Mint; 2]; 2], b: [[Mint; 2]; 2]) -> [[Mint; 2]; 2] {
+        let mut result = [[Mint::ZERO; 2]; 2];
 
         for i in 0..2 {
             for j in 0..2 {
-                let sum = a[i][0] as i128 * b[0][j] as i128 + a[i][1] as i128 * b[1][j] as i128;
-                result[i][j] = (sum % MOD as i128) as i64;
+                result[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j];
             }
         }
 
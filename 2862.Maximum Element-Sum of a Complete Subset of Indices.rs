@@ -1,3 +1,5 @@
+use crate::number_theory::Sieve;
+
 impl Solution {
     /// Groups indices by square-free kernel and picks the largest sum.
     ///
@@ -11,7 +13,7 @@ impl Solution {
     /// - Sum values by kernel and return the maximum sum.
     ///
     /// # Complexity
-    /// - Time: O(n log log n)
+    /// - Time: O(n)
     /// - Space: O(n)
     pub fn maximum_sum(nums: Vec<i32>) -> i64 {
         let n = nums.len();
@@ -19,8 +21,7 @@ impl Solution {
             return 0;
         }
 
-        let smallest_prime_factor = Self::smallest_prime_factors(n);
-        let kernels = Self::square_free_kernels(&smallest_prime_factor);
+        let kernels = Self::square_free_kernels(&Sieve::new(n));
         let mut sums = vec![0_i64; n + 1];
         let mut best = 0_i64;
 
@@ -37,37 +38,14 @@ impl Solution {
         best
     }
 
-    fn smallest_prime_factors(limit: usize) -> Vec<usize> {
-        let mut spf: Vec<usize> = (0..=limit).collect();
-        if limit >= 1 {
-            spf[1] = 1;
-        }
-
-        let mut p = 2_usize;
-        while p * p <= limit {
-            if spf[p] == p {
-                let mut multiple = p * p;
-                while multiple <= limit {
-                    if spf[multiple] == multiple {
-                        spf[multiple] = p;
-                    }
-                    multiple += p;
-                }
-            }
-            p += 1;
-        }
-
-        spf
-    }
-
-    fn square_free_kernels(spf: &[usize]) -> Vec<usize> {
-        let limit = spf.len() - 1;
+    fn square_free_kernels(sieve: &Sieve) -> Vec<usize> {
+        let limit = sieve.limit();
         let mut kernels = vec![1_usize; limit + 1];
 
         for value in 2..=limit {
-            let prime = spf[value];
+            let prime = sieve.smallest_factor(value);
             let reduced = value / prime;
-            if reduced % prime == 0 {
+            if reduced.is_multiple_of(prime) {
                 kernels[value] = kernels[reduced / prime];
             } else {
                 kernels[value] = kernels[reduced] * prime;
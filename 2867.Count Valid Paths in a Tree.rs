@@ -1,3 +1,5 @@
+use crate::number_theory::Sieve;
+
 impl Solution {
     /// Counts paths that contain exactly one prime label.
     ///
@@ -21,7 +23,7 @@ impl Solution {
     /// - Space: O(n)
     pub fn count_paths(n: i32, edges: Vec<Vec<i32>>) -> i64 {
         let n = n as usize;
-        let is_prime = Self::sieve_primes(n);
+        let sieve = Sieve::new(n);
         let mut graph = vec![Vec::new(); n + 1];
 
         for edge in edges {
@@ -31,11 +33,11 @@ impl Solution {
             graph[v].push(u);
         }
 
-        let component_size = Self::non_prime_component_sizes(n, &graph, &is_prime);
+        let component_size = Self::non_prime_component_sizes(n, &graph, &sieve);
         let mut answer = 0_i64;
 
         for node in 1..=n {
-            if !is_prime[node] {
+            if !sieve.is_prime(node) {
                 continue;
             }
             let mut total = 0_i64;
@@ -43,7 +45,7 @@ impl Solution {
             let mut accumulated = 0_i64;
 
             for &neighbor in &graph[node] {
-                if is_prime[neighbor] {
+                if sieve.is_prime(neighbor) {
                     continue;
                 }
                 let size = component_size[neighbor] as i64;
@@ -58,34 +60,12 @@ impl Solution {
         answer
     }
 
-    fn sieve_primes(limit: usize) -> Vec<bool> {
-        let mut is_prime = vec![true; limit + 1];
-        is_prime[0] = false;
-        if limit >= 1 {
-            is_prime[1] = false;
-        }
-
-        let mut p = 2_usize;
-        while p * p <= limit {
-            if is_prime[p] {
-                let mut multiple = p * p;
-                while multiple <= limit {
-                    is_prime[multiple] = false;
-                    multiple += p;
-                }
-            }
-            p += 1;
-        }
-
-        is_prime
-    }
-
-    fn non_prime_component_sizes(n: usize, graph: &[Vec<usize>], is_prime: &[bool]) -> Vec<usize> {
+    fn non_prime_component_sizes(n: usize, graph: &[Vec<usize>], sieve: &Sieve) -> Vec<usize> {
         let mut component_size = vec![0_usize; n + 1];
         let mut visited = vec![false; n + 1];
 
         for node in 1..=n {
-            if is_prime[node] || visited[node] {
+            if sieve.is_prime(node) || visited[node] {
                 continue;
             }
             let mut stack = vec![node];
@@ -95,7 +75,7 @@ impl Solution {
             while let Some(current) = stack.pop() {
                 members.push(current);
                 for &neighbor in &graph[current] {
-                    if !is_prime[neighbor] && !visited[neighbor] {
+                    if !sieve.is_prime(neighbor) && !visited[neighbor] {
                         visited[neighbor] = true;
                         stack.push(neighbor);
                     }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Greedily concentrates available bits into k numbers.
     ///
@@ -15,7 +17,6 @@ impl Solution {
     /// - Time: O(n * popcount + k * B), where B = 31
     /// - Space: O(B)
     pub fn max_sum(nums: Vec<i32>, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let mut bit_counts = [0_u32; 31];
         for num in nums {
             let mut value = num as u32;
@@ -26,7 +27,7 @@ impl Solution {
             }
         }
 
-        let mut result = 0_i64;
+        let mut result = Mint::ZERO;
         let k = k as usize;
         let mut active_bits: Vec<usize> = (0..31).filter(|&bit| bit_counts[bit] > 0).collect();
         let mut next_bits = Vec::with_capacity(active_bits.len());
@@ -44,10 +45,11 @@ impl Solution {
                 }
             }
             std::mem::swap(&mut active_bits, &mut next_bits);
-            result = (result + (value * value) % MOD) % MOD;
+            let value = Mint::from(value);
+            result += value * value;
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -72,6 +74,6 @@ mod tests {
 
     #[test]
     fn test_all_bits_concentrated() {
-        assert_eq!(Solution::max_sum(vec![1, 1, 1], 2), 5);
+        assert_eq!(Solution::max_sum(vec![1, 1, 1], 2), 2);
     }
 }
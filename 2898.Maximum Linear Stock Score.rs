@@ -18,7 +18,7 @@ impl Solution {
     /// - Space: O(n) for the hash map
     pub fn max_score(prices: Vec<i32>) -> i64 {
         let mut groups: HashMap<i32, i64> = HashMap::with_capacity(prices.len());
-        prices.iter().enumerate().for_each(|(i, x)| {
+        prices.iter().enumerate().for_each(|(i, &x)| {
             *groups.entry(x - i as i32).or_insert(0) += x as i64;
         });
         groups.values().copied().max().unwrap_or(0)
@@ -45,7 +45,7 @@ impl Solution {
             }
         }
 
-        let mut i = (0..n).rev().find(|&i| f[i] == mx).unwrap_or(0);
+        let i = (0..n).rev().find(|&i| f[i] == mx).unwrap_or(0);
         let mut ans = Vec::with_capacity(n);
         let mut j = i as i32;
         while j >= 0 {
@@ -69,7 +69,10 @@ mod tests {
             .map(String::from)
             .collect();
         let result = Solution::get_words_in_longest_subsequence(3, words, vec![1, 2, 2]);
-        assert_eq!(result, vec!["bab", "dab"]);
+        // Both ["bab", "dab"] and ["bab", "cab"] are accepted answers
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], "bab");
+        assert!(result[1] == "dab" || result[1] == "cab");
     }
 
     #[test]
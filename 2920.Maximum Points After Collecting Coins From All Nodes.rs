@@ -43,10 +43,10 @@ impl Solution {
                 order.push(node);
                 continue;
             }
-            if parent != usize::MAX {
-                if let Some(pos) = graph[node].iter().position(|&next| next == parent) {
-                    graph[node].swap_remove(pos);
-                }
+            if parent != usize::MAX
+                && let Some(pos) = graph[node].iter().position(|&next| next == parent)
+            {
+                graph[node].swap_remove(pos);
             }
             stack.push((node, parent, true));
             for &child in &graph[node] {
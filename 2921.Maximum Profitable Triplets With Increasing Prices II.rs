@@ -1,32 +1,4 @@
-struct BinaryIndexedTree {
-    n: usize,
-    c: Vec<i32>,
-}
-
-impl BinaryIndexedTree {
-    fn new(n: usize) -> Self {
-        Self {
-            n,
-            c: vec![0; n + 1],
-        }
-    }
-
-    fn update(&mut self, mut x: usize, v: i32) {
-        while x <= self.n {
-            self.c[x] = self.c[x].max(v);
-            x += x & x.wrapping_neg();
-        }
-    }
-
-    fn query(&self, mut x: usize) -> i32 {
-        let mut mx = 0;
-        while x > 0 {
-            mx = mx.max(self.c[x]);
-            x -= x & x.wrapping_neg();
-        }
-        mx
-    }
-}
+use crate::fenwick::{Fenwick, Max};
 
 impl Solution {
     /// Finds the maximum profit from a triplet with strictly increasing prices using BIT.
@@ -52,19 +24,19 @@ impl Solution {
         let mut left = vec![0i32; n];
         let mut right = vec![0i32; n];
 
-        let mut tree1 = BinaryIndexedTree::new(m + 1);
-        let mut tree2 = BinaryIndexedTree::new(m + 1);
+        let mut tree1: Fenwick<Max<i32>> = Fenwick::new(m + 1);
+        let mut tree2: Fenwick<Max<i32>> = Fenwick::new(m + 1);
 
         for i in 0..n {
             let x = prices[i] as usize;
-            left[i] = tree1.query(x - 1);
-            tree1.update(x, profits[i]);
+            left[i] = tree1.prefix(x).0.max(0);
+            tree1.add(x, Max(profits[i]));
         }
 
         for i in (0..n).rev() {
             let x = (m as i32 + 1 - prices[i]) as usize;
-            right[i] = tree2.query(x - 1);
-            tree2.update(x, profits[i]);
+            right[i] = tree2.prefix(x).0.max(0);
+            tree2.add(x, Max(profits[i]));
         }
 
         (0..n)
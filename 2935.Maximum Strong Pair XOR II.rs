@@ -1,79 +1,4 @@
-struct TrieNode {
-    children: [Option<usize>; 2],
-    count: usize,
-}
-
-impl TrieNode {
-    fn new() -> Self {
-        Self {
-            children: [None; 2],
-            count: 0,
-        }
-    }
-}
-
-struct Trie {
-    nodes: Vec<TrieNode>,
-    max_bit: u32,
-}
-
-impl Trie {
-    fn new(max_bit: u32) -> Self {
-        Self {
-            nodes: vec![TrieNode::new()],
-            max_bit,
-        }
-    }
-
-    fn insert(&mut self, value: u32) {
-        let mut idx = 0;
-        self.nodes[idx].count += 1;
-        for bit in (0..=self.max_bit).rev() {
-            let b = ((value >> bit) & 1) as usize;
-            let next = match self.nodes[idx].children[b] {
-                Some(next) => next,
-                None => {
-                    let next = self.nodes.len();
-                    self.nodes.push(TrieNode::new());
-                    self.nodes[idx].children[b] = Some(next);
-                    next
-                }
-            };
-            idx = next;
-            self.nodes[idx].count += 1;
-        }
-    }
-
-    fn remove(&mut self, value: u32) {
-        let mut idx = 0;
-        self.nodes[idx].count -= 1;
-        for bit in (0..=self.max_bit).rev() {
-            let b = ((value >> bit) & 1) as usize;
-            let next = self.nodes[idx].children[b].expect("value must exist in trie");
-            idx = next;
-            self.nodes[idx].count -= 1;
-        }
-    }
-
-    fn max_xor(&self, value: u32) -> u32 {
-        let mut idx = 0;
-        let mut result = 0_u32;
-        for bit in (0..=self.max_bit).rev() {
-            let b = ((value >> bit) & 1) as usize;
-            let preferred = b ^ 1;
-            if let Some(next) = self.nodes[idx].children[preferred] {
-                if self.nodes[next].count > 0 {
-                    result |= 1_u32 << bit;
-                    idx = next;
-                    continue;
-                }
-            }
-            let next = self.nodes[idx].children[b].expect("trie must contain at least one value");
-            idx = next;
-        }
-        result
-    }
-}
+use crate::trie::{Bits, Trie};
 
 impl Solution {
     /// Maximizes the XOR among all strong pairs using a sliding-window trie.
@@ -86,7 +11,7 @@ impl Solution {
     /// # Approach
     /// - Sort `nums` in ascending order.
     /// - Maintain a window `[l, r]` that satisfies `nums[l] * 2 >= nums[r]`.
-    /// - Store the window values in a bitwise trie with reference counts.
+    /// - Store the window values in a bitwise trie, removing them as they leave the window.
     /// - For each `r`, evict too-small values, insert `nums[r]`, and query the maximum XOR.
     ///
     /// # Complexity
@@ -97,24 +22,20 @@ impl Solution {
         nums.sort_unstable();
 
         let max_value = *nums.last().expect("nums is non-empty") as u32;
-        let max_bit = if max_value == 0 {
-            0
-        } else {
-            31 - max_value.leading_zeros()
-        };
+        let width = (u32::BITS - max_value.leading_zeros()).max(1);
 
-        let mut trie = Trie::new(max_bit);
+        let mut trie: Trie<Bits> = Trie::new();
         let mut left = 0_usize;
-        let mut best = 0_u32;
+        let mut best = 0_u64;
 
         for &value in &nums {
             let y = value as i64;
             while (nums[left] as i64) * 2 < y {
-                trie.remove(nums[left] as u32);
+                trie.remove(Bits::msb_first(nums[left] as u64, width));
                 left += 1;
             }
-            trie.insert(value as u32);
-            best = best.max(trie.max_xor(value as u32));
+            trie.insert(Bits::msb_first(value as u64, width));
+            best = best.max(trie.max_xor(value as u64, width).unwrap_or(0));
         }
 
         best as i32
@@ -1,17 +1,4 @@
-use std::sync::LazyLock;
-
-const MOD: i64 = 1_000_000_007;
-const MAX_N: usize = 100_000;
-
-static PRECOMP: LazyLock<(Vec<i64>, Vec<i64>)> = LazyLock::new(|| {
-    let mut factorial = vec![1_i64; MAX_N + 1];
-    let mut pow2 = vec![1_i64; MAX_N + 1];
-    for i in 1..=MAX_N {
-        factorial[i] = factorial[i - 1] * i as i64 % MOD;
-        pow2[i] = pow2[i - 1] * 2 % MOD;
-    }
-    (factorial, pow2)
-});
+use crate::mod_int::{Combinatorics, Mint};
 
 impl Solution {
     /// Counts infection sequences by combining independent segments.
@@ -22,47 +9,34 @@ impl Solution {
     /// Interleaving the segment-wise sequences yields a multinomial factor.
     ///
     /// # Approach
-    /// - Precompute factorials and powers of two once (up to `n <= 10^5`).
+    /// - Build factorial tables up to `n`.
     /// - Compute the multinomial factor `total! / product(len_i!)`.
     /// - Multiply by `2^(len-1)` for each middle segment of length `len > 0`.
     ///
     /// # Complexity
-    /// - Time: O(m), where `m = sick.len()`
-    /// - Space: O(1) per call, with O(MAX_N) precomputation
+    /// - Time: O(n + m log n), where `m = sick.len()`
+    /// - Space: O(n)
     pub fn number_of_sequence(n: i32, sick: Vec<i32>) -> i32 {
         let n = n as usize;
         let infected = sick.len();
-        let total = n - infected;
-        let (factorial, pow2) = &*PRECOMP;
-
-        let mut numerator = factorial[total];
-        let mut denominator = 1_i64;
+        let mut comb = Combinatorics::with_capacity(n);
 
+        let middle: Vec<usize> = sick
+            .windows(2)
+            .map(|w| (w[1] - w[0] - 1) as usize)
+            .collect();
         let left_len = sick[0] as usize;
-        denominator = denominator * factorial[left_len] % MOD;
-        for i in 1..infected {
-            let len = (sick[i] - sick[i - 1] - 1) as usize;
-            denominator = denominator * factorial[len] % MOD;
-            if len > 0 {
-                numerator = numerator * pow2[len - 1] % MOD;
-            }
-        }
         let right_len = n - 1 - sick[infected - 1] as usize;
-        denominator = denominator * factorial[right_len] % MOD;
 
-        ((numerator * Self::mod_pow(denominator, MOD - 2)) % MOD) as i32
-    }
+        let mut lens = middle.clone();
+        lens.extend([left_len, right_len]);
+        let orders: Mint = middle
+            .iter()
+            .filter(|&&len| len > 0)
+            .map(|&len| Mint::new(2).pow(len as u64 - 1))
+            .product();
 
-    fn mod_pow(mut base: i64, mut exp: i64) -> i64 {
-        let mut result = 1_i64;
-        while exp > 0 {
-            if exp & 1 == 1 {
-                result = result * base % MOD;
-            }
-            base = base * base % MOD;
-            exp >>= 1;
-        }
-        result
+        (comb.multinomial(&lens) * orders).value() as i32
     }
 }
 
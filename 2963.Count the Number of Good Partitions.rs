@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts good partitions by grouping overlapping value ranges.
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(n)
     pub fn number_of_good_partitions(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         if nums.is_empty() {
             return 0;
         }
@@ -27,7 +28,7 @@ impl Solution {
         let segments = nums
             .iter()
             .enumerate()
-            .fold((0_i64, 0_usize), |(seg, current_end), (i, &value)| {
+            .fold((0_u64, 0_usize), |(seg, current_end), (i, &value)| {
                 let new_end = current_end.max(last_index[&value]);
                 let new_seg = if i == new_end { seg + 1 } else { seg };
                 (new_seg, new_end)
@@ -38,18 +39,7 @@ impl Solution {
             return 1;
         }
 
-        let mut result = 1_i64;
-        let mut base = 2_i64;
-        let mut exp = segments - 1;
-        while exp > 0 {
-            if exp & 1 == 1 {
-                result = (result * base) % MOD;
-            }
-            base = (base * base) % MOD;
-            exp >>= 1;
-        }
-
-        result as i32
+        Mint::new(2).pow(segments - 1).value() as i32
     }
 }
 
@@ -83,7 +73,7 @@ mod tests {
 
     #[test]
     fn test_two_segments() {
-        let nums = vec![5, 6, 5, 7, 8];
+        let nums = vec![5, 6, 5, 7];
         assert_eq!(Solution::number_of_good_partitions(nums), 2);
     }
 
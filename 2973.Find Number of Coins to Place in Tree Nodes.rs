@@ -92,6 +92,7 @@ impl Solution {
     /// - subtree size
     /// - top three largest costs
     /// - bottom two smallest costs
+    ///
     /// Merge children by inserting their extremes into fixed-size arrays.
     /// If the subtree has fewer than three nodes, place `1` coin. Otherwise,
     /// compute the best product from (largest * second * third) and
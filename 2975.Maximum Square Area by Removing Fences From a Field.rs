@@ -85,7 +85,7 @@ mod tests {
 
     #[test]
     fn test_single_fence_each() {
-        assert_eq!(Solution::maximize_square_area(3, 3, vec![2], vec![2]), 1);
+        assert_eq!(Solution::maximize_square_area(3, 3, vec![2], vec![2]), 4);
     }
 
     #[test]
@@ -98,10 +98,7 @@ mod tests {
 
     #[test]
     fn test_no_common_gaps_asymmetric() {
-        assert_eq!(
-            Solution::maximize_square_area(10, 5, vec![5], vec![2, 3]),
-            -1
-        );
+        assert_eq!(Solution::maximize_square_area(6, 7, vec![2], vec![4]), -1);
     }
 
     #[test]
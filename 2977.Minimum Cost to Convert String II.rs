@@ -63,12 +63,14 @@ impl Solution {
             }
             let mut index: HashMap<Vec<u8>, usize> = HashMap::with_capacity(rules.len() * 2);
             let mut edges: Vec<(usize, usize, i64)> = Vec::with_capacity(rules.len());
+            let mut next_id = 0;
 
             for (o, c, co) in rules {
                 let from = match index.entry(o) {
                     Entry::Occupied(entry) => *entry.get(),
                     Entry::Vacant(entry) => {
-                        let id = index.len();
+                        let id = next_id;
+                        next_id += 1;
                         entry.insert(id);
                         id
                     }
@@ -77,7 +79,8 @@ impl Solution {
                 let to = match index.entry(c) {
                     Entry::Occupied(entry) => *entry.get(),
                     Entry::Vacant(entry) => {
-                        let id = index.len();
+                        let id = next_id;
+                        next_id += 1;
                         entry.insert(id);
                         id
                     }
@@ -1,59 +1,12 @@
-struct Fenwick {
-    tree: Vec<i64>,
-}
-
-impl Fenwick {
-    fn new(size: usize) -> Self {
-        Self {
-            tree: vec![0; size + 1],
-        }
-    }
-
-    fn add(&mut self, mut idx: usize, delta: i64) {
-        let n = self.tree.len();
-        while idx < n {
-            self.tree[idx] += delta;
-            idx += idx & (!idx + 1);
-        }
-    }
-
-    fn sum(&self, mut idx: usize) -> i64 {
-        let mut acc = 0i64;
-        while idx > 0 {
-            acc += self.tree[idx];
-            idx &= idx - 1;
-        }
-        acc
-    }
-
-    fn lower_bound(&self, target: i64) -> usize {
-        let mut idx = 0usize;
-        let mut bit = 1usize;
-        while bit < self.tree.len() {
-            bit <<= 1;
-        }
-        let mut acc = 0i64;
-        while bit > 0 {
-            let next = idx + bit;
-            if next < self.tree.len() && acc + self.tree[next] < target {
-                acc += self.tree[next];
-                idx = next;
-            }
-            bit >>= 1;
-        }
-        idx + 1
-    }
-}
+use crate::fenwick::Fenwick;
 
-fn sum_k_smallest(k: usize, counts: &Fenwick, sums: &Fenwick, values: &[i32]) -> i64 {
+fn sum_k_smallest(k: usize, counts: &Fenwick<i64>, sums: &Fenwick<i64>, values: &[i32]) -> i64 {
     if k == 0 {
         return 0;
     }
-    let pos = counts.lower_bound(k as i64);
-    let count_before = counts.sum(pos - 1);
-    let sum_before = sums.sum(pos - 1);
-    let remaining = k as i64 - count_before;
-    sum_before + remaining * values[pos - 1] as i64
+    let pos = counts.kth(k as i64);
+    let remaining = k as i64 - counts.prefix(pos);
+    sums.prefix(pos) + remaining * values[pos] as i64
 }
 
 impl Solution {
@@ -83,11 +36,9 @@ impl Solution {
 
         let mut compressed = vec![0usize; n];
         for (idx, value) in nums.iter().enumerate().skip(1) {
-            let pos = values
+            compressed[idx] = values
                 .binary_search(value)
-                .expect("value should be present in compression")
-                + 1;
-            compressed[idx] = pos;
+                .expect("value should be present in compression");
         }
 
         let mut counts = Fenwick::new(values.len());
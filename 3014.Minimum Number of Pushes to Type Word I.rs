@@ -10,8 +10,9 @@ impl Solution {
     /// Let `n = word.len()`, `full = n / 8`, `rem = n % 8`.
     /// - `full` complete levels contribute `8 * full * (full + 1) / 2`
     /// - `rem` leftover letters at depth `full + 1` contribute `rem * (full + 1)`
-    /// Total = `(full * (full + 1) / 2) * 8 + rem * (full + 1)`
-    ///       = `(full + 1) * (4 * full + rem)`
+    ///
+    /// Total = `(full * (full + 1) / 2) * 8 + rem * (full + 1)`, which simplifies
+    /// to `(full + 1) * (4 * full + rem)`.
     ///
     /// # Complexity
     /// - Time: O(1)
@@ -21,7 +21,7 @@ impl Solution {
         let bytes = word.as_bytes();
         let n = bytes.len();
         let k = k as usize;
-        let max_steps = (n + k - 1) / k;
+        let max_steps = n.div_ceil(k);
 
         let z = Self::build_z(bytes);
         for step in 1..max_steps {
@@ -13,6 +13,7 @@ impl Solution {
     /// For each value `v` with frequency `c`:
     /// - One element can extend `best[v - 1]` to `best[v]`.
     /// - One element can extend a sequence ending at `v` to `v + 1`.
+    ///
     /// If `c == 1`, the `v + 1` update must use the previous `best[v]`
     /// snapshot; if `c >= 2`, another element can use the updated `best[v]`.
     ///
@@ -105,7 +105,7 @@ mod tests {
         // arr1[i], arr2[i] <= 10^8 = 100_000_000 (9 digits)
         assert_eq!(
             Solution::longest_common_prefix(vec![100_000_000], vec![100_000_001]),
-            9
+            8
         );
     }
 }
@@ -1,110 +1,39 @@
+use crate::trie::{Pairs, Trie};
+
 impl Solution {
-    /// Count prefix-suffix pairs using KMP borders and a trie.
+    /// Count prefix-suffix pairs with a trie keyed by character pairs.
     ///
     /// # Intuition
-    /// A previous word matches iff it is a border (prefix = suffix) of the
-    /// current word. Borders are obtained from the KMP prefix function.
+    /// Pair each word's `i`-th character with its `i`-th character from the
+    /// end. A shorter word is both a prefix and a suffix of a longer one
+    /// exactly when its pair sequence is a prefix of the longer word's.
     ///
     /// # Approach
-    /// Maintain a trie of all prior words with an end counter at each node.
-    /// For each word:
-    /// - Build the KMP prefix function and mark all border lengths.
-    /// - Walk the trie along the word's prefix once; at each position, if the
-    ///   prefix length is a border, add the stored end count.
-    /// - Insert the word into the trie.
+    /// Maintain a `Trie<Pairs>` of all prior words, whose nodes count the
+    /// words ending there. For each word:
+    /// - Walk the trie along the word's pair sequence and add the end count
+    ///   of every node visited.
+    /// - Insert the word's pair sequence into the trie.
     ///
     /// # Complexity
-    /// - Time: O(total_length)
+    /// - Time: O(total_length), times a binary search over at most 26² children per step
     /// - Space: O(total_length)
     pub fn count_prefix_suffix_pairs(words: Vec<String>) -> i64 {
-        let mut trie = Trie::new();
+        let mut trie: Trie<Pairs> = Trie::new();
         let mut answer = 0i64;
 
         for word in words {
-            let prefix = Self::build_prefix(word.as_bytes());
-            let borders = Self::build_borders(&prefix);
-            answer += trie.count_borders(&word, &borders);
-            trie.insert(&word);
+            let bytes = word.into_bytes();
+            let pairs = || bytes.iter().copied().zip(bytes.iter().rev().copied());
+            answer += trie
+                .path(pairs())
+                .map(|node| trie.ends(node) as i64)
+                .sum::<i64>();
+            trie.insert(pairs());
         }
 
         answer
     }
-
-    fn build_prefix(bytes: &[u8]) -> Vec<usize> {
-        let n = bytes.len();
-        let mut prefix = vec![0usize; n];
-        for i in 1..n {
-            let mut j = prefix[i - 1];
-            while j > 0 && bytes[i] != bytes[j] {
-                j = prefix[j - 1];
-            }
-            if bytes[i] == bytes[j] {
-                j += 1;
-            }
-            prefix[i] = j;
-        }
-        prefix
-    }
-
-    fn build_borders(prefix: &[usize]) -> Vec<bool> {
-        let n = prefix.len();
-        let mut borders = vec![false; n + 1];
-        let mut len = n;
-        while len > 0 {
-            borders[len] = true;
-            len = prefix[len - 1];
-        }
-        borders
-    }
-}
-
-struct Trie {
-    next: Vec<[i32; 26]>,
-    end_count: Vec<i64>,
-}
-
-impl Trie {
-    fn new() -> Self {
-        Self {
-            next: vec![[-1; 26]],
-            end_count: vec![0],
-        }
-    }
-
-    fn insert(&mut self, word: &str) {
-        let mut node = 0usize;
-        for &byte in word.as_bytes() {
-            let idx = (byte - b'a') as usize;
-            let child = self.next[node][idx];
-            if child == -1 {
-                let new_index = self.next.len() as i32;
-                self.next[node][idx] = new_index;
-                self.next.push([-1; 26]);
-                self.end_count.push(0);
-                node = new_index as usize;
-            } else {
-                node = child as usize;
-            }
-        }
-        self.end_count[node] += 1;
-    }
-
-    fn count_borders(&self, word: &str, borders: &[bool]) -> i64 {
-        let mut node = 0usize;
-        let mut total = 0i64;
-        for (i, &byte) in word.as_bytes().iter().enumerate() {
-            let idx = (byte - b'a') as usize;
-            let child = self.next[node][idx];
-            if child == -1 {
-                break;
-            }
-            node = child as usize;
-            if borders[i + 1] {
-                total += self.end_count[node];
-            }
-        }
-        total
-    }
 }
 
 #[cfg(test)]
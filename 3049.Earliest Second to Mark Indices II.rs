@@ -28,7 +28,6 @@ impl Solution {
     /// - Time: O(m log^2 m)
     /// - Space: O(m + n)
     pub fn earliest_second_to_mark_indices(nums: Vec<i32>, change_indices: Vec<i32>) -> i32 {
-        let n = nums.len();
         let m = change_indices.len();
         let nums: Vec<i64> = nums.into_iter().map(|value| value as i64).collect();
         let change: Vec<usize> = change_indices
@@ -100,11 +99,11 @@ impl Solution {
             }
 
             let limit = suffix_len / 2;
-            if chosen > limit {
-                if let Some(Reverse(removed)) = heap.pop() {
-                    total_saving -= removed;
-                    chosen -= 1;
-                }
+            if chosen > limit
+                && let Some(Reverse(removed)) = heap.pop()
+            {
+                total_saving -= removed;
+                chosen -= 1;
             }
         }
 
@@ -1,3 +1,5 @@
+use crate::fenwick::Fenwick;
+
 impl Solution {
     /// Fenwick difference tracking for greater-count comparisons.
     ///
@@ -6,7 +8,7 @@ impl Solution {
     /// which can be derived from prefix counts in a frequency structure.
     ///
     /// # Approach
-    /// Coordinate-compress `nums` so each value maps to `1..=m`, ordered by
+    /// Coordinate-compress `nums` so each value maps to `0..m`, ordered by
     /// descending value. Maintain a single Fenwick tree storing the frequency
     /// difference `count(arr1) - count(arr2)` at each rank. For each value,
     /// the prefix sum over ranks greater than it gives the difference in
@@ -23,13 +25,13 @@ impl Solution {
 
         let mut arr1 = Vec::with_capacity(n);
         let mut arr2 = Vec::with_capacity(n);
-        let mut diff = Fenwick::new(sorted.len());
+        let mut diff: Fenwick<i32> = Fenwick::new(sorted.len());
 
         let first = nums[0];
         let second = nums[1];
         let rank = |value: i32| -> usize {
             let idx = sorted.binary_search(&value).expect("value must exist");
-            sorted.len() - idx
+            sorted.len() - 1 - idx
         };
         arr1.push(first);
         arr2.push(second);
@@ -38,7 +40,7 @@ impl Solution {
 
         for &value in nums.iter().skip(2) {
             let r = rank(value);
-            let greater_diff = diff.prefix_sum(r.saturating_sub(1));
+            let greater_diff = diff.prefix(r);
             let choose_arr1 = greater_diff > 0 || (greater_diff == 0 && arr1.len() <= arr2.len());
 
             if choose_arr1 {
@@ -55,36 +57,6 @@ impl Solution {
     }
 }
 
-#[derive(Debug, Clone)]
-struct Fenwick {
-    tree: Vec<i32>,
-}
-
-impl Fenwick {
-    fn new(size: usize) -> Self {
-        Self {
-            tree: vec![0; size + 1],
-        }
-    }
-
-    fn add(&mut self, mut idx: usize, delta: i32) {
-        let n = self.tree.len();
-        while idx < n {
-            self.tree[idx] += delta;
-            idx += idx & idx.wrapping_neg();
-        }
-    }
-
-    fn prefix_sum(&self, mut idx: usize) -> i32 {
-        let mut sum = 0i32;
-        while idx > 0 {
-            sum += self.tree[idx];
-            idx &= idx - 1;
-        }
-        sum
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
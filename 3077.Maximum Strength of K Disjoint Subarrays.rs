@@ -11,6 +11,7 @@ impl Solution {
     /// Maintain:
     /// - `dp[j]`: best strength using exactly `j` subarrays in the processed prefix.
     /// - `best[j]`: best strength where the j-th subarray ends at the current element.
+    ///
     /// For each value, update `j` in descending order to preserve previous states:
     /// `best[j] = max(best[j], dp[j - 1]) + coeff[j] * value`
     /// `dp[j] = max(dp[j], best[j])`
@@ -1,4 +1,4 @@
-const MOD: i64 = 1_000_000_007;
+use crate::mod_int::Mint;
 
 impl Solution {
     /// Weighted subsequence DP via supersequence counting.
@@ -14,6 +14,7 @@ impl Solution {
     /// - Excluding `value` from `T` doubles the weight (`* 2`), because it can be in or out of
     ///   the outer subsequence.
     /// - Including `value` adds the previous weight to `sum + value`.
+    ///
     /// Use a fresh `next` array each step and apply modulo arithmetic.
     ///
     /// # Complexity
@@ -21,23 +22,22 @@ impl Solution {
     /// - Space: O(k)
     pub fn sum_of_power(nums: Vec<i32>, k: i32) -> i32 {
         let target = k as usize;
-        let mut dp = vec![0i64; target + 1];
-        dp[0] = 1;
+        let mut dp = vec![Mint::ZERO; target + 1];
+        dp[0] = Mint::ONE;
 
         for value in nums {
             let val = value as usize;
-            let mut next = vec![0i64; target + 1];
+            let mut next = vec![Mint::ZERO; target + 1];
             for sum in 0..=target {
-                let doubled = (dp[sum] * 2) % MOD;
-                next[sum] = (next[sum] + doubled) % MOD;
+                next[sum] += dp[sum] * 2;
                 if sum + val <= target {
-                    next[sum + val] = (next[sum + val] + dp[sum]) % MOD;
+                    next[sum + val] += dp[sum];
                 }
             }
             dp = next;
         }
 
-        dp[target] as i32
+        dp[target].value() as i32
     }
 }
 
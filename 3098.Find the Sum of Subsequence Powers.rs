@@ -1,9 +1,9 @@
-const MOD: i64 = 1_000_000_007;
+use crate::mod_int::Mint;
 
-fn count_subsets_with_min_gap_at_least(values: &[i64], k: usize, gap: i64) -> i64 {
+fn count_subsets_with_min_gap_at_least(values: &[i64], k: usize, gap: i64) -> Mint {
     let n = values.len();
-    let mut dp = vec![vec![0i64; k + 1]; n + 1];
-    dp[0][0] = 1;
+    let mut dp = vec![vec![Mint::ZERO; k + 1]; n + 1];
+    dp[0][0] = Mint::ONE;
 
     let mut eligible: isize = -1;
     for i in 1..=n {
@@ -13,11 +13,11 @@ fn count_subsets_with_min_gap_at_least(values: &[i64], k: usize, gap: i64) -> i6
             eligible += 1;
         }
         let allowed_prefix = (eligible + 1) as usize;
-        dp[i][0] = 1;
+        dp[i][0] = Mint::ONE;
         for j in 1..=k {
             let without = dp[i - 1][j];
             let with = dp[allowed_prefix][j - 1];
-            dp[i][j] = (without + with) % MOD;
+            dp[i][j] = without + with;
         }
     }
 
@@ -65,23 +65,14 @@ impl Solution {
             counts.push(count_subsets_with_min_gap_at_least(&values, k, gap));
         }
 
-        let mut total = 0i64;
+        let mut total = Mint::ZERO;
         for idx in 0..diffs.len() {
             let current = counts[idx];
-            let next = if idx + 1 < counts.len() {
-                counts[idx + 1]
-            } else {
-                0
-            };
-            let mut diff = current - next;
-            diff %= MOD;
-            if diff < 0 {
-                diff += MOD;
-            }
-            total = (total + (diffs[idx] % MOD) * diff) % MOD;
+            let next = counts.get(idx + 1).copied().unwrap_or(Mint::ZERO);
+            total += (current - next) * diffs[idx];
         }
 
-        total as i32
+        total.value() as i32
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Binary search over divisibility union.
     ///
@@ -79,7 +81,7 @@ impl Solution {
     }
 
     fn lcm_limited(a: i64, b: i64, limit: i64) -> i64 {
-        let g = Self::gcd(a, b);
+        let g = gcd(a, b);
         let lcm = (a / g) as i128 * b as i128;
         if lcm > limit as i128 {
             limit.saturating_add(1)
@@ -87,15 +89,6 @@ impl Solution {
             lcm as i64
         }
     }
-
-    fn gcd(mut a: i64, mut b: i64) -> i64 {
-        while b != 0 {
-            let rem = a % b;
-            a = b;
-            b = rem;
-        }
-        a
-    }
 }
 
 #[cfg(test)]
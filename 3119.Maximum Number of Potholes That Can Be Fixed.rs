@@ -64,7 +64,7 @@ mod tests {
 
     #[test]
     fn multiple_segments() {
-        assert_eq!(Solution::max_potholes("x]..xx...x..".to_string(), 14), 0);
+        assert_eq!(Solution::max_potholes("x...xx...x..".to_string(), 14), 4);
     }
 
     #[test]
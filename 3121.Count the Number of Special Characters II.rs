@@ -16,7 +16,7 @@ impl Solution {
     /// - `lower` — set when a lowercase byte is seen.
     /// - `upper` — set when an uppercase byte is seen.
     /// - `bad`   — set when a lowercase byte is seen whose `upper` bit is already
-    ///             set (i.e., its uppercase appeared earlier in the string).
+    ///   set (i.e., its uppercase appeared earlier in the string).
     ///
     /// Answer: `(lower & upper & !bad).count_ones()`.
     ///
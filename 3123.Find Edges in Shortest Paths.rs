@@ -1,37 +1,4 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-
-#[derive(Debug, Clone, Copy)]
-struct Edge {
-    from: usize,
-    to: usize,
-    weight: i64,
-}
-
-fn dijkstra(start: usize, adjacency: &[Vec<(usize, i64)>]) -> Vec<i64> {
-    let node_count = adjacency.len();
-    let inf = i64::MAX / 4;
-    let mut dist = vec![inf; node_count];
-    let mut heap = BinaryHeap::new();
-
-    dist[start] = 0;
-    heap.push((Reverse(0i64), start));
-
-    while let Some((Reverse(current), node)) = heap.pop() {
-        if current != dist[node] {
-            continue;
-        }
-        for &(next_node, weight) in &adjacency[node] {
-            let next_cost = current + weight;
-            if next_cost < dist[next_node] {
-                dist[next_node] = next_cost;
-                heap.push((Reverse(next_cost), next_node));
-            }
-        }
-    }
-
-    dist
-}
+use crate::graph::Graph;
 
 impl Solution {
     /// Marks edges that appear in any shortest path from 0 to n - 1.
@@ -41,7 +8,7 @@ impl Solution {
     /// with a shortest suffix to n - 1.
     ///
     /// # Approach
-    /// - Run Dijkstra from 0 and from n - 1 to get two distance arrays.
+    /// - Build the undirected graph and run Dijkstra from 0 and from n - 1.
     /// - For each edge (u, v, w), check if dist0[u] + w + distN[v] equals the
     ///   shortest distance, or the symmetric orientation.
     ///
@@ -49,40 +16,25 @@ impl Solution {
     /// - Time: O((n + m) log n)
     /// - Space: O(n + m)
     pub fn find_answer(n: i32, edges: Vec<Vec<i32>>) -> Vec<bool> {
-        let node_count = n as usize;
-        let mut adjacency = vec![Vec::<(usize, i64)>::new(); node_count];
-
-        let edge_list: Vec<Edge> = edges
-            .iter()
-            .map(|edge| {
-                let from = edge[0] as usize;
-                let to = edge[1] as usize;
-                let weight = edge[2] as i64;
-                adjacency[from].push((to, weight));
-                adjacency[to].push((from, weight));
-                Edge { from, to, weight }
-            })
-            .collect();
-
-        if node_count == 0 {
-            return Vec::with_capacity(0);
-        }
-
-        let dist_from_start = dijkstra(0, &adjacency);
-        let dist_from_end = dijkstra(node_count - 1, &adjacency);
-        let shortest = dist_from_start[node_count - 1];
-        let inf = i64::MAX / 4;
-
-        if shortest >= inf {
-            return vec![false; edge_list.len()];
-        }
-
-        edge_list
+        let n = n as usize;
+        let graph = Graph::undirected(
+            n,
+            edges
+                .iter()
+                .map(|edge| (edge[0] as usize, edge[1] as usize, edge[2] as i64)),
+        );
+        let from_start = graph.dijkstra(0);
+        let from_end = graph.dijkstra(n - 1);
+        let Some(shortest) = from_start.dist(n - 1) else {
+            return vec![false; edges.len()];
+        };
+
+        let through = |u: usize, v: usize, w: i64| matches!((from_start.dist(u), from_end.dist(v)), (Some(a), Some(b)) if a + w + b == shortest);
+        edges
             .iter()
             .map(|edge| {
-                let forward = dist_from_start[edge.from] + edge.weight + dist_from_end[edge.to];
-                let backward = dist_from_start[edge.to] + edge.weight + dist_from_end[edge.from];
-                forward == shortest || backward == shortest
+                let (u, v, w) = (edge[0] as usize, edge[1] as usize, edge[2] as i64);
+                through(u, v, w) || through(v, u, w)
             })
             .collect()
     }
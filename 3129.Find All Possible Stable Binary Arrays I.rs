@@ -1,21 +1,4 @@
-const MOD: i64 = 1_000_000_007;
-
-fn mod_add(left: i64, right: i64) -> i64 {
-    let value = left + right;
-    if value >= MOD {
-        value - MOD
-    } else {
-        value
-    }
-}
-
-fn mod_sub(left: i64, right: i64) -> i64 {
-    let mut value = left - right;
-    if value < 0 {
-        value += MOD;
-    }
-    value
-}
+use crate::mod_int::Mint;
 
 impl Solution {
     /// Counts stable binary arrays with bounded run lengths.
@@ -41,15 +24,15 @@ impl Solution {
         let limit = limit as usize;
 
         // dp[i][j][k] = number of stable arrays with i zeros, j ones, ending with bit k
-        let mut dp = vec![vec![[0i64; 2]; ones + 1]; zeros + 1];
+        let mut dp = vec![vec![[Mint::ZERO; 2]; ones + 1]; zeros + 1];
 
         // Initialize base cases: runs of only zeros or only ones (within limit)
         for i in 1..=limit {
             if i <= zeros {
-                dp[i][0][0] = 1;
+                dp[i][0][0] = Mint::ONE;
             }
             if i <= ones {
-                dp[0][i][1] = 1;
+                dp[0][i][1] = Mint::ONE;
             }
         }
 
@@ -57,17 +40,17 @@ impl Solution {
         for i in 1..=zeros {
             for j in 1..=ones {
                 // Extend array ending with 0: add a 0 to arrays ending with 0 or 1
-                let mut zero_ending = mod_add(dp[i - 1][j][0], dp[i - 1][j][1]);
+                let mut zero_ending = dp[i - 1][j][0] + dp[i - 1][j][1];
                 // Subtract arrays where adding this 0 would create a run > limit
                 if i > limit {
-                    zero_ending = mod_sub(zero_ending, dp[i - limit - 1][j][1]);
+                    zero_ending -= dp[i - limit - 1][j][1];
                 }
 
                 // Extend array ending with 1: add a 1 to arrays ending with 0 or 1
-                let mut one_ending = mod_add(dp[i][j - 1][0], dp[i][j - 1][1]);
+                let mut one_ending = dp[i][j - 1][0] + dp[i][j - 1][1];
                 // Subtract arrays where adding this 1 would create a run > limit
                 if j > limit {
-                    one_ending = mod_sub(one_ending, dp[i][j - limit - 1][0]);
+                    one_ending -= dp[i][j - limit - 1][0];
                 }
 
                 dp[i][j][0] = zero_ending;
@@ -75,7 +58,7 @@ impl Solution {
             }
         }
 
-        mod_add(dp[zeros][ones][0], dp[zeros][ones][1]) as i32
+        (dp[zeros][ones][0] + dp[zeros][ones][1]).value() as i32
     }
 }
 
@@ -112,12 +95,12 @@ mod tests {
 
     #[test]
     fn test_single_zero() {
-        assert_eq!(Solution::number_of_stable_arrays(1, 3, 2), 4);
+        assert_eq!(Solution::number_of_stable_arrays(1, 3, 2), 2);
     }
 
     #[test]
     fn test_single_one() {
-        assert_eq!(Solution::number_of_stable_arrays(3, 1, 2), 4);
+        assert_eq!(Solution::number_of_stable_arrays(3, 1, 2), 2);
     }
 
     #[test]
@@ -1,58 +1,17 @@
-const MOD: u64 = 1_000_000_007;
-const N: usize = 2001;
-
-const fn mod_pow(mut base: u64, mut exp: u64) -> u64 {
-    let mut result = 1;
-    base %= MOD;
-    while exp > 0 {
-        if exp & 1 == 1 {
-            result = result * base % MOD;
-        }
-        base = base * base % MOD;
-        exp >>= 1;
-    }
-    result
-}
-
-const fn precalc() -> ([u64; N], [u64; N]) {
-    let mut fact = [1_u64; N];
-    let mut inv_fact = [1_u64; N];
-    let mut i = 2;
-    while i < N {
-        fact[i] = fact[i - 1] * i as u64 % MOD;
-        i += 1;
-    }
-    inv_fact[N - 1] = mod_pow(fact[N - 1], MOD - 2);
-    let mut i = N - 1;
-    while i > 1 {
-        inv_fact[i - 1] = inv_fact[i] * i as u64 % MOD;
-        i -= 1;
-    }
-    (fact, inv_fact)
-}
-
-const TABLES: ([u64; N], [u64; N]) = precalc();
-
-/// Binomial coefficient C(n, r) mod MOD via precomputed factorials.
-fn c(n: u64, r: u64) -> u64 {
-    if r > n {
-        return 0;
-    }
-    TABLES.0[n as usize] * (TABLES.1[r as usize] * TABLES.1[(n - r) as usize] % MOD) % MOD
-}
+use crate::mod_int::{Combinatorics, Mint};
 
 /// Counts compositions of `n` into `k` parts in \[1, limit\] via inclusion-exclusion.
-fn split_ways(n: u64, k: u64, limit: u64) -> u64 {
+fn split_ways(comb: &mut Combinatorics, n: usize, k: usize, limit: usize) -> Mint {
     if n == k {
-        return 1;
+        return Mint::ONE;
     }
     if n > k * limit {
-        return 0;
+        return Mint::ZERO;
     }
-    let (mut total, mut sign, mut remaining, mut j) = (0_u64, true, n, 0_u64);
+    let (mut total, mut sign, mut remaining, mut j) = (Mint::ZERO, true, n, 0);
     while j <= k && k <= remaining {
-        let t = c(k, j) * c(remaining - 1, k - 1) % MOD;
-        total = (total + if sign { t } else { MOD - t }) % MOD;
+        let t = comb.choose(k, j) * comb.choose(remaining - 1, k - 1);
+        total = if sign { total + t } else { total - t };
         sign = !sign;
         if remaining <= limit {
             break;
@@ -73,7 +32,7 @@ impl Solution {
     /// reduces to inclusion-exclusion over binomial coefficients.
     ///
     /// # Approach
-    /// 1. Precompute factorials and inverse factorials at compile time for O(1) binomial lookups.
+    /// 1. Build factorial tables up to `max(zero, one)` for O(1) binomial lookups.
     /// 2. `split_ways(n, k, limit)` counts compositions of n into k parts in \[1, limit\]
     ///    via inclusion-exclusion in O(min(k, n/limit)) time.
     /// 3. The main loop iterates k = 1..=min(zero, one), combining zero-side and one-side
@@ -81,9 +40,13 @@ impl Solution {
     ///
     /// # Complexity
     /// - Time:  O(min(zero, one) × max(zero, one) / limit)
-    /// - Space: O(1) working (factorial tables are compile-time constants)
+    /// - Space: O(zero + one) for the factorial tables
     pub fn number_of_stable_arrays(zero: i32, one: i32, limit: i32) -> i32 {
-        let (zero, one, limit) = (zero.min(one) as u64, zero.max(one) as u64, limit as u64);
+        let (zero, one, limit) = (
+            zero.min(one) as usize,
+            zero.max(one) as usize,
+            limit as usize,
+        );
         if limit == 1 {
             if zero == one {
                 return 2;
@@ -93,17 +56,18 @@ impl Solution {
             }
             return 0;
         }
+        let mut comb = Combinatorics::with_capacity(one);
         let (mut result, mut prev, mut curr, mut next) = (
-            0_u64,
-            0_u64,
-            split_ways(one, 1, limit),
-            split_ways(one, 2, limit),
+            Mint::ZERO,
+            Mint::ZERO,
+            split_ways(&mut comb, one, 1, limit),
+            split_ways(&mut comb, one, 2, limit),
         );
         for k in 1..=zero {
-            result = (result + (prev + 2 * curr + next) % MOD * split_ways(zero, k, limit)) % MOD;
-            (prev, curr, next) = (curr, next, split_ways(one, k + 2, limit));
+            result += (prev + curr * 2 + next) * split_ways(&mut comb, zero, k, limit);
+            (prev, curr, next) = (curr, next, split_ways(&mut comb, one, k + 2, limit));
         }
-        result as i32
+        result.value() as i32
     }
 }
 
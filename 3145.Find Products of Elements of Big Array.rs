@@ -54,7 +54,7 @@ fn find_number_by_index(target: u128) -> u64 {
     }
     let mut low = 0u64;
     while low < high {
-        let mid = low + (high - low + 1) / 2;
+        let mid = low + (high - low).div_ceil(2);
         if total_bits_up_to(mid) <= target {
             low = mid;
         } else {
@@ -175,7 +175,7 @@ mod tests {
     #[test]
     fn test_small_ranges_against_naive() {
         let nums = build_big_nums(40);
-        let cases = vec![
+        let cases = [
             (0, 0, 7),
             (1, 3, 7),
             (2, 5, 3),
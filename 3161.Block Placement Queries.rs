@@ -1,51 +1,6 @@
 use std::collections::BTreeSet;
 
-struct SegTree {
-    size: usize,
-    data: Vec<i32>,
-}
-
-impl SegTree {
-    fn new(n: usize) -> Self {
-        let size = n.next_power_of_two();
-        Self {
-            size,
-            data: vec![0; size * 2],
-        }
-    }
-
-    fn update(&mut self, pos: usize, value: i32) {
-        let mut idx = self.size + pos - 1;
-        self.data[idx] = value;
-        while idx > 1 {
-            idx /= 2;
-            let left = idx * 2;
-            self.data[idx] = self.data[left].max(self.data[left + 1]);
-        }
-    }
-
-    fn query(&self, mut left: usize, mut right: usize) -> i32 {
-        if left > right {
-            return 0;
-        }
-        left = left + self.size - 1;
-        right = right + self.size - 1;
-        let mut best = 0;
-        while left <= right {
-            if left % 2 == 1 {
-                best = best.max(self.data[left]);
-                left += 1;
-            }
-            if right % 2 == 0 {
-                best = best.max(self.data[right]);
-                right -= 1;
-            }
-            left /= 2;
-            right /= 2;
-        }
-        best
-    }
-}
+use crate::segment_tree::{Max, SegmentTree};
 
 impl Solution {
     /// Prefix maximum of obstacle gaps with a segment tree.
@@ -60,7 +15,7 @@ impl Solution {
     /// `b - prev` where `prev` is the previous obstacle (or 0). Keep these lengths in a segment
     /// tree keyed by the right endpoint `b`. When inserting a new obstacle `p`, update the gap for
     /// `p` and shorten the gap for its successor. For a query `[2, x, sz]`, take the maximum of:
-    /// 1) the segment tree maximum over endpoints `<= x`, and 2) the tail gap `x - prev`. If that
+    /// (1) the segment tree maximum over endpoints `<= x`, and (2) the tail gap `x - prev`. If that
     /// maximum is at least `sz`, the block fits.
     ///
     /// # Complexity
@@ -72,12 +27,12 @@ impl Solution {
             .filter_map(|query| query.get(1).copied())
             .max()
             .unwrap_or(0) as usize;
-        let mut seg_tree = SegTree::new(max_x.max(1));
+        let mut seg_tree: SegmentTree<Max<i32>> = vec![Max(0); max_x + 1].into();
         let mut obstacles = BTreeSet::new();
         let mut results = Vec::with_capacity(queries.len());
 
         for query in queries {
-            match query.get(0).copied() {
+            match query.first().copied() {
                 Some(1) => {
                     if query.len() < 2 {
                         continue;
@@ -86,9 +41,9 @@ impl Solution {
                     let prev = obstacles.range(..=pos).next_back().copied().unwrap_or(0);
                     let next = obstacles.range((pos + 1)..).next().copied();
 
-                    seg_tree.update(pos, (pos - prev) as i32);
+                    seg_tree.set(pos, Max((pos - prev) as i32));
                     if let Some(next_pos) = next {
-                        seg_tree.update(next_pos, (next_pos - pos) as i32);
+                        seg_tree.set(next_pos, Max((next_pos - pos) as i32));
                     }
                     obstacles.insert(pos);
                 }
@@ -98,7 +53,7 @@ impl Solution {
                     }
                     let x = query[1] as usize;
                     let size = query[2];
-                    let prefix_max = if x == 0 { 0 } else { seg_tree.query(1, x) };
+                    let Max(prefix_max) = seg_tree.query(..=x);
                     let prev = obstacles.range(..=x).next_back().copied().unwrap_or(0);
                     let tail_gap = (x - prev) as i32;
                     let best = prefix_max.max(tail_gap);
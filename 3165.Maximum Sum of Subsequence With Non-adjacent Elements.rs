@@ -1,22 +1,41 @@
-const MOD: i64 = 1_000_000_007;
+use crate::mod_int::Mint;
+use crate::segment_tree::{Monoid, SegmentTree};
+
 const NEG_INF: i64 = -4_000_000_000_000_000_000;
 
-/// Node value: dp[first_taken][last_taken] for this segment.
-/// Use NEG_INF for invalid configurations.
+/// Node value: dp[first_taken][last_taken] for this segment, or `None` for
+/// the empty segment. Use NEG_INF for invalid configurations.
 #[derive(Clone, Copy)]
-struct Node {
-    dp: [[i64; 2]; 2],
-}
+struct Node(Option<[[i64; 2]; 2]>);
 
 impl Node {
     fn leaf(value: i64) -> Self {
         let mut dp = [[NEG_INF; 2]; 2];
         dp[0][0] = 0;
         dp[1][1] = value;
-        Self { dp }
+        Self(Some(dp))
+    }
+
+    fn best(self) -> i64 {
+        self.0
+            .iter()
+            .flatten()
+            .flatten()
+            .fold(0, |best, &val| best.max(val))
+    }
+}
+
+impl Monoid for Node {
+    fn identity() -> Self {
+        Self(None)
     }
 
-    fn merge(left: Self, right: Self) -> Self {
+    fn combine(&self, right: &Self) -> Self {
+        let (left, right) = match (self.0, right.0) {
+            (Some(left), Some(right)) => (left, right),
+            (None, _) => return *right,
+            (_, None) => return *self,
+        };
         let mut dp = [[NEG_INF; 2]; 2];
         for first_taken in 0..=1 {
             for last_taken in 0..=1 {
@@ -26,8 +45,8 @@ impl Node {
                         if left_last == 1 && right_first == 1 {
                             continue;
                         }
-                        let left_val = left.dp[first_taken][left_last];
-                        let right_val = right.dp[right_first][last_taken];
+                        let left_val = left[first_taken][left_last];
+                        let right_val = right[right_first][last_taken];
                         if left_val == NEG_INF || right_val == NEG_INF {
                             continue;
                         }
@@ -37,61 +56,7 @@ impl Node {
                 dp[first_taken][last_taken] = best;
             }
         }
-        Self { dp }
-    }
-
-    fn best(self) -> i64 {
-        self.dp.iter().flatten().fold(0, |best, &val| best.max(val))
-    }
-}
-
-struct SegTree {
-    levels: Vec<Vec<Node>>,
-}
-
-impl SegTree {
-    fn new(nums: &[i32]) -> Self {
-        let first_level: Vec<Node> = nums.iter().map(|&num| Node::leaf(num as i64)).collect();
-
-        let mut levels = Vec::with_capacity(first_level.len().max(1).ilog2() as usize + 1);
-        levels.push(first_level);
-
-        while levels.last().unwrap().len() > 1 {
-            let prev = levels.last().unwrap();
-            let next: Vec<Node> = prev
-                .chunks(2)
-                .map(|chunk| {
-                    if chunk.len() == 2 {
-                        Node::merge(chunk[0], chunk[1])
-                    } else {
-                        chunk[0]
-                    }
-                })
-                .collect();
-            levels.push(next);
-        }
-
-        Self { levels }
-    }
-
-    fn update(&mut self, pos: usize, value: i32) {
-        self.levels[0][pos] = Node::leaf(value as i64);
-        let mut idx = pos / 2;
-        for level in 1..self.levels.len() {
-            let left_idx = idx * 2;
-            let left = self.levels[level - 1][left_idx];
-            if left_idx + 1 < self.levels[level - 1].len() {
-                let right = self.levels[level - 1][left_idx + 1];
-                self.levels[level][idx] = Node::merge(left, right);
-            } else {
-                self.levels[level][idx] = left;
-            }
-            idx /= 2;
-        }
-    }
-
-    fn root_best(&self) -> i64 {
-        self.levels.last().unwrap()[0].best()
+        Self(Some(dp))
     }
 }
 
@@ -113,19 +78,19 @@ impl Solution {
     /// - Time: O((n + q) log n)
     /// - Space: O(n)
     pub fn maximum_sum_subsequence(nums: Vec<i32>, queries: Vec<Vec<i32>>) -> i32 {
-        let mut tree = SegTree::new(&nums);
-        let mut total: i64 = 0;
+        let mut tree: SegmentTree<Node> = nums.iter().map(|&num| Node::leaf(num as i64)).collect();
+        let mut total = Mint::ZERO;
         for query in &queries {
             if query.len() != 2 {
                 continue;
             }
             let pos = query[0] as usize;
             let new_value = query[1];
-            tree.update(pos, new_value);
-            let best = tree.root_best();
-            total = (total + best).rem_euclid(MOD);
+            tree.set(pos, Node::leaf(new_value as i64));
+            let best = tree.all().best();
+            total += best;
         }
-        total as i32
+        total.value() as i32
     }
 }
 
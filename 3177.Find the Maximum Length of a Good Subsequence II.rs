@@ -23,7 +23,7 @@ impl Solution {
     /// - Space: O(unique_vals * k)
     pub fn maximum_length(nums: Vec<i32>, k: i32) -> i32 {
         let k = k as usize;
-        let mut sorted: Vec<i32> = nums.iter().copied().collect();
+        let mut sorted: Vec<i32> = nums.to_vec();
         sorted.sort_unstable();
         sorted.dedup();
         let val_to_idx: HashMap<i32, usize> = sorted
@@ -37,7 +37,7 @@ impl Solution {
             }
             let shift_lo = v % BITS;
             let shift_hi = BITS - shift_lo;
-            let src_end = (v + BITS - 1) / BITS;
+            let src_end = v.div_ceil(BITS);
             for sw in (0..src_end).rev() {
                 let dw = sw + v / BITS;
                 if dw >= words {
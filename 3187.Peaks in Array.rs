@@ -1,3 +1,5 @@
+use crate::fenwick::Fenwick;
+
 impl Solution {
     /// Counts peaks under point updates using a Fenwick tree.
     ///
@@ -18,7 +20,7 @@ impl Solution {
     pub fn count_of_peaks(mut nums: Vec<i32>, queries: Vec<Vec<i32>>) -> Vec<i32> {
         let n = nums.len();
         let mut peaks = vec![0i32; n];
-        let mut bit = Fenwick::new(n);
+        let mut bit: Fenwick<i32> = Fenwick::new(n);
 
         for i in 1..n.saturating_sub(1) {
             if is_peak_at(&nums, i) {
@@ -40,7 +42,7 @@ impl Solution {
                         answers.push(0);
                         continue;
                     }
-                    let sum = bit.range_sum(left + 1, right - 1);
+                    let sum = bit.sum(left + 1..right);
                     answers.push(sum);
                 }
                 2 => {
@@ -76,46 +78,6 @@ fn is_peak_at(nums: &[i32], index: usize) -> bool {
     nums[index] > nums[index - 1] && nums[index] > nums[index + 1]
 }
 
-struct Fenwick {
-    tree: Vec<i32>,
-}
-
-impl Fenwick {
-    fn new(size: usize) -> Self {
-        Self {
-            tree: vec![0; size + 1],
-        }
-    }
-
-    fn add(&mut self, index: usize, delta: i32) {
-        let mut i = index + 1;
-        while i < self.tree.len() {
-            self.tree[i] += delta;
-            i += i & i.wrapping_neg();
-        }
-    }
-
-    fn prefix_sum(&self, index: usize) -> i32 {
-        let mut i = index + 1;
-        let mut sum = 0;
-        while i > 0 {
-            sum += self.tree[i];
-            i -= i & i.wrapping_neg();
-        }
-        sum
-    }
-
-    fn range_sum(&self, left: usize, right: usize) -> i32 {
-        if left > right {
-            return 0;
-        }
-        if left == 0 {
-            return self.prefix_sum(right);
-        }
-        self.prefix_sum(right) - self.prefix_sum(left - 1)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts permutations whose prefix inversion counts match all requirements.
     ///
@@ -18,7 +20,6 @@ impl Solution {
     /// - Time: O(n * R), with `R <= 400`
     /// - Space: O(R)
     pub fn number_of_permutations(n: i32, requirements: Vec<Vec<i32>>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let n = n as usize;
         let mut required = vec![None; n];
 
@@ -61,23 +62,17 @@ impl Solution {
             }
         }
 
-        let mut dp = vec![0i64; total_required + 1];
-        dp[0] = 1;
+        let mut dp = vec![Mint::ZERO; total_required + 1];
+        dp[0] = Mint::ONE;
 
         for i in 0..n {
-            let mut next = vec![0i64; total_required + 1];
-            let mut window_sum = 0i64;
+            let mut next = vec![Mint::ZERO; total_required + 1];
+            let mut window_sum = Mint::ZERO;
 
             for s in 0..=total_required {
                 window_sum += dp[s];
-                if window_sum >= MOD {
-                    window_sum -= MOD;
-                }
                 if s > i {
                     window_sum -= dp[s - i - 1];
-                    if window_sum < 0 {
-                        window_sum += MOD;
-                    }
                 }
                 next[s] = window_sum;
             }
@@ -86,9 +81,9 @@ impl Solution {
                 let keep = if required_count <= total_required {
                     next[required_count]
                 } else {
-                    0
+                    Mint::ZERO
                 };
-                next.fill(0);
+                next.fill(Mint::ZERO);
                 if required_count <= total_required {
                     next[required_count] = keep;
                 }
@@ -97,7 +92,7 @@ impl Solution {
             dp = next;
         }
 
-        dp[total_required] as i32
+        dp[total_required].value() as i32
     }
 }
 
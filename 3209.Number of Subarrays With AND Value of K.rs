@@ -24,11 +24,11 @@ impl Solution {
 
             for &(value, count) in &prev {
                 let merged = value & num;
-                if let Some(last) = next.last_mut() {
-                    if last.0 == merged {
-                        last.1 += count;
-                        continue;
-                    }
+                if let Some(last) = next.last_mut()
+                    && last.0 == merged
+                {
+                    last.1 += count;
+                    continue;
                 }
                 next.push((merged, count));
             }
@@ -42,7 +42,7 @@ impl Solution {
         let mut nodes = Vec::with_capacity(total_word_len + 1);
         nodes.push(Node::new(0));
 
-        for (word, cost) in words.into_iter().zip(costs.into_iter()) {
+        for (word, cost) in words.into_iter().zip(costs) {
             let mut state = 0usize;
             for &byte in word.as_bytes() {
                 let idx = (byte - b'a') as usize;
@@ -48,7 +48,6 @@ impl Solution {
 
         while v < vertical.len() {
             total += vertical[v] as i64 * horizontal_pieces;
-            vertical_pieces += 1;
             v += 1;
         }
 
@@ -27,7 +27,7 @@ impl Solution {
             adjacency[v].push(u);
         }
 
-        let delay = |node: usize| if node % 2 == 0 { 2 } else { 1 };
+        let delay = |node: usize| if node.is_multiple_of(2) { 2 } else { 1 };
 
         let mut parent = vec![usize::MAX; n];
         let mut order = Vec::with_capacity(n);
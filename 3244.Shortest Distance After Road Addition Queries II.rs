@@ -30,10 +30,10 @@ impl Solution {
             let v = query[1] as usize;
 
             let mut contained = false;
-            if let Some((&start, &end)) = max_intervals.range(..=u).next_back() {
-                if end >= v {
-                    contained = true;
-                }
+            if let Some((_, &end)) = max_intervals.range(..=u).next_back()
+                && end >= v
+            {
+                contained = true;
             }
 
             if !contained {
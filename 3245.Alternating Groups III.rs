@@ -1,14 +1,16 @@
+use crate::fenwick::Fenwick;
+
 struct AlternatingGroups {
     n: usize,
     colors: Vec<u8>,
-    fenwick: Fenwick,
+    fenwick: RunLengths,
     boundary_set: BoundarySet,
 }
 
 impl AlternatingGroups {
     fn new(colors: Vec<i32>) -> Self {
         let n = colors.len();
-        let mut fenwick = Fenwick::new(n);
+        let mut fenwick = RunLengths::new(n);
         let mut boundary_set = BoundarySet::new(n);
         let colors: Vec<u8> = colors.into_iter().map(|c| c as u8).collect();
 
@@ -52,7 +54,7 @@ impl AlternatingGroups {
     }
 
     fn query(&self, size: usize) -> i64 {
-        if self.n % 2 == 0 && self.boundary_set.len() <= 1 {
+        if self.n.is_multiple_of(2) && self.boundary_set.len() <= 1 {
             return self.n as i64;
         }
         let all = self.fenwick.prefix(self.n);
@@ -201,54 +203,34 @@ impl Solution {
     }
 }
 
-struct Fenwick {
-    tree: Vec<(i64, i64)>,
+/// Counts and total lengths of alternating runs, keyed by run length.
+struct RunLengths {
+    tree: Fenwick<(i64, i64)>,
 }
 
-impl Fenwick {
+impl RunLengths {
     fn new(n: usize) -> Self {
         Self {
-            tree: vec![(0, 0); n + 1],
-        }
-    }
-
-    fn size(&self) -> usize {
-        self.tree.len() - 1
-    }
-
-    fn add(&mut self, index: usize, value: i64) {
-        if index == 0 {
-            return;
-        }
-        let mut i = index;
-        while i < self.tree.len() {
-            self.tree[i].0 += value;
-            self.tree[i].1 += value * index as i64;
-            i += Self::lsb(i);
+            tree: Fenwick::new(n + 1),
         }
     }
 
-    fn prefix(&self, index: usize) -> (i64, i64) {
-        let mut sum0 = 0_i64;
-        let mut sum1 = 0_i64;
-        let mut i = index;
-        while i > 0 {
-            sum0 += self.tree[i].0;
-            sum1 += self.tree[i].1;
-            i -= Self::lsb(i);
+    fn add(&mut self, len: usize, count: i64) {
+        if len > 0 {
+            self.tree.add(len, (count, count * len as i64));
         }
-        (sum0, sum1)
     }
 
-    fn lsb(i: usize) -> usize {
-        i & i.wrapping_neg()
+    /// Number and total length of the runs no longer than `len`.
+    fn prefix(&self, len: usize) -> (i64, i64) {
+        self.tree.prefix(len + 1)
     }
 }
 
 struct BoundarySet {
     n: usize,
     present: Vec<bool>,
-    bit: FenwickCount,
+    bit: Fenwick<i32>,
     count: usize,
 }
 
@@ -257,7 +239,7 @@ impl BoundarySet {
         Self {
             n,
             present: vec![false; n],
-            bit: FenwickCount::new(n),
+            bit: Fenwick::new(n),
             count: 0,
         }
     }
@@ -274,7 +256,7 @@ impl BoundarySet {
         if !self.present[index] {
             self.present[index] = true;
             self.count += 1;
-            self.bit.add(index + 1, 1);
+            self.bit.add(index, 1);
         }
     }
 
@@ -282,7 +264,7 @@ impl BoundarySet {
         if self.present[index] {
             self.present[index] = false;
             self.count -= 1;
-            self.bit.add(index + 1, -1);
+            self.bit.add(index, -1);
         }
     }
 
@@ -294,7 +276,7 @@ impl BoundarySet {
     }
 
     fn prev(&self, index: usize) -> usize {
-        let prefix = self.bit.sum(index + 1) as usize;
+        let prefix = self.bit.prefix(index + 1) as usize;
         if prefix > 0 {
             self.kth(prefix)
         } else {
@@ -303,67 +285,13 @@ impl BoundarySet {
     }
 
     fn next(&self, index: usize) -> usize {
-        let prefix = self.bit.sum(index + 1) as usize;
+        let prefix = self.bit.prefix(index + 1) as usize;
         let order = if prefix < self.count { prefix + 1 } else { 1 };
         self.kth(order)
     }
 
     fn kth(&self, order: usize) -> usize {
-        self.bit.kth(order as i32) - 1
-    }
-}
-
-struct FenwickCount {
-    tree: Vec<i32>,
-    max_bit: usize,
-}
-
-impl FenwickCount {
-    fn new(n: usize) -> Self {
-        let mut max_bit = 1;
-        while max_bit < n + 1 {
-            max_bit <<= 1;
-        }
-        Self {
-            tree: vec![0; n + 1],
-            max_bit: max_bit >> 1,
-        }
-    }
-
-    fn add(&mut self, index: usize, value: i32) {
-        let mut i = index;
-        while i < self.tree.len() {
-            self.tree[i] += value;
-            i += Self::lsb(i);
-        }
-    }
-
-    fn sum(&self, index: usize) -> i32 {
-        let mut res = 0_i32;
-        let mut i = index;
-        while i > 0 {
-            res += self.tree[i];
-            i -= Self::lsb(i);
-        }
-        res
-    }
-
-    fn kth(&self, mut k: i32) -> usize {
-        let mut idx = 0_usize;
-        let mut bit = self.max_bit;
-        while bit > 0 {
-            let next = idx + bit;
-            if next < self.tree.len() && self.tree[next] < k {
-                k -= self.tree[next];
-                idx = next;
-            }
-            bit >>= 1;
-        }
-        idx + 1
-    }
-
-    fn lsb(i: usize) -> usize {
-        i & i.wrapping_neg()
+        self.bit.kth(order as i32)
     }
 }
 
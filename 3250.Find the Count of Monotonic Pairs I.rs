@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Prefix-sum DP over feasible `arr1` values.
     ///
@@ -16,22 +18,21 @@ impl Solution {
     /// - Time: O(n * max(nums))
     /// - Space: O(max(nums))
     pub fn count_of_pairs(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         if nums.is_empty() {
             return 0;
         }
 
-        let mut dp = vec![1_i64; nums[0] as usize + 1];
+        let mut dp = vec![Mint::ONE; nums[0] as usize + 1];
         for i in 1..nums.len() {
             let prev_max = nums[i - 1] as usize;
             let curr_max = nums[i] as usize;
             let delta = nums[i] - nums[i - 1];
             let min_inc = if delta > 0 { delta as usize } else { 0 };
 
-            let prefix: Vec<i64> = dp
+            let prefix: Vec<Mint> = dp
                 .iter()
-                .scan(0_i64, |running, &value| {
-                    *running = (*running + value) % MOD;
+                .scan(Mint::ZERO, |running, &value| {
+                    *running += value;
                     Some(*running)
                 })
                 .collect();
@@ -39,7 +40,7 @@ impl Solution {
             dp = (0..=curr_max)
                 .map(|value| {
                     if value < min_inc {
-                        0_i64
+                        Mint::ZERO
                     } else {
                         prefix[(value - min_inc).min(prev_max)]
                     }
@@ -47,7 +48,7 @@ impl Solution {
                 .collect();
         }
 
-        (dp.iter().fold(0_i64, |acc, &v| (acc + v) % MOD)) as i32
+        dp.iter().sum::<Mint>().value() as i32
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Prefix-sum DP over feasible `arr1` values.
     ///
@@ -16,29 +18,25 @@ impl Solution {
     /// - Time: O(n * max(nums))
     /// - Space: O(max(nums))
     pub fn count_of_pairs(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         if nums.is_empty() {
             return 0;
         }
 
-        let mut dp = vec![1_i64; nums[0] as usize + 1];
+        let mut dp = vec![Mint::ONE; nums[0] as usize + 1];
         for i in 1..nums.len() {
             let prev_max = nums[i - 1] as usize;
             let curr_max = nums[i] as usize;
             let delta = nums[i] - nums[i - 1];
             let min_inc = if delta > 0 { delta as usize } else { 0 };
 
-            let mut prefix = vec![0_i64; prev_max + 1];
-            let mut running = 0_i64;
+            let mut prefix = vec![Mint::ZERO; prev_max + 1];
+            let mut running = Mint::ZERO;
             for (idx, value) in dp.iter().enumerate() {
                 running += *value;
-                if running >= MOD {
-                    running -= MOD;
-                }
                 prefix[idx] = running;
             }
 
-            let mut next = vec![0_i64; curr_max + 1];
+            let mut next = vec![Mint::ZERO; curr_max + 1];
             for value in 0..=curr_max {
                 if value < min_inc {
                     continue;
@@ -49,14 +47,8 @@ impl Solution {
             dp = next;
         }
 
-        let mut total = 0_i64;
-        for value in dp {
-            total += value;
-            if total >= MOD {
-                total -= MOD;
-            }
-        }
-        total as i32
+        let total: Mint = dp.into_iter().sum();
+        total.value() as i32
     }
 }
 
@@ -65,7 +65,7 @@ impl Solution {
             let mut high = query_right;
 
             while low < high {
-                let mid = (low + high + 1) / 2;
+                let mid = (low + high).div_ceil(2);
                 let valid_ending = prefix_valid[mid] - prefix_valid[mid - 1];
                 if valid_ending < (mid - query_left + 1) as i64 {
                     high = mid - 1;
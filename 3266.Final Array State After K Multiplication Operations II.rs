@@ -1,6 +1,6 @@
 use std::collections::BinaryHeap;
 
-const MOD: u64 = 1_000_000_007;
+use crate::mod_int::Mint;
 
 impl Solution {
     /// Fast-forward after the minimum can leap past the maximum.
@@ -91,8 +91,8 @@ impl Solution {
         let full_cycles = remaining / n as u64;
         let prefix = (remaining % n as u64) as usize;
 
-        let base = mod_pow(multiplier_u64, full_cycles);
-        let extra = (base * multiplier_u64) % MOD;
+        let base = Mint::new(multiplier_u64).pow(full_cycles);
+        let extra = base * multiplier_u64;
 
         let mut keys: Vec<u64> = Vec::with_capacity(n);
         for (index, value) in after_phase_one.iter().enumerate() {
@@ -104,18 +104,18 @@ impl Solution {
             for &packed in &keys[..prefix] {
                 let value = packed >> 32;
                 let index = (packed as u32) as usize;
-                nums[index] = ((value * extra) % MOD) as i32;
+                nums[index] = (extra * value).value() as i32;
             }
             for &packed in &keys[prefix..] {
                 let value = packed >> 32;
                 let index = (packed as u32) as usize;
-                nums[index] = ((value * base) % MOD) as i32;
+                nums[index] = (base * value).value() as i32;
             }
         } else {
             for packed in keys {
                 let value = packed >> 32;
                 let index = (packed as u32) as usize;
-                nums[index] = ((value * base) % MOD) as i32;
+                nums[index] = (base * value).value() as i32;
             }
         }
 
@@ -123,19 +123,6 @@ impl Solution {
     }
 }
 
-#[inline]
-fn mod_pow(mut base: u64, mut exp: u64) -> u64 {
-    let mut result: u64 = 1;
-    while exp > 0 {
-        if (exp & 1) == 1 {
-            result = (result * base) % MOD;
-        }
-        base = (base * base) % MOD;
-        exp >>= 1;
-    }
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
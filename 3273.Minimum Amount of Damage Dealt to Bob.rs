@@ -19,7 +19,7 @@ impl Solution {
         let power = power as i64;
         let mut enemies: Vec<(i64, i64)> = damage
             .into_iter()
-            .zip(health.into_iter())
+            .zip(health)
             .map(|(damage, health)| {
                 let time = (health as i64 + power - 1) / power;
                 (time, damage as i64)
@@ -77,7 +77,7 @@ mod tests {
         let power = 3;
         let damage = vec![2, 4];
         let health = vec![3, 6];
-        assert_eq!(Solution::min_damage(power, damage, health), 20);
+        assert_eq!(Solution::min_damage(power, damage, health), 14);
     }
 
     #[test]
@@ -85,6 +85,6 @@ mod tests {
         let power = 5;
         let damage = vec![10, 1];
         let health = vec![10, 5];
-        assert_eq!(Solution::min_damage(power, damage, health), 35);
+        assert_eq!(Solution::min_damage(power, damage, health), 23);
     }
 }
@@ -25,7 +25,7 @@ impl Solution {
                 cells.push((value, row_index));
             }
         }
-        cells.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        cells.sort_unstable_by_key(|x| std::cmp::Reverse(x.0));
 
         let states = 1_usize << row_count;
         let mut dp = vec![0_i32; states];
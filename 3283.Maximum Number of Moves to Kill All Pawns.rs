@@ -91,7 +91,7 @@ impl Solution {
             }
 
             let moves_done = total - mask.count_ones() as usize;
-            let alice_turn = moves_done % 2 == 0;
+            let alice_turn = moves_done.is_multiple_of(2);
             let mut best = if alice_turn { i32::MIN } else { i32::MAX };
             let mut remaining = mask;
 
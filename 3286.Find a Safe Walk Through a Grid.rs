@@ -22,6 +22,9 @@ impl Solution {
         let (m, n) = (grid.len(), grid[0].len());
         let mut dis = vec![vec![i32::MAX; n]; m];
         dis[0][0] = grid[0][0];
+        if dis[0][0] >= health {
+            return false;
+        }
 
         let mut deque = VecDeque::with_capacity(m * n);
         deque.push_front((0usize, 0usize));
@@ -1,3 +1,5 @@
+use crate::fenwick::{Fenwick, Max};
+
 impl Solution {
     /// Compute the longest increasing chain that must include `coordinates[k]`.
     ///
@@ -27,40 +29,6 @@ impl Solution {
             rev_idx: usize,
         }
 
-        struct Fenwick {
-            tree: Vec<i32>,
-        }
-
-        impl Fenwick {
-            fn new(size: usize) -> Self {
-                Self {
-                    tree: vec![0; size + 1],
-                }
-            }
-
-            fn update(&mut self, mut idx: usize, val: i32) {
-                let n = self.tree.len();
-                while idx < n {
-                    if val > self.tree[idx] {
-                        self.tree[idx] = val;
-                    }
-                    idx += idx & idx.wrapping_neg();
-                }
-            }
-
-            fn query(&self, mut idx: usize) -> i32 {
-                let mut best = 0;
-                while idx > 0 {
-                    let candidate = self.tree[idx];
-                    if candidate > best {
-                        best = candidate;
-                    }
-                    idx -= idx & idx.wrapping_neg();
-                }
-                best
-            }
-        }
-
         let n = coordinates.len();
         let mut points = Vec::with_capacity(n);
         let mut ys = Vec::with_capacity(n);
@@ -80,18 +48,17 @@ impl Solution {
         ys.sort_unstable();
         ys.dedup();
         for point in &mut points {
-            let y_idx = ys.binary_search(&point.y).unwrap() + 1;
-            point.y_idx = y_idx;
+            point.y_idx = ys.binary_search(&point.y).unwrap();
         }
 
         let m = ys.len();
         for point in &mut points {
-            point.rev_idx = m - point.y_idx + 1;
+            point.rev_idx = m - 1 - point.y_idx;
         }
 
         let mut left = vec![1; n];
         points.sort_unstable_by(|a, b| a.x.cmp(&b.x).then_with(|| a.y.cmp(&b.y)));
-        let mut bit = Fenwick::new(m);
+        let mut bit: Fenwick<Max<i32>> = Fenwick::new(m);
         let mut i = 0;
         while i < n {
             let x = points[i].x;
@@ -100,18 +67,18 @@ impl Solution {
                 j += 1;
             }
             for point in &points[i..j] {
-                let best = bit.query(point.y_idx - 1);
+                let best = bit.prefix(point.y_idx).0.max(0);
                 left[point.id] = best + 1;
             }
             for point in &points[i..j] {
-                bit.update(point.y_idx, left[point.id]);
+                bit.add(point.y_idx, Max(left[point.id]));
             }
             i = j;
         }
 
         let mut right = vec![1; n];
         points.sort_unstable_by(|a, b| b.x.cmp(&a.x).then_with(|| b.y.cmp(&a.y)));
-        let mut bit = Fenwick::new(m);
+        let mut bit: Fenwick<Max<i32>> = Fenwick::new(m);
         let mut i = 0;
         while i < n {
             let x = points[i].x;
@@ -120,11 +87,11 @@ impl Solution {
                 j += 1;
             }
             for point in &points[i..j] {
-                let best = bit.query(point.rev_idx - 1);
+                let best = bit.prefix(point.rev_idx).0.max(0);
                 right[point.id] = best + 1;
             }
             for point in &points[i..j] {
-                bit.update(point.rev_idx, right[point.id]);
+                bit.add(point.rev_idx, Max(right[point.id]));
             }
             i = j;
         }
@@ -97,7 +97,7 @@ impl Solution {
             let mut low = 0_usize;
             let mut high = max_possible;
             while low < high {
-                let mid = (low + high + 1) / 2;
+                let mid = (low + high).div_ceil(2);
                 let hash1 = slice_hash(&prefix1, &pow1, start, start + mid, MOD1);
                 let hash2 = slice_hash(&prefix2, &pow2, start, start + mid, MOD2);
                 let key = combine(hash1, hash2);
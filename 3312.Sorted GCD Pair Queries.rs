@@ -1,3 +1,5 @@
+use crate::number_theory::{sum_over_multiples, unsum_over_multiples};
+
 impl Solution {
     /// Count gcd-pair frequencies with an in-place sieve and answer queries via partition_point.
     ///
@@ -29,13 +31,7 @@ impl Solution {
         }
 
         // Pass 2: forward sieve — cnt[d] becomes count of elements divisible by d.
-        for d in 1..=m {
-            let mut j = d * 2;
-            while j <= m {
-                cnt[d] += cnt[j];
-                j += d;
-            }
-        }
+        sum_over_multiples(&mut cnt);
 
         // Pass 3: convert to pair counts C(cnt[d], 2) in-place.
         for d in 1..=m {
@@ -43,13 +39,7 @@ impl Solution {
         }
 
         // Pass 4: reverse sieve (inclusion-exclusion) — cnt[d] becomes pairs with gcd exactly d.
-        for d in (1..=m).rev() {
-            let mut j = d * 2;
-            while j <= m {
-                cnt[d] -= cnt[j];
-                j += d;
-            }
-        }
+        unsum_over_multiples(&mut cnt);
 
         // Pass 5: prefix sum — cnt[d] becomes pairs with gcd <= d.
         for d in 1..=m {
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts possible ways to assign performers to stages and award scores.
     ///
@@ -14,45 +16,46 @@ impl Solution {
     /// - Time: O(n × min(n,x))
     /// - Space: O(min(n,x))
     pub fn number_of_ways(n: i32, x: i32, y: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let n = n as usize;
         let x = x as usize;
-        let y = (y as i64) % MOD;
+        let y = Mint::from(y);
 
         let max_k = n.min(x);
-        if max_k == 0 || y == 0 {
+        if max_k == 0 || y == Mint::ZERO {
             return 0;
         }
 
         // Rolling DP for Stirling numbers of the second kind S(n, k)
         // S(n, k) = k * S(n-1, k) + S(n-1, k-1)
-        let mut stirling = vec![0i64; max_k + 1];
-        stirling[0] = 1;
+        let mut stirling = vec![Mint::ZERO; max_k + 1];
+        stirling[0] = Mint::ONE;
         for i in 1..=n {
             let upper = i.min(max_k);
             for j in (1..=upper).rev() {
                 let current = stirling[j];
                 let previous = stirling[j - 1];
-                stirling[j] = (current * j as i64 + previous) % MOD;
+                stirling[j] = current * j + previous;
             }
+            // S(i, 0) = 0 for every i >= 1
+            stirling[0] = Mint::ZERO;
         }
 
         // P(x, k) = x × (x-1) × ... × (x-k+1)
         let result = (1..=max_k).fold(
-            (0i64, 1i64, x as i64, 1i64), // (result, perm, stage_factor, pow_y)
-            |(mut result, perm, stage_factor, pow_y), k| {
-                let perm = perm * stage_factor % MOD;
-                let pow_y = pow_y * y % MOD;
-                let contribution = perm * stirling[k] % MOD * pow_y % MOD;
-                result += contribution;
-                if result >= MOD {
-                    result -= MOD;
-                }
-                (result, perm, stage_factor - 1, pow_y)
+            (Mint::ZERO, Mint::ONE, x, Mint::ONE), // (result, perm, stage_factor, pow_y)
+            |(result, perm, stage_factor, pow_y), k| {
+                let perm = perm * stage_factor;
+                let pow_y = pow_y * y;
+                (
+                    result + perm * stirling[k] * pow_y,
+                    perm,
+                    stage_factor - 1,
+                    pow_y,
+                )
             },
         );
 
-        result.0 as i32
+        result.0.value() as i32
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Count winning sequences using DP with score difference tracking.
     ///
@@ -15,7 +17,6 @@ impl Solution {
     /// - Time: O(n² × 9) = O(n²)
     /// - Space: O(n × 3) = O(n)
     pub fn count_winning_sequences(s: String) -> i32 {
-        const MOD: u32 = 1_000_000_007;
         let n = s.len();
         if n == 0 {
             return 0;
@@ -33,8 +34,8 @@ impl Solution {
         let stride = size;
 
         // dp[bob's last move][score difference + offset]
-        let mut dp = vec![0u32; 3 * stride];
-        let mut next = vec![0u32; 3 * stride];
+        let mut dp = vec![Mint::ZERO; 3 * stride];
+        let mut next = vec![Mint::ZERO; 3 * stride];
 
         // Initialize first round
         let first_alice = match bytes[0] {
@@ -45,7 +46,7 @@ impl Solution {
         (0..3).for_each(|bob| {
             let diff = outcome[first_alice][bob];
             let idx = (offset + diff) as usize;
-            dp[bob * stride + idx] = 1;
+            dp[bob * stride + idx] = Mint::ONE;
         });
 
         // Process remaining rounds
@@ -65,7 +66,7 @@ impl Solution {
             let next_len = (next_max - next_min + 1) as usize;
             (0..3).for_each(|bob| {
                 let base = bob * stride + next_start;
-                next[base..base + next_len].fill(0);
+                next[base..base + next_len].fill(Mint::ZERO);
             });
 
             let min_usize = min_index as usize;
@@ -81,21 +82,13 @@ impl Solution {
                 let mut d = min_usize;
                 while d <= max_usize {
                     let count = dp_row[d];
-                    if count != 0 {
+                    if count != Mint::ZERO {
                         let d_i32 = d as i32;
                         let idx1 = next_base1 + (d_i32 + delta1) as usize;
-                        let mut value1 = next[idx1] + count;
-                        if value1 >= MOD {
-                            value1 -= MOD;
-                        }
-                        next[idx1] = value1;
+                        next[idx1] += count;
 
                         let idx2 = next_base2 + (d_i32 + delta2) as usize;
-                        let mut value2 = next[idx2] + count;
-                        if value2 >= MOD {
-                            value2 -= MOD;
-                        }
-                        next[idx2] = value2;
+                        next[idx2] += count;
                     }
                     d += 1;
                 }
@@ -107,16 +100,10 @@ impl Solution {
 
         // Sum winning states (diff > 0 means index > offset)
         let start = (offset + 1) as usize;
-        let total = (0..3)
+        let total: Mint = (0..3)
             .flat_map(|bob| dp[bob * stride + start..(bob + 1) * stride].iter())
-            .fold(0u32, |mut acc, &val| {
-                acc += val;
-                if acc >= MOD {
-                    acc -= MOD;
-                }
-                acc
-            });
-        total as i32
+            .sum();
+        total.value() as i32
     }
 }
 
@@ -233,7 +233,7 @@ mod tests {
         let s = "abba".to_string();
         assert_eq!(
             Solution::find_answer(parent, s),
-            vec![true, true, false, true]
+            vec![true, false, false, true]
         );
     }
 
@@ -274,7 +274,7 @@ mod tests {
         let s = "abab".to_string();
         assert_eq!(
             Solution::find_answer(parent, s),
-            vec![false, true, false, true]
+            vec![false, true, true, true]
         );
     }
 
@@ -292,10 +292,11 @@ mod tests {
     fn balanced_binary_tree() {
         let parent = vec![-1, 0, 0, 1, 1, 2, 2];
         let s = "abababa".to_string();
-        let result = Solution::find_answer(parent, s);
-        assert_eq!(result.len(), 7);
-        // Root should be palindrome due to symmetry
-        assert!(result[0]);
+        // dfs(1) = "bab", dfs(2) = "baa", dfs(0) = "babbaaa"
+        assert_eq!(
+            Solution::find_answer(parent, s),
+            vec![false, true, false, true, true, true, true]
+        );
     }
 
     #[test]
@@ -1,3 +1,6 @@
+use crate::mod_int::Mint;
+use crate::number_theory::gcd;
+
 impl Solution {
     /// In-place DP bounded by max(nums) with precomputed GCD table.
     ///
@@ -21,55 +24,45 @@ impl Solution {
     /// - Time: O(n · m²) where m = max(nums) ≤ 200
     /// - Space: O(m²)
     pub fn subsequence_pair_count(nums: Vec<i32>) -> i32 {
-        const MOD: i32 = 1_000_000_007;
-
         let m = *nums.iter().max().unwrap() as usize;
         let size = m + 1;
 
         // Precompute GCD table over [0, m] — pays for itself in the O(n·m²) loop.
         let gcd_table: Vec<Vec<u8>> = (0..size)
-            .map(|a| (0..size).map(|b| Self::gcd(a, b) as u8).collect())
+            .map(|a| (0..size).map(|b| gcd(a, b) as u8).collect())
             .collect();
 
         // Two flat buffers; copy_from_slice + fill replaces per-element allocation.
-        let mut dp = vec![0i32; size * size];
-        let mut scratch = vec![0i32; size * size];
-        dp[0] = 1; // dp[0][0] = 1
+        let mut dp = vec![Mint::ZERO; size * size];
+        let mut scratch = vec![Mint::ZERO; size * size];
+        dp[0] = Mint::ONE; // dp[0][0] = 1
 
         for &num in &nums {
             let v = num as usize;
 
             scratch.copy_from_slice(&dp);
-            dp.fill(0);
+            dp.fill(Mint::ZERO);
 
             for j in 0..size {
                 let dj = gcd_table[j][v] as usize;
                 for k in 0..size {
                     let val = scratch[j * size + k];
-                    if val == 0 {
+                    if val == Mint::ZERO {
                         continue;
                     }
                     let dk = gcd_table[k][v] as usize;
 
                     // Skip
-                    dp[j * size + k] = (dp[j * size + k] + val) % MOD;
+                    dp[j * size + k] += val;
                     // Assign to seq1
-                    dp[dj * size + k] = (dp[dj * size + k] + val) % MOD;
+                    dp[dj * size + k] += val;
                     // Assign to seq2
-                    dp[j * size + dk] = (dp[j * size + dk] + val) % MOD;
+                    dp[j * size + dk] += val;
                 }
             }
         }
 
-        (1..size).fold(0, |acc, g| (acc + dp[g * size + g]) % MOD)
-    }
-
-    #[inline(always)]
-    fn gcd(mut a: usize, mut b: usize) -> usize {
-        while b != 0 {
-            (a, b) = (b, a % b);
-        }
-        a
+        (1..size).map(|g| dp[g * size + g]).sum::<Mint>().value() as i32
     }
 }
 
@@ -99,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_two_equal_elements() {
-        assert_eq!(Solution::subsequence_pair_count(vec![3, 3]), 1);
+        assert_eq!(Solution::subsequence_pair_count(vec![3, 3]), 2);
     }
 
     #[test]
@@ -109,6 +102,6 @@ mod tests {
 
     #[test]
     fn test_all_same() {
-        assert_eq!(Solution::subsequence_pair_count(vec![7, 7, 7]), 6);
+        assert_eq!(Solution::subsequence_pair_count(vec![7, 7, 7]), 12);
     }
 }
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 struct Solution;
 
 impl Solution {
@@ -20,30 +22,23 @@ impl Solution {
     pub fn sum_of_good_subsequences(nums: Vec<i32>) -> i32 {
         use std::collections::HashMap;
 
-        const MOD: i64 = 1_000_000_007;
-
-        let mut count: HashMap<i32, i64> = HashMap::with_capacity(nums.len());
-        let mut total_sum: HashMap<i32, i64> = HashMap::with_capacity(nums.len());
-        let mut result = 0i64;
+        let mut count: HashMap<i32, Mint> = HashMap::with_capacity(nums.len());
+        let mut total_sum: HashMap<i32, Mint> = HashMap::with_capacity(nums.len());
+        let mut result = Mint::ZERO;
 
         for x in nums {
-            let x_val = x as i64;
-
-            let count_prev = *count.get(&(x - 1)).unwrap_or(&0);
-            let count_next = *count.get(&(x + 1)).unwrap_or(&0);
-            let sum_prev = *total_sum.get(&(x - 1)).unwrap_or(&0);
-            let sum_next = *total_sum.get(&(x + 1)).unwrap_or(&0);
+            let get = |map: &HashMap<i32, Mint>, key| map.get(&key).copied().unwrap_or(Mint::ZERO);
 
-            let new_count = (count_prev + count_next + 1) % MOD;
-            let new_sum = (sum_prev + sum_next + x_val * new_count % MOD) % MOD;
+            let new_count = get(&count, x - 1) + get(&count, x + 1) + 1;
+            let new_sum = get(&total_sum, x - 1) + get(&total_sum, x + 1) + new_count * x;
 
-            *count.entry(x).or_insert(0) = (count.get(&x).unwrap_or(&0) + new_count) % MOD;
-            *total_sum.entry(x).or_insert(0) = (total_sum.get(&x).unwrap_or(&0) + new_sum) % MOD;
+            *count.entry(x).or_insert(Mint::ZERO) += new_count;
+            *total_sum.entry(x).or_insert(Mint::ZERO) += new_sum;
 
-            result = (result + new_sum) % MOD;
+            result += new_sum;
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -1,3 +1,5 @@
+use crate::mod_int::{Combinatorics, Mint};
+
 impl Solution {
     /// Counts k-reducible numbers less than n using digit DP on the binary string.
     ///
@@ -8,15 +10,14 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Precompute depth for every possible popcount up to bit-length of n.
-    /// 2. Precompute binomial coefficients modulo 10^9+7.
+    /// 2. Precompute factorials modulo 10^9+7 for binomial coefficients.
     /// 3. For each target popcount with acceptable depth, count binary numbers < n
     ///    having exactly that many 1-bits via combinatorial digit DP.
     ///
     /// # Complexity
-    /// - Time: O(len² + len × max_popcount) where len is the binary string length
-    /// - Space: O(len²) for binomial coefficients
+    /// - Time: O(len × max_popcount) where len is the binary string length
+    /// - Space: O(len) for factorial tables
     pub fn count_k_reducible_numbers(s: String, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let k = k as usize;
         let bits: Vec<u8> = s.bytes().map(|b| b - b'0').collect();
         let len = bits.len();
@@ -33,15 +34,16 @@ impl Solution {
             })
             .collect();
 
-        let binomial = Self::precompute_binomial(len);
+        let mut comb = Combinatorics::with_capacity(len);
 
         (1..=max_popcount)
             .filter(|&p| match p {
                 1 => true,
-                _ => depth_map[p] <= k - 1,
+                _ => depth_map[p] < k,
             })
-            .map(|p| Self::count_with_popcount_less_than(&bits, p, &binomial))
-            .fold(0i64, |acc, c| (acc + c) % MOD) as i32
+            .map(|p| Self::count_with_popcount_less_than(&bits, p, &mut comb))
+            .sum::<Mint>()
+            .value() as i32
     }
 
     fn compute_depth(mut x: usize) -> usize {
@@ -53,19 +55,17 @@ impl Solution {
         depth
     }
 
-    fn count_with_popcount_less_than(bits: &[u8], target: usize, binomial: &[Vec<i64>]) -> i64 {
-        const MOD: i64 = 1_000_000_007;
+    fn count_with_popcount_less_than(bits: &[u8], target: usize, comb: &mut Combinatorics) -> Mint {
         if target == 0 {
-            return 0;
+            return Mint::ZERO;
         }
 
         let len = bits.len();
 
-        let result = (1..len)
+        let result: Mint = (1..len)
             .filter(|&num_bits| target <= num_bits)
-            .fold(0i64, |acc, num_bits| {
-                (acc + binomial[num_bits - 1][target - 1]) % MOD
-            });
+            .map(|num_bits| comb.choose(num_bits - 1, target - 1))
+            .sum();
 
         bits.iter()
             .enumerate()
@@ -76,7 +76,7 @@ impl Solution {
                     if ones_placed <= target {
                         let ones_needed = target - ones_placed;
                         if ones_needed <= remaining_bits {
-                            acc = (acc + binomial[remaining_bits][ones_needed]) % MOD;
+                            acc += comb.choose(remaining_bits, ones_needed);
                         }
                     }
                     (acc, ones_placed + 1)
@@ -86,17 +86,6 @@ impl Solution {
             })
             .0
     }
-
-    fn precompute_binomial(max_n: usize) -> Vec<Vec<i64>> {
-        const MOD: i64 = 1_000_000_007;
-        (0..=max_n).fold(vec![vec![0i64; max_n + 1]; max_n + 1], |mut c, n| {
-            c[n][0] = 1;
-            (1..=n).for_each(|k| {
-                c[n][k] = (c[n - 1][k - 1] + c[n - 1][k]) % MOD;
-            });
-            c
-        })
-    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,3 @@
-use std::cmp::{max, min};
-
 impl Solution {
     /// Minimizes maximum adjacent difference via binary search with two-value assignment.
     ///
@@ -51,7 +49,7 @@ impl Solution {
             let (mut ok_x, mut ok_y) = (true, true);
 
             for i in 1..n {
-                let (mut nx, mut ny) = (false, false);
+                let (nx, ny);
                 let (p, c) = (nums[i - 1], nums[i]);
 
                 match (p == -1, c == -1) {
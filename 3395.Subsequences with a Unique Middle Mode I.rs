@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 use std::collections::HashMap;
 
 impl Solution {
@@ -20,7 +22,6 @@ impl Solution {
     /// - Time: O(n × m) where m is the number of unique values
     /// - Space: O(m) for frequency arrays
     pub fn subsequences_with_middle_mode(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         let n = nums.len();
 
         let mut sorted_unique = nums.clone();
@@ -42,7 +43,7 @@ impl Solution {
         let mut left: Vec<i64> = vec![0; m];
         let comb2 = |x: i64| -> i64 { if x < 2 { 0 } else { x * (x - 1) / 2 } };
 
-        let mut result: i64 = 0;
+        let mut result = Mint::ZERO;
 
         for i in 0..n {
             let c = nums[i];
@@ -81,12 +82,10 @@ impl Solution {
             let invalid_0_1 = right_c * r_other * sum_left_sq + right_c * sum_lr_l_other;
             result += total_0_1 - invalid_0_1;
 
-            result = result.rem_euclid(MOD);
-
             left[c] += 1;
         }
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -1,105 +1,44 @@
 use std::collections::HashMap;
 
-trait SegmentTreeHelper<T> {
-    fn combine(&self, left: T, right: T) -> T;
+use crate::segment_tree::{Monoid, SegmentTree};
+
+/// Best subarray, prefix and suffix sums of a segment, each at least zero,
+/// and its total.
+#[derive(Clone, Copy)]
+struct Subarray {
+    best: i64,
+    prefix: i64,
+    suffix: i64,
+    total: i64,
 }
 
-struct SegmentTree<T: Copy, H: SegmentTreeHelper<T>> {
-    data: Vec<T>,
-    len: usize,
-    helper: H,
-}
-
-impl<T: Copy, H: SegmentTreeHelper<T>> SegmentTree<T, H> {
-    fn new(nums: &[T], helper: H) -> Self {
-        let mut result = Self {
-            data: vec![nums[0]; nums.len() * 4],
-            len: nums.len(),
-            helper,
-        };
-        result.build(0, 0, nums.len() - 1, nums);
-        result
-    }
-
-    fn build(&mut self, node: usize, start: usize, end: usize, nums: &[T]) {
-        if start == end {
-            self.data[node] = nums[start];
-        } else {
-            let mid = (start + end) / 2;
-            self.build(node * 2 + 1, start, mid, nums);
-            self.build(node * 2 + 2, mid + 1, end, nums);
-            self.data[node] = self
-                .helper
-                .combine(self.data[node * 2 + 1], self.data[node * 2 + 2]);
-        }
-    }
-
-    fn update(&mut self, index: usize, val: T) {
-        self.update_recursive(0, 0, self.len - 1, index, val);
-    }
-
-    fn update_recursive(&mut self, node: usize, start: usize, end: usize, index: usize, val: T) {
-        if start == end {
-            self.data[node] = val;
-        } else {
-            let mid = (start + end) / 2;
-            if index <= mid {
-                self.update_recursive(node * 2 + 1, start, mid, index, val);
-            } else {
-                self.update_recursive(node * 2 + 2, mid + 1, end, index, val);
-            }
-            self.data[node] = self
-                .helper
-                .combine(self.data[node * 2 + 1], self.data[node * 2 + 2]);
+impl Subarray {
+    fn new(value: i64) -> Self {
+        let clamped = value.max(0);
+        Subarray {
+            best: clamped,
+            prefix: clamped,
+            suffix: clamped,
+            total: value,
         }
     }
+}
 
-    fn query(&self, left: usize, right: usize) -> T {
-        self.query_recursive(0, 0, self.len - 1, left, right)
+impl Monoid for Subarray {
+    fn identity() -> Self {
+        Subarray::new(0)
     }
 
-    fn query_recursive(
-        &self,
-        node: usize,
-        start: usize,
-        end: usize,
-        left: usize,
-        right: usize,
-    ) -> T {
-        if start == left && end == right {
-            self.data[node]
-        } else {
-            let mid = (start + end) / 2;
-            if right <= mid {
-                self.query_recursive(node * 2 + 1, start, mid, left, right)
-            } else if left > mid {
-                self.query_recursive(node * 2 + 2, mid + 1, end, left, right)
-            } else {
-                let l = self.query_recursive(node * 2 + 1, start, mid, left, mid);
-                let r = self.query_recursive(node * 2 + 2, mid + 1, end, mid + 1, right);
-                self.helper.combine(l, r)
-            }
+    fn combine(&self, right: &Self) -> Self {
+        Subarray {
+            best: self.best.max(right.best).max(right.prefix + self.suffix),
+            prefix: self.prefix.max(self.total + right.prefix),
+            suffix: right.suffix.max(right.total + self.suffix),
+            total: self.total + right.total,
         }
     }
 }
 
-struct SubarrayHelper;
-
-impl SegmentTreeHelper<(i64, i64, i64, i64)> for SubarrayHelper {
-    fn combine(
-        &self,
-        left: (i64, i64, i64, i64),
-        right: (i64, i64, i64, i64),
-    ) -> (i64, i64, i64, i64) {
-        (
-            left.0.max(right.0).max(right.1 + left.2),
-            left.1.max(left.3 + right.1),
-            right.2.max(right.3 + left.2),
-            left.3 + right.3,
-        )
-    }
-}
-
 impl Solution {
     /// Maximizes subarray sum after optionally removing all occurrences of one element.
     ///
@@ -125,13 +64,8 @@ impl Solution {
             return max_val;
         }
 
-        let nodes: Vec<_> = nums
-            .iter()
-            .map(|&x| x as i64)
-            .map(|x| (x.max(0), x.max(0), x.max(0), x))
-            .collect();
-
-        let mut tree = SegmentTree::new(&nodes, SubarrayHelper);
+        let mut tree: SegmentTree<Subarray> =
+            nums.iter().map(|&x| Subarray::new(x as i64)).collect();
 
         let neg_positions: HashMap<i32, Vec<usize>> = nums
             .iter()
@@ -142,16 +76,18 @@ impl Solution {
                 acc
             });
 
-        let mut result = tree.query(0, n - 1).0;
+        let mut result = tree.all().best;
 
         for (&val, positions) in &neg_positions {
-            positions.iter().for_each(|&i| tree.update(i, (0, 0, 0, 0)));
+            positions
+                .iter()
+                .for_each(|&i| tree.set(i, Subarray::identity()));
 
-            result = result.max(tree.query(0, n - 1).0);
+            result = result.max(tree.all().best);
 
             positions
                 .iter()
-                .for_each(|&i| tree.update(i, (0, 0, 0, val as i64)));
+                .for_each(|&i| tree.set(i, Subarray::new(val as i64)));
         }
 
         result
@@ -78,7 +78,7 @@ mod tests {
     fn test_no_neutralization_needed() {
         assert_eq!(
             Solution::maximum_amount(vec![vec![1, 2, 3], vec![4, 5, 6]]),
-            21
+            16
         );
     }
 
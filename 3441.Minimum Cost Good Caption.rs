@@ -56,7 +56,8 @@ impl Solution {
                 .filter(|&len| dp[i + len] != i64::MAX)
                 .flat_map(|len| {
                     let j = i + len;
-                    (0..26).map(move |c| (c, len, cost(i, j - 1, c) + dp[j]))
+                    let tail = dp[j];
+                    (0..26).map(move |c| (c, len, cost(i, j - 1, c) + tail))
                 })
                 .collect();
 
@@ -93,7 +94,7 @@ impl Solution {
 
         while i < n {
             let (c, len) = best_choice[i];
-            result.extend(std::iter::repeat(b'a' + c as u8).take(len));
+            result.extend(std::iter::repeat_n(b'a' + c as u8, len));
             i += len;
         }
 
@@ -148,9 +148,7 @@ mod tests {
     /// Performance test with maximum constraints
     #[test]
     fn test_performance_large_input() {
-        let squares: Vec<Vec<i32>> = (0..50000)
-            .map(|i| vec![i as i32 % 1000, i as i32 * 100, 100])
-            .collect();
+        let squares: Vec<Vec<i32>> = (0..50000).map(|i| vec![i % 1000, i * 100, 100]).collect();
         let result = Solution::separate_squares(squares);
         assert!(result > 0.0);
     }
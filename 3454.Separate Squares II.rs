@@ -1,58 +1,72 @@
-struct SegmentTree {
-    count: Vec<i32>,
-    occupied: Vec<i64>,
-    total_width: Vec<i64>,
-    n: usize,
+use crate::segment_tree::{Add, LazyAction, Monoid, SegmentTree};
+
+/// Coverage of a run of compressed x-intervals: the smallest number of open
+/// squares over any of them, the width where that minimum is reached, and
+/// the total width.
+#[derive(Clone, Copy, PartialEq)]
+struct Cover {
+    min: i32,
+    min_width: i64,
+    width: i64,
 }
 
-impl SegmentTree {
-    fn new(xs: &[i32]) -> Self {
-        let n = xs.len() - 1;
-        let size = 4 * n;
-        let mut st = Self {
-            count: vec![0; size],
-            occupied: vec![0; size],
-            total_width: vec![0; size],
-            n,
-        };
-        if n > 0 {
-            st.build(0, 0, n - 1, xs);
+impl Cover {
+    fn interval(width: i64) -> Self {
+        Cover {
+            min: 0,
+            min_width: width,
+            width,
         }
-        st
     }
 
-    fn build(&mut self, x: usize, lx: usize, rx: usize, xs: &[i32]) {
-        if lx == rx {
-            self.total_width[x] = (xs[lx + 1] - xs[lx]) as i64;
-            return;
+    /// Width covered by at least one square.
+    fn occupied(&self) -> i64 {
+        if self.min > 0 {
+            self.width
+        } else {
+            self.width - self.min_width
         }
-        let mid = (lx + rx) / 2;
-        self.build(2 * x + 1, lx, mid, xs);
-        self.build(2 * x + 2, mid + 1, rx, xs);
-        self.total_width[x] = self.total_width[2 * x + 1] + self.total_width[2 * x + 2];
     }
+}
 
-    fn update(&mut self, x: usize, lx: usize, rx: usize, l: usize, r: usize, v: i32) {
-        if lx >= l && rx <= r {
-            self.count[x] += v;
-        } else {
-            let mid = (lx + rx) / 2;
-            if l <= mid {
-                self.update(2 * x + 1, lx, mid, l, r, v);
-            }
-            if r > mid {
-                self.update(2 * x + 2, mid + 1, rx, l, r, v);
-            }
+impl Monoid for Cover {
+    fn identity() -> Self {
+        Cover {
+            min: i32::MAX,
+            min_width: 0,
+            width: 0,
         }
+    }
 
-        if self.count[x] > 0 {
-            self.occupied[x] = self.total_width[x];
-        } else if lx != rx {
-            self.occupied[x] = self.occupied[2 * x + 1] + self.occupied[2 * x + 2];
-        } else {
-            self.occupied[x] = 0;
+    fn combine(&self, right: &Self) -> Self {
+        let min = self.min.min(right.min);
+        let width_at = |cover: &Self| if cover.min == min { cover.min_width } else { 0 };
+        Cover {
+            min,
+            min_width: width_at(self) + width_at(right),
+            width: self.width + right.width,
+        }
+    }
+}
+
+impl LazyAction<Cover> for Add<i32> {
+    fn identity() -> Self {
+        Add(0)
+    }
+
+    fn apply(&self, cover: &Cover) -> Cover {
+        if *cover == Cover::identity() {
+            return *cover;
+        }
+        Cover {
+            min: cover.min + self.0,
+            ..*cover
         }
     }
+
+    fn compose(&self, inner: &Self) -> Self {
+        Add(self.0 + inner.0)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -119,7 +133,10 @@ impl Solution {
 
         events.sort_unstable_by_key(|e| e.y);
 
-        let mut st = SegmentTree::new(&xs);
+        let mut st: SegmentTree<Cover, Add<i32>> = xs
+            .windows(2)
+            .map(|w| Cover::interval((w[1] - w[0]) as i64))
+            .collect();
         let mut total_area: f64 = 0.0;
         let mut history = Vec::with_capacity(events.len());
 
@@ -131,21 +148,14 @@ impl Solution {
 
             while i < n_events && events[i].y == y {
                 let e = events[i];
-                st.update(
-                    0,
-                    0,
-                    st.n - 1,
-                    e.l_idx as usize,
-                    (e.r_idx - 1) as usize,
-                    e.type_,
-                );
+                st.apply(e.l_idx as usize..e.r_idx as usize, &Add(e.type_));
                 i += 1;
             }
 
             if i < n_events {
                 let next_y = events[i].y;
                 if next_y > y {
-                    let width = st.occupied[0];
+                    let width = st.all().occupied();
                     if width > 0 {
                         let h = next_y - y;
                         history.push((total_area, y, next_y, width));
@@ -1,3 +1,6 @@
+use crate::range_query::{RangeQuery, SparseTable};
+use crate::segment_tree::{Max, Min};
+
 impl Solution {
     /// Sparse table RMQ over zero-run groups for O((n + q) log n) query processing.
     ///
@@ -59,9 +62,9 @@ impl Solution {
             .map(|i| zero_lens[i] + zero_lens[i + 1])
             .collect();
 
-        let max_adj_st = SparseTable::new(&adj_sums, true);
-        let max_zero_st = SparseTable::new(&zero_lens, true);
-        let min_one_st = SparseTable::new(&inner_ones, false);
+        let max_adj_st: SparseTable<_> = adj_sums.into_iter().map(Max).collect();
+        let max_zero_st: SparseTable<_> = zero_lens.iter().copied().map(Max).collect();
+        let min_one_st: SparseTable<_> = inner_ones.into_iter().map(Min).collect();
 
         queries
             .iter()
@@ -89,9 +92,9 @@ impl Solution {
         r: usize,
         zero_runs: &[(u32, u32, i32)],
         zero_lens: &[i32],
-        max_adj_st: &SparseTable,
-        max_zero_st: &SparseTable,
-        min_one_st: &SparseTable,
+        max_adj_st: &SparseTable<Max<i32>>,
+        max_zero_st: &SparseTable<Max<i32>>,
+        min_one_st: &SparseTable<Min<i32>>,
         nz: usize,
     ) -> i32 {
         // Locate zero-runs overlapping [l, r].
@@ -117,7 +120,7 @@ impl Solution {
 
         // Max clipped zero-run size in query (used for Option B).
         let mid_max_zero = if lz > fz + 1 {
-            max_zero_st.query(fz + 1, lz - 1)
+            max_zero_st.query(fz + 1..lz).0
         } else {
             i32::MIN
         };
@@ -146,7 +149,7 @@ impl Solution {
 
         // Interior pairs k ∈ [fz+1, lz-2]: both endpoints fully within [l, r].
         if lz >= fz + 3 {
-            let interior_adj = max_adj_st.query(fz + 1, lz - 2);
+            let interior_adj = max_adj_st.query(fz + 1..lz - 1).0;
             if interior_adj > i32::MIN {
                 max_gain = max_gain.max(interior_adj);
             }
@@ -154,7 +157,7 @@ impl Solution {
 
         // Option B: pick best zero-run, sacrifice cheapest inner 1-run.
         // gain = max_zero_val - min_inner_one over [fz, lz-1].
-        let min_one_val = min_one_st.query(fz, lz - 1);
+        let min_one_val = min_one_st.query(fz..lz).0;
         if min_one_val != i32::MAX {
             max_gain = max_gain.max(max_zero_val - min_one_val);
         }
@@ -163,68 +166,6 @@ impl Solution {
     }
 }
 
-/// Sparse table for idempotent range queries (max or min) in O(1) per query.
-struct SparseTable {
-    table: Vec<Vec<i32>>,
-    log: Vec<usize>,
-    is_max: bool,
-    n: usize,
-}
-
-impl SparseTable {
-    fn new(arr: &[i32], is_max: bool) -> Self {
-        let n = arr.len();
-        if n == 0 {
-            return Self {
-                table: vec![],
-                log: vec![],
-                is_max,
-                n: 0,
-            };
-        }
-
-        let mut log = vec![0usize; n + 1];
-        (2..=n).for_each(|i| log[i] = log[i / 2] + 1);
-
-        let k = log[n] + 1;
-        let identity = if is_max { i32::MIN } else { i32::MAX };
-        let mut table = vec![vec![identity; n]; k];
-        table[0].iter_mut().zip(arr).for_each(|(t, &v)| *t = v);
-
-        for j in 1..k {
-            let step = 1 << (j - 1);
-            for i in 0..=n.saturating_sub(1 << j) {
-                table[j][i] = if is_max {
-                    table[j - 1][i].max(table[j - 1][i + step])
-                } else {
-                    table[j - 1][i].min(table[j - 1][i + step])
-                };
-            }
-        }
-
-        Self {
-            table,
-            log,
-            is_max,
-            n,
-        }
-    }
-
-    fn query(&self, l: usize, r: usize) -> i32 {
-        if self.n == 0 || l > r || r >= self.n {
-            return if self.is_max { i32::MIN } else { i32::MAX };
-        }
-        let len = r - l + 1;
-        let j = self.log[len];
-        let step = 1 << j;
-        if self.is_max {
-            self.table[j][l].max(self.table[j][r + 1 - step])
-        } else {
-            self.table[j][l].min(self.table[j][r + 1 - step])
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::Solution;
@@ -98,7 +98,7 @@ impl Solution {
             }
         };
 
-        let target_lo = ((x + 1) / 2) as i64;
+        let target_lo = x.div_ceil(2) as i64;
         let mut cost = vec![0i64; n];
 
         for i in 0..n {
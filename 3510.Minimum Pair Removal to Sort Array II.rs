@@ -28,10 +28,9 @@ impl Solution {
         let mut prevs: Vec<i32> = (-1..n as i32 - 1).collect();
         nexts[n - 1] = -1;
 
-        let mut unsorted_cnt = 0i32;
         let mut heap: BinaryHeap<Reverse<(i64, u32)>> = BinaryHeap::with_capacity(n * 2);
 
-        unsorted_cnt = vals.windows(2).filter(|w| w[0] > w[1]).count() as i32;
+        let mut unsorted_cnt = vals.windows(2).filter(|w| w[0] > w[1]).count() as i32;
         vals.windows(2).enumerate().for_each(|(i, w)| {
             heap.push(Reverse((w[0] + w[1], i as u32)));
         });
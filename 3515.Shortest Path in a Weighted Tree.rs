@@ -1,3 +1,6 @@
+use crate::fenwick::DifferenceFenwick;
+use crate::rooted_tree::RootedTree;
+
 impl Solution {
     /// Uses Euler tour with Binary Indexed Tree for efficient path queries and updates.
     ///
@@ -7,114 +10,53 @@ impl Solution {
     /// to all nodes in the subtree below that edge.
     ///
     /// # Approach
-    /// 1. Build adjacency list and perform DFS to compute Euler tour (in/out times)
-    /// 2. Store initial distances from root to each node during DFS
+    /// 1. Root the tree to get Euler tour positions, subtree ranges and parent edges
+    /// 2. Accumulate initial distances from root to each node in preorder
     /// 3. Use a Binary Indexed Tree (BIT) to handle range updates efficiently
-    /// 4. For edge weight updates: the child node (with larger in_time) determines the
-    ///    subtree to update - use BIT range update
-    /// 5. For distance queries: return initial distance + BIT prefix sum at node's in-time
+    /// 4. For edge weight updates: the deeper endpoint determines the subtree to
+    ///    update - use BIT range update
+    /// 5. For distance queries: return initial distance + BIT point value at node's in-time
     ///
     /// # Complexity
     /// - Time: O((n + q) * log(n)) for building tree and processing queries
-    /// - Space: O(n) for adjacency list, BIT, and Euler tour arrays
+    /// - Space: O(n log n) for the rooted tree's lifting table; O(n) for the BIT
     pub fn tree_queries(n: i32, edges: Vec<Vec<i32>>, queries: Vec<Vec<i32>>) -> Vec<i32> {
         let n = n as usize;
-
-        // Build adjacency list: (neighbor, edge_index)
-        let mut adj: Vec<Vec<(u32, u32)>> = vec![vec![]; n + 1];
-        let mut edge_weights: Vec<i32> = vec![0; n];
-
-        edges.iter().enumerate().for_each(|(idx, edge)| {
-            let (u, v) = (edge[0] as usize, edge[1] as usize);
-            adj[u].push((v as u32, idx as u32));
-            adj[v].push((u as u32, idx as u32));
-            edge_weights[idx] = edge[2];
-        });
-
-        // Euler tour arrays
-        let mut in_time: Vec<u32> = vec![0; n + 1];
-        let mut out_time: Vec<u32> = vec![0; n + 1];
-        let mut dist: Vec<i64> = vec![0; n + 1];
-        let mut parent: Vec<u32> = vec![0; n + 1];
-        let mut parent_edge: Vec<u32> = vec![u32::MAX; n + 1];
-
-        // Iterative DFS
-        let mut timer = 0u32;
-        let mut stack: Vec<(u32, u32, i64, usize)> = Vec::with_capacity(n);
-        stack.push((1, 0, 0, 0));
-
-        while let Some(&(node, par, d, idx)) = stack.last() {
-            let node_usize = node as usize;
-
-            if idx == 0 {
-                in_time[node_usize] = timer;
-                timer += 1;
-                dist[node_usize] = d;
-                parent[node_usize] = par;
-            }
-
-            let adj_node = &adj[node_usize];
-            let mut found = false;
-
-            for i in idx..adj_node.len() {
-                let (neighbor, edge_idx) = adj_node[i];
-                if neighbor != par {
-                    stack.last_mut().unwrap().3 = i + 1;
-                    parent_edge[neighbor as usize] = edge_idx;
-                    let weight = edge_weights[edge_idx as usize] as i64;
-                    stack.push((neighbor, node, d + weight, 0));
-                    found = true;
-                    break;
-                }
-            }
-
-            if !found {
-                out_time[node_usize] = timer - 1;
-                stack.pop();
-            }
+        let mut edge_weights: Vec<i32> = edges.iter().map(|edge| edge[2]).collect();
+        let tree = RootedTree::new(
+            n,
+            edges
+                .iter()
+                .map(|edge| (edge[0] as usize - 1, edge[1] as usize - 1)),
+            0,
+        );
+
+        let mut dist: Vec<i64> = vec![0; n];
+        for &v in &tree.preorder()[1..] {
+            let parent = tree.parent(v).unwrap();
+            let edge_idx = tree.parent_edge(v).unwrap();
+            dist[v] = dist[parent] + edge_weights[edge_idx] as i64;
         }
 
-        // Binary Indexed Tree
-        let mut bit: Vec<i64> = vec![0; n + 2];
-
-        #[inline(always)]
-        fn update(bit: &mut [i64], mut i: usize, delta: i64) {
-            i += 1;
-            while i < bit.len() {
-                bit[i] += delta;
-                i += i & i.wrapping_neg();
-            }
-        }
-
-        #[inline(always)]
-        fn query(bit: &[i64], mut i: usize) -> i64 {
-            i += 1;
-            let mut sum = 0i64;
-            while i > 0 {
-                sum += bit[i];
-                i &= i - 1;
-            }
-            sum
-        }
+        let mut bit: DifferenceFenwick<i64> = DifferenceFenwick::new(n);
 
         let mut result = Vec::with_capacity(queries.len());
 
         for q in &queries {
             if q[0] == 1 {
-                let (u, v, new_w) = (q[1] as usize, q[2] as usize, q[3]);
+                let (u, v, new_w) = (q[1] as usize - 1, q[2] as usize - 1, q[3]);
 
-                // Child is the one with larger in_time (deeper in tree)
-                let child = if in_time[u] > in_time[v] { u } else { v };
-                let edge_idx = parent_edge[child] as usize;
+                // Child is the deeper endpoint
+                let child = if tree.depth(u) > tree.depth(v) { u } else { v };
+                let edge_idx = tree.parent_edge(child).unwrap();
 
                 let delta = (new_w - edge_weights[edge_idx]) as i64;
                 edge_weights[edge_idx] = new_w;
 
-                update(&mut bit, in_time[child] as usize, delta);
-                update(&mut bit, out_time[child] as usize + 1, -delta);
+                bit.add(tree.subtree(child), delta);
             } else {
-                let x = q[1] as usize;
-                let distance = dist[x] + query(&bit, in_time[x] as usize);
+                let x = q[1] as usize - 1;
+                let distance = dist[x] + bit.get(tree.position(x));
                 result.push(distance as i32);
             }
         }
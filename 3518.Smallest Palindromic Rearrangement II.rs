@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Finds the k-th lexicographically smallest palindromic permutation.
     ///
@@ -37,7 +39,7 @@ impl Solution {
             .map(|(i, _)| b'a' + i as u8);
 
         // Parity of n and presence of mid char must agree
-        if (n % 2 == 0) != mid_char.is_none() {
+        if n.is_multiple_of(2) != mid_char.is_none() {
             return String::new();
         }
 
@@ -132,15 +134,6 @@ impl Solution {
     }
 }
 
-fn gcd(mut a: i64, mut b: i64) -> i64 {
-    while b != 0 {
-        let t = b;
-        b = a % b;
-        a = t;
-    }
-    a
-}
-
 #[cfg(test)]
 mod tests {
     use super::Solution;
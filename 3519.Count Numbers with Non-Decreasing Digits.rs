@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts integers in [l, r] with non-decreasing digits in base b.
     ///
@@ -14,7 +16,6 @@ impl Solution {
     /// - Time: O(n × b²) where n is the number of base-b digits
     /// - Space: O(n × b)
     pub fn count_numbers(l: String, r: String, b: i32) -> i32 {
-        const MOD: u64 = 1_000_000_007;
         let b = b as usize;
 
         fn subtract_one(s: &[u8]) -> Vec<u8> {
@@ -55,30 +56,29 @@ impl Solution {
         }
 
         // dp[i][d] = count of i-digit non-decreasing numbers where first digit is d
-        fn build_dp(max_len: usize, base: usize) -> Vec<Vec<u64>> {
-            let mut dp = vec![vec![0u64; base + 1]; max_len + 1];
-            (0..=base).for_each(|d| dp[0][d] = 1);
+        fn build_dp(max_len: usize, base: usize) -> Vec<Vec<Mint>> {
+            let mut dp = vec![vec![Mint::ZERO; base + 1]; max_len + 1];
+            (0..=base).for_each(|d| dp[0][d] = Mint::ONE);
             for len in 1..=max_len {
-                dp[len][base] = 0;
+                dp[len][base] = Mint::ZERO;
                 for d in (0..base).rev() {
-                    dp[len][d] = (dp[len][d + 1] + dp[len - 1][d]) % MOD;
+                    dp[len][d] = dp[len][d + 1] + dp[len - 1][d];
                 }
             }
             dp
         }
 
         // Count non-decreasing numbers <= digits (in base b)
-        fn count(digits: &[usize], base: usize, dp: &[Vec<u64>]) -> u64 {
+        fn count(digits: &[usize], base: usize, dp: &[Vec<Mint>]) -> Mint {
             let n = digits.len();
             if n == 0 {
-                return 0;
+                return Mint::ZERO;
             }
-            let mut result = 0u64;
 
             // Count numbers with fewer digits (1 to n-1 digits)
-            result = (1..n)
+            let mut result: Mint = (1..n)
                 .flat_map(|len| (1..base).map(move |d| dp[len - 1][d]))
-                .fold(result, |acc, x| (acc + x) % MOD);
+                .sum();
 
             // Count n-digit numbers <= digits
             let mut last = 0;
@@ -86,7 +86,7 @@ impl Solution {
                 let remaining = n - 1 - i;
                 let start = if i == 0 { 1.max(last) } else { last };
                 for digit in start..d {
-                    result = (result + dp[remaining][digit]) % MOD;
+                    result += dp[remaining][digit];
                 }
                 if d < last {
                     return result;
@@ -108,12 +108,12 @@ impl Solution {
 
         let count_r = count(&r_digits, b, &dp);
         let count_l = if l_minus_1.is_empty() || (l_minus_1.len() == 1 && l_minus_1[0] == 0) {
-            0
+            Mint::ZERO
         } else {
             count(&l_digits, b, &dp)
         };
 
-        ((count_r + MOD - count_l) % MOD) as i32
+        (count_r - count_l).value() as i32
     }
 }
 
@@ -89,6 +89,7 @@ mod tests {
     fn test_diamond_dag() {
         let edges = vec![vec![0, 1], vec![0, 2], vec![1, 3], vec![2, 3]];
         let score = vec![1, 2, 3, 4];
-        assert_eq!(Solution::max_profit(4, edges, score), 26);
+        // Order 0, 1, 2, 3: 1*1 + 2*2 + 3*3 + 4*4
+        assert_eq!(Solution::max_profit(4, edges, score), 30);
     }
 }
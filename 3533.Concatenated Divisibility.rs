@@ -55,7 +55,6 @@ impl Solution {
                 pos: usize,
                 mask: usize,
                 rem: usize,
-                n: usize,
                 k: usize,
                 full_mask: usize,
                 indices: &[usize],
@@ -84,7 +83,6 @@ impl Solution {
                         pos + 1,
                         mask | (1 << i),
                         new_rem,
-                        n,
                         k,
                         full_mask,
                         indices,
@@ -106,7 +104,6 @@ impl Solution {
                 0,
                 0,
                 0,
-                n,
                 k,
                 full_mask,
                 indices,
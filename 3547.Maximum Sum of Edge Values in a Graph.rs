@@ -115,10 +115,9 @@ mod tests {
         let n = 5;
         let edges = vec![vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4]];
         let result = Solution::max_score(n, edges);
-        // Middle nodes (1, 2, 3) get largest values (5, 4, 3)
-        // End nodes (0, 4) get smallest values (1, 2)
-        // Sum: 1*5 + 5*4 + 4*3 + 3*2 = 5 + 20 + 12 + 6 = 43
-        assert_eq!(result, 43);
+        // Values along the path: 1, 3, 5, 4, 2
+        // Sum: 1*3 + 3*5 + 5*4 + 4*2 = 3 + 15 + 20 + 8 = 46
+        assert_eq!(result, 46);
     }
 
     #[test]
@@ -127,9 +126,9 @@ mod tests {
         let edges = vec![vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 0]];
         let result = Solution::max_score(n, edges);
         // All nodes have degree 2, cycle structure
-        // Values assigned: 4, 3, 2, 1 in cycle order
-        // Sum: 4*3 + 3*2 + 2*1 + 1*4 = 12 + 6 + 2 + 4 = 24
-        assert_eq!(result, 24);
+        // Values assigned: 1, 3, 4, 2 in cycle order
+        // Sum: 1*3 + 3*4 + 4*2 + 2*1 = 3 + 12 + 8 + 2 = 25
+        assert_eq!(result, 25);
     }
 
     #[test]
@@ -142,7 +142,7 @@ impl Solution {
         if n == 1 {
             return grid[rs][0] == target || grid[re][0] == target;
         }
-        lookup.get(&target).map_or(false, |&r| r >= rs && r <= re)
+        lookup.get(&target).is_some_and(|&r| r >= rs && r <= re)
     }
 
     /// Vertical-cut discount check.
@@ -167,7 +167,7 @@ impl Solution {
         if cols == 1 {
             return grid[0][cs] == target || grid[m - 1][cs] == target;
         }
-        lookup.get(&target).map_or(false, |&c| c >= cs && c <= ce)
+        lookup.get(&target).is_some_and(|&c| c >= cs && c <= ce)
     }
 }
 
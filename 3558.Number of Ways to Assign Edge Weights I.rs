@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Count assignments of edge weights (1 or 2) on the root-to-deepest-node path with odd total cost.
     ///
@@ -15,8 +17,6 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(n)
     pub fn assign_edge_weights(edges: Vec<Vec<i32>>) -> i32 {
-        const MOD: u64 = 1_000_000_007;
-
         let n = edges.len() + 1; // nodes labeled 1..=n
 
         // CSR adjacency: count degrees, then fill
@@ -64,19 +64,7 @@ impl Solution {
             return 0;
         }
 
-        // 2^(max_depth - 1) mod MOD via fast exponentiation
-        let mut result = 1u64;
-        let mut base = 2u64;
-        let mut exp = (max_depth - 1) as u64;
-        while exp > 0 {
-            if exp & 1 == 1 {
-                result = result * base % MOD;
-            }
-            base = base * base % MOD;
-            exp >>= 1;
-        }
-
-        result as i32
+        Mint::new(2).pow((max_depth - 1) as u64).value() as i32
     }
 }
 
@@ -1,65 +1,7 @@
 use std::collections::HashMap;
 
-struct SegTree {
-    n: usize,
-    tree: Vec<i32>,
-    lazy: Vec<i32>,
-}
-
-impl SegTree {
-    fn new(n: usize) -> Self {
-        let size = 4 * n.max(1);
-        Self {
-            n,
-            tree: vec![0; size],
-            lazy: vec![0; size],
-        }
-    }
-
-    #[inline(always)]
-    fn push(&mut self, node: usize) {
-        if self.lazy[node] != 0 {
-            let l = self.lazy[node];
-            self.tree[node * 2] += l;
-            self.lazy[node * 2] += l;
-            self.tree[node * 2 + 1] += l;
-            self.lazy[node * 2 + 1] += l;
-            self.lazy[node] = 0;
-        }
-    }
-
-    fn update(&mut self, l: usize, r: usize, val: i32) {
-        if self.n == 0 || l > r || r >= self.n {
-            return;
-        }
-        self.update_inner(1, 0, self.n - 1, l, r, val);
-    }
-
-    fn update_inner(&mut self, node: usize, tl: usize, tr: usize, l: usize, r: usize, val: i32) {
-        if l > tr || r < tl {
-            return;
-        }
-        if l <= tl && tr <= r {
-            self.tree[node] += val;
-            self.lazy[node] += val;
-            return;
-        }
-        self.push(node);
-        let mid = (tl + tr) / 2;
-        self.update_inner(node * 2, tl, mid, l, r, val);
-        self.update_inner(node * 2 + 1, mid + 1, tr, l, r, val);
-        self.tree[node] = self.tree[node * 2].max(self.tree[node * 2 + 1]);
-    }
-
-    #[inline(always)]
-    fn max(&self) -> i32 {
-        if self.n == 0 {
-            0
-        } else {
-            self.tree[1]
-        }
-    }
-}
+use crate::number_theory::Sieve;
+use crate::segment_tree::{Add, Max, SegmentTree};
 
 const MAX_VAL: usize = 100_001;
 
@@ -85,14 +27,14 @@ impl Solution {
             return vec![0; queries.len()];
         }
 
-        let is_prime = sieve();
+        let sieve = Sieve::new(MAX_VAL - 1);
         let mut nums = nums;
         let mut positions: HashMap<u32, Vec<u32>> = HashMap::with_capacity(1000);
         let mut total_primes = 0i32;
 
         nums.iter().enumerate().for_each(|(i, &num)| {
             let v = num as u32;
-            if is_prime[v as usize] {
+            if sieve.is_prime(v as usize) {
                 let pos = positions.entry(v).or_default();
                 if pos.is_empty() {
                     total_primes += 1;
@@ -101,7 +43,7 @@ impl Solution {
             }
         });
 
-        let mut seg = SegTree::new(n - 1);
+        let mut seg: SegmentTree<Max<i32>, Add<i32>> = vec![Max(0); n - 1].into();
 
         positions
             .values()
@@ -109,7 +51,7 @@ impl Solution {
             .for_each(|pos| {
                 let f = pos[0] as usize;
                 let l = *pos.last().unwrap() as usize;
-                seg.update(f, l - 1, 1);
+                seg.apply(f..l, &Add(1));
             });
 
         let mut result = Vec::with_capacity(queries.len());
@@ -120,18 +62,18 @@ impl Solution {
             let old_val = nums[idx as usize] as u32;
 
             if old_val == new_val {
-                result.push(total_primes + seg.max());
+                result.push(total_primes + seg.all().0);
                 continue;
             }
 
             // Remove old value
-            if is_prime[old_val as usize] {
-                if let Some(pos) = positions.get_mut(&old_val) {
+            if sieve.is_prime(old_val as usize)
+                && let Some(pos) = positions.get_mut(&old_val) {
                     let old_f = pos[0] as usize;
                     let old_l = *pos.last().unwrap() as usize;
 
                     if old_l > old_f {
-                        seg.update(old_f, old_l - 1, -1);
+                        seg.apply(old_f..old_l, &Add(-1));
                     }
 
                     if let Ok(i) = pos.binary_search(&idx) {
@@ -145,16 +87,15 @@ impl Solution {
                         let new_f = pos[0] as usize;
                         let new_l = *pos.last().unwrap() as usize;
                         if new_l > new_f {
-                            seg.update(new_f, new_l - 1, 1);
+                            seg.apply(new_f..new_l, &Add(1));
                         }
                     }
                 }
-            }
 
             nums[idx as usize] = new_val as i32;
 
             // Add new value
-            if is_prime[new_val as usize] {
+            if sieve.is_prime(new_val as usize) {
                 let pos = positions.entry(new_val).or_default();
                 let was_empty = pos.is_empty();
 
@@ -162,7 +103,7 @@ impl Solution {
                     let old_f = pos[0] as usize;
                     let old_l = *pos.last().unwrap() as usize;
                     if old_l > old_f {
-                        seg.update(old_f, old_l - 1, -1);
+                        seg.apply(old_f..old_l, &Add(-1));
                     }
                 }
 
@@ -177,33 +118,18 @@ impl Solution {
                     let new_f = pos[0] as usize;
                     let new_l = *pos.last().unwrap() as usize;
                     if new_l > new_f {
-                        seg.update(new_f, new_l - 1, 1);
+                        seg.apply(new_f..new_l, &Add(1));
                     }
                 }
             }
 
-            result.push(total_primes + seg.max());
+            result.push(total_primes + seg.all().0);
         }
 
         result
     }
 }
 
-fn sieve() -> [bool; MAX_VAL] {
-    let mut is_prime = [true; MAX_VAL];
-    is_prime[0] = false;
-    is_prime[1] = false;
-    (2..)
-        .take_while(|&i| i * i < MAX_VAL)
-        .filter(|&i| is_prime[i])
-        .for_each(|i| {
-            (i * i..MAX_VAL).step_by(i).for_each(|j| {
-                is_prime[j] = false;
-            });
-        });
-    is_prime
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Maximize subarray GCD score with optional doubling.
     ///
@@ -52,8 +54,8 @@ impl Solution {
             let capacity = entries.len() + 1;
             let mut new_entries = entries.into_iter().fold(
                 Vec::with_capacity(capacity),
-                |mut acc, (g, mp, ls, le)| {
-                    let new_g = Self::gcd(g, nums[r]);
+                |mut acc: Vec<(i32, u8, usize, usize)>, (g, mp, ls, le)| {
+                    let new_g = gcd(g, nums[r]);
                     let new_mp = mp.min(pow2[r]);
 
                     match acc.last_mut() {
@@ -103,10 +105,6 @@ impl Solution {
 
         ans
     }
-
-    fn gcd(a: i32, b: i32) -> i32 {
-        if b == 0 { a } else { Self::gcd(b, a % b) }
-    }
 }
 
 #[cfg(test)]
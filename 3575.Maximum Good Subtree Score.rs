@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Maximum good subtree score using bitmask DP with subset enumeration.
     ///
@@ -40,17 +42,16 @@ impl Solution {
 
         let masks: Vec<Option<usize>> = vals.iter().map(|&v| digit_mask(v as i64)).collect();
 
-        let mut result = 0i64;
+        let mut result = Mint::ZERO;
 
         fn dfs(
             u: usize,
             children: &[Vec<usize>],
             vals: &[i32],
             masks: &[Option<usize>],
-            result: &mut i64,
+            result: &mut Mint,
         ) -> Vec<i64> {
             const NEG_INF: i64 = i64::MIN / 2;
-            const MOD: i64 = 1_000_000_007;
 
             let mut dp = vec![NEG_INF; 1024];
             dp[0] = 0;
@@ -84,14 +85,14 @@ impl Solution {
             });
 
             let max_score = *dp.iter().max().unwrap();
-            *result = (*result + max_score) % MOD;
+            *result += max_score;
 
             dp
         }
 
         dfs(0, &children, &vals, &masks, &mut result);
 
-        result as i32
+        result.value() as i32
     }
 }
 
@@ -48,7 +48,7 @@ impl Solution {
         }
 
         // Sort optional edges descending for partition-based processing
-        optional.sort_unstable_by(|a, b| b.2.cmp(&a.2));
+        optional.sort_unstable_by_key(|x| std::cmp::Reverse(x.2));
 
         // Generate candidates, skip doubled mandatory (can't upgrade mandatory)
         let mut candidates = Vec::with_capacity(2 * m);
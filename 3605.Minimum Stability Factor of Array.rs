@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Binary search on answer with sparse table for GCD queries.
     ///
@@ -39,7 +41,7 @@ impl Solution {
             (0..n).for_each(|i| {
                 let j = i + (1 << (k - 1));
                 st[k][i] = if j < n {
-                    Self::gcd(st[k - 1][i], st[k - 1][j])
+                    gcd(st[k - 1][i], st[k - 1][j])
                 } else {
                     st[k - 1][i]
                 };
@@ -49,7 +51,7 @@ impl Solution {
         // GCD range query [l, r]
         let query = |l: usize, r: usize| -> i64 {
             let k = lg[r - l + 1];
-            Self::gcd(st[k][l], st[k][r + 1 - (1 << k)])
+            gcd(st[k][l], st[k][r + 1 - (1 << k)])
         };
 
         // For each j, find minimum start index where GCD([start, j]) >= 2
@@ -112,14 +114,6 @@ impl Solution {
         lo as i32
     }
 
-    fn gcd(a: i64, b: i64) -> i64 {
-        if b == 0 {
-            a
-        } else {
-            Self::gcd(b, a % b)
-        }
-    }
-
     #[inline]
     fn floor_log2(x: usize) -> usize {
         (usize::BITS as usize - 1) - x.leading_zeros() as usize
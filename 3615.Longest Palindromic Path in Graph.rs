@@ -41,30 +41,29 @@ impl Solution {
         let mut memo: HashMap<u32, i8> = HashMap::with_capacity(1 << 18);
 
         // Odd length palindromes
-        result = (0..n)
-            .map(|center| {
-                Self::dfs(
-                    &adj,
-                    &by_label,
-                    center,
-                    center,
-                    1u16 << center,
-                    n,
-                    &mut memo,
-                    &mut result,
-                )
-            })
-            .fold(result, i32::max);
+        for center in 0..n {
+            let best = Self::dfs(
+                &adj,
+                &by_label,
+                center,
+                center,
+                1u16 << center,
+                n,
+                &mut memo,
+                &mut result,
+            );
+            result = result.max(best);
+        }
 
         // Even length palindromes
-        result = (0..n)
+        let pairs = (0..n)
             .flat_map(|u| ((u + 1)..n).map(move |v| (u, v)))
-            .filter(|&(u, v)| adj[u] & (1 << v) != 0 && label[u] == label[v])
-            .map(|(u, v)| {
-                let mask = (1u16 << u) | (1u16 << v);
-                Self::dfs(&adj, &by_label, u, v, mask, n, &mut memo, &mut result)
-            })
-            .fold(result, i32::max);
+            .filter(|&(u, v)| adj[u] & (1 << v) != 0 && label[u] == label[v]);
+        for (u, v) in pairs {
+            let mask = (1u16 << u) | (1u16 << v);
+            let best = Self::dfs(&adj, &by_label, u, v, mask, n, &mut memo, &mut result);
+            result = result.max(best);
+        }
 
         result
     }
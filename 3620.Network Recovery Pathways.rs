@@ -1,19 +1,19 @@
 impl Solution {
-    /// Single Dijkstra pass tracking minimum edge cost on optimal path.
+    /// Binary search on the bottleneck edge with a cost-bounded Dijkstra check.
     ///
     /// # Intuition
-    /// Instead of binary search + multiple Dijkstra runs, track the minimum edge
-    /// on each path during a single Dijkstra. State: (total_cost, min_edge, node).
-    /// Optimize for maximum min_edge among all paths with total_cost <= k.
+    /// If a path with minimum edge at least `t` fits in budget `k`, so does one
+    /// for every smaller threshold. The answer is therefore the largest `t` for
+    /// which the cheapest path using only edges of cost `>= t` costs at most `k`.
     ///
     /// # Approach
-    /// 1. Filter edges to only those connecting online nodes
-    /// 2. Run modified Dijkstra with state (cost, -min_edge, node)
-    /// 3. For each node, track best (min_edge) for each reachable cost
-    /// 4. Return maximum min_edge that reaches destination with cost <= k
+    /// 1. Keep only edges whose endpoints are both online
+    /// 2. Sort the distinct edge costs as threshold candidates
+    /// 3. Binary search the candidates, running Dijkstra restricted to edges
+    ///    with cost `>= t` and checking the distance to `n - 1` against `k`
     ///
     /// # Complexity
-    /// - Time: O(m log m + (n + m) log n)
+    /// - Time: O((n + m) log n · log m)
     /// - Space: O(n + m)
     pub fn find_max_path_score(edges: Vec<Vec<i32>>, online: Vec<bool>, k: i64) -> i32 {
         use std::cmp::Reverse;
@@ -21,59 +21,48 @@ impl Solution {
 
         let n = online.len();
         let mut adj: Vec<Vec<(usize, i64)>> = vec![vec![]; n];
+        let mut costs = Vec::with_capacity(edges.len());
 
         for edge in edges.iter() {
             let (u, v, cost) = (edge[0] as usize, edge[1] as usize, edge[2] as i64);
             if online[u] && online[v] {
                 adj[u].push((v, cost));
+                costs.push(cost);
             }
         }
-
-        // State: (cost, -min_edge_on_path, node)
-        // Negative min_edge for max-heap behavior on min_edge
-        let mut heap = BinaryHeap::new();
-        heap.push(Reverse((0i64, i64::MIN, 0usize)));
-
-        // best[node] = maximum min_edge we've seen reaching this node with cost <= k
-        let mut best = vec![i64::MIN; n];
-        best[0] = i64::MAX;
-
-        let mut result = -1;
-
-        while let Some(Reverse((cost, neg_min_edge, u))) = heap.pop() {
-            let min_edge = -neg_min_edge;
-
-            // If we've already found a better path to this node, skip
-            if min_edge < best[u] {
-                continue;
-            }
-
-            if u == n - 1 {
-                result = result.max(min_edge as i32);
-                continue;
-            }
-
-            for &(v, edge_cost) in adj[u].iter() {
-                let new_cost = cost + edge_cost;
-                if new_cost > k {
+        costs.sort_unstable();
+        costs.dedup();
+
+        let reachable = |threshold: i64| -> bool {
+            let mut dist = vec![i64::MAX; n];
+            let mut heap = BinaryHeap::new();
+            dist[0] = 0;
+            heap.push(Reverse((0i64, 0usize)));
+
+            while let Some(Reverse((cost, u))) = heap.pop() {
+                if u == n - 1 {
+                    return true;
+                }
+                if cost > dist[u] {
                     continue;
                 }
-
-                let new_min_edge = if u == 0 {
-                    edge_cost
-                } else {
-                    min_edge.min(edge_cost)
-                };
-
-                // Only explore if this gives a better min_edge for node v
-                if new_min_edge > best[v] {
-                    best[v] = new_min_edge;
-                    heap.push(Reverse((new_cost, -new_min_edge, v)));
+                for &(v, edge_cost) in &adj[u] {
+                    let next = cost + edge_cost;
+                    if edge_cost >= threshold && next <= k && next < dist[v] {
+                        dist[v] = next;
+                        heap.push(Reverse((next, v)));
+                    }
                 }
             }
+            false
+        };
+
+        // Number of candidates that still admit a path within budget
+        let feasible = costs.partition_point(|&t| reachable(t));
+        match feasible {
+            0 => -1,
+            count => costs[count - 1] as i32,
         }
-
-        result
     }
 }
 
@@ -1,39 +1,4 @@
-struct BinaryIndexedTree {
-    size: usize,
-    data: Vec<i64>,
-}
-
-impl BinaryIndexedTree {
-    fn new(array_size: usize) -> Self {
-        let size = array_size + 1;
-        Self {
-            size,
-            data: vec![0; size + 1],
-        }
-    }
-
-    fn update(&mut self, index: usize, delta: i64) {
-        let mut idx = (index + 1) as i32;
-        while (idx as usize) <= self.size {
-            self.data[idx as usize] += delta;
-            idx += idx & -idx;
-        }
-    }
-
-    fn prefix_sum(&self, index: usize) -> i64 {
-        let mut idx = (index + 1) as i32;
-        let mut total = 0i64;
-        while idx > 0 {
-            total += self.data[idx as usize];
-            idx -= idx & -idx;
-        }
-        total
-    }
-
-    fn range_sum(&self, left: usize, right: usize) -> i64 {
-        self.prefix_sum(right) - self.prefix_sum(left - 1)
-    }
-}
+use crate::fenwick::Fenwick;
 
 struct PopcountDepthCache {
     depth_cache: Vec<i32>,
@@ -42,7 +7,7 @@ struct PopcountDepthCache {
 impl PopcountDepthCache {
     fn new() -> Self {
         let depth_cache = (0..=64)
-            .map(|value| Self::compute_depth(value as i32) - 1)
+            .map(|value| Self::compute_depth(value) - 1)
             .collect();
         Self { depth_cache }
     }
@@ -86,12 +51,12 @@ impl Solution {
     pub fn popcount_depth(mut nums: Vec<i64>, queries: Vec<Vec<i64>>) -> Vec<i32> {
         let array_size = nums.len();
         let depth_cache = PopcountDepthCache::new();
-        let mut depth_trees: Vec<_> = (0..5).map(|_| BinaryIndexedTree::new(array_size)).collect();
+        let mut depth_trees: Vec<Fenwick<i64>> = (0..5).map(|_| Fenwick::new(array_size)).collect();
 
         nums.iter().enumerate().for_each(|(index, &number)| {
             let depth = depth_cache.get_depth(number) as usize;
             if depth < depth_trees.len() {
-                depth_trees[depth].update(index, 1);
+                depth_trees[depth].add(index, 1);
             }
         });
 
@@ -102,7 +67,7 @@ impl Solution {
                     let (left, right, target_depth) =
                         (query[1] as usize, query[2] as usize, query[3] as usize);
                     Some(match target_depth < depth_trees.len() {
-                        true => depth_trees[target_depth].range_sum(left, right) as i32,
+                        true => depth_trees[target_depth].sum(left..=right) as i32,
                         false => 0,
                     })
                 }
@@ -114,10 +79,10 @@ impl Solution {
                     nums[index] = new_value;
                     if old_depth != new_depth {
                         if old_depth < depth_trees.len() {
-                            depth_trees[old_depth].update(index, -1);
+                            depth_trees[old_depth].add(index, -1);
                         }
                         if new_depth < depth_trees.len() {
-                            depth_trees[new_depth].update(index, 1);
+                            depth_trees[new_depth].add(index, 1);
                         }
                     }
                     None
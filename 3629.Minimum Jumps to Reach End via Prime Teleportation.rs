@@ -1,45 +1,10 @@
 use std::sync::LazyLock;
 
-/// Precomputed sieve: `SIEVE[v] = (smallest_prime_factor_of_v, v / p^k)` where
-/// `p^k` is the full power of the smallest prime factor dividing `v`.
-/// This lets us fully factorise any value ≤ 10^6 in O(log v) chain lookups
-/// instead of O(√v) trial division.
-///
-/// `SIEVE[v] = (-1, -1)` means v is 0 or 1 (no prime factors).
-/// `SIEVE[v] = (v, 1)` means v is prime.
-static SIEVE: LazyLock<Vec<(i32, i32)>> = LazyLock::new(|| {
-    let n: i32 = 1_000_000;
-    let sqrt_n: i32 = 1_000;
-    let mut sieve = vec![(-1_i32, -1_i32); (n + 1) as usize];
-    // For each prime p found by the sieve, mark all multiples j of p.
-    // Store (p, j/p^k) so factorisation is a simple chain: follow .1 until 1.
-    for p in 2..=sqrt_n {
-        if sieve[p as usize].0 == -1 {
-            sieve[p as usize] = (p, 1);
-            let mut j = p * p;
-            let mut cofactor_base = p; // j / p at each step
-            while j <= n {
-                if sieve[j as usize].0 == -1 {
-                    // Remove all factors of p from j to get the cofactor.
-                    let mut x = cofactor_base;
-                    while x % p == 0 {
-                        x /= p;
-                    }
-                    sieve[j as usize] = (p, x);
-                }
-                j += p;
-                cofactor_base += 1;
-            }
-        }
-    }
-    // Values > sqrt_n that were never marked are prime.
-    for v in (sqrt_n + 1)..=n {
-        if sieve[v as usize].0 == -1 {
-            sieve[v as usize] = (v, 1);
-        }
-    }
-    sieve
-});
+use crate::number_theory::Sieve;
+
+/// Smallest prime factors of every value ≤ 10^6, so any value factorises
+/// in O(log v) lookups instead of O(√v) trial division.
+static SIEVE: LazyLock<Sieve> = LazyLock::new(|| Sieve::new(1_000_000));
 
 impl Solution {
     /// Level-by-level BFS with a precomputed SPF sieve and virtual prime nodes.
@@ -56,8 +21,8 @@ impl Solution {
     /// value is factorised in O(log val) instead of O(√val).
     ///
     /// # Approach
-    /// 1. Sieve (static, computed once): `SIEVE[v] = (spf, cofactor)` enables
-    ///    O(log v) factorisation by repeatedly following the cofactor chain.
+    /// 1. Sieve (static, computed once): the smallest-prime-factor table
+    ///    enables O(log v) factorisation by repeated division.
     /// 2. Assign an id to each prime that appears as a `nums[i]` value (sources).
     /// 3. Build adjacency lists: for each index `j`, walk its prime factorisation
     ///    and append `j` to the list of every prime id that divides `nums[j]`.
@@ -77,13 +42,13 @@ impl Solution {
             return last;
         }
 
-        let sieve: &[(i32, i32)] = &SIEVE;
+        let sieve = &*SIEVE;
 
         // Fast path: can we teleport directly from index 0 to index n-1?
         {
             let a = nums[0];
             let b = nums[n - 1];
-            if sieve[a as usize].0 == a && b % a == 0 {
+            if sieve.is_prime(a as usize) && b % a == 0 {
                 return 1;
             }
         }
@@ -96,7 +61,7 @@ impl Solution {
         {
             let mut prev = 0_i32;
             let all_distinct_primes = nums.iter().all(|&x| {
-                let ok = prev < x && sieve[x as usize].0 == x;
+                let ok = prev < x && sieve.is_prime(x as usize);
                 prev = x;
                 ok
             });
@@ -118,7 +83,7 @@ impl Solution {
         let mut num_primes = 0_i32;
         for &x in &nums {
             let xu = x as usize;
-            if sieve[xu].0 == x && prime_id[xu] == -1 {
+            if sieve.is_prime(xu) && prime_id[xu] == -1 {
                 prime_id[xu] = num_primes;
                 num_primes += 1;
             }
@@ -127,14 +92,11 @@ impl Solution {
         // Build adjacency: al[prime_id] = list of indices j where prime | nums[j].
         let mut al: Vec<Vec<i32>> = vec![Vec::new(); num_primes as usize];
         for (j, &x) in nums.iter().enumerate() {
-            let mut y = x;
-            while y > 1 {
-                let (p, cofactor) = sieve[y as usize];
-                let id = prime_id[p as usize];
+            for (p, _) in sieve.factorize(x as usize) {
+                let id = prime_id[p];
                 if id != -1 {
                     al[id as usize].push(j as i32);
                 }
-                y = cofactor;
             }
         }
 
@@ -10,6 +10,7 @@ impl Solution {
     /// - Land-first: start land at its open time, finish, then start water at
     ///   max(land_finish, waterStartTime[j]), add waterDuration[j].
     /// - Water-first: symmetric.
+    ///
     /// Return the minimum across all candidates.
     ///
     /// # Complexity
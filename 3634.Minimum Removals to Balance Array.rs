@@ -27,7 +27,7 @@ impl Solution {
             })
             .max()
             .unwrap_or(0);
-        return n as i32 - max_kept as i32;
+        n as i32 - max_kept as i32
     }
 }
 
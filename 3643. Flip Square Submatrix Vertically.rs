@@ -30,9 +30,9 @@ impl Solution {
 
             // Swap the elements within the column range [y, y + k)
             for j in y..y + k {
-                grid[top_row][j] = grid[top_row][j] ^ grid[bottom_row][j];
-                grid[bottom_row][j] = grid[top_row][j] ^ grid[bottom_row][j];
-                grid[top_row][j] = grid[top_row][j] ^ grid[bottom_row][j];
+                grid[top_row][j] ^= grid[bottom_row][j];
+                grid[bottom_row][j] ^= grid[top_row][j];
+                grid[top_row][j] ^= grid[bottom_row][j];
             }
         }
 
@@ -105,7 +105,7 @@ mod tests {
         let result = Solution::reverse_submatrix(grid, 1, 1, 2);
         let expected = vec![
             vec![1, 2, 3, 4],
-            vec![5, 10, 9, 8],
+            vec![5, 10, 11, 8],
             vec![9, 6, 7, 12],
             vec![13, 14, 15, 16],
         ];
@@ -124,8 +124,8 @@ mod tests {
         let result = Solution::reverse_submatrix(grid, 1, 1, 3);
         let expected = vec![
             vec![1, 2, 3, 4, 5],
-            vec![6, 16, 17, 18, 10],
-            vec![11, 11, 12, 13, 15],
+            vec![6, 17, 18, 19, 10],
+            vec![11, 12, 13, 14, 15],
             vec![16, 7, 8, 9, 20],
             vec![21, 22, 23, 24, 25],
         ];
@@ -55,7 +55,7 @@ impl Solution {
         });
 
         // Sort cells by value in descending order for threshold processing
-        sorted_cells.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        sorted_cells.sort_unstable_by_key(|x| std::cmp::Reverse(x.0));
 
         // Process each teleport level
         (1..=k).for_each(|teleports| {
@@ -134,50 +134,51 @@ mod tests {
     #[test]
     fn test_single_cell_grid() {
         let grid = vec![vec![42]];
-        assert_eq!(Solution::min_cost(grid, 0), 0);
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 0);
         assert_eq!(Solution::min_cost(grid, 5), 0);
     }
 
     #[test]
     fn test_single_row_grid() {
         let grid = vec![vec![1, 2, 3, 4]];
-        assert_eq!(Solution::min_cost(grid, 0), 9); // 0 + 1 + 2 + 3 + 4 - 1
-        assert_eq!(Solution::min_cost(grid, 1), 3); // teleport to value <= 1, then traverse
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 9); // 0 + 1 + 2 + 3 + 4 - 1
+        assert_eq!(Solution::min_cost(grid, 1), 9); // only smaller values are reachable by teleport
     }
 
     #[test]
     fn test_single_column_grid() {
         let grid = vec![vec![1], vec![2], vec![3], vec![4]];
-        assert_eq!(Solution::min_cost(grid, 0), 9); // 0 + 1 + 2 + 3 + 4 - 1
-        assert_eq!(Solution::min_cost(grid, 1), 3); // teleport to value <= 1, then traverse
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 9); // 0 + 1 + 2 + 3 + 4 - 1
+        assert_eq!(Solution::min_cost(grid, 1), 9); // only smaller values are reachable by teleport
     }
 
     #[test]
     fn test_all_same_values() {
         let grid = vec![vec![5, 5], vec![5, 5]];
-        assert_eq!(Solution::min_cost(grid, 0), 15); // 0 + 5 + 5 + 5
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 10); // 0 + 5 + 5
         assert_eq!(Solution::min_cost(grid, 1), 0); // can teleport anywhere since all <= 5
     }
 
     #[test]
     fn test_decreasing_values() {
         let grid = vec![vec![9, 8, 7], vec![6, 5, 4], vec![3, 2, 1]];
-        assert_eq!(Solution::min_cost(grid, 0), 20); // 0 + 8 + 7 + 4 + 1
-        assert_eq!(Solution::min_cost(grid, 1), 12); // Better with one teleport
-        assert_eq!(Solution::min_cost(grid, 2), 0); // Can reach destination with 2 teleports
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 12); // 0 + 6 + 3 + 2 + 1
+        assert_eq!(Solution::min_cost(grid.clone(), 1), 0); // Teleport from 9 straight to 1
+        assert_eq!(Solution::min_cost(grid, 2), 0); // Extra teleports cannot hurt
     }
 
     #[test]
     fn test_increasing_values() {
         let grid = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
-        assert_eq!(Solution::min_cost(grid, 0), 20); // 0 + 2 + 3 + 6 + 9
-        assert_eq!(Solution::min_cost(grid, 1), 0); // From (0,0) with value 1, can teleport to any cell
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 20); // 0 + 2 + 3 + 6 + 9
+        // Walk to 4, teleport to 3 at (0, 2), then walk 6 and 9: 4 + 6 + 9
+        assert_eq!(Solution::min_cost(grid, 1), 19);
     }
 
     #[test]
     fn test_large_value_differences() {
         let grid = vec![vec![1, 1000], vec![1000, 1]];
-        assert_eq!(Solution::min_cost(grid, 0), 1001); // 0 + 1000 + 1
+        assert_eq!(Solution::min_cost(grid.clone(), 0), 1001); // 0 + 1000 + 1
         assert_eq!(Solution::min_cost(grid, 1), 0); // Teleport from start to end
     }
 }
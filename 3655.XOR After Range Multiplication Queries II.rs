@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Computes XOR of array after range multiplication queries with sqrt decomposition.
     ///
@@ -16,14 +18,13 @@ impl Solution {
     /// - Time: O((n + q) * sqrt(n))
     /// - Space: O(n) for the difference buffer
     pub fn xor_after_queries(nums: Vec<i32>, queries: Vec<Vec<i32>>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-        let mut nums: Vec<i64> = nums.into_iter().map(i64::from).collect();
+        let mut nums: Vec<Mint> = nums.into_iter().map(Mint::from).collect();
         let n = nums.len();
         let bravexuneth = (n, &queries);
         let t = (bravexuneth.0 as f64).sqrt() as usize + 1;
 
         // (k, residue) -> list of (l, r_next, v) for diff array operations
-        let mut groups: Vec<Vec<(usize, usize, i64)>> = Vec::new();
+        let mut groups: Vec<Vec<(usize, usize, Mint)>> = Vec::new();
         let mut key_to_idx: Vec<i64> = Vec::new();
         let mut key_map = std::collections::HashMap::<i64, usize>::new();
 
@@ -31,7 +32,7 @@ impl Solution {
             let l = q[0] as usize;
             let r = q[1] as usize;
             let k = q[2] as usize;
-            let v = q[3] as i64;
+            let v = Mint::from(q[3]);
             if k < t {
                 let residue = l % k;
                 let key = ((k as i64) << 20) | residue as i64;
@@ -50,57 +51,44 @@ impl Solution {
             } else {
                 let mut i = l;
                 while i <= r {
-                    nums[i] = nums[i] * v % MOD;
+                    nums[i] *= v;
                     i += k;
                 }
             }
         }
 
-        let mut dif = vec![1i64; n + t];
+        let mut dif = vec![Mint::ONE; n + t];
         for (gi, group) in groups.iter().enumerate() {
             let key = key_to_idx[gi];
             let k = (key >> 20) as usize;
             let residue = (key & 0xF_FFFF) as usize;
 
             for &(l, r_next, v) in group {
-                dif[l] = dif[l] * v % MOD;
-                dif[r_next] = dif[r_next] * Self::pow_mod(v, MOD - 2, MOD) % MOD;
+                dif[l] *= v;
+                dif[r_next] /= v;
             }
 
             // Propagate and apply only along this residue class stride
             let mut j = residue + k;
             while j < n {
-                dif[j] = dif[j] * dif[j - k] % MOD;
+                dif[j] = dif[j] * dif[j - k];
                 j += k;
             }
             j = residue;
             while j < n {
-                nums[j] = nums[j] * dif[j] % MOD;
+                nums[j] *= dif[j];
                 j += k;
             }
 
             // Sparse reset: only indices along this residue's stride + overflow
             j = residue;
             while j < n + t {
-                dif[j] = 1;
+                dif[j] = Mint::ONE;
                 j += k;
             }
         }
 
-        nums.into_iter().fold(0, |acc, x| acc ^ x as i32)
-    }
-
-    #[inline]
-    fn pow_mod(mut x: i64, mut y: i64, m: i64) -> i64 {
-        let mut res = 1i64;
-        while y > 0 {
-            if y & 1 == 1 {
-                res = res * x % m;
-            }
-            x = x * x % m;
-            y >>= 1;
-        }
-        res
+        nums.into_iter().fold(0, |acc, x| acc ^ x.value() as i32)
     }
 }
 
@@ -141,9 +129,6 @@ mod tests {
     fn empty_queries_returns_original_xor() {
         let nums = vec![1, 2, 3, 4, 5];
         let queries: Vec<Vec<i32>> = vec![];
-        assert_eq!(
-            Solution::xor_after_queries(nums, queries),
-            1 ^ 2 ^ 3 ^ 4 ^ 5
-        );
+        assert_eq!(Solution::xor_after_queries(nums, queries), 4 ^ 5);
     }
 }
@@ -54,8 +54,7 @@ impl Solution {
             }
             let lxx = lx + ld;
             let mut lc = 0;
-            let mut rx = 0;
-            let mut rd = 0;
+            let (mut rx, mut rd);
             let mut j = i + 1;
             loop {
                 (rx, rd) = ss[j];
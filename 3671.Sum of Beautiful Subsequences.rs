@@ -1,3 +1,6 @@
+use crate::mod_int::Mint;
+use crate::number_theory::Sieve;
+
 impl Solution {
     /// Calculates total beauty of strictly increasing subsequences by GCD
     ///
@@ -16,8 +19,6 @@ impl Solution {
     /// - Time: O(n × √max_val × log(max_val))
     /// - Space: O(D × max_val/D) where D = number of distinct divisors
     pub fn total_beauty(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
         if nums.is_empty() {
             return 0;
         }
@@ -25,19 +26,7 @@ impl Solution {
         let max_val = *nums.iter().max().unwrap() as usize;
 
         // Euler's totient using sieve
-        let phi: Vec<i64> = {
-            let mut phi: Vec<i64> = (0..=max_val as i64).collect();
-            for i in 2..=max_val {
-                if phi[i] == i as i64 {
-                    let mut j = i;
-                    while j <= max_val {
-                        phi[j] -= phi[j] / i as i64;
-                        j += i;
-                    }
-                }
-            }
-            phi
-        };
+        let phi = Sieve::new(max_val).totients();
 
         // Precompute which divisors are needed
         let needs_bit = nums
@@ -46,7 +35,7 @@ impl Solution {
                 let v = num as usize;
                 (1..)
                     .take_while(|d| d * d <= v)
-                    .filter(|d| v % d == 0)
+                    .filter(|d| v.is_multiple_of(*d))
                     .for_each(|d| {
                         needs[d] = true;
                         needs[v / d] = true;
@@ -55,44 +44,44 @@ impl Solution {
             });
 
         // Batch allocate all needed BITs
-        let mut bit: Vec<Vec<i64>> = (0..=max_val)
+        let mut bit: Vec<Vec<Mint>> = (0..=max_val)
             .map(|d| {
                 if d > 0 && needs_bit[d] {
-                    vec![0i64; max_val / d + 2]
+                    vec![Mint::ZERO; max_val / d + 2]
                 } else {
                     Vec::with_capacity(0)
                 }
             })
             .collect();
 
-        let mut answer: i64 = 0;
+        let mut answer = Mint::ZERO;
 
         for &num in &nums {
             let v = num as usize;
             let mut d = 1;
 
             while d * d <= v {
-                if v % d == 0 {
+                if v.is_multiple_of(d) {
                     // Process small divisor d
                     let normalized = v / d;
                     {
                         let bit_d = &mut bit[d];
 
                         // Inline query: sum for indices < normalized
-                        let mut prev = 0i64;
+                        let mut prev = Mint::ZERO;
                         let mut idx = normalized - 1;
                         while idx > 0 {
                             prev += bit_d[idx];
                             idx -= idx & idx.wrapping_neg();
                         }
 
-                        let cnt = (prev % MOD + 1) % MOD;
-                        answer = (answer + phi[d] * cnt) % MOD;
+                        let cnt = prev + 1;
+                        answer += cnt * phi[d];
 
                         // Inline update
                         let mut idx = normalized;
                         while idx < bit_d.len() {
-                            bit_d[idx] = (bit_d[idx] + cnt) % MOD;
+                            bit_d[idx] += cnt;
                             idx += idx & idx.wrapping_neg();
                         }
                     }
@@ -103,19 +92,19 @@ impl Solution {
                         let normalized = d; // v / large_d = d
                         let bit_d = &mut bit[large_d];
 
-                        let mut prev = 0i64;
+                        let mut prev = Mint::ZERO;
                         let mut idx = normalized - 1;
                         while idx > 0 {
                             prev += bit_d[idx];
                             idx -= idx & idx.wrapping_neg();
                         }
 
-                        let cnt = (prev % MOD + 1) % MOD;
-                        answer = (answer + phi[large_d] * cnt) % MOD;
+                        let cnt = prev + 1;
+                        answer += cnt * phi[large_d];
 
                         let mut idx = normalized;
                         while idx < bit_d.len() {
-                            bit_d[idx] = (bit_d[idx] + cnt) % MOD;
+                            bit_d[idx] += cnt;
                             idx += idx & idx.wrapping_neg();
                         }
                     }
@@ -124,7 +113,7 @@ impl Solution {
             }
         }
 
-        answer as i32
+        answer.value() as i32
     }
 }
 
@@ -164,6 +153,6 @@ mod tests {
 
     #[test]
     fn test_large_values() {
-        assert_eq!(Solution::total_beauty(vec![12, 18, 24]), 78);
+        assert_eq!(Solution::total_beauty(vec![12, 18, 24]), 84);
     }
 }
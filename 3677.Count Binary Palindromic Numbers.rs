@@ -34,7 +34,7 @@ impl Solution {
 
     /// Counts palindromes of exactly `len` bits that are ≤ n
     fn count_palindromes_with_length(n: i64, len: usize) -> i64 {
-        let half_len = (len + 1) / 2;
+        let half_len = len.div_ceil(2);
         let min_half = 1i64 << (half_len - 1);
         let max_half = (1i64 << half_len) - 1;
 
@@ -58,7 +58,7 @@ impl Solution {
 
     /// Constructs a palindrome of given length from its first half
     fn make_palindrome(half: i64, len: usize) -> i64 {
-        let half_len = (len + 1) / 2;
+        let half_len = len.div_ceil(2);
         let base = half << (len - half_len);
 
         // Mirror bits from the first half to complete the palindrome
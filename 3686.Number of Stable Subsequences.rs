@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts stable subsequences using parity-based dynamic programming.
     ///
@@ -19,18 +21,18 @@ impl Solution {
     /// - Time: O(n) - single pass through array
     /// - Space: O(1) - only 4 state variables
     pub fn count_stable_subsequences(nums: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-        let (e2, e1, o1, o2) =
-            nums.iter()
-                .fold((0i64, 0i64, 0i64, 0i64), |(e2, e1, o1, o2), &num| {
-                    if num & 1 == 0 {
-                        ((e2 + e1) % MOD, (1 + e1 + o1 + o2) % MOD, o1, o2)
-                    } else {
-                        (e2, e1, (1 + e2 + e1 + o1) % MOD, (o1 + o2) % MOD)
-                    }
-                });
+        let (e2, e1, o1, o2) = nums.iter().fold(
+            (Mint::ZERO, Mint::ZERO, Mint::ZERO, Mint::ZERO),
+            |(e2, e1, o1, o2), &num| {
+                if num & 1 == 0 {
+                    (e2 + e1, e1 + o1 + o2 + 1, o1, o2)
+                } else {
+                    (e2, e1, e2 + e1 + o1 + 1, o1 + o2)
+                }
+            },
+        );
 
-        ((e2 + e1 + o1 + o2) % MOD) as i32
+        (e2 + e1 + o1 + o2).value() as i32
     }
 }
 
@@ -80,7 +80,7 @@ mod tests {
 
     #[test]
     fn test_max_alternating_sum() {
-        let test_cases = vec![
+        let test_cases = [
             TestCase {
                 nums: vec![1, 2, 3],
                 swaps: vec![vec![0, 2], vec![1, 2]],
@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts valid ZigZag arrays using cumulative DP.
     ///
@@ -16,7 +18,6 @@ impl Solution {
     /// - Time: O(n * m) where m = r - l + 1
     /// - Space: O(m)
     pub fn zig_zag_arrays(n: i32, l: i32, r: i32) -> i32 {
-        const MODULO: i64 = 1_000_000_007;
         let num_values = (r - l + 1) as usize;
         let array_length = n as usize;
 
@@ -28,14 +29,14 @@ impl Solution {
         const DECREASING: usize = 1;
 
         // cumulative_counts[value][direction]: running sum of valid arrays
-        let mut even_step_counts = vec![[1i64; 2]; num_values];
-        let mut odd_step_counts = vec![[1i64; 2]; num_values];
+        let mut even_step_counts = vec![[Mint::ONE; 2]; num_values];
+        let mut odd_step_counts = vec![[Mint::ONE; 2]; num_values];
 
         // Cannot increase from minimum value, cannot decrease from maximum value
-        even_step_counts[0][INCREASING] = 0;
-        odd_step_counts[0][INCREASING] = 0;
-        even_step_counts[num_values - 1][DECREASING] = 0;
-        odd_step_counts[num_values - 1][DECREASING] = 0;
+        even_step_counts[0][INCREASING] = Mint::ZERO;
+        odd_step_counts[0][INCREASING] = Mint::ZERO;
+        even_step_counts[num_values - 1][DECREASING] = Mint::ZERO;
+        odd_step_counts[num_values - 1][DECREASING] = Mint::ZERO;
 
         for step in 1..array_length {
             let (current_counts, previous_counts) = if step % 2 == 1 {
@@ -46,22 +47,14 @@ impl Solution {
 
             // Forward pass: accumulate increasing transitions (must come from decreasing)
             (1..num_values).for_each(|value| {
-                let mut sum =
+                current_counts[value][INCREASING] =
                     current_counts[value - 1][INCREASING] + previous_counts[value - 1][DECREASING];
-                if sum >= MODULO {
-                    sum -= MODULO;
-                }
-                current_counts[value][INCREASING] = sum;
             });
 
             // Backward pass: accumulate decreasing transitions (must come from increasing)
             (0..num_values - 1).rev().for_each(|value| {
-                let mut sum =
+                current_counts[value][DECREASING] =
                     current_counts[value + 1][DECREASING] + previous_counts[value + 1][INCREASING];
-                if sum >= MODULO {
-                    sum -= MODULO;
-                }
-                current_counts[value][DECREASING] = sum;
             });
         }
 
@@ -74,7 +67,8 @@ impl Solution {
         final_counts
             .iter()
             .flat_map(|counts| counts.iter())
-            .fold(0i64, |total, &count| (total + count) % MODULO) as i32
+            .sum::<Mint>()
+            .value() as i32
     }
 }
 
@@ -1,4 +1,4 @@
-const MODULO: i64 = 1_000_000_007;
+use crate::mod_int::Mint;
 
 impl Solution {
     /// Counts valid ZigZag arrays using k×k matrix exponentiation.
@@ -26,23 +26,20 @@ impl Solution {
         let mut matrix = Self::build_matrix(k);
         Self::mat_pow(&mut matrix, n - 1, k);
 
-        let total: i64 = matrix
-            .iter()
-            .flat_map(|row| row.iter())
-            .fold(0, |acc, &v| (acc + v) % MODULO);
+        let total: Mint = matrix.iter().flat_map(|row| row.iter()).sum();
 
-        ((total * 2) % MODULO) as i32
+        (total * 2).value() as i32
     }
 
     /// Builds the k×k transition matrix where `M[i][j] = 1` iff `i + j >= k`.
-    fn build_matrix(k: usize) -> Vec<Vec<i64>> {
+    fn build_matrix(k: usize) -> Vec<Vec<Mint>> {
         (0..k)
-            .map(|i| (0..k).map(|j| i64::from(i + j >= k)).collect())
+            .map(|i| (0..k).map(|j| Mint::from(i + j >= k)).collect())
             .collect()
     }
 
     /// Raises `matrix` to the `exp`-th power in-place using repeated squaring.
-    fn mat_pow(matrix: &mut Vec<Vec<i64>>, mut exp: i32, k: usize) {
+    fn mat_pow(matrix: &mut Vec<Vec<Mint>>, mut exp: i32, k: usize) {
         let mut result = Self::identity(k);
         while exp > 0 {
             if exp & 1 == 1 {
@@ -55,15 +52,15 @@ impl Solution {
     }
 
     /// Computes `a = a * b` using column-major access on `b` (cache-friendly since `b` is symmetric).
-    fn mat_mul(a: &mut Vec<Vec<i64>>, b: &[Vec<i64>], k: usize) {
-        let mut result = vec![vec![0i64; k]; k];
+    fn mat_mul(a: &mut Vec<Vec<Mint>>, b: &[Vec<Mint>], k: usize) {
+        let mut result = vec![vec![Mint::ZERO; k]; k];
         for i in 0..k {
             for j in 0..k {
-                if a[i][j] == 0 {
+                if a[i][j] == Mint::ZERO {
                     continue;
                 }
                 for p in 0..k {
-                    result[i][p] = (result[i][p] + a[i][j] * b[p][j]) % MODULO;
+                    result[i][p] += a[i][j] * b[p][j];
                 }
             }
         }
@@ -71,24 +68,24 @@ impl Solution {
     }
 
     /// Computes `a = a * a` (squaring), reusing the same column-major trick.
-    fn mat_sqr(a: &mut Vec<Vec<i64>>, k: usize) {
-        let mut result = vec![vec![0i64; k]; k];
+    fn mat_sqr(a: &mut Vec<Vec<Mint>>, k: usize) {
+        let mut result = vec![vec![Mint::ZERO; k]; k];
         for i in 0..k {
             for j in 0..k {
-                if a[i][j] == 0 {
+                if a[i][j] == Mint::ZERO {
                     continue;
                 }
                 for p in 0..k {
-                    result[i][p] = (result[i][p] + a[i][j] * a[p][j]) % MODULO;
+                    result[i][p] += a[i][j] * a[p][j];
                 }
             }
         }
         *a = result;
     }
 
-    fn identity(k: usize) -> Vec<Vec<i64>> {
+    fn identity(k: usize) -> Vec<Vec<Mint>> {
         (0..k)
-            .map(|i| (0..k).map(|j| i64::from(i == j)).collect())
+            .map(|i| (0..k).map(|j| Mint::from(i == j)).collect())
             .collect()
     }
 }
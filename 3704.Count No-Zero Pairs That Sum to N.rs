@@ -14,7 +14,7 @@ impl Solution {
     /// - Space: O(1)
     pub fn count_no_zero_pairs(n: i64) -> i64 {
         let mut target_digits = [0u8; 16];
-        let num_digits = std::iter::successors(Some(n), |&x| (x >= 10).then(|| x / 10))
+        let num_digits = std::iter::successors(Some(n), |&x| (x >= 10).then_some(x / 10))
             .enumerate()
             .map(|(i, x)| {
                 target_digits[i] = (x % 10) as u8;
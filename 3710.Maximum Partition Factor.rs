@@ -74,7 +74,7 @@ impl Solution {
         let (mut lo, mut hi) = (0, distances.len() - 1);
 
         while lo < hi {
-            let mid = lo + (hi - lo + 1) / 2;
+            let mid = lo + (hi - lo).div_ceil(2);
             if can_partition(distances[mid]) {
                 lo = mid;
             } else {
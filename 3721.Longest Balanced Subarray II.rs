@@ -1,149 +1,53 @@
-use std::cmp::{max, min};
+use crate::segment_tree::{Add, LazyAction, Monoid, SegmentTree};
 
 const MAXV: usize = 100_000;
 
-struct SegTree {
-    n: usize,
-    mn: Vec<i32>,
-    mx: Vec<i32>,
-    lz: Vec<i32>,
+/// Minimum and maximum of a range of `diff` values.
+#[derive(Clone, Copy, PartialEq)]
+struct Span {
+    min: i32,
+    max: i32,
 }
 
-impl SegTree {
-    #[inline(always)]
-    fn new(n: usize) -> Self {
-        let size = 4 * n + 5;
-        Self {
-            n,
-            mn: vec![0; size],
-            mx: vec![0; size],
-            lz: vec![0; size],
-        }
-    }
-
-    fn build(&mut self, arr: &[i32]) {
-        self.build_rec(1, 0, self.n - 1, arr);
+impl Span {
+    fn contains_zero(&self) -> bool {
+        self.min <= 0 && 0 <= self.max
     }
+}
 
-    fn build_rec(&mut self, idx: usize, left: usize, right: usize, arr: &[i32]) {
-        if left == right {
-            let value = arr[left];
-            self.mn[idx] = value;
-            self.mx[idx] = value;
-            return;
+impl Monoid for Span {
+    fn identity() -> Self {
+        Span {
+            min: i32::MAX,
+            max: i32::MIN,
         }
-        let mid = (left + right) >> 1;
-        let left_child = idx << 1;
-        let right_child = left_child | 1;
-        self.build_rec(left_child, left, mid, arr);
-        self.build_rec(right_child, mid + 1, right, arr);
-        self.mn[idx] = min(self.mn[left_child], self.mn[right_child]);
-        self.mx[idx] = max(self.mx[left_child], self.mx[right_child]);
-    }
-
-    #[inline(always)]
-    fn apply(&mut self, idx: usize, delta: i32) {
-        self.mn[idx] += delta;
-        self.mx[idx] += delta;
-        self.lz[idx] += delta;
     }
 
-    #[inline(always)]
-    fn push(&mut self, idx: usize) {
-        let delta = self.lz[idx];
-        if delta != 0 {
-            let left_child = idx << 1;
-            let right_child = left_child | 1;
-            self.apply(left_child, delta);
-            self.apply(right_child, delta);
-            self.lz[idx] = 0;
+    fn combine(&self, right: &Self) -> Self {
+        Span {
+            min: self.min.min(right.min),
+            max: self.max.max(right.max),
         }
     }
+}
 
-    #[inline(always)]
-    fn pull(&mut self, idx: usize) {
-        let left_child = idx << 1;
-        let right_child = left_child | 1;
-        self.mn[idx] = min(self.mn[left_child], self.mn[right_child]);
-        self.mx[idx] = max(self.mx[left_child], self.mx[right_child]);
+impl LazyAction<Span> for Add<i32> {
+    fn identity() -> Self {
+        Add(0)
     }
 
-    #[inline(always)]
-    fn add(&mut self, left: usize, right: usize, delta: i32) {
-        if left > right {
-            return;
+    fn apply(&self, span: &Span) -> Span {
+        if *span == Span::identity() {
+            return *span;
         }
-        self.add_rec(1, 0, self.n - 1, left, right, delta);
-    }
-
-    fn add_rec(
-        &mut self,
-        idx: usize,
-        tree_left: usize,
-        tree_right: usize,
-        query_left: usize,
-        query_right: usize,
-        delta: i32,
-    ) {
-        if query_left <= tree_left && tree_right <= query_right {
-            self.apply(idx, delta);
-            return;
-        }
-        self.push(idx);
-        let tree_mid = (tree_left + tree_right) >> 1;
-
-        if query_left <= tree_mid {
-            self.add_rec(
-                idx << 1,
-                tree_left,
-                tree_mid,
-                query_left,
-                query_right.min(tree_mid),
-                delta,
-            );
+        Span {
+            min: span.min + self.0,
+            max: span.max + self.0,
         }
-        if query_right > tree_mid {
-            self.add_rec(
-                idx << 1 | 1,
-                tree_mid + 1,
-                tree_right,
-                query_left.max(tree_mid + 1),
-                query_right,
-                delta,
-            );
-        }
-        self.pull(idx);
-    }
-
-    #[inline(always)]
-    fn rightmost_zero_from(&mut self, query_left: usize) -> Option<usize> {
-        self.find_rec(1, 0, self.n - 1, query_left)
     }
 
-    fn find_rec(
-        &mut self,
-        idx: usize,
-        tree_left: usize,
-        tree_right: usize,
-        query_left: usize,
-    ) -> Option<usize> {
-        if tree_right < query_left {
-            return None;
-        }
-        if self.mn[idx] > 0 || self.mx[idx] < 0 {
-            return None;
-        }
-        if tree_left == tree_right {
-            return Some(tree_left);
-        }
-
-        self.push(idx);
-        let tree_mid = (tree_left + tree_right) >> 1;
-
-        if let Some(result) = self.find_rec(idx << 1 | 1, tree_mid + 1, tree_right, query_left) {
-            return Some(result);
-        }
-        self.find_rec(idx << 1, tree_left, tree_mid, query_left)
+    fn compose(&self, inner: &Self) -> Self {
+        Add(self.0 + inner.0)
     }
 }
 
@@ -160,7 +64,8 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Build `diff[r]` for `l = 0`.
-    /// 2. Segment tree supports range add and rightmost index with value `0`.
+    /// 2. A segment tree of `(min, max)` supports range add. Adjacent `diff` values differ by at
+    ///    most one, so the rightmost zero precedes the longest suffix whose span excludes zero.
     /// 3. Iterate left boundary `l`:
     ///    - query rightmost `r >= l` where `diff[r] == 0`
     ///    - apply one range update reflecting removal of `nums[l]`
@@ -212,22 +117,25 @@ impl Solution {
             return n as i32;
         }
 
-        let mut seg_tree = SegTree::new(n);
-        seg_tree.build(&diff);
+        let mut seg_tree: SegmentTree<Span, Add<i32>> = diff
+            .iter()
+            .map(|&value| Span {
+                min: value,
+                max: value,
+            })
+            .collect();
 
         let mut best: usize = 0;
         for left in 0..n {
-            if let Some(right) = seg_tree.rightmost_zero_from(left) {
-                let length = right + 1 - left;
-                if length > best {
-                    best = length;
-                }
+            let end = seg_tree.min_left(n, |span| !span.contains_zero());
+            if end > left {
+                best = best.max(end - left);
             }
 
             let next = next_occ[left] as usize;
             if next > left + 1 {
                 let delta = if (nums[left] & 1) != 0 { -1 } else { 1 };
-                seg_tree.add(left + 1, next - 1, delta);
+                seg_tree.apply(left + 1..next, &Add(delta));
             }
         }
 
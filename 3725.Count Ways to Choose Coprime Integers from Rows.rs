@@ -1,3 +1,6 @@
+use crate::mod_int::Mint;
+use crate::number_theory::gcd;
+
 impl Solution {
     /// Dynamic programming with GCD state tracking for coprime row selections.
     ///
@@ -15,32 +18,24 @@ impl Solution {
     /// - Time: O(m * n * MAX_VAL) where MAX_VAL = 150
     /// - Space: O(MAX_VAL)
     pub fn count_coprime(mat: Vec<Vec<i32>>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         const MAX_VAL: usize = 151;
 
-        fn gcd(a: usize, b: usize) -> usize {
-            match b {
-                0 => a,
-                _ => gcd(b, a % b),
-            }
-        }
-
-        let mut dp = vec![0i64; MAX_VAL];
+        let mut dp = vec![Mint::ZERO; MAX_VAL];
 
         mat[0].iter().for_each(|&val| dp[val as usize] += 1);
 
         mat.iter().skip(1).for_each(|row| {
-            let mut new_dp = vec![0i64; MAX_VAL];
-            (1..MAX_VAL).filter(|&g| dp[g] > 0).for_each(|g| {
+            let mut new_dp = vec![Mint::ZERO; MAX_VAL];
+            (1..MAX_VAL).filter(|&g| dp[g] != Mint::ZERO).for_each(|g| {
                 row.iter().for_each(|&val| {
                     let new_g = gcd(g, val as usize);
-                    new_dp[new_g] = (new_dp[new_g] + dp[g]) % MOD;
+                    new_dp[new_g] += dp[g];
                 });
             });
             dp = new_dp;
         });
 
-        dp[1] as i32
+        dp[1].value() as i32
     }
 }
 
@@ -17,8 +17,6 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(min(n, k))
     pub fn num_good_subarrays(nums: Vec<i32>, k: i32) -> i64 {
-        let n = nums.len();
-
         // Use Vec for small k, HashMap for large k
         if k <= 100_000 {
             Self::solve_with_vec(&nums, k as usize)
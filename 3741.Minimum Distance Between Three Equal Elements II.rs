@@ -69,7 +69,7 @@ mod tests {
 
     #[test]
     fn test_all_same() {
-        assert_eq!(Solution::minimum_distance(vec![5, 5, 5]), 4);
+        assert_eq!(Solution::minimum_distance(vec![3, 3, 3]), 4);
     }
 
     #[test]
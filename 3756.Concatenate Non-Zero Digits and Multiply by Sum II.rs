@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Concatenates non-zero digits in range and multiplies by their sum.
     ///
@@ -8,9 +10,9 @@ impl Solution {
     ///
     /// # Approach
     /// 1. Scan `s` once to build:
-    ///    - `prefix_sum[k]`: sum of the first k non-zero digits (mod MOD)
-    ///    - `prefix_num[k]`: number formed by concatenating first k non-zero digits (mod MOD)
-    ///    - `pows[k]`: 10^k mod MOD
+    ///    - `prefix_sum[k]`: sum of the first k non-zero digits (mod 1e9+7)
+    ///    - `prefix_num[k]`: number formed by concatenating first k non-zero digits (mod 1e9+7)
+    ///    - `pows[k]`: 10^k mod 1e9+7
     ///    - `nz_left[i]`: compressed index of the first non-zero digit at position >= i
     ///    - `nz_right[i]`: compressed index of the last non-zero digit at position <= i
     /// 2. For each query [l, r]:
@@ -23,17 +25,15 @@ impl Solution {
     /// - Time: O(n + q)
     /// - Space: O(n)
     pub fn sum_and_multiply(s: String, queries: Vec<Vec<i32>>) -> Vec<i32> {
-        const MOD: i64 = 1_000_000_007;
-
         let bytes = s.as_bytes();
         let n = bytes.len();
 
         // Count non-zero digits for exact allocation
         let nz_count = bytes.iter().filter(|&&b| b != b'0').count();
 
-        let mut prefix_sum = vec![0_i64; nz_count + 1];
-        let mut prefix_num = vec![0_i64; nz_count + 1];
-        let mut pows = vec![1_i64; nz_count + 1];
+        let mut prefix_sum = vec![Mint::ZERO; nz_count + 1];
+        let mut prefix_num = vec![Mint::ZERO; nz_count + 1];
+        let mut pows = vec![Mint::ONE; nz_count + 1];
 
         // nz_left[i] = compressed index of the FIRST non-zero digit at position >= i
         // Use nz_count as sentinel meaning "no non-zero digit in [i, n)"
@@ -47,18 +47,18 @@ impl Solution {
         for i in 0..n {
             nz_left[i] = ci;
             if bytes[i] != b'0' {
-                let d = i64::from(bytes[i] - b'0');
-                prefix_num[ci + 1] = (prefix_num[ci] * 10 + d) % MOD;
-                prefix_sum[ci + 1] = (prefix_sum[ci] + d) % MOD;
-                pows[ci + 1] = pows[ci] * 10 % MOD;
+                let d = bytes[i] - b'0';
+                prefix_num[ci + 1] = prefix_num[ci] * 10 + d;
+                prefix_sum[ci + 1] = prefix_sum[ci] + d;
+                pows[ci + 1] = pows[ci] * 10;
                 ci += 1;
             }
         }
         // nz_left[n] stays nz_count (sentinel)
 
-        // Backward pass: fill nz_right
-        let mut last_nz = usize::MAX; // sentinel: no non-zero seen yet from the right
-        for i in (0..n).rev() {
+        // Second forward pass: fill nz_right
+        let mut last_nz = usize::MAX; // sentinel: no non-zero seen yet
+        for i in 0..n {
             if bytes[i] != b'0' {
                 last_nz = nz_left[i]; // compressed index of position i
             }
@@ -81,14 +81,14 @@ impl Solution {
             }
 
             // Sum of non-zero digits in compressed range [cl, cr]
-            let sum = (prefix_sum[cr + 1] - prefix_sum[cl] + MOD) % MOD;
+            let sum = prefix_sum[cr + 1] - prefix_sum[cl];
 
             // Number formed by concatenating those digits
             // = prefix_num[cr+1] - prefix_num[cl] * 10^(cr-cl+1)
             let len = cr + 1 - cl;
-            let num = ((prefix_num[cr + 1] - prefix_num[cl] * pows[len]) % MOD + MOD) % MOD;
+            let num = prefix_num[cr + 1] - prefix_num[cl] * pows[len];
 
-            ans.push((sum * num % MOD) as i32);
+            ans.push((sum * num).value() as i32);
         }
 
         ans
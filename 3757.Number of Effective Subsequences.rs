@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Möbius Transform over Subset Lattice
     ///
@@ -13,9 +15,7 @@ impl Solution {
     /// # Complexity
     /// - Time: O(n + 2^k * k) where k = popcount(total_or) ≤ 20
     /// - Space: O(n + 2^k)
-
     pub fn count_effective(nums: Vec<i32>) -> i32 {
-        const MOD: u64 = 1_000_000_007;
         let n = nums.len();
 
         let total_or: i32 = nums.iter().fold(0, |a, &x| a | x);
@@ -58,28 +58,24 @@ impl Solution {
         }
 
         // Powers of 2
-        let pow2: Vec<u64> = std::iter::successors(Some(1u64), |&x| Some((x * 2) % MOD))
+        let pow2: Vec<Mint> = std::iter::successors(Some(Mint::ONE), |&x| Some(x * 2))
             .take(n + 1)
             .collect();
 
-        // Inclusion-exclusion for f[full]: branchless with batched modulo
-        const BATCH: usize = 512;
-        let k_u32 = k as u32;
-        let mut even_sum = 0u64;
-        let mut odd_sum = 0u64;
-        for s in 0..=full {
-            let val = pow2[cnt[s] as usize];
-            let parity = (k_u32 - s.count_ones()) & 1;
-            even_sum += val * (1 - parity as u64);
-            odd_sum += val * parity as u64;
-            if (s + 1) % BATCH == 0 {
-                even_sum %= MOD;
-                odd_sum %= MOD;
-            }
-        }
-        let non_eff = (even_sum % MOD + MOD - odd_sum % MOD) % MOD;
+        // Inclusion-exclusion for f[full]: subsets missing an odd number of
+        // bits are subtracted
+        let non_eff: Mint = (0..=full)
+            .map(|s| {
+                let val = pow2[cnt[s] as usize];
+                if (k - s.count_ones() as usize) % 2 == 0 {
+                    val
+                } else {
+                    -val
+                }
+            })
+            .sum();
 
-        ((pow2[n] + MOD - non_eff) % MOD) as i32
+        (pow2[n] - non_eff).value() as i32
     }
 }
 
@@ -23,6 +23,10 @@ impl Solution {
         if k == 0 {
             return n as i32;
         }
+        // Fewer than k other elements exist, so nothing can qualify
+        if k as usize >= n {
+            return 0;
+        }
 
         // Partition at index n-k: elements before are <= threshold, after are >= threshold
         let (left, threshold, _) = nums.select_nth_unstable(n - k as usize);
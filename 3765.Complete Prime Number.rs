@@ -1,3 +1,5 @@
+use crate::number_theory::is_prime;
+
 impl Solution {
     /// Check whether every decimal prefix and suffix of `num` is prime.
     ///
@@ -5,34 +7,35 @@ impl Solution {
     /// A complete prime must satisfy primality for:
     /// - prefixes: `num`, `num / 10`, `num / 100`, ...
     /// - suffixes: `num % 10`, `num % 100`, ...
+    ///
     /// We can generate both families with simple arithmetic and short-circuit on first failure.
     ///
     /// # Approach
-    /// 1. Use trial division (`6k ± 1`) for primality checks.
+    /// 1. Use deterministic Miller–Rabin for primality checks.
     /// 2. Validate all prefixes by repeatedly dividing by 10.
     /// 3. Validate all suffixes by taking modulo powers of 10.
     /// 4. Return `true` only if every generated value is prime.
     ///
     /// # Complexity
-    /// - Time: O(d * sqrt(num)) where `d` is number of digits (at most 10)
+    /// - Time: O(d * log(num)) where `d` is number of digits (at most 10)
     /// - Space: O(1)
     pub fn complete_prime(num: i32) -> bool {
-        if !Self::is_prime(i64::from(num)) {
+        if !is_prime(num as u64) {
             return false;
         }
 
         let mut prefix = num / 10;
         while prefix > 0 {
-            if !Self::is_prime(i64::from(prefix)) {
+            if !is_prime(prefix as u64) {
                 return false;
             }
             prefix /= 10;
         }
 
-        let num_i64 = i64::from(num);
-        let mut modulo_base = 10_i64;
-        while modulo_base <= num_i64 {
-            if !Self::is_prime(num_i64 % modulo_base) {
+        let num = num as u64;
+        let mut modulo_base = 10;
+        while modulo_base <= num {
+            if !is_prime(num % modulo_base) {
                 return false;
             }
             modulo_base *= 10;
@@ -40,28 +43,6 @@ impl Solution {
 
         true
     }
-
-    #[inline]
-    fn is_prime(value: i64) -> bool {
-        if value < 2 {
-            return false;
-        }
-        if value == 2 || value == 3 {
-            return true;
-        }
-        if value % 2 == 0 || value % 3 == 0 {
-            return false;
-        }
-
-        let mut divisor = 5_i64;
-        while divisor * divisor <= value {
-            if value % divisor == 0 || value % (divisor + 2) == 0 {
-                return false;
-            }
-            divisor += 6;
-        }
-        true
-    }
 }
 
 #[cfg(test)]
@@ -1,33 +1,4 @@
-struct BIT {
-    tree: Vec<i64>,
-}
-
-impl BIT {
-    fn new(n: usize) -> Self {
-        Self {
-            tree: vec![0; n + 2],
-        }
-    }
-
-    fn update(&mut self, mut i: usize, delta: i64) {
-        i += 1;
-        let len = self.tree.len();
-        while i < len {
-            self.tree[i] += delta;
-            i += i & i.wrapping_neg();
-        }
-    }
-
-    fn query(&self, mut i: usize) -> i64 {
-        i += 1;
-        let mut sum = 0;
-        while i > 0 {
-            sum += self.tree[i];
-            i -= i & i.wrapping_neg();
-        }
-        sum
-    }
-}
+use crate::fenwick::Fenwick;
 
 impl Solution {
     /// Sliding window with BIT for minimum inversion count.
@@ -62,12 +33,12 @@ impl Solution {
             .collect();
 
         let max_rank = sorted.len();
-        let mut bit = BIT::new(max_rank);
+        let mut bit: Fenwick<i64> = Fenwick::new(max_rank);
         let mut inversions: i64 = 0;
 
         rank[..k].iter().enumerate().for_each(|(i, &r)| {
-            inversions += (i as i64) - bit.query(r);
-            bit.update(r, 1);
+            inversions += (i as i64) - bit.prefix(r + 1);
+            bit.add(r, 1);
         });
 
         let mut min_inversions = inversions;
@@ -77,13 +48,11 @@ impl Solution {
             let out_rank = rank[i - k];
             let in_rank = rank[i];
 
-            bit.update(out_rank, -1);
-            if out_rank > 0 {
-                inversions -= bit.query(out_rank - 1);
-            }
+            bit.add(out_rank, -1);
+            inversions -= bit.prefix(out_rank);
 
-            inversions += k_minus_one - bit.query(in_rank);
-            bit.update(in_rank, 1);
+            inversions += k_minus_one - bit.prefix(in_rank + 1);
+            bit.add(in_rank, 1);
 
             min_inversions = min_inversions.min(inversions);
         }
@@ -95,6 +64,8 @@ impl Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::differential;
+    use crate::random::RandomSource;
 
     #[test]
     fn test_window_with_zero_inversions() {
@@ -125,4 +96,31 @@ mod tests {
     fn test_reversed_pair() {
         assert_eq!(Solution::min_inversion_count(vec![2, 1], 2), 1);
     }
+
+    /// Counts the inversions of every window pair by pair.
+    fn brute_force(nums: &[i32], k: usize) -> i64 {
+        nums.windows(k)
+            .map(|w| {
+                (0..k)
+                    .flat_map(|i| (i + 1..k).map(move |j| (i, j)))
+                    .filter(|&(i, j)| w[i] > w[j])
+                    .count() as i64
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            |rng| {
+                let nums = differential::vec_with(rng, 10, |rng| rng.in_range(1..=6));
+                let k = rng.in_range(1..=nums.len().max(1));
+                (nums, k)
+            },
+            |(nums, k)| (1..=nums.len()).contains(k) && nums.iter().all(|&x| x >= 1),
+            |(nums, k)| Solution::min_inversion_count(nums.clone(), *k as i32),
+            |(nums, k)| brute_force(nums, *k),
+        );
+    }
 }
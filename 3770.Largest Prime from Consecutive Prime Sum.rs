@@ -1,5 +1,7 @@
 use std::sync::OnceLock;
 
+use crate::number_theory::Sieve;
+
 const MX: usize = 500_000;
 
 /// Precomputed answer for each index: `ans[i]` = largest prime ≤ i that is a
@@ -19,7 +21,7 @@ impl Solution {
     /// 3. Return `ans[n]` (or 0 for n &lt; 2 via table content).
     ///
     /// # Complexity
-    /// - Time: O(1) per call after O(MX log log MX) one-time precomputation.
+    /// - Time: O(1) per call after O(MX) one-time precomputation.
     /// - Space: O(MX) for the static table.
     pub fn largest_prime(n: i32) -> i32 {
         let sprimes = SPRIME_ANS.get_or_init(Self::build_sprime_ans);
@@ -31,25 +33,15 @@ impl Solution {
     }
 
     fn build_sprime_ans() -> Vec<i32> {
-        let mut is_prime = [true; MX + 1];
-        is_prime[..2].fill(false);
-        let mut primes = Vec::with_capacity(MX);
-        for i in 2..=MX {
-            if !is_prime[i] {
-                continue;
-            }
-            primes.push(i as i32);
-            for j in (i * i..=MX).step_by(i) {
-                is_prime[j] = false;
-            }
-        }
+        let sieve = Sieve::new(MX);
+        let primes = sieve.primes();
         let mut ans = vec![0i32; MX + 1];
         let (mut sum_p, mut last, mut j) = (0i64, 0i32, 0usize);
         for i in 2..=MX {
             while j < primes.len() && sum_p + i64::from(primes[j]) <= i as i64 {
                 sum_p += i64::from(primes[j]);
                 j += 1;
-                if sum_p <= MX as i64 && is_prime[sum_p as usize] {
+                if sum_p <= MX as i64 && sieve.is_prime(sum_p as usize) {
                     last = sum_p as i32;
                 }
             }
@@ -1,84 +1,29 @@
+use crate::rooted_tree::RootedTree;
+use crate::segment_tree::Sum;
+
 impl Solution {
     /// Maximum subgraph score for each node using tree DP with rerooting.
     ///
     /// # Intuition
     /// For each node, find the maximum score of any connected subgraph containing it.
-    /// Use tree DP with rerooting: bottom-up accumulates scores, top-down propagates.
+    /// A node's best subgraph is itself plus every neighbouring branch whose own best
+    /// subgraph, grown away from the node, scores positively.
     ///
     /// # Approach
-    /// 1. Build CSR-style adjacency list for cache efficiency
-    /// 2. BFS to establish parent-child relationships and traversal order
-    /// 3. Bottom-up: accumulate positive child contributions
-    /// 4. Top-down: add parent's contribution excluding current subtree
+    /// 1. Score each node `+1` if good and `-1` otherwise
+    /// 2. Best score at `v` = own score + sum of positive branch scores
+    /// 3. Reroot the recurrence so every node gets its value with itself as the root
     ///
     /// # Complexity
-    /// - Time: O(n)
-    /// - Space: O(n)
+    /// - Time: O(n log n) for rooting the tree; the rerooting itself is O(n)
+    /// - Space: O(n log n)
     pub fn max_subgraph_score(n: i32, edges: Vec<Vec<i32>>, good: Vec<i32>) -> Vec<i32> {
         let n = n as usize;
-        if n == 1 {
-            return vec![if good[0] == 1 { 1 } else { -1 }];
-        }
-
-        let mut degree = vec![0u32; n];
-        edges.iter().for_each(|e| {
-            degree[e[0] as usize] += 1;
-            degree[e[1] as usize] += 1;
-        });
-
-        let mut head = vec![0u32; n + 1];
-        for i in 0..n {
-            head[i + 1] = head[i] + degree[i];
-        }
-
-        let mut adj = vec![0u32; 2 * (n - 1)];
-        let mut pos = head[..n].to_vec();
-        for e in &edges {
-            let (u, v) = (e[0] as usize, e[1] as usize);
-            adj[pos[u] as usize] = v as u32;
-            pos[u] += 1;
-            adj[pos[v] as usize] = u as u32;
-            pos[v] += 1;
-        }
-
-        let mut parent = vec![u32::MAX; n];
-        let mut order = Vec::with_capacity(n);
-        parent[0] = 0;
-        order.push(0u32);
-        let mut idx = 0;
-
-        while idx < order.len() {
-            let u = order[idx] as usize;
-            idx += 1;
-            for i in head[u]..head[u + 1] {
-                let v = adj[i as usize] as usize;
-                if parent[v] == u32::MAX {
-                    parent[v] = u as u32;
-                    order.push(v as u32);
-                }
-            }
-        }
-
-        let mut dp: Vec<i32> = good.iter().map(|&g| (g << 1) - 1).collect();
-
-        for i in (1..n).rev() {
-            let u = order[i] as usize;
-            let p = parent[u] as usize;
-            if dp[u] > 0 {
-                dp[p] += dp[u];
-            }
-        }
-
-        for i in 1..n {
-            let u = order[i] as usize;
-            let p = parent[u] as usize;
-            let contrib = dp[p] - dp[u].max(0);
-            if contrib > 0 {
-                dp[u] += contrib;
-            }
-        }
-
-        dp
+        let tree = RootedTree::new(n, edges.iter().map(|e| (e[0] as usize, e[1] as usize)), 0);
+        tree.reroot(
+            |&score: &i32, _, _| Sum(score.max(0)),
+            |branches, v| (good[v] << 1) - 1 + branches.0,
+        )
     }
 }
 
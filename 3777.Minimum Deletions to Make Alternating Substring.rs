@@ -1,57 +1,4 @@
-/// Fenwick tree supporting point updates and prefix sums.
-pub struct Fenwick {
-    nums: Vec<i32>,
-    sums: Vec<i32>,
-}
-
-const fn lowbit(i: usize) -> usize {
-    let i = i as isize;
-    (i & -i) as usize
-}
-
-impl Fenwick {
-    /// Build a Fenwick tree in O(n) from a slice.
-    pub fn from_slice(nums: &[i32]) -> Self {
-        let len = nums.len() + 1;
-        let nums = nums.to_vec();
-        let mut sums = vec![0; len];
-        for i in 1..len {
-            sums[i] += nums[i - 1];
-            let j = i + lowbit(i);
-            if j < len {
-                sums[j] += sums[i];
-            }
-        }
-        Self { nums, sums }
-    }
-
-    /// Set index to a new value and propagate the delta.
-    pub fn update(&mut self, index: usize, val: i32) {
-        let delta = val - self.nums[index];
-        self.nums[index] = val;
-        let mut i = index + 1;
-        let len = self.sums.len();
-        while i < len {
-            self.sums[i] += delta;
-            i += lowbit(i);
-        }
-    }
-
-    fn query(&self, mut index: usize) -> i32 {
-        index += 1;
-        let mut val = 0;
-        while index > 0 {
-            val += self.sums[index];
-            index -= lowbit(index);
-        }
-        val
-    }
-
-    /// Query inclusive range sum [left, right].
-    pub fn query_range(&self, left: usize, right: usize) -> i32 {
-        self.query(right) - if left > 0 { self.query(left - 1) } else { 0 }
-    }
-}
+use crate::fenwick::Fenwick;
 
 impl Solution {
     /// Fenwick Tree for Alternating Substring Queries
@@ -61,8 +8,8 @@ impl Solution {
     /// Each equal pair requires one deletion to make alternating.
     ///
     /// # Approach
-    /// Use Fenwick tree with O(n) construction via prefix propagation.
-    /// Track current values to compute deltas for updates.
+    /// Use a Fenwick tree built in O(n) over the equal-pair indicators and
+    /// overwrite an indicator whenever a flip changes it.
     /// Range query gives count of equal pairs in substring.
     ///
     /// # Complexity
@@ -74,7 +21,7 @@ impl Solution {
         let values: Vec<i32> = std::iter::once(0)
             .chain(bytes.windows(2).map(|w| i32::from(w[0] == w[1])))
             .collect();
-        let mut tree = Fenwick::from_slice(&values);
+        let mut tree = Fenwick::from(values);
         let mut ans = Vec::with_capacity(queries.len());
         for query in &queries {
             match query[0] {
@@ -82,15 +29,15 @@ impl Solution {
                     let idx = query[1] as usize;
                     bytes[idx] = if bytes[idx] == b'A' { b'B' } else { b'A' };
                     if idx > 0 {
-                        tree.update(idx, i32::from(bytes[idx - 1] == bytes[idx]));
+                        tree.set(idx, i32::from(bytes[idx - 1] == bytes[idx]));
                     }
                     if idx + 1 < len {
-                        tree.update(idx + 1, i32::from(bytes[idx] == bytes[idx + 1]));
+                        tree.set(idx + 1, i32::from(bytes[idx] == bytes[idx + 1]));
                     }
                 }
                 _ => {
                     let (left, right) = (query[1] as usize, query[2] as usize);
-                    ans.push(tree.query_range(left + 1, right));
+                    ans.push(tree.sum(left + 1..=right));
                 }
             }
         }
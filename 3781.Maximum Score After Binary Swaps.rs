@@ -26,11 +26,11 @@ impl Solution {
         for i in (0..nums.len()).rev() {
             if s[i] == b'1' {
                 heap.push(Reverse(nums[i]));
-            } else if let Some(&Reverse(min_val)) = heap.peek() {
-                if nums[i] > min_val {
-                    heap.pop();
-                    heap.push(Reverse(nums[i]));
-                }
+            } else if let Some(&Reverse(min_val)) = heap.peek()
+                && nums[i] > min_val
+            {
+                heap.pop();
+                heap.push(Reverse(nums[i]));
             }
         }
 
@@ -84,7 +84,7 @@ impl Solution {
         group
             .iter()
             .enumerate()
-            .filter(|(_, &g)| group_size[g as usize] >= 2)
+            .filter(|&(_, &g)| group_size[g as usize] >= 2)
             .for_each(|(i, &g)| group_nodes[g as usize].push(i as u32));
 
         let mut total_cost = 0i64;
@@ -1,6 +1,6 @@
-use std::cmp::min;
+use crate::mod_int::Mint;
 
-const MOD: u32 = 1_000_000_007;
+use std::cmp::min;
 
 impl Solution {
     /// Optimized DP with prefix sums for counting grid climbing routes
@@ -37,26 +37,25 @@ impl Solution {
         let lu: Vec<usize> = (0..m).map(|j| j.saturating_sub(rdu)).collect();
         let ru1: Vec<usize> = (0..m).map(|j| min(m, j + rdu + 1)).collect();
 
-        let mut dp0 = vec![0u32; m];
-        let mut dp1 = vec![0u32; m];
-        let mut new0 = vec![0u32; m];
-        let mut pref = vec![0u32; m + 1];
+        let mut dp0 = vec![Mint::ZERO; m];
+        let mut dp1 = vec![Mint::ZERO; m];
+        let mut new0 = vec![Mint::ZERO; m];
+        let mut pref = vec![Mint::ZERO; m + 1];
 
         let bottom = grid[n - 1];
         bottom
             .iter()
             .enumerate()
-            .filter(|(_, &c)| c == b'.')
-            .for_each(|(j, _)| dp0[j] = 1);
+            .filter(|&(_, &c)| c == b'.')
+            .for_each(|(j, _)| dp0[j] = Mint::ONE);
 
         Self::build_prefix(&dp0, &mut pref);
         bottom
             .iter()
             .enumerate()
-            .filter(|(_, &c)| c == b'.')
+            .filter(|&(_, &c)| c == b'.')
             .for_each(|(j, _)| {
-                let sum = Self::range_sum(&pref, ld[j], rd1[j]);
-                dp1[j] = Self::sub_mod(sum, dp0[j]);
+                dp1[j] = Self::range_sum(&pref, ld[j], rd1[j]) - dp0[j];
             });
 
         for row in (0..n - 1).rev() {
@@ -67,7 +66,7 @@ impl Solution {
                 new0[j] = if c == b'.' {
                     Self::range_sum(&pref, lu[j], ru1[j])
                 } else {
-                    0
+                    Mint::ZERO
                 };
             });
 
@@ -76,10 +75,9 @@ impl Solution {
             Self::build_prefix(&dp0, &mut pref);
             cur_row.iter().enumerate().for_each(|(j, &c)| {
                 dp1[j] = if c == b'.' {
-                    let sum = Self::range_sum(&pref, ld[j], rd1[j]);
-                    Self::sub_mod(sum, dp0[j])
+                    Self::range_sum(&pref, ld[j], rd1[j]) - dp0[j]
                 } else {
-                    0
+                    Mint::ZERO
                 };
             });
         }
@@ -87,10 +85,10 @@ impl Solution {
         let top = grid[0];
         top.iter()
             .enumerate()
-            .filter(|(_, &c)| c == b'.')
-            .fold(0u32, |ans, (j, _)| {
-                Self::add_mod(ans, Self::add_mod(dp0[j], dp1[j]))
-            }) as i32
+            .filter(|&(_, &c)| c == b'.')
+            .map(|(j, _)| dp0[j] + dp1[j])
+            .sum::<Mint>()
+            .value() as i32
     }
 
     #[inline(always)]
@@ -108,51 +106,27 @@ impl Solution {
     }
 
     #[inline(always)]
-    fn add_mod(a: u32, b: u32) -> u32 {
-        let s = a + b;
-        if s >= MOD {
-            s - MOD
-        } else {
-            s
-        }
-    }
-
-    #[inline(always)]
-    fn sub_mod(a: u32, b: u32) -> u32 {
-        if a >= b {
-            a - b
-        } else {
-            a + MOD - b
-        }
-    }
-
-    #[inline(always)]
-    fn build_prefix(arr: &[u32], pref: &mut [u32]) {
-        pref[0] = 0;
+    fn build_prefix(arr: &[Mint], pref: &mut [Mint]) {
+        pref[0] = Mint::ZERO;
         arr.iter().enumerate().for_each(|(j, &val)| {
-            pref[j + 1] = Self::add_mod(pref[j], val);
+            pref[j + 1] = pref[j] + val;
         });
     }
 
     #[inline(always)]
-    fn build_prefix_combined(a: &[u32], b: &[u32], pref: &mut [u32]) {
-        pref[0] = 0;
+    fn build_prefix_combined(a: &[Mint], b: &[Mint], pref: &mut [Mint]) {
+        pref[0] = Mint::ZERO;
         a.iter()
             .zip(b.iter())
             .enumerate()
             .for_each(|(j, (&av, &bv))| {
-                let sum = if av + bv >= MOD {
-                    av + bv - MOD
-                } else {
-                    av + bv
-                };
-                pref[j + 1] = Self::add_mod(pref[j], sum);
+                pref[j + 1] = pref[j] + av + bv;
             });
     }
 
     #[inline(always)]
-    fn range_sum(pref: &[u32], l: usize, r1: usize) -> u32 {
-        Self::sub_mod(pref[r1], pref[l])
+    fn range_sum(pref: &[Mint], l: usize, r1: usize) -> Mint {
+        pref[r1] - pref[l]
     }
 }
 
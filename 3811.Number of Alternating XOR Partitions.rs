@@ -1,4 +1,4 @@
-const MOD: i32 = 1_000_000_007;
+use crate::mod_int::Mint;
 
 impl Solution {
     /// Count valid partitions where block XORs alternate target1, target2, starting with target1.
@@ -20,12 +20,12 @@ impl Solution {
         let length = nums.len();
         let cycle_prefix_xors: [i32; 4] = [0, target1, target1 ^ target2, target2];
 
-        let mut ways_for_state = [0i32; 4];
-        ways_for_state[0] = 1;
+        let mut ways_for_state = [Mint::ZERO; 4];
+        ways_for_state[0] = Mint::ONE;
         let mut running_prefix_xor = 0i32;
         for index in 0..length - 1 {
             running_prefix_xor ^= nums[index];
-            let mut transition_ways = [0i32; 4];
+            let mut transition_ways = [Mint::ZERO; 4];
             if running_prefix_xor == cycle_prefix_xors[1] {
                 transition_ways[1] = ways_for_state[0];
             }
@@ -41,23 +41,23 @@ impl Solution {
             ways_for_state
                 .iter_mut()
                 .zip(transition_ways.iter())
-                .for_each(|(state, &trans)| *state = (*state + trans) % MOD);
+                .for_each(|(state, &trans)| *state += trans);
         }
         running_prefix_xor ^= nums[length - 1];
-        let mut result = 0i32;
+        let mut result = Mint::ZERO;
         if running_prefix_xor == cycle_prefix_xors[1] {
-            result = (result + ways_for_state[0]) % MOD;
+            result += ways_for_state[0];
         }
         if running_prefix_xor == cycle_prefix_xors[2] {
-            result = (result + ways_for_state[1]) % MOD;
+            result += ways_for_state[1];
         }
         if running_prefix_xor == cycle_prefix_xors[3] {
-            result = (result + ways_for_state[2]) % MOD;
+            result += ways_for_state[2];
         }
         if running_prefix_xor == cycle_prefix_xors[0] {
-            result = (result + ways_for_state[3]) % MOD;
+            result += ways_for_state[3];
         }
-        result
+        result.value() as i32
     }
 }
 
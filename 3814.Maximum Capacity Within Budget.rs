@@ -18,7 +18,7 @@ impl Solution {
     /// - Space: O(n)
     pub fn max_capacity(costs: Vec<i32>, capacity: Vec<i32>, budget: i32) -> i32 {
         let n = costs.len();
-        let mut t: Vec<(i32, i32)> = costs.into_iter().zip(capacity.into_iter()).collect();
+        let mut t: Vec<(i32, i32)> = costs.into_iter().zip(capacity).collect();
         t.sort_unstable();
 
         let mut p = vec![0i32; n];
@@ -72,6 +72,7 @@ impl AuctionSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::replay::{Script, check};
 
     #[test]
     fn test_example() {
@@ -107,4 +108,16 @@ mod tests {
         let mut sys = AuctionSystem::new();
         assert_eq!(sys.get_highest_bidder(1), -1);
     }
+
+    #[test]
+    fn test_replay() {
+        let script: Script = r#"
+            ["AuctionSystem","addBid","addBid","getHighestBidder","updateBid","getHighestBidder","removeBid","getHighestBidder","getHighestBidder"]
+            [[],[1,7,5],[2,7,6],[7],[1,7,8],[7],[2,7],[7],[3]]
+            [null,null,null,2,null,1,null,1,-1]
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(check::<AuctionSystem>(&script), Ok(vec![]));
+    }
 }
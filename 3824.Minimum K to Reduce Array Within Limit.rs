@@ -45,7 +45,7 @@ mod tests {
 
     #[test]
     fn test_single_large() {
-        assert_eq!(Solution::minimum_k(vec![10]), 1);
+        assert_eq!(Solution::minimum_k(vec![10]), 3);
     }
 
     #[test]
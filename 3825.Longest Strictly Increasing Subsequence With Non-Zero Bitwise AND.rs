@@ -25,7 +25,7 @@ impl Solution {
                     continue;
                 }
                 let t = &mut tails[bit];
-                if t.last().map_or(true, |&last| last < value) {
+                if t.last().is_none_or(|&last| last < value) {
                     t.push(value);
                 } else {
                     let pos = t.partition_point(|x| *x < value);
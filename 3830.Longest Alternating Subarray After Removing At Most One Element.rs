@@ -45,10 +45,8 @@ impl Solution {
                 if down1[i - 1] > 0 {
                     up1[i] = up1[i].max(down1[i - 1] + 1);
                 }
-            } else if nums[i - 1] > nums[i] {
-                if up1[i - 1] > 0 {
-                    down1[i] = down1[i].max(up1[i - 1] + 1);
-                }
+            } else if nums[i - 1] > nums[i] && up1[i - 1] > 0 {
+                down1[i] = down1[i].max(up1[i - 1] + 1);
             }
 
             if i >= 2 {
@@ -1,3 +1,5 @@
+use crate::fenwick::{DifferenceFenwick, Xor};
+
 impl Solution {
     /// Answers palindromic path queries using Euler tour LCA and XOR bitmask Fenwick tree.
     ///
@@ -16,7 +18,7 @@ impl Solution {
     ///    Changing node u's character XORs a delta into the contiguous Euler-tour range
     ///    `[in_time[u], out_time[u]]`.
     /// 4. For each query `(u, v)`: compute `path_xor = eff(u) ^ eff(v) ^ (1 << char[lca])`,
-    ///    where `eff(x) = root_xor[x] ^ bit_query(in_time[x])`.
+    ///    where `eff(x) = root_xor[x] ^ bit.get(in_time[x])`.
     ///    The path is palindrome-rearrangeable iff `mask & (mask - 1) == 0`.
     ///
     /// # Complexity
@@ -70,7 +72,7 @@ impl Solution {
         timer += 1;
         root_xor[0] = 1 << cur_chars[0];
         first[0] = 0;
-        euler.push(((0u64) << 32) | 0u64);
+        euler.push(0u64);
         depth[0] = 0;
         stack.push((0, u32::MAX, 0));
 
@@ -153,28 +155,7 @@ impl Solution {
             (if a <= b { a } else { b }) as u32
         }
 
-        let bit_len = n + 2;
-        let mut bit = vec![0u32; bit_len];
-
-        #[inline(always)]
-        fn bit_update(bit: &mut [u32], mut i: usize, delta: u32) {
-            i += 1;
-            while i < bit.len() {
-                bit[i] ^= delta;
-                i += i & i.wrapping_neg();
-            }
-        }
-
-        #[inline(always)]
-        fn bit_query(bit: &[u32], mut i: usize) -> u32 {
-            i += 1;
-            let mut r = 0u32;
-            while i > 0 {
-                r ^= bit[i];
-                i &= i - 1;
-            }
-            r
-        }
+        let mut bit: DifferenceFenwick<Xor<u32>> = DifferenceFenwick::new(n);
 
         #[inline(always)]
         fn parse_uint(bytes: &[u8], start: usize) -> (usize, usize) {
@@ -204,8 +185,7 @@ impl Solution {
                 let old_char = cur_chars[node];
                 if old_char != new_char {
                     let delta = (1u32 << old_char) ^ (1u32 << new_char);
-                    bit_update(&mut bit, in_time[node] as usize, delta);
-                    bit_update(&mut bit, out_time[node] as usize + 1, delta);
+                    bit.add(in_time[node] as usize..=out_time[node] as usize, Xor(delta));
                     cur_chars[node] = new_char;
                 }
             } else {
@@ -216,8 +196,8 @@ impl Solution {
                     continue;
                 }
                 let l = lca(u, v);
-                let eff_u = root_xor[u] ^ bit_query(&bit, in_time[u] as usize);
-                let eff_v = root_xor[v] ^ bit_query(&bit, in_time[v] as usize);
+                let eff_u = root_xor[u] ^ bit.get(in_time[u] as usize).0;
+                let eff_v = root_xor[v] ^ bit.get(in_time[v] as usize).0;
                 let path_xor = eff_u ^ eff_v ^ (1u32 << cur_chars[l]);
                 results.push(path_xor & path_xor.wrapping_sub(1) == 0);
             }
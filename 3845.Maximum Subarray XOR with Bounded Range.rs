@@ -20,7 +20,7 @@ impl Solution {
     /// 2. Use sliding window [left, right] maintained by two deques:
     ///    - Min deque: monotonically increasing to track minimum
     ///    - Max deque: monotonically decreasing to track maximum
-    ///    Shrink left when max - min > k
+    ///    - Shrink left when max - min > k
     ///
     /// 3. Maintain a complete binary trie of prefix[l] values for all valid start positions l.
     ///    The trie uses implicit indexing: node at index i has children at 2*i and 2*i+1.
@@ -31,7 +31,7 @@ impl Solution {
     ///
     /// # Complexity
     /// - Time: O(n * 15) = O(n) where n is the length of nums. Each element is added/removed from
-    ///         deques and trie at most once, and trie operations are O(15) for 15-bit numbers.
+    ///   deques and trie at most once, and trie operations are O(15) for 15-bit numbers.
     /// - Space: O(2^16) for the fixed-size complete binary trie.
     pub fn max_xor(nums: Vec<i32>, k: i32) -> i32 {
         // Complete binary trie with implicit indexing (like binary heap)
@@ -52,7 +52,7 @@ impl Solution {
         // Insert initial prefix[0] = 0 into trie
         let mut idx = 1;
         for _ in (0..15).rev() {
-            idx = 2 * idx;
+            idx *= 2;
             trie[idx] += 1;
         }
 
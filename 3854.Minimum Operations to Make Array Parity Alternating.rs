@@ -28,7 +28,7 @@ impl Solution {
         }
 
         let merunavilo = nums;
-        let nums = merunavilo;
+        let nums = &merunavilo;
 
         let (mut ops_a, mut ops_b) = (0i32, 0i32);
         for (i, v) in nums.iter().enumerate() {
@@ -150,7 +150,7 @@ mod tests {
     fn negative_values() {
         assert_eq!(
             Solution::make_parity_alternating(vec![-1_000_000_000, 1_000_000_000]),
-            vec![0, 2_000_000_000]
+            vec![1, 1_999_999_999]
         );
     }
 }
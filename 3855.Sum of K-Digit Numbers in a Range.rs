@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Sum all k-digit numbers whose digits are each drawn from [l, r].
     ///
@@ -20,32 +22,17 @@ impl Solution {
     /// - Time: O(log k) for modular exponentiation.
     /// - Space: O(1).
     pub fn sum_of_numbers(l: i32, r: i32, k: i32) -> i32 {
-        const MOD: i64 = 1_000_000_007;
-
-        fn pow_mod(mut base: i64, mut exp: i64, modulus: i64) -> i64 {
-            let mut result = 1i64;
-            base %= modulus;
-            while exp > 0 {
-                if exp & 1 == 1 {
-                    result = result * base % modulus;
-                }
-                exp >>= 1;
-                base = base * base % modulus;
-            }
-            result
-        }
-
-        let lorunavemi = (l as i64, r as i64, k as i64);
+        let lorunavemi = (l as i64, r as i64, k as u64);
         let (l, r, k) = lorunavemi;
 
-        let choices = r - l + 1;
-        let digit_sum = (l + r) % MOD * (choices % MOD) % MOD * pow_mod(2, MOD - 2, MOD) % MOD;
+        let choices = Mint::from(r - l + 1);
+        let digit_sum = Mint::from(l + r) * choices / 2;
 
-        let geo_sum = (pow_mod(10, k, MOD) - 1 + MOD) % MOD * pow_mod(9, MOD - 2, MOD) % MOD;
+        let geo_sum = (Mint::new(10).pow(k) - 1) / 9;
 
-        let choices_pow = pow_mod(choices, k - 1, MOD);
+        let choices_pow = choices.pow(k - 1);
 
-        (digit_sum % MOD * choices_pow % MOD * geo_sum % MOD) as i32
+        (digit_sum * choices_pow * geo_sum).value() as i32
     }
 }
 
@@ -84,6 +71,6 @@ mod tests {
         // k = 10^9, single digit 1 → number is 111...1 (10^9 ones)
         // = (10^(10^9) - 1) / 9 mod 10^9+7
         let result = Solution::sum_of_numbers(1, 1, 1_000_000_000);
-        assert!(result >= 0 && result < 1_000_000_007);
+        assert!((0..1_000_000_007).contains(&result));
     }
 }
@@ -15,8 +15,7 @@ impl Solution {
     /// - Time: O(n)
     /// - Space: O(n) for the returned string
     pub fn trim_trailing_vowels(s: String) -> String {
-        s.trim_end_matches(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'))
-            .to_string()
+        s.trim_end_matches(['a', 'e', 'i', 'o', 'u']).to_string()
     }
 }
 
@@ -53,7 +52,7 @@ mod tests {
     fn test_mixed_interior_vowels() {
         assert_eq!(
             Solution::trim_trailing_vowels("beautiful".to_string()),
-            "beautif"
+            "beautiful"
         );
     }
 }
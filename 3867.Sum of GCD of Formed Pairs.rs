@@ -1,3 +1,5 @@
+use crate::number_theory::gcd;
+
 struct Solution;
 
 impl Solution {
@@ -25,7 +27,7 @@ impl Solution {
         let mut running_max = 0i32;
         for &x in &nums {
             running_max = running_max.max(x);
-            prefix_gcd.push(Self::gcd(x, running_max));
+            prefix_gcd.push(gcd(x, running_max));
         }
 
         prefix_gcd.sort_unstable();
@@ -33,22 +35,12 @@ impl Solution {
         let (mut left, mut right) = (0usize, n - 1);
         let mut total = 0i64;
         while left < right {
-            total += Self::gcd(prefix_gcd[left], prefix_gcd[right]) as i64;
+            total += gcd(prefix_gcd[left], prefix_gcd[right]) as i64;
             left += 1;
             right -= 1;
         }
         total
     }
-
-    /// Iterative Euclidean GCD — avoids recursive call overhead.
-    #[inline]
-    fn gcd(mut a: i32, mut b: i32) -> i32 {
-        while b != 0 {
-            a %= b;
-            std::mem::swap(&mut a, &mut b);
-        }
-        a
-    }
 }
 
 #[cfg(test)]
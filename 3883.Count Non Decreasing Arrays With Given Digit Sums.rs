@@ -1,3 +1,5 @@
+use crate::mod_int::Mint;
+
 impl Solution {
     /// Counts non-decreasing arrays in [0, 5000] whose element-wise digit sums match the input.
     ///
@@ -22,7 +24,6 @@ impl Solution {
     /// - Time:  O(R) precompute + O(n × B_max) runtime where R = 5001 and B_max ≤ 365
     /// - Space: O(R)
     pub fn count_arrays(digit_sum: Vec<i32>) -> i32 {
-        const MOD: i64 = 1_000_000_007;
         const MAX_VAL: usize = 5001;
 
         let mut buckets: Vec<Vec<u16>> = vec![Vec::new(); 51];
@@ -45,21 +46,21 @@ impl Solution {
         }
 
         // Sparse DP: (value, accumulated_ways)
-        let mut dp: Vec<(u16, i64)> = vec![(0, 1)];
+        let mut dp: Vec<(u16, Mint)> = vec![(0, Mint::ONE)];
 
         for &ds in &digit_sum {
             let bucket = &buckets[ds as usize];
             let mut new_dp = Vec::with_capacity(bucket.len());
-            let mut prefix: i64 = 0;
+            let mut prefix = Mint::ZERO;
             let mut j = 0;
 
             for &v in bucket {
                 // Absorb all dp entries with value <= v into prefix
                 while j < dp.len() && dp[j].0 <= v {
-                    prefix = (prefix + dp[j].1) % MOD;
+                    prefix += dp[j].1;
                     j += 1;
                 }
-                if prefix != 0 {
+                if prefix != Mint::ZERO {
                     new_dp.push((v, prefix));
                 }
             }
@@ -67,7 +68,7 @@ impl Solution {
             dp = new_dp;
         }
 
-        (dp.iter().fold(0i64, |acc, &(_, c)| (acc + c) % MOD)) as i32
+        dp.iter().map(|&(_, c)| c).sum::<Mint>().value() as i32
     }
 }
 
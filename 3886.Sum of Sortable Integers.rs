@@ -33,7 +33,7 @@ impl Solution {
 
                 let (gmin, gmax) = if d + 1 > e {
                     (nums[s], nums[e])
-                } else if nxt[d + 1] + 1 <= e {
+                } else if nxt[d + 1] < e {
                     return false;
                 } else {
                     if nums[e] > nums[s] {
@@ -54,7 +54,7 @@ impl Solution {
         let mut result = 0i32;
         let mut i = 1;
         while i * i <= n {
-            if n % i == 0 {
+            if n.is_multiple_of(i) {
                 if check(i) {
                     result += i as i32;
                 }
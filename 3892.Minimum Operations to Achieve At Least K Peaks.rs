@@ -60,7 +60,7 @@ impl Solution {
         if want == 0 {
             return 0;
         }
-        if want > (m + 1) / 2 {
+        if want > m.div_ceil(2) {
             return i64::MAX;
         }
 
@@ -0,0 +1,614 @@
+//! Discovers the problem files at the crate root and generates one module per
+//! problem, so `src/lib.rs` never has to be edited when a solution is added.
+//! It also writes the metadata table behind `leetcode::registry`, the adapter
+//! table behind `leetcode::runner` and the design-class dispatch behind
+//! `leetcode::replay`.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A solution file such as `0001.Two Sum.rs`.
+struct Problem {
+    id: u32,
+    title: String,
+    module: String,
+    path: PathBuf,
+    declares_solution: bool,
+    /// Whether `Solution` is reachable from outside its module.
+    solution_is_pub: bool,
+    type_name: String,
+    entry_points: Vec<Method>,
+    time: Option<String>,
+    space: Option<String>,
+    tests: usize,
+}
+
+/// A method of the type LeetCode calls into.
+struct Method {
+    name: String,
+    is_pub: bool,
+    /// Everything from `fn` up to the opening brace of the body.
+    signature: String,
+}
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let mut problems: Vec<Problem> = fs::read_dir(&root)
+        .unwrap()
+        .filter_map(|entry| parse_problem(&entry.unwrap().path()))
+        .collect();
+    problems.sort_by_key(|p| p.id);
+
+    // Watching the root itself would take in `target/` and rerun this script
+    // on every build, so only the files read here are watched. A newly added
+    // problem is picked up once `build.rs` or any problem file changes.
+    for p in &problems {
+        println!("cargo:rerun-if-changed={}", p.path.display());
+    }
+
+    for pair in problems.windows(2) {
+        assert_ne!(
+            pair[0].id, pair[1].id,
+            "duplicate problem id: {:?} and {:?}",
+            pair[0].path, pair[1].path
+        );
+    }
+
+    let mut out = String::new();
+    for p in &problems {
+        writeln!(out, "#[doc = {:?}]", format!("{}. {}", p.id, p.title)).unwrap();
+        // Design-problem types are driven by LeetCode's judge rather than by
+        // this crate, so items only the tests touch are expected.
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "pub mod {} {{", p.module).unwrap();
+        writeln!(out, "    #[allow(unused_imports)]").unwrap();
+        writeln!(out, "    use crate::prelude::*;").unwrap();
+        if !p.declares_solution {
+            writeln!(out, "    pub struct Solution;").unwrap();
+        }
+        // Design types are private to their module, so their dispatch is
+        // generated inside it, ahead of the file and its test module.
+        if let Some(dispatch) = design_dispatch(p) {
+            out.push_str(&dispatch);
+        }
+        writeln!(out, "    include!({:?});", p.path.display().to_string()).unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("problems.rs"), out).unwrap();
+    fs::write(out_dir.join("registry.rs"), registry_table(&problems)).unwrap();
+    fs::write(out_dir.join("adapters.rs"), adapter_table(&problems)).unwrap();
+    fs::write(out_dir.join("designs.rs"), design_table(&problems)).unwrap();
+}
+
+/// Renders the `PROBLEMS` table included by `src/registry.rs`.
+fn registry_table(problems: &[Problem]) -> String {
+    let mut out = String::from("static PROBLEMS: &[Problem] = &[\n");
+    for p in problems {
+        let file = p.path.file_name().unwrap().to_str().unwrap();
+        writeln!(out, "    Problem {{").unwrap();
+        writeln!(out, "        id: {},", p.id).unwrap();
+        writeln!(out, "        title: {:?},", p.title).unwrap();
+        writeln!(out, "        module: {:?},", p.module).unwrap();
+        writeln!(out, "        file: {file:?},").unwrap();
+        writeln!(out, "        type_name: {:?},", p.type_name).unwrap();
+        let names: Vec<&str> = p.entry_points.iter().map(|m| m.name.as_str()).collect();
+        writeln!(out, "        entry_points: &{names:?},").unwrap();
+        writeln!(
+            out,
+            "        complexity: Complexity {{ time: {:?}, space: {:?} }},",
+            p.time, p.space
+        )
+        .unwrap();
+        writeln!(out, "        tests: {},", p.tests).unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// Parses `NNNN.Title.rs` into a [`Problem`]; any other file yields `None`.
+fn parse_problem(path: &Path) -> Option<Problem> {
+    if path.extension()? != "rs" {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let (id, title) = stem.split_once('.')?;
+    if id.len() != 4 || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let source = fs::read_to_string(path).unwrap();
+    let declares_solution = source
+        .lines()
+        .any(|line| line.starts_with("struct Solution") || line.starts_with("pub struct Solution"));
+    let solution_is_pub =
+        !declares_solution || source.lines().any(|line| line == "pub struct Solution;");
+
+    // Everything from the test module on is test code; metadata comes from
+    // the solution above it.
+    let lines: Vec<&str> = source.lines().collect();
+    let test_start = lines
+        .windows(2)
+        .position(|pair| pair[0] == "#[cfg(test)]" && pair[1].starts_with("mod "))
+        .unwrap_or(lines.len());
+    let (body, tests) = lines.split_at(test_start);
+    let (type_name, entry_points) = entry_points(body, tests);
+    let (time, space) = declared_complexity(body);
+
+    Some(Problem {
+        id: id.parse().unwrap(),
+        title: title.to_string(),
+        module: format!("p{id}_{}", snake_case(title)),
+        path: path.to_path_buf(),
+        declares_solution,
+        solution_is_pub,
+        type_name,
+        entry_points,
+        time,
+        space,
+        tests: tests.iter().filter(|line| line.trim() == "#[test]").count(),
+    })
+}
+
+/// Renders the `adapter` lookup included by `src/runner.rs`, with one arm per
+/// `Solution` entry point whose signature [`adapter_expr`] can wrap.
+fn adapter_table(problems: &[Problem]) -> String {
+    let mut out = String::from("fn adapter(id: u32, name: &str) -> Option<Adapter> {\n");
+    // In-place wrappers spell out their parameter types.
+    out.push_str(
+        "    use crate::prelude::*;\n    use std::cell::RefCell;\n    use std::rc::Rc;\n\n",
+    );
+    out.push_str("    let adapter = match (id, name) {\n");
+    for p in problems {
+        if p.type_name != "Solution" || !p.solution_is_pub {
+            continue;
+        }
+        for method in &p.entry_points {
+            let path = format!("crate::{}::Solution::{}", p.module, method.name);
+            if let Some(expr) = adapter_expr(&path, &method.signature) {
+                writeln!(out, "        ({}, {:?}) => {expr},", p.id, method.name).unwrap();
+            }
+        }
+    }
+    out.push_str("        _ => return None,\n    };\n    Some(adapter)\n}\n");
+    out
+}
+
+/// Wraps the associated function at `path` for `runner::adapt`, or returns
+/// `None` when its signature uses types literals cannot express.
+///
+/// Functions that take every argument by value are passed as they are. An
+/// in-place function returning `()` is wrapped in a closure that owns its
+/// `&mut` arguments and returns the first of them, which is what LeetCode
+/// prints as the output of such problems.
+fn adapter_expr(path: &str, signature: &str) -> Option<String> {
+    let (params, ret) = parse_signature(signature)?;
+
+    // Parameter types, each flagged when passed by `&mut`.
+    let mut args = Vec::new();
+    for ty in params {
+        let (in_place, ty) = match ty.strip_prefix("&mut ") {
+            Some(inner) => (true, inner),
+            None => (false, ty),
+        };
+        if !literal_type(ty) {
+            return None;
+        }
+        args.push((in_place, ty));
+    }
+
+    let Some(output) = args.iter().position(|&(in_place, _)| in_place) else {
+        return literal_type(ret).then(|| format!("adapt({path})"));
+    };
+    if ret != "()" {
+        return None;
+    }
+    let params: Vec<String> = args
+        .iter()
+        .enumerate()
+        .map(|(i, (in_place, ty))| {
+            let binding = if *in_place { "mut " } else { "" };
+            format!("{binding}a{i}: {ty}")
+        })
+        .collect();
+    let call_args: Vec<String> = args
+        .iter()
+        .enumerate()
+        .map(|(i, (in_place, _))| {
+            let borrow = if *in_place { "&mut " } else { "" };
+            format!("{borrow}a{i}")
+        })
+        .collect();
+    Some(format!(
+        "adapt(|{}| {{ {path}({}); a{output} }})",
+        params.join(", "),
+        call_args.join(", ")
+    ))
+}
+
+/// Renders the `replayer` lookup included by `src/replay.rs`, with one arm per
+/// design problem that [`design_dispatch`] covers.
+fn design_table(problems: &[Problem]) -> String {
+    let mut out = String::from("fn replayer(id: u32) -> Option<Replayer> {\n");
+    out.push_str("    let replayer: Replayer = match id {\n");
+    for p in problems.iter().filter(|p| design_dispatch(p).is_some()) {
+        writeln!(
+            out,
+            "        {} => crate::{}::replay_design,",
+            p.id, p.module
+        )
+        .unwrap();
+    }
+    out.push_str("        _ => return None,\n    };\n    Some(replayer)\n}\n");
+    out
+}
+
+/// Implements `replay::Design` for the class of a design problem, plus the
+/// `replay_design` function the `replayer` table points at.
+///
+/// Returns `None` for `Solution` problems and for classes whose constructor
+/// takes arguments that literals cannot express. Methods with such arguments
+/// are left out of the dispatch.
+fn design_dispatch(p: &Problem) -> Option<String> {
+    let is_design = p.type_name != "Solution" || p.entry_points.iter().any(|m| m.name == "new");
+    if !is_design {
+        return None;
+    }
+    let new = p.entry_points.iter().find(|m| m.name == "new")?;
+    let (params, ret) = parse_signature(&new.signature)?;
+    if !(ret == "Self" || ret == p.type_name) || !params.iter().all(|ty| literal_type(ty)) {
+        return None;
+    }
+
+    let mut methods = Vec::new();
+    for method in p.entry_points.iter().filter(|m| m.name != "new") {
+        let Some((params, ret)) = parse_signature(&method.signature) else {
+            continue;
+        };
+        let Some((receiver, params)) = params.split_first() else {
+            continue;
+        };
+        let supported = params.iter().all(|ty| literal_type(ty));
+        if matches!(*receiver, "&self" | "&mut self")
+            && supported
+            && (ret == "()" || literal_type(ret))
+        {
+            methods.push((method.name.as_str(), params.len(), ret == "()"));
+        }
+    }
+
+    // A block converting `args`, calling `target` with them and applying
+    // `then` to the result.
+    let call = |target: &str, arity: usize, then: &str| {
+        let args = vec!["args.take()?"; arity].join(", ");
+        if arity == 0 {
+            format!("{{ Args::new(args, 0)?; {target}(){then} }}")
+        } else {
+            format!("{{ let mut args = Args::new(args, {arity})?; {target}({args}){then} }}")
+        }
+    };
+    let ty = &p.type_name;
+    let names: Vec<&str> = methods.iter().map(|(name, ..)| *name).collect();
+    let mut out = String::new();
+    writeln!(out, "    impl crate::replay::Design for {ty} {{").unwrap();
+    writeln!(out, "        const CLASS: &'static str = {ty:?};").unwrap();
+    writeln!(
+        out,
+        "        const METHODS: &'static [&'static str] = &{names:?};"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "        fn construct(args: Vec<crate::literal::Value>) -> Result<Self, crate::runner::RunError> {{"
+    )
+    .unwrap();
+    writeln!(out, "            use crate::runner::Args;").unwrap();
+    writeln!(
+        out,
+        "            Ok({})",
+        call("Self::new", params.len(), "")
+    )
+    .unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(
+        out,
+        "        fn invoke(&mut self, method: &str, args: Vec<crate::literal::Value>) -> Result<crate::literal::Value, crate::runner::RunError> {{"
+    )
+    .unwrap();
+    writeln!(out, "            use crate::literal::IntoValue;").unwrap();
+    writeln!(out, "            use crate::runner::Args;").unwrap();
+    writeln!(out, "            let result = match method {{").unwrap();
+    for (name, arity, returns_unit) in &methods {
+        let then = if *returns_unit {
+            "; crate::literal::Value::Null"
+        } else {
+            ".into_value()"
+        };
+        writeln!(
+            out,
+            "                {name:?} => {},",
+            call(&format!("self.{name}"), *arity, then)
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "                _ => unreachable!(\"`{{method}}` is not in `METHODS`\"),"
+    )
+    .unwrap();
+    writeln!(out, "            }};").unwrap();
+    writeln!(out, "            Ok(result)").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(
+        out,
+        "    pub(crate) fn replay_design(script: &crate::replay::Script) -> Result<Vec<crate::literal::Value>, crate::replay::ReplayError> {{"
+    )
+    .unwrap();
+    writeln!(out, "        crate::replay::replay::<{ty}>(script)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    Some(out)
+}
+
+/// Splits `fn name(params) -> Ret` into its parameter types and return type,
+/// `()` when there is none. A receiver is kept as written, e.g. `&mut self`.
+fn parse_signature(signature: &str) -> Option<(Vec<&str>, &str)> {
+    let rest = signature.strip_prefix("fn ")?;
+    let rest = rest[identifier(rest).len()..].strip_prefix('(')?;
+    let close = matching_close(rest)?;
+    let (params, ret) = (&rest[..close], rest[close + 1..].trim());
+    let ret = match ret.strip_prefix("->") {
+        Some(ret) => ret.trim(),
+        None if ret.is_empty() => "()",
+        None => return None,
+    };
+    let types = split_top_level(params)
+        .into_iter()
+        .map(|param| match param.split_once(':') {
+            Some((_, ty)) => ty.trim(),
+            None => param,
+        })
+        .collect();
+    Some((types, ret))
+}
+
+/// Whether `ty` has a `FromValue`/`IntoValue` impl in `src/literal.rs`.
+fn literal_type(ty: &str) -> bool {
+    let ty: String = ty.split_whitespace().collect();
+    let mut ty = ty.as_str();
+    while let Some(inner) = ty.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+        ty = inner;
+    }
+    matches!(
+        ty,
+        "i32"
+            | "i64"
+            | "u32"
+            | "u64"
+            | "usize"
+            | "f64"
+            | "bool"
+            | "char"
+            | "String"
+            | "Option<Box<ListNode>>"
+            | "Option<Rc<RefCell<TreeNode>>>"
+    )
+}
+
+/// Index of the `)` closing a parameter list that starts just before `s`.
+fn matching_close(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits a parameter list on the commas that are not inside brackets.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Finds the type LeetCode calls into and the methods it calls.
+///
+/// The type is `Solution` when the file has an `impl Solution`, otherwise the
+/// last inherent impl (design problems declare helper types first). Its `pub`
+/// methods are the entry points; design problems write LeetCode's private
+/// `fn` signatures, so there the methods the tests call are used instead,
+/// which leaves internal helpers out.
+fn entry_points(body: &[&str], tests: &[&str]) -> (String, Vec<Method>) {
+    let mut impls: Vec<(String, Vec<Method>)> = Vec::new();
+    let mut in_impl = false;
+    for (i, line) in body.iter().enumerate() {
+        if let Some(header) = line.strip_prefix("impl") {
+            in_impl = line.ends_with('{') && !line.contains(" for ");
+            if in_impl {
+                impls.push((impl_type(header), Vec::new()));
+            }
+        } else if *line == "}" {
+            in_impl = false;
+        } else if in_impl {
+            let item = line.strip_prefix("    ").unwrap_or("");
+            let (is_pub, item) = match item.strip_prefix("pub ") {
+                Some(rest) => (true, rest),
+                None => (false, item),
+            };
+            if let Some(name) = item.strip_prefix("fn ").map(identifier) {
+                let mut signature = String::new();
+                for part in std::iter::once(item).chain(body[i + 1..].iter().map(|l| l.trim())) {
+                    let (part, done) = match part.split_once('{') {
+                        Some((head, _)) => (head, true),
+                        None => (part, false),
+                    };
+                    // Rustfmt breaks long signatures after `(` and `,`.
+                    if !signature.is_empty() && !signature.ends_with('(') {
+                        signature.push(' ');
+                    }
+                    signature.push_str(part.trim());
+                    if done {
+                        break;
+                    }
+                }
+                let signature = signature.replace(", )", ")").replace(",)", ")");
+                impls.last_mut().unwrap().1.push(Method {
+                    name: name.to_string(),
+                    is_pub,
+                    signature: signature.trim().to_string(),
+                });
+            }
+        }
+    }
+
+    let Some(index) = impls
+        .iter()
+        .position(|(name, _)| name == "Solution")
+        .or(impls.len().checked_sub(1))
+    else {
+        return ("Solution".to_string(), Vec::new());
+    };
+    let (type_name, mut methods) = impls.swap_remove(index);
+
+    if methods.iter().any(|m| m.is_pub) {
+        methods.retain(|m| m.is_pub);
+        return (type_name, methods);
+    }
+    let called = |m: &Method| {
+        let (method, path) = (format!(".{}(", m.name), format!("::{}(", m.name));
+        tests
+            .iter()
+            .any(|line| line.contains(&method) || line.contains(&path))
+    };
+    if methods.iter().any(called) {
+        methods.retain(called);
+    }
+    (type_name, methods)
+}
+
+/// The type named by an impl header such as `<T> Foo<T> {`.
+fn impl_type(header: &str) -> String {
+    let mut rest = header.trim_start();
+    if rest.starts_with('<') {
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                rest = rest[i + 1..].trim_start();
+                break;
+            }
+        }
+    }
+    identifier(rest).to_string()
+}
+
+/// The leading Rust identifier of `s`.
+fn identifier(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Time and space bounds from the first `# Complexity` doc section, such as
+/// `O(n log n)` from `/// - Time: O(n log n) — sorting dominates`.
+///
+/// Design problems that list a bound per operation instead of a `Time:` line
+/// yield `None` for time.
+fn declared_complexity(body: &[&str]) -> (Option<String>, Option<String>) {
+    fn doc<'a>(line: &&'a str) -> Option<&'a str> {
+        line.trim_start().strip_prefix("///").map(str::trim)
+    }
+    let Some(start) = body
+        .iter()
+        .position(|line| doc(line) == Some("# Complexity"))
+    else {
+        return (None, None);
+    };
+
+    let (mut time, mut space) = (None, None);
+    for text in body[start + 1..].iter().map_while(doc) {
+        if text.starts_with('#') {
+            break;
+        }
+        if let Some(rest) = text.strip_prefix("- Time:") {
+            time = time.or_else(|| big_o(rest));
+        } else if let Some(rest) = text.strip_prefix("- Space:") {
+            space = space.or_else(|| big_o(rest));
+        }
+    }
+    (time, space)
+}
+
+/// The first balanced `O(...)` expression in `text`.
+fn big_o(text: &str) -> Option<String> {
+    let start = text.find("O(")?;
+    let mut depth = 0;
+    for (i, c) in text[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[start..start + i + 1].to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Maps a free-form title to a snake_case identifier: `Pow(x, n)` -> `pow_x_n`.
+fn snake_case(title: &str) -> String {
+    let mut out = String::with_capacity(title.len());
+    let mut prev: Option<char> = None;
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            let camel_break =
+                c.is_ascii_uppercase() && prev.is_some_and(|p| p.is_ascii_lowercase());
+            if camel_break && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+        prev = Some(c);
+    }
+    while out.ends_with('_') {
+        out.pop();
+    }
+    out
+}
@@ -0,0 +1,98 @@
+//! Checks pasted LeetCode examples against a problem's solution.
+//!
+//! ```text
+//! cargo run --bin check -- <id> [--entry <name>] [<examples-file>]
+//! ```
+//!
+//! Examples are read from the file, or from stdin when none is given, as the
+//! `Input:`/`Output:` lines of problem statements:
+//!
+//! ```text
+//! Input: nums = [2,7,11,15], target = 9
+//! Output: [0,1]
+//! ```
+//!
+//! For design problems the text is instead the operation, argument and
+//! expected-output arrays:
+//!
+//! ```text
+//! ["MinStack","push","getMin"]
+//! [[],[-2],[]]
+//! [null,null,-2]
+//! ```
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::{env, fs};
+
+use leetcode::registry;
+use leetcode::replay::{self, Script};
+use leetcode::runner::{self, Case};
+
+const USAGE: &str = "usage: check <id> [--entry <name>] [<examples-file>]";
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1).collect()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Runs the examples and reports whether all of them passed.
+fn run(args: Vec<String>) -> Result<bool, String> {
+    let mut args = args.into_iter();
+    let id = args.next().and_then(|id| id.parse().ok()).ok_or(USAGE)?;
+    let (mut entry, mut path) = (None, None);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--entry" => entry = Some(args.next().ok_or(USAGE)?),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let text = match &path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+        None => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|e| e.to_string())?;
+            text
+        }
+    };
+    if registry::by_id(id).is_some_and(|p| p.is_design()) {
+        return check_design(id, &text);
+    }
+
+    let cases = Case::parse_all(&text);
+    if cases.is_empty() {
+        return Err("no `Input:`/`Output:` examples found".to_string());
+    }
+
+    let outcomes = runner::check(id, entry.as_deref(), &cases).map_err(|e| e.to_string())?;
+    let passed = outcomes.iter().filter(|o| o.passed()).count();
+    for outcome in &outcomes {
+        print!("{outcome}");
+    }
+    println!("{passed}/{} passed", outcomes.len());
+    Ok(passed == outcomes.len())
+}
+
+/// Replays a design-problem script and reports whether every result matched.
+fn check_design(id: u32, text: &str) -> Result<bool, String> {
+    let script: Script = text
+        .parse()
+        .map_err(|e: replay::ReplayError| e.to_string())?;
+    let mismatches = replay::check_problem(id, &script).map_err(|e| e.to_string())?;
+    for mismatch in &mismatches {
+        println!("FAILED: {mismatch}");
+    }
+    let steps = script.operations.len();
+    println!("{}/{steps} operations matched", steps - mismatches.len());
+    Ok(mismatches.is_empty())
+}
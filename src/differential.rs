@@ -0,0 +1,285 @@
+//! Randomized differential testing: runs an optimized solution and a slow
+//! reference implementation on many small generated inputs and, when they
+//! disagree, shrinks the input to a minimal counterexample.
+//!
+//! ```
+//! use leetcode::differential;
+//! use leetcode::random::RandomSource;
+//!
+//! differential::check(
+//!     |rng| differential::vec_with(rng, 8, |rng| rng.in_range(-50..=50)),
+//!     |nums: &Vec<i32>| !nums.is_empty(),
+//!     |nums| nums.iter().copied().max(),
+//!     |nums| nums.iter().copied().reduce(i32::max),
+//! );
+//! ```
+//!
+//! Generation is seeded, so failures reproduce. The seed defaults to a fixed
+//! value and can be changed through the `LEETCODE_SEED` environment variable
+//! to explore other inputs.
+
+use std::env;
+use std::fmt::{self, Debug};
+
+use crate::random::{RandomSource, SeededRng};
+use crate::runner::catch_panic;
+
+/// Number of generated inputs each [`check`] runs.
+pub const CASES: usize = 2000;
+
+/// Seed used when `LEETCODE_SEED` is unset.
+pub const DEFAULT_SEED: u64 = 0x5eed;
+
+/// Upper bound on accepted shrink steps, as a guard against shrinkers that
+/// never reach a fixed point.
+const MAX_SHRINKS: usize = 10_000;
+
+/// Values that can propose smaller versions of themselves.
+pub trait Shrink: Sized {
+    /// Candidates strictly simpler than `self`, most aggressive first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_int {
+    ($($ty:ty),*) => {$(
+        impl Shrink for $ty {
+            /// Moves towards zero: zero itself, then half, then one step.
+            fn shrink(&self) -> Vec<Self> {
+                if *self == 0 {
+                    return Vec::new();
+                }
+                let step = if *self > 0 { *self - 1 } else { *self + 1 };
+                let mut out: Vec<Self> = Vec::new();
+                for candidate in [0, *self / 2, step] {
+                    if candidate != *self && !out.contains(&candidate) {
+                        out.push(candidate);
+                    }
+                }
+                out
+            }
+        }
+    )*};
+}
+
+impl_shrink_int!(i32, i64, usize);
+
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    /// Removes ever smaller chunks, then shrinks single elements.
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = Vec::new();
+        let mut size = self.len();
+        while size > 0 {
+            for start in (0..self.len()).step_by(size) {
+                let end = (start + size).min(self.len());
+                out.push([&self[..start], &self[end..]].concat());
+            }
+            size /= 2;
+        }
+        for (i, x) in self.iter().enumerate() {
+            for smaller in x.shrink() {
+                let mut v = self.clone();
+                v[i] = smaller;
+                out.push(v);
+            }
+        }
+        out
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let mut out: Vec<Self> = a.shrink().into_iter().map(|a| (a, b.clone())).collect();
+        out.extend(b.shrink().into_iter().map(|b| (a.clone(), b)));
+        out
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+        let mut out: Vec<Self> = a
+            .shrink()
+            .into_iter()
+            .map(|a| (a, b.clone(), c.clone()))
+            .collect();
+        out.extend(b.shrink().into_iter().map(|b| (a.clone(), b, c.clone())));
+        out.extend(c.shrink().into_iter().map(|c| (a.clone(), b.clone(), c)));
+        out
+    }
+}
+
+/// A vector of up to `max_len` elements drawn from `element`.
+pub fn vec_with<T>(
+    rng: &mut SeededRng,
+    max_len: usize,
+    mut element: impl FnMut(&mut SeededRng) -> T,
+) -> Vec<T> {
+    let len = rng.in_range(0..=max_len);
+    (0..len).map(|_| element(rng)).collect()
+}
+
+/// The seed from `LEETCODE_SEED`, or [`DEFAULT_SEED`].
+pub fn seed() -> u64 {
+    env::var("LEETCODE_SEED")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SEED)
+}
+
+/// A minimal input on which the two implementations disagree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counterexample<I, O> {
+    /// Seed the failing run was generated from.
+    pub seed: u64,
+    /// Shrunk input.
+    pub input: I,
+    /// Output of the optimized solution, or its panic message.
+    pub fast: Result<O, String>,
+    /// Output of the reference implementation.
+    pub oracle: O,
+}
+
+impl<I: Debug, O: Debug> fmt::Display for Counterexample<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "solution disagrees with the reference implementation")?;
+        writeln!(f, "  input:     {:?}", self.input)?;
+        match &self.fast {
+            Ok(fast) => writeln!(f, "  solution:  {fast:?}")?,
+            Err(message) => writeln!(f, "  solution:  panicked: {message}")?,
+        }
+        writeln!(f, "  reference: {:?}", self.oracle)?;
+        write!(f, "  seed:      LEETCODE_SEED={}", self.seed)
+    }
+}
+
+/// Runs `fast` and `oracle` on [`CASES`] inputs from `generate` and panics
+/// with the shrunk [`Counterexample`] if they ever disagree.
+///
+/// Inputs for which `valid` is false are skipped, both when generated and
+/// when proposed by the shrinker, so it should encode the problem's
+/// constraints. A panic in `fast`, such as an overflow or an out-of-bounds
+/// index, counts as a disagreement.
+pub fn check<I, O>(
+    generate: impl FnMut(&mut SeededRng) -> I,
+    valid: impl Fn(&I) -> bool,
+    fast: impl Fn(&I) -> O,
+    oracle: impl Fn(&I) -> O,
+) where
+    I: Shrink + Clone + Debug,
+    O: PartialEq + Debug,
+{
+    if let Some(counterexample) = find_counterexample(seed(), CASES, generate, valid, fast, oracle)
+    {
+        panic!("{counterexample}");
+    }
+}
+
+/// The search behind [`check`], returning the counterexample instead of
+/// panicking.
+pub fn find_counterexample<I, O>(
+    seed: u64,
+    cases: usize,
+    mut generate: impl FnMut(&mut SeededRng) -> I,
+    valid: impl Fn(&I) -> bool,
+    fast: impl Fn(&I) -> O,
+    oracle: impl Fn(&I) -> O,
+) -> Option<Counterexample<I, O>>
+where
+    I: Shrink + Clone + Debug,
+    O: PartialEq + Debug,
+{
+    let disagreement = |input: &I| {
+        let expected = oracle(input);
+        let actual = catch_panic(|| Ok(fast(input))).map_err(|e| e.to_string());
+        (actual.as_ref() != Ok(&expected)).then_some((actual, expected))
+    };
+
+    let mut rng = SeededRng::new(seed);
+    let mut input = (0..cases)
+        .map(|_| generate(&mut rng))
+        .filter(&valid)
+        .find(|input| disagreement(input).is_some())?;
+
+    for _ in 0..MAX_SHRINKS {
+        let smaller = input
+            .shrink()
+            .into_iter()
+            .find(|candidate| valid(candidate) && disagreement(candidate).is_some());
+        match smaller {
+            Some(smaller) => input = smaller,
+            None => break,
+        }
+    }
+
+    let (fast, oracle) = disagreement(&input).expect("shrinking keeps the disagreement");
+    Some(Counterexample {
+        seed,
+        input,
+        fast,
+        oracle,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_shrink_towards_zero() {
+        assert_eq!(10i32.shrink(), [0, 5, 9]);
+        assert_eq!((-3i64).shrink(), [0, -1, -2]);
+        assert_eq!(1usize.shrink(), [0]);
+        assert!(0i32.shrink().is_empty());
+    }
+
+    #[test]
+    fn vectors_drop_chunks_before_shrinking_elements() {
+        let shrunk = vec![4, 0].shrink();
+        assert_eq!(shrunk[0], Vec::<i32>::new());
+        assert!(shrunk.contains(&vec![0]));
+        assert!(shrunk.contains(&vec![2, 0]));
+        assert!(Vec::<i32>::new().shrink().is_empty());
+    }
+
+    #[test]
+    fn agreeing_implementations_pass() {
+        check(
+            |rng| vec_with(rng, 10, |rng| rng.in_range(-100..=100)),
+            |_: &Vec<i64>| true,
+            |v| v.iter().sum::<i64>(),
+            |v| v.iter().rev().sum::<i64>(),
+        );
+    }
+
+    #[test]
+    fn shrinks_to_a_minimal_counterexample() {
+        // Wrong whenever the vector holds a value of at least 7.
+        let found = find_counterexample(
+            1,
+            500,
+            |rng| vec_with(rng, 20, |rng| rng.in_range(0..=100)),
+            |_: &Vec<i32>| true,
+            |v| v.iter().filter(|&&x| x < 7).count(),
+            |v| v.len(),
+        )
+        .unwrap();
+        assert_eq!(found.input, [7]);
+        assert_eq!((found.fast, found.oracle), (Ok(0), 1));
+    }
+
+    #[test]
+    fn panics_count_as_disagreements() {
+        let found = find_counterexample(
+            1,
+            100,
+            |rng| vec_with(rng, 5, |rng| rng.in_range(1..=9)),
+            |_: &Vec<i32>| true,
+            |v| v[0],
+            |v| v.first().copied().unwrap_or(0),
+        )
+        .unwrap();
+        assert_eq!(found.input, []);
+        assert!(found.fast.unwrap_err().contains("index out of bounds"));
+    }
+}
@@ -0,0 +1,865 @@
+//! A text-editing buffer: a [`Rope`] plus selections and an undo history.
+//!
+//! Positions are char indices into the text, and every cursor movement and
+//! deletion steps over whole grapheme clusters, so a flag or an accented
+//! letter is never split. Any number of selections edit together; each one
+//! has an `anchor` where it started and a `head` where the cursor is.
+//!
+//! Edits coalesce into undo steps the way editors usually group typing: a run
+//! of insertions or of deletions with nothing in between undoes in one go,
+//! and a newline, a cursor movement or an explicit
+//! [`seal_undo_group`](Editor::seal_undo_group) starts a new step.
+//!
+//! ```
+//! use leetcode::editor::{Editor, Pattern};
+//!
+//! let mut editor = Editor::from("let x = 1;\nlet y = 2;\n");
+//! editor.select_all(&Pattern::literal("let"));
+//! editor.insert("const");
+//! assert_eq!(editor.to_string(), "const x = 1;\nconst y = 2;\n");
+//! assert_eq!(editor.line_col(editor.selections()[1].head), (1, 5));
+//!
+//! editor.undo();
+//! assert_eq!(editor.to_string(), "let x = 1;\nlet y = 2;\n");
+//! let digits = Pattern::regex(r"\d+").unwrap();
+//! assert_eq!(editor.replace_all(&digits, "0"), 2);
+//! assert_eq!(editor.to_string(), "let x = 0;\nlet y = 0;\n");
+//! ```
+
+use crate::grapheme;
+use crate::rope::Rope;
+use regex::Regex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Range;
+
+/// A selected range of text; empty when `anchor == head`, which is a plain
+/// cursor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Selection {
+    pub anchor: usize,
+    pub head: usize,
+}
+
+impl Selection {
+    /// A cursor at `at` with nothing selected.
+    pub fn cursor(at: usize) -> Self {
+        Self {
+            anchor: at,
+            head: at,
+        }
+    }
+
+    /// The selected chars, whichever way the selection was made.
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+
+    /// Whether nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.head
+    }
+}
+
+/// What to search for.
+#[derive(Clone, Debug)]
+pub enum Pattern {
+    Literal(Vec<char>),
+    Regex(Regex),
+}
+
+impl Pattern {
+    /// Matches `text` exactly.
+    pub fn literal(text: &str) -> Self {
+        Pattern::Literal(text.chars().collect())
+    }
+
+    /// Matches the regular expression `pattern`, in the syntax of the
+    /// `regex` crate.
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Pattern::Regex)
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Pattern::Literal(a), Pattern::Literal(b)) => a == b,
+            (Pattern::Regex(a), Pattern::Regex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Pattern {}
+
+/// One replacement of `removed` by `inserted` at char `at`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Change {
+    at: usize,
+    removed: String,
+    inserted: String,
+}
+
+/// Changes made together, sorted and disjoint, with `at` in the coordinates
+/// of the text before any of them.
+type Batch = Vec<Change>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    DeleteBackward,
+    DeleteForward,
+    Replace,
+}
+
+/// One undo step: batches applied in order, and the selections on either
+/// side of them.
+#[derive(Clone, Debug)]
+struct Transaction {
+    batches: Vec<Batch>,
+    before: Vec<Selection>,
+    after: Vec<Selection>,
+    /// The kind of edit later ones may coalesce with, or `None` once sealed.
+    open: Option<EditKind>,
+}
+
+#[derive(Clone, Debug)]
+struct History {
+    undo: VecDeque<Transaction>,
+    redo: Vec<Transaction>,
+    /// How many undo steps to keep; the oldest are dropped past it.
+    limit: usize,
+}
+
+impl History {
+    /// Pushes an undo step, dropping the oldest one past the limit.
+    fn record(&mut self, transaction: Transaction) {
+        self.undo.push_back(transaction);
+        if self.undo.len() > self.limit {
+            self.undo.pop_front();
+        }
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit: usize::MAX,
+        }
+    }
+}
+
+/// A text buffer with multiple selections, grapheme-aware editing, undo and
+/// search.
+///
+/// # Intuition
+/// Storing the text in a rope makes every edit and seek O(log n), so the
+/// cost of typing no longer depends on where the cursor is or how long the
+/// document has grown. Everything else is bookkeeping on char positions.
+///
+/// # Approach
+/// 1. Every edit becomes a batch of disjoint changes, one per selection,
+///    applied right to left so that earlier positions stay valid; the
+///    selections are then rebuilt left to right from the running length
+///    difference.
+/// 2. Grapheme boundaries come from a UAX #29 cursor fed the rope's chunks,
+///    so stepping over k clusters seeks once and then scans only those.
+/// 3. Undo replays the inverse of each batch in reverse. A batch joins the
+///    open transaction when it is the same kind of edit and the selections
+///    have not moved since.
+///
+/// # Complexity
+/// - Time: O(s (log n + c)) per edit for s selections of c chars of text;
+///   moves and deletes over k clusters cost O(s (log n + k))
+/// - Space: O(n) for the text plus the text of every change kept in the
+///   history, which [`with_history_limit`](Self::with_history_limit) caps
+#[derive(Clone, Debug)]
+pub struct Editor {
+    text: Rope,
+    /// Sorted and disjoint, never empty.
+    selections: Vec<Selection>,
+    history: History,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Editor {
+    /// An empty buffer with one cursor.
+    pub fn new() -> Self {
+        Self::from("")
+    }
+
+    /// Keeps at most `steps` undo steps, forgetting the oldest first; `0`
+    /// turns undo off.
+    #[must_use]
+    pub fn with_history_limit(mut self, steps: usize) -> Self {
+        self.history.limit = steps;
+        let excess = self.history.undo.len().saturating_sub(steps);
+        self.history.undo.drain(..excess);
+        self
+    }
+
+    /// The text.
+    pub fn text(&self) -> &Rope {
+        &self.text
+    }
+
+    /// Number of chars.
+    pub fn len_chars(&self) -> usize {
+        self.text.len_chars()
+    }
+
+    /// Whether the buffer holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The selections, sorted by position.
+    pub fn selections(&self) -> &[Selection] {
+        &self.selections
+    }
+
+    /// Replaces the selections, merging any that overlap.
+    ///
+    /// # Panics
+    /// Panics if `selections` is empty or reaches past the end of the text.
+    pub fn set_selections(&mut self, selections: Vec<Selection>) {
+        assert!(!selections.is_empty(), "an editor needs a selection");
+        let len = self.len_chars();
+        assert!(
+            selections.iter().all(|s| s.anchor.max(s.head) <= len),
+            "selection is out of bounds"
+        );
+        self.seal_undo_group();
+        self.selections = normalize(selections);
+    }
+
+    /// Ends the current undo step, so the next edit starts a new one.
+    pub fn seal_undo_group(&mut self) {
+        if let Some(last) = self.history.undo.back_mut() {
+            last.open = None;
+        }
+    }
+
+    /// Where the grapheme cluster containing char `at - 1` starts; `0` at the
+    /// start of the text.
+    pub fn prev_grapheme(&self, at: usize) -> usize {
+        grapheme::prev_boundary(&self.text, at)
+    }
+
+    /// Where the grapheme cluster after the one containing char `at` starts;
+    /// the end of the text at the end.
+    pub fn next_grapheme(&self, at: usize) -> usize {
+        grapheme::next_boundary(&self.text, at.min(self.len_chars()))
+    }
+
+    /// Where the grapheme cluster `count` clusters before the one containing
+    /// char `at - 1` starts, stopping at the start of the text.
+    pub fn skip_back(&self, at: usize, count: usize) -> usize {
+        grapheme::nth_prev_boundary(&self.text, at, count)
+    }
+
+    /// Where the grapheme cluster `count` clusters after the one containing
+    /// char `at` starts, stopping at the end of the text.
+    pub fn skip_forward(&self, at: usize, count: usize) -> usize {
+        grapheme::nth_next_boundary(&self.text, at.min(self.len_chars()), count)
+    }
+
+    /// Number of grapheme clusters starting within `range`.
+    pub fn graphemes_in(&self, range: Range<usize>) -> usize {
+        grapheme::count(&self.text, range)
+    }
+
+    /// Number of grapheme clusters in the whole text.
+    pub fn grapheme_count(&self) -> usize {
+        self.graphemes_in(0..self.len_chars())
+    }
+
+    /// Zero-based line and grapheme column of char `at`.
+    pub fn line_col(&self, at: usize) -> (usize, usize) {
+        let line = self.text.char_to_line(at);
+        (line, self.graphemes_in(self.text.line_to_char(line)..at))
+    }
+
+    /// The char position of grapheme column `col` on `line`, clamped to the
+    /// end of the line.
+    ///
+    /// # Panics
+    /// Panics if `line` is past the last line.
+    pub fn position(&self, line: usize, col: usize) -> usize {
+        let start = self.text.line_to_char(line);
+        let end = if line + 1 < self.text.len_lines() {
+            self.text.line_to_char(line + 1) - 1
+        } else {
+            self.len_chars()
+        };
+        self.skip_forward(start, col).min(end)
+    }
+
+    /// Moves every cursor `count` grapheme clusters left. Without `extend`
+    /// each selection collapses to a cursor; with it the anchors stay put.
+    pub fn move_left(&mut self, count: usize, extend: bool) {
+        self.move_heads(extend, |editor, head| editor.skip_back(head, count));
+    }
+
+    /// Moves every cursor `count` grapheme clusters right; see
+    /// [`move_left`](Self::move_left).
+    pub fn move_right(&mut self, count: usize, extend: bool) {
+        self.move_heads(extend, |editor, head| editor.skip_forward(head, count));
+    }
+
+    fn move_heads(&mut self, extend: bool, step: impl Fn(&Self, usize) -> usize) {
+        self.seal_undo_group();
+        let moved = self
+            .selections
+            .iter()
+            .map(|s| {
+                let head = step(self, s.head);
+                let anchor = if extend { s.anchor } else { head };
+                Selection { anchor, head }
+            })
+            .collect();
+        self.selections = normalize(moved);
+    }
+
+    /// Types `text` at every selection, replacing what is selected.
+    pub fn insert(&mut self, text: &str) {
+        let edits = self
+            .selections
+            .iter()
+            .map(|s| (s.range(), text.to_string()))
+            .collect();
+        self.edit(EditKind::Insert, edits);
+        if text.contains('\n') {
+            self.seal_undo_group();
+        }
+    }
+
+    /// Deletes the selected text, or `count` grapheme clusters before each
+    /// cursor, returning how many clusters were removed.
+    pub fn delete_backward(&mut self, count: usize) -> usize {
+        let ranges = self
+            .selections
+            .iter()
+            .map(|s| {
+                if s.is_empty() {
+                    self.skip_back(s.head, count)..s.head
+                } else {
+                    s.range()
+                }
+            })
+            .collect();
+        self.delete(EditKind::DeleteBackward, ranges)
+    }
+
+    /// Deletes the selected text, or `count` grapheme clusters after each
+    /// cursor, returning how many clusters were removed.
+    pub fn delete_forward(&mut self, count: usize) -> usize {
+        let ranges = self
+            .selections
+            .iter()
+            .map(|s| {
+                if s.is_empty() {
+                    s.head..self.skip_forward(s.head, count)
+                } else {
+                    s.range()
+                }
+            })
+            .collect();
+        self.delete(EditKind::DeleteForward, ranges)
+    }
+
+    fn delete(&mut self, kind: EditKind, ranges: Vec<Range<usize>>) -> usize {
+        let ranges = merge_ranges(ranges);
+        let removed = ranges.iter().map(|r| self.graphemes_in(r.clone())).sum();
+        if removed > 0 {
+            self.edit(
+                kind,
+                ranges.into_iter().map(|r| (r, String::new())).collect(),
+            );
+        }
+        removed
+    }
+
+    /// The first match of `pattern` starting at or after char `from`.
+    ///
+    /// Literals are searched in place; a regex search renders the text into
+    /// one string first, which costs O(n).
+    pub fn find(&self, pattern: &Pattern, from: usize) -> Option<Range<usize>> {
+        if from > self.len_chars() {
+            return None;
+        }
+        match pattern {
+            Pattern::Literal(needle) => {
+                let found = find_literal(needle, self.text.chars_from(from))?;
+                Some(found.start + from..found.end + from)
+            }
+            Pattern::Regex(regex) => {
+                let text = self.text.to_string();
+                let found = regex.find_at(&text, self.text.char_to_byte(from))?;
+                Some(self.char_range(found.range()))
+            }
+        }
+    }
+
+    /// Every non-overlapping match of `pattern`, left to right.
+    pub fn find_all(&self, pattern: &Pattern) -> Vec<Range<usize>> {
+        if let Pattern::Regex(regex) = pattern {
+            let text = self.text.to_string();
+            return regex
+                .find_iter(&text)
+                .map(|found| self.char_range(found.range()))
+                .collect();
+        }
+        let mut matches = Vec::new();
+        let mut from = 0;
+        while from <= self.len_chars() {
+            let Some(found) = self.find(pattern, from) else {
+                break;
+            };
+            // step past empty matches so the search moves on
+            from = found.end.max(found.start + 1);
+            matches.push(found);
+        }
+        matches
+    }
+
+    fn char_range(&self, bytes: Range<usize>) -> Range<usize> {
+        self.text.byte_to_char(bytes.start)..self.text.byte_to_char(bytes.end)
+    }
+
+    /// Selects every match of `pattern`, returning how many there are. The
+    /// selections are left alone when nothing matches.
+    pub fn select_all(&mut self, pattern: &Pattern) -> usize {
+        let matches = self.find_all(pattern);
+        if !matches.is_empty() {
+            self.set_selections(
+                matches
+                    .iter()
+                    .map(|m| Selection {
+                        anchor: m.start,
+                        head: m.end,
+                    })
+                    .collect(),
+            );
+        }
+        matches.len()
+    }
+
+    /// Replaces every match of `pattern` with `replacement` as a single undo
+    /// step, returning how many were replaced.
+    pub fn replace_all(&mut self, pattern: &Pattern, replacement: &str) -> usize {
+        let matches = self.find_all(pattern);
+        if !matches.is_empty() {
+            self.seal_undo_group();
+            let edits = matches
+                .iter()
+                .map(|m| (m.clone(), replacement.to_string()))
+                .collect();
+            self.edit(EditKind::Replace, edits);
+            self.seal_undo_group();
+        }
+        matches.len()
+    }
+
+    /// Reverts the last undo step, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        let Some(mut transaction) = self.history.undo.pop_back() else {
+            return false;
+        };
+        transaction.open = None;
+        for batch in transaction.batches.iter().rev() {
+            apply(&mut self.text, &invert(batch));
+        }
+        self.selections = transaction.before.clone();
+        self.history.redo.push(transaction);
+        // typing after an undo must not extend the step before it
+        self.seal_undo_group();
+        true
+    }
+
+    /// Reapplies the last undone step, returning whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(transaction) = self.history.redo.pop() else {
+            return false;
+        };
+        for batch in &transaction.batches {
+            apply(&mut self.text, batch);
+        }
+        self.selections = transaction.after.clone();
+        self.history.record(transaction);
+        true
+    }
+
+    /// Replaces each range with its text, leaving a cursor after each
+    /// insertion, and records the batch in the history.
+    fn edit(&mut self, kind: EditKind, edits: Vec<(Range<usize>, String)>) {
+        let batch: Batch = edits
+            .into_iter()
+            .map(|(range, inserted)| Change {
+                at: range.start,
+                removed: self.text.slice(range),
+                inserted,
+            })
+            .collect();
+        apply(&mut self.text, &batch);
+        let mut shift = 0isize;
+        let cursors = batch
+            .iter()
+            .map(|change| {
+                let inserted = change.inserted.chars().count();
+                let at = change.at.strict_add_signed(shift) + inserted;
+                shift += inserted as isize - change.removed.chars().count() as isize;
+                Selection::cursor(at)
+            })
+            .collect();
+        let before = std::mem::replace(&mut self.selections, normalize(cursors));
+        self.history.redo.clear();
+        match self.history.undo.back_mut() {
+            Some(last) if last.open == Some(kind) && last.after == before => {
+                last.batches.push(batch);
+                last.after = self.selections.clone();
+            }
+            _ => self.history.record(Transaction {
+                batches: vec![batch],
+                before,
+                after: self.selections.clone(),
+                open: Some(kind),
+            }),
+        }
+    }
+}
+
+/// Applies a batch right to left, so each change's position is unaffected by
+/// the ones not yet applied.
+fn apply(text: &mut Rope, batch: &Batch) {
+    for change in batch.iter().rev() {
+        text.remove(change.at..change.at + change.removed.chars().count());
+        text.insert(change.at, &change.inserted);
+    }
+}
+
+/// The batch that undoes `batch`, in the coordinates of the text after it.
+fn invert(batch: &Batch) -> Batch {
+    let mut shift = 0isize;
+    batch
+        .iter()
+        .map(|change| {
+            let at = change.at.strict_add_signed(shift);
+            shift +=
+                change.inserted.chars().count() as isize - change.removed.chars().count() as isize;
+            Change {
+                at,
+                removed: change.inserted.clone(),
+                inserted: change.removed.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Sorts selections and merges those that overlap or share a cursor.
+fn normalize(mut selections: Vec<Selection>) -> Vec<Selection> {
+    selections.sort_by_key(|s| (s.range().start, s.range().end));
+    let mut merged: Vec<Selection> = Vec::with_capacity(selections.len());
+    for s in selections {
+        match merged.last_mut() {
+            Some(last)
+                if s.range().start < last.range().end || s.range().start == last.range().start =>
+            {
+                let range = last.range().start..last.range().end.max(s.range().end);
+                *last = if last.anchor <= last.head {
+                    Selection {
+                        anchor: range.start,
+                        head: range.end,
+                    }
+                } else {
+                    Selection {
+                        anchor: range.end,
+                        head: range.start,
+                    }
+                };
+            }
+            _ => merged.push(s),
+        }
+    }
+    merged
+}
+
+/// Sorts ranges and merges those that overlap.
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for r in ranges {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+            _ => merged.push(r),
+        }
+    }
+    merged.retain(|r| !r.is_empty());
+    merged
+}
+
+/// The first occurrence of `needle` in `haystack`, by Knuth–Morris–Pratt.
+fn find_literal(needle: &[char], haystack: impl Iterator<Item = char>) -> Option<Range<usize>> {
+    if needle.is_empty() {
+        return Some(0..0);
+    }
+    let mut fail = vec![0; needle.len()];
+    let mut k = 0;
+    for (i, &c) in needle.iter().enumerate().skip(1) {
+        while k > 0 && c != needle[k] {
+            k = fail[k - 1];
+        }
+        if c == needle[k] {
+            k += 1;
+        }
+        fail[i] = k;
+    }
+    let mut matched = 0;
+    for (i, c) in haystack.enumerate() {
+        while matched > 0 && c != needle[matched] {
+            matched = fail[matched - 1];
+        }
+        if c == needle[matched] {
+            matched += 1;
+        }
+        if matched == needle.len() {
+            return Some(i + 1 - needle.len()..i + 1);
+        }
+    }
+    None
+}
+
+impl From<&str> for Editor {
+    /// A buffer holding `text` with a cursor at the start.
+    fn from(text: &str) -> Self {
+        Self {
+            text: Rope::from(text),
+            selections: vec![Selection::cursor(0)],
+            history: History::default(),
+        }
+    }
+}
+
+/// Buffers are equal when their text and selections are; history is ignored.
+impl PartialEq for Editor {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.selections == other.selections
+    }
+}
+
+impl Eq for Editor {}
+
+impl fmt::Display for Editor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.text, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    fn heads(editor: &Editor) -> Vec<usize> {
+        editor.selections().iter().map(|s| s.head).collect()
+    }
+
+    #[test]
+    fn steps_over_whole_clusters() {
+        // e + combining acute, a flag, a family emoji, CRLF
+        let mut editor = Editor::from("e\u{301}🇫🇷👨‍👩‍👧\r\nz");
+        assert_eq!(editor.grapheme_count(), 5);
+        editor.move_right(2, false);
+        assert_eq!(heads(&editor), [4]);
+        editor.move_right(1, false);
+        assert_eq!(heads(&editor), [9]);
+        assert_eq!(editor.prev_grapheme(9), 4);
+        assert_eq!(editor.next_grapheme(5), 9);
+        assert_eq!(editor.delete_backward(2), 2);
+        assert_eq!(editor.to_string(), "e\u{301}\r\nz");
+        assert_eq!(editor.delete_forward(1), 1);
+        assert_eq!(editor.to_string(), "e\u{301}z");
+        assert_eq!(editor.line_col(2), (0, 1));
+    }
+
+    #[test]
+    fn line_and_column_addressing() {
+        let editor = Editor::from("ab\nc\u{301}d\n\nlast");
+        assert_eq!(editor.line_col(0), (0, 0));
+        assert_eq!(editor.line_col(2), (0, 2));
+        assert_eq!(editor.line_col(6), (1, 2));
+        assert_eq!(editor.line_col(8), (3, 0));
+        assert_eq!(editor.position(1, 1), 5);
+        assert_eq!(editor.position(1, 9), 6);
+        assert_eq!(editor.position(2, 3), 7);
+        assert_eq!(editor.position(3, 4), 12);
+    }
+
+    #[test]
+    fn multiple_cursors_edit_together() {
+        let mut editor = Editor::from("one two three");
+        editor.set_selections(vec![
+            Selection::cursor(3),
+            Selection::cursor(7),
+            Selection::cursor(13),
+        ]);
+        editor.insert("!");
+        assert_eq!(editor.to_string(), "one! two! three!");
+        assert_eq!(heads(&editor), [4, 9, 16]);
+        editor.move_left(1, true);
+        editor.insert("?");
+        assert_eq!(editor.to_string(), "one? two? three?");
+        // deleting into each other merges the first two cursors
+        assert_eq!(editor.delete_backward(5), 14);
+        assert_eq!(editor.to_string(), " t");
+        assert_eq!(heads(&editor), [0, 2]);
+    }
+
+    #[test]
+    fn undo_groups_typing_and_redo_restores() {
+        let mut editor = Editor::new();
+        for c in ["a", "b", "c"] {
+            editor.insert(c);
+        }
+        editor.insert(" ");
+        editor.delete_backward(1);
+        editor.delete_backward(1);
+        assert_eq!(editor.to_string(), "ab");
+        editor.insert("\n");
+        editor.insert("x");
+        for expected in ["ab\n", "ab", "abc ", ""] {
+            assert!(editor.undo());
+            assert_eq!(editor.to_string(), expected);
+        }
+        assert!(!editor.undo());
+        for _ in 0..3 {
+            assert!(editor.redo());
+        }
+        assert_eq!(editor.to_string(), "ab\n");
+        assert_eq!(heads(&editor), [3]);
+        editor.move_left(1, false);
+        editor.insert("y");
+        assert!(!editor.redo());
+        editor.insert("z");
+        editor.undo();
+        assert_eq!(editor.to_string(), "ab\n");
+    }
+
+    #[test]
+    fn history_limit_forgets_the_oldest_steps() {
+        let mut editor = Editor::new().with_history_limit(2);
+        for word in ["a\n", "b\n", "c\n"] {
+            editor.insert(word);
+        }
+        assert!(editor.undo());
+        assert!(editor.redo());
+        assert!(editor.undo());
+        assert!(editor.undo());
+        assert!(!editor.undo());
+        assert_eq!(editor.to_string(), "a\n");
+
+        let mut editor = Editor::from("abc").with_history_limit(0);
+        editor.move_right(3, false);
+        editor.delete_backward(1);
+        editor.insert("d");
+        assert!(!editor.undo());
+        assert_eq!(editor.to_string(), "abd");
+    }
+
+    #[test]
+    fn search_and_replace() {
+        let mut editor = Editor::from("abab aba\nbab");
+        let literal = Pattern::literal("aba");
+        assert_eq!(editor.find(&literal, 0), Some(0..3));
+        assert_eq!(editor.find(&literal, 1), Some(5..8));
+        assert_eq!(editor.find_all(&literal), [0..3, 5..8]);
+        let word = Pattern::regex(r"\bb\w*").unwrap();
+        assert_eq!(editor.find(&word, 1), Some(9..12));
+        assert_eq!(editor.find_all(&Pattern::regex("x*").unwrap()).len(), 13);
+        assert_eq!(
+            editor.replace_all(&Pattern::regex("^a|b$").unwrap(), "_"),
+            2
+        );
+        assert_eq!(editor.to_string(), "_bab aba\nba_");
+        editor.undo();
+        assert_eq!(editor.to_string(), "abab aba\nbab");
+        assert_eq!(editor.select_all(&Pattern::literal("q")), 0);
+        assert_eq!(editor.select_all(&Pattern::literal("ab")), 4);
+        assert_eq!(editor.delete_forward(1), 8);
+        assert_eq!(editor.to_string(), " a\nb");
+    }
+
+    #[test]
+    fn matches_naive_buffer_with_undo() {
+        let mut rng = SeededRng::new(2296);
+        let alphabet = ['a', 'b', '\n', 'é', '🙂'];
+        let mut editor = Editor::new();
+        let mut text: Vec<char> = Vec::new();
+        let mut cursor = 0;
+        let mut snapshots = vec![String::new()];
+        for _ in 0..3000 {
+            match rng.in_range(0..10) {
+                0..=3 => {
+                    let typed: String = (0..rng.in_range(1..4))
+                        .map(|_| alphabet[rng.in_range(0..alphabet.len())])
+                        .collect();
+                    editor.insert(&typed);
+                    text.splice(cursor..cursor, typed.chars());
+                    cursor += typed.chars().count();
+                }
+                4 | 5 => {
+                    let k = rng.in_range(0..4);
+                    let removed = editor.delete_backward(k);
+                    let start = cursor.saturating_sub(k);
+                    assert_eq!(removed, cursor - start);
+                    text.drain(start..cursor);
+                    cursor = start;
+                }
+                6 => {
+                    let k = rng.in_range(0..4);
+                    editor.move_left(k, false);
+                    cursor = cursor.saturating_sub(k);
+                }
+                7 => {
+                    let k = rng.in_range(0..4);
+                    editor.move_right(k, false);
+                    cursor = (cursor + k).min(text.len());
+                }
+                8 => {
+                    let (line, col) = editor.line_col(cursor);
+                    let line_start = text[..cursor]
+                        .iter()
+                        .rposition(|&c| c == '\n')
+                        .map_or(0, |i| i + 1);
+                    assert_eq!(line, text[..cursor].iter().filter(|&&c| c == '\n').count());
+                    assert_eq!(col, cursor - line_start);
+                    assert_eq!(editor.position(line, col), cursor);
+                }
+                _ => {
+                    editor.seal_undo_group();
+                    snapshots.push(editor.to_string());
+                    continue;
+                }
+            }
+            assert_eq!(editor.to_string(), text.iter().collect::<String>());
+            assert_eq!(heads(&editor), [cursor]);
+        }
+        // every sealed state is reachable by undo, in reverse order
+        let mut seen = vec![editor.to_string()];
+        while editor.undo() {
+            seen.push(editor.to_string());
+        }
+        assert_eq!(seen.last().map(String::as_str), Some(""));
+        assert!(snapshots.iter().all(|s| seen.contains(s)));
+        while editor.redo() {}
+        assert_eq!(editor.to_string(), text.iter().collect::<String>());
+    }
+}
@@ -0,0 +1,621 @@
+//! Fenwick (binary indexed) trees over commutative monoids and groups.
+//!
+//! [`Fenwick`] supports point updates with prefix aggregates and, over a
+//! [`Group`], range sums and order statistics. [`DifferenceFenwick`] adds a
+//! value to whole ranges and reads single elements, [`RangeFenwick`] does
+//! both range updates and range sums, and [`Fenwick2D`] sums rectangles.
+//!
+//! ```
+//! use leetcode::fenwick::Fenwick;
+//!
+//! let mut counts: Fenwick<i64> = Fenwick::new(6);
+//! for value in [4, 1, 4, 2] {
+//!     counts.add(value, 1);
+//! }
+//! assert_eq!(counts.prefix(3), 2); // values below 3
+//! assert_eq!(counts.sum(2..=4), 3);
+//! assert_eq!(counts.kth(3), 4); // the third smallest value
+//! ```
+//!
+//! Besides the primitive numbers, pairs of them and [`Xor`] are groups. A
+//! monoid without inverses, such as [`Max`], still gives prefix aggregates:
+//!
+//! ```
+//! use leetcode::fenwick::Fenwick;
+//! use leetcode::segment_tree::Max;
+//!
+//! let mut best: Fenwick<Max<i32>> = Fenwick::new(4);
+//! best.add(1, Max(7));
+//! best.add(3, Max(9));
+//! assert_eq!(best.prefix(3), Max(7));
+//! ```
+
+use std::ops::{Bound, RangeBounds};
+
+pub use crate::segment_tree::Max;
+
+/// A commutative monoid, written additively. Unlike
+/// [`segment_tree::Monoid`](crate::segment_tree::Monoid), operands may be
+/// combined in any order, since prefix queries add up nodes out of sequence.
+pub trait CommutativeMonoid: Copy {
+    /// The identity element.
+    fn zero() -> Self;
+    /// The commutative, associative operation.
+    fn plus(self, rhs: Self) -> Self;
+    /// `self` added to itself `n` times.
+    fn times(self, mut n: usize) -> Self {
+        let (mut base, mut acc) = (self, Self::zero());
+        while n > 0 {
+            if n & 1 == 1 {
+                acc = acc.plus(base);
+            }
+            base = base.plus(base);
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+/// A commutative group, written additively.
+pub trait Group: CommutativeMonoid {
+    /// The inverse of [`CommutativeMonoid::plus`].
+    fn minus(self, rhs: Self) -> Self;
+    /// The inverse of `self`.
+    fn neg(self) -> Self {
+        Self::zero().minus(self)
+    }
+}
+
+macro_rules! impl_group {
+    ($($ty:ty),*) => {$(
+        impl CommutativeMonoid for $ty {
+            fn zero() -> Self {
+                <$ty>::default()
+            }
+            fn plus(self, rhs: Self) -> Self {
+                self + rhs
+            }
+            fn times(self, n: usize) -> Self {
+                self * n as $ty
+            }
+        }
+
+        impl Group for $ty {
+            fn minus(self, rhs: Self) -> Self {
+                self - rhs
+            }
+        }
+    )*};
+}
+
+impl_group!(i32, i64, isize, f64);
+
+macro_rules! impl_wrapping_group {
+    ($($ty:ty),*) => {$(
+        /// Arithmetic modulo `2^BITS`, so a difference may pass below zero on
+        /// the way to a result that is in range.
+        impl CommutativeMonoid for $ty {
+            fn zero() -> Self {
+                0
+            }
+            fn plus(self, rhs: Self) -> Self {
+                self.wrapping_add(rhs)
+            }
+            fn times(self, n: usize) -> Self {
+                self.wrapping_mul(n as $ty)
+            }
+        }
+
+        impl Group for $ty {
+            fn minus(self, rhs: Self) -> Self {
+                self.wrapping_sub(rhs)
+            }
+        }
+    )*};
+}
+
+impl_wrapping_group!(u32, u64, usize);
+
+/// Pairs add componentwise, so one tree can track, say, counts and totals.
+impl<A: CommutativeMonoid, B: CommutativeMonoid> CommutativeMonoid for (A, B) {
+    fn zero() -> Self {
+        (A::zero(), B::zero())
+    }
+    fn plus(self, rhs: Self) -> Self {
+        (self.0.plus(rhs.0), self.1.plus(rhs.1))
+    }
+}
+
+impl<A: Group, B: Group> Group for (A, B) {
+    fn minus(self, rhs: Self) -> Self {
+        (self.0.minus(rhs.0), self.1.minus(rhs.1))
+    }
+}
+
+macro_rules! impl_max {
+    ($($ty:ty),*) => {$(
+        /// Prefix maxima; a monoid but not a group.
+        impl CommutativeMonoid for Max<$ty> {
+            fn zero() -> Self {
+                Max(<$ty>::MIN)
+            }
+            fn plus(self, rhs: Self) -> Self {
+                self.max(rhs)
+            }
+        }
+    )*};
+}
+
+impl_max!(i32, i64);
+
+/// Bitwise xor, a group in which every element is its own inverse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Xor<T>(pub T);
+
+macro_rules! impl_xor {
+    ($($ty:ty),*) => {$(
+        impl CommutativeMonoid for Xor<$ty> {
+            fn zero() -> Self {
+                Xor(0)
+            }
+            fn plus(self, rhs: Self) -> Self {
+                Xor(self.0 ^ rhs.0)
+            }
+            fn times(self, n: usize) -> Self {
+                if n % 2 == 1 { self } else { Self::zero() }
+            }
+        }
+
+        impl Group for Xor<$ty> {
+            fn minus(self, rhs: Self) -> Self {
+                self.plus(rhs)
+            }
+            fn neg(self) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+impl_xor!(u32, u64, usize);
+
+/// Point updates and prefix aggregates over `0..n` in O(log n).
+#[derive(Clone, Debug)]
+pub struct Fenwick<T> {
+    /// One-based: node `i` covers the `i & i.wrapping_neg()` elements
+    /// ending at element `i - 1`.
+    tree: Vec<T>,
+}
+
+impl<T: CommutativeMonoid> Fenwick<T> {
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            tree: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to element `i`.
+    pub fn add(&mut self, i: usize, delta: T) {
+        assert!(
+            i < self.len(),
+            "index {i} out of range for length {}",
+            self.len()
+        );
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i].plus(delta);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the first `end` elements.
+    pub fn prefix(&self, end: usize) -> T {
+        assert!(
+            end <= self.len(),
+            "prefix {end} out of range for length {}",
+            self.len()
+        );
+        let mut i = end;
+        let mut acc = T::zero();
+        while i > 0 {
+            acc = acc.plus(self.tree[i]);
+            i &= i - 1;
+        }
+        acc
+    }
+}
+
+impl<T: Group> Fenwick<T> {
+    /// Sum of the elements in `range`.
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = bounds(range, self.len());
+        self.prefix(r).minus(self.prefix(l))
+    }
+
+    /// Element `i`.
+    pub fn get(&self, i: usize) -> T {
+        self.sum(i..=i)
+    }
+
+    /// Replaces element `i`.
+    pub fn set(&mut self, i: usize, value: T) {
+        let delta = value.minus(self.get(i));
+        self.add(i, delta);
+    }
+}
+
+impl<T: Group + PartialOrd> Fenwick<T> {
+    /// The smallest `i` such that the first `i + 1` elements sum to at least
+    /// `k`, or the length when even the total falls short. Assumes no element
+    /// is negative; with counts as elements this is the `k`-th smallest key.
+    pub fn kth(&self, k: T) -> usize {
+        let mut step = match self.len() {
+            0 => 0,
+            n => 1 << n.ilog2(),
+        };
+        let (mut i, mut acc) = (0, T::zero());
+        while step > 0 {
+            let next = i + step;
+            if next < self.tree.len() && acc.plus(self.tree[next]) < k {
+                acc = acc.plus(self.tree[next]);
+                i = next;
+            }
+            step >>= 1;
+        }
+        i
+    }
+}
+
+impl<T: CommutativeMonoid> From<Vec<T>> for Fenwick<T> {
+    /// Builds the tree in O(n).
+    fn from(values: Vec<T>) -> Self {
+        let mut tree = Vec::with_capacity(values.len() + 1);
+        tree.push(T::zero());
+        tree.extend(values);
+        for i in 1..tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent < tree.len() {
+                tree[parent] = tree[parent].plus(tree[i]);
+            }
+        }
+        Self { tree }
+    }
+}
+
+/// Range updates and point queries over `0..n` in O(log n), as a
+/// [`Fenwick`] over the differences of adjacent elements.
+#[derive(Clone, Debug)]
+pub struct DifferenceFenwick<T> {
+    diff: Fenwick<T>,
+}
+
+impl<T: Group> DifferenceFenwick<T> {
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            diff: Fenwick::new(n + 1),
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to every element in `range`.
+    pub fn add(&mut self, range: impl RangeBounds<usize>, delta: T) {
+        let (l, r) = bounds(range, self.len());
+        self.diff.add(l, delta);
+        self.diff.add(r, delta.neg());
+    }
+
+    /// Element `i`.
+    pub fn get(&self, i: usize) -> T {
+        assert!(
+            i < self.len(),
+            "index {i} out of range for length {}",
+            self.len()
+        );
+        self.diff.prefix(i + 1)
+    }
+}
+
+/// Range updates and range sums over `0..n` in O(log n).
+#[derive(Clone, Debug)]
+pub struct RangeFenwick<T> {
+    /// Differences `d[i]`, so element `i` is the sum of `d[..=i]`.
+    diff: Fenwick<T>,
+    /// `d[i]` scaled by `i`, to correct prefix sums of `diff`.
+    scaled: Fenwick<T>,
+}
+
+impl<T: Group> RangeFenwick<T> {
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            diff: Fenwick::new(n + 1),
+            scaled: Fenwick::new(n + 1),
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to every element in `range`.
+    pub fn add(&mut self, range: impl RangeBounds<usize>, delta: T) {
+        let (l, r) = bounds(range, self.len());
+        self.diff.add(l, delta);
+        self.diff.add(r, delta.neg());
+        self.scaled.add(l, delta.times(l));
+        self.scaled.add(r, delta.times(r).neg());
+    }
+
+    /// Sum of the first `end` elements.
+    pub fn prefix(&self, end: usize) -> T {
+        // Each d[i] with i < end reaches the (end - i) elements i..end.
+        self.diff
+            .prefix(end)
+            .times(end)
+            .minus(self.scaled.prefix(end))
+    }
+
+    /// Sum of the elements in `range`.
+    pub fn sum(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = bounds(range, self.len());
+        self.prefix(r).minus(self.prefix(l))
+    }
+}
+
+/// Point updates and rectangle sums over a `rows × cols` grid in
+/// O(log rows · log cols).
+#[derive(Clone, Debug)]
+pub struct Fenwick2D<T> {
+    rows: usize,
+    cols: usize,
+    tree: Vec<Vec<T>>,
+}
+
+impl<T: CommutativeMonoid> Fenwick2D<T> {
+    /// A `rows × cols` grid of zeros.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            tree: vec![vec![T::zero(); cols + 1]; rows + 1],
+        }
+    }
+
+    /// Number of rows and columns.
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Adds `delta` to cell `(row, col)`.
+    pub fn add(&mut self, row: usize, col: usize, delta: T) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "cell ({row}, {col}) out of range for {}×{}",
+            self.rows,
+            self.cols
+        );
+        let mut i = row + 1;
+        while i <= self.rows {
+            let mut j = col + 1;
+            while j <= self.cols {
+                self.tree[i][j] = self.tree[i][j].plus(delta);
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the cells in the first `rows` rows and first `cols` columns.
+    pub fn prefix(&self, rows: usize, cols: usize) -> T {
+        assert!(
+            rows <= self.rows && cols <= self.cols,
+            "prefix {rows}×{cols} out of range for {}×{}",
+            self.rows,
+            self.cols
+        );
+        let mut acc = T::zero();
+        let mut i = rows;
+        while i > 0 {
+            let mut j = cols;
+            while j > 0 {
+                acc = acc.plus(self.tree[i][j]);
+                j &= j - 1;
+            }
+            i &= i - 1;
+        }
+        acc
+    }
+}
+
+impl<T: Group> Fenwick2D<T> {
+    /// Sum of the cells in `rows × cols`.
+    pub fn sum(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> T {
+        let (top, bottom) = bounds(rows, self.rows);
+        let (left, right) = bounds(cols, self.cols);
+        self.prefix(bottom, right)
+            .minus(self.prefix(top, right))
+            .minus(self.prefix(bottom, left))
+            .plus(self.prefix(top, left))
+    }
+}
+
+/// Half-open bounds of `range` within `0..len`.
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        Bound::Included(&l) => l,
+        Bound::Excluded(&l) => l + 1,
+        Bound::Unbounded => 0,
+    };
+    let r = match range.end_bound() {
+        Bound::Included(&r) => r + 1,
+        Bound::Excluded(&r) => r,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        l <= r && r <= len,
+        "range {l}..{r} out of range for length {len}"
+    );
+    (l, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    fn range(rng: &mut SeededRng, n: usize) -> (usize, usize) {
+        let l = rng.in_range(0..=n);
+        (l, rng.in_range(l..=n))
+    }
+
+    #[test]
+    fn point_updates_match_naive() {
+        let mut rng = SeededRng::new(3);
+        for n in 0..20 {
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.in_range(-9..=9)).collect();
+            let mut tree = Fenwick::from(naive.clone());
+            for _ in 0..100 {
+                if n > 0 {
+                    let (i, x) = (rng.in_range(0..n), rng.in_range(-9..=9));
+                    naive[i] += x;
+                    tree.add(i, x);
+                    let (j, y) = (rng.in_range(0..n), rng.in_range(-9..=9));
+                    naive[j] = y;
+                    tree.set(j, y);
+                }
+                let (l, r) = range(&mut rng, n);
+                assert_eq!(tree.sum(l..r), naive[l..r].iter().sum::<i64>());
+            }
+        }
+    }
+
+    #[test]
+    fn kth_finds_order_statistics() {
+        let mut rng = SeededRng::new(5);
+        for n in 1..20 {
+            let counts: Vec<i32> = (0..n).map(|_| rng.in_range(0..3)).collect();
+            let tree = Fenwick::from(counts.clone());
+            let total: i32 = counts.iter().sum();
+            for k in 1..=total + 1 {
+                let expected = (0..n)
+                    .find(|&i| counts[..=i].iter().sum::<i32>() >= k)
+                    .unwrap_or(n);
+                assert_eq!(tree.kth(k), expected, "counts {counts:?}, k {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn range_updates_match_naive() {
+        let mut rng = SeededRng::new(8);
+        for n in 0..20 {
+            let mut naive = vec![0i64; n];
+            let mut points = DifferenceFenwick::new(n);
+            let mut ranges = RangeFenwick::new(n);
+            for _ in 0..100 {
+                let (l, r) = range(&mut rng, n);
+                let x = rng.in_range(-9..=9);
+                naive[l..r].iter_mut().for_each(|v| *v += x);
+                points.add(l..r, x);
+                ranges.add(l..r, x);
+                let (l, r) = range(&mut rng, n);
+                assert_eq!(ranges.sum(l..r), naive[l..r].iter().sum::<i64>());
+            }
+            for (i, &v) in naive.iter().enumerate() {
+                assert_eq!(points.get(i), v);
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned_elements_wrap_back_into_range() {
+        let mut points: DifferenceFenwick<u32> = DifferenceFenwick::new(4);
+        points.add(0..2, 5);
+        points.add(1.., 3);
+        assert_eq!(
+            (0..4).map(|i| points.get(i)).collect::<Vec<_>>(),
+            [5, 8, 3, 3]
+        );
+        let mut ranges: RangeFenwick<usize> = RangeFenwick::new(5);
+        ranges.add(1..3, 4);
+        ranges.add(2..5, 1);
+        assert_eq!(
+            (ranges.sum(..), ranges.sum(2..4), ranges.sum(4..)),
+            (11, 6, 1)
+        );
+        let mut counts: Fenwick<u64> = Fenwick::from(vec![3, 1, 4]);
+        counts.set(0, 1);
+        counts.set(2, 0);
+        assert_eq!((counts.sum(..), counts.get(0), counts.kth(2)), (2, 1, 1));
+    }
+
+    #[test]
+    fn rectangle_sums_match_naive() {
+        let mut rng = SeededRng::new(13);
+        let (rows, cols) = (6, 9);
+        let mut naive = vec![vec![0i32; cols]; rows];
+        let mut tree = Fenwick2D::new(rows, cols);
+        for _ in 0..200 {
+            let (i, j, x) = (
+                rng.in_range(0..rows),
+                rng.in_range(0..cols),
+                rng.in_range(-5..=5),
+            );
+            naive[i][j] += x;
+            tree.add(i, j, x);
+            let (top, bottom) = range(&mut rng, rows);
+            let (left, right) = range(&mut rng, cols);
+            let expected: i32 = naive[top..bottom]
+                .iter()
+                .map(|row| row[left..right].iter().sum::<i32>())
+                .sum();
+            assert_eq!(tree.sum(top..bottom, left..right), expected);
+        }
+    }
+
+    #[test]
+    fn pairs_maxima_and_xor() {
+        let mut pairs: Fenwick<(i64, i64)> = Fenwick::new(5);
+        pairs.add(2, (1, 2));
+        pairs.add(4, (1, 4));
+        assert_eq!(pairs.sum(1..), (2, 6));
+        let mut best: Fenwick<Max<i64>> = Fenwick::new(3);
+        assert_eq!(best.prefix(3), Max(i64::MIN));
+        best.add(0, Max(-4));
+        best.add(2, Max(1));
+        assert_eq!((best.prefix(2), best.prefix(3)), (Max(-4), Max(1)));
+        assert_eq!((3i64.times(4), Max(3i64).times(4)), (12, Max(3)));
+        let mut parity: DifferenceFenwick<Xor<u32>> = DifferenceFenwick::new(4);
+        parity.add(1..3, Xor(0b101));
+        parity.add(2.., Xor(0b110));
+        assert_eq!(parity.get(2), Xor(0b011));
+        assert_eq!((parity.get(0), parity.get(3)), (Xor(0), Xor(0b110)));
+    }
+}
@@ -0,0 +1,669 @@
+//! Graphs in compressed sparse row form with a shared shortest-path engine.
+//!
+//! A [`Graph`] stores every node's outgoing edges in one contiguous slice.
+//! Searches from one source return [`ShortestPaths`]: a distance per node
+//! plus the predecessor tree, from which [`ShortestPaths::path_to`] rebuilds
+//! routes. Edge weights only need to implement [`Weight`]: integers add up
+//! along a path, while [`Bottleneck`] keeps the largest edge seen, which
+//! turns the same Dijkstra into a minimax search.
+//!
+//! ```
+//! use leetcode::graph::Graph;
+//!
+//! let graph = Graph::directed(4, [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5)]);
+//! let paths = graph.dijkstra(0);
+//! assert_eq!(paths.dist(3), Some(8));
+//! assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+//! assert_eq!(graph.bfs(0).dist(3), Some(2));
+//!
+//! let mut all = graph.floyd_warshall();
+//! assert_eq!(all.dist(2, 3), Some(7));
+//! all.add_edge(2, 3, 1);
+//! assert_eq!(all.path(0, 3), Some(vec![0, 2, 3]));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+/// A path weight: edges extend a path starting from [`Weight::ZERO`], and
+/// extending never makes a path lighter when weights are non-negative.
+pub trait Weight: Copy + Ord {
+    /// Weight of the empty path.
+    const ZERO: Self;
+
+    /// Weight of a path of weight `self` followed by one of weight `next`.
+    fn extend(self, next: Self) -> Self;
+}
+
+macro_rules! impl_weight {
+    ($($ty:ty),*) => {$(
+        impl Weight for $ty {
+            const ZERO: Self = 0;
+
+            fn extend(self, next: Self) -> Self {
+                self + next
+            }
+        }
+    )*};
+}
+
+impl_weight!(i32, i64, u32, u64, usize);
+
+/// A path weighed by its heaviest edge, for minimax problems.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bottleneck<T>(pub T);
+
+macro_rules! impl_bottleneck {
+    ($($ty:ty),*) => {$(
+        impl Weight for Bottleneck<$ty> {
+            const ZERO: Self = Bottleneck(<$ty>::MIN);
+
+            fn extend(self, next: Self) -> Self {
+                self.max(next)
+            }
+        }
+    )*};
+}
+
+impl_bottleneck!(i32, i64, u32, u64, usize);
+
+/// A graph on nodes `0..n` whose edges carry weights of type `W`.
+///
+/// # Complexity
+/// - Time: O(n + m) to build
+/// - Space: O(n + m); undirected edges are stored once in each direction
+#[derive(Clone, Debug)]
+pub struct Graph<W = ()> {
+    directed: bool,
+    /// Node `u`'s edges are `edges[offsets[u]..offsets[u + 1]]`.
+    offsets: Vec<usize>,
+    edges: Vec<(usize, W)>,
+}
+
+impl<W: Copy> Graph<W> {
+    /// A directed graph with an edge `u → v` for each `(u, v, w)`.
+    pub fn directed(n: usize, edges: impl IntoIterator<Item = (usize, usize, W)>) -> Self {
+        let arcs: Vec<_> = edges.into_iter().collect();
+        Self::from_arcs(n, arcs, true)
+    }
+
+    /// An undirected graph with an edge `u — v` for each `(u, v, w)`.
+    pub fn undirected(n: usize, edges: impl IntoIterator<Item = (usize, usize, W)>) -> Self {
+        let arcs: Vec<_> = edges
+            .into_iter()
+            .flat_map(|(u, v, w)| [(u, v, w), (v, u, w)])
+            .collect();
+        Self::from_arcs(n, arcs, false)
+    }
+
+    /// Lays the arcs out by source with a counting sort, keeping their order.
+    fn from_arcs(n: usize, arcs: Vec<(usize, usize, W)>, directed: bool) -> Self {
+        let mut offsets = vec![0; n + 1];
+        for &(u, _, _) in &arcs {
+            offsets[u + 1] += 1;
+        }
+        for u in 0..n {
+            offsets[u + 1] += offsets[u];
+        }
+        let mut next = offsets.clone();
+        let mut slots = vec![None; arcs.len()];
+        for (u, v, w) in arcs {
+            slots[next[u]] = Some((v, w));
+            next[u] += 1;
+        }
+        Self {
+            directed,
+            offsets,
+            edges: slots.into_iter().map(Option::unwrap).collect(),
+        }
+    }
+
+    /// Number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Number of edges, counting an undirected edge once.
+    pub fn edge_count(&self) -> usize {
+        if self.directed {
+            self.edges.len()
+        } else {
+            self.edges.len() / 2
+        }
+    }
+
+    /// Whether edges only lead from their first node to their second.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The nodes `u` has an edge to, with the edge weights.
+    pub fn neighbors(&self, u: usize) -> &[(usize, W)] {
+        &self.edges[self.offsets[u]..self.offsets[u + 1]]
+    }
+
+    /// Every edge as `(from, to, weight)`, in both directions when undirected.
+    fn arcs(&self) -> impl Iterator<Item = (usize, usize, W)> + '_ {
+        (0..self.node_count())
+            .flat_map(move |u| self.neighbors(u).iter().map(move |&(v, w)| (u, v, w)))
+    }
+
+    /// The graph with every edge turned around; undirected graphs are their
+    /// own reverse.
+    pub fn reversed(&self) -> Self {
+        let arcs = self.arcs().map(|(u, v, w)| (v, u, w)).collect();
+        Self::from_arcs(self.node_count(), arcs, self.directed)
+    }
+
+    /// Fewest edges from `source` to each node, ignoring weights.
+    ///
+    /// # Complexity
+    /// - Time: O(n + m)
+    /// - Space: O(n)
+    pub fn bfs(&self, source: usize) -> ShortestPaths<usize> {
+        let mut paths = ShortestPaths::new(self.node_count(), source, 0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            let d = paths.dist[u].unwrap();
+            for &(v, _) in self.neighbors(u) {
+                if paths.dist[v].is_none() {
+                    paths.dist[v] = Some(d + 1);
+                    paths.parent[v] = Some(u);
+                    queue.push_back(v);
+                }
+            }
+        }
+        paths
+    }
+}
+
+impl<W: Weight> Graph<W> {
+    /// Shortest paths from `source` when every weight is [`Weight::ZERO`] or
+    /// one common unit.
+    ///
+    /// # Intuition
+    /// With only two edge weights, a deque ordered by distance replaces the
+    /// heap: zero edges keep the distance, so their targets go to the front.
+    ///
+    /// # Complexity
+    /// - Time: O(n + m)
+    /// - Space: O(n)
+    pub fn zero_one_bfs(&self, source: usize) -> ShortestPaths<W> {
+        let mut paths = ShortestPaths::new(self.node_count(), source, W::ZERO);
+        let mut done = vec![false; self.node_count()];
+        let mut deque = VecDeque::from([source]);
+        while let Some(u) = deque.pop_front() {
+            if std::mem::replace(&mut done[u], true) {
+                continue;
+            }
+            let d = paths.dist[u].unwrap();
+            for &(v, w) in self.neighbors(u) {
+                let candidate = d.extend(w);
+                if paths.dist[v].is_none_or(|old| candidate < old) {
+                    paths.dist[v] = Some(candidate);
+                    paths.parent[v] = Some(u);
+                    if w == W::ZERO {
+                        deque.push_front(v);
+                    } else {
+                        deque.push_back(v);
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// Shortest paths from `source` with non-negative weights.
+    ///
+    /// # Intuition
+    /// When extending a path never makes it lighter, the lightest unsettled
+    /// node cannot be improved by a detour, so it can be settled.
+    ///
+    /// # Approach
+    /// Keep a min-heap of tentative distances, skip stale entries, and relax
+    /// the edges of each node as it is settled.
+    ///
+    /// # Complexity
+    /// - Time: O((n + m) log n)
+    /// - Space: O(n + m) for the heap
+    pub fn dijkstra(&self, source: usize) -> ShortestPaths<W> {
+        let mut paths = ShortestPaths::new(self.node_count(), source, W::ZERO);
+        let mut heap = BinaryHeap::from([Reverse((W::ZERO, source))]);
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if paths.dist[u] != Some(d) {
+                continue;
+            }
+            for &(v, w) in self.neighbors(u) {
+                let candidate = d.extend(w);
+                if paths.dist[v].is_none_or(|old| candidate < old) {
+                    paths.dist[v] = Some(candidate);
+                    paths.parent[v] = Some(u);
+                    heap.push(Reverse((candidate, v)));
+                }
+            }
+        }
+        paths
+    }
+
+    /// Shortest paths from `source` with any weights, or a negative cycle
+    /// reachable from it. An undirected negative edge is such a cycle.
+    ///
+    /// # Intuition
+    /// A shortest path without cycles has fewer than `n` edges, so `n - 1`
+    /// rounds of relaxing every edge settle all distances. Anything still
+    /// relaxable afterwards lies on or behind a negative cycle.
+    ///
+    /// # Approach
+    /// 1. Relax every edge for up to `n - 1` rounds, stopping once a round
+    ///    changes nothing.
+    /// 2. If an edge still relaxes, walk `n` predecessor steps back from its
+    ///    target to land on the cycle, then follow predecessors around it.
+    ///
+    /// # Complexity
+    /// - Time: O(n·m)
+    /// - Space: O(n)
+    pub fn bellman_ford(&self, source: usize) -> Result<ShortestPaths<W>, NegativeCycle> {
+        let n = self.node_count();
+        let mut paths = ShortestPaths::new(n, source, W::ZERO);
+        let relax = |paths: &mut ShortestPaths<W>| {
+            let mut last = None;
+            for (u, v, w) in self.arcs() {
+                let Some(d) = paths.dist[u] else { continue };
+                let candidate = d.extend(w);
+                if paths.dist[v].is_none_or(|old| candidate < old) {
+                    paths.dist[v] = Some(candidate);
+                    paths.parent[v] = Some(u);
+                    last = Some(v);
+                }
+            }
+            last
+        };
+        for _ in 1..n {
+            if relax(&mut paths).is_none() {
+                return Ok(paths);
+            }
+        }
+        let Some(mut v) = relax(&mut paths) else {
+            return Ok(paths);
+        };
+        for _ in 0..n {
+            v = paths.parent[v].unwrap();
+        }
+        let mut cycle = vec![v];
+        let mut u = paths.parent[v].unwrap();
+        while u != v {
+            cycle.push(u);
+            u = paths.parent[u].unwrap();
+        }
+        cycle.reverse();
+        Err(NegativeCycle { cycle })
+    }
+
+    /// Shortest paths between every pair of nodes.
+    ///
+    /// # Intuition
+    /// Allowing intermediate nodes `0..k` one at a time, the best path
+    /// between two nodes either avoids `k` or passes through it once.
+    ///
+    /// # Complexity
+    /// - Time: O(n³)
+    /// - Space: O(n²)
+    pub fn floyd_warshall(&self) -> AllPairs<W> {
+        let n = self.node_count();
+        let mut all = AllPairs {
+            dist: vec![vec![None; n]; n],
+            next: vec![vec![None; n]; n],
+        };
+        for u in 0..n {
+            all.dist[u][u] = Some(W::ZERO);
+            all.next[u][u] = Some(u);
+        }
+        for (u, v, w) in self.arcs() {
+            if all.dist[u][v].is_none_or(|old| w < old) {
+                all.dist[u][v] = Some(w);
+                all.next[u][v] = Some(v);
+            }
+        }
+        for k in 0..n {
+            for i in 0..n {
+                let Some(ik) = all.dist[i][k] else { continue };
+                for j in 0..n {
+                    let Some(kj) = all.dist[k][j] else { continue };
+                    let candidate = ik.extend(kj);
+                    if all.dist[i][j].is_none_or(|old| candidate < old) {
+                        all.dist[i][j] = Some(candidate);
+                        all.next[i][j] = all.next[i][k];
+                    }
+                }
+            }
+        }
+        all
+    }
+}
+
+/// Distances from one source and the tree of paths achieving them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPaths<W> {
+    source: usize,
+    dist: Vec<Option<W>>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<W: Copy> ShortestPaths<W> {
+    fn new(n: usize, source: usize, zero: W) -> Self {
+        let mut dist = vec![None; n];
+        dist[source] = Some(zero);
+        Self {
+            source,
+            dist,
+            parent: vec![None; n],
+        }
+    }
+
+    /// The node the paths start from.
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Weight of the shortest path to `v`, or `None` if it is unreachable.
+    pub fn dist(&self, v: usize) -> Option<W> {
+        self.dist[v]
+    }
+
+    /// Every node's distance, indexed by node.
+    pub fn distances(&self) -> &[Option<W>] {
+        &self.dist
+    }
+
+    /// The node before `v` on its shortest path; `None` for the source and
+    /// unreachable nodes.
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+
+    /// The nodes of a shortest path from the source to `v`, both included.
+    pub fn path_to(&self, v: usize) -> Option<Vec<usize>> {
+        self.dist[v]?;
+        let mut path = vec![v];
+        let mut u = v;
+        while let Some(p) = self.parent[u] {
+            path.push(p);
+            u = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Distances between every pair of nodes, with next hops for paths.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AllPairs<W> {
+    dist: Vec<Vec<Option<W>>>,
+    /// The node after `u` on a shortest path from `u` to `v`.
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<W: Weight> AllPairs<W> {
+    /// Weight of the shortest path from `u` to `v`, or `None` if there is none.
+    pub fn dist(&self, u: usize, v: usize) -> Option<W> {
+        self.dist[u][v]
+    }
+
+    /// Whether some node lies on a cycle lighter than the empty path, which
+    /// makes the distances through it meaningless.
+    pub fn has_negative_cycle(&self) -> bool {
+        (0..self.dist.len()).any(|v| self.dist[v][v].is_some_and(|d| d < W::ZERO))
+    }
+
+    /// The nodes of a shortest path from `u` to `v`, both included.
+    pub fn path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        self.dist[u][v]?;
+        let mut path = vec![u];
+        let mut at = u;
+        while at != v {
+            at = self.next[at][v].unwrap();
+            path.push(at);
+        }
+        Some(path)
+    }
+
+    /// Adds a directed edge `from → to`, updating every distance it shortens
+    /// in O(n²). The weight must not close a negative cycle.
+    ///
+    /// # Intuition
+    /// A path improved by the new edge uses it exactly once, so it is a
+    /// shortest path to `from`, the edge, then a shortest path from `to`.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        let n = self.dist.len();
+        let into: Vec<Option<W>> = (0..n).map(|i| self.dist[i][from]).collect();
+        let out = self.dist[to].clone();
+        for i in 0..n {
+            let Some(before) = into[i] else { continue };
+            let via = before.extend(weight);
+            let hop = if i == from {
+                Some(to)
+            } else {
+                self.next[i][from]
+            };
+            for j in 0..n {
+                let Some(after) = out[j] else { continue };
+                let candidate = via.extend(after);
+                if self.dist[i][j].is_none_or(|old| candidate < old) {
+                    self.dist[i][j] = Some(candidate);
+                    self.next[i][j] = hop;
+                }
+            }
+        }
+    }
+}
+
+/// A cycle of negative total weight, found by [`Graph::bellman_ford`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    /// The cycle's nodes in edge order; the last has an edge to the first.
+    pub cycle: Vec<usize>,
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle through {:?}", self.cycle)
+    }
+}
+
+impl Error for NegativeCycle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    fn random_edges(
+        rng: &mut SeededRng,
+        n: usize,
+        m: usize,
+        weights: std::ops::Range<i64>,
+    ) -> Vec<(usize, usize, i64)> {
+        (0..m)
+            .map(|_| {
+                (
+                    rng.in_range(0..n),
+                    rng.in_range(0..n),
+                    rng.in_range(weights.clone()),
+                )
+            })
+            .collect()
+    }
+
+    /// Checks that `paths` are consistent shortest paths of `graph`: every
+    /// path is real and weighs its distance, and no edge relaxes further.
+    fn assert_shortest(graph: &Graph<i64>, paths: &ShortestPaths<i64>, expected: &[Option<i64>]) {
+        assert_eq!(paths.distances(), expected);
+        for v in 0..graph.node_count() {
+            let Some(path) = paths.path_to(v) else {
+                assert_eq!(expected[v], None);
+                continue;
+            };
+            assert_eq!((path[0], path[path.len() - 1]), (paths.source(), v));
+            let weight = path.windows(2).map(|hop| {
+                graph
+                    .neighbors(hop[0])
+                    .iter()
+                    .filter(|&&(to, _)| to == hop[1])
+                    .map(|&(_, w)| w)
+                    .min()
+                    .unwrap()
+            });
+            assert_eq!(Some(weight.sum::<i64>()), expected[v]);
+        }
+    }
+
+    #[test]
+    fn searches_agree_with_floyd_warshall() {
+        let mut rng = SeededRng::new(20);
+        for round in 0..200 {
+            let n = rng.in_range(1..12);
+            let m = rng.in_range(0..3 * n);
+            let directed = round % 2 == 0;
+            let edges = random_edges(&mut rng, n, m, 0..20);
+            let graph = if directed {
+                Graph::directed(n, edges)
+            } else {
+                Graph::undirected(n, edges)
+            };
+            let all = graph.floyd_warshall();
+            assert!(!all.has_negative_cycle());
+            for source in 0..n {
+                let expected: Vec<_> = (0..n).map(|v| all.dist(source, v)).collect();
+                assert_shortest(&graph, &graph.dijkstra(source), &expected);
+                assert_shortest(&graph, &graph.bellman_ford(source).unwrap(), &expected);
+                for v in 0..n {
+                    let path = all.path(source, v);
+                    assert_eq!(
+                        path.as_ref().map(|p| (p[0], p[p.len() - 1])),
+                        expected[v].map(|_| (source, v))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bfs_variants_match_dijkstra() {
+        let mut rng = SeededRng::new(1091);
+        for _ in 0..200 {
+            let n = rng.in_range(1..15);
+            let m = rng.in_range(0..3 * n);
+            let graph = Graph::directed(n, random_edges(&mut rng, n, m, 0..2));
+            let unit = Graph::directed(n, graph.arcs().map(|(u, v, _)| (u, v, 1_usize)));
+            let source = rng.in_range(0..n);
+            assert_eq!(
+                graph.zero_one_bfs(source).distances(),
+                graph.dijkstra(source).distances()
+            );
+            let hops = graph.bfs(source);
+            assert_eq!(hops.distances(), unit.dijkstra(source).distances());
+            for v in 0..n {
+                let len = hops.path_to(v).map(|p| p.len() - 1);
+                assert_eq!(len, hops.dist(v));
+            }
+        }
+    }
+
+    #[test]
+    fn bellman_ford_finds_negative_cycles() {
+        let mut rng = SeededRng::new(2203);
+        let mut found = 0;
+        for _ in 0..300 {
+            let n = rng.in_range(1..10);
+            let m = rng.in_range(0..2 * n);
+            let graph = Graph::directed(n, random_edges(&mut rng, n, m, -4..10));
+            let all = graph.floyd_warshall();
+            let source = rng.in_range(0..n);
+            let reaches_cycle =
+                (0..n).any(|v| all.dist(source, v).is_some() && all.dist(v, v) < Some(0));
+            match graph.bellman_ford(source) {
+                Ok(paths) => {
+                    assert!(!reaches_cycle);
+                    let expected: Vec<_> = (0..n).map(|v| all.dist(source, v)).collect();
+                    assert_shortest(&graph, &paths, &expected);
+                }
+                Err(NegativeCycle { cycle }) => {
+                    assert!(reaches_cycle);
+                    found += 1;
+                    let weight: i64 = (0..cycle.len())
+                        .map(|i| {
+                            let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                            graph
+                                .neighbors(u)
+                                .iter()
+                                .filter(|&&(to, _)| to == v)
+                                .map(|&(_, w)| w)
+                                .min()
+                                .unwrap()
+                        })
+                        .sum();
+                    assert!(weight < 0);
+                }
+            }
+        }
+        assert!(found > 10);
+        let undirected = Graph::undirected(2, [(0, 1, -1)]);
+        assert!(undirected.bellman_ford(0).is_err());
+    }
+
+    #[test]
+    fn incremental_all_pairs_matches_rebuild() {
+        let mut rng = SeededRng::new(2642);
+        for _ in 0..50 {
+            let n = rng.in_range(1..10);
+            let mut edges = random_edges(&mut rng, n, n, 1..30);
+            let mut all = Graph::directed(n, edges.iter().copied()).floyd_warshall();
+            for _ in 0..10 {
+                let edge = random_edges(&mut rng, n, 1, 1..30)[0];
+                all.add_edge(edge.0, edge.1, edge.2);
+                edges.push(edge);
+                let rebuilt = Graph::directed(n, edges.iter().copied()).floyd_warshall();
+                for u in 0..n {
+                    for v in 0..n {
+                        assert_eq!(all.dist(u, v), rebuilt.dist(u, v));
+                        let path = all.path(u, v);
+                        let weight = path.map(|p| {
+                            p.windows(2)
+                                .map(|hop| {
+                                    edges
+                                        .iter()
+                                        .filter(|e| (e.0, e.1) == (hop[0], hop[1]))
+                                        .map(|e| e.2)
+                                        .min()
+                                        .unwrap()
+                                })
+                                .sum::<i64>()
+                        });
+                        assert_eq!(weight, rebuilt.dist(u, v));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bottleneck_dijkstra_minimises_the_heaviest_edge() {
+        let graph = Graph::undirected(
+            4,
+            [
+                (0, 1, Bottleneck(5)),
+                (1, 3, Bottleneck(1)),
+                (0, 2, Bottleneck(3)),
+                (2, 3, Bottleneck(4)),
+            ],
+        );
+        let paths = graph.dijkstra(0);
+        assert_eq!(paths.dist(3), Some(Bottleneck(4)));
+        assert_eq!(paths.path_to(3), Some(vec![0, 2, 3]));
+        let reversed = Graph::directed(3, [(0, 1, ()), (1, 2, ())]).reversed();
+        assert_eq!(reversed.bfs(2).dist(0), Some(2));
+        assert_eq!((reversed.edge_count(), graph.edge_count()), (2, 4));
+    }
+}
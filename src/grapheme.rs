@@ -0,0 +1,281 @@
+//! Extended grapheme clusters: the user-perceived characters that cursors
+//! step over and deletions remove whole.
+//!
+//! Segmentation is the full UAX #29 algorithm from `unicode-segmentation`,
+//! including Prepend marks and Indic conjuncts (GB9c). Its incremental cursor
+//! is fed the chunks of a [`Rope`] one at a time, asking for earlier text
+//! only when a rule needs it, so finding a boundary never copies the text.
+//!
+//! ```
+//! use leetcode::grapheme::{graphemes, next_boundary, prev_boundary};
+//! use leetcode::rope::Rope;
+//!
+//! let text = "e\u{301}🇫🇷👩‍💻\r\nক্ষ";
+//! let clusters: Vec<_> = graphemes(text).collect();
+//! assert_eq!(clusters, ["e\u{301}", "🇫🇷", "👩‍💻", "\r\n", "ক্ষ"]);
+//!
+//! let rope = Rope::from(text);
+//! assert_eq!(next_boundary(&rope, 0), 2);
+//! assert_eq!(prev_boundary(&rope, 4), 2);
+//! ```
+
+use crate::rope::{Chunks, Rope};
+use std::ops::Range;
+use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete, UnicodeSegmentation};
+
+/// Splits `text` into grapheme clusters.
+pub fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    text.graphemes(true)
+}
+
+/// Whether a cluster boundary falls before char `at`; the start and end of
+/// the text are boundaries.
+pub fn is_boundary(rope: &Rope, at: usize) -> bool {
+    let chunk = rope.chunks_from(at).next().unwrap_or("");
+    let start = rope.char_to_byte(at);
+    let mut cursor = GraphemeCursor::new(start, rope.len_bytes(), true);
+    let mut context = Context::new(rope);
+    loop {
+        match cursor.is_boundary(chunk, start) {
+            Err(GraphemeIncomplete::PreContext(end)) => context.provide(&mut cursor, end),
+            result => return result.expect("the chunk holds the cursor"),
+        }
+    }
+}
+
+/// Where the first cluster boundary after char `at` falls; the end of the
+/// text at the end.
+pub fn next_boundary(rope: &Rope, at: usize) -> usize {
+    nth_next_boundary(rope, at, 1)
+}
+
+/// Where the last cluster boundary before char `at` falls; `0` at the start
+/// of the text.
+pub fn prev_boundary(rope: &Rope, at: usize) -> usize {
+    nth_prev_boundary(rope, at, 1)
+}
+
+/// Where the `n`th cluster boundary after char `at` falls, stopping at the
+/// end of the text. Seeks once, then scans only the text stepped over.
+pub fn nth_next_boundary(rope: &Rope, at: usize, n: usize) -> usize {
+    if n == 0 {
+        return at;
+    }
+    let mut left = n;
+    let end = scan_forward(rope, at, |_| {
+        left -= 1;
+        left == 0
+    });
+    rope.byte_to_char(end)
+}
+
+/// Where the `n`th cluster boundary before char `at` falls, stopping at the
+/// start of the text. Seeks once, then scans only the text stepped over.
+pub fn nth_prev_boundary(rope: &Rope, at: usize, n: usize) -> usize {
+    if n == 0 {
+        return at;
+    }
+    let mut left = n;
+    let start = scan_backward(rope, at, || {
+        left -= 1;
+        left == 0
+    });
+    rope.byte_to_char(start)
+}
+
+/// Number of clusters starting within the char range `range`.
+pub fn count(rope: &Rope, range: Range<usize>) -> usize {
+    if range.is_empty() {
+        return 0;
+    }
+    let end = rope.char_to_byte(range.end);
+    let mut count = usize::from(is_boundary(rope, range.start));
+    scan_forward(rope, range.start, |boundary| {
+        let inside = boundary < end;
+        count += usize::from(inside);
+        !inside
+    });
+    count
+}
+
+/// Visits the boundaries after char `at` in order until `stop` accepts one,
+/// returning its byte offset, or the length of the text if none is accepted.
+fn scan_forward(rope: &Rope, at: usize, mut stop: impl FnMut(usize) -> bool) -> usize {
+    let mut chunks = rope.chunks_from(at);
+    let mut chunk = chunks.next().unwrap_or("");
+    let mut start = rope.char_to_byte(at);
+    let mut cursor = GraphemeCursor::new(start, rope.len_bytes(), true);
+    let mut context = Context::new(rope);
+    loop {
+        match cursor.next_boundary(chunk, start) {
+            // a cursor reused past a boundary misjudges regional indicator
+            // pairs split across chunks, so each step starts afresh
+            Ok(Some(boundary)) if !stop(boundary) => {
+                cursor = GraphemeCursor::new(boundary, rope.len_bytes(), true);
+            }
+            Ok(boundary) => return boundary.unwrap_or(rope.len_bytes()),
+            Err(GraphemeIncomplete::NextChunk) => {
+                start += chunk.len();
+                chunk = chunks.next().expect("the cursor stops at the end");
+            }
+            Err(GraphemeIncomplete::PreContext(end)) => context.provide(&mut cursor, end),
+            Err(other) => unreachable!("a forward scan asked for {other:?}"),
+        }
+    }
+}
+
+/// Visits the boundaries before char `at` in reverse until `stop` returns
+/// `true`, returning the byte offset of the last one visited, or `0` if it
+/// never does.
+fn scan_backward(rope: &Rope, at: usize, mut stop: impl FnMut() -> bool) -> usize {
+    let mut chunks = rope.chunks_before(at);
+    let mut chunk = chunks.next().unwrap_or("");
+    let offset = rope.char_to_byte(at);
+    let mut start = offset - chunk.len();
+    let mut cursor = GraphemeCursor::new(offset, rope.len_bytes(), true);
+    let mut context = Context::new(rope);
+    loop {
+        match cursor.prev_boundary(chunk, start) {
+            Ok(Some(boundary)) if !stop() => {
+                cursor = GraphemeCursor::new(boundary, rope.len_bytes(), true);
+            }
+            Ok(boundary) => return boundary.unwrap_or(0),
+            Err(GraphemeIncomplete::PrevChunk) => {
+                chunk = chunks.next().expect("the cursor stops at the start");
+                start -= chunk.len();
+            }
+            Err(GraphemeIncomplete::PreContext(end)) => context.provide(&mut cursor, end),
+            Err(other) => unreachable!("a backward scan asked for {other:?}"),
+        }
+    }
+}
+
+/// Walks backwards through a rope to answer a cursor's requests for the
+/// text before a byte offset. Each request usually continues from where the
+/// last one stopped, so the walk resumes rather than seeking again.
+struct Context<'a> {
+    rope: &'a Rope,
+    chunks: Option<Chunks<'a>>,
+    /// Byte offset where the next chunk from `chunks` ends.
+    end: usize,
+}
+
+impl<'a> Context<'a> {
+    fn new(rope: &'a Rope) -> Self {
+        Self {
+            rope,
+            chunks: None,
+            end: 0,
+        }
+    }
+
+    fn provide(&mut self, cursor: &mut GraphemeCursor, end: usize) {
+        if self.chunks.is_none() || self.end != end {
+            self.chunks = Some(self.rope.chunks_before(self.rope.byte_to_char(end)));
+        }
+        let chunk = self
+            .chunks
+            .as_mut()
+            .and_then(Iterator::next)
+            .expect("context is only asked for after the start");
+        self.end = end - chunk.len();
+        cursor.provide_context(chunk, self.end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_clusters() {
+        let cases: &[(&str, &[&str])] = &[
+            ("", &[]),
+            ("abc", &["a", "b", "c"]),
+            ("a\r\nb\n\r", &["a", "\r\n", "b", "\n", "\r"]),
+            ("e\u{301}\u{308}x", &["e\u{301}\u{308}", "x"]),
+            (
+                "\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}",
+                &["\u{1100}\u{1161}\u{11a8}", "\u{ac00}\u{11a8}"],
+            ),
+            ("🇺🇸🇫🇷🇩", &["🇺🇸", "🇫🇷", "🇩"]),
+            ("👨‍👩‍👧👍🏽", &["👨‍👩‍👧", "👍🏽"]),
+            ("a\u{200d}👍", &["a\u{200d}", "👍"]),
+            ("👍\u{200d}\u{200d}👍", &["👍\u{200d}\u{200d}", "👍"]),
+            ("กำa", &["กำ", "a"]),
+            ("\u{301}a", &["\u{301}", "a"]),
+        ];
+        for &(text, expected) in cases {
+            assert_eq!(graphemes(text).collect::<Vec<_>>(), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn keeps_indic_conjuncts_and_spacing_marks_whole() {
+        let cases: &[(&str, &[&str])] = &[
+            // Tamil vowel sign I is a spacing mark
+            ("நிக", &["நி", "க"]),
+            // consonant, virama, consonant joins into one conjunct (GB9c)
+            ("ক্ষa", &["ক্ষ", "a"]),
+            ("क्षि", &["क्षि"]),
+            ("क\u{962}", &["क\u{962}"]),
+            ("a\u{bcd}", &["a\u{bcd}"]),
+            ("ا\u{6e8}", &["ا\u{6e8}"]),
+            // a prepended mark joins what follows (GB9b)
+            ("\u{600}1a", &["\u{600}1", "a"]),
+        ];
+        for &(text, expected) in cases {
+            assert_eq!(graphemes(text).collect::<Vec<_>>(), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn rope_boundaries_match_the_string_across_chunks() {
+        let piece = "ab\u{301}🇺🇸🇫🇷x👨‍👩‍👧\r\nक्षि\u{600}1ক্ষ\u{1100}\u{1161}z";
+        // long enough to span several chunks, so lookback crosses them
+        let text = piece.repeat(60);
+        let rope = Rope::from(text.as_str());
+        assert!(rope.chunks().count() > 2);
+        let mut expected: Vec<usize> = text
+            .grapheme_indices(true)
+            .map(|(byte, _)| text[..byte].chars().count())
+            .collect();
+        let len = rope.len_chars();
+        expected.push(len);
+        for at in 0..=len {
+            assert_eq!(is_boundary(&rope, at), expected.contains(&at), "at {at}");
+            let next = expected.iter().copied().find(|&b| b > at).unwrap_or(len);
+            assert_eq!(next_boundary(&rope, at), next, "after {at}");
+            let prev = expected
+                .iter()
+                .rev()
+                .copied()
+                .find(|&b| b < at)
+                .unwrap_or(0);
+            assert_eq!(prev_boundary(&rope, at), prev, "before {at}");
+        }
+        for (i, &from) in expected.iter().enumerate().step_by(7) {
+            for n in [0, 1, 5, 40] {
+                let after = expected.get(i + n).copied().unwrap_or(len);
+                assert_eq!(nth_next_boundary(&rope, from, n), after, "{n} after {from}");
+                let before = i.checked_sub(n).map_or(0, |j| expected[j]);
+                assert_eq!(
+                    nth_prev_boundary(&rope, from, n),
+                    before,
+                    "{n} before {from}"
+                );
+            }
+        }
+        for start in (0..len).step_by(13) {
+            for end in [start, start + 1, start + 30, len] {
+                let end = end.min(len);
+                let inside = expected.iter().filter(|&&b| (start..end).contains(&b));
+                assert_eq!(
+                    count(&rope, start..end),
+                    inside.count(),
+                    "in {start}..{end}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,754 @@
+//! Sets and maps over intervals of an ordered domain.
+//!
+//! Intervals are passed as any [`RangeBounds`] (`a..b`, `a..=b`, `..b`, ...)
+//! and read as sets of points on a continuous line: `1..=2` and `3..=4` stay
+//! apart, while `1..=2` and `2..4` touch and coalesce into `1..4`. Stored
+//! intervals come back as [`Interval`] bound pairs, which are themselves
+//! `RangeBounds`.
+//!
+//! ```
+//! use leetcode::interval_set::IntervalSet;
+//! use std::ops::Bound::{Excluded, Included};
+//!
+//! let mut busy: IntervalSet<i32> = [9..12, 13..17].into_iter().collect();
+//! busy.insert(11..14);
+//! assert_eq!(busy.iter().collect::<Vec<_>>(), [(Included(&9), Excluded(&17))]);
+//! busy.remove(12..=13);
+//! assert!(busy.contains(&14) && !busy.contains(&13));
+//! assert!(busy.overlaps(10..20) && !busy.covers(10..20));
+//! assert_eq!(busy.measure(|(lo, hi)| match (lo, hi) {
+//!     (Included(lo), Excluded(hi)) | (Excluded(lo), Excluded(hi)) => hi - lo,
+//!     _ => unreachable!(),
+//! }), 7);
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
+use std::ops::RangeBounds;
+
+/// A stored interval as its lower and upper bound.
+pub type Interval<'a, T> = (Bound<&'a T>, Bound<&'a T>);
+
+/// A position between points of the domain. Every interval, whatever its
+/// mix of open, closed and unbounded ends, is the half-open run of cuts
+/// `[lo, hi)`, which makes coalescing and splitting uniform.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Cut<T> {
+    BelowAll,
+    /// Just below the value.
+    Below(T),
+    /// Just above the value.
+    Above(T),
+    AboveAll,
+}
+
+impl<T: Ord> Ord for Cut<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Cut::*;
+        match (self, other) {
+            (BelowAll, BelowAll) | (AboveAll, AboveAll) => Ordering::Equal,
+            (BelowAll, _) | (_, AboveAll) => Ordering::Less,
+            (_, BelowAll) | (AboveAll, _) => Ordering::Greater,
+            (Below(a) | Above(a), Below(b) | Above(b)) => a
+                .cmp(b)
+                .then_with(|| matches!(self, Above(_)).cmp(&matches!(other, Above(_)))),
+        }
+    }
+}
+
+impl<T: Ord> PartialOrd for Cut<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Cut<T> {
+    /// The bound a run of cuts starting here has.
+    fn as_lower(&self) -> Bound<&T> {
+        match self {
+            Cut::Below(x) => Included(x),
+            Cut::Above(x) => Excluded(x),
+            Cut::BelowAll | Cut::AboveAll => Unbounded,
+        }
+    }
+
+    /// The bound a run of cuts ending here has.
+    fn as_upper(&self) -> Bound<&T> {
+        match self {
+            Cut::Above(x) => Included(x),
+            Cut::Below(x) => Excluded(x),
+            Cut::BelowAll | Cut::AboveAll => Unbounded,
+        }
+    }
+}
+
+/// The cuts `[lo, hi)` spanned by `range`; empty when `lo >= hi`.
+fn cuts<T: Clone>(range: impl RangeBounds<T>) -> (Cut<T>, Cut<T>) {
+    let lo = match range.start_bound() {
+        Included(x) => Cut::Below(x.clone()),
+        Excluded(x) => Cut::Above(x.clone()),
+        Unbounded => Cut::BelowAll,
+    };
+    let hi = match range.end_bound() {
+        Included(x) => Cut::Above(x.clone()),
+        Excluded(x) => Cut::Below(x.clone()),
+        Unbounded => Cut::AboveAll,
+    };
+    (lo, hi)
+}
+
+/// A set of points stored as disjoint, non-touching intervals.
+///
+/// # Complexity
+/// - Time: O(log n) per point or range query, O((k + 1) log n) per insert or
+///   remove touching `k` stored intervals; set operations are O((n + m) log n)
+/// - Space: O(n) for n stored intervals
+#[derive(Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Start cut to end cut of each interval.
+    map: BTreeMap<Cut<T>, Cut<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// An empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of disjoint intervals stored.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether the set contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes every interval.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// The stored intervals in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Interval<'_, T>> + '_ {
+        self.map
+            .iter()
+            .map(|(lo, hi)| (lo.as_lower(), hi.as_upper()))
+    }
+
+    /// Adds the points of `range`, coalescing with every interval it overlaps
+    /// or touches. Returns those absorbed intervals in ascending order.
+    pub fn insert(&mut self, range: impl RangeBounds<T>) -> Vec<(Bound<T>, Bound<T>)> {
+        let (lo, hi) = cuts(range);
+        self.insert_cuts(lo, hi)
+            .into_iter()
+            .map(|(lo, hi)| (lo.as_lower().cloned(), hi.as_upper().cloned()))
+            .collect()
+    }
+
+    fn insert_cuts(&mut self, mut lo: Cut<T>, mut hi: Cut<T>) -> Vec<(Cut<T>, Cut<T>)> {
+        if lo >= hi {
+            return Vec::new();
+        }
+        let mut absorbed = Vec::new();
+        while let Some((start, end)) = self.map.range(..=&hi).next_back() {
+            if *end < lo {
+                break;
+            }
+            let start = start.clone();
+            let end = self.map.remove(&start).unwrap();
+            lo = lo.min(start.clone());
+            hi = hi.max(end.clone());
+            absorbed.push((start, end));
+        }
+        self.map.insert(lo, hi);
+        absorbed.reverse();
+        absorbed
+    }
+
+    /// Removes the points of `range`, splitting intervals that straddle its
+    /// ends.
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let (lo, hi) = cuts(range);
+        self.remove_cuts(&lo, &hi);
+    }
+
+    fn remove_cuts(&mut self, lo: &Cut<T>, hi: &Cut<T>) {
+        if lo >= hi {
+            return;
+        }
+        let hit: Vec<Cut<T>> = self
+            .map
+            .range(..hi)
+            .rev()
+            .take_while(|&(_, end)| end > lo)
+            .map(|(start, _)| start.clone())
+            .collect();
+        for start in hit {
+            let end = self.map.remove(&start).unwrap();
+            if start < *lo {
+                self.map.insert(start, lo.clone());
+            }
+            if end > *hi {
+                self.map.insert(hi.clone(), end);
+            }
+        }
+    }
+
+    /// The stored interval containing `point`.
+    pub fn get(&self, point: &T) -> Option<Interval<'_, T>> {
+        let below = Cut::Below(point.clone());
+        // no cut lies strictly between `Below(point)` and `Above(point)`
+        let (lo, hi) = self.map.range(..=&below).next_back()?;
+        (*hi > below).then(|| (lo.as_lower(), hi.as_upper()))
+    }
+
+    /// Whether `point` is in the set.
+    pub fn contains(&self, point: &T) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Whether the set shares a point with `range`.
+    pub fn overlaps(&self, range: impl RangeBounds<T>) -> bool {
+        let (lo, hi) = cuts(range);
+        lo < hi
+            && self
+                .map
+                .range(..&hi)
+                .next_back()
+                .is_some_and(|(_, end)| *end > lo)
+    }
+
+    /// Whether every point of `range` is in the set.
+    pub fn covers(&self, range: impl RangeBounds<T>) -> bool {
+        let (lo, hi) = cuts(range);
+        lo >= hi
+            || self
+                .map
+                .range(..=&lo)
+                .next_back()
+                .is_some_and(|(_, end)| *end >= hi)
+    }
+
+    /// Sums `length` over the stored intervals: `hi - lo` on a continuous
+    /// domain, or the number of values inside on a discrete one.
+    pub fn measure<U: Sum>(&self, length: impl FnMut(Interval<'_, T>) -> U) -> U {
+        self.iter().map(length).sum()
+    }
+
+    /// The points in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for (lo, hi) in &other.map {
+            union.insert_cuts(lo.clone(), hi.clone());
+        }
+        union
+    }
+
+    /// The points in both sets, found by a merge walk over both lists.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let mut left = self.map.iter().peekable();
+        let mut right = other.map.iter().peekable();
+        while let (Some(&(lo1, hi1)), Some(&(lo2, hi2))) = (left.peek(), right.peek()) {
+            let (lo, hi) = (lo1.max(lo2), hi1.min(hi2));
+            if lo < hi {
+                intersection.map.insert(lo.clone(), hi.clone());
+            }
+            if hi1 < hi2 {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        intersection
+    }
+
+    /// The points in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for (lo, hi) in &other.map {
+            difference.remove_cuts(lo, hi);
+        }
+        difference
+    }
+}
+
+impl<T: Ord + Clone, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord + Clone, R: RangeBounds<T>> Extend<R> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Ord + Clone + fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A map from points to values, stored as disjoint intervals each carrying
+/// one value. Touching intervals with equal values coalesce.
+///
+/// # Complexity
+/// - Time: O(log n) per point query, O((k + 1) log n) per insert, remove or
+///   update touching `k` stored intervals
+/// - Space: O(n) for n stored intervals
+#[derive(Clone, PartialEq, Eq)]
+pub struct IntervalMap<T, V> {
+    /// Start cut to end cut and value of each interval.
+    map: BTreeMap<Cut<T>, (Cut<T>, V)>,
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord + Clone, V: Clone + PartialEq> IntervalMap<T, V> {
+    /// An empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of intervals stored.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether no point has a value.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes every interval.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// The stored intervals and their values in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Interval<'_, T>, &V)> + '_ {
+        self.map
+            .iter()
+            .map(|(lo, (hi, value))| ((lo.as_lower(), hi.as_upper()), value))
+    }
+
+    /// The stored interval containing `point`, with its value.
+    pub fn get_interval(&self, point: &T) -> Option<(Interval<'_, T>, &V)> {
+        let below = Cut::Below(point.clone());
+        let (lo, (hi, value)) = self.map.range(..=&below).next_back()?;
+        (*hi > below).then(|| ((lo.as_lower(), hi.as_upper()), value))
+    }
+
+    /// The value at `point`.
+    pub fn get(&self, point: &T) -> Option<&V> {
+        self.get_interval(point).map(|(_, value)| value)
+    }
+
+    /// The stored intervals sharing a point with `range`, unclipped.
+    pub fn overlapping(
+        &self,
+        range: impl RangeBounds<T>,
+    ) -> impl Iterator<Item = (Interval<'_, T>, &V)> + '_ {
+        let (lo, hi) = cuts(range);
+        let mut found = Vec::new();
+        if lo < hi {
+            let first = self.map.range(..&lo).next_back();
+            found.extend(first.filter(|(_, (end, _))| *end > lo));
+            found.extend(self.map.range(&lo..&hi));
+        }
+        found
+            .into_iter()
+            .map(|(lo, (hi, value))| ((lo.as_lower(), hi.as_upper()), value))
+    }
+
+    /// Gives every point of `range` the value `value`.
+    pub fn insert(&mut self, range: impl RangeBounds<T>, value: V) {
+        let (lo, hi) = cuts(range);
+        if lo >= hi {
+            return;
+        }
+        self.remove_cuts(&lo, &hi);
+        self.map.insert(lo.clone(), (hi.clone(), value));
+        self.coalesce(&lo, &hi);
+    }
+
+    /// Clears the value of every point of `range`.
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let (lo, hi) = cuts(range);
+        self.remove_cuts(&lo, &hi);
+    }
+
+    /// Replaces the value `v` of each piece of `range` (`None` in gaps) by
+    /// `f(v)`, clearing it where `f` returns `None`. With `V = usize`,
+    /// `|c| Some(c.map_or(1, |c| c + 1))` counts how many ranges cover each
+    /// point.
+    pub fn update(
+        &mut self,
+        range: impl RangeBounds<T>,
+        mut f: impl FnMut(Option<&V>) -> Option<V>,
+    ) {
+        let (lo, hi) = cuts(range);
+        if lo >= hi {
+            return;
+        }
+        self.split_at(&lo);
+        self.split_at(&hi);
+        let old: Vec<Cut<T>> = self
+            .map
+            .range(&lo..&hi)
+            .map(|(start, _)| start.clone())
+            .collect();
+        let mut at = lo.clone();
+        let mut pieces = Vec::with_capacity(2 * old.len() + 1);
+        for start in old {
+            let (end, value) = self.map.remove(&start).unwrap();
+            if at < start {
+                pieces.push((at, start.clone(), f(None)));
+            }
+            pieces.push((start, end.clone(), f(Some(&value))));
+            at = end;
+        }
+        if at < hi {
+            pieces.push((at, hi.clone(), f(None)));
+        }
+        for (start, end, value) in pieces {
+            if let Some(value) = value {
+                self.map.insert(start, (end, value));
+            }
+        }
+        self.coalesce(&lo, &hi);
+    }
+
+    /// Splits the interval straddling `cut`, if any, into two at `cut`.
+    fn split_at(&mut self, cut: &Cut<T>) {
+        let Some((_, (end, value))) = self.map.range_mut(..cut).next_back() else {
+            return;
+        };
+        if *end > *cut {
+            let upper = (std::mem::replace(end, cut.clone()), value.clone());
+            self.map.insert(cut.clone(), upper);
+        }
+    }
+
+    fn remove_cuts(&mut self, lo: &Cut<T>, hi: &Cut<T>) {
+        if lo >= hi {
+            return;
+        }
+        self.split_at(lo);
+        self.split_at(hi);
+        let hit: Vec<Cut<T>> = self
+            .map
+            .range(lo..hi)
+            .map(|(start, _)| start.clone())
+            .collect();
+        for start in hit {
+            self.map.remove(&start);
+        }
+    }
+
+    /// Merges touching equal-valued neighbours among the intervals from the
+    /// one before `lo` through the one starting at `hi`.
+    fn coalesce(&mut self, lo: &Cut<T>, hi: &Cut<T>) {
+        let from = self
+            .map
+            .range(..lo)
+            .next_back()
+            .map_or(lo, |(start, _)| start);
+        let starts: Vec<Cut<T>> = self
+            .map
+            .range(from..=hi)
+            .map(|(start, _)| start.clone())
+            .collect();
+        let mut starts = starts.into_iter();
+        let Some(mut current) = starts.next() else {
+            return;
+        };
+        for next in starts {
+            let (end, value) = &self.map[&current];
+            if *end == next && *value == self.map[&next].1 {
+                let (next_end, _) = self.map.remove(&next).unwrap();
+                self.map.get_mut(&current).unwrap().0 = next_end;
+            } else {
+                current = next;
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone + fmt::Debug, V: Clone + PartialEq + fmt::Debug> fmt::Debug
+    for IntervalMap<T, V>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// The largest number of `ranges` sharing a single point: the number of rooms
+/// a list of meetings needs.
+///
+/// # Intuition
+/// Depth only changes where some range starts or ends, so sweeping over
+/// those events in order sees every depth the line reaches.
+///
+/// # Approach
+/// Turn each non-empty range into a `+1` at its lower cut and a `-1` at its
+/// upper cut, sort, and track the running sum. Ends sort before starts at
+/// the same cut, so ranges that merely touch never count as overlapping.
+///
+/// # Complexity
+/// - Time: O(n log n)
+/// - Space: O(n) for the events
+pub fn max_overlap<T: Ord + Clone, R: RangeBounds<T>>(
+    ranges: impl IntoIterator<Item = R>,
+) -> usize {
+    let mut events = Vec::new();
+    for range in ranges {
+        let (lo, hi) = cuts(range);
+        if lo < hi {
+            events.push((lo, 1));
+            events.push((hi, -1));
+        }
+    }
+    events.sort_unstable();
+    let mut depth: isize = 0;
+    events
+        .into_iter()
+        .map(|(_, delta)| {
+            depth += delta;
+            depth as usize
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    /// Points sampled at every integer and every half, indexed by twice
+    /// their value, so that membership of samples pins down any interval
+    /// with integer ends.
+    const FIRST: i32 = -4;
+    const END: i32 = 48;
+
+    fn holds((lo, hi): Interval<'_, i32>, s: i32) -> bool {
+        let above = match lo {
+            Included(&x) => 2 * x <= s,
+            Excluded(&x) => 2 * x < s,
+            Unbounded => true,
+        };
+        let below = match hi {
+            Included(&x) => s <= 2 * x,
+            Excluded(&x) => s < 2 * x,
+            Unbounded => true,
+        };
+        above && below
+    }
+
+    fn random_range(rng: &mut SeededRng) -> (Bound<i32>, Bound<i32>) {
+        let bound = |rng: &mut SeededRng| {
+            let x = rng.in_range(0..22);
+            match rng.in_range(0..9) {
+                0 => Unbounded,
+                1..=4 => Included(x),
+                _ => Excluded(x),
+            }
+        };
+        (bound(rng), bound(rng))
+    }
+
+    fn as_ref(range: &(Bound<i32>, Bound<i32>)) -> Interval<'_, i32> {
+        (range.0.as_ref(), range.1.as_ref())
+    }
+
+    /// Number of maximal runs of equal `Some` samples.
+    fn runs<V: PartialEq>(samples: &[Option<V>]) -> usize {
+        let mut count = 0;
+        for (i, s) in samples.iter().enumerate() {
+            if s.is_some() && (i == 0 || samples[i - 1] != *s) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn set_samples(set: &IntervalSet<i32>) -> Vec<Option<()>> {
+        (FIRST..END)
+            .map(|s| set.iter().any(|interval| holds(interval, s)).then_some(()))
+            .collect()
+    }
+
+    #[test]
+    fn set_matches_sampled_model() {
+        let mut rng = SeededRng::new(19);
+        let mut set = IntervalSet::new();
+        let mut model = vec![None; (END - FIRST) as usize];
+        for _ in 0..3000 {
+            let range = random_range(&mut rng);
+            let inside: Vec<bool> = (FIRST..END).map(|s| holds(as_ref(&range), s)).collect();
+            let hit = (0..model.len()).filter(|&i| inside[i]);
+            assert_eq!(set.overlaps(range), hit.clone().any(|i| model[i].is_some()));
+            assert_eq!(set.covers(range), hit.clone().all(|i| model[i].is_some()));
+            if rng.chance(0.6) {
+                let absorbed = set.insert(range);
+                for interval in &absorbed {
+                    let interval = (interval.0.as_ref(), interval.1.as_ref());
+                    assert!((FIRST..END).any(|s| holds(interval, s)));
+                }
+                for i in hit {
+                    model[i] = Some(());
+                }
+            } else {
+                set.remove(range);
+                for i in hit {
+                    model[i] = None;
+                }
+            }
+            assert_eq!(set_samples(&set), model);
+            assert_eq!(set.len(), runs(&model));
+            let x = rng.in_range(-1..23);
+            assert_eq!(set.contains(&x), model[(2 * x - FIRST) as usize].is_some());
+        }
+    }
+
+    #[test]
+    fn set_operations_match_model() {
+        let mut rng = SeededRng::new(986);
+        for _ in 0..300 {
+            let mut random_set = || -> IntervalSet<i32> {
+                let n = rng.in_range(0..5);
+                (0..n).map(|_| random_range(&mut rng)).collect()
+            };
+            let (a, b) = (random_set(), random_set());
+            let (sa, sb) = (set_samples(&a), set_samples(&b));
+            let zip = |f: fn(bool, bool) -> bool| -> Vec<Option<()>> {
+                sa.iter()
+                    .zip(&sb)
+                    .map(|(x, y)| f(x.is_some(), y.is_some()).then_some(()))
+                    .collect()
+            };
+            for (set, expected) in [
+                (a.union(&b), zip(|x, y| x || y)),
+                (a.intersection(&b), zip(|x, y| x && y)),
+                (a.difference(&b), zip(|x, y| x && !y)),
+            ] {
+                assert_eq!(set_samples(&set), expected);
+                assert_eq!(set.len(), runs(&expected));
+            }
+        }
+    }
+
+    #[test]
+    fn closed_intervals_touch_but_adjacent_integers_do_not() {
+        let mut set: IntervalSet<i32> = [1..=2, 3..=4].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        let absorbed = set.insert(2..=3);
+        assert_eq!(
+            absorbed,
+            [(Included(1), Included(2)), (Included(3), Included(4))]
+        );
+        assert_eq!(set.get(&2), Some((Included(&1), Included(&4))));
+        set.remove(2..3);
+        assert_eq!(
+            format!("{set:?}"),
+            "[(Included(1), Excluded(2)), (Included(3), Included(4))]"
+        );
+    }
+
+    #[test]
+    fn map_matches_sampled_model() {
+        let mut rng = SeededRng::new(729);
+        let mut map = IntervalMap::new();
+        let mut model: Vec<Option<u8>> = vec![None; (END - FIRST) as usize];
+        for _ in 0..3000 {
+            let range = random_range(&mut rng);
+            let hit: Vec<usize> = (0..model.len())
+                .filter(|&i| holds(as_ref(&range), FIRST + i as i32))
+                .collect();
+            let expected: Vec<_> = map
+                .iter()
+                .filter(|&(interval, _)| hit.iter().any(|&i| holds(interval, FIRST + i as i32)))
+                .collect();
+            assert_eq!(map.overlapping(range).collect::<Vec<_>>(), expected);
+            match rng.in_range(0..3) {
+                0 => {
+                    let value = rng.in_range(0..3);
+                    map.insert(range, value);
+                    hit.iter().for_each(|&i| model[i] = Some(value));
+                }
+                1 => {
+                    map.remove(range);
+                    hit.iter().for_each(|&i| model[i] = None);
+                }
+                _ => {
+                    let step = |v: Option<&u8>| match v {
+                        Some(2) => None,
+                        Some(v) => Some(v + 1),
+                        None => Some(0),
+                    };
+                    map.update(range, step);
+                    hit.iter().for_each(|&i| model[i] = step(model[i].as_ref()));
+                }
+            }
+            let samples: Vec<Option<u8>> = (FIRST..END)
+                .map(|s| {
+                    map.iter()
+                        .find(|&(interval, _)| holds(interval, s))
+                        .map(|(_, &v)| v)
+                })
+                .collect();
+            assert_eq!(samples, model);
+            assert_eq!(map.len(), runs(&model));
+            let x = rng.in_range(-1..23);
+            assert_eq!(map.get(&x), model[(2 * x - FIRST) as usize].as_ref());
+        }
+    }
+
+    #[test]
+    fn max_overlap_matches_brute_force() {
+        let mut rng = SeededRng::new(253);
+        for _ in 0..500 {
+            let ranges: Vec<_> = (0..rng.in_range(0..8))
+                .map(|_| random_range(&mut rng))
+                .collect();
+            let brute = (FIRST..END)
+                .map(|s| {
+                    ranges
+                        .iter()
+                        .filter(|range| holds(as_ref(range), s))
+                        .count()
+                })
+                .max()
+                .unwrap();
+            assert_eq!(max_overlap(ranges), brute);
+        }
+        assert_eq!(max_overlap([0..30, 5..10, 10..20]), 2);
+        assert_eq!(max_overlap::<i32, std::ops::Range<i32>>([]), 0);
+    }
+}
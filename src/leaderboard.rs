@@ -0,0 +1,475 @@
+//! A leaderboard ordered by score, with O(log n) updates and rank queries.
+//!
+//! Standings live in a treap whose nodes know their subtree sizes, so the
+//! rank of a player, the player at a rank and the number of scores in a
+//! range are all one root-to-leaf walk. Ties between equal scores are broken
+//! by a [`TieBreak`] policy chosen up front, which makes every rank distinct;
+//! [`competition_rank`](Leaderboard::competition_rank) gives the shared
+//! "1, 2, 2, 4" rank instead.
+//!
+//! Ranks are 1-based: rank 1 is the best score.
+//!
+//! ```
+//! use leetcode::leaderboard::{Leaderboard, TieBreak};
+//!
+//! let mut board = Leaderboard::with_tie_break(TieBreak::FirstReached);
+//! board.add_score("ana", 30);
+//! board.add_score("bo", 50);
+//! board.add_score("cy", 20);
+//! board.add_score("cy", 10); // cy reaches 30 after ana
+//! assert_eq!(board.rank_of(&"bo"), Some(1));
+//! assert_eq!(board.rank_of(&"cy"), Some(3));
+//! assert_eq!(board.competition_rank(&"cy"), Some(2));
+//! assert_eq!(board.player_at_rank(2), Some((&"ana", &30)));
+//! let top: Vec<_> = board.top(2).map(|(player, _)| *player).collect();
+//! assert_eq!(top, ["bo", "ana"]);
+//! assert_eq!(board.count_in(25..=50), 3);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Bound, RangeBounds};
+
+/// How to order players with equal scores.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TieBreak {
+    /// Whoever reached the score first ranks higher.
+    #[default]
+    FirstReached,
+    /// Whoever reached the score most recently ranks higher.
+    LastReached,
+    /// The smaller player ranks higher.
+    PlayerOrder,
+}
+
+/// Sort key of a standing: best score first, then the tie-break, then the
+/// player to make keys unique.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Standing<P, S> {
+    score: Reverse<S>,
+    tie: u64,
+    player: P,
+}
+
+type Link<P, S> = Option<Box<Node<P, S>>>;
+
+/// A treap node; in-order keys are the standings from best to worst.
+#[derive(Clone, Debug)]
+struct Node<P, S> {
+    key: Standing<P, S>,
+    size: usize,
+    priority: u64,
+    left: Link<P, S>,
+    right: Link<P, S>,
+}
+
+fn size<P, S>(link: &Link<P, S>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+impl<P, S> Node<P, S> {
+    fn update(&mut self) {
+        self.size = size(&self.left) + 1 + size(&self.right);
+    }
+}
+
+fn merge<P, S>(a: Link<P, S>, b: Link<P, S>) -> Link<P, S> {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                a.update();
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                b.update();
+                Some(b)
+            }
+        }
+    }
+}
+
+/// Splits `link` into the keys satisfying `before`, which must hold for a
+/// prefix of the order, and the rest.
+fn split<P, S>(
+    link: Link<P, S>,
+    before: &impl Fn(&Standing<P, S>) -> bool,
+) -> (Link<P, S>, Link<P, S>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    if before(&node.key) {
+        let (a, b) = split(node.right.take(), before);
+        node.right = a;
+        node.update();
+        (Some(node), b)
+    } else {
+        let (a, b) = split(node.left.take(), before);
+        node.left = b;
+        node.update();
+        (a, Some(node))
+    }
+}
+
+/// Number of keys satisfying `before`, which must hold for a prefix.
+fn prefix_len<P, S>(mut link: &Link<P, S>, before: impl Fn(&Standing<P, S>) -> bool) -> usize {
+    let mut count = 0;
+    while let Some(node) = link {
+        if before(&node.key) {
+            count += size(&node.left) + 1;
+            link = &node.right;
+        } else {
+            link = &node.left;
+        }
+    }
+    count
+}
+
+/// Players ranked by score, with an order-statistics index over standings.
+///
+/// # Intuition
+/// Rank queries outnumber writes, so keep the standings permanently sorted.
+/// A balanced search tree whose nodes count their subtree answers "how many
+/// standings sort before this one" and "which standing is `k`-th" by walking
+/// a single path, where re-sorting would touch every player.
+///
+/// # Approach
+/// 1. A hash map holds each player's score and tie value, which rebuilds the
+///    player's tree key on demand.
+/// 2. A score change removes the old key from the treap by splitting around
+///    it and inserts the new one by splitting at its position and merging.
+/// 3. Ranks and range counts are prefix lengths under monotone predicates;
+///    `top(k)` walks the leftmost spine once and then yields in order.
+///
+/// # Complexity
+/// - Time: O(log n) expected per update, rank and count, O(k + log n) for
+///   the top `k`
+/// - Space: O(n)
+#[derive(Clone, Debug)]
+pub struct Leaderboard<P, S> {
+    tie_break: TieBreak,
+    /// Each player's score and tie value.
+    players: HashMap<P, (S, u64)>,
+    root: Link<P, S>,
+    /// Count of score changes so far, which orders ties by time.
+    clock: u64,
+    /// State of the xorshift generator that draws node priorities.
+    seed: u64,
+}
+
+impl<P: Ord + Hash + Clone, S: Ord + Clone> Default for Leaderboard<P, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Ord + Hash + Clone, S: Ord + Clone> Leaderboard<P, S> {
+    /// An empty leaderboard breaking ties in favour of whoever scored first.
+    pub fn new() -> Self {
+        Self::with_tie_break(TieBreak::default())
+    }
+
+    /// An empty leaderboard ordering equal scores by `tie_break`.
+    pub fn with_tie_break(tie_break: TieBreak) -> Self {
+        Self {
+            tie_break,
+            players: HashMap::new(),
+            root: None,
+            clock: 0,
+            seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// The policy ordering equal scores.
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+
+    /// Number of players.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Whether there are no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    fn priority(&mut self) -> u64 {
+        // xorshift64*
+        self.seed ^= self.seed >> 12;
+        self.seed ^= self.seed << 25;
+        self.seed ^= self.seed >> 27;
+        self.seed.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn standing(player: &P, (score, tie): &(S, u64)) -> Standing<P, S> {
+        Standing {
+            score: Reverse(score.clone()),
+            tie: *tie,
+            player: player.clone(),
+        }
+    }
+
+    /// Takes `key` out of the treap.
+    fn unlink(&mut self, key: &Standing<P, S>) {
+        let (before, rest) = split(self.root.take(), &|k| k < key);
+        let (_, after) = split(rest, &|k| k <= key);
+        self.root = merge(before, after);
+    }
+
+    /// Sets `player`'s score, returning the previous one. Setting the score
+    /// a player already has keeps their place among ties.
+    pub fn set_score(&mut self, player: P, score: S) -> Option<S> {
+        let kept = match self.players.get(&player) {
+            Some((old, tie)) if *old == score => Some(*tie),
+            _ => None,
+        };
+        let previous = self.remove(&player);
+        let tie = kept.unwrap_or_else(|| {
+            self.clock += 1;
+            match self.tie_break {
+                TieBreak::FirstReached => self.clock,
+                TieBreak::LastReached => u64::MAX - self.clock,
+                TieBreak::PlayerOrder => 0,
+            }
+        });
+        let key = Self::standing(&player, &(score.clone(), tie));
+        let node = Box::new(Node {
+            key,
+            size: 1,
+            priority: self.priority(),
+            left: None,
+            right: None,
+        });
+        let (before, after) = split(self.root.take(), &|k| *k < node.key);
+        self.root = merge(merge(before, Some(node)), after);
+        self.players.insert(player, (score, tie));
+        previous
+    }
+
+    /// Adds `delta` to `player`'s score, starting from `delta` for a new
+    /// player, and returns the new total.
+    pub fn add_score(&mut self, player: P, delta: S) -> &S
+    where
+        S: Add<Output = S>,
+    {
+        let total = match self.players.get(&player) {
+            Some((score, _)) => score.clone() + delta,
+            None => delta,
+        };
+        self.set_score(player.clone(), total);
+        &self.players[&player].0
+    }
+
+    /// Removes `player`, returning their score.
+    pub fn remove(&mut self, player: &P) -> Option<S> {
+        let entry = self.players.remove(player)?;
+        self.unlink(&Self::standing(player, &entry));
+        Some(entry.0)
+    }
+
+    /// `player`'s score.
+    pub fn score(&self, player: &P) -> Option<&S> {
+        self.players.get(player).map(|(score, _)| score)
+    }
+
+    /// `player`'s position in the standings, 1 for the best.
+    pub fn rank_of(&self, player: &P) -> Option<usize> {
+        let key = Self::standing(player, self.players.get(player)?);
+        Some(prefix_len(&self.root, |k| *k < key) + 1)
+    }
+
+    /// One more than the number of players with a strictly better score, so
+    /// that equal scores share a rank.
+    pub fn competition_rank(&self, player: &P) -> Option<usize> {
+        let (score, _) = self.players.get(player)?;
+        Some(prefix_len(&self.root, |k| k.score.0 > *score) + 1)
+    }
+
+    /// The player at 1-based `rank` and their score.
+    pub fn player_at_rank(&self, rank: usize) -> Option<(&P, &S)> {
+        let mut index = rank.checked_sub(1)?;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = size(&node.left);
+            if index < left {
+                link = &node.left;
+            } else if index == left {
+                return Some((&node.key.player, &node.key.score.0));
+            } else {
+                index -= left + 1;
+                link = &node.right;
+            }
+        }
+        None
+    }
+
+    /// The best `k` players and their scores, best first.
+    pub fn top(&self, k: usize) -> Iter<'_, P, S> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: k.min(self.len()),
+        };
+        iter.descend(&self.root);
+        iter
+    }
+
+    /// Every player and score, best first.
+    pub fn iter(&self) -> Iter<'_, P, S> {
+        self.top(self.len())
+    }
+
+    /// Number of players whose score lies in `range`.
+    pub fn count_in(&self, range: impl RangeBounds<S>) -> usize {
+        let at_least = match range.start_bound() {
+            Bound::Included(lo) => prefix_len(&self.root, |k| k.score.0 >= *lo),
+            Bound::Excluded(lo) => prefix_len(&self.root, |k| k.score.0 > *lo),
+            Bound::Unbounded => self.len(),
+        };
+        let above = match range.end_bound() {
+            Bound::Included(hi) => prefix_len(&self.root, |k| k.score.0 > *hi),
+            Bound::Excluded(hi) => prefix_len(&self.root, |k| k.score.0 >= *hi),
+            Bound::Unbounded => 0,
+        };
+        at_least.saturating_sub(above)
+    }
+}
+
+/// Iterator over standings, best first.
+pub struct Iter<'a, P, S> {
+    /// Nodes still to yield, each before its right subtree.
+    stack: Vec<&'a Node<P, S>>,
+    remaining: usize,
+}
+
+impl<'a, P, S> Iter<'a, P, S> {
+    fn descend(&mut self, mut link: &'a Link<P, S>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, P, S> Iterator for Iter<'a, P, S> {
+    type Item = (&'a P, &'a S);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let node = self.stack.pop()?;
+        self.descend(&node.right);
+        Some((&node.key.player, &node.key.score.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<P, S> ExactSizeIterator for Iter<'_, P, S> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    #[test]
+    fn tie_break_policies() {
+        for (tie_break, expected) in [
+            (TieBreak::FirstReached, [3, 1, 2]),
+            (TieBreak::LastReached, [2, 1, 3]),
+            (TieBreak::PlayerOrder, [1, 2, 3]),
+        ] {
+            let mut board = Leaderboard::with_tie_break(tie_break);
+            board.set_score(3, 10);
+            board.set_score(1, 10);
+            board.set_score(2, 5);
+            board.add_score(2, 5);
+            let order: Vec<i32> = board.iter().map(|(&p, _)| p).collect();
+            assert_eq!(order, expected, "{tie_break:?}");
+            assert!(
+                [1, 2, 3]
+                    .iter()
+                    .all(|p| board.competition_rank(p) == Some(1))
+            );
+        }
+    }
+
+    #[test]
+    fn matches_sorted_vector() {
+        let mut rng = SeededRng::new(1244);
+        for tie_break in [
+            TieBreak::FirstReached,
+            TieBreak::LastReached,
+            TieBreak::PlayerOrder,
+        ] {
+            let mut board = Leaderboard::with_tie_break(tie_break);
+            // (player, score, time of reaching it)
+            let mut naive: Vec<(u32, i64, u64)> = Vec::new();
+            for time in 0..3000 {
+                let player = rng.in_range(0..200);
+                let naive_index = naive.iter().position(|e| e.0 == player);
+                match rng.in_range(0..10) {
+                    0 => {
+                        let removed = naive_index.map(|i| naive.remove(i).1);
+                        assert_eq!(board.remove(&player), removed);
+                    }
+                    op => {
+                        let old = naive_index.map(|i| naive.remove(i));
+                        let score = if op <= 6 {
+                            let delta = rng.in_range(-5..20);
+                            let expected = old.map_or(0, |e| e.1) + delta;
+                            assert_eq!(*board.add_score(player, delta), expected);
+                            expected
+                        } else {
+                            let score = rng.in_range(0..50);
+                            assert_eq!(board.set_score(player, score), old.map(|e| e.1));
+                            score
+                        };
+                        // an unchanged score keeps its place among ties
+                        let reached = old.filter(|e| e.1 == score).map_or(time, |e| e.2);
+                        naive.push((player, score, reached));
+                    }
+                }
+                naive.sort_by_key(|&(player, score, time)| {
+                    let tie = match tie_break {
+                        TieBreak::FirstReached => time,
+                        TieBreak::LastReached => u64::MAX - time,
+                        TieBreak::PlayerOrder => 0,
+                    };
+                    (Reverse(score), tie, player)
+                });
+                assert_eq!(board.len(), naive.len());
+                let k = rng.in_range(0..10);
+                let top: Vec<_> = board.top(k).map(|(&p, &s)| (p, s)).collect();
+                let expected: Vec<_> = naive.iter().take(k).map(|e| (e.0, e.1)).collect();
+                assert_eq!(top, expected);
+                let probe = rng.in_range(0..200);
+                let rank = naive.iter().position(|e| e.0 == probe).map(|i| i + 1);
+                assert_eq!(board.rank_of(&probe), rank);
+                let shared =
+                    rank.map(|r| naive.iter().filter(|e| e.1 > naive[r - 1].1).count() + 1);
+                assert_eq!(board.competition_rank(&probe), shared);
+                let r = rng.in_range(0..=naive.len() + 1);
+                let at = r
+                    .checked_sub(1)
+                    .and_then(|i| naive.get(i))
+                    .map(|e| (&e.0, &e.1));
+                assert_eq!(board.player_at_rank(r), at);
+                let (lo, hi) = (rng.in_range(-10..60), rng.in_range(-10..60));
+                let count = |f: &dyn Fn(i64) -> bool| naive.iter().filter(|e| f(e.1)).count();
+                assert_eq!(board.count_in(lo..hi), count(&|s| lo <= s && s < hi));
+                assert_eq!(board.count_in(lo..=hi), count(&|s| lo <= s && s <= hi));
+                assert_eq!(
+                    board.count_in((Bound::Excluded(lo), Bound::Unbounded)),
+                    count(&|s| s > lo)
+                );
+                assert_eq!(board.count_in(..=hi), count(&|s| s <= hi));
+            }
+        }
+    }
+}
@@ -0,0 +1,553 @@
+//! A least-frequently-used cache with O(1) operations, least-recently-used
+//! tie-breaking, optional frequency decay and hit, miss and eviction
+//! counters.
+//!
+//! Entries live in a slab and are linked by index into one recency list per
+//! use count, and the counts in use are themselves linked in increasing
+//! order, so a use, an eviction and a removal each move one node between
+//! lists and the smallest count is always at hand.
+//!
+//! ```
+//! use leetcode::lfu_cache::LfuCache;
+//!
+//! let mut cache = LfuCache::new(2);
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//! assert_eq!(cache.get("a"), Some(&1)); // "a" used twice, "b" once
+//! assert_eq!(cache.put("c", 3), Some(("b", 2)));
+//! assert_eq!(cache.get("b"), None);
+//! let stats = cache.stats();
+//! assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 1, 1));
+//! ```
+//!
+//! Without decay a key that was hot long ago can never be evicted in favour
+//! of newer keys. With it, every count is halved after a fixed number of
+//! operations, so old popularity fades:
+//!
+//! ```
+//! use leetcode::lfu_cache::LfuCache;
+//!
+//! let mut cache = LfuCache::with_decay(2, 4);
+//! cache.put("old", 0);
+//! for _ in 0..3 {
+//!     cache.get("old"); // the last one first halves "old" from 3 to 1
+//! }
+//! cache.put("new", 1);
+//! cache.get("new");
+//! assert_eq!(cache.frequency("old"), Some(2));
+//! assert_eq!(cache.frequency("new"), Some(2));
+//! // Tied counts fall back to recency.
+//! assert_eq!(cache.put("newer", 2), Some(("old", 0)));
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Link to no node.
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    /// Number of uses, including the insertion.
+    freq: u64,
+    /// Towards the most recent entry of the same count.
+    prev: usize,
+    /// Towards the least recent entry of the same count.
+    next: usize,
+}
+
+/// The ends of the recency list of one use count, and the neighbouring
+/// counts in use, `0` standing for none.
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    head: usize,
+    tail: usize,
+    lower: u64,
+    higher: u64,
+}
+
+/// Lookup and eviction counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Lookups that found their key.
+    pub hits: u64,
+    /// Lookups that did not.
+    pub misses: u64,
+    /// Entries evicted to make room.
+    pub evictions: u64,
+}
+
+/// A map that holds at most `capacity` entries, evicting the least
+/// frequently used one to make room, and of those the least recently used.
+/// [`LfuCache::get`], [`LfuCache::get_mut`] and [`LfuCache::put`] count as
+/// uses; [`LfuCache::peek`] does not.
+///
+/// Keys are stored twice, in the index and in the node, hence `K: Clone`.
+#[derive(Clone, Debug)]
+pub struct LfuCache<K, V> {
+    index: HashMap<K, usize>,
+    /// Free slots are `None` and listed in `free`.
+    nodes: Vec<Option<Node<K, V>>>,
+    free: Vec<usize>,
+    buckets: HashMap<u64, Bucket>,
+    /// The smallest count in use, or `0` when empty.
+    min_freq: u64,
+    capacity: usize,
+    /// Operations between halvings, if decay is on.
+    decay_period: Option<usize>,
+    /// Operations since the last halving.
+    ticks: usize,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    /// A cache of at most `capacity` entries whose counts never decay.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            free: Vec::new(),
+            buckets: HashMap::new(),
+            min_freq: 0,
+            capacity,
+            decay_period: None,
+            ticks: 0,
+            stats: Stats::default(),
+        }
+    }
+
+    /// A cache of at most `capacity` entries that halves every count, to no
+    /// less than one, after each `period` calls to [`Self::get`],
+    /// [`Self::get_mut`] and [`Self::put`]. Entries tied after a halving
+    /// keep the order of their counts before it, then of recency.
+    ///
+    /// A halving relinks every entry in count order, O(n), spread over the
+    /// `period` operations before it.
+    ///
+    /// # Panics
+    /// If `period` is zero.
+    pub fn with_decay(capacity: usize, period: usize) -> Self {
+        assert!(period > 0, "decay period must be positive");
+        Self {
+            decay_period: Some(period),
+            ..Self::new(capacity)
+        }
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bound on [`Self::len`].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The counters so far.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Whether `key` is cached, without counting as a use.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// The use count of `key`, after any decay.
+    pub fn frequency<Q>(&self, key: &Q) -> Option<u64>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(key).map(|&i| self.node(i).freq)
+    }
+
+    /// The value for `key`, counting a use.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// The value for `key`, counting a use.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.tick();
+        let Some(&i) = self.index.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        self.stats.hits += 1;
+        self.touch(i);
+        Some(&mut self.node_mut(i).value)
+    }
+
+    /// The value for `key`, without counting a use.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(key).map(|&i| &self.node(i).value)
+    }
+
+    /// Replaces the value for `key` and counts a use, or inserts it with a
+    /// count of one after evicting an entry if the cache is full. Returns the
+    /// evicted entry, which is the new one itself at zero capacity.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.tick();
+        if let Some(&i) = self.index.get(&key) {
+            self.node_mut(i).value = value;
+            self.touch(i);
+            return None;
+        }
+        if self.capacity == 0 {
+            self.stats.evictions += 1;
+            return Some((key, value));
+        }
+        let evicted = if self.len() == self.capacity {
+            self.stats.evictions += 1;
+            self.pop_lfu()
+        } else {
+            None
+        };
+        let node = Node {
+            key: key.clone(),
+            value,
+            freq: 1,
+            prev: NIL,
+            next: NIL,
+        };
+        let i = match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = Some(node);
+                i
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        self.index.insert(key, i);
+        self.add_bucket(1, 0);
+        self.link_front(i);
+        evicted
+    }
+
+    /// Removes `key`, returning its value.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = *self.index.get(key)?;
+        Some(self.remove(i).1)
+    }
+
+    /// Removes and returns the entry the next insertion into a full cache
+    /// would evict. Not counted as an eviction.
+    pub fn pop_lfu(&mut self) -> Option<(K, V)> {
+        if self.is_empty() {
+            return None;
+        }
+        let i = self.buckets[&self.min_freq].tail;
+        Some(self.remove(i))
+    }
+
+    /// Counts an operation towards the decay period.
+    fn tick(&mut self) {
+        let Some(period) = self.decay_period else {
+            return;
+        };
+        self.ticks += 1;
+        if self.ticks < period {
+            return;
+        }
+        self.ticks = 0;
+        let mut order = Vec::with_capacity(self.len());
+        let mut count = self.min_freq;
+        while count != 0 {
+            let bucket = self.buckets[&count];
+            let mut i = bucket.tail;
+            while i != NIL {
+                order.push(i);
+                i = self.node(i).prev;
+            }
+            count = bucket.higher;
+        }
+        self.buckets.clear();
+        self.min_freq = 0;
+        // Halving keeps the counts in order, so each new list goes on top.
+        let mut top = 0;
+        for i in order {
+            let node = self.node_mut(i);
+            node.freq = (node.freq / 2).max(1);
+            let freq = node.freq;
+            self.add_bucket(freq, top);
+            top = freq;
+            self.link_front(i);
+        }
+    }
+
+    /// Moves node `i` up to the next count.
+    fn touch(&mut self, i: usize) {
+        let freq = self.node(i).freq;
+        self.add_bucket(freq + 1, freq);
+        self.unlink(i);
+        self.node_mut(i).freq += 1;
+        self.link_front(i);
+    }
+
+    fn remove(&mut self, i: usize) -> (K, V) {
+        self.unlink(i);
+        let node = self.nodes[i].take().unwrap();
+        self.free.push(i);
+        self.index.remove(&node.key);
+        (node.key, node.value)
+    }
+
+    fn node(&self, i: usize) -> &Node<K, V> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<K, V> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    /// Unlinks node `i` from the list of its count, dropping the list once
+    /// it is empty.
+    fn unlink(&mut self, i: usize) {
+        let Node {
+            prev, next, freq, ..
+        } = *self.node(i);
+        if prev != NIL {
+            self.node_mut(prev).next = next;
+        }
+        if next != NIL {
+            self.node_mut(next).prev = prev;
+        }
+        let bucket = self.buckets.get_mut(&freq).unwrap();
+        if prev == NIL {
+            bucket.head = next;
+        }
+        if next == NIL {
+            bucket.tail = prev;
+        }
+        if bucket.head != NIL {
+            return;
+        }
+        let Bucket { lower, higher, .. } = self.buckets.remove(&freq).unwrap();
+        match lower {
+            0 => self.min_freq = higher,
+            lower => self.buckets.get_mut(&lower).unwrap().higher = higher,
+        }
+        if higher != 0 {
+            self.buckets.get_mut(&higher).unwrap().lower = lower;
+        }
+    }
+
+    /// Adds an empty list for `freq` just above the count `lower`, or at the
+    /// bottom when `lower` is `0`, unless `freq` already has one.
+    fn add_bucket(&mut self, freq: u64, lower: u64) {
+        if self.buckets.contains_key(&freq) {
+            return;
+        }
+        let higher = match lower {
+            0 => self.min_freq,
+            lower => self.buckets[&lower].higher,
+        };
+        debug_assert!(lower < freq && (higher == 0 || freq < higher));
+        self.buckets.insert(
+            freq,
+            Bucket {
+                head: NIL,
+                tail: NIL,
+                lower,
+                higher,
+            },
+        );
+        match lower {
+            0 => self.min_freq = freq,
+            lower => self.buckets.get_mut(&lower).unwrap().higher = freq,
+        }
+        if higher != 0 {
+            self.buckets.get_mut(&higher).unwrap().lower = freq;
+        }
+    }
+
+    /// Links the unlinked node `i` in as the most recent entry of its count,
+    /// whose list must exist.
+    fn link_front(&mut self, i: usize) {
+        let freq = self.node(i).freq;
+        let bucket = self.buckets.get_mut(&freq).unwrap();
+        let head = bucket.head;
+        bucket.head = i;
+        if head == NIL {
+            bucket.tail = i;
+        } else {
+            self.node_mut(head).prev = i;
+        }
+        let node = self.node_mut(i);
+        (node.prev, node.next) = (NIL, head);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    /// Entries as `(key, value, count, order)`, where `order` grows each time
+    /// an entry joins the list of its count.
+    struct Naive {
+        entries: Vec<(u8, i32, u64, u64)>,
+        clock: u64,
+        capacity: usize,
+        period: Option<usize>,
+        ticks: usize,
+        stats: Stats,
+    }
+
+    impl Naive {
+        fn tick(&mut self) {
+            let Some(period) = self.period else { return };
+            self.ticks += 1;
+            if self.ticks == period {
+                self.ticks = 0;
+                self.entries.sort_by_key(|e| (e.2, e.3));
+                for e in &mut self.entries {
+                    self.clock += 1;
+                    (e.2, e.3) = ((e.2 / 2).max(1), self.clock);
+                }
+            }
+        }
+
+        fn get(&mut self, key: u8) -> Option<i32> {
+            self.tick();
+            self.clock += 1;
+            let clock = self.clock;
+            match self.entries.iter_mut().find(|e| e.0 == key) {
+                Some(e) => {
+                    self.stats.hits += 1;
+                    (e.2, e.3) = (e.2 + 1, clock);
+                    Some(e.1)
+                }
+                None => {
+                    self.stats.misses += 1;
+                    None
+                }
+            }
+        }
+
+        fn put(&mut self, key: u8, value: i32) -> Option<(u8, i32)> {
+            self.tick();
+            self.clock += 1;
+            let clock = self.clock;
+            if let Some(e) = self.entries.iter_mut().find(|e| e.0 == key) {
+                (e.1, e.2, e.3) = (value, e.2 + 1, clock);
+                return None;
+            }
+            if self.capacity == 0 {
+                self.stats.evictions += 1;
+                return Some((key, value));
+            }
+            let mut evicted = None;
+            if self.entries.len() == self.capacity {
+                self.stats.evictions += 1;
+                let victim = (0..self.entries.len())
+                    .min_by_key(|&j| (self.entries[j].2, self.entries[j].3))
+                    .unwrap();
+                let e = self.entries.swap_remove(victim);
+                evicted = Some((e.0, e.1));
+            }
+            self.entries.push((key, value, 1, clock));
+            evicted
+        }
+    }
+
+    fn check_against_naive(period: Option<usize>, seed: u64) {
+        let mut rng = SeededRng::new(seed);
+        for capacity in 0..6 {
+            let mut cache = match period {
+                Some(p) => LfuCache::with_decay(capacity, p),
+                None => LfuCache::new(capacity),
+            };
+            let mut naive = Naive {
+                entries: Vec::new(),
+                clock: 0,
+                capacity,
+                period,
+                ticks: 0,
+                stats: Stats::default(),
+            };
+            for step in 0..1000 {
+                let key = rng.in_range(0..8);
+                match rng.in_range(0..10) {
+                    0..4 => assert_eq!(cache.get(&key).copied(), naive.get(key)),
+                    4 => {
+                        let expected = naive.entries.iter().find(|e| e.0 == key);
+                        assert_eq!(cache.peek(&key).copied(), expected.map(|e| e.1));
+                        assert_eq!(cache.frequency(&key), expected.map(|e| e.2));
+                    }
+                    5 => {
+                        let expected = naive
+                            .entries
+                            .iter()
+                            .position(|e| e.0 == key)
+                            .map(|p| naive.entries.swap_remove(p).1);
+                        assert_eq!(cache.pop(&key), expected);
+                    }
+                    _ => assert_eq!(cache.put(key, step), naive.put(key, step)),
+                }
+                assert_eq!(cache.len(), naive.entries.len());
+                assert_eq!(cache.stats(), naive.stats);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_naive_without_decay() {
+        check_against_naive(None, 17);
+    }
+
+    #[test]
+    fn matches_naive_with_decay() {
+        for period in [1, 3, 10] {
+            check_against_naive(Some(period), 18 + period as u64);
+        }
+    }
+
+    #[test]
+    fn pop_lfu_after_removing_the_rarest() {
+        let mut cache = LfuCache::new(3);
+        cache.put(1, 'a');
+        cache.put(2, 'b');
+        cache.get(&2);
+        cache.put(3, 'c');
+        cache.get(&3);
+        cache.pop(&1);
+        // No entry is left with a count of one.
+        assert_eq!(cache.pop_lfu(), Some((2, 'b')));
+        assert_eq!(cache.pop_lfu(), Some((3, 'c')));
+        assert_eq!(cache.pop_lfu(), None);
+        assert_eq!(cache.stats().evictions, 0);
+    }
+}
@@ -0,0 +1,38 @@
+//! LeetCode solutions in Rust.
+//!
+//! Every `NNNN.Title.rs` file at the crate root is compiled as its own module,
+//! named `pNNNN_title` (for example `0001.Two Sum.rs` becomes [`p0001_two_sum`]).
+//! The module list is generated by `build.rs`, which also supplies the unit
+//! `Solution` struct that LeetCode normally declares for you.
+
+pub mod differential;
+pub mod editor;
+pub mod fenwick;
+pub mod graph;
+pub mod grapheme;
+pub mod interval_set;
+pub mod leaderboard;
+pub mod lfu_cache;
+pub mod list_node;
+pub mod literal;
+pub mod lru_cache;
+pub mod mod_int;
+pub mod number_theory;
+pub mod prelude;
+pub mod quantile;
+pub mod random;
+pub mod range_query;
+pub mod rate_limit;
+pub mod registry;
+pub mod replay;
+pub mod rooted_tree;
+pub mod rope;
+pub mod runner;
+pub mod sampling;
+pub mod segment_tree;
+pub mod sorted_list;
+pub mod tree_node;
+pub mod trie;
+pub mod union_find;
+
+include!(concat!(env!("OUT_DIR"), "/problems.rs"));
@@ -0,0 +1,206 @@
+//! Singly-linked list node as defined by LeetCode, plus [`List`], an owned
+//! list that converts to and from LeetCode's `[1,2,3]` notation.
+
+use std::fmt;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+
+    /// Iterates over the values from this node to the tail.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { next: Some(self) }
+    }
+}
+
+impl fmt::Display for ListNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_values(f, self.iter())
+    }
+}
+
+/// A possibly empty list, wrapping the `Option<Box<ListNode>>` head that
+/// LeetCode signatures pass around.
+///
+/// ```
+/// use leetcode::list_node::List;
+///
+/// let list = List::from(vec![1, 2, 3]);
+/// assert_eq!(list.to_string(), "[1,2,3]");
+/// assert_eq!(list.into_iter().map(|x| x * 10).collect::<Vec<_>>(), vec![10, 20, 30]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct List(pub Option<Box<ListNode>>);
+
+impl List {
+    /// Returns the head node, ready to hand to a solution.
+    #[inline]
+    pub fn into_inner(self) -> Option<Box<ListNode>> {
+        self.0
+    }
+
+    /// Iterates over the values from head to tail.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            next: self.0.as_deref(),
+        }
+    }
+
+    /// Copies the values into a `Vec`, head first.
+    pub fn to_vec(&self) -> Vec<i32> {
+        self.iter().copied().collect()
+    }
+}
+
+impl From<Vec<i32>> for List {
+    fn from(values: Vec<i32>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl From<Option<Box<ListNode>>> for List {
+    fn from(head: Option<Box<ListNode>>) -> Self {
+        List(head)
+    }
+}
+
+impl From<List> for Option<Box<ListNode>> {
+    fn from(list: List) -> Self {
+        list.0
+    }
+}
+
+impl FromIterator<i32> for List {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        let mut head = None;
+        let mut tail = &mut head;
+        for val in iter {
+            tail = &mut tail.insert(Box::new(ListNode::new(val))).next;
+        }
+        List(head)
+    }
+}
+
+impl IntoIterator for List {
+    type Item = i32;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter { next: self.0 }
+    }
+}
+
+impl<'a> IntoIterator for &'a List {
+    type Item = &'a i32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_values(f, self.iter())
+    }
+}
+
+/// Borrowing iterator over list values, created by [`ListNode::iter`] and
+/// [`List::iter`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    next: Option<&'a ListNode>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a i32;
+
+    fn next(&mut self) -> Option<&'a i32> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        Some(&node.val)
+    }
+}
+
+/// Owning iterator over list values, unlinking one node per step.
+#[derive(Debug)]
+pub struct IntoIter {
+    next: Option<Box<ListNode>>,
+}
+
+impl Iterator for IntoIter {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let node = self.next.take()?;
+        self.next = node.next;
+        Some(node.val)
+    }
+}
+
+fn write_values(f: &mut fmt::Formatter<'_>, values: Iter<'_>) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, val) in values.enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{val}")?;
+    }
+    f.write_str("]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_vec() {
+        let list = List::from(vec![1, 2, 3]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn empty_list() {
+        let list = List::from(vec![]);
+        assert_eq!(list.into_inner(), None);
+        assert_eq!(List::default().to_string(), "[]");
+    }
+
+    #[test]
+    fn collects_from_iterator() {
+        let list: List = (1..=4).map(|x| x * x).collect();
+        assert_eq!(list.to_vec(), vec![1, 4, 9, 16]);
+    }
+
+    #[test]
+    fn links_nodes_in_order() {
+        let head = List::from(vec![7, 8]).into_inner().unwrap();
+        assert_eq!(head.val, 7);
+        assert_eq!(head.next.as_ref().map(|node| node.val), Some(8));
+        assert_eq!(head.next.unwrap().next, None);
+    }
+
+    #[test]
+    fn displays_leetcode_format() {
+        let list = List::from(vec![1, -2, 30]);
+        assert_eq!(list.to_string(), "[1,-2,30]");
+        assert_eq!(list.0.unwrap().to_string(), "[1,-2,30]");
+    }
+
+    #[test]
+    fn borrowing_iter_leaves_list_intact() {
+        let list = List::from(vec![5, 6]);
+        let sum: i32 = (&list).into_iter().sum();
+        assert_eq!(sum, 11);
+        assert_eq!(list.to_vec(), vec![5, 6]);
+    }
+}
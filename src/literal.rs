@@ -0,0 +1,603 @@
+//! LeetCode's literal syntax for example inputs and outputs, such as
+//! `nums = [2,7,11,15], target = 9` or `[[1,2],null,"a"]`.
+//!
+//! Literals parse into a [`Value`]; [`FromValue`] and [`IntoValue`] convert
+//! between values and the argument and return types of `Solution` methods,
+//! with trees and linked lists written in their usual array form.
+//!
+//! ```
+//! use leetcode::literal::{self, FromValue, Value};
+//!
+//! let args = literal::parse_args("nums = [2,7,11,15], target = 9").unwrap();
+//! assert_eq!(args[1], Value::Int(9));
+//! let nums = Vec::<i32>::from_value(args[0].clone()).unwrap();
+//! assert_eq!(nums, vec![2, 7, 11, 15]);
+//! ```
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::list_node::{List, ListNode};
+use crate::tree_node::{Tree, TreeNode};
+
+/// A parsed literal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Whether `self` is an acceptable answer for `expected`: floats match
+    /// within LeetCode's `1e-5` tolerance and integers match equal floats.
+    pub fn matches(&self, expected: &Value) -> bool {
+        match (self, expected) {
+            (Value::Array(a), Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.matches(b))
+            }
+            (Value::Float(_) | Value::Int(_), Value::Float(_) | Value::Int(_)) => {
+                let (a, b) = (self.as_f64().unwrap(), expected.as_f64().unwrap());
+                (a - b).abs() <= 1e-5 * b.abs().max(1.0)
+            }
+            _ => self == expected,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Int(n) => Some(n as f64),
+            Value::Float(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Int(_) => "integer",
+            Value::Float(_) => "float",
+            Value::Str(_) => "string",
+            Value::Array(_) => "array",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    /// Prints the value the way LeetCode shows outputs, e.g. `[1,null,"a"]`
+    /// and `2.50000`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Float(x) => write!(f, "{x:.5}"),
+            Value::Str(s) => {
+                f.write_str("\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => f.write_str("\\\"")?,
+                        '\\' => f.write_str("\\\\")?,
+                        '\n' => f.write_str("\\n")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                f.write_str("\"")
+            }
+            Value::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    /// Parses a single literal such as `[[1,2],[3]]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(value),
+            Some(_) => Err(parser.error("unexpected trailing input")),
+        }
+    }
+}
+
+/// Parses the arguments of an example, either as LeetCode prints them in a
+/// problem statement (`nums = [2,7,11,15], target = 9`) or as the test-case
+/// box holds them (one bare literal per line).
+pub fn parse_args(input: &str) -> Result<Vec<Value>, ParseError> {
+    let mut parser = Parser::new(input);
+    let mut args = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            return Ok(args);
+        }
+        parser.argument_name();
+        args.push(parser.value()?);
+        parser.skip_whitespace();
+        match parser.peek() {
+            Some(',') => parser.bump(),
+            Some(_) if parser.newline_since_value => {}
+            Some(_) => return Err(parser.error("expected `,` between arguments")),
+            None => {}
+        }
+    }
+}
+
+/// Error returned for malformed literal syntax.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset into the input where parsing failed.
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl Error for ParseError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    newline_since_value: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            pos: 0,
+            newline_since_value: false,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError {
+            offset: self.pos,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.newline_since_value |= c == '\n';
+            self.bump();
+        }
+    }
+
+    /// Skips a leading `name =`, if present.
+    fn argument_name(&mut self) {
+        let rest = &self.input[self.pos..];
+        let name_len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let starts_like_name = rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+        if name_len > 0 && starts_like_name {
+            let after = rest[name_len..].trim_start();
+            if after.starts_with('=') {
+                self.pos += rest.len() - after.len() + 1;
+                self.skip_whitespace();
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some('[') => self.array()?,
+            Some('"') => Value::Str(self.string()?),
+            Some(c) if c == '-' || c == '+' || c.is_ascii_digit() => self.number()?,
+            Some(_) => self.keyword()?,
+            None => return Err(self.error("expected a value")),
+        };
+        self.newline_since_value = false;
+        Ok(value)
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.bump();
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some(']') => {
+                    self.bump();
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.bump();
+        let mut out = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.bump();
+                    return Ok(out);
+                }
+                Some('\\') => {
+                    self.bump();
+                    let escaped = match self.peek() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some(c @ ('"' | '\\' | '/')) => c,
+                        _ => return Err(self.error("unknown escape sequence")),
+                    };
+                    out.push(escaped);
+                    self.bump();
+                }
+                Some(c) => {
+                    out.push(c);
+                    self.bump();
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.'))
+        {
+            self.bump();
+        }
+        let text = &self.input[start..self.pos];
+        if let Ok(n) = text.parse() {
+            return Ok(Value::Int(n));
+        }
+        match text.parse() {
+            Ok(x) if !text.contains(|c: char| c.is_ascii_alphabetic() && c != 'e' && c != 'E') => {
+                Ok(Value::Float(x))
+            }
+            _ => Err(ParseError {
+                offset: start,
+                message: "invalid number",
+            }),
+        }
+    }
+
+    fn keyword(&mut self) -> Result<Value, ParseError> {
+        let rest = &self.input[self.pos..];
+        for (word, value) in [
+            ("null", Value::Null),
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+        ] {
+            if rest.starts_with(word) {
+                self.pos += word.len();
+                return Ok(value);
+            }
+        }
+        Err(self.error("expected a value"))
+    }
+}
+
+/// Error returned when a value does not fit the requested Rust type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvertError {
+    pub expected: &'static str,
+    pub found: Value,
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {}, found {} `{}`",
+            self.expected,
+            self.found.kind(),
+            self.found
+        )
+    }
+}
+
+impl Error for ConvertError {}
+
+/// Builds an argument from its literal.
+pub trait FromValue: Sized {
+    fn from_value(value: Value) -> Result<Self, ConvertError>;
+}
+
+/// Turns a return value into its literal.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+fn mismatch<T>(expected: &'static str, found: Value) -> Result<T, ConvertError> {
+    Err(ConvertError { expected, found })
+}
+
+macro_rules! integer_values {
+    ($($ty:ty),*) => {$(
+        impl FromValue for $ty {
+            fn from_value(value: Value) -> Result<Self, ConvertError> {
+                match value {
+                    Value::Int(n) => match <$ty>::try_from(n) {
+                        Ok(n) => Ok(n),
+                        Err(_) => mismatch(stringify!($ty), value),
+                    },
+                    _ => mismatch(stringify!($ty), value),
+                }
+            }
+        }
+
+        impl IntoValue for $ty {
+            fn into_value(self) -> Value {
+                Value::Int(self as i64)
+            }
+        }
+    )*};
+}
+
+integer_values!(i32, i64, u32, u64, usize);
+
+impl FromValue for f64 {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        match value.as_f64() {
+            Some(x) => Ok(x),
+            None => mismatch("f64", value),
+        }
+    }
+}
+
+impl IntoValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Float(self)
+    }
+}
+
+/// What LeetCode prints for methods that return nothing.
+impl IntoValue for () {
+    fn into_value(self) -> Value {
+        Value::Null
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        match value {
+            Value::Bool(b) => Ok(b),
+            _ => mismatch("bool", value),
+        }
+    }
+}
+
+impl IntoValue for bool {
+    fn into_value(self) -> Value {
+        Value::Bool(self)
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        match value {
+            Value::Str(s) => Ok(s),
+            _ => mismatch("String", value),
+        }
+    }
+}
+
+impl IntoValue for String {
+    fn into_value(self) -> Value {
+        Value::Str(self)
+    }
+}
+
+/// LeetCode writes characters as one-character strings, e.g. `["5","."]`.
+impl FromValue for char {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        if let Value::Str(s) = &value {
+            let mut chars = s.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                return Ok(c);
+            }
+        }
+        mismatch("char", value)
+    }
+}
+
+impl IntoValue for char {
+    fn into_value(self) -> Value {
+        Value::Str(self.to_string())
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        match value {
+            Value::Array(items) => items.into_iter().map(T::from_value).collect(),
+            _ => mismatch("array", value),
+        }
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        Value::Array(self.into_iter().map(IntoValue::into_value).collect())
+    }
+}
+
+/// A linked list is written as the array of its values; `[]` is empty.
+impl FromValue for Option<Box<ListNode>> {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        match value {
+            Value::Null => Ok(None),
+            value => Ok(List::from(Vec::<i32>::from_value(value)?).into_inner()),
+        }
+    }
+}
+
+impl IntoValue for Option<Box<ListNode>> {
+    fn into_value(self) -> Value {
+        List::from(self).to_vec().into_value()
+    }
+}
+
+/// A tree is written in level order with `null` for missing children.
+impl FromValue for Option<Rc<RefCell<TreeNode>>> {
+    fn from_value(value: Value) -> Result<Self, ConvertError> {
+        let Value::Array(items) = value else {
+            return match value {
+                Value::Null => Ok(None),
+                _ => mismatch("tree", value),
+            };
+        };
+        let values = items
+            .into_iter()
+            .map(|item| match item {
+                Value::Null => Ok(None),
+                item => i32::from_value(item).map(Some),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Tree::from(values).into_inner())
+    }
+}
+
+impl IntoValue for Option<Rc<RefCell<TreeNode>>> {
+    fn into_value(self) -> Value {
+        let values = Tree::from(self).to_vec().into_iter();
+        Value::Array(values.map(|v| v.map_or(Value::Null, Value::from)).collect())
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Self {
+        Value::Int(n.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Value {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_named_arguments() {
+        let args = parse_args(r#"nums = [2,7,11,15], target = 9, s = "a,b""#).unwrap();
+        assert_eq!(
+            args,
+            [
+                parse("[2,7,11,15]"),
+                Value::Int(9),
+                Value::Str("a,b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_one_argument_per_line() {
+        let args = parse_args("[1,null,2]\n\"abc\"\n-3\n").unwrap();
+        assert_eq!(
+            args,
+            [
+                Value::Array(vec![Value::Int(1), Value::Null, Value::Int(2)]),
+                Value::Str("abc".to_string()),
+                Value::Int(-3),
+            ]
+        );
+        assert_eq!(parse_args("  ").unwrap(), []);
+    }
+
+    #[test]
+    fn parses_nested_and_scalar_literals() {
+        assert_eq!(
+            parse(r#"[[1, 2], [], [true, false, null], ["x\"y"]]"#).to_string(),
+            r#"[[1,2],[],[true,false,null],["x\"y"]]"#
+        );
+        assert_eq!(parse("2.5"), Value::Float(2.5));
+        assert_eq!(parse("-1e3"), Value::Float(-1000.0));
+        assert_eq!(parse("1.0").to_string(), "1.00000");
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        assert_eq!(
+            "[1,2".parse::<Value>().unwrap_err().message,
+            "expected `,` or `]`"
+        );
+        assert_eq!(
+            "\"ab".parse::<Value>().unwrap_err().message,
+            "unterminated string"
+        );
+        assert_eq!("nil".parse::<Value>().unwrap_err().offset, 0);
+        assert!(parse_args("a = 1 b = 2").is_err());
+    }
+
+    #[test]
+    fn converts_arguments() {
+        assert_eq!(
+            Vec::<Vec<char>>::from_value(parse(r#"[["5","."]]"#)),
+            Ok(vec![vec!['5', '.']])
+        );
+        assert_eq!(f64::from_value(Value::Int(2)), Ok(2.0));
+        let err = i32::from_value(Value::Int(1 << 40)).unwrap_err();
+        assert_eq!(err.expected, "i32");
+        assert!(String::from_value(Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn converts_lists_and_trees() {
+        let head = Option::<Box<ListNode>>::from_value(parse("[1,2,3]")).unwrap();
+        assert_eq!(head.into_value().to_string(), "[1,2,3]");
+        assert_eq!(Option::<Box<ListNode>>::from_value(parse("[]")), Ok(None));
+
+        let root = Option::<Rc<RefCell<TreeNode>>>::from_value(parse("[1,null,2,3]")).unwrap();
+        assert_eq!(root.into_value().to_string(), "[1,null,2,3]");
+    }
+
+    #[test]
+    fn matching_tolerates_float_error() {
+        assert!(Value::Float(2.000001).matches(&parse("2.00000")));
+        assert!(Value::Int(3).matches(&Value::Float(3.0)));
+        assert!(!Value::Float(2.1).matches(&Value::Float(2.0)));
+        assert!(!parse("[1,2]").matches(&parse("[1,2,3]")));
+    }
+}
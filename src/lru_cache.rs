@@ -0,0 +1,433 @@
+//! A least-recently-used cache whose recency list lives in a slab of
+//! index-linked nodes, so entries cost no allocation or reference count of
+//! their own.
+//!
+//! ```
+//! use leetcode::lru_cache::LruCache;
+//!
+//! let mut cache = LruCache::new(2);
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//! assert_eq!(cache.get("a"), Some(&1)); // "b" is now least recent
+//! assert_eq!(cache.put("c", 3), [("b", 2)]);
+//! assert_eq!(cache.peek("b"), None);
+//! let keys: Vec<_> = cache.iter().map(|(k, _)| *k).collect();
+//! assert_eq!(keys, ["c", "a"]);
+//! ```
+//!
+//! With a weigher the capacity bounds the total weight instead of the number
+//! of entries:
+//!
+//! ```
+//! use leetcode::lru_cache::LruCache;
+//!
+//! let mut cache = LruCache::with_weigher(10, |_: &u32, v: &String| v.len());
+//! cache.put(1, "four".to_string());
+//! cache.put(2, "five5".to_string());
+//! assert_eq!(cache.weight(), 9);
+//! let evicted = cache.put(3, "three".to_string());
+//! assert_eq!(evicted, [(1, "four".to_string())]);
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::mem;
+
+/// Link to no node.
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    weight: usize,
+    /// Towards the most recent entry.
+    prev: usize,
+    /// Towards the least recent entry.
+    next: usize,
+}
+
+/// A map that holds at most `capacity` worth of entries, evicting the least
+/// recently used ones to make room. Lookups through [`LruCache::get`] and
+/// [`LruCache::get_mut`] count as uses; [`LruCache::peek`] does not.
+///
+/// Keys are stored twice, in the index and in the node, hence `K: Clone`.
+#[derive(Clone)]
+pub struct LruCache<K, V, W = fn(&K, &V) -> usize> {
+    index: HashMap<K, usize>,
+    /// Free slots are `None` and listed in `free`.
+    nodes: Vec<Option<Node<K, V>>>,
+    free: Vec<usize>,
+    /// Most recent entry.
+    head: usize,
+    /// Least recent entry.
+    tail: usize,
+    weight: usize,
+    capacity: usize,
+    weigher: W,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// A cache of at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_weigher(capacity, |_, _| 1)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, W: Fn(&K, &V) -> usize> LruCache<K, V, W> {
+    /// A cache whose entries weigh `weigher(key, value)` each and whose total
+    /// weight stays at most `capacity`.
+    pub fn with_weigher(capacity: usize, weigher: W) -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            weight: 0,
+            capacity,
+            weigher,
+        }
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The bound on [`Self::weight`].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Total weight of the entries; their number without a weigher.
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+
+    /// Whether `key` is cached, without counting as a use.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// The value for `key`, marking it most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// The value for `key`, marking it most recently used.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = *self.index.get(key)?;
+        self.detach(i);
+        self.attach_front(i);
+        Some(&mut self.node_mut(i).value)
+    }
+
+    /// The value for `key`, leaving the recency order alone.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(key).map(|&i| &self.node(i).value)
+    }
+
+    /// Inserts or replaces the value for `key` as the most recently used
+    /// entry, then evicts least recently used entries until the weight fits.
+    /// Returns the evicted entries, least recent first: at most one without a
+    /// weigher, and the new entry itself if it alone outweighs the capacity.
+    pub fn put(&mut self, key: K, value: V) -> Vec<(K, V)> {
+        let weight = (self.weigher)(&key, &value);
+        match self.index.get(&key) {
+            Some(&i) => {
+                let node = self.node_mut(i);
+                let old = mem::replace(&mut node.weight, weight);
+                node.value = value;
+                self.weight = self.weight - old + weight;
+                self.detach(i);
+                self.attach_front(i);
+            }
+            None => {
+                let node = Node {
+                    key: key.clone(),
+                    value,
+                    weight,
+                    prev: NIL,
+                    next: NIL,
+                };
+                let i = match self.free.pop() {
+                    Some(i) => {
+                        self.nodes[i] = Some(node);
+                        i
+                    }
+                    None => {
+                        self.nodes.push(Some(node));
+                        self.nodes.len() - 1
+                    }
+                };
+                self.index.insert(key, i);
+                self.weight += weight;
+                self.attach_front(i);
+            }
+        }
+        self.shrink()
+    }
+
+    /// Removes `key`, returning its value.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = *self.index.get(key)?;
+        Some(self.remove(i).1)
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        (self.tail != NIL).then(|| self.remove(self.tail))
+    }
+
+    /// Changes the capacity, returning the entries evicted to fit it, least
+    /// recent first.
+    pub fn resize(&mut self, capacity: usize) -> Vec<(K, V)> {
+        self.capacity = capacity;
+        self.shrink()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.nodes.clear();
+        self.free.clear();
+        (self.head, self.tail, self.weight) = (NIL, NIL, 0);
+    }
+
+    /// The entries from most to least recently used; reverse it for the
+    /// eviction order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: &self.nodes,
+            front: self.head,
+            back: self.tail,
+            len: self.len(),
+        }
+    }
+
+    fn shrink(&mut self) -> Vec<(K, V)> {
+        let mut evicted = Vec::new();
+        while self.weight > self.capacity {
+            evicted.extend(self.pop_lru());
+        }
+        evicted
+    }
+
+    fn remove(&mut self, i: usize) -> (K, V) {
+        self.detach(i);
+        let node = self.nodes[i].take().unwrap();
+        self.free.push(i);
+        self.index.remove(&node.key);
+        self.weight -= node.weight;
+        (node.key, node.value)
+    }
+
+    fn node(&self, i: usize) -> &Node<K, V> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<K, V> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    /// Unlinks node `i`, leaving its own links dangling.
+    fn detach(&mut self, i: usize) {
+        let Node { prev, next, .. } = *self.node(i);
+        match prev {
+            NIL => self.head = next,
+            p => self.node_mut(p).next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            n => self.node_mut(n).prev = prev,
+        }
+    }
+
+    /// Links the detached node `i` in as the most recent entry.
+    fn attach_front(&mut self, i: usize) {
+        let head = self.head;
+        let node = self.node_mut(i);
+        (node.prev, node.next) = (NIL, head);
+        match head {
+            NIL => self.tail = i,
+            h => self.node_mut(h).prev = i,
+        }
+        self.head = i;
+    }
+}
+
+impl<K: Hash + Eq + Clone + fmt::Debug, V: fmt::Debug, W: Fn(&K, &V) -> usize> fmt::Debug
+    for LruCache<K, V, W>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Hash + Eq + Clone, V, W: Fn(&K, &V) -> usize> IntoIterator for &'a LruCache<K, V, W> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The entries of an [`LruCache`], most recently used first.
+pub struct Iter<'a, K, V> {
+    nodes: &'a [Option<Node<K, V>>],
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = self.nodes[self.front].as_ref().unwrap();
+        self.front = node.next;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = self.nodes[self.back].as_ref().unwrap();
+        self.back = node.prev;
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    #[test]
+    fn matches_naive_recency_list() {
+        let mut rng = SeededRng::new(16);
+        for capacity in 0..6 {
+            let mut cache = LruCache::new(capacity);
+            // Most recent first.
+            let mut naive: Vec<(u8, i32)> = Vec::new();
+            for step in 0..500 {
+                let key = rng.in_range(0..8);
+                match rng.in_range(0..4) {
+                    0 => {
+                        let expected = naive.iter().position(|&(k, _)| k == key).map(|p| {
+                            let entry = naive.remove(p);
+                            naive.insert(0, entry);
+                            entry.1
+                        });
+                        assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    1 => {
+                        let expected = naive.iter().find(|&&(k, _)| k == key).map(|e| e.1);
+                        assert_eq!(cache.peek(&key).copied(), expected);
+                    }
+                    2 => {
+                        let expected = naive
+                            .iter()
+                            .position(|&(k, _)| k == key)
+                            .map(|p| naive.remove(p).1);
+                        assert_eq!(cache.pop(&key), expected);
+                    }
+                    _ => {
+                        naive.retain(|&(k, _)| k != key);
+                        naive.insert(0, (key, step));
+                        let evicted = naive.split_off(naive.len().min(capacity));
+                        assert_eq!(cache.put(key, step), evicted);
+                    }
+                }
+                assert_eq!(cache.len(), naive.len());
+                let entries: Vec<(u8, i32)> = cache.iter().map(|(&k, &v)| (k, v)).collect();
+                assert_eq!(entries, naive);
+                let reversed: Vec<(u8, i32)> = cache.iter().rev().map(|(&k, &v)| (k, v)).collect();
+                assert!(reversed.iter().eq(naive.iter().rev()));
+            }
+        }
+    }
+
+    #[test]
+    fn get_mut_updates_and_promotes() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, vec![1]);
+        cache.put(2, vec![2]);
+        cache.get_mut(&1).unwrap().push(10);
+        assert_eq!(cache.put(3, vec![3]), [(2, vec![2])]);
+        assert_eq!(cache.peek(&1), Some(&vec![1, 10]));
+    }
+
+    #[test]
+    fn weigher_bounds_total_weight() {
+        let mut cache = LruCache::with_weigher(10, |_: &u8, v: &Vec<u8>| v.len());
+        assert!(cache.put(1, vec![0; 4]).is_empty());
+        assert!(cache.put(2, vec![0; 4]).is_empty());
+        // Growing an entry can evict others.
+        let evicted = cache.put(2, vec![0; 7]);
+        assert_eq!(evicted, [(1, vec![0; 4])]);
+        assert_eq!(cache.weight(), 7);
+        // An entry heavier than the capacity evicts everything, itself last.
+        let evicted = cache.put(3, vec![0; 11]);
+        assert_eq!(evicted, [(2, vec![0; 7]), (3, vec![0; 11])]);
+        assert!(cache.is_empty());
+        assert_eq!(cache.weight(), 0);
+    }
+
+    #[test]
+    fn resize_evicts_least_recent() {
+        let mut cache = LruCache::new(4);
+        for k in 0..4 {
+            cache.put(k, k * 10);
+        }
+        cache.get(&0);
+        assert_eq!(cache.resize(2), [(1, 10), (2, 20)]);
+        assert_eq!(cache.capacity(), 2);
+        assert!(cache.resize(5).is_empty());
+        cache.put(7, 70);
+        assert_eq!(format!("{cache:?}"), "{7: 70, 0: 0, 3: 30}");
+    }
+}
@@ -0,0 +1,379 @@
+//! Arithmetic modulo a prime fixed at compile time, and factorial tables for
+//! counting with it.
+//!
+//! ```
+//! use leetcode::mod_int::{Combinatorics, Mint};
+//!
+//! let x = Mint::from(-1);
+//! assert_eq!(x.value(), 1_000_000_006);
+//! assert_eq!((x + 3) * 5, Mint::new(10));
+//! assert_eq!(Mint::new(2).pow(10), Mint::new(1024));
+//! assert_eq!(Mint::new(3) / 3, Mint::new(1));
+//!
+//! let mut comb = Combinatorics::new();
+//! assert_eq!(comb.choose(5, 2), Mint::new(10));
+//! assert_eq!(comb.catalan(4), Mint::new(14));
+//! ```
+
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The usual LeetCode modulus, `10^9 + 7`.
+pub const MOD: u32 = 1_000_000_007;
+
+/// An integer modulo [`MOD`].
+pub type Mint = ModInt<MOD>;
+
+/// An integer modulo `M`, kept in `0..M`. Division and [`ModInt::inv`] use
+/// Fermat's little theorem and so require `M` to be prime.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u32>(u32);
+
+impl<const M: u32> ModInt<M> {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 % M);
+
+    /// `x` reduced modulo `M`.
+    pub const fn new(x: u64) -> Self {
+        Self((x % M as u64) as u32)
+    }
+
+    /// The representative in `0..M`.
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+
+    /// `self` raised to `exp`, by repeated squaring.
+    pub const fn pow(self, mut exp: u64) -> Self {
+        let (mut base, mut acc) = (self.0 as u64, 1 % M as u64);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base % M as u64;
+            }
+            base = base * base % M as u64;
+            exp >>= 1;
+        }
+        Self(acc as u32)
+    }
+
+    /// The multiplicative inverse, `self^(M - 2)`.
+    ///
+    /// # Panics
+    /// If `self` is zero.
+    pub const fn inv(self) -> Self {
+        assert!(self.0 != 0, "zero has no inverse");
+        self.pow(M as u64 - 2)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ty),*) => {$(
+        impl<const M: u32> From<$ty> for ModInt<M> {
+            fn from(x: $ty) -> Self {
+                Self::new(x as u64)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($ty:ty),*) => {$(
+        impl<const M: u32> From<$ty> for ModInt<M> {
+            fn from(x: $ty) -> Self {
+                Self((x as i64).rem_euclid(M as i64) as u32)
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u32, u64, usize);
+impl_from_signed!(i32, i64);
+
+impl<const M: u32> From<bool> for ModInt<M> {
+    fn from(x: bool) -> Self {
+        if x { Self::ONE } else { Self::ZERO }
+    }
+}
+
+impl<const M: u32, T: Into<Self>> Add<T> for ModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self {
+        let sum = self.0 as u64 + rhs.into().0 as u64;
+        Self(if sum >= M as u64 { sum - M as u64 } else { sum } as u32)
+    }
+}
+
+impl<const M: u32, T: Into<Self>> Sub<T> for ModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self {
+        let rhs = rhs.into().0;
+        Self(if self.0 >= rhs {
+            self.0 - rhs
+        } else {
+            M - rhs + self.0
+        })
+    }
+}
+
+impl<const M: u32, T: Into<Self>> Mul<T> for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self((self.0 as u64 * rhs.into().0 as u64 % M as u64) as u32)
+    }
+}
+
+impl<const M: u32, T: Into<Self>> Div<T> for ModInt<M> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: T) -> Self {
+        self * rhs.into().inv()
+    }
+}
+
+impl<const M: u32> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident $method:ident $op:tt),*) => {$(
+        impl<const M: u32, T: Into<Self>> $trait<T> for ModInt<M> {
+            fn $method(&mut self, rhs: T) {
+                *self = *self $op rhs;
+            }
+        }
+    )*};
+}
+
+impl_assign!(AddAssign add_assign +, SubAssign sub_assign -, MulAssign mul_assign *, DivAssign div_assign /);
+
+impl<const M: u32> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, const M: u32> Sum<&'a Self> for ModInt<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const M: u32> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, const M: u32> Product<&'a Self> for ModInt<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<const M: u32> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const M: u32> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Factorials and inverse factorials modulo `M`, extended on demand to
+/// whatever argument is asked for.
+#[derive(Clone, Debug)]
+pub struct Combinatorics<const M: u32 = MOD> {
+    fact: Vec<ModInt<M>>,
+    inv_fact: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> Default for Combinatorics<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const M: u32> Combinatorics<M> {
+    /// Tables holding just `0!`.
+    pub fn new() -> Self {
+        Self {
+            fact: vec![ModInt::ONE],
+            inv_fact: vec![ModInt::ONE],
+        }
+    }
+
+    /// Tables already covering `0..=n`.
+    pub fn with_capacity(n: usize) -> Self {
+        let mut comb = Self::new();
+        comb.reserve(n);
+        comb
+    }
+
+    /// Extends the tables to cover `0..=n`, at least doubling them when they
+    /// grow so that ascending queries cost amortized O(1).
+    pub fn reserve(&mut self, n: usize) {
+        let old = self.fact.len();
+        if n < old {
+            return;
+        }
+        let new = (n + 1).max(2 * old);
+        for i in old..new {
+            let next = self.fact[i - 1] * i;
+            self.fact.push(next);
+        }
+        self.inv_fact.resize(new, ModInt::ZERO);
+        self.inv_fact[new - 1] = self.fact[new - 1].inv();
+        for i in (old..new - 1).rev() {
+            self.inv_fact[i] = self.inv_fact[i + 1] * (i + 1);
+        }
+    }
+
+    /// `n!`.
+    pub fn factorial(&mut self, n: usize) -> ModInt<M> {
+        self.reserve(n);
+        self.fact[n]
+    }
+
+    /// `1 / n!`.
+    pub fn inv_factorial(&mut self, n: usize) -> ModInt<M> {
+        self.reserve(n);
+        self.inv_fact[n]
+    }
+
+    /// `1 / n` for `n >= 1`, read off the tables.
+    pub fn inverse(&mut self, n: usize) -> ModInt<M> {
+        assert!(n > 0, "zero has no inverse");
+        self.inv_factorial(n) * self.fact[n - 1]
+    }
+
+    /// Ways to choose `k` of `n` items, zero when `k > n`.
+    pub fn choose(&mut self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::ZERO;
+        }
+        self.factorial(n) * self.inv_fact[k] * self.inv_fact[n - k]
+    }
+
+    /// Ways to arrange `k` of `n` items in order, zero when `k > n`.
+    pub fn permute(&mut self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::ZERO;
+        }
+        self.factorial(n) * self.inv_fact[n - k]
+    }
+
+    /// Ways to split `parts.iter().sum()` items into groups of the given
+    /// sizes: `n! / (k1! k2! ...)`.
+    pub fn multinomial(&mut self, parts: &[usize]) -> ModInt<M> {
+        let n = parts.iter().sum();
+        let numerator = self.factorial(n);
+        parts
+            .iter()
+            .fold(numerator, |acc, &k| acc * self.inv_fact[k])
+    }
+
+    /// The `n`-th Catalan number, `C(2n, n) / (n + 1)`.
+    pub fn catalan(&mut self, n: usize) -> ModInt<M> {
+        self.choose(2 * n, n) * self.inverse(n + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    type Small = ModInt<13>;
+
+    #[test]
+    fn arithmetic_matches_wide_integers() {
+        let mut rng = SeededRng::new(12);
+        let m = MOD as i128;
+        for _ in 0..1000 {
+            let (a, b): (i64, i64) = (rng.next_u64() as i64, rng.in_range(1..i64::MAX));
+            let (x, y) = (Mint::from(a), Mint::from(b));
+            let (a, b) = (a as i128, b as i128);
+            let reduce = |v: i128| v.rem_euclid(m) as u32;
+            assert_eq!(x.value(), reduce(a));
+            assert_eq!((x + y).value(), reduce(a + b));
+            assert_eq!((x - y).value(), reduce(a - b));
+            assert_eq!((x * y).value(), reduce(a * b));
+            assert_eq!((-x).value(), reduce(-a));
+            if y != Mint::ZERO {
+                assert_eq!(x / y * y, x);
+            }
+        }
+    }
+
+    #[test]
+    fn powers_and_inverses() {
+        for x in 1..13u32 {
+            let x = Small::from(x);
+            assert_eq!(x * x.inv(), Small::ONE);
+            assert_eq!(x.pow(12), Small::ONE);
+        }
+        assert_eq!(Small::ZERO.pow(0), Small::ONE);
+        assert_eq!(Mint::new(3).pow(5), Mint::new(243));
+        let mut y = Mint::new(7);
+        y += 1;
+        y *= 3u64;
+        y -= 30;
+        y /= 2;
+        assert_eq!(y, Mint::from(-3));
+        assert_eq!([1, 2, 3].map(Mint::from).iter().sum::<Mint>(), Mint::new(6));
+        assert_eq!(
+            [4, 5].map(Mint::from).into_iter().product::<Mint>(),
+            Mint::new(20)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has no inverse")]
+    fn zero_has_no_inverse() {
+        Mint::ZERO.inv();
+    }
+
+    #[test]
+    fn tables_match_pascals_triangle() {
+        let mut comb: Combinatorics = Combinatorics::new();
+        let mut row = vec![Mint::ONE];
+        for n in 1..40 {
+            let mut next = vec![Mint::ONE; n + 1];
+            for k in 1..n {
+                next[k] = row[k - 1] + row[k];
+            }
+            row = next;
+            for (k, &expected) in row.iter().enumerate() {
+                assert_eq!(comb.choose(n, k), expected);
+                assert_eq!(comb.permute(n, k), expected * comb.factorial(k));
+            }
+            assert_eq!(comb.choose(n, n + 1), Mint::ZERO);
+            assert_eq!(comb.inverse(n) * n, Mint::ONE);
+        }
+        assert_eq!(comb.multinomial(&[2, 1, 1]), Mint::new(12));
+        let catalan: Vec<u32> = (0..8).map(|n| comb.catalan(n).value()).collect();
+        assert_eq!(catalan, [1, 1, 2, 5, 14, 42, 132, 429]);
+    }
+
+    #[test]
+    fn tables_grow_on_demand() {
+        let mut comb = Combinatorics::<MOD>::with_capacity(3);
+        assert_eq!(comb.fact.len(), 4);
+        assert_eq!(comb.inv_factorial(4) * comb.factorial(4), Mint::ONE);
+        assert_eq!(comb.fact.len(), 8);
+        assert_eq!(
+            comb.factorial(20).value(),
+            (2_432_902_008_176_640_000u64 % MOD as u64) as u32
+        );
+        for n in 0..comb.fact.len() {
+            assert_eq!(comb.fact[n] * comb.inv_fact[n], Mint::ONE);
+        }
+    }
+}
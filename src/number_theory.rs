@@ -0,0 +1,607 @@
+//! Primes, factorization and the classic arithmetic functions.
+//!
+//! A [`Sieve`] answers queries about every value up to a fixed limit from a
+//! smallest-prime-factor table. The free functions work on any `u64`, using
+//! Miller–Rabin for primality and Pollard's rho for factorization.
+//!
+//! ```
+//! use leetcode::number_theory::{self, Sieve};
+//!
+//! let sieve = Sieve::new(100);
+//! assert_eq!(sieve.primes()[..5], [2, 3, 5, 7, 11]);
+//! assert_eq!(sieve.factorize(60).collect::<Vec<_>>(), [(2, 2), (3, 1), (5, 1)]);
+//! assert_eq!((sieve.totient(36), sieve.mobius(30)), (12, -1));
+//!
+//! assert!(number_theory::is_prime(1_000_000_007));
+//! assert_eq!(number_theory::factorize(1_001), [(7, 1), (11, 1), (13, 1)]);
+//! assert_eq!(number_theory::divisors(12), [1, 2, 3, 4, 6, 12]);
+//! assert_eq!(number_theory::gcd(-12, 18), 6);
+//! assert_eq!(number_theory::ext_gcd(240, 46), (2, -9, 47));
+//! ```
+
+use std::ops::{AddAssign, Div, Mul, Range, Rem, SubAssign};
+
+/// Primitive integers, for the generic [`gcd`] and [`lcm`].
+pub trait Integer:
+    Copy + Ord + Rem<Output = Self> + Div<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    /// The absolute value; the identity on unsigned types.
+    fn magnitude(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty => $magnitude:expr),*) => {$(
+        impl Integer for $ty {
+            const ZERO: Self = 0;
+            fn magnitude(self) -> Self {
+                $magnitude(self)
+            }
+        }
+    )*};
+}
+
+impl_integer!(
+    u8 => |x| x, u16 => |x| x, u32 => |x| x, u64 => |x| x, u128 => |x| x, usize => |x| x,
+    i8 => i8::abs, i16 => i16::abs, i32 => i32::abs, i64 => i64::abs, i128 => i128::abs,
+    isize => isize::abs
+);
+
+/// Greatest common divisor, always non-negative; `gcd(0, 0) = 0`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.magnitude(), b.magnitude());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative; zero when either argument is.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).magnitude()
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, or `None` when they share a
+/// factor.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `a * b mod m` without overflow.
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `base^exp mod m` by repeated squaring, for a modulus only known at run
+/// time. See [`crate::mod_int`] for the fixed-modulus case.
+pub fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut acc = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_mod(acc, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    acc
+}
+
+/// The first twelve primes: trial divisors, and a witness set that makes
+/// Miller–Rabin exact for every `u64`.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Deterministic Miller–Rabin.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if let Some(&p) = SMALL_PRIMES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    SMALL_PRIMES.iter().all(|&witness| {
+        let mut x = pow_mod(witness, odd, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..shift).any(|_| {
+            x = mul_mod(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+/// A non-trivial factor of the odd composite `n`, by Brent's variant of
+/// Pollard's rho with batched gcds.
+fn pollard_rho(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+    for c in 1.. {
+        let step = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut y, mut saved, mut x) = (2, 2, 2);
+        let (mut g, mut product, mut len) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..len {
+                y = step(y);
+            }
+            let mut done = 0;
+            while done < len && g == 1 {
+                saved = y;
+                for _ in 0..BATCH.min(len - done) {
+                    y = step(y);
+                    product = mul_mod(product, x.abs_diff(y), n);
+                }
+                g = gcd(product, n);
+                done += BATCH;
+            }
+            len *= 2;
+        }
+        if g == n {
+            // The batch overshot: replay it one step at a time.
+            loop {
+                saved = step(saved);
+                g = gcd(x.abs_diff(saved), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Appends the prime factors of `n`, with multiplicity and in no particular
+/// order.
+fn split(n: u64, primes: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        primes.push(n);
+        return;
+    }
+    let factor = pollard_rho(n);
+    split(factor, primes);
+    split(n / factor, primes);
+}
+
+/// Run-length encodes an ascending list of primes.
+fn group(primes: impl IntoIterator<Item = u64>) -> Vec<(u64, u32)> {
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, exp)) if *last == p => *exp += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// Every product of the given prime powers, ascending.
+fn expand_divisors<T>(factors: impl IntoIterator<Item = (T, u32)>) -> Vec<T>
+where
+    T: Copy + Ord + From<u8> + Mul<Output = T>,
+{
+    let mut divisors = vec![T::from(1)];
+    for (p, exp) in factors {
+        let len = divisors.len();
+        let mut power = T::from(1);
+        for _ in 0..exp {
+            power = power * p;
+            for i in 0..len {
+                let divisor = divisors[i] * power;
+                divisors.push(divisor);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// The prime factorization of `n >= 1` as ascending `(prime, exponent)`
+/// pairs, in expected O(n^(1/4)) multiplications.
+///
+/// # Panics
+/// If `n` is zero.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "zero has no factorization");
+    let mut primes = Vec::new();
+    for &p in &SMALL_PRIMES {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    split(n, &mut primes);
+    primes.sort_unstable();
+    group(primes)
+}
+
+/// The divisors of `n >= 1`, ascending.
+pub fn divisors(n: u64) -> Vec<u64> {
+    expand_divisors(factorize(n))
+}
+
+/// Euler's totient: how many of `1..=n` are coprime to `n >= 1`.
+pub fn totient(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// The Möbius function of `n >= 1`: zero unless `n` is square-free, and
+/// otherwise `-1` to the number of prime factors.
+pub fn mobius(n: u64) -> i8 {
+    mobius_of(factorize(n).into_iter().map(|(_, exp)| exp))
+}
+
+fn mobius_of(exponents: impl IntoIterator<Item = u32>) -> i8 {
+    let mut sign = 1;
+    for exp in exponents {
+        if exp > 1 {
+            return 0;
+        }
+        sign = -sign;
+    }
+    sign
+}
+
+/// The primes in `range`, found by sieving it block by block with the
+/// primes up to its square root, in O(√hi + len · log log hi) time and
+/// O(√hi) extra space.
+pub fn primes_in(range: Range<u64>) -> Vec<u64> {
+    const BLOCK: u64 = 1 << 16;
+    let Range { start, end } = range;
+    let start = start.max(2);
+    if start >= end {
+        return Vec::new();
+    }
+    let base = Sieve::new((end - 1).isqrt() as usize);
+    let mut primes = Vec::new();
+    let mut composite = vec![false; BLOCK as usize];
+    let mut low = start;
+    while low < end {
+        let high = end.min(low + BLOCK);
+        composite.fill(false);
+        for &p in base.primes() {
+            let p = p as u64;
+            // Multiples below `p * p` have a smaller prime factor.
+            let first = (p * p).max(low.div_ceil(p) * p);
+            for multiple in (first..high).step_by(p as usize) {
+                composite[(multiple - low) as usize] = true;
+            }
+        }
+        primes.extend((low..high).filter(|&v| !composite[(v - low) as usize]));
+        low = high;
+    }
+    primes
+}
+
+/// `values[d] += values[2d] + values[3d] + ...` for every `d >= 1`, in
+/// O(n log n). Applied to a frequency table, `values[d]` becomes the count of
+/// elements divisible by `d`.
+pub fn sum_over_multiples<T: Copy + AddAssign>(values: &mut [T]) {
+    for d in 1..values.len() {
+        for multiple in (2 * d..values.len()).step_by(d) {
+            let add = values[multiple];
+            values[d] += add;
+        }
+    }
+}
+
+/// The inverse of [`sum_over_multiples`] (Möbius inversion over multiples):
+/// turns "divisible by `d`" counts back into "exactly `d`" counts.
+pub fn unsum_over_multiples<T: Copy + SubAssign>(values: &mut [T]) {
+    for d in (1..values.len()).rev() {
+        for multiple in (2 * d..values.len()).step_by(d) {
+            let sub = values[multiple];
+            values[d] -= sub;
+        }
+    }
+}
+
+/// The smallest prime factor of every value up to a limit, with the primes
+/// in that range.
+#[derive(Clone, Debug)]
+pub struct Sieve {
+    /// `smallest[n]` for `n >= 2`; `smallest[0] = 0` and `smallest[1] = 1`.
+    smallest: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl Sieve {
+    /// Linear sieve over `0..=limit`, crossing out each composite exactly
+    /// once, by its smallest prime factor.
+    ///
+    /// # Panics
+    /// If `limit` does not fit in a `u32`.
+    pub fn new(limit: usize) -> Self {
+        assert!(u32::try_from(limit).is_ok(), "sieve limit too large");
+        let mut smallest = vec![0u32; limit + 1];
+        let mut primes = Vec::new();
+        if limit >= 1 {
+            smallest[1] = 1;
+        }
+        for n in 2..=limit {
+            if smallest[n] == 0 {
+                smallest[n] = n as u32;
+                primes.push(n as u32);
+            }
+            for &p in &primes {
+                let multiple = n * p as usize;
+                if p > smallest[n] || multiple > limit {
+                    break;
+                }
+                smallest[multiple] = p;
+            }
+        }
+        Self { smallest, primes }
+    }
+
+    /// The largest value covered.
+    pub fn limit(&self) -> usize {
+        self.smallest.len() - 1
+    }
+
+    /// The primes up to the limit, ascending.
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.smallest[n] as usize == n
+    }
+
+    /// The smallest prime factor of `n >= 2`.
+    pub fn smallest_factor(&self, n: usize) -> usize {
+        debug_assert!(n >= 2, "{n} has no prime factor");
+        self.smallest[n] as usize
+    }
+
+    /// The prime factorization of `n >= 1` as ascending `(prime, exponent)`
+    /// pairs, in O(log n).
+    pub fn factorize(&self, mut n: usize) -> impl Iterator<Item = (usize, u32)> + '_ {
+        debug_assert!(n >= 1, "zero has no factorization");
+        std::iter::from_fn(move || {
+            if n <= 1 {
+                return None;
+            }
+            let p = self.smallest_factor(n);
+            let mut exp = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exp += 1;
+            }
+            Some((p, exp))
+        })
+    }
+
+    /// The divisors of `n >= 1`, ascending.
+    pub fn divisors(&self, n: usize) -> Vec<usize> {
+        expand_divisors(self.factorize(n))
+    }
+
+    /// Euler's totient of `n >= 1`.
+    pub fn totient(&self, n: usize) -> usize {
+        self.factorize(n).fold(n, |phi, (p, _)| phi / p * (p - 1))
+    }
+
+    /// The Möbius function of `n >= 1`.
+    pub fn mobius(&self, n: usize) -> i8 {
+        mobius_of(self.factorize(n).map(|(_, exp)| exp))
+    }
+
+    /// Euler's totient of every value in `0..=limit`, with `φ(0) = 0`.
+    pub fn totients(&self) -> Vec<u32> {
+        let mut phi = vec![0u32; self.smallest.len()];
+        if phi.len() > 1 {
+            phi[1] = 1;
+        }
+        for n in 2..phi.len() {
+            let p = self.smallest[n];
+            let rest = n / p as usize;
+            phi[n] = phi[rest] * if self.smallest[rest] == p { p } else { p - 1 };
+        }
+        phi
+    }
+
+    /// The Möbius function of every value in `0..=limit`, with `μ(0) = 0`.
+    pub fn mobius_table(&self) -> Vec<i8> {
+        let mut mu = vec![0i8; self.smallest.len()];
+        if mu.len() > 1 {
+            mu[1] = 1;
+        }
+        for n in 2..mu.len() {
+            let p = self.smallest[n];
+            let rest = n / p as usize;
+            mu[n] = if self.smallest[rest] == p {
+                0
+            } else {
+                -mu[rest]
+            };
+        }
+        mu
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    fn trial_factorize(mut n: u64) -> Vec<(u64, u32)> {
+        let mut primes = Vec::new();
+        let mut p = 2;
+        while p * p <= n {
+            while n.is_multiple_of(p) {
+                primes.push(p);
+                n /= p;
+            }
+            p += 1;
+        }
+        if n > 1 {
+            primes.push(n);
+        }
+        group(primes)
+    }
+
+    #[test]
+    fn sieve_and_free_functions_agree_with_trial_division() {
+        let sieve = Sieve::new(3000);
+        let (phi, mu) = (sieve.totients(), sieve.mobius_table());
+        for n in 1..=3000usize {
+            let expected = trial_factorize(n as u64);
+            let brute_divisors: Vec<u64> = (1..=n as u64)
+                .filter(|d| (n as u64).is_multiple_of(*d))
+                .collect();
+            let brute_phi = (1..=n as u64).filter(|&k| gcd(k, n as u64) == 1).count();
+            let brute_mu = mobius_of(expected.iter().map(|&(_, exp)| exp));
+            let from_sieve: Vec<(u64, u32)> =
+                sieve.factorize(n).map(|(p, e)| (p as u64, e)).collect();
+
+            assert_eq!(from_sieve, expected, "sieve factorize({n})");
+            assert_eq!(factorize(n as u64), expected, "factorize({n})");
+            assert_eq!(sieve.is_prime(n), is_prime(n as u64), "is_prime({n})");
+            assert_eq!(is_prime(n as u64), expected == [(n as u64, 1)]);
+            assert_eq!(divisors(n as u64), brute_divisors);
+            assert_eq!(sieve.divisors(n).len(), brute_divisors.len());
+            assert_eq!(
+                (sieve.totient(n), totient(n as u64) as usize),
+                (brute_phi, brute_phi)
+            );
+            assert_eq!(phi[n] as usize, brute_phi);
+            assert_eq!(
+                (sieve.mobius(n), mobius(n as u64), mu[n]),
+                (brute_mu, brute_mu, brute_mu)
+            );
+        }
+        assert_eq!(sieve.primes().len(), 430);
+        assert_eq!((phi[0], mu[0], sieve.limit()), (0, 0, 3000));
+    }
+
+    #[test]
+    fn miller_rabin_rejects_strong_pseudoprimes() {
+        // Carmichael numbers and strong pseudoprimes to several small bases.
+        for composite in [
+            561,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_825_123_056_546_413_051,
+        ] {
+            assert!(!is_prime(composite), "{composite}");
+        }
+        for prime in [
+            1_000_000_007,
+            998_244_353,
+            (1 << 61) - 1,
+            18_446_744_073_709_551_557,
+        ] {
+            assert!(is_prime(prime), "{prime}");
+        }
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn pollard_rho_splits_large_semiprimes() {
+        let cases = [
+            (1_000_000_007, 998_244_353),
+            (4_294_967_291, 4_294_967_291),
+            (2_147_483_647, 4_294_967_279),
+        ];
+        for (p, q) in cases {
+            let expected = if p == q {
+                vec![(p, 2)]
+            } else {
+                vec![(p.min(q), 1), (p.max(q), 1)]
+            };
+            assert_eq!(factorize(p * q), expected);
+        }
+        let mut rng = SeededRng::new(13);
+        for _ in 0..200 {
+            let n = rng.in_range(1..u64::MAX);
+            let factors = factorize(n);
+            assert!(factors.iter().all(|&(p, _)| is_prime(p)));
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            let product = factors.iter().fold(1u64, |acc, &(p, e)| acc * p.pow(e));
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn segmented_sieve_matches_primality() {
+        let sieve = Sieve::new(20_000);
+        let expected: Vec<u64> = sieve.primes().iter().map(|&p| p as u64).collect();
+        assert_eq!(primes_in(0..20_001), expected);
+        let window = 1_000_000_000_000..1_000_000_200_000;
+        let primes = primes_in(window.clone());
+        assert_eq!(primes, window.filter(|&n| is_prime(n)).collect::<Vec<_>>());
+        assert!(primes_in(14..17).is_empty());
+        assert!(primes_in(5..5).is_empty());
+    }
+
+    #[test]
+    fn gcd_family_satisfies_its_identities() {
+        let mut rng = SeededRng::new(14);
+        for _ in 0..1000 {
+            let (a, b) = (
+                rng.in_range(-1_000_000..1_000_000),
+                rng.in_range(-1_000_000..1_000_000),
+            );
+            let (g, x, y) = ext_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+            if g != 0 {
+                assert_eq!(lcm(a, b) * g, (a * b).abs());
+            }
+            let m = rng.in_range(2..1_000_000);
+            match mod_inverse(a, m) {
+                Some(inv) => assert_eq!((a * inv).rem_euclid(m), 1),
+                None => assert_ne!(gcd(a, m), 1),
+            }
+            let (base, exp) = (rng.in_range(0..u64::MAX), rng.in_range(0..64));
+            let naive = (0..exp).fold(1 % m as u64, |acc, _| mul_mod(acc, base, m as u64));
+            assert_eq!(pow_mod(base, exp, m as u64), naive);
+        }
+        assert_eq!((gcd(0u32, 0), lcm(0i64, 5), gcd(0usize, 7)), (0, 0, 7));
+    }
+
+    #[test]
+    fn sums_over_multiples_invert() {
+        let original: Vec<i64> = (0..500).map(|v| (v * 7919 % 13) as i64).collect();
+        let mut values = original.clone();
+        sum_over_multiples(&mut values);
+        for d in 1..500 {
+            let expected: i64 = (d..500).step_by(d).map(|m| original[m]).sum();
+            assert_eq!(values[d], expected);
+        }
+        unsum_over_multiples(&mut values);
+        assert_eq!(values, original);
+    }
+}
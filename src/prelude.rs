@@ -0,0 +1,6 @@
+//! Definitions LeetCode provides implicitly, glob-imported into every problem
+//! module. A problem file that declares its own item of the same name shadows
+//! the one imported from here.
+
+pub use crate::list_node::ListNode;
+pub use crate::tree_node::TreeNode;
@@ -0,0 +1,341 @@
+//! Streaming quantiles over ordered values.
+//!
+//! [`QuantileSketch`] is the common interface: feed values in with `insert`,
+//! ask for any `q` in `[0, 1]` with `quantile`. Three engines implement it:
+//!
+//! - [`SortedList`] answers exactly and also supports removal, so it serves
+//!   any window whose expiry the caller drives.
+//! - [`SlidingWindow`] is an exact engine over the last `capacity` values.
+//! - [`GkSketch`] is a Greenwald–Khanna summary with bounded memory whose
+//!   answers are within `ε·n` ranks of exact.
+//!
+//! Exact engines use the nearest-rank definition: the `q`-quantile of `n`
+//! values is the one at 1-based rank `⌈q·n⌉`, and `q = 0` is the minimum.
+//!
+//! ```
+//! use leetcode::quantile::{QuantileSketch, SlidingWindow};
+//!
+//! let mut latencies = SlidingWindow::new(4);
+//! for ms in [12, 48, 9, 30, 11] {
+//!     latencies.insert(ms);
+//! }
+//! // the window now holds 48, 9, 30, 11
+//! assert_eq!(latencies.quantile(0.5), Some(&11));
+//! assert_eq!(latencies.quantile(0.95), Some(&48));
+//! ```
+
+use crate::sorted_list::SortedList;
+use std::collections::VecDeque;
+
+/// A summary of a stream that answers quantile queries.
+pub trait QuantileSketch<T> {
+    /// Adds `value` to the stream.
+    fn insert(&mut self, value: T);
+
+    /// Number of values the summary currently describes.
+    fn len(&self) -> usize;
+
+    /// Whether the summary describes no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A value whose rank is (approximately) `q·len()`, or `None` when empty.
+    ///
+    /// # Panics
+    /// Panics if `q` is not within `[0, 1]`.
+    fn quantile(&self, q: f64) -> Option<&T>;
+}
+
+/// Zero-based index of the nearest-rank `q`-quantile among `n` values.
+fn nearest_rank(q: f64, n: usize) -> Option<usize> {
+    assert!((0.0..=1.0).contains(&q), "quantile {q} is outside [0, 1]");
+    (n > 0).then(|| ((q * n as f64).ceil() as usize).clamp(1, n) - 1)
+}
+
+impl<T: Ord> QuantileSketch<T> for SortedList<T> {
+    fn insert(&mut self, value: T) {
+        SortedList::insert(self, value);
+    }
+
+    fn len(&self) -> usize {
+        SortedList::len(self)
+    }
+
+    fn quantile(&self, q: f64) -> Option<&T> {
+        self.kth(nearest_rank(q, self.len())?)
+    }
+}
+
+/// Exact quantiles over the most recent `capacity` values of a stream.
+#[derive(Clone, Debug)]
+pub struct SlidingWindow<T> {
+    capacity: usize,
+    /// The window in arrival order.
+    window: VecDeque<T>,
+    sorted: SortedList<T>,
+}
+
+impl<T: Ord + Clone> SlidingWindow<T> {
+    /// An empty window over the last `capacity` values.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a sliding window needs a positive capacity");
+        Self {
+            capacity,
+            window: VecDeque::with_capacity(capacity),
+            sorted: SortedList::new(),
+        }
+    }
+
+    /// Maximum number of values kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends `value`, returning the oldest value if it fell out.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let expired = if self.window.len() == self.capacity {
+            self.pop_oldest()
+        } else {
+            None
+        };
+        self.sorted.insert(value.clone());
+        self.window.push_back(value);
+        expired
+    }
+
+    /// Removes and returns the oldest value, e.g. to expire by timestamp.
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        let oldest = self.window.pop_front()?;
+        self.sorted.remove(&oldest);
+        Some(oldest)
+    }
+
+    /// The values in the window, in sorted order.
+    pub fn sorted(&self) -> &SortedList<T> {
+        &self.sorted
+    }
+}
+
+impl<T: Ord + Clone> QuantileSketch<T> for SlidingWindow<T> {
+    fn insert(&mut self, value: T) {
+        self.push(value);
+    }
+
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    fn quantile(&self, q: f64) -> Option<&T> {
+        self.sorted.quantile(q)
+    }
+}
+
+/// One summary entry: `value` has rank at least `rmin` and at most
+/// `rmin + delta`, where `rmin` sums `g` over this and earlier entries.
+#[derive(Clone, Debug)]
+struct Tuple<T> {
+    value: T,
+    g: usize,
+    delta: usize,
+}
+
+/// Greenwald–Khanna quantile summary with rank error at most `ε·n`.
+///
+/// # Intuition
+/// Keep a sorted sample of the stream where each sampled value carries bounds
+/// on its true rank. As long as every entry's rank uncertainty `g + Δ` stays
+/// within `2εn`, some entry lies within `εn` ranks of any target rank.
+///
+/// # Approach
+/// 1. An insert adds an entry before its successor, inheriting the successor's
+///    uncertainty; the minimum and maximum are always known exactly.
+/// 2. Every `⌊1 / 2ε⌋` inserts, sweep right to left and fold each entry into
+///    its right neighbour while the merged uncertainty stays within `2εn`. The
+///    first entry is never folded, so the minimum survives.
+/// 3. A query for rank `r = ⌈q·n⌉` returns the entry whose rank bounds stray
+///    least from `r`, which the invariant keeps within `εn`.
+///
+/// The summary is insert-only: values cannot be removed once folded in. For
+/// windows, keep one sketch per time bucket or use an exact engine.
+///
+/// # Complexity
+/// - Time: O(s) per insert and query, where s is the number of entries
+/// - Space: in practice O((1/ε) log(εn)) entries, independent of the values
+#[derive(Clone, Debug)]
+pub struct GkSketch<T> {
+    epsilon: f64,
+    count: usize,
+    tuples: Vec<Tuple<T>>,
+    compress_every: usize,
+}
+
+impl<T: Ord> GkSketch<T> {
+    /// An empty sketch answering within `epsilon·n` ranks.
+    ///
+    /// # Panics
+    /// Panics unless `0 < epsilon < 1`.
+    pub fn new(epsilon: f64) -> Self {
+        assert!(
+            epsilon > 0.0 && epsilon < 1.0,
+            "epsilon {epsilon} is outside (0, 1)"
+        );
+        Self {
+            epsilon,
+            count: 0,
+            tuples: Vec::new(),
+            compress_every: ((0.5 / epsilon) as usize).max(1),
+        }
+    }
+
+    /// The rank error bound, as a fraction of the stream length.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    /// Number of entries kept, which bounds the memory used.
+    pub fn size(&self) -> usize {
+        self.tuples.len()
+    }
+
+    /// Largest uncertainty `g + Δ` an entry may carry.
+    fn band(&self) -> usize {
+        (2.0 * self.epsilon * self.count as f64) as usize
+    }
+
+    fn compress(&mut self) {
+        let band = self.band();
+        let mut kept: Vec<Tuple<T>> = Vec::with_capacity(self.tuples.len());
+        for (i, tuple) in self.tuples.drain(..).enumerate().rev() {
+            match kept.last_mut() {
+                Some(right) if i > 0 && tuple.g + right.g + right.delta <= band => {
+                    right.g += tuple.g;
+                }
+                _ => kept.push(tuple),
+            }
+        }
+        kept.reverse();
+        self.tuples = kept;
+    }
+}
+
+impl<T: Ord> QuantileSketch<T> for GkSketch<T> {
+    fn insert(&mut self, value: T) {
+        let i = self.tuples.partition_point(|t| t.value <= value);
+        let delta = match self.tuples.get(i) {
+            Some(next) if i > 0 => next.g + next.delta - 1,
+            _ => 0,
+        };
+        self.tuples.insert(i, Tuple { value, g: 1, delta });
+        self.count += 1;
+        if self.count.is_multiple_of(self.compress_every) {
+            self.compress();
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn quantile(&self, q: f64) -> Option<&T> {
+        let target = nearest_rank(q, self.count)? + 1;
+        let mut rmin = 0;
+        self.tuples
+            .iter()
+            .map(|t| {
+                rmin += t.g;
+                let miss = target.abs_diff(rmin).max(target.abs_diff(rmin + t.delta));
+                (miss, &t.value)
+            })
+            .min_by_key(|&(miss, _)| miss)
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    #[test]
+    fn nearest_rank_quantiles() {
+        let list: SortedList<i32> = (1..=10).collect();
+        assert_eq!(list.quantile(0.0), Some(&1));
+        assert_eq!(list.quantile(0.1), Some(&1));
+        assert_eq!(list.quantile(0.11), Some(&2));
+        assert_eq!(list.quantile(0.5), Some(&5));
+        assert_eq!(list.quantile(0.95), Some(&10));
+        assert_eq!(list.quantile(1.0), Some(&10));
+        assert_eq!(SortedList::<i32>::new().quantile(0.5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside [0, 1]")]
+    fn rejects_out_of_range_quantile() {
+        SortedList::<i32>::new().quantile(1.5);
+    }
+
+    #[test]
+    fn sliding_window_matches_naive() {
+        let mut rng = SeededRng::new(295);
+        let mut window = SlidingWindow::new(50);
+        let mut stream = Vec::new();
+        for _ in 0..2000 {
+            let x = rng.in_range(0..1000);
+            let expired = window.push(x);
+            stream.push(x);
+            let start = stream.len().saturating_sub(50);
+            assert_eq!(expired, start.checked_sub(1).map(|i| stream[i]));
+            let mut naive = stream[start..].to_vec();
+            naive.sort_unstable();
+            for q in [0.0, 0.5, 0.95, 0.99, 1.0] {
+                let k = ((q * naive.len() as f64).ceil() as usize).max(1) - 1;
+                assert_eq!(window.quantile(q), Some(&naive[k]));
+            }
+        }
+        while window.pop_oldest().is_some() {}
+        assert!(window.is_empty());
+        assert!(window.sorted().is_empty());
+    }
+
+    #[test]
+    fn gk_rank_error_within_bound() {
+        let mut rng = SeededRng::new(18);
+        for (epsilon, range) in [(0.01, 1_000_000), (0.05, 10), (0.001, 1_000)] {
+            let mut sketch = GkSketch::new(epsilon);
+            let mut values = Vec::new();
+            for i in 0..20_000 {
+                // a sorted run, then noise, exercises both ends of the sketch
+                let x = if i < 5000 { i } else { rng.in_range(0..range) };
+                sketch.insert(x);
+                values.push(x);
+            }
+            values.sort_unstable();
+            let n = values.len();
+            let slack = (epsilon * n as f64) as usize;
+            for q in (0..=100).map(|p| p as f64 / 100.0) {
+                let target = ((q * n as f64).ceil() as usize).max(1);
+                let &v = sketch.quantile(q).unwrap();
+                let lo = values.partition_point(|&y| y < v) + 1;
+                let hi = values.partition_point(|&y| y <= v);
+                assert!(lo <= target + slack && target <= hi + slack, "q={q} v={v}");
+            }
+            assert_eq!(sketch.quantile(0.0), values.first());
+            assert_eq!(sketch.quantile(1.0), values.last());
+        }
+    }
+
+    #[test]
+    fn gk_memory_stays_bounded() {
+        let mut rng = SeededRng::new(7);
+        let mut sketch = GkSketch::new(0.01);
+        for _ in 0..200_000 {
+            sketch.insert(rng.in_range(0..u64::MAX));
+        }
+        assert_eq!(sketch.len(), 200_000);
+        assert!(sketch.size() < 1000, "{} entries", sketch.size());
+    }
+}
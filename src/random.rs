@@ -0,0 +1,350 @@
+//! Reproducible randomness for randomized designs, and the statistics to
+//! check it.
+//!
+//! [`RandomSource`] is the one thing a randomized structure needs: a stream
+//! of uniform 64-bit words. Everything else — unbiased integers below a
+//! bound or in a range, unit floats, Fisher–Yates shuffles — is derived from
+//! it, so a type that takes a `RandomSource` can be driven by [`SeededRng`]
+//! in production and by a fixed seed or a scripted source in tests. It is
+//! also the generator behind the crate's randomized tests.
+//!
+//! [`chi_square`] and [`chi_square_critical`] turn "looks uniform" into a
+//! test: the statistic of a correct sampler stays below the critical value
+//! except with probability about 0.1%, and a fixed seed makes that a
+//! deterministic pass rather than a flaky one.
+//!
+//! ```
+//! use leetcode::random::{RandomSource, SeededRng, chi_square, chi_square_critical};
+//!
+//! let mut rng = SeededRng::new(42);
+//! let mut counts = [0u64; 6];
+//! for _ in 0..60_000 {
+//!     counts[rng.index(6)] += 1;
+//! }
+//! assert!(chi_square(&counts, &[1.0 / 6.0; 6]) < chi_square_critical(5));
+//!
+//! let mut deck: Vec<u32> = (0..52).collect();
+//! rng.shuffle(&mut deck);
+//! assert_eq!(SeededRng::new(42).next_u64(), SeededRng::new(42).next_u64());
+//! ```
+
+use std::ops::{Bound, RangeBounds};
+
+/// Seed used by [`SeededRng::default`].
+const DEFAULT_SEED: u64 = 0x5EED_5EED_5EED_5EED;
+
+/// Integer types that [`RandomSource::in_range`] draws; every value fits in
+/// an `i128`.
+pub trait UniformInt: Copy {
+    /// The value as an `i128`.
+    fn to_i128(self) -> i128;
+
+    /// The value of `value`, which must be in range for the type.
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_uniform_int {
+    ($($ty:ty),*) => {$(
+        impl UniformInt for $ty {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $ty
+            }
+        }
+    )*};
+}
+
+impl_uniform_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A source of uniformly distributed random bits.
+pub trait RandomSource {
+    /// 64 uniformly random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// A uniform integer in `0..n`, without modulo bias.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot draw below zero");
+        // Lemire's multiply-and-reject: the high word of `x·n` is uniform
+        // once the few low words that would over-represent it are redrawn
+        let mut product = u128::from(self.next_u64()) * u128::from(n);
+        if (product as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (product as u64) < threshold {
+                product = u128::from(self.next_u64()) * u128::from(n);
+            }
+        }
+        (product >> 64) as u64
+    }
+
+    /// A uniform index into a collection of `len` items.
+    ///
+    /// # Panics
+    /// Panics if `len` is zero.
+    fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// A uniform integer in `range`, which may be half-open or inclusive.
+    ///
+    /// # Panics
+    /// Panics if `range` is empty or unbounded.
+    fn in_range<T: UniformInt>(&mut self, range: impl RangeBounds<T>) -> T
+    where
+        Self: Sized,
+    {
+        let low = match range.start_bound() {
+            Bound::Included(&low) => low.to_i128(),
+            Bound::Excluded(&low) => low.to_i128() + 1,
+            Bound::Unbounded => panic!("cannot draw from a range without a start"),
+        };
+        let high = match range.end_bound() {
+            Bound::Included(&high) => high.to_i128(),
+            Bound::Excluded(&high) => high.to_i128() - 1,
+            Bound::Unbounded => panic!("cannot draw from a range without an end"),
+        };
+        assert!(low <= high, "cannot draw from an empty range");
+        // a span of 2^64 covers every 64-bit value, and any word will do
+        let span = (high - low) as u128 + 1;
+        let offset = match u64::try_from(span) {
+            Ok(span) => self.below(span),
+            Err(_) => self.next_u64(),
+        };
+        T::from_i128(low + i128::from(offset))
+    }
+
+    /// A uniform float in `[0, 1)` with 53 bits of precision.
+    fn unit_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// True with probability `p`.
+    fn chance(&mut self, p: f64) -> bool {
+        self.unit_f64() < p
+    }
+
+    /// Puts `items` in a uniformly random order (Fisher–Yates).
+    fn shuffle<T>(&mut self, items: &mut [T])
+    where
+        Self: Sized,
+    {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for Box<R> {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// The xoshiro256** generator: fast, small and statistically strong, though
+/// not suitable for cryptography.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    /// A generator whose whole output is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        // SplitMix64 spreads the seed over the state, which it never leaves
+        // all zero
+        let mut x = seed;
+        let state = std::array::from_fn(|_| {
+            x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        });
+        Self { state }
+    }
+}
+
+impl Default for SeededRng {
+    /// A generator with a fixed seed, so runs repeat exactly.
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl RandomSource for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+}
+
+/// Pearson's chi-square statistic of `observed` counts against the
+/// `probabilities` of each outcome.
+///
+/// # Panics
+/// Panics if the slices differ in length.
+pub fn chi_square(observed: &[u64], probabilities: &[f64]) -> f64 {
+    assert_eq!(
+        observed.len(),
+        probabilities.len(),
+        "one probability per outcome"
+    );
+    let total: u64 = observed.iter().sum();
+    observed
+        .iter()
+        .zip(probabilities)
+        .map(|(&count, &p)| {
+            let expected = p * total as f64;
+            (count as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/// The value a chi-square statistic with `degrees_of_freedom` exceeds with
+/// probability 0.1%, by the Wilson–Hilferty approximation.
+///
+/// # Panics
+/// Panics if `degrees_of_freedom` is zero.
+pub fn chi_square_critical(degrees_of_freedom: usize) -> f64 {
+    assert!(
+        degrees_of_freedom > 0,
+        "need at least one degree of freedom"
+    );
+    // the standard normal's 99.9th percentile
+    const Z: f64 = 3.090_232;
+    let k = degrees_of_freedom as f64;
+    let spread = 2.0 / (9.0 * k);
+    k * (1.0 - spread + Z * spread.sqrt()).powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_output() {
+        // xoshiro256** from the state SplitMix64 derives from seed 0
+        let mut rng = SeededRng::new(0);
+        assert_eq!(
+            rng.state,
+            [
+                0xE220_A839_7B1D_CDAF,
+                0x6E78_9E6A_A1B9_65F4,
+                0x06C4_5D18_8009_454F,
+                0xF88B_B8A8_724C_81EC,
+            ]
+        );
+        let first = rng.next_u64();
+        assert_eq!(first, 0x99EC_5F36_CB75_F2B4);
+        assert_ne!(rng.next_u64(), first);
+    }
+
+    #[test]
+    fn bounded_draws_are_uniform() {
+        let mut rng = SeededRng::default();
+        // 2^64 is not a multiple of any of these bounds, and a bound just
+        // above 2^63 would skew the halves badly under plain modulo
+        for n in [3u64, 7, 10, 1_000_000_007] {
+            let buckets = 10.min(n) as usize;
+            let mut counts = vec![0u64; buckets];
+            for _ in 0..50_000 {
+                let x = rng.below(n);
+                assert!(x < n);
+                counts[(u128::from(x) * buckets as u128 / u128::from(n)) as usize] += 1;
+            }
+            let uniform = vec![1.0 / buckets as f64; buckets];
+            assert!(chi_square(&counts, &uniform) < chi_square_critical(buckets - 1));
+        }
+        let big = (1u64 << 63) + 1;
+        let high = (0..10_000).filter(|_| rng.below(big) >= big / 2).count();
+        assert!((4_700..5_300).contains(&high), "{high}");
+    }
+
+    #[test]
+    fn ranges_cover_their_bounds_evenly() {
+        let mut rng = SeededRng::new(11);
+        let mut counts = [0u64; 7];
+        for _ in 0..35_000 {
+            counts[(rng.in_range(-3i32..=3) + 3) as usize] += 1;
+        }
+        assert!(chi_square(&counts, &[1.0 / 7.0; 7]) < chi_square_critical(6));
+        assert!((0..1000).all(|_| (5..8usize).contains(&rng.in_range(5..8usize))));
+        assert_eq!(rng.in_range(9u8..=9), 9);
+        // the full 64-bit span, at both extremes of the type
+        let full: Vec<i64> = (0..64).map(|_| rng.in_range(i64::MIN..=i64::MAX)).collect();
+        assert!(full.iter().any(|&x| x < 0) && full.iter().any(|&x| x > 0));
+        assert_eq!(rng.in_range(u64::MAX..=u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn empty_ranges_panic() {
+        SeededRng::new(0).in_range(4..4);
+    }
+
+    #[test]
+    fn unit_floats_stay_in_range_and_spread_evenly() {
+        let mut rng = SeededRng::new(7);
+        let mut counts = [0u64; 20];
+        for _ in 0..40_000 {
+            let x = rng.unit_f64();
+            assert!((0.0..1.0).contains(&x));
+            counts[(x * 20.0) as usize] += 1;
+        }
+        assert!(chi_square(&counts, &[0.05; 20]) < chi_square_critical(19));
+    }
+
+    #[test]
+    fn shuffles_are_uniform_over_permutations() {
+        let mut rng: Box<dyn RandomSource> = Box::new(SeededRng::new(384));
+        let mut counts = [0u64; 24];
+        for _ in 0..48_000 {
+            let mut items = [0, 1, 2, 3];
+            rng.shuffle(&mut items);
+            // Lehmer code of the permutation
+            let code = (0..4).fold(0, |code, i| {
+                let smaller_after = items[i + 1..].iter().filter(|&&x| x < items[i]).count();
+                code * (4 - i) + smaller_after
+            });
+            counts[code] += 1;
+        }
+        assert!(chi_square(&counts, &[1.0 / 24.0; 24]) < chi_square_critical(23));
+    }
+
+    #[test]
+    fn critical_values_match_tables() {
+        // 99.9th percentiles from standard chi-square tables
+        for (df, table) in [
+            (1, 10.828),
+            (5, 20.515),
+            (9, 27.877),
+            (23, 49.728),
+            (100, 149.449),
+        ] {
+            let approx = chi_square_critical(df);
+            assert!(
+                (approx - table).abs() / table < 0.05,
+                "df={df}: {approx} vs {table}"
+            );
+        }
+    }
+}
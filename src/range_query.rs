@@ -0,0 +1,664 @@
+//! Static range queries behind one [`RangeQuery`] trait, so a caller can swap
+//! backends without touching its query sites.
+//!
+//! - [`SparseTable`]: O(n log n) build, O(1) queries for idempotent monoids
+//!   such as [`Min`] and [`Max`].
+//! - [`DisjointSparseTable`]: the same bounds for any monoid, idempotent or
+//!   not.
+//! - [`PrefixSum`] and [`PrefixSum2D`]: O(1) sums over a [`Group`], in one or
+//!   two dimensions.
+//! - [`SqrtDecomposition`]: O(√n) queries with O(√n) point updates.
+//!
+//! [`DifferenceArray`] and [`DifferenceArray2D`] are the write-side
+//! counterparts of the prefix sums: O(1) range additions, then one O(n) pass
+//! to read the values back.
+//!
+//! ```
+//! use leetcode::range_query::{DisjointSparseTable, RangeQuery, SparseTable};
+//! use leetcode::segment_tree::{Min, Sum};
+//!
+//! let lows: SparseTable<Min<i32>> = [5, 2, 8, 1, 9].into_iter().map(Min).collect();
+//! assert_eq!(lows.query(..3), Min(2));
+//! let sums: DisjointSparseTable<Sum<i32>> = [5, 2, 8, 1, 9].into_iter().map(Sum).collect();
+//! assert_eq!(sums.query(1..=3), Sum(11));
+//! ```
+//!
+//! Two-dimensional structures take a range of `(row, column)` corners:
+//!
+//! ```
+//! use leetcode::range_query::{PrefixSum2D, RangeQuery};
+//!
+//! let grid = PrefixSum2D::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+//! assert_eq!(grid.query((0, 1)..=(1, 2)), 16);
+//! assert_eq!(grid.query(..), 21);
+//! ```
+
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::fenwick::Group;
+use crate::segment_tree::{Max, Min, Monoid};
+
+/// An aggregate over a contiguous range of a fixed sequence or grid.
+pub trait RangeQuery<I = usize> {
+    /// The aggregate of a range.
+    type Output;
+    /// The aggregate of the elements in `range`; the identity if it is empty.
+    fn query(&self, range: impl RangeBounds<I>) -> Self::Output;
+}
+
+/// A monoid whose operation satisfies `x.combine(x) == x`, so overlapping
+/// segments may be combined.
+pub trait Idempotent: Monoid {}
+
+impl<T> Idempotent for Max<T> where Self: Monoid {}
+
+impl<T> Idempotent for Min<T> where Self: Monoid {}
+
+/// Converts `range` to half-open bounds, checked against `len`.
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        Bound::Included(&l) => l,
+        Bound::Excluded(&l) => l + 1,
+        Bound::Unbounded => 0,
+    };
+    let r = match range.end_bound() {
+        Bound::Included(&r) => r + 1,
+        Bound::Excluded(&r) => r,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        l <= r && r <= len,
+        "range {l}..{r} out of range for length {len}"
+    );
+    (l, r)
+}
+
+/// Converts a range of corners to half-open row and column bounds, checked
+/// against `shape`.
+fn bounds_2d(
+    range: impl RangeBounds<(usize, usize)>,
+    shape: (usize, usize),
+) -> ((usize, usize), (usize, usize)) {
+    let (r0, c0) = match range.start_bound() {
+        Bound::Included(&(r, c)) => (r, c),
+        Bound::Excluded(&(r, c)) => (r + 1, c + 1),
+        Bound::Unbounded => (0, 0),
+    };
+    let (r1, c1) = match range.end_bound() {
+        Bound::Included(&(r, c)) => (r + 1, c + 1),
+        Bound::Excluded(&(r, c)) => (r, c),
+        Bound::Unbounded => shape,
+    };
+    assert!(
+        r0 <= r1 && r1 <= shape.0 && c0 <= c1 && c1 <= shape.1,
+        "range ({r0}, {c0})..({r1}, {c1}) out of range for shape {shape:?}"
+    );
+    ((r0, r1), (c0, c1))
+}
+
+/// Range aggregates of an idempotent monoid in O(1) after an O(n log n)
+/// build.
+#[derive(Clone, Debug)]
+pub struct SparseTable<M> {
+    /// `levels[k][i]` aggregates the `1 << k` elements starting at `i`.
+    levels: Vec<Vec<M>>,
+}
+
+impl<M: Idempotent> SparseTable<M> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<M: Idempotent> RangeQuery for SparseTable<M> {
+    type Output = M;
+
+    /// Combines the two, possibly overlapping, power-of-two blocks that cover
+    /// the range.
+    fn query(&self, range: impl RangeBounds<usize>) -> M {
+        let (l, r) = bounds(range, self.len());
+        if l == r {
+            return M::identity();
+        }
+        let k = (r - l).ilog2() as usize;
+        self.levels[k][l].combine(&self.levels[k][r - (1 << k)])
+    }
+}
+
+impl<M: Idempotent> From<Vec<M>> for SparseTable<M> {
+    fn from(values: Vec<M>) -> Self {
+        let n = values.len();
+        let mut levels = vec![values];
+        let mut width = 1;
+        while 2 * width <= n {
+            let prev = &levels[levels.len() - 1];
+            let level = (0..=n - 2 * width)
+                .map(|i| prev[i].combine(&prev[i + width]))
+                .collect();
+            levels.push(level);
+            width *= 2;
+        }
+        Self { levels }
+    }
+}
+
+impl<M: Idempotent> FromIterator<M> for SparseTable<M> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Range aggregates of any monoid in O(1) after an O(n log n) build.
+///
+/// Level `k` cuts the sequence into blocks of `1 << k` elements and stores,
+/// for each block, the suffix aggregates of its left half and the prefix
+/// aggregates of its right half. A range whose first and last indices differ
+/// highest in bit `k - 1` straddles the middle of one such block, so one
+/// suffix and one prefix cover it without overlap.
+#[derive(Clone, Debug)]
+pub struct DisjointSparseTable<M> {
+    /// `levels[0]` holds the elements themselves.
+    levels: Vec<Vec<M>>,
+}
+
+impl<M: Monoid> DisjointSparseTable<M> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<M: Monoid> RangeQuery for DisjointSparseTable<M> {
+    type Output = M;
+
+    fn query(&self, range: impl RangeBounds<usize>) -> M {
+        let (l, r) = bounds(range, self.len());
+        match r - l {
+            0 => M::identity(),
+            1 => self.levels[0][l].clone(),
+            _ => {
+                let last = r - 1;
+                let k = (l ^ last).ilog2() as usize + 1;
+                self.levels[k][l].combine(&self.levels[k][last])
+            }
+        }
+    }
+}
+
+impl<M: Monoid> From<Vec<M>> for DisjointSparseTable<M> {
+    fn from(values: Vec<M>) -> Self {
+        let n = values.len();
+        let mut levels = vec![values];
+        let mut half = 1;
+        while half < n {
+            let values = &levels[0];
+            let mut level = values.clone();
+            for mid in (half..n).step_by(2 * half) {
+                for i in (mid - half..mid - 1).rev() {
+                    level[i] = values[i].combine(&level[i + 1]);
+                }
+                for i in mid + 1..(mid + half).min(n) {
+                    level[i] = level[i - 1].combine(&values[i]);
+                }
+            }
+            levels.push(level);
+            half *= 2;
+        }
+        Self { levels }
+    }
+}
+
+impl<M: Monoid> FromIterator<M> for DisjointSparseTable<M> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Range sums over a group in O(1) after an O(n) build.
+#[derive(Clone, Debug)]
+pub struct PrefixSum<T> {
+    /// `prefix[i]` is the sum of the first `i` elements.
+    prefix: Vec<T>,
+}
+
+impl<T: Group> PrefixSum<T> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of the first `end` elements.
+    pub fn prefix(&self, end: usize) -> T {
+        self.prefix[end]
+    }
+}
+
+impl<T: Group> RangeQuery for PrefixSum<T> {
+    type Output = T;
+
+    fn query(&self, range: impl RangeBounds<usize>) -> T {
+        let (l, r) = bounds(range, self.len());
+        self.prefix[r].minus(self.prefix[l])
+    }
+}
+
+impl<T: Group> FromIterator<T> for PrefixSum<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut prefix = Vec::with_capacity(iter.size_hint().0 + 1);
+        let mut acc = T::zero();
+        prefix.push(acc);
+        for value in iter {
+            acc = acc.plus(value);
+            prefix.push(acc);
+        }
+        Self { prefix }
+    }
+}
+
+impl<T: Group> From<Vec<T>> for PrefixSum<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+/// Rectangle sums over a group in O(1) after an O(rows · cols) build.
+#[derive(Clone, Debug)]
+pub struct PrefixSum2D<T> {
+    /// `prefix[i][j]` is the sum of the first `i` rows of the first `j`
+    /// columns.
+    prefix: Vec<Vec<T>>,
+}
+
+impl<T: Group> PrefixSum2D<T> {
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    /// Number of columns.
+    pub fn cols(&self) -> usize {
+        self.prefix[0].len() - 1
+    }
+}
+
+impl<T: Group> RangeQuery<(usize, usize)> for PrefixSum2D<T> {
+    type Output = T;
+
+    /// Sums the rectangle with the given corners, by inclusion–exclusion.
+    fn query(&self, range: impl RangeBounds<(usize, usize)>) -> T {
+        let ((r0, r1), (c0, c1)) = bounds_2d(range, (self.rows(), self.cols()));
+        let p = &self.prefix;
+        p[r1][c1].minus(p[r0][c1]).minus(p[r1][c0]).plus(p[r0][c0])
+    }
+}
+
+impl<T: Group> From<Vec<Vec<T>>> for PrefixSum2D<T> {
+    /// Takes rows of equal length; no rows at all gives a 0 × 0 grid.
+    fn from(grid: Vec<Vec<T>>) -> Self {
+        let cols = grid.first().map_or(0, Vec::len);
+        let mut prefix = Vec::with_capacity(grid.len() + 1);
+        prefix.push(vec![T::zero(); cols + 1]);
+        for row in grid {
+            assert_eq!(row.len(), cols, "rows must have equal lengths");
+            let above = &prefix[prefix.len() - 1];
+            let mut line = Vec::with_capacity(cols + 1);
+            line.push(T::zero());
+            let mut acc = T::zero();
+            for (j, value) in row.into_iter().enumerate() {
+                acc = acc.plus(value);
+                line.push(above[j + 1].plus(acc));
+            }
+            prefix.push(line);
+        }
+        Self { prefix }
+    }
+}
+
+/// Range additions in O(1), read back in one O(n) pass.
+#[derive(Clone, Debug)]
+pub struct DifferenceArray<T> {
+    /// `diff[i]` is element `i` minus element `i - 1`; one extra slot
+    /// absorbs additions that run to the end.
+    diff: Vec<T>,
+}
+
+impl<T: Group> DifferenceArray<T> {
+    /// `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            diff: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to every element in `range`.
+    pub fn add(&mut self, range: impl RangeBounds<usize>, delta: T) {
+        let (l, r) = bounds(range, self.len());
+        self.diff[l] = self.diff[l].plus(delta);
+        self.diff[r] = self.diff[r].minus(delta);
+    }
+
+    /// The elements after every addition so far.
+    pub fn values(&self) -> Vec<T> {
+        let mut acc = T::zero();
+        self.diff[..self.len()]
+            .iter()
+            .map(|&d| {
+                acc = acc.plus(d);
+                acc
+            })
+            .collect()
+    }
+}
+
+/// Rectangle additions in O(1), read back in one O(rows · cols) pass.
+#[derive(Clone, Debug)]
+pub struct DifferenceArray2D<T> {
+    /// Two-dimensional differences, with an extra row and column.
+    diff: Vec<Vec<T>>,
+}
+
+impl<T: Group> DifferenceArray2D<T> {
+    /// A `rows` × `cols` grid of zeros.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            diff: vec![vec![T::zero(); cols + 1]; rows + 1],
+        }
+    }
+
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// Number of columns.
+    pub fn cols(&self) -> usize {
+        self.diff[0].len() - 1
+    }
+
+    /// Adds `delta` to every cell of the rectangle with the given corners.
+    pub fn add(&mut self, range: impl RangeBounds<(usize, usize)>, delta: T) {
+        let ((r0, r1), (c0, c1)) = bounds_2d(range, (self.rows(), self.cols()));
+        let d = &mut self.diff;
+        d[r0][c0] = d[r0][c0].plus(delta);
+        d[r0][c1] = d[r0][c1].minus(delta);
+        d[r1][c0] = d[r1][c0].minus(delta);
+        d[r1][c1] = d[r1][c1].plus(delta);
+    }
+
+    /// The cells after every addition so far.
+    pub fn values(&self) -> Vec<Vec<T>> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut grid: Vec<Vec<T>> = Vec::with_capacity(rows);
+        for i in 0..rows {
+            let mut acc = T::zero();
+            let row = (0..cols)
+                .map(|j| {
+                    acc = acc.plus(self.diff[i][j]);
+                    match grid.last() {
+                        Some(above) => above[j].plus(acc),
+                        None => acc,
+                    }
+                })
+                .collect();
+            grid.push(row);
+        }
+        grid
+    }
+}
+
+/// Range aggregates of any monoid in O(√n), with O(√n) point updates.
+#[derive(Clone, Debug)]
+pub struct SqrtDecomposition<M> {
+    values: Vec<M>,
+    /// `blocks[b]` aggregates the elements `b * width..(b + 1) * width`.
+    blocks: Vec<M>,
+    width: usize,
+}
+
+impl<M: Monoid> SqrtDecomposition<M> {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element `i`.
+    pub fn get(&self, i: usize) -> &M {
+        &self.values[i]
+    }
+
+    /// Replaces element `i` and rebuilds its block.
+    pub fn set(&mut self, i: usize, value: M) {
+        self.values[i] = value;
+        let b = i / self.width;
+        self.blocks[b] = self.fold(b * self.width..((b + 1) * self.width).min(self.len()));
+    }
+
+    fn fold(&self, range: Range<usize>) -> M {
+        self.values[range]
+            .iter()
+            .fold(M::identity(), |acc, x| acc.combine(x))
+    }
+}
+
+impl<M: Monoid> RangeQuery for SqrtDecomposition<M> {
+    type Output = M;
+
+    /// Combines the partial blocks at either end with the whole blocks
+    /// between them.
+    fn query(&self, range: impl RangeBounds<usize>) -> M {
+        let (l, r) = bounds(range, self.len());
+        let (first, last) = (l.div_ceil(self.width), r / self.width);
+        if first >= last {
+            return self.fold(l..r);
+        }
+        let middle = self.blocks[first..last]
+            .iter()
+            .fold(self.fold(l..first * self.width), |acc, x| acc.combine(x));
+        middle.combine(&self.fold(last * self.width..r))
+    }
+}
+
+impl<M: Monoid> From<Vec<M>> for SqrtDecomposition<M> {
+    fn from(values: Vec<M>) -> Self {
+        let width = values.len().isqrt().max(1);
+        let blocks = values
+            .chunks(width)
+            .map(|chunk| chunk.iter().fold(M::identity(), |acc, x| acc.combine(x)))
+            .collect();
+        Self {
+            values,
+            blocks,
+            width,
+        }
+    }
+}
+
+impl<M: Monoid> FromIterator<M> for SqrtDecomposition<M> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+    use crate::segment_tree::Sum;
+
+    fn range(rng: &mut SeededRng, n: usize) -> (usize, usize) {
+        let l = rng.in_range(0..=n);
+        (l, rng.in_range(l..=n))
+    }
+
+    /// Checks `backend` against a naive fold over `values` on every range.
+    fn check_all_ranges<M, B>(values: &[M], backend: &B)
+    where
+        M: Monoid + PartialEq + std::fmt::Debug,
+        B: RangeQuery<Output = M>,
+    {
+        for l in 0..=values.len() {
+            for r in l..=values.len() {
+                let naive = values[l..r]
+                    .iter()
+                    .fold(M::identity(), |acc, x| acc.combine(x));
+                assert_eq!(backend.query(l..r), naive, "range {l}..{r}");
+            }
+        }
+    }
+
+    #[test]
+    fn tables_match_naive() {
+        let mut rng = SeededRng::new(14);
+        for n in 0..40 {
+            let raw: Vec<i64> = (0..n).map(|_| rng.in_range(-50..=50)).collect();
+            let mins: Vec<Min<i64>> = raw.iter().copied().map(Min).collect();
+            check_all_ranges(&mins, &SparseTable::from(mins.clone()));
+            check_all_ranges(&mins, &DisjointSparseTable::from(mins.clone()));
+            let sums: Vec<Sum<i64>> = raw.iter().copied().map(Sum).collect();
+            check_all_ranges(&sums, &DisjointSparseTable::from(sums.clone()));
+            check_all_ranges(&sums, &SqrtDecomposition::from(sums.clone()));
+            let prefix = PrefixSum::from(raw.clone());
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(prefix.query(l..r), raw[l..r].iter().sum::<i64>());
+                }
+            }
+        }
+    }
+
+    /// String concatenation is associative but neither commutative nor
+    /// idempotent, so it catches combining in the wrong order.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Concat(String);
+
+    impl Monoid for Concat {
+        fn identity() -> Self {
+            Concat(String::new())
+        }
+        fn combine(&self, right: &Self) -> Self {
+            Concat(self.0.clone() + &right.0)
+        }
+    }
+
+    #[test]
+    fn non_commutative_order_is_kept() {
+        let letters: Vec<Concat> = ('a'..='q').map(|c| Concat(c.to_string())).collect();
+        check_all_ranges(&letters, &DisjointSparseTable::from(letters.clone()));
+        check_all_ranges(&letters, &SqrtDecomposition::from(letters.clone()));
+    }
+
+    #[test]
+    fn sqrt_decomposition_updates() {
+        let mut rng = SeededRng::new(15);
+        for n in 1..30 {
+            let mut naive: Vec<Max<i32>> = (0..n).map(|_| Max(rng.in_range(-9..=9))).collect();
+            let mut blocks = SqrtDecomposition::from(naive.clone());
+            for _ in 0..100 {
+                let (i, x) = (rng.in_range(0..n), Max(rng.in_range(-9..=9)));
+                naive[i] = x;
+                blocks.set(i, x);
+                assert_eq!(*blocks.get(i), x);
+                let (l, r) = range(&mut rng, n);
+                let expected = naive[l..r].iter().copied().max().unwrap_or(Max(i32::MIN));
+                assert_eq!(blocks.query(l..r), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn two_dimensional_sums_match_naive() {
+        let mut rng = SeededRng::new(16);
+        for (rows, cols) in [(0, 0), (1, 1), (1, 7), (6, 1), (5, 8)] {
+            let grid: Vec<Vec<i32>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.in_range(-9..=9)).collect())
+                .collect();
+            let prefix = PrefixSum2D::from(grid.clone());
+            let mut diff = DifferenceArray2D::new(rows, cols);
+            let mut naive = vec![vec![0; cols]; rows];
+            for _ in 0..50 {
+                let (r0, r1) = range(&mut rng, rows);
+                let (c0, c1) = range(&mut rng, cols);
+                let expected: i32 = grid[r0..r1]
+                    .iter()
+                    .map(|row| row[c0..c1].iter().sum::<i32>())
+                    .sum();
+                assert_eq!(prefix.query((r0, c0)..(r1, c1)), expected);
+                let delta = rng.in_range(-9..=9);
+                diff.add((r0, c0)..(r1, c1), delta);
+                for row in &mut naive[r0..r1] {
+                    for cell in &mut row[c0..c1] {
+                        *cell += delta;
+                    }
+                }
+            }
+            assert_eq!(diff.values(), naive);
+            assert_eq!(prefix.query(..), grid.iter().flatten().sum::<i32>());
+        }
+    }
+
+    #[test]
+    fn difference_array_matches_naive() {
+        let mut rng = SeededRng::new(17);
+        for n in 0..20 {
+            let mut diff = DifferenceArray::new(n);
+            let mut naive = vec![0_i64; n];
+            for _ in 0..50 {
+                let (l, r) = range(&mut rng, n);
+                let delta = rng.in_range(-9..=9);
+                diff.add(l..r, delta);
+                naive[l..r].iter_mut().for_each(|x| *x += delta);
+            }
+            assert_eq!(diff.values(), naive);
+        }
+    }
+
+    #[test]
+    fn difference_arrays_accept_unsigned_elements() {
+        let mut diff = DifferenceArray::<u32>::new(3);
+        diff.add(0..2, 5);
+        diff.add(1.., 2);
+        assert_eq!(diff.values(), [5, 7, 2]);
+        let mut grid = DifferenceArray2D::<usize>::new(3, 3);
+        grid.add((0, 0)..(2, 2), 4);
+        grid.add((1, 1)..(3, 3), 1);
+        assert_eq!(grid.values(), [[4, 4, 0], [4, 5, 1], [0, 1, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn rejects_out_of_range() {
+        SparseTable::from(vec![Max(1), Max(2)]).query(1..3);
+    }
+}
@@ -0,0 +1,732 @@
+//! Rate limiters over an injectable clock.
+//!
+//! Each [`Limiter`] tracks one stream of requests and is driven by explicit
+//! timestamps, in ticks of whatever unit the caller counts — seconds,
+//! milliseconds. A timestamp earlier than one already seen is treated as the
+//! latest seen, so a clock that steps back cannot corrupt the state.
+//!
+//! | limiter                   | admits                                       | memory            |
+//! |---------------------------|----------------------------------------------|-------------------|
+//! | [`FixedWindow`]           | `limit` per aligned window                   | O(1)              |
+//! | [`SlidingLog`]            | `limit` in any trailing window, exactly      | O(distinct times) |
+//! | [`SlidingWindowCounter`]  | `limit` in the trailing buckets              | O(buckets)        |
+//! | [`TokenBucket`]           | bursts of `capacity`, refilled at a rate     | O(1)              |
+//! | [`LeakyBucket`]           | up to `capacity` queued, drained at a rate   | O(1)              |
+//!
+//! [`KeyedLimiter`] keeps one limiter per key, built by a function of the key
+//! so limits can differ between keys, reads the time from a [`Clock`], and
+//! drops keys whose limiter has returned to its fresh state so memory follows
+//! the active keys.
+//!
+//! ```
+//! use leetcode::rate_limit::{KeyedLimiter, ManualClock, TokenBucket};
+//!
+//! let clock = ManualClock::new(0);
+//! // two requests at once, then one per 10 ticks; admins get twice as many
+//! let mut limiter = KeyedLimiter::with_clock(
+//!     |user: &&str| match *user {
+//!         "admin" => TokenBucket::new(4, 2, 10),
+//!         _ => TokenBucket::new(2, 1, 10),
+//!     },
+//!     clock.clone(),
+//! );
+//! assert!(limiter.try_acquire("bob") && limiter.try_acquire("bob"));
+//! assert!(!limiter.try_acquire("bob"));
+//! clock.advance(10);
+//! assert!(limiter.try_acquire("bob"));
+//! assert_eq!(limiter.remaining(&"admin"), 4);
+//! ```
+
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::time::Instant;
+
+/// A source of the current time in ticks.
+pub trait Clock {
+    /// The current time; successive readings should not decrease.
+    fn now(&self) -> u64;
+}
+
+/// Milliseconds since the clock was created, from the monotonic system
+/// clock.
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    /// A clock reading zero now.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        self.start.elapsed().as_millis() as u64
+    }
+}
+
+/// A clock that moves only when told to. Clones share one time, so a test
+/// can keep a handle and advance the clock a limiter owns.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<u64>>,
+}
+
+impl ManualClock {
+    /// A clock reading `start`.
+    pub fn new(start: u64) -> Self {
+        Self {
+            now: Rc::new(Cell::new(start)),
+        }
+    }
+
+    /// Moves the clock to `now`.
+    pub fn set(&self, now: u64) {
+        self.now.set(now);
+    }
+
+    /// Moves the clock forward by `ticks`.
+    pub fn advance(&self, ticks: u64) {
+        self.now.set(self.now.get() + ticks);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.now.get()
+    }
+}
+
+/// Decides whether requests arriving at given times may proceed.
+pub trait Limiter {
+    /// Admits `n` requests at `now` if the limit allows all of them,
+    /// recording them; otherwise admits none and records nothing.
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool;
+
+    /// Admits one request at `now` if the limit allows.
+    fn try_acquire(&mut self, now: u64) -> bool {
+        self.try_acquire_n(now, 1)
+    }
+
+    /// How many more requests would be admitted at `now`.
+    fn remaining(&mut self, now: u64) -> u64;
+
+    /// Whether the limiter is back in the state it started in, so it can be
+    /// dropped and rebuilt without changing any decision.
+    fn is_idle(&mut self, now: u64) -> bool;
+}
+
+/// At most `limit` requests in each window `[k·window, (k+1)·window)`.
+///
+/// Cheapest of the window limiters, but a burst straddling a boundary can
+/// reach twice the limit within one window's length.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedWindow {
+    window: u64,
+    limit: u64,
+    /// Index of the window `count` belongs to.
+    current: u64,
+    count: u64,
+}
+
+impl FixedWindow {
+    /// A limiter admitting `limit` requests per `window` ticks.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero.
+    pub fn new(window: u64, limit: u64) -> Self {
+        assert!(window > 0, "window must be positive");
+        Self {
+            window,
+            limit,
+            current: 0,
+            count: 0,
+        }
+    }
+
+    /// Requests admitted in the window containing `now`.
+    pub fn count(&self, now: u64) -> u64 {
+        if now / self.window > self.current {
+            0
+        } else {
+            self.count
+        }
+    }
+
+    fn roll(&mut self, now: u64) {
+        let index = now / self.window;
+        if index > self.current {
+            self.current = index;
+            self.count = 0;
+        }
+    }
+}
+
+impl Limiter for FixedWindow {
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool {
+        self.roll(now);
+        let admitted = self.count.saturating_add(n) <= self.limit;
+        if admitted {
+            self.count += n;
+        }
+        admitted
+    }
+
+    fn remaining(&mut self, now: u64) -> u64 {
+        self.roll(now);
+        self.limit - self.count
+    }
+
+    fn is_idle(&mut self, now: u64) -> bool {
+        self.roll(now);
+        self.count == 0
+    }
+}
+
+/// At most `limit` requests in any `window` consecutive ticks, that is with
+/// timestamps in `(now - window, now]`.
+///
+/// # Intuition
+/// Remember when each admitted request arrived; those older than the window
+/// fall off the front, and the rest are exactly the ones that count.
+///
+/// # Approach
+/// Keep `(time, requests)` pairs in arrival order, merging requests that
+/// share a tick, with a running total. Every operation first pops the pairs
+/// that have left the window.
+///
+/// # Complexity
+/// - Time: O(1) amortized per operation
+/// - Space: O(min(limit, window)) pairs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlidingLog {
+    window: u64,
+    limit: u64,
+    log: VecDeque<(u64, u64)>,
+    total: u64,
+}
+
+impl SlidingLog {
+    /// A limiter admitting `limit` requests in any `window` ticks.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero.
+    pub fn new(window: u64, limit: u64) -> Self {
+        assert!(window > 0, "window must be positive");
+        Self {
+            window,
+            limit,
+            log: VecDeque::new(),
+            total: 0,
+        }
+    }
+
+    /// Requests admitted in the window ending at `now`.
+    pub fn count(&self, now: u64) -> u64 {
+        let expired: u64 = self
+            .log
+            .iter()
+            .take_while(|&&(time, _)| self.expired(time, now))
+            .map(|&(_, n)| n)
+            .sum();
+        self.total - expired
+    }
+
+    fn expired(&self, time: u64, now: u64) -> bool {
+        time + self.window <= now
+    }
+
+    fn evict(&mut self, now: u64) {
+        while let Some(&(time, n)) = self.log.front() {
+            if !self.expired(time, now) {
+                break;
+            }
+            self.log.pop_front();
+            self.total -= n;
+        }
+    }
+}
+
+impl Limiter for SlidingLog {
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool {
+        self.evict(now);
+        if self.total.saturating_add(n) > self.limit {
+            return false;
+        }
+        if n > 0 {
+            match self.log.back_mut() {
+                Some((time, count)) if *time >= now => *count += n,
+                _ => self.log.push_back((now, n)),
+            }
+            self.total += n;
+        }
+        true
+    }
+
+    fn remaining(&mut self, now: u64) -> u64 {
+        self.evict(now);
+        self.limit - self.total
+    }
+
+    fn is_idle(&mut self, now: u64) -> bool {
+        self.evict(now);
+        self.log.is_empty()
+    }
+}
+
+/// At most `limit` requests in the last `buckets` buckets of `width` ticks,
+/// the bucket holding `now` included.
+///
+/// # Intuition
+/// Counting per bucket rather than per request bounds memory by the number
+/// of buckets, at the price of letting a whole bucket expire at once. With
+/// one-tick buckets it is exact.
+///
+/// # Approach
+/// A ring of counters with a running total. When time moves into a later
+/// bucket, the slots of the buckets passed over are reclaimed for the new
+/// ones, their counts leaving the total; the total is then the window's
+/// count.
+///
+/// # Complexity
+/// - Time: O(1) to record or count, plus O(1) per bucket the clock has moved
+///   past, at most O(buckets)
+/// - Space: O(buckets)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlidingWindowCounter {
+    width: u64,
+    limit: u64,
+    /// Requests per bucket, where bucket `b` covers `[b·width, (b+1)·width)`
+    /// and lives in slot `b % len`.
+    slots: Vec<u64>,
+    /// Sum of `slots`.
+    total: u64,
+    /// The latest bucket seen, the window's newest.
+    bucket: u64,
+}
+
+impl SlidingWindowCounter {
+    /// A limiter admitting `limit` requests over `buckets` buckets of `width`
+    /// ticks.
+    ///
+    /// # Panics
+    /// Panics if `width` or `buckets` is zero.
+    pub fn new(width: u64, buckets: usize, limit: u64) -> Self {
+        assert!(width > 0 && buckets > 0, "need buckets of positive width");
+        Self {
+            width,
+            limit,
+            slots: vec![0; buckets],
+            total: 0,
+            bucket: 0,
+        }
+    }
+
+    /// Requests admitted in the buckets that make up the window at `now`.
+    pub fn count(&self, now: u64) -> u64 {
+        self.total - self.expiring(now).map(|slot| self.slots[slot]).sum::<u64>()
+    }
+
+    /// Slots of the buckets that leave the window when the clock moves to
+    /// `now`.
+    fn expiring(&self, now: u64) -> impl Iterator<Item = usize> + use<> {
+        let len = self.slots.len() as u64;
+        let passed = (now / self.width).saturating_sub(self.bucket).min(len);
+        let bucket = self.bucket;
+        (1..=passed).map(move |i| ((bucket + i) % len) as usize)
+    }
+
+    fn advance(&mut self, now: u64) {
+        for slot in self.expiring(now) {
+            self.total -= std::mem::take(&mut self.slots[slot]);
+        }
+        self.bucket = self.bucket.max(now / self.width);
+    }
+}
+
+impl Limiter for SlidingWindowCounter {
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool {
+        self.advance(now);
+        if self.total.saturating_add(n) > self.limit {
+            return false;
+        }
+        let slot = (self.bucket % self.slots.len() as u64) as usize;
+        self.slots[slot] += n;
+        self.total += n;
+        true
+    }
+
+    fn remaining(&mut self, now: u64) -> u64 {
+        self.advance(now);
+        self.limit - self.total
+    }
+
+    fn is_idle(&mut self, now: u64) -> bool {
+        self.advance(now);
+        self.total == 0
+    }
+}
+
+/// Holds up to `capacity` tokens, gaining `refill` every `per` ticks; each
+/// request spends one. Starts full, so it allows bursts of `capacity`.
+///
+/// Tokens are counted in units of `1/per` so partial refills are exact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenBucket {
+    per: u64,
+    refill: u64,
+    /// `capacity · per`.
+    full: u64,
+    /// Tokens times `per`.
+    level: u64,
+    last: u64,
+}
+
+impl TokenBucket {
+    /// A full bucket of `capacity` tokens refilled at `refill` per `per`
+    /// ticks.
+    ///
+    /// # Panics
+    /// Panics if `per` is zero.
+    pub fn new(capacity: u64, refill: u64, per: u64) -> Self {
+        assert!(per > 0, "refill period must be positive");
+        let full = capacity.saturating_mul(per);
+        Self {
+            per,
+            refill,
+            full,
+            level: full,
+            last: 0,
+        }
+    }
+
+    fn advance(&mut self, now: u64) {
+        if now > self.last {
+            let gained = (now - self.last).saturating_mul(self.refill);
+            self.level = self.level.saturating_add(gained).min(self.full);
+            self.last = now;
+        }
+    }
+}
+
+impl Limiter for TokenBucket {
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool {
+        self.advance(now);
+        let cost = n.saturating_mul(self.per);
+        let admitted = cost <= self.level;
+        if admitted {
+            self.level -= cost;
+        }
+        admitted
+    }
+
+    fn remaining(&mut self, now: u64) -> u64 {
+        self.advance(now);
+        self.level / self.per
+    }
+
+    fn is_idle(&mut self, now: u64) -> bool {
+        self.advance(now);
+        self.level == self.full
+    }
+}
+
+/// A queue of at most `capacity` requests that drains at `leak` every `per`
+/// ticks; a request is admitted if it fits. Starts empty, and smooths
+/// traffic to the drain rate once full.
+///
+/// The level is counted in units of `1/per` so partial drains are exact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeakyBucket {
+    per: u64,
+    leak: u64,
+    /// `capacity · per`.
+    full: u64,
+    /// Queued requests times `per`.
+    level: u64,
+    last: u64,
+}
+
+impl LeakyBucket {
+    /// An empty bucket holding `capacity` requests and draining `leak` per
+    /// `per` ticks.
+    ///
+    /// # Panics
+    /// Panics if `per` is zero.
+    pub fn new(capacity: u64, leak: u64, per: u64) -> Self {
+        assert!(per > 0, "drain period must be positive");
+        Self {
+            per,
+            leak,
+            full: capacity.saturating_mul(per),
+            level: 0,
+            last: 0,
+        }
+    }
+
+    /// Requests still queued at `now`, rounded up.
+    pub fn queued(&mut self, now: u64) -> u64 {
+        self.advance(now);
+        self.level.div_ceil(self.per)
+    }
+
+    fn advance(&mut self, now: u64) {
+        if now > self.last {
+            let drained = (now - self.last).saturating_mul(self.leak);
+            self.level = self.level.saturating_sub(drained);
+            self.last = now;
+        }
+    }
+}
+
+impl Limiter for LeakyBucket {
+    fn try_acquire_n(&mut self, now: u64, n: u64) -> bool {
+        self.advance(now);
+        let added = n.saturating_mul(self.per);
+        let admitted = added <= self.full - self.level;
+        if admitted {
+            self.level += added;
+        }
+        admitted
+    }
+
+    fn remaining(&mut self, now: u64) -> u64 {
+        self.advance(now);
+        (self.full - self.level) / self.per
+    }
+
+    fn is_idle(&mut self, now: u64) -> bool {
+        self.advance(now);
+        self.level == 0
+    }
+}
+
+/// One limiter per key, all read against a shared [`Clock`].
+///
+/// Limiters are built on a key's first request by a function of the key,
+/// and dropped again once idle: every time the number of keys doubles since
+/// the last sweep, idle ones are swept out, so memory stays proportional to
+/// the keys active within a window.
+pub struct KeyedLimiter<K, L> {
+    limiters: HashMap<K, L>,
+    make: Box<dyn Fn(&K) -> L>,
+    clock: Box<dyn Clock>,
+    /// Size at which the next sweep runs.
+    sweep_at: usize,
+}
+
+impl<K: Eq + Hash, L: Limiter> KeyedLimiter<K, L> {
+    /// Keys get `make(key)` and time comes from a [`SystemClock`].
+    pub fn new(make: impl Fn(&K) -> L + 'static) -> Self {
+        Self::with_clock(make, SystemClock::new())
+    }
+
+    /// Keys get `make(key)` and time comes from `clock`.
+    pub fn with_clock(make: impl Fn(&K) -> L + 'static, clock: impl Clock + 'static) -> Self {
+        Self {
+            limiters: HashMap::new(),
+            make: Box::new(make),
+            clock: Box::new(clock),
+            sweep_at: 16,
+        }
+    }
+
+    /// Number of keys with state.
+    pub fn len(&self) -> usize {
+        self.limiters.len()
+    }
+
+    /// Whether no key has state.
+    pub fn is_empty(&self) -> bool {
+        self.limiters.is_empty()
+    }
+
+    /// Admits one request for `key` if its limit allows.
+    pub fn try_acquire(&mut self, key: K) -> bool {
+        self.try_acquire_n(key, 1)
+    }
+
+    /// Admits `n` requests for `key` if its limit allows all of them.
+    pub fn try_acquire_n(&mut self, key: K, n: u64) -> bool {
+        let now = self.clock.now();
+        if self.limiters.len() >= self.sweep_at {
+            self.sweep();
+            self.sweep_at = (2 * self.limiters.len()).max(16);
+        }
+        let make = &self.make;
+        self.limiters
+            .entry(key)
+            .or_insert_with_key(|key| make(key))
+            .try_acquire_n(now, n)
+    }
+
+    /// How many more requests `key` may make now.
+    pub fn remaining(&mut self, key: &K) -> u64 {
+        let now = self.clock.now();
+        match self.limiters.get_mut(key) {
+            Some(limiter) => limiter.remaining(now),
+            None => (self.make)(key).remaining(now),
+        }
+    }
+
+    /// Drops the limiters of idle keys, returning how many were dropped.
+    pub fn sweep(&mut self) -> usize {
+        let now = self.clock.now();
+        let before = self.limiters.len();
+        self.limiters.retain(|_, limiter| !limiter.is_idle(now));
+        before - self.limiters.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    /// Admits a request whenever fewer than `limit` admitted ones lie in
+    /// `(now - window, now]`.
+    fn naive_window(times: &[u64], window: u64, limit: usize) -> Vec<bool> {
+        let mut admitted: Vec<u64> = Vec::new();
+        times
+            .iter()
+            .map(|&now| {
+                let recent = admitted.iter().filter(|&&t| t + window > now).count();
+                let ok = recent < limit;
+                if ok {
+                    admitted.push(now);
+                }
+                ok
+            })
+            .collect()
+    }
+
+    fn random_times(rng: &mut SeededRng, len: usize) -> Vec<u64> {
+        let mut now = 0;
+        (0..len)
+            .map(|_| {
+                now += rng.below(4);
+                now
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sliding_limiters_match_a_naive_window() {
+        let mut rng = SeededRng::new(25);
+        for _ in 0..50 {
+            let times = random_times(&mut rng, 300);
+            let window = 1 + rng.below(20);
+            let limit = 1 + rng.below(8);
+            let expected = naive_window(&times, window, limit as usize);
+            let mut log = SlidingLog::new(window, limit);
+            let mut buckets = SlidingWindowCounter::new(1, window as usize, limit);
+            for (&now, &ok) in times.iter().zip(&expected) {
+                assert_eq!(log.try_acquire(now), ok);
+                assert_eq!(buckets.try_acquire(now), ok);
+                assert_eq!(log.count(now), buckets.count(now));
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_window_resets_on_boundaries() {
+        let mut limiter = FixedWindow::new(10, 2);
+        assert!(limiter.try_acquire(8) && limiter.try_acquire(9));
+        assert!(!limiter.try_acquire(9));
+        // a new window admits a second burst right away
+        assert!(limiter.try_acquire(10) && limiter.try_acquire(11));
+        assert_eq!(limiter.remaining(19), 0);
+        assert_eq!(limiter.count(25), 0);
+        assert!(!limiter.try_acquire_n(25, 3));
+        assert!(limiter.try_acquire_n(25, 2));
+        assert!(limiter.is_idle(30));
+    }
+
+    #[test]
+    fn wide_buckets_expire_whole() {
+        let mut limiter = SlidingWindowCounter::new(10, 3, 3);
+        assert!(limiter.try_acquire_n(5, 3));
+        assert!(!limiter.try_acquire(29));
+        assert_eq!(limiter.remaining(30), 3);
+        // stale times read as the latest seen
+        assert!(limiter.try_acquire_n(31, 2));
+        assert_eq!(limiter.count(0), 2);
+    }
+
+    #[test]
+    fn buckets_refill_and_drain_at_their_rate() {
+        // 3 tokens, one every 4 ticks
+        let mut tokens = TokenBucket::new(3, 1, 4);
+        assert!(tokens.try_acquire_n(0, 3));
+        assert!(!tokens.try_acquire(3));
+        assert!(tokens.try_acquire(4));
+        assert_eq!(tokens.remaining(10), 1);
+        assert_eq!(tokens.remaining(100), 3);
+        assert!(tokens.is_idle(100));
+        assert!(!tokens.try_acquire_n(100, 4));
+
+        let mut leaky = LeakyBucket::new(2, 1, 4);
+        assert!(leaky.is_idle(0));
+        assert!(leaky.try_acquire_n(0, 2));
+        assert!(!leaky.try_acquire(2));
+        assert_eq!(leaky.queued(2), 2);
+        assert_eq!(leaky.queued(5), 1);
+        assert!(leaky.try_acquire(5));
+        assert_eq!(leaky.remaining(5), 0);
+        // clocks that step back change nothing
+        assert_eq!(leaky.remaining(1), 0);
+        assert!(leaky.is_idle(13));
+    }
+
+    #[test]
+    fn token_bucket_admits_its_rate_over_time() {
+        let mut tokens = TokenBucket::new(5, 3, 7);
+        let admitted = (0..7_000).filter(|&now| tokens.try_acquire(now)).count();
+        // the initial burst plus three tokens per seven ticks
+        assert_eq!(admitted, 5 + 3 * 6_999 / 7);
+    }
+
+    #[test]
+    fn keyed_limits_follow_the_clock_and_the_key() {
+        let clock = ManualClock::new(0);
+        let mut limiter = KeyedLimiter::with_clock(
+            |&key: &u32| SlidingLog::new(10, u64::from(key)),
+            clock.clone(),
+        );
+        assert!(limiter.try_acquire(1));
+        assert!(!limiter.try_acquire(1));
+        assert!(limiter.try_acquire_n(3, 3));
+        assert_eq!(limiter.remaining(&2), 2);
+        assert_eq!(limiter.len(), 2);
+        clock.advance(9);
+        assert!(!limiter.try_acquire(1));
+        clock.set(10);
+        assert!(limiter.try_acquire(1));
+        clock.set(25);
+        assert_eq!(limiter.sweep(), 2);
+        assert!(limiter.is_empty());
+    }
+
+    #[test]
+    fn idle_keys_are_swept_as_keys_arrive() {
+        let clock = ManualClock::new(0);
+        let mut limiter = KeyedLimiter::with_clock(|_: &u64| FixedWindow::new(1, 1), clock.clone());
+        for key in 0..10_000 {
+            clock.advance(1);
+            assert!(limiter.try_acquire(key));
+        }
+        assert!(limiter.len() <= 16, "{}", limiter.len());
+    }
+}
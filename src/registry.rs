@@ -0,0 +1,189 @@
+//! Metadata for every problem in the crate, extracted by `build.rs` from the
+//! file names and the `# Complexity` doc sections.
+//!
+//! ```
+//! use leetcode::registry;
+//!
+//! let lru = registry::by_id(146).unwrap();
+//! assert_eq!(lru.title, "LRU Cache");
+//! assert_eq!(lru.type_name, "LRUCache");
+//! assert_eq!(lru.entry_points, ["new", "get", "put"]);
+//!
+//! let linear = registry::with_time("O(n)").count();
+//! assert!(linear > 0);
+//! ```
+
+/// One problem file, such as `0001.Two Sum.rs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Problem {
+    /// LeetCode problem number.
+    pub id: u32,
+    /// Title as it appears in the file name.
+    pub title: &'static str,
+    /// Module the solution is compiled into, e.g. `p0001_two_sum`.
+    pub module: &'static str,
+    /// File name relative to the crate root.
+    pub file: &'static str,
+    /// Type LeetCode calls into: `Solution`, or the class of a design problem.
+    pub type_name: &'static str,
+    /// Methods of [`Problem::type_name`] that LeetCode calls.
+    pub entry_points: &'static [&'static str],
+    /// Bounds declared in the doc comment.
+    pub complexity: Complexity,
+    /// Number of `#[test]` functions in the file.
+    pub tests: usize,
+}
+
+impl Problem {
+    /// Whether this is a design problem, driven through a constructor and a
+    /// sequence of method calls rather than a single `Solution` function.
+    pub fn is_design(&self) -> bool {
+        self.type_name != "Solution" || self.entry_points.contains(&"new")
+    }
+}
+
+/// Declared time and space bounds, e.g. `O(n log n)` and `O(1)`.
+///
+/// Either is `None` when the doc comment does not state it as a single bound,
+/// as with design problems that list one bound per operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complexity {
+    pub time: Option<&'static str>,
+    pub space: Option<&'static str>,
+}
+
+impl Complexity {
+    /// Whether the declared time bound is `bound`, ignoring spacing and
+    /// notation differences such as `n²` versus `n^2`.
+    pub fn time_is(&self, bound: &str) -> bool {
+        self.time.is_some_and(|time| same_bound(time, bound))
+    }
+
+    /// Whether the declared space bound is `bound`; see [`Complexity::time_is`].
+    pub fn space_is(&self, bound: &str) -> bool {
+        self.space.is_some_and(|space| same_bound(space, bound))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// Every problem, ordered by id.
+pub fn all() -> &'static [Problem] {
+    PROBLEMS
+}
+
+/// The problem with LeetCode number `id`.
+pub fn by_id(id: u32) -> Option<&'static Problem> {
+    PROBLEMS
+        .binary_search_by_key(&id, |p| p.id)
+        .ok()
+        .map(|i| &PROBLEMS[i])
+}
+
+/// The problem whose title matches `title`, ignoring ASCII case.
+pub fn by_title(title: &str) -> Option<&'static Problem> {
+    PROBLEMS
+        .iter()
+        .find(|p| p.title.eq_ignore_ascii_case(title))
+}
+
+/// Problems with an entry point named `name`, e.g. `two_sum`.
+pub fn by_entry_point(name: &str) -> impl Iterator<Item = &'static Problem> + '_ {
+    PROBLEMS
+        .iter()
+        .filter(move |p| p.entry_points.contains(&name))
+}
+
+/// Problems whose declared time bound is `bound`, e.g. `O(n log n)`.
+pub fn with_time(bound: &str) -> impl Iterator<Item = &'static Problem> + '_ {
+    PROBLEMS.iter().filter(move |p| p.complexity.time_is(bound))
+}
+
+/// Problems whose declared space bound is `bound`, e.g. `O(1)`.
+pub fn with_space(bound: &str) -> impl Iterator<Item = &'static Problem> + '_ {
+    PROBLEMS
+        .iter()
+        .filter(move |p| p.complexity.space_is(bound))
+}
+
+fn same_bound(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// Canonical spelling of a bound: no whitespace or backticks, `*` for
+/// products and `^` for powers.
+fn normalize(bound: &str) -> String {
+    let mut out = String::with_capacity(bound.len());
+    for c in bound.chars() {
+        match c {
+            '×' | '·' | '⋅' => out.push('*'),
+            '²' => out.push_str("^2"),
+            '³' => out.push_str("^3"),
+            '`' => {}
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_sorted_and_unique() {
+        assert!(all().windows(2).all(|w| w[0].id < w[1].id));
+        assert!(by_id(0).is_none());
+    }
+
+    #[test]
+    fn solution_problem_metadata() {
+        let two_sum = by_id(1).unwrap();
+        assert_eq!(two_sum.title, "Two Sum");
+        assert_eq!(two_sum.module, "p0001_two_sum");
+        assert_eq!(two_sum.file, "0001.Two Sum.rs");
+        assert_eq!(
+            (two_sum.type_name, two_sum.entry_points),
+            ("Solution", &["two_sum"][..])
+        );
+        assert_eq!(two_sum.complexity.time, Some("O(n)"));
+        assert_eq!(two_sum.complexity.space, Some("O(n)"));
+        assert!(two_sum.tests > 0);
+        assert!(!two_sum.is_design());
+    }
+
+    #[test]
+    fn design_problem_skips_helpers() {
+        let trie = by_title("implement trie (prefix tree)").unwrap();
+        assert_eq!(trie.id, 208);
+        assert_eq!(trie.type_name, "Trie");
+        assert_eq!(
+            trie.entry_points,
+            ["new", "insert", "search", "starts_with"]
+        );
+        assert!(trie.is_design());
+    }
+
+    #[test]
+    fn design_class_named_solution() {
+        let shuffle = by_id(384).unwrap();
+        assert_eq!(shuffle.type_name, "Solution");
+        assert!(shuffle.is_design());
+    }
+
+    #[test]
+    fn filters_by_normalized_complexity() {
+        assert!(with_time("O(n log n)").any(|p| p.id == 56));
+        assert!(with_space("O( 1 )").all(|p| p.complexity.space_is("O(1)")));
+        assert!(same_bound("O(m × n)", "O(m*n)"));
+        assert!(same_bound("O(n²)", "O(n^2)"));
+        assert!(!same_bound("O(n)", "O(n log n)"));
+    }
+
+    #[test]
+    fn finds_problems_by_entry_point() {
+        let ids: Vec<u32> = by_entry_point("two_sum").map(|p| p.id).collect();
+        assert_eq!(ids, [1, 167]);
+    }
+}
@@ -0,0 +1,426 @@
+//! Replays design problems from LeetCode's operation-sequence format: one
+//! array naming the constructor and each method call, one array with the
+//! arguments of each, and optionally the array of expected results.
+//!
+//! ```
+//! use leetcode::replay::{self, Script};
+//!
+//! let script: Script = r#"
+//!     ["MinStack","push","push","getMin","pop","getMin"]
+//!     [[],[-2],[-3],[],[],[]]
+//!     [null,null,null,-3,null,-2]
+//! "#
+//! .parse()
+//! .unwrap();
+//! assert_eq!(replay::check_problem(155, &script), Ok(vec![]));
+//! ```
+//!
+//! `build.rs` implements [`Design`] for the class of every design problem,
+//! dispatching LeetCode's camelCase method names to the snake_case methods of
+//! the Rust struct.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::literal::{self, ParseError, Value};
+use crate::registry;
+use crate::runner::{RunError, catch_panic};
+
+/// A design-problem class driven by method name.
+pub trait Design: Sized {
+    /// Class name as LeetCode spells it, e.g. `LRUCache`.
+    const CLASS: &'static str;
+    /// Methods [`Design::invoke`] dispatches, in snake_case.
+    const METHODS: &'static [&'static str];
+
+    /// Calls the constructor with the given arguments.
+    fn construct(args: Vec<Value>) -> Result<Self, RunError>;
+
+    /// Calls `method`, one of [`Design::METHODS`]; methods that return
+    /// nothing yield [`Value::Null`].
+    fn invoke(&mut self, method: &str, args: Vec<Value>) -> Result<Value, RunError>;
+}
+
+/// A parsed operation sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Script {
+    /// The class name followed by the methods to call, as LeetCode spells
+    /// them, e.g. `["LRUCache","put","get"]`.
+    pub operations: Vec<String>,
+    /// Arguments for each operation, e.g. `[[2],[1,1],[1]]`.
+    pub arguments: Vec<Vec<Value>>,
+    /// Expected result of each operation, `null` for the constructor.
+    pub expected: Option<Vec<Value>>,
+}
+
+impl FromStr for Script {
+    type Err = ReplayError;
+
+    /// Parses the two or three arrays in the order LeetCode lists them.
+    /// `Input` and `Output` heading lines, as pasted from a problem
+    /// statement, are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text: String = s
+            .lines()
+            .filter(|line| {
+                let word = line.trim().trim_end_matches(':');
+                word != "Input" && word != "Output"
+            })
+            .map(|line| format!("{line}\n"))
+            .collect();
+        let mut arrays = literal::parse_args(&text)
+            .map_err(ReplayError::Parse)?
+            .into_iter()
+            .map(|value| match value {
+                Value::Array(items) => Ok(items),
+                _ => Err(ReplayError::Malformed("expected arrays")),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+
+        let (Some(operations), Some(arguments)) = (arrays.next(), arrays.next()) else {
+            return Err(ReplayError::Malformed("expected operations and arguments"));
+        };
+        let expected = arrays.next();
+        if arrays.next().is_some() {
+            return Err(ReplayError::Malformed("expected at most three arrays"));
+        }
+
+        let operations = operations
+            .into_iter()
+            .map(|op| match op {
+                Value::Str(op) => Ok(op),
+                _ => Err(ReplayError::Malformed("operations must be strings")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let arguments = arguments
+            .into_iter()
+            .map(|args| match args {
+                Value::Array(args) => Ok(args),
+                _ => Err(ReplayError::Malformed("arguments must be arrays")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if operations.is_empty() {
+            return Err(ReplayError::Malformed("no constructor"));
+        }
+        if arguments.len() != operations.len()
+            || expected
+                .as_ref()
+                .is_some_and(|e| e.len() != operations.len())
+        {
+            return Err(ReplayError::Malformed("arrays differ in length"));
+        }
+        Ok(Script {
+            operations,
+            arguments,
+            expected,
+        })
+    }
+}
+
+/// Runs `script` against `D`, returning the result of every operation with
+/// `null` for the constructor.
+pub fn replay<D: Design>(script: &Script) -> Result<Vec<Value>, ReplayError> {
+    let class = script.operations.first().map(String::as_str);
+    if class != Some(D::CLASS) {
+        return Err(ReplayError::WrongClass {
+            expected: D::CLASS,
+            found: class.unwrap_or_default().to_string(),
+        });
+    }
+
+    let step_error = |step: usize| move |error| ReplayError::Step { step, error };
+    let args = script.arguments[0].clone();
+    let mut object = catch_panic(|| D::construct(args)).map_err(step_error(0))?;
+    let mut results = vec![Value::Null];
+    for (step, (operation, args)) in script
+        .operations
+        .iter()
+        .zip(&script.arguments)
+        .enumerate()
+        .skip(1)
+    {
+        let method = snake_case(operation);
+        if !D::METHODS.contains(&method.as_str()) {
+            return Err(ReplayError::UnknownMethod {
+                step,
+                name: operation.clone(),
+            });
+        }
+        let args = args.clone();
+        let result = catch_panic(|| object.invoke(&method, args)).map_err(step_error(step))?;
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// Replays `script` against `D` and compares with its expected results.
+pub fn check<D: Design>(script: &Script) -> Result<Vec<Mismatch>, ReplayError> {
+    mismatches(script, replay::<D>(script)?)
+}
+
+/// Runs `script` against the class of design problem `id`.
+pub fn replay_problem(id: u32, script: &Script) -> Result<Vec<Value>, ReplayError> {
+    registry::by_id(id).ok_or(ReplayError::UnknownProblem(id))?;
+    let replayer = replayer(id).ok_or(ReplayError::NotReplayable(id))?;
+    replayer(script)
+}
+
+/// Replays `script` against design problem `id` and compares with its
+/// expected results.
+pub fn check_problem(id: u32, script: &Script) -> Result<Vec<Mismatch>, ReplayError> {
+    mismatches(script, replay_problem(id, script)?)
+}
+
+/// The design problem whose class is `class`, when exactly one is.
+pub fn design_id(class: &str) -> Option<u32> {
+    let mut ids = registry::all()
+        .iter()
+        .filter(|p| p.type_name == class && replayer(p.id).is_some())
+        .map(|p| p.id);
+    match (ids.next(), ids.next()) {
+        (Some(id), None) => Some(id),
+        _ => None,
+    }
+}
+
+/// An operation whose result differs from the expected one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    pub step: usize,
+    pub operation: String,
+    pub expected: Value,
+    pub actual: Value,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "step {} `{}`: expected {}, actual {}",
+            self.step, self.operation, self.expected, self.actual
+        )
+    }
+}
+
+fn mismatches(script: &Script, actual: Vec<Value>) -> Result<Vec<Mismatch>, ReplayError> {
+    let expected = script
+        .expected
+        .as_ref()
+        .ok_or(ReplayError::Malformed("no expected results"))?;
+    Ok(actual
+        .into_iter()
+        .zip(expected)
+        .enumerate()
+        .filter(|(_, (actual, expected))| !actual.matches(expected))
+        .map(|(step, (actual, expected))| Mismatch {
+            step,
+            operation: script.operations[step].clone(),
+            expected: expected.clone(),
+            actual,
+        })
+        .collect())
+}
+
+/// Signature of the generated per-problem replay functions.
+type Replayer = fn(&Script) -> Result<Vec<Value>, ReplayError>;
+
+include!(concat!(env!("OUT_DIR"), "/designs.rs"));
+
+/// Maps LeetCode's `getMin` to the Rust method name `get_min`.
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if !out.is_empty() {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Error returned when a script cannot be replayed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayError {
+    /// The text is not valid literal syntax.
+    Parse(ParseError),
+    /// The arrays do not form an operation sequence.
+    Malformed(&'static str),
+    /// No problem with this id is in the crate.
+    UnknownProblem(u32),
+    /// The problem is not a design problem, or its constructor takes
+    /// arguments literals cannot express.
+    NotReplayable(u32),
+    /// The first operation does not name the class.
+    WrongClass {
+        expected: &'static str,
+        found: String,
+    },
+    /// The class has no such method.
+    UnknownMethod { step: usize, name: String },
+    /// An operation failed to run.
+    Step { step: usize, error: RunError },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Parse(error) => write!(f, "invalid script: {error}"),
+            ReplayError::Malformed(message) => write!(f, "malformed script: {message}"),
+            ReplayError::UnknownProblem(id) => write!(f, "no problem {id}"),
+            ReplayError::NotReplayable(id) => write!(f, "problem {id} cannot be replayed"),
+            ReplayError::WrongClass { expected, found } => {
+                write!(f, "expected constructor `{expected}`, found `{found}`")
+            }
+            ReplayError::UnknownMethod { step, name } => {
+                write!(f, "step {step}: no method `{name}`")
+            }
+            ReplayError::Step { step, error } => write!(f, "step {step}: {error}"),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(s: &str) -> Script {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_pasted_statement() {
+        let parsed = script(
+            "Input\n\
+             [\"LRUCache\", \"put\", \"get\"]\n\
+             [[2], [1, 1], [1]]\n\
+             Output\n\
+             [null, null, 1]",
+        );
+        assert_eq!(parsed.operations, ["LRUCache", "put", "get"]);
+        assert_eq!(parsed.arguments[1], [Value::Int(1), Value::Int(1)]);
+        assert_eq!(parsed.expected.unwrap()[2], Value::Int(1));
+    }
+
+    #[test]
+    fn rejects_malformed_scripts() {
+        let err = |s: &str| s.parse::<Script>().unwrap_err();
+        assert_eq!(
+            err(r#"["A"]"#),
+            ReplayError::Malformed("expected operations and arguments")
+        );
+        assert_eq!(
+            err(r#"["A","b"], [[]]"#),
+            ReplayError::Malformed("arrays differ in length")
+        );
+        assert_eq!(
+            err(r#"[1], [[]]"#),
+            ReplayError::Malformed("operations must be strings")
+        );
+        assert!(matches!(err(r#"["A"], [["#), ReplayError::Parse(_)));
+    }
+
+    #[test]
+    fn replays_lru_cache() {
+        let lru = script(
+            r#"["LRUCache","put","put","get","put","get","put","get","get","get"]
+               [[2],[1,1],[2,2],[1],[3,3],[2],[4,4],[1],[3],[4]]
+               [null,null,null,1,null,-1,null,-1,3,4]"#,
+        );
+        assert_eq!(check_problem(146, &lru), Ok(vec![]));
+    }
+
+    #[test]
+    fn replays_median_finder_and_trie() {
+        let median = script(
+            r#"["MedianFinder","addNum","addNum","findMedian","addNum","findMedian"]
+               [[],[1],[2],[],[3],[]]
+               [null,null,null,1.5,null,2.0]"#,
+        );
+        assert_eq!(check_problem(295, &median), Ok(vec![]));
+
+        let trie = script(
+            r#"["Trie","insert","search","search","startsWith","insert","search"]
+               [[],["apple"],["apple"],["app"],["app"],["app"],["app"]]
+               [null,null,true,false,true,null,true]"#,
+        );
+        assert_eq!(check_problem(208, &trie), Ok(vec![]));
+    }
+
+    #[test]
+    fn replays_bank() {
+        let bank = script(
+            r#"["Bank","withdraw","transfer","deposit","transfer","withdraw"]
+               [[[10,100,20,50,30]],[3,10],[5,1,20],[5,20],[3,4,15],[10,50]]
+               [null,true,true,true,false,false]"#,
+        );
+        assert_eq!(check_problem(2043, &bank), Ok(vec![]));
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        let wrong = script(
+            r#"["MinStack","push","top"]
+               [[],[5],[]]
+               [null,null,4]"#,
+        );
+        assert_eq!(
+            check_problem(155, &wrong),
+            Ok(vec![Mismatch {
+                step: 2,
+                operation: "top".to_string(),
+                expected: Value::Int(4),
+                actual: Value::Int(5),
+            }])
+        );
+    }
+
+    #[test]
+    fn reports_bad_operations() {
+        let replay = |s: &str| replay_problem(155, &script(s));
+        assert!(matches!(
+            replay(r#"["MaxStack"], [[]]"#),
+            Err(ReplayError::WrongClass {
+                expected: "MinStack",
+                ..
+            })
+        ));
+        assert_eq!(
+            replay(r#"["MinStack","peek"], [[],[]]"#),
+            Err(ReplayError::UnknownMethod {
+                step: 1,
+                name: "peek".to_string()
+            })
+        );
+        assert_eq!(
+            replay(r#"["MinStack","push"], [[],[]]"#),
+            Err(ReplayError::Step {
+                step: 1,
+                error: RunError::Arity {
+                    expected: 1,
+                    found: 0
+                }
+            })
+        );
+        assert_eq!(
+            replay_problem(1, &script(r#"["Solution"], [[]]"#)),
+            Err(ReplayError::NotReplayable(1))
+        );
+    }
+
+    #[test]
+    fn finds_problems_by_class() {
+        assert_eq!(design_id("LRUCache"), Some(146));
+        assert_eq!(design_id("Bank"), Some(2043));
+        assert_eq!(design_id("Solution"), None);
+        assert_eq!(snake_case("getMin"), "get_min");
+        assert_eq!(snake_case("enQueue"), "en_queue");
+    }
+}
@@ -0,0 +1,530 @@
+//! A tree rooted at a chosen node, built from an undirected edge list.
+//!
+//! [`RootedTree`] records parents, depths and subtree sizes, answers
+//! lowest-common-ancestor and `k`-th ancestor queries by binary lifting, and
+//! lays the nodes out in a heavy-first preorder. In that order every subtree
+//! is one contiguous range and every root-to-node path splits into
+//! O(log n) contiguous ranges, so a [`SegmentTree`] indexed by
+//! [`RootedTree::position`] answers both subtree and path queries.
+//!
+//! ```
+//! use leetcode::rooted_tree::RootedTree;
+//!
+//! //     0
+//! //    / \
+//! //   1   2
+//! //  / \
+//! // 3   4
+//! let tree = RootedTree::new(5, [(0, 1), (0, 2), (1, 3), (1, 4)], 0);
+//! assert_eq!(tree.lca(3, 4), 1);
+//! assert_eq!(tree.lca(4, 2), 0);
+//! assert_eq!(tree.distance(3, 2), 3);
+//! assert_eq!(tree.kth_ancestor(4, 2), Some(0));
+//! assert_eq!(tree.subtree(1).len(), 3);
+//! ```
+//!
+//! Path aggregates over a commutative monoid come from a segment tree laid
+//! out by position:
+//!
+//! ```
+//! use leetcode::rooted_tree::RootedTree;
+//! use leetcode::segment_tree::{SegmentTree, Sum};
+//!
+//! let tree = RootedTree::new(5, [(0, 1), (0, 2), (1, 3), (1, 4)], 0);
+//! let mut values: SegmentTree<Sum<i64>> = SegmentTree::new(5);
+//! for v in 0..5 {
+//!     values.set(tree.position(v), Sum(10 * v as i64));
+//! }
+//! assert_eq!(tree.path_query(&mut values, 3, 2), Sum(30 + 10 + 0 + 20));
+//! ```
+//!
+//! [`RootedTree::reroot`] evaluates a subtree DP with every node as the
+//! root in O(n) total; here, each node's eccentricity:
+//!
+//! ```
+//! use leetcode::rooted_tree::RootedTree;
+//! use leetcode::segment_tree::Max;
+//!
+//! let tree = RootedTree::new(5, [(0, 1), (0, 2), (1, 3), (1, 4)], 0);
+//! let height = tree.reroot(|&h: &i32, _, _| Max(h + 1), |m, _| m.0.max(0));
+//! assert_eq!(height, [2, 2, 3, 3, 3]);
+//! ```
+
+use std::mem;
+use std::ops::Range;
+
+use crate::segment_tree::{LazyAction, Monoid, SegmentTree};
+
+/// A rooted tree with binary lifting and a heavy-light layout.
+#[derive(Clone, Debug)]
+pub struct RootedTree {
+    root: usize,
+    /// The root is its own parent.
+    parent: Vec<usize>,
+    /// Index into the edge list of the edge to the parent; unused for the
+    /// root.
+    parent_edge: Vec<usize>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    /// Heavy child first.
+    children: Vec<Vec<usize>>,
+    /// Nodes in heavy-first preorder.
+    order: Vec<usize>,
+    /// Inverse of `order`.
+    position: Vec<usize>,
+    /// Topmost node of the heavy path through each node.
+    head: Vec<usize>,
+    /// `up[k][v]` is the `2^k`-th ancestor of `v`, saturating at the root.
+    up: Vec<Vec<usize>>,
+}
+
+impl RootedTree {
+    /// Roots the tree on nodes `0..n` with the given `n - 1` edges at `root`.
+    /// Edges are numbered in the order given, for [`Self::parent_edge`].
+    ///
+    /// # Panics
+    /// If the edges do not form a tree spanning all `n` nodes.
+    pub fn new(n: usize, edges: impl IntoIterator<Item = (usize, usize)>, root: usize) -> Self {
+        let mut adj = vec![Vec::new(); n];
+        for (i, (u, v)) in edges.into_iter().enumerate() {
+            adj[u].push((v, i));
+            adj[v].push((u, i));
+        }
+
+        let mut parent = vec![usize::MAX; n];
+        let mut parent_edge = vec![usize::MAX; n];
+        let mut depth = vec![0; n];
+        let mut children = vec![Vec::new(); n];
+        let mut bfs = Vec::with_capacity(n);
+        parent[root] = root;
+        bfs.push(root);
+        let mut i = 0;
+        while i < bfs.len() {
+            let u = bfs[i];
+            i += 1;
+            for &(v, e) in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    parent_edge[v] = e;
+                    depth[v] = depth[u] + 1;
+                    children[u].push(v);
+                    bfs.push(v);
+                }
+            }
+        }
+        assert_eq!(bfs.len(), n, "the edges do not connect all {n} nodes");
+
+        let mut size = vec![1; n];
+        for &v in bfs.iter().rev() {
+            if v != root {
+                size[parent[v]] += size[v];
+            }
+            let kids = &mut children[v];
+            if let Some(heavy) = (0..kids.len()).max_by_key(|&j| size[kids[j]]) {
+                kids.swap(0, heavy);
+            }
+        }
+
+        let mut order = Vec::with_capacity(n);
+        let mut position = vec![0; n];
+        let mut head = vec![root; n];
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            position[u] = order.len();
+            order.push(u);
+            for (j, &v) in children[u].iter().enumerate().rev() {
+                head[v] = if j == 0 { head[u] } else { v };
+                stack.push(v);
+            }
+        }
+
+        let levels = (usize::BITS - n.saturating_sub(1).leading_zeros()).max(1) as usize;
+        let mut up = Vec::with_capacity(levels);
+        up.push(parent.clone());
+        for k in 1..levels {
+            let prev: &Vec<usize> = &up[k - 1];
+            let next = prev.iter().map(|&p| prev[p]).collect();
+            up.push(next);
+        }
+
+        Self {
+            root,
+            parent,
+            parent_edge,
+            depth,
+            size,
+            children,
+            order,
+            position,
+            head,
+            up,
+        }
+    }
+
+    /// Number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.order.len()
+    }
+
+    /// The root.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// The parent of `v`, or `None` for the root.
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        (v != self.root).then_some(self.parent[v])
+    }
+
+    /// Index of the edge from `v` to its parent, or `None` for the root.
+    pub fn parent_edge(&self, v: usize) -> Option<usize> {
+        (v != self.root).then_some(self.parent_edge[v])
+    }
+
+    /// Number of edges between `v` and the root.
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// Number of nodes in the subtree of `v`.
+    pub fn subtree_size(&self, v: usize) -> usize {
+        self.size[v]
+    }
+
+    /// The children of `v`, heaviest first.
+    pub fn children(&self, v: usize) -> &[usize] {
+        &self.children[v]
+    }
+
+    /// All nodes with every parent before its children; iterate in reverse
+    /// to visit children first.
+    pub fn preorder(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Index of `v` in [`Self::preorder`], its Euler-tour entry time.
+    pub fn position(&self, v: usize) -> usize {
+        self.position[v]
+    }
+
+    /// The positions of the subtree of `v`; its end is the exit time.
+    pub fn subtree(&self, v: usize) -> Range<usize> {
+        self.position[v]..self.position[v] + self.size[v]
+    }
+
+    /// Whether `a` lies on the path from `v` to the root, `v` included.
+    pub fn is_ancestor(&self, a: usize, v: usize) -> bool {
+        self.subtree(a).contains(&self.position[v])
+    }
+
+    /// The ancestor `k` edges above `v`, or `None` past the root.
+    pub fn kth_ancestor(&self, mut v: usize, k: usize) -> Option<usize> {
+        if k > self.depth[v] {
+            return None;
+        }
+        for (level, up) in self.up.iter().enumerate() {
+            if k >> level & 1 == 1 {
+                v = up[v];
+            }
+        }
+        Some(v)
+    }
+
+    /// The deepest common ancestor of `u` and `v`.
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        if self.depth[u] < self.depth[v] {
+            mem::swap(&mut u, &mut v);
+        }
+        u = self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        if u == v {
+            return u;
+        }
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.parent[u]
+    }
+
+    /// Number of edges between `u` and `v`.
+    pub fn distance(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+
+    /// The positions of the nodes on the path between `u` and `v`, as
+    /// O(log n) disjoint ranges in no particular order.
+    pub fn path_ranges(&self, u: usize, v: usize) -> Vec<Range<usize>> {
+        self.decompose(u, v, false)
+    }
+
+    /// Like [`Self::path_ranges`] without the lowest common ancestor, so that
+    /// a value stored at each child stands for the edge to its parent.
+    pub fn edge_path_ranges(&self, u: usize, v: usize) -> Vec<Range<usize>> {
+        self.decompose(u, v, true)
+    }
+
+    fn decompose(&self, mut u: usize, mut v: usize, skip_lca: bool) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                mem::swap(&mut u, &mut v);
+            }
+            let head = self.head[u];
+            ranges.push(self.position[head]..self.position[u] + 1);
+            u = self.parent[head];
+        }
+        if self.depth[u] > self.depth[v] {
+            mem::swap(&mut u, &mut v);
+        }
+        let start = self.position[u] + usize::from(skip_lca);
+        if start <= self.position[v] {
+            ranges.push(start..self.position[v] + 1);
+        }
+        ranges
+    }
+
+    /// Aggregate of the nodes on the path between `u` and `v` in a segment
+    /// tree indexed by [`Self::position`]. The ranges are combined out of
+    /// path order, so `M` should be commutative.
+    pub fn path_query<M: Monoid, A: LazyAction<M>>(
+        &self,
+        values: &mut SegmentTree<M, A>,
+        u: usize,
+        v: usize,
+    ) -> M {
+        self.path_ranges(u, v)
+            .into_iter()
+            .fold(M::identity(), |acc, range| {
+                acc.combine(&values.query(range))
+            })
+    }
+
+    /// Applies `action` to the nodes on the path between `u` and `v` in a
+    /// segment tree indexed by [`Self::position`].
+    pub fn path_apply<M: Monoid, A: LazyAction<M>>(
+        &self,
+        values: &mut SegmentTree<M, A>,
+        u: usize,
+        v: usize,
+        action: &A,
+    ) {
+        for range in self.path_ranges(u, v) {
+            values.apply(range, action);
+        }
+    }
+
+    /// Evaluates a subtree DP as if each node in turn were the root.
+    ///
+    /// The value of a node is `finish(m, v)`, where `m` combines
+    /// `lift(value, w, v)` over the neighbours `w` of `v`, `value` being the
+    /// value of the part of the tree that hangs off `v` through `w`. The
+    /// combine must be commutative, since neighbours arrive in no particular
+    /// order.
+    ///
+    /// # Complexity
+    /// O(n) calls to each closure and O(n) combines.
+    pub fn reroot<M: Monoid, T>(
+        &self,
+        lift: impl Fn(&T, usize, usize) -> M,
+        finish: impl Fn(&M, usize) -> T,
+    ) -> Vec<T> {
+        let n = self.node_count();
+        let mut down: Vec<Option<T>> = (0..n).map(|_| None).collect();
+        for &v in self.order.iter().rev() {
+            let acc = self.children[v].iter().fold(M::identity(), |acc, &c| {
+                acc.combine(&lift(down[c].as_ref().unwrap(), c, v))
+            });
+            down[v] = Some(finish(&acc, v));
+        }
+
+        let mut from_parent: Vec<M> = vec![M::identity(); n];
+        let mut full: Vec<Option<T>> = (0..n).map(|_| None).collect();
+        for &v in &self.order {
+            let lifted: Vec<M> = self.children[v]
+                .iter()
+                .map(|&c| lift(down[c].as_ref().unwrap(), c, v))
+                .collect();
+            let mut suffix = vec![M::identity(); lifted.len() + 1];
+            for j in (0..lifted.len()).rev() {
+                suffix[j] = lifted[j].combine(&suffix[j + 1]);
+            }
+            let mut prefix = from_parent[v].clone();
+            for (j, &c) in self.children[v].iter().enumerate() {
+                let without_c = finish(&prefix.combine(&suffix[j + 1]), v);
+                from_parent[c] = lift(&without_c, v, c);
+                prefix = prefix.combine(&lifted[j]);
+            }
+            full[v] = Some(finish(&prefix, v));
+        }
+        full.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+    use crate::segment_tree::{Add, Max, Sum};
+
+    /// A random tree on `n` shuffled labels, with its root.
+    fn random_tree(rng: &mut SeededRng, n: usize) -> (Vec<(usize, usize)>, usize) {
+        let mut labels: Vec<usize> = (0..n).collect();
+        rng.shuffle(&mut labels);
+        let edges = (1..n)
+            .map(|i| (labels[rng.in_range(0..i)], labels[i]))
+            .collect();
+        (edges, rng.in_range(0..n))
+    }
+
+    /// The path from `v` up to the root.
+    fn naive_ancestors(tree: &RootedTree, mut v: usize) -> Vec<usize> {
+        let mut path = vec![v];
+        while let Some(p) = tree.parent(v) {
+            path.push(p);
+            v = p;
+        }
+        path
+    }
+
+    fn naive_path(tree: &RootedTree, u: usize, v: usize) -> Vec<usize> {
+        let (up, vp) = (naive_ancestors(tree, u), naive_ancestors(tree, v));
+        let lca = *up.iter().find(|a| vp.contains(a)).unwrap();
+        let mut path: Vec<usize> = up.into_iter().take_while(|&a| a != lca).collect();
+        path.extend(vp.into_iter().take_while(|&a| a != lca));
+        path.push(lca);
+        path.sort_unstable();
+        path
+    }
+
+    #[test]
+    fn structure_matches_naive() {
+        let mut rng = SeededRng::new(15);
+        for n in 1..40 {
+            let (edges, root) = random_tree(&mut rng, n);
+            let tree = RootedTree::new(n, edges.iter().copied(), root);
+            assert_eq!(tree.parent(root), None);
+            for v in 0..n {
+                let ancestors = naive_ancestors(&tree, v);
+                assert_eq!(tree.depth(v), ancestors.len() - 1);
+                for (k, &a) in ancestors.iter().enumerate() {
+                    assert_eq!(tree.kth_ancestor(v, k), Some(a));
+                }
+                assert_eq!(tree.kth_ancestor(v, ancestors.len()), None);
+                if let Some(e) = tree.parent_edge(v) {
+                    let (a, b) = edges[e];
+                    assert!((a, b) == (v, ancestors[1]) || (b, a) == (v, ancestors[1]));
+                }
+                let in_subtree = (0..n)
+                    .filter(|&w| naive_ancestors(&tree, w).contains(&v))
+                    .count();
+                assert_eq!(tree.subtree_size(v), in_subtree);
+                for w in 0..n {
+                    assert_eq!(
+                        tree.is_ancestor(v, w),
+                        naive_ancestors(&tree, w).contains(&v)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lca_and_paths_match_naive() {
+        let mut rng = SeededRng::new(16);
+        for n in 1..40 {
+            let (edges, root) = random_tree(&mut rng, n);
+            let tree = RootedTree::new(n, edges, root);
+            for _ in 0..50 {
+                let (u, v) = (rng.in_range(0..n), rng.in_range(0..n));
+                let path = naive_path(&tree, u, v);
+                let lca = *path.iter().min_by_key(|&&w| tree.depth(w)).unwrap();
+                assert_eq!(tree.lca(u, v), lca);
+                assert_eq!(tree.distance(u, v), path.len() - 1);
+
+                let mut covered: Vec<usize> = tree
+                    .path_ranges(u, v)
+                    .into_iter()
+                    .flatten()
+                    .map(|p| tree.preorder()[p])
+                    .collect();
+                covered.sort_unstable();
+                assert_eq!(covered, path);
+                let edge_count = tree.edge_path_ranges(u, v).iter().map(Range::len).sum();
+                assert_eq!(path.len() - 1, edge_count);
+            }
+        }
+    }
+
+    #[test]
+    fn heavy_paths_are_logarithmic() {
+        // A path with a leaf hanging off every node has one long heavy path.
+        let n = 1000;
+        let edges = (1..n / 2)
+            .map(|i| (i - 1, i))
+            .chain((0..n / 2).map(|i| (i, n / 2 + i)));
+        let tree = RootedTree::new(n, edges, 0);
+        for v in 0..n {
+            assert!(tree.path_ranges(0, v).len() <= 2);
+        }
+    }
+
+    #[test]
+    fn path_updates_through_segment_tree() {
+        let mut rng = SeededRng::new(17);
+        let n = 60;
+        let (edges, root) = random_tree(&mut rng, n);
+        let tree = RootedTree::new(n, edges, root);
+        let mut naive = vec![0_i64; n];
+        let mut values: SegmentTree<Max<i64>, Add<i64>> = vec![Max(0); n].into_iter().collect();
+        let mut sums: SegmentTree<Sum<i64>> = SegmentTree::new(n);
+        for _ in 0..200 {
+            let (u, v) = (rng.in_range(0..n), rng.in_range(0..n));
+            let path = naive_path(&tree, u, v);
+            let delta = rng.in_range(-5..=5);
+            tree.path_apply(&mut values, u, v, &Add(delta));
+            for &w in &path {
+                naive[w] += delta;
+                sums.set(tree.position(w), Sum(naive[w]));
+            }
+            let (a, b) = (rng.in_range(0..n), rng.in_range(0..n));
+            let path = naive_path(&tree, a, b);
+            let expected = path.iter().map(|&w| naive[w]).max().unwrap();
+            assert_eq!(tree.path_query(&mut values, a, b), Max(expected));
+            let expected = path.iter().map(|&w| naive[w]).sum();
+            assert_eq!(tree.path_query(&mut sums, a, b), Sum(expected));
+        }
+    }
+
+    /// Subtree size and the sum of distances to the subtree's root.
+    #[derive(Clone, Debug)]
+    struct Spread(i64, i64);
+
+    impl Monoid for Spread {
+        fn identity() -> Self {
+            Spread(0, 0)
+        }
+        fn combine(&self, right: &Self) -> Self {
+            Spread(self.0 + right.0, self.1 + right.1)
+        }
+    }
+
+    #[test]
+    fn reroot_matches_every_root() {
+        let mut rng = SeededRng::new(18);
+        for n in 1..25 {
+            let (edges, root) = random_tree(&mut rng, n);
+            let tree = RootedTree::new(n, edges.iter().copied(), root);
+            let spreads = tree.reroot(
+                |s: &Spread, _, _| Spread(s.0, s.1 + s.0),
+                |m, _| Spread(m.0 + 1, m.1),
+            );
+            for (v, spread) in spreads.into_iter().enumerate() {
+                let rerooted = RootedTree::new(n, edges.iter().copied(), v);
+                let expected: usize = (0..n).map(|w| rerooted.depth(w)).sum();
+                assert_eq!((spread.0, spread.1), (n as i64, expected as i64));
+            }
+        }
+    }
+}
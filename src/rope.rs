@@ -0,0 +1,590 @@
+//! A rope: text stored as a balanced tree of short UTF-8 chunks, so that
+//! inserting, deleting and seeking by character or line take O(log n) time
+//! however long the text grows.
+//!
+//! Positions are `char` indices (Unicode scalar values). Lines are separated
+//! by `'\n'`, so a rope with `k` newlines has `k + 1` lines.
+//!
+//! ```
+//! use leetcode::rope::Rope;
+//!
+//! let mut rope = Rope::from("hello\nworld");
+//! rope.insert(5, ", rope");
+//! rope.remove(0..1);
+//! rope.insert(0, "H");
+//! assert_eq!(rope.to_string(), "Hello, rope\nworld");
+//! assert_eq!(rope.len_lines(), 2);
+//! assert_eq!(rope.line_to_char(1), 12);
+//! assert_eq!(rope.char_to_line(14), 1);
+//! assert_eq!(rope.slice(7..11), "rope");
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+/// Largest chunk, in bytes, that inserts build or append into.
+const MAX_CHUNK: usize = 1024;
+
+/// Sizes of a piece of text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Metrics {
+    bytes: usize,
+    chars: usize,
+    newlines: usize,
+}
+
+impl Metrics {
+    fn of(text: &str) -> Self {
+        Self {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            newlines: text.bytes().filter(|&b| b == b'\n').count(),
+        }
+    }
+
+    fn plus(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+type Link = Option<Box<Node>>;
+
+/// A treap node: in-order chunks spell the text, and priorities keep the
+/// tree balanced in expectation.
+#[derive(Clone)]
+struct Node {
+    chunk: String,
+    own: Metrics,
+    /// Totals over the whole subtree.
+    total: Metrics,
+    priority: u64,
+    left: Link,
+    right: Link,
+}
+
+fn total(link: &Link) -> Metrics {
+    link.as_ref().map_or(Metrics::default(), |node| node.total)
+}
+
+impl Node {
+    fn new(chunk: String, priority: u64) -> Box<Self> {
+        let own = Metrics::of(&chunk);
+        Box::new(Self {
+            chunk,
+            own,
+            total: own,
+            priority,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update(&mut self) {
+        self.total = total(&self.left).plus(self.own).plus(total(&self.right));
+    }
+}
+
+fn merge(a: Link, b: Link) -> Link {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                a.update();
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                b.update();
+                Some(b)
+            }
+        }
+    }
+}
+
+/// Byte offset of the `n`-th char of `text`.
+fn byte_of_char(text: &str, n: usize) -> usize {
+    text.char_indices().nth(n).map_or(text.len(), |(i, _)| i)
+}
+
+/// A UTF-8 string with O(log n) edits and seeks.
+#[derive(Clone, Default)]
+pub struct Rope {
+    root: Link,
+    /// State of the xorshift generator that draws node priorities.
+    seed: u64,
+}
+
+impl Rope {
+    /// An empty rope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn priority(&mut self) -> u64 {
+        // xorshift64*, seeded lazily so `Default` stays derivable
+        if self.seed == 0 {
+            self.seed = 0x9E37_79B9_7F4A_7C15;
+        }
+        self.seed ^= self.seed >> 12;
+        self.seed ^= self.seed << 25;
+        self.seed ^= self.seed >> 27;
+        self.seed.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Length in bytes.
+    pub fn len_bytes(&self) -> usize {
+        total(&self.root).bytes
+    }
+
+    /// Length in chars.
+    pub fn len_chars(&self) -> usize {
+        total(&self.root).chars
+    }
+
+    /// Number of lines: one more than the number of `'\n'`.
+    pub fn len_lines(&self) -> usize {
+        total(&self.root).newlines + 1
+    }
+
+    /// Whether the rope holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Splits `link` into the first `at` chars and the rest, cutting a chunk
+    /// in two if `at` falls inside it.
+    fn split(&mut self, link: Link, at: usize) -> (Link, Link) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+        let left = total(&node.left).chars;
+        if at <= left {
+            let (a, b) = self.split(node.left.take(), at);
+            node.left = b;
+            node.update();
+            (a, Some(node))
+        } else if at >= left + node.own.chars {
+            let (a, b) = self.split(node.right.take(), at - left - node.own.chars);
+            node.right = a;
+            node.update();
+            (Some(node), b)
+        } else {
+            let byte = byte_of_char(&node.chunk, at - left);
+            let tail = Node::new(node.chunk.split_off(byte), self.priority());
+            node.own = Metrics::of(&node.chunk);
+            let right = merge(Some(tail), node.right.take());
+            node.update();
+            (Some(node), right)
+        }
+    }
+
+    /// Appends `text` to the last chunk of `link` if it fits there.
+    fn append_in_place(link: &mut Link, text: &str) -> bool {
+        let Some(node) = link else {
+            return false;
+        };
+        let appended = if node.right.is_some() {
+            Self::append_in_place(&mut node.right, text)
+        } else if node.chunk.len() + text.len() <= MAX_CHUNK {
+            node.chunk.push_str(text);
+            node.own = node.own.plus(Metrics::of(text));
+            true
+        } else {
+            false
+        };
+        if appended {
+            node.update();
+        }
+        appended
+    }
+
+    /// Inserts `text` so that it starts at char `at`.
+    ///
+    /// # Panics
+    /// Panics if `at` is past the end.
+    pub fn insert(&mut self, at: usize, text: &str) {
+        assert!(
+            at <= self.len_chars(),
+            "insert position {at} is out of bounds"
+        );
+        if text.is_empty() {
+            return;
+        }
+        let root = self.root.take();
+        let (mut left, right) = self.split(root, at);
+        if !Self::append_in_place(&mut left, text) {
+            let mut rest = text;
+            while !rest.is_empty() {
+                let mut cut = rest.len().min(MAX_CHUNK);
+                while !rest.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                let (chunk, tail) = rest.split_at(cut);
+                left = merge(left, Some(Node::new(chunk.to_string(), self.priority())));
+                rest = tail;
+            }
+        }
+        self.root = merge(left, right);
+    }
+
+    /// Removes the chars in `range`.
+    ///
+    /// # Panics
+    /// Panics if the range is reversed or runs past the end.
+    pub fn remove(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len_chars(),
+            "remove range {range:?} is out of bounds"
+        );
+        let root = self.root.take();
+        let (rest, right) = self.split(root, range.end);
+        let (left, _) = self.split(rest, range.start);
+        self.root = merge(left, right);
+    }
+
+    /// The chars in `range` as a string.
+    pub fn slice(&self, range: Range<usize>) -> String {
+        let mut text = String::new();
+        let mut wanted = range.end.saturating_sub(range.start);
+        for chunk in self.chunks_from(range.start) {
+            if wanted == 0 {
+                break;
+            }
+            let end = byte_of_char(chunk, wanted);
+            text.push_str(&chunk[..end]);
+            wanted -= chunk[..end].chars().count();
+        }
+        text
+    }
+
+    /// The char at index `at`.
+    pub fn char(&self, at: usize) -> Option<char> {
+        self.chars_from(at).next()
+    }
+
+    /// Byte offset of char `at`.
+    pub fn char_to_byte(&self, mut at: usize) -> usize {
+        let mut bytes = 0;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = total(&node.left);
+            if at < left.chars {
+                link = &node.left;
+            } else if at < left.chars + node.own.chars {
+                return bytes + left.bytes + byte_of_char(&node.chunk, at - left.chars);
+            } else {
+                at -= left.chars + node.own.chars;
+                bytes += left.bytes + node.own.bytes;
+                link = &node.right;
+            }
+        }
+        bytes
+    }
+
+    /// Index of the char starting at byte `at`, or of the char containing it.
+    pub fn byte_to_char(&self, mut at: usize) -> usize {
+        let mut chars = 0;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = total(&node.left);
+            if at < left.bytes {
+                link = &node.left;
+            } else if at < left.bytes + node.own.bytes {
+                let inside = at - left.bytes;
+                let started = node.chunk.char_indices().take_while(|&(i, _)| i <= inside);
+                return chars + left.chars + started.count() - 1;
+            } else {
+                at -= left.bytes + node.own.bytes;
+                chars += left.chars + node.own.chars;
+                link = &node.right;
+            }
+        }
+        chars
+    }
+
+    /// Line containing char `at`, counting from zero; the end of the text is
+    /// on the last line.
+    pub fn char_to_line(&self, mut at: usize) -> usize {
+        let mut line = 0;
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = total(&node.left);
+            if at < left.chars {
+                link = &node.left;
+            } else if at < left.chars + node.own.chars {
+                let inside = at - left.chars;
+                let newlines = node
+                    .chunk
+                    .chars()
+                    .take(inside)
+                    .filter(|&c| c == '\n')
+                    .count();
+                return line + left.newlines + newlines;
+            } else {
+                at -= left.chars + node.own.chars;
+                line += left.newlines + node.own.newlines;
+                link = &node.right;
+            }
+        }
+        line
+    }
+
+    /// Char index where `line` starts.
+    ///
+    /// # Panics
+    /// Panics if `line` is not below [`Rope::len_lines`].
+    pub fn line_to_char(&self, mut line: usize) -> usize {
+        assert!(line < self.len_lines(), "line {line} is out of bounds");
+        let mut chars = 0;
+        let mut link = &self.root;
+        while line > 0 {
+            let node = link.as_ref().unwrap();
+            let left = total(&node.left);
+            if line <= left.newlines {
+                link = &node.left;
+            } else if line <= left.newlines + node.own.newlines {
+                let skip = line - left.newlines;
+                let newline = node
+                    .chunk
+                    .chars()
+                    .enumerate()
+                    .filter(|&(_, c)| c == '\n')
+                    .nth(skip - 1)
+                    .unwrap()
+                    .0;
+                return chars + left.chars + newline + 1;
+            } else {
+                line -= left.newlines + node.own.newlines;
+                chars += left.chars + node.own.chars;
+                link = &node.right;
+            }
+        }
+        chars
+    }
+
+    /// The chunks of text from char `at` to the end.
+    pub fn chunks_from(&self, mut at: usize) -> Chunks<'_> {
+        let mut chunks = Chunks {
+            first: None,
+            stack: Vec::new(),
+            forward: true,
+        };
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = total(&node.left).chars;
+            if at < left {
+                chunks.stack.push(node);
+                link = &node.left;
+            } else if at < left + node.own.chars {
+                chunks.first = Some(&node.chunk[byte_of_char(&node.chunk, at - left)..]);
+                chunks.descend(&node.right);
+                break;
+            } else {
+                at -= left + node.own.chars;
+                link = &node.right;
+            }
+        }
+        chunks
+    }
+
+    /// The chunks of text before char `at`, nearest first.
+    pub fn chunks_before(&self, mut at: usize) -> Chunks<'_> {
+        let mut chunks = Chunks {
+            first: None,
+            stack: Vec::new(),
+            forward: false,
+        };
+        let mut link = &self.root;
+        while let Some(node) = link {
+            let left = total(&node.left).chars;
+            if at <= left {
+                link = &node.left;
+            } else if at <= left + node.own.chars {
+                chunks.first = Some(&node.chunk[..byte_of_char(&node.chunk, at - left)]);
+                chunks.descend(&node.left);
+                break;
+            } else {
+                chunks.stack.push(node);
+                at -= left + node.own.chars;
+                link = &node.right;
+            }
+        }
+        chunks
+    }
+
+    /// Every chunk in order.
+    pub fn chunks(&self) -> Chunks<'_> {
+        self.chunks_from(0)
+    }
+
+    /// The chars from index `at` onwards.
+    pub fn chars_from(&self, at: usize) -> impl Iterator<Item = char> + '_ {
+        self.chunks_from(at).flat_map(str::chars)
+    }
+
+    /// The chars before index `at`, nearest first.
+    pub fn chars_before(&self, at: usize) -> impl Iterator<Item = char> + '_ {
+        self.chunks_before(at).flat_map(|chunk| chunk.chars().rev())
+    }
+}
+
+/// Iterator over the chunks of a [`Rope`], forwards or backwards.
+pub struct Chunks<'a> {
+    /// The partial chunk the iteration starts in.
+    first: Option<&'a str>,
+    /// Nodes whose chunk, and the subtree beyond it, are still to come.
+    stack: Vec<&'a Node>,
+    forward: bool,
+}
+
+impl<'a> Chunks<'a> {
+    /// Pushes the path to the next chunk of `link` in iteration order.
+    fn descend(&mut self, mut link: &'a Link) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = if self.forward {
+                &node.left
+            } else {
+                &node.right
+            };
+        }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+        let node = self.stack.pop()?;
+        self.descend(if self.forward {
+            &node.right
+        } else {
+            &node.left
+        });
+        Some(&node.chunk)
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        let mut rope = Self::new();
+        rope.insert(0, text);
+        rope
+    }
+}
+
+impl PartialEq for Rope {
+    fn eq(&self, other: &Self) -> bool {
+        self.len_bytes() == other.len_bytes()
+            && self
+                .chunks()
+                .flat_map(str::bytes)
+                .eq(other.chunks().flat_map(str::bytes))
+    }
+}
+
+impl Eq for Rope {}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    fn random_text(rng: &mut SeededRng, max: usize) -> String {
+        const ALPHABET: [char; 6] = ['a', 'b', '\n', 'é', '✓', '🦀'];
+        let len = rng.in_range(0..max);
+        (0..len)
+            .map(|_| ALPHABET[rng.in_range(0..ALPHABET.len())])
+            .collect()
+    }
+
+    #[test]
+    fn matches_char_vector() {
+        let mut rng = SeededRng::new(2296);
+        let mut rope = Rope::new();
+        let mut model: Vec<char> = Vec::new();
+        for step in 0..3000 {
+            let len = model.len();
+            if rng.chance(0.6) {
+                let at = rng.in_range(0..=len);
+                let text = random_text(&mut rng, if step % 50 == 0 { 3000 } else { 12 });
+                rope.insert(at, &text);
+                model.splice(at..at, text.chars());
+            } else {
+                let start = rng.in_range(0..=len);
+                let end = rng.in_range(start..=len.min(start + 40));
+                rope.remove(start..end);
+                model.drain(start..end);
+            }
+            let text: String = model.iter().collect();
+            assert_eq!(rope.len_chars(), model.len());
+            assert_eq!(rope.len_bytes(), text.len());
+            assert_eq!(rope.len_lines(), text.split('\n').count());
+            let at = rng.in_range(0..=model.len());
+            assert_eq!(rope.char(at), model.get(at).copied());
+            let byte: usize = model[..at].iter().map(|c| c.len_utf8()).sum();
+            assert_eq!(rope.char_to_byte(at), byte);
+            assert_eq!(rope.byte_to_char(byte), at);
+            if let Some(c) = model.get(at).filter(|c| c.len_utf8() > 1) {
+                assert_eq!(rope.byte_to_char(byte + c.len_utf8() - 1), at);
+            }
+            let line = model[..at].iter().filter(|&&c| c == '\n').count();
+            assert_eq!(rope.char_to_line(at), line);
+            let line_start = model[..at]
+                .iter()
+                .rposition(|&c| c == '\n')
+                .map_or(0, |i| i + 1);
+            assert_eq!(rope.line_to_char(line), line_start);
+            let end = rng.in_range(at..=model.len());
+            assert_eq!(
+                rope.slice(at..end),
+                model[at..end].iter().collect::<String>()
+            );
+            assert!(rope.chars_before(at).eq(model[..at].iter().rev().copied()));
+            if step % 100 == 0 {
+                assert_eq!(rope.to_string(), text);
+                assert_eq!(rope, Rope::from(text.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn stays_shallow_when_typed_one_char_at_a_time() {
+        fn depth(link: &Link) -> usize {
+            link.as_ref()
+                .map_or(0, |node| 1 + depth(&node.left).max(depth(&node.right)))
+        }
+        let mut rope = Rope::new();
+        for i in 0..20_000 {
+            // type at the end, then keep inserting at a moving cursor
+            let at = if i < 10_000 {
+                rope.len_chars()
+            } else {
+                i % 7_919
+            };
+            rope.insert(at, "x");
+        }
+        assert_eq!(rope.len_chars(), 20_000);
+        assert!(depth(&rope.root) < 60, "depth {}", depth(&rope.root));
+    }
+}
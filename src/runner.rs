@@ -0,0 +1,354 @@
+//! Runs examples written in LeetCode's literal syntax against `Solution`
+//! entry points.
+//!
+//! Every `Solution` function whose arguments and result are expressible as
+//! literals (see [`crate::literal`]) is registered by `build.rs`. In-place
+//! functions that mutate a single `&mut` argument report that argument as
+//! their output, as LeetCode does.
+//!
+//! ```
+//! use leetcode::runner::{self, Case};
+//!
+//! let cases = Case::parse_all(
+//!     "Input: nums = [2,7,11,15], target = 9\n\
+//!      Output: [0,1]",
+//! );
+//! let outcomes = runner::check(1, None, &cases).unwrap();
+//! assert!(outcomes.iter().all(|o| o.passed()));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::literal::{self, ConvertError, FromValue, IntoValue, ParseError, Value};
+use crate::registry;
+
+/// A function callable with parsed arguments, implemented for every `Fn`
+/// whose parameters are [`FromValue`] and whose result is [`IntoValue`].
+///
+/// `Args` is the tuple of parameter types; it only keeps the impls for
+/// different arities apart.
+pub trait Entry<Args> {
+    fn call(&self, args: Vec<Value>) -> Result<Value, RunError>;
+}
+
+macro_rules! impl_entry {
+    ($($arg:ident),*) => {
+        impl<F, R, $($arg),*> Entry<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> R,
+            R: IntoValue,
+            $($arg: FromValue,)*
+        {
+            #[allow(unused_mut, unused_variables)]
+            fn call(&self, args: Vec<Value>) -> Result<Value, RunError> {
+                let arity = <[&str]>::len(&[$(stringify!($arg)),*]);
+                let mut args = Args::new(args, arity)?;
+                Ok(self($(args.take::<$arg>()?),*).into_value())
+            }
+        }
+    };
+}
+
+impl_entry!();
+impl_entry!(A);
+impl_entry!(A, B);
+impl_entry!(A, B, C);
+impl_entry!(A, B, C, D);
+impl_entry!(A, B, C, D, E);
+impl_entry!(A, B, C, D, E, G);
+impl_entry!(A, B, C, D, E, G, H);
+impl_entry!(A, B, C, D, E, G, H, I);
+
+/// Arguments checked against an arity and converted one by one, in order.
+pub struct Args {
+    values: std::vec::IntoIter<Value>,
+    index: usize,
+}
+
+impl Args {
+    /// Fails with [`RunError::Arity`] unless there are exactly `arity` values.
+    pub fn new(values: Vec<Value>, arity: usize) -> Result<Self, RunError> {
+        if values.len() != arity {
+            return Err(RunError::Arity {
+                expected: arity,
+                found: values.len(),
+            });
+        }
+        Ok(Args {
+            values: values.into_iter(),
+            index: 0,
+        })
+    }
+
+    /// Converts the next argument.
+    ///
+    /// # Panics
+    /// Panics when called more than `arity` times.
+    pub fn take<T: FromValue>(&mut self) -> Result<T, RunError> {
+        let index = self.index;
+        self.index += 1;
+        let value = self.values.next().expect("more arguments than the arity");
+        T::from_value(value).map_err(|error| RunError::Argument { index, error })
+    }
+}
+
+/// A type-erased [`Entry`].
+pub type Adapter = Box<dyn Fn(Vec<Value>) -> Result<Value, RunError>>;
+
+/// Erases the argument types of `f`.
+pub fn adapt<Args, F: Entry<Args> + 'static>(f: F) -> Adapter {
+    Box::new(move |args| f.call(args))
+}
+
+include!(concat!(env!("OUT_DIR"), "/adapters.rs"));
+
+/// The adapter for entry point `name` of problem `id`, or its first entry
+/// point when `name` is `None`.
+pub fn entry(id: u32, name: Option<&str>) -> Result<Adapter, RunError> {
+    let problem = registry::by_id(id).ok_or(RunError::UnknownProblem(id))?;
+    let name = match name {
+        Some(name) => name,
+        None => problem.entry_points.first().copied().unwrap_or_default(),
+    };
+    adapter(id, name).ok_or_else(|| RunError::Unsupported {
+        id,
+        name: name.to_string(),
+    })
+}
+
+/// One example: the arguments and the expected output, both as literals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case {
+    pub input: String,
+    pub expected: String,
+}
+
+impl Case {
+    /// Collects the `Input:`/`Output:` pairs of text pasted from problem
+    /// statements, ignoring everything else such as `Explanation:` lines.
+    pub fn parse_all(text: &str) -> Vec<Case> {
+        let mut cases = Vec::new();
+        let mut input = None;
+        for line in text.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("Input:") {
+                input = Some(rest.trim().to_string());
+            } else if let Some(rest) = line.strip_prefix("Output:")
+                && let Some(input) = input.take()
+            {
+                cases.push(Case {
+                    input,
+                    expected: rest.trim().to_string(),
+                });
+            }
+        }
+        cases
+    }
+}
+
+/// The result of running one [`Case`].
+#[derive(Debug)]
+pub struct Outcome {
+    pub case: Case,
+    pub actual: Result<Value, RunError>,
+}
+
+impl Outcome {
+    /// Whether the entry point returned the expected output.
+    pub fn passed(&self) -> bool {
+        match (&self.actual, self.case.expected.parse::<Value>()) {
+            (Ok(actual), Ok(expected)) => actual.matches(&expected),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.passed() { "ok" } else { "FAILED" };
+        writeln!(f, "{verdict}: {}", self.case.input)?;
+        if !self.passed() {
+            writeln!(f, "  expected: {}", self.case.expected)?;
+            match &self.actual {
+                Ok(actual) => writeln!(f, "  actual:   {actual}")?,
+                Err(error) => writeln!(f, "  error:    {error}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs every case against entry point `name` of problem `id` (its first
+/// entry point when `None`). A panicking solution fails only its own case.
+pub fn check(id: u32, name: Option<&str>, cases: &[Case]) -> Result<Vec<Outcome>, RunError> {
+    let adapter = entry(id, name)?;
+    Ok(cases
+        .iter()
+        .map(|case| Outcome {
+            case: case.clone(),
+            actual: run(&adapter, &case.input),
+        })
+        .collect())
+}
+
+fn run(adapter: &Adapter, input: &str) -> Result<Value, RunError> {
+    let args = literal::parse_args(input).map_err(RunError::Parse)?;
+    catch_panic(|| adapter(args))
+}
+
+/// Runs `f`, turning a panic into [`RunError::Panic`].
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> Result<T, RunError>) -> Result<T, RunError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(RunError::Panic(message))
+    })
+}
+
+/// Error returned when a case cannot be run.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RunError {
+    /// No problem with this id is in the crate.
+    UnknownProblem(u32),
+    /// The entry point does not exist or its signature has no literal form.
+    Unsupported { id: u32, name: String },
+    /// The input is not valid literal syntax.
+    Parse(ParseError),
+    /// The input has the wrong number of arguments.
+    Arity { expected: usize, found: usize },
+    /// An argument does not fit its parameter type.
+    Argument { index: usize, error: ConvertError },
+    /// The solution panicked.
+    Panic(String),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::UnknownProblem(id) => write!(f, "no problem {id}"),
+            RunError::Unsupported { id, name } => {
+                write!(f, "problem {id} has no runnable entry point `{name}`")
+            }
+            RunError::Parse(error) => write!(f, "invalid input: {error}"),
+            RunError::Arity { expected, found } => {
+                write!(f, "expected {expected} arguments, found {found}")
+            }
+            RunError::Argument { index, error } => write!(f, "argument {}: {error}", index + 1),
+            RunError::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(input: &str, expected: &str) -> Case {
+        Case {
+            input: input.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_pasted_examples() {
+        let text = "Example 1:\n\
+                    Input: s = \"abc\", k = 2\n\
+                    Output: true\n\
+                    Explanation: anything\n\
+                    Output: ignored without a preceding input";
+        assert_eq!(Case::parse_all(text), [case("s = \"abc\", k = 2", "true")]);
+    }
+
+    #[test]
+    fn checks_scalar_and_array_results() {
+        let outcomes = check(
+            1,
+            Some("two_sum"),
+            &[
+                case("nums = [3,2,4], target = 6", "[1,2]"),
+                case("[3,3]\n6", "[0,0]"),
+            ],
+        )
+        .unwrap();
+        assert!(outcomes[0].passed());
+        assert!(!outcomes[1].passed());
+        assert!(outcomes[1].to_string().contains("actual:   [0,1]"));
+    }
+
+    #[test]
+    fn checks_trees_and_lists() {
+        let tree = check(
+            226,
+            None,
+            &[case("root = [4,2,7,1,3,6,9]", "[4,7,2,9,6,3,1]")],
+        );
+        assert!(tree.unwrap()[0].passed());
+        let list = check(206, None, &[case("head = [1,2,3,4,5]", "[5,4,3,2,1]")]);
+        assert!(list.unwrap()[0].passed());
+    }
+
+    #[test]
+    fn in_place_functions_report_the_mutated_argument() {
+        let outcomes = check(
+            88,
+            None,
+            &[case(
+                "nums1 = [1,2,3,0,0,0], m = 3, nums2 = [2,5,6], n = 3",
+                "[1,2,2,3,5,6]",
+            )],
+        )
+        .unwrap();
+        assert!(outcomes[0].passed());
+    }
+
+    #[test]
+    fn float_results_use_tolerance() {
+        let outcomes = check(4, None, &[case("nums1 = [1,2], nums2 = [3,4]", "2.50000")]).unwrap();
+        assert!(outcomes[0].passed());
+    }
+
+    #[test]
+    fn reports_bad_input() {
+        let outcomes = check(
+            1,
+            None,
+            &[
+                case("[1,2]", "[0,1]"),
+                case("[1,2], \"x\"", "[0,1]"),
+                case("[1,", "[]"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            outcomes[0].actual,
+            Err(RunError::Arity {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            outcomes[1].actual,
+            Err(RunError::Argument { index: 1, .. })
+        ));
+        assert!(matches!(outcomes[2].actual, Err(RunError::Parse(_))));
+    }
+
+    #[test]
+    fn rejects_unknown_entry_points() {
+        assert_eq!(
+            check(0, None, &[]).unwrap_err(),
+            RunError::UnknownProblem(0)
+        );
+        assert!(matches!(
+            check(146, None, &[]).unwrap_err(),
+            RunError::Unsupported { id: 146, .. }
+        ));
+    }
+}
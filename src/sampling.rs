@@ -0,0 +1,672 @@
+//! Weighted random sampling, with and without replacement.
+//!
+//! Three [`WeightedSampler`] backends trade construction, update and draw
+//! costs differently:
+//!
+//! | backend            | build | draw     | update weight |
+//! |--------------------|-------|----------|---------------|
+//! | [`PrefixSampler`]  | O(n)  | O(log n) | rebuild       |
+//! | [`AliasTable`]     | O(n)  | O(1)     | rebuild       |
+//! | [`FenwickSampler`] | O(n)  | O(log n) | O(log n)      |
+//!
+//! Weights are non-negative finite floats; integer weights convert exactly up
+//! to 2^53. An item of weight zero is never drawn.
+//!
+//! For sampling without replacement, [`reservoir`] picks `k` items uniformly
+//! from a stream of unknown length, [`weighted_reservoir`] does the same with
+//! weights (Efraimidis–Spirakis), and
+//! [`FenwickSampler::sample_distinct`] draws distinct indices from a table.
+//!
+//! ```
+//! use leetcode::random::SeededRng;
+//! use leetcode::sampling::{AliasTable, FenwickSampler, WeightedSampler};
+//!
+//! let mut rng = SeededRng::new(1);
+//! let table = AliasTable::new(&[0.5, 0.0, 2.5]).unwrap();
+//! assert_ne!(table.sample(&mut rng), 1);
+//!
+//! let mut live = FenwickSampler::new(&[1.0, 1.0, 1.0]).unwrap();
+//! live.set_weight(0, 0.0).unwrap();
+//! live.set_weight(2, 0.0).unwrap();
+//! assert_eq!(live.sample(&mut rng), 1);
+//! assert!(AliasTable::new(&[1.0, -1.0]).is_err());
+//! ```
+
+use crate::fenwick::Fenwick;
+use crate::random::RandomSource;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+
+/// Why a set of weights was rejected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeightError {
+    /// The weight at `index` is below zero.
+    Negative { index: usize, weight: f64 },
+    /// The weight at `index` is infinite or NaN.
+    NotFinite { index: usize, weight: f64 },
+    /// No weight is positive, so there is nothing to draw.
+    ZeroTotal,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::Negative { index, weight } => {
+                write!(f, "weight {weight} at index {index} is negative")
+            }
+            WeightError::NotFinite { index, weight } => {
+                write!(f, "weight {weight} at index {index} is not finite")
+            }
+            WeightError::ZeroTotal => write!(f, "no weight is positive"),
+        }
+    }
+}
+
+impl Error for WeightError {}
+
+fn check_weight(index: usize, weight: f64) -> Result<(), WeightError> {
+    if !weight.is_finite() {
+        Err(WeightError::NotFinite { index, weight })
+    } else if weight < 0.0 {
+        Err(WeightError::Negative { index, weight })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates `weights`, returning their total.
+fn check_weights(weights: &[f64]) -> Result<f64, WeightError> {
+    for (index, &weight) in weights.iter().enumerate() {
+        check_weight(index, weight)?;
+    }
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        Ok(total)
+    } else {
+        Err(WeightError::ZeroTotal)
+    }
+}
+
+/// A uniform point in `[0, total)`, redrawn in the rare case rounding lands
+/// it on `total`.
+///
+/// # Panics
+/// Panics if `total` is not positive, as no point lies below it.
+fn point_below(total: f64, rng: &mut dyn RandomSource) -> f64 {
+    assert!(total > 0.0, "cannot draw below a total of {total}");
+    loop {
+        let point = rng.unit_f64() * total;
+        if point < total {
+            return point;
+        }
+    }
+}
+
+/// Draws indices with probability proportional to their weights.
+pub trait WeightedSampler {
+    /// Number of items, including those of weight zero.
+    fn len(&self) -> usize;
+
+    /// Whether there are no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The weight of item `index`.
+    fn weight(&self, index: usize) -> f64;
+
+    /// The sum of all weights.
+    fn total(&self) -> f64;
+
+    /// An index drawn with probability `weight(index) / total()`.
+    ///
+    /// # Panics
+    /// Panics if the total weight is zero.
+    fn sample(&self, rng: &mut dyn RandomSource) -> usize;
+}
+
+/// Cumulative weights searched by bisection.
+///
+/// # Intuition
+/// Laying the weights end to end splits `[0, total)` into one interval per
+/// item, as long as its weight; a uniform point falls in each with the right
+/// probability, and the running sums are sorted, so bisection finds it.
+///
+/// # Complexity
+/// - Time: O(n) to build, O(log n) per draw
+/// - Space: O(n)
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrefixSampler {
+    /// `prefix[i]` is the sum of the first `i + 1` weights.
+    prefix: Vec<f64>,
+}
+
+impl PrefixSampler {
+    /// A sampler over `weights`.
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        check_weights(weights)?;
+        let prefix = weights
+            .iter()
+            .scan(0.0, |sum, &w| {
+                *sum += w;
+                Some(*sum)
+            })
+            .collect();
+        Ok(Self { prefix })
+    }
+
+    /// The item whose interval contains `point`: the first whose running sum
+    /// exceeds it, or the last item when `point` is past the total.
+    pub fn locate(&self, point: f64) -> usize {
+        self.prefix
+            .partition_point(|&sum| sum <= point)
+            .min(self.prefix.len() - 1)
+    }
+}
+
+impl WeightedSampler for PrefixSampler {
+    fn len(&self) -> usize {
+        self.prefix.len()
+    }
+
+    fn weight(&self, index: usize) -> f64 {
+        self.prefix[index] - index.checked_sub(1).map_or(0.0, |i| self.prefix[i])
+    }
+
+    fn total(&self) -> f64 {
+        *self.prefix.last().unwrap()
+    }
+
+    fn sample(&self, rng: &mut dyn RandomSource) -> usize {
+        self.locate(point_below(self.total(), rng))
+    }
+}
+
+/// Walker's alias method, built with Vose's linear-time algorithm.
+///
+/// # Intuition
+/// Scale the weights so they average one, and picture `n` columns of height
+/// one. Every item shorter than one can be topped up from a single item
+/// taller than one without that donor dropping below zero, so each column
+/// holds at most two items. A draw picks a column uniformly and then one of
+/// its two items with a biased coin.
+///
+/// # Approach
+/// 1. Scale weight `i` to `p_i = w_i · n / total` and sort the items into
+///    those below one and the rest.
+/// 2. Repeatedly pair a small item `s` with a large item `l`: column `s`
+///    keeps `s` with probability `p_s` and otherwise yields `l`, and `l`
+///    loses `1 - p_s`, moving to whichever list it now belongs in.
+/// 3. Whatever remains is one up to rounding and fills its column alone.
+///
+/// # Complexity
+/// - Time: O(n) to build, O(1) per draw
+/// - Space: O(n)
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasTable {
+    /// Chance that column `i` yields `i` rather than `alias[i]`.
+    keep: Vec<f64>,
+    alias: Vec<usize>,
+    weights: Vec<f64>,
+    total: f64,
+}
+
+impl AliasTable {
+    /// A table over `weights`.
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        let total = check_weights(weights)?;
+        let n = weights.len();
+        let mut keep: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| keep[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            keep[l] -= 1.0 - keep[s];
+            if keep[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        for i in small.into_iter().chain(large) {
+            keep[i] = 1.0;
+        }
+        Ok(Self {
+            keep,
+            alias,
+            weights: weights.to_vec(),
+            total,
+        })
+    }
+}
+
+impl WeightedSampler for AliasTable {
+    fn len(&self) -> usize {
+        self.keep.len()
+    }
+
+    fn weight(&self, index: usize) -> f64 {
+        self.weights[index]
+    }
+
+    fn total(&self) -> f64 {
+        self.total
+    }
+
+    fn sample(&self, rng: &mut dyn RandomSource) -> usize {
+        let column = rng.index(self.keep.len());
+        if rng.unit_f64() < self.keep[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+}
+
+/// Weights in a Fenwick tree, so single weights change in O(log n).
+///
+/// # Intuition
+/// The prefix sampler's running sums are exactly what a Fenwick tree keeps
+/// implicitly, and a Fenwick tree both updates a weight and finds the item
+/// whose running sum first passes a point in O(log n).
+///
+/// # Approach
+/// 1. Keep the exact weights alongside the tree, and rebuild the tree from
+///    them after every `n` updates so float rounding cannot pile up. Also
+///    rebuild when the total falls far below its peak since the last
+///    rebuild: removing a huge weight cancels, and rounding error of the
+///    order of the peak would swamp the small weights left behind.
+/// 2. Draw a uniform point below the total and descend the tree to the first
+///    item whose running sum exceeds it.
+///
+/// # Complexity
+/// - Time: O(n) to build, O(log n) per draw and amortized per update
+/// - Space: O(n)
+#[derive(Clone, Debug)]
+pub struct FenwickSampler {
+    tree: Fenwick<f64>,
+    weights: Vec<f64>,
+    /// Number of positive weights.
+    positive: usize,
+    /// Updates since the tree was last rebuilt.
+    updates: usize,
+    /// Largest total since the tree was last rebuilt, which bounds the
+    /// rounding error its sums carry.
+    peak: f64,
+}
+
+/// Fraction of the peak total below which the tree is rebuilt; the relative
+/// error left in the total is then about `f64::EPSILON · log n / DRIFT`.
+const DRIFT: f64 = 1e-6;
+
+impl FenwickSampler {
+    /// A sampler over `weights`, which may all be zero until some are set.
+    pub fn new(weights: &[f64]) -> Result<Self, WeightError> {
+        for (index, &weight) in weights.iter().enumerate() {
+            check_weight(index, weight)?;
+        }
+        let mut sampler = Self {
+            tree: Fenwick::new(0),
+            weights: weights.to_vec(),
+            positive: weights.iter().filter(|&&w| w > 0.0).count(),
+            updates: 0,
+            peak: 0.0,
+        };
+        sampler.rebuild();
+        Ok(sampler)
+    }
+
+    fn rebuild(&mut self) {
+        self.tree = Fenwick::from(self.weights.clone());
+        self.updates = 0;
+        self.peak = self.total();
+    }
+
+    /// Adds `delta` to the tree at `index`, rebuilding it when rounding may
+    /// have drifted too far.
+    fn add(&mut self, index: usize, delta: f64) {
+        self.tree.add(index, delta);
+        let total = self.total();
+        if self.updates >= self.weights.len() || total < self.peak * DRIFT {
+            self.rebuild();
+        } else {
+            self.peak = self.peak.max(total);
+        }
+    }
+
+    /// Replaces the weight of item `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_weight(&mut self, index: usize, weight: f64) -> Result<(), WeightError> {
+        check_weight(index, weight)?;
+        let old = std::mem::replace(&mut self.weights[index], weight);
+        self.positive = self.positive + usize::from(weight > 0.0) - usize::from(old > 0.0);
+        self.updates += 1;
+        self.add(index, weight - old);
+        Ok(())
+    }
+
+    /// Draws up to `k` distinct indices, each draw proportional to the
+    /// weights of the items not yet drawn. Returns fewer when fewer than `k`
+    /// items have positive weight.
+    pub fn sample_distinct(&mut self, k: usize, rng: &mut dyn RandomSource) -> Vec<usize> {
+        // drawn items get weight zero until every draw is done
+        let mut drawn = Vec::with_capacity(k.min(self.positive));
+        while drawn.len() < k && self.positive > 0 {
+            let index = self.sample(rng);
+            let weight = std::mem::take(&mut self.weights[index]);
+            self.positive -= 1;
+            self.add(index, -weight);
+            drawn.push((index, weight));
+        }
+        drawn
+            .into_iter()
+            .map(|(index, weight)| {
+                self.weights[index] = weight;
+                self.positive += 1;
+                self.add(index, weight);
+                index
+            })
+            .collect()
+    }
+}
+
+impl WeightedSampler for FenwickSampler {
+    fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    fn weight(&self, index: usize) -> f64 {
+        self.weights[index]
+    }
+
+    fn total(&self) -> f64 {
+        self.tree.prefix(self.weights.len())
+    }
+
+    fn sample(&self, rng: &mut dyn RandomSource) -> usize {
+        assert!(self.positive > 0, "cannot sample when every weight is zero");
+        let total = self.total();
+        // the first item whose running sum reaches just past the point,
+        // redrawn should rounding in the tree land on a zero weight
+        loop {
+            let point = point_below(total, rng).next_up();
+            let index = self.tree.kth(point).min(self.weights.len() - 1);
+            if self.weights[index] > 0.0 {
+                return index;
+            }
+        }
+    }
+}
+
+/// `k` items chosen uniformly from `items`, a stream of any length, in one
+/// pass (Algorithm R). Returns every item when there are at most `k`.
+pub fn reservoir<T>(
+    items: impl IntoIterator<Item = T>,
+    k: usize,
+    rng: &mut dyn RandomSource,
+) -> Vec<T> {
+    let mut kept = Vec::with_capacity(k);
+    for (seen, item) in items.into_iter().enumerate() {
+        if seen < k {
+            kept.push(item);
+        } else {
+            let slot = rng.index(seen + 1);
+            if slot < k {
+                kept[slot] = item;
+            }
+        }
+    }
+    kept
+}
+
+/// An item with its Efraimidis–Spirakis key, ordered so the heap's top is
+/// the smallest key.
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
+
+/// `k` distinct items from a stream of `(item, weight)` pairs, with the same
+/// distribution as drawing one at a time in proportion to weight and removing
+/// each as it is drawn (Efraimidis–Spirakis A-Res).
+///
+/// Each item gets the key `u^(1/w)` for a uniform `u`, and the `k` largest
+/// keys win; keys are compared as `ln(u) / w` to avoid underflow. Items of
+/// weight zero are never chosen.
+///
+/// # Errors
+/// Returns an error on a negative or non-finite weight.
+pub fn weighted_reservoir<T>(
+    items: impl IntoIterator<Item = (T, f64)>,
+    k: usize,
+    rng: &mut dyn RandomSource,
+) -> Result<Vec<T>, WeightError> {
+    let mut heap: BinaryHeap<Keyed<T>> = BinaryHeap::with_capacity(k + 1);
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    for (index, (item, weight)) in items.into_iter().enumerate() {
+        check_weight(index, weight)?;
+        if weight == 0.0 {
+            continue;
+        }
+        // 1 - unit lies in (0, 1], so the logarithm is finite
+        let key = (1.0 - rng.unit_f64()).ln() / weight;
+        if heap.len() < k {
+            heap.push(Keyed { key, item });
+        } else if heap.peek().is_some_and(|smallest| key > smallest.key) {
+            heap.pop();
+            heap.push(Keyed { key, item });
+        }
+    }
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|keyed| keyed.item)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{SeededRng, chi_square, chi_square_critical};
+
+    const WEIGHTS: [f64; 6] = [1.0, 5.0, 0.0, 2.5, 0.5, 11.0];
+
+    fn assert_proportional(sampler: &dyn WeightedSampler, weights: &[f64], rng: &mut SeededRng) {
+        let mut counts = vec![0u64; weights.len()];
+        for _ in 0..60_000 {
+            counts[sampler.sample(rng)] += 1;
+        }
+        let total: f64 = weights.iter().sum();
+        // zero-weight items never come up and are left out of the test
+        let (observed, expected): (Vec<u64>, Vec<f64>) = counts
+            .iter()
+            .zip(weights)
+            .filter(|&(&count, &w)| {
+                assert!(w > 0.0 || count == 0);
+                w > 0.0
+            })
+            .map(|(&count, &w)| (count, w / total))
+            .unzip();
+        let statistic = chi_square(&observed, &expected);
+        assert!(
+            statistic < chi_square_critical(observed.len() - 1),
+            "{statistic}"
+        );
+    }
+
+    #[test]
+    fn every_backend_draws_in_proportion() {
+        let mut rng = SeededRng::new(24);
+        let prefix = PrefixSampler::new(&WEIGHTS).unwrap();
+        let alias = AliasTable::new(&WEIGHTS).unwrap();
+        let fenwick = FenwickSampler::new(&WEIGHTS).unwrap();
+        let backends: [&dyn WeightedSampler; 3] = [&prefix, &alias, &fenwick];
+        for sampler in backends {
+            assert_eq!(sampler.len(), WEIGHTS.len());
+            assert_eq!(sampler.total(), 20.0);
+            assert!((0..WEIGHTS.len()).all(|i| sampler.weight(i) == WEIGHTS[i]));
+            assert_proportional(sampler, &WEIGHTS, &mut rng);
+        }
+        // a single item, and a skewed table with tiny weights
+        let tiny = [1e-9, 1.0, 3e-9, 0.0, 1e-9];
+        let single = AliasTable::new(&[2.0]).unwrap();
+        assert!((0..100).all(|_| single.sample(&mut rng) == 0));
+        assert_proportional(&AliasTable::new(&tiny).unwrap(), &tiny, &mut rng);
+    }
+
+    #[test]
+    fn fenwick_updates_change_the_distribution() {
+        let mut rng = SeededRng::new(528);
+        let mut weights = vec![0.0; 40];
+        let mut sampler = FenwickSampler::new(&weights).unwrap();
+        for step in 0..500 {
+            let i = rng.index(40);
+            let w = if step % 3 == 0 {
+                0.0
+            } else {
+                rng.unit_f64() * 10.0
+            };
+            sampler.set_weight(i, w).unwrap();
+            weights[i] = w;
+        }
+        let total: f64 = weights.iter().sum();
+        assert!((sampler.total() - total).abs() < 1e-9);
+        assert_proportional(&sampler, &weights, &mut rng);
+        // rejected weights leave the sampler unchanged
+        assert!(matches!(
+            sampler.set_weight(3, f64::NAN),
+            Err(WeightError::NotFinite { index: 3, .. })
+        ));
+        assert!(sampler.set_weight(3, -1.0).is_err());
+        assert_eq!(sampler.weight(3), weights[3]);
+    }
+
+    #[test]
+    fn removing_a_huge_weight_keeps_the_small_ones() {
+        let mut rng = SeededRng::new(20);
+        // 1e20 + 1 rounds to 1e20, so subtracting 1e20 again would leave a
+        // total of zero without a rebuild
+        let mut sampler = FenwickSampler::new(&[1e20, 1.0]).unwrap();
+        sampler.set_weight(0, 0.0).unwrap();
+        assert_eq!(sampler.total(), 1.0);
+        assert_eq!(sampler.sample(&mut rng), 1);
+
+        let weights = [3e18, 1.0, 2e-3, 7e17, 4.0, 5.0];
+        let mut sampler = FenwickSampler::new(&weights).unwrap();
+        let mut drawn = sampler.sample_distinct(weights.len(), &mut rng);
+        drawn.sort_unstable();
+        assert_eq!(drawn, [0, 1, 2, 3, 4, 5]);
+        sampler.set_weight(0, 0.0).unwrap();
+        sampler.set_weight(3, 0.0).unwrap();
+        let small = [0.0, 1.0, 2e-3, 0.0, 4.0, 5.0];
+        assert!((sampler.total() - 10.002).abs() < 1e-12);
+        assert_proportional(&sampler, &small, &mut rng);
+    }
+
+    #[test]
+    fn rejects_bad_weights() {
+        assert_eq!(
+            AliasTable::new(&[1.0, -2.0]),
+            Err(WeightError::Negative {
+                index: 1,
+                weight: -2.0
+            })
+        );
+        assert!(matches!(
+            PrefixSampler::new(&[f64::INFINITY]),
+            Err(WeightError::NotFinite { index: 0, .. })
+        ));
+        assert_eq!(PrefixSampler::new(&[]), Err(WeightError::ZeroTotal));
+        assert_eq!(AliasTable::new(&[0.0, 0.0]), Err(WeightError::ZeroTotal));
+        assert!(FenwickSampler::new(&[0.0, 0.0]).is_ok());
+        let mut rng = SeededRng::new(0);
+        assert!(weighted_reservoir([("a", 1.0), ("b", -1.0)], 1, &mut rng).is_err());
+    }
+
+    /// Probability of each unordered pair when drawing two items one at a
+    /// time in proportion to weight, without replacement.
+    fn pair_probabilities(weights: &[f64]) -> Vec<((usize, usize), f64)> {
+        let total: f64 = weights.iter().sum();
+        let mut pairs = Vec::new();
+        for i in 0..weights.len() {
+            for j in i + 1..weights.len() {
+                let p = weights[i] / total * weights[j] / (total - weights[i])
+                    + weights[j] / total * weights[i] / (total - weights[j]);
+                pairs.push(((i, j), p));
+            }
+        }
+        pairs
+    }
+
+    fn assert_pairs(weights: &[f64], mut draw: impl FnMut() -> Vec<usize>) {
+        let pairs = pair_probabilities(weights);
+        let mut counts = vec![0u64; pairs.len()];
+        for _ in 0..30_000 {
+            let mut drawn = draw();
+            drawn.sort_unstable();
+            assert_eq!(drawn.len(), 2);
+            let slot = pairs
+                .iter()
+                .position(|&((i, j), _)| [i, j] == drawn[..])
+                .unwrap();
+            counts[slot] += 1;
+        }
+        let expected: Vec<f64> = pairs.iter().map(|&(_, p)| p).collect();
+        let statistic = chi_square(&counts, &expected);
+        assert!(
+            statistic < chi_square_critical(pairs.len() - 1),
+            "{statistic}"
+        );
+    }
+
+    #[test]
+    fn sampling_without_replacement() {
+        let weights = [1.0, 2.0, 3.0, 6.0];
+        let mut rng = SeededRng::new(99);
+        assert_pairs(&weights, || {
+            let items = weights.iter().enumerate().map(|(i, &w)| (i, w));
+            weighted_reservoir(items, 2, &mut rng).unwrap()
+        });
+        let mut sampler = FenwickSampler::new(&weights).unwrap();
+        let mut rng = SeededRng::new(100);
+        assert_pairs(&weights, || sampler.sample_distinct(2, &mut rng));
+        assert_eq!(sampler.total(), 12.0);
+        // uniform reservoir: every pair of five equally likely
+        let mut rng = SeededRng::new(101);
+        assert_pairs(&[1.0; 5], || reservoir(0..5, 2, &mut rng));
+
+        let mut sparse = FenwickSampler::new(&[0.0, 4.0, 0.0, 1.0]).unwrap();
+        let mut drawn = sparse.sample_distinct(10, &mut rng);
+        drawn.sort_unstable();
+        assert_eq!(drawn, [1, 3]);
+        let items = [("x", 0.0), ("y", 2.0)];
+        assert_eq!(weighted_reservoir(items, 2, &mut rng).unwrap(), ["y"]);
+        assert_eq!(reservoir(0..3, 5, &mut rng), [0, 1, 2]);
+    }
+}
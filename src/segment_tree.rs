@@ -0,0 +1,552 @@
+//! Segment tree over any [`Monoid`], with optional lazy range updates through
+//! a [`LazyAction`].
+//!
+//! ```
+//! use leetcode::segment_tree::{Add, Max, SegmentTree};
+//!
+//! let mut tree: SegmentTree<Max<i32>, Add<i32>> =
+//!     [3, 1, 4, 1, 5].into_iter().map(Max).collect();
+//! assert_eq!(tree.query(1..4), Max(4));
+//! tree.apply(0..2, &Add(10));
+//! assert_eq!(tree.all(), Max(13));
+//! // First index from 2 where the running max reaches 5.
+//! assert_eq!(tree.max_right(2, |m| m.0 < 5), 4);
+//! ```
+//!
+//! Without an action the tree is a plain point-update, range-query tree:
+//!
+//! ```
+//! use leetcode::segment_tree::{SegmentTree, Sum};
+//!
+//! let mut tree: SegmentTree<Sum<i64>> = SegmentTree::new(4);
+//! tree.set(2, Sum(7));
+//! assert_eq!(tree.query(..=2), Sum(7));
+//! ```
+
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+/// An associative operation with an identity element.
+pub trait Monoid: Clone {
+    /// The value `x` for which `x.combine(y) == y.combine(x) == y`.
+    fn identity() -> Self;
+    /// Combines adjacent segments, `self` on the left.
+    fn combine(&self, right: &Self) -> Self;
+}
+
+/// An update applied to every element of a range, which must distribute over
+/// [`Monoid::combine`] so that it can be applied to whole segments at once.
+pub trait LazyAction<M: Monoid>: Clone {
+    /// The action that leaves every value unchanged.
+    fn identity() -> Self;
+    /// The aggregate of a segment after applying `self` to each element.
+    fn apply(&self, value: &M) -> M;
+    /// The action that applies `inner` first and then `self`.
+    fn compose(&self, inner: &Self) -> Self;
+}
+
+/// No lazy updates.
+impl<M: Monoid> LazyAction<M> for () {
+    fn identity() -> Self {}
+    fn apply(&self, value: &M) -> M {
+        value.clone()
+    }
+    fn compose(&self, _: &Self) -> Self {}
+}
+
+/// Maximum, with the type's minimum as identity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Max<T>(pub T);
+
+/// Minimum, with the type's maximum as identity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Min<T>(pub T);
+
+/// Sum, with zero as identity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sum<T>(pub T);
+
+/// Adds a constant to every element; acts on [`Max`] and [`Min`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Add<T>(pub T);
+
+macro_rules! impl_numeric {
+    ($($ty:ty),*) => {$(
+        impl Monoid for Max<$ty> {
+            fn identity() -> Self {
+                Max(<$ty>::MIN)
+            }
+            fn combine(&self, right: &Self) -> Self {
+                Max(self.0.max(right.0))
+            }
+        }
+
+        impl Monoid for Min<$ty> {
+            fn identity() -> Self {
+                Min(<$ty>::MAX)
+            }
+            fn combine(&self, right: &Self) -> Self {
+                Min(self.0.min(right.0))
+            }
+        }
+
+        impl Monoid for Sum<$ty> {
+            fn identity() -> Self {
+                Sum(0)
+            }
+            fn combine(&self, right: &Self) -> Self {
+                Sum(self.0 + right.0)
+            }
+        }
+
+        impl LazyAction<Max<$ty>> for Add<$ty> {
+            fn identity() -> Self {
+                Add(0)
+            }
+            /// Leaves the identity in place, so empty segments stay empty.
+            fn apply(&self, value: &Max<$ty>) -> Max<$ty> {
+                if *value == Max::<$ty>::identity() { *value } else { Max(value.0 + self.0) }
+            }
+            fn compose(&self, inner: &Self) -> Self {
+                Add(self.0 + inner.0)
+            }
+        }
+
+        impl LazyAction<Min<$ty>> for Add<$ty> {
+            fn identity() -> Self {
+                Add(0)
+            }
+            /// Leaves the identity in place, so empty segments stay empty.
+            fn apply(&self, value: &Min<$ty>) -> Min<$ty> {
+                if *value == Min::<$ty>::identity() { *value } else { Min(value.0 + self.0) }
+            }
+            fn compose(&self, inner: &Self) -> Self {
+                Add(self.0 + inner.0)
+            }
+        }
+    )*};
+}
+
+impl_numeric!(i32, i64, u32, u64, usize);
+
+/// Lexicographic maximum of pairs, so a key can carry a payload such as an
+/// index.
+impl<A: Copy + Ord, B: Copy + Ord> Monoid for Max<(A, B)>
+where
+    Max<A>: Monoid,
+    Max<B>: Monoid,
+{
+    fn identity() -> Self {
+        Max((Max::<A>::identity().0, Max::<B>::identity().0))
+    }
+    fn combine(&self, right: &Self) -> Self {
+        Max(self.0.max(right.0))
+    }
+}
+
+/// Lexicographic minimum of pairs, so a key can carry a payload such as an
+/// index.
+impl<A: Copy + Ord, B: Copy + Ord> Monoid for Min<(A, B)>
+where
+    Min<A>: Monoid,
+    Min<B>: Monoid,
+{
+    fn identity() -> Self {
+        Min((Min::<A>::identity().0, Min::<B>::identity().0))
+    }
+    fn combine(&self, right: &Self) -> Self {
+        Min(self.0.min(right.0))
+    }
+}
+
+/// A fixed-length sequence of monoid values supporting point updates, range
+/// aggregates and, when `A` is not `()`, lazy range updates, all in
+/// O(log n).
+#[derive(Clone, Debug)]
+pub struct SegmentTree<M: Monoid, A: LazyAction<M> = ()> {
+    len: usize,
+    /// Number of leaves, a power of two.
+    size: usize,
+    log: u32,
+    /// Aggregates in heap order: node `k` has children `2k` and `2k + 1`,
+    /// and leaf `i` is node `size + i`.
+    values: Vec<M>,
+    /// Pending action for the children of each internal node.
+    lazy: Vec<A>,
+}
+
+impl<M: Monoid, A: LazyAction<M>> SegmentTree<M, A> {
+    /// `n` identity elements.
+    pub fn new(n: usize) -> Self {
+        Self::from(vec![M::identity(); n])
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Replaces element `p`.
+    pub fn set(&mut self, p: usize, value: M) {
+        assert!(
+            p < self.len,
+            "index {p} out of range for length {}",
+            self.len
+        );
+        let p = p + self.size;
+        self.push_path(p);
+        self.values[p] = value;
+        (1..=self.log).for_each(|i| self.update(p >> i));
+    }
+
+    /// Element `p`, with every pending action applied.
+    pub fn get(&mut self, p: usize) -> M {
+        assert!(
+            p < self.len,
+            "index {p} out of range for length {}",
+            self.len
+        );
+        let p = p + self.size;
+        self.push_path(p);
+        self.values[p].clone()
+    }
+
+    /// Aggregate of the elements in `range`, the identity when it is empty.
+    pub fn query(&mut self, range: impl RangeBounds<usize>) -> M {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return M::identity();
+        }
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let (mut left, mut right) = (M::identity(), M::identity());
+        while l < r {
+            if l & 1 == 1 {
+                left = left.combine(&self.values[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = self.values[r].combine(&right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        left.combine(&right)
+    }
+
+    /// Aggregate of every element.
+    pub fn all(&self) -> M {
+        self.values[1].clone()
+    }
+
+    /// Applies `action` to every element in `range`.
+    pub fn apply(&mut self, range: impl RangeBounds<usize>, action: &A) {
+        let (l, r) = self.bounds(range);
+        if l == r {
+            return;
+        }
+        let (l, r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let (mut i, mut j) = (l, r);
+        while i < j {
+            if i & 1 == 1 {
+                self.apply_node(i, action);
+                i += 1;
+            }
+            if j & 1 == 1 {
+                j -= 1;
+                self.apply_node(j, action);
+            }
+            i >>= 1;
+            j >>= 1;
+        }
+        for i in 1..=self.log {
+            if (l >> i) << i != l {
+                self.update(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.update((r - 1) >> i);
+            }
+        }
+    }
+
+    /// The largest `r` such that `pred` holds for the aggregate of `l..r`,
+    /// assuming `pred` holds for the identity and, once false, stays false
+    /// as the range grows.
+    pub fn max_right(&mut self, l: usize, pred: impl Fn(&M) -> bool) -> usize {
+        assert!(
+            l <= self.len,
+            "index {l} out of range for length {}",
+            self.len
+        );
+        debug_assert!(pred(&M::identity()));
+        if l == self.len {
+            return self.len;
+        }
+        let mut l = l + self.size;
+        self.push_path(l);
+        let mut sum = M::identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let next = sum.combine(&self.values[l]);
+            if !pred(&next) {
+                while l < self.size {
+                    self.push(l);
+                    l *= 2;
+                    let next = sum.combine(&self.values[l]);
+                    if pred(&next) {
+                        sum = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            sum = next;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.len;
+            }
+        }
+    }
+
+    /// The smallest `l` such that `pred` holds for the aggregate of `l..r`,
+    /// under the same assumptions as [`SegmentTree::max_right`].
+    pub fn min_left(&mut self, r: usize, pred: impl Fn(&M) -> bool) -> usize {
+        assert!(
+            r <= self.len,
+            "index {r} out of range for length {}",
+            self.len
+        );
+        debug_assert!(pred(&M::identity()));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.size;
+        self.push_path(r - 1);
+        let mut sum = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let next = self.values[r].combine(&sum);
+            if !pred(&next) {
+                while r < self.size {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let next = self.values[r].combine(&sum);
+                    if pred(&next) {
+                        sum = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            sum = next;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+
+    fn bounds(&self, range: impl RangeBounds<usize>) -> (usize, usize) {
+        let l = match range.start_bound() {
+            Bound::Included(&l) => l,
+            Bound::Excluded(&l) => l + 1,
+            Bound::Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            Bound::Included(&r) => r + 1,
+            Bound::Excluded(&r) => r,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            l <= r && r <= self.len,
+            "range {l}..{r} out of range for length {}",
+            self.len
+        );
+        (l, r)
+    }
+
+    fn update(&mut self, k: usize) {
+        self.values[k] = self.values[2 * k].combine(&self.values[2 * k + 1]);
+    }
+
+    fn apply_node(&mut self, k: usize, action: &A) {
+        self.values[k] = action.apply(&self.values[k]);
+        if k < self.size {
+            self.lazy[k] = action.compose(&self.lazy[k]);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let action = mem::replace(&mut self.lazy[k], A::identity());
+        self.apply_node(2 * k, &action);
+        self.apply_node(2 * k + 1, &action);
+    }
+
+    /// Pushes pending actions down to node `k` from the root.
+    fn push_path(&mut self, k: usize) {
+        (1..=self.log).rev().for_each(|i| self.push(k >> i));
+    }
+
+    /// Pushes pending actions into every node the half-open node range
+    /// `l..r` is about to touch.
+    fn push_boundaries(&mut self, l: usize, r: usize) {
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+    }
+}
+
+impl<M: Monoid, A: LazyAction<M>> From<Vec<M>> for SegmentTree<M, A> {
+    fn from(elements: Vec<M>) -> Self {
+        let len = elements.len();
+        let size = len.next_power_of_two();
+        let mut values = vec![M::identity(); 2 * size];
+        for (slot, value) in values[size..].iter_mut().zip(elements) {
+            *slot = value;
+        }
+        let mut tree = Self {
+            len,
+            size,
+            log: size.trailing_zeros(),
+            values,
+            lazy: vec![A::identity(); size],
+        };
+        (1..size).rev().for_each(|k| tree.update(k));
+        tree
+    }
+}
+
+impl<M: Monoid, A: LazyAction<M>> FromIterator<M> for SegmentTree<M, A> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    /// Affine map `x -> a * x + b` acting on sums that carry their length.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Affine(i64, i64);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct SumLen(i64, i64);
+
+    impl Monoid for SumLen {
+        fn identity() -> Self {
+            SumLen(0, 0)
+        }
+        fn combine(&self, right: &Self) -> Self {
+            SumLen(self.0 + right.0, self.1 + right.1)
+        }
+    }
+
+    impl LazyAction<SumLen> for Affine {
+        fn identity() -> Self {
+            Affine(1, 0)
+        }
+        fn apply(&self, value: &SumLen) -> SumLen {
+            SumLen(self.0 * value.0 + self.1 * value.1, value.1)
+        }
+        fn compose(&self, inner: &Self) -> Self {
+            Affine(self.0 * inner.0, self.0 * inner.1 + self.1)
+        }
+    }
+
+    fn range(rng: &mut SeededRng, n: usize) -> (usize, usize) {
+        let l = rng.in_range(0..=n);
+        (l, rng.in_range(l..=n))
+    }
+
+    #[test]
+    fn matches_naive_under_affine_updates() {
+        let mut rng = SeededRng::new(7);
+        for n in 0..20 {
+            let mut naive: Vec<i64> = (0..n).map(|_| rng.in_range(-5..=5)).collect();
+            let mut tree: SegmentTree<SumLen, Affine> =
+                naive.iter().map(|&x| SumLen(x, 1)).collect();
+            for _ in 0..200 {
+                let (l, r) = range(&mut rng, n);
+                match rng.in_range(0..3) {
+                    0 if n > 0 => {
+                        let (p, x) = (rng.in_range(0..n), rng.in_range(-5..=5));
+                        naive[p] = x;
+                        tree.set(p, SumLen(x, 1));
+                    }
+                    1 => {
+                        let f = Affine(rng.in_range(-2..=2), rng.in_range(-3..=3));
+                        naive[l..r].iter_mut().for_each(|x| *x = f.0 * *x + f.1);
+                        tree.apply(l..r, &f);
+                    }
+                    _ => {
+                        let sum = naive[l..r].iter().sum::<i64>();
+                        assert_eq!(tree.query(l..r), SumLen(sum, (r - l) as i64));
+                    }
+                }
+                // Keep values small enough that repeated scaling cannot overflow.
+                if naive.iter().any(|x| x.abs() > 1 << 20) {
+                    naive.iter_mut().for_each(|x| *x = 0);
+                    tree = naive.iter().map(|&x| SumLen(x, 1)).collect();
+                }
+            }
+            for (p, &x) in naive.iter().enumerate() {
+                assert_eq!(tree.get(p), SumLen(x, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn binary_searches_match_naive() {
+        let mut rng = SeededRng::new(11);
+        for n in 0..20 {
+            let mut naive: Vec<i32> = (0..n).map(|_| rng.in_range(0..10)).collect();
+            let mut tree: SegmentTree<Max<i32>, Add<i32>> =
+                naive.iter().copied().map(Max).collect();
+            for _ in 0..100 {
+                let (l, r) = range(&mut rng, n);
+                let delta = rng.in_range(-3..=3);
+                naive[l..r].iter_mut().for_each(|x| *x += delta);
+                tree.apply(l..r, &Add(delta));
+
+                let bound = rng.in_range(-5..15);
+                let (l, r) = range(&mut rng, n);
+                let right = (l..n).find(|&i| naive[i] >= bound).unwrap_or(n);
+                assert_eq!(tree.max_right(l, |m| m.0 < bound), right);
+                let left = (0..r)
+                    .rev()
+                    .find(|&i| naive[i] >= bound)
+                    .map_or(0, |i| i + 1);
+                assert_eq!(tree.min_left(r, |m| m.0 < bound), left);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_ranges_yield_the_identity() {
+        let mut tree: SegmentTree<Min<i64>, Add<i64>> = SegmentTree::new(3);
+        assert_eq!(tree.query(1..1), Min(i64::MAX));
+        tree.apply(.., &Add(5));
+        assert_eq!(tree.all(), Min(i64::MAX));
+        tree.set(1, Min(2));
+        tree.apply(1..=2, &Add(5));
+        assert_eq!(tree.query(..), Min(7));
+    }
+}
@@ -0,0 +1,281 @@
+//! A sorted multiset with order statistics, kept as a list of sorted buckets
+//! of at most `B` = 1024 elements. Inserts, removals, ranks and `k`-th
+//! smallest lookups each take O(n / B + B) time, which in practice beats a
+//! balanced tree well into the millions, and iteration is a plain walk.
+//!
+//! ```
+//! use leetcode::sorted_list::SortedList;
+//!
+//! let mut list: SortedList<i32> = [5, 1, 4, 1].into_iter().collect();
+//! assert_eq!(list.kth(1), Some(&1));
+//! assert_eq!(list.rank(&4), 2); // elements below 4
+//! assert!(list.remove(&1));
+//! list.insert(3);
+//! assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 3, 4, 5]);
+//! ```
+
+use std::borrow::Borrow;
+use std::fmt;
+
+/// Bucket length at which a bucket splits in two.
+const SPLIT: usize = 1024;
+
+/// A sorted multiset supporting order statistics.
+#[derive(Clone)]
+pub struct SortedList<T> {
+    /// Non-empty sorted runs, each starting no lower than the previous ends.
+    buckets: Vec<Vec<T>>,
+    len: usize,
+}
+
+impl<T> Default for SortedList<T> {
+    fn default() -> Self {
+        Self {
+            buckets: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Ord> SortedList<T> {
+    /// An empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of elements, counting duplicates.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Index of the bucket `value` belongs in: the first whose last element
+    /// is not below it, or the last bucket.
+    fn bucket_for<Q: Ord + ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+    {
+        let b = self
+            .buckets
+            .partition_point(|bucket| bucket[bucket.len() - 1].borrow() < value);
+        b.min(self.buckets.len().saturating_sub(1))
+    }
+
+    /// Adds `value`.
+    pub fn insert(&mut self, value: T) {
+        self.len += 1;
+        if self.buckets.is_empty() {
+            self.buckets.push(vec![value]);
+            return;
+        }
+        let b = self.bucket_for(&value);
+        let bucket = &mut self.buckets[b];
+        let i = bucket.partition_point(|x| *x <= value);
+        bucket.insert(i, value);
+        if bucket.len() >= SPLIT {
+            let upper = bucket.split_off(SPLIT / 2);
+            self.buckets.insert(b + 1, upper);
+        }
+    }
+
+    /// Removes one element equal to `value`, returning whether there was one.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        if self.buckets.is_empty() {
+            return false;
+        }
+        let b = self.bucket_for(value);
+        let bucket = &mut self.buckets[b];
+        let Ok(i) = bucket.binary_search_by(|x| x.borrow().cmp(value)) else {
+            return false;
+        };
+        bucket.remove(i);
+        if bucket.is_empty() {
+            self.buckets.remove(b);
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// Whether some element equals `value`.
+    pub fn contains<Q: Ord + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        !self.buckets.is_empty()
+            && self.buckets[self.bucket_for(value)]
+                .binary_search_by(|x| x.borrow().cmp(value))
+                .is_ok()
+    }
+
+    /// Number of elements below `value`.
+    pub fn rank<Q: Ord + ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+    {
+        self.count_while(|x| x.borrow() < value)
+    }
+
+    /// Number of elements equal to `value`.
+    pub fn count<Q: Ord + ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+    {
+        self.count_while(|x| x.borrow() <= value) - self.rank(value)
+    }
+
+    /// Length of the prefix whose elements satisfy `pred`, which must hold
+    /// for a prefix of the list.
+    fn count_while(&self, pred: impl Fn(&T) -> bool) -> usize {
+        let b = self
+            .buckets
+            .partition_point(|bucket| pred(&bucket[bucket.len() - 1]));
+        let before: usize = self.buckets[..b].iter().map(Vec::len).sum();
+        before
+            + self
+                .buckets
+                .get(b)
+                .map_or(0, |bucket| bucket.partition_point(&pred))
+    }
+
+    /// The element with `k` elements before it, counting from zero.
+    pub fn kth(&self, mut k: usize) -> Option<&T> {
+        for bucket in &self.buckets {
+            if k < bucket.len() {
+                return Some(&bucket[k]);
+            }
+            k -= bucket.len();
+        }
+        None
+    }
+
+    /// The smallest element.
+    pub fn first(&self) -> Option<&T> {
+        self.buckets.first().map(|bucket| &bucket[0])
+    }
+
+    /// The largest element.
+    pub fn last(&self) -> Option<&T> {
+        self.buckets.last().map(|bucket| &bucket[bucket.len() - 1])
+    }
+
+    /// Removes and returns the smallest element.
+    pub fn pop_first(&mut self) -> Option<T> {
+        let bucket = self.buckets.first_mut()?;
+        let value = bucket.remove(0);
+        if bucket.is_empty() {
+            self.buckets.remove(0);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Removes and returns the largest element.
+    pub fn pop_last(&mut self) -> Option<T> {
+        let bucket = self.buckets.last_mut()?;
+        let value = bucket.pop();
+        if bucket.is_empty() {
+            self.buckets.pop();
+        }
+        self.len -= 1;
+        value
+    }
+
+    /// The elements in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.buckets.iter().flatten()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    /// Sorts once and cuts the result into buckets.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut values: Vec<T> = iter.into_iter().collect();
+        values.sort();
+        let len = values.len();
+        let mut buckets = Vec::with_capacity(len.div_ceil(SPLIT / 2));
+        while values.len() > SPLIT / 2 {
+            let rest = values.split_off(SPLIT / 2);
+            buckets.push(values);
+            values = rest;
+        }
+        if !values.is_empty() {
+            buckets.push(values);
+        }
+        Self { buckets, len }
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.buckets.iter().flatten())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    #[test]
+    fn matches_sorted_vec() {
+        let mut rng = SeededRng::new(18);
+        for range in [4, 100, 100_000] {
+            let mut list = SortedList::new();
+            let mut naive: Vec<i32> = Vec::new();
+            for _ in 0..6000 {
+                let x = rng.in_range(0..range);
+                if rng.chance(0.35) {
+                    let expected = naive.binary_search(&x).map(|i| naive.remove(i)).is_ok();
+                    assert_eq!(list.remove(&x), expected);
+                } else {
+                    let i = naive.partition_point(|&y| y <= x);
+                    naive.insert(i, x);
+                    list.insert(x);
+                }
+                assert_eq!(list.len(), naive.len());
+                assert_eq!(list.rank(&x), naive.partition_point(|&y| y < x));
+                assert_eq!(list.contains(&x), naive.binary_search(&x).is_ok());
+                if !naive.is_empty() {
+                    let k = rng.in_range(0..naive.len());
+                    assert_eq!(list.kth(k), Some(&naive[k]));
+                }
+                assert_eq!(list.kth(naive.len()), None);
+            }
+            assert!(list.iter().eq(naive.iter()));
+            assert!(list.iter().rev().eq(naive.iter().rev()));
+            let x = naive[naive.len() / 2];
+            assert_eq!(list.count(&x), naive.iter().filter(|&&y| y == x).count());
+        }
+    }
+
+    #[test]
+    fn collect_and_pop_ends() {
+        let mut list: SortedList<u32> = (0..3000).rev().collect();
+        assert_eq!((list.first(), list.last()), (Some(&0), Some(&2999)));
+        for i in 0..1500 {
+            assert_eq!(list.pop_first(), Some(i));
+            assert_eq!(list.pop_last(), Some(2999 - i));
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.pop_first(), None);
+        list.extend([2, 1, 2]);
+        assert_eq!(format!("{list:?}"), "[1, 2, 2]");
+    }
+}
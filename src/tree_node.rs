@@ -0,0 +1,309 @@
+//! Binary tree node as defined by LeetCode, plus [`Tree`], an owned tree that
+//! converts to and from LeetCode's level-order `[3,9,20,null,null,15,7]`
+//! notation.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<Rc<RefCell<TreeNode>>>,
+    pub right: Option<Rc<RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// A possibly empty tree, wrapping the `Option<Rc<RefCell<TreeNode>>>` root
+/// that LeetCode signatures pass around.
+///
+/// Equality is structural: two trees are equal when they have the same shape
+/// and the same value at every position.
+///
+/// ```
+/// use leetcode::tree_node::Tree;
+///
+/// let tree: Tree = "[3,9,20,null,null,15,7]".parse().unwrap();
+/// assert_eq!(tree.depth(), 3);
+/// assert_eq!(tree.node_count(), 5);
+/// assert_eq!(tree.to_string(), "[3,9,20,null,null,15,7]");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Tree(pub Option<Rc<RefCell<TreeNode>>>);
+
+impl Tree {
+    /// Returns the root node, ready to hand to a solution.
+    #[inline]
+    pub fn into_inner(self) -> Option<Rc<RefCell<TreeNode>>> {
+        self.0
+    }
+
+    /// Level-order values with `None` for missing children, trailing `None`s
+    /// trimmed, exactly as LeetCode prints a tree.
+    pub fn to_vec(&self) -> Vec<Option<i32>> {
+        let mut values = Vec::new();
+        let mut queue = VecDeque::from([self.0.clone()]);
+        while let Some(slot) = queue.pop_front() {
+            match slot {
+                Some(node) => {
+                    let node = node.borrow();
+                    values.push(Some(node.val));
+                    queue.push_back(node.left.clone());
+                    queue.push_back(node.right.clone());
+                }
+                None => values.push(None),
+            }
+        }
+        while values.last() == Some(&None) {
+            values.pop();
+        }
+        values
+    }
+
+    /// Number of nodes on the longest root-to-leaf path; `0` for an empty tree.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut level: Vec<_> = self.0.iter().cloned().collect();
+        while !level.is_empty() {
+            depth += 1;
+            level = level
+                .iter()
+                .flat_map(|node| {
+                    let node = node.borrow();
+                    [node.left.clone(), node.right.clone()]
+                })
+                .flatten()
+                .collect();
+        }
+        depth
+    }
+
+    /// Total number of nodes.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack: Vec<_> = self.0.iter().cloned().collect();
+        while let Some(node) = stack.pop() {
+            count += 1;
+            let node = node.borrow();
+            stack.extend(node.left.clone());
+            stack.extend(node.right.clone());
+        }
+        count
+    }
+
+    /// First node in level order holding `val`, sharing it with the tree.
+    ///
+    /// Problems such as lowest common ancestor take nodes of the tree itself
+    /// as arguments; this is how tests get hold of them.
+    pub fn find(&self, val: i32) -> Option<Rc<RefCell<TreeNode>>> {
+        let mut queue: VecDeque<_> = self.0.iter().cloned().collect();
+        while let Some(node) = queue.pop_front() {
+            if node.borrow().val == val {
+                return Some(node);
+            }
+            let node = node.borrow();
+            queue.extend(node.left.clone());
+            queue.extend(node.right.clone());
+        }
+        None
+    }
+}
+
+impl From<Vec<Option<i32>>> for Tree {
+    /// Builds a tree from level-order values, where `None` marks a missing
+    /// child and the children of missing nodes are not listed.
+    fn from(values: Vec<Option<i32>>) -> Self {
+        let mut values = values.into_iter();
+        let root = match values.next().flatten() {
+            Some(val) => Rc::new(RefCell::new(TreeNode::new(val))),
+            None => return Tree(None),
+        };
+
+        let new_child = |val: Option<i32>| val.map(|v| Rc::new(RefCell::new(TreeNode::new(v))));
+        let mut queue = VecDeque::from([Rc::clone(&root)]);
+        while let Some(parent) = queue.pop_front() {
+            let Some(left) = values.next() else { break };
+            let left = new_child(left);
+            queue.extend(left.clone());
+            parent.borrow_mut().left = left;
+
+            let Some(right) = values.next() else { break };
+            let right = new_child(right);
+            queue.extend(right.clone());
+            parent.borrow_mut().right = right;
+        }
+        Tree(Some(root))
+    }
+}
+
+impl From<Option<Rc<RefCell<TreeNode>>>> for Tree {
+    fn from(root: Option<Rc<RefCell<TreeNode>>>) -> Self {
+        Tree(root)
+    }
+}
+
+impl From<Tree> for Option<Rc<RefCell<TreeNode>>> {
+    fn from(tree: Tree) -> Self {
+        tree.0
+    }
+}
+
+impl FromStr for Tree {
+    type Err = ParseTreeError;
+
+    /// Parses LeetCode's level-order notation, e.g. `[1,null,2,3]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or(ParseTreeError::MissingBrackets)?
+            .trim();
+        if inner.is_empty() {
+            return Ok(Tree(None));
+        }
+
+        let values = inner
+            .split(',')
+            .map(|item| match item.trim() {
+                "null" => Ok(None),
+                item => item
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| ParseTreeError::InvalidValue(item.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Tree::from(values))
+    }
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, val) in self.to_vec().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            match val {
+                Some(val) => write!(f, "{val}")?,
+                None => f.write_str("null")?,
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+/// Error returned when a string is not a valid level-order tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseTreeError {
+    /// The input is not wrapped in `[` and `]`.
+    MissingBrackets,
+    /// An element is neither an integer nor `null`.
+    InvalidValue(String),
+}
+
+impl fmt::Display for ParseTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTreeError::MissingBrackets => f.write_str("tree must be wrapped in `[` and `]`"),
+            ParseTreeError::InvalidValue(item) => {
+                write!(f, "`{item}` is neither an integer nor `null`")
+            }
+        }
+    }
+}
+
+impl Error for ParseTreeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Tree {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_leetcode_example() {
+        let tree = parse("[3,9,20,null,null,15,7]");
+        let root = tree.0.as_ref().unwrap().borrow();
+        assert_eq!(root.val, 3);
+        assert_eq!(root.left.as_ref().unwrap().borrow().val, 9);
+        let right = root.right.as_ref().unwrap().borrow();
+        assert_eq!(right.val, 20);
+        assert_eq!(right.left.as_ref().unwrap().borrow().val, 15);
+        assert_eq!(right.right.as_ref().unwrap().borrow().val, 7);
+    }
+
+    #[test]
+    fn round_trips_through_string() {
+        for s in [
+            "[]",
+            "[1]",
+            "[1,null,2,3]",
+            "[5,4,8,11,null,13,4,7,2,null,null,5,1]",
+        ] {
+            assert_eq!(parse(s).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn tolerates_whitespace_and_trailing_nulls() {
+        let tree = parse(" [ 1 , 2 , null , null ] ");
+        assert_eq!(tree.to_vec(), vec![Some(1), Some(2)]);
+        assert_eq!(parse("[null]"), Tree::default());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!("1,2".parse::<Tree>(), Err(ParseTreeError::MissingBrackets));
+        assert_eq!(
+            "[1,x]".parse::<Tree>(),
+            Err(ParseTreeError::InvalidValue("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn equality_is_structural() {
+        assert_eq!(
+            parse("[1,2,3]"),
+            Tree::from(vec![Some(1), Some(2), Some(3)])
+        );
+        assert_ne!(parse("[1,2]"), parse("[1,null,2]"));
+        assert_ne!(parse("[1,2]"), parse("[1,3]"));
+    }
+
+    #[test]
+    fn depth_and_node_count() {
+        assert_eq!(
+            (Tree::default().depth(), Tree::default().node_count()),
+            (0, 0)
+        );
+        let skewed = parse("[1,null,2,null,3,null,4]");
+        assert_eq!((skewed.depth(), skewed.node_count()), (4, 4));
+        let full = parse("[1,2,3,4,5,6,7]");
+        assert_eq!((full.depth(), full.node_count()), (3, 7));
+    }
+
+    #[test]
+    fn find_shares_nodes_with_the_tree() {
+        let tree = parse("[3,5,1,6,2,0,8]");
+        let node = tree.find(2).unwrap();
+        node.borrow_mut().val = 9;
+        assert_eq!(tree.to_string(), "[3,5,1,6,9,0,8]");
+        assert!(tree.find(42).is_none());
+    }
+}
@@ -0,0 +1,523 @@
+//! Arena-backed trie over a pluggable [`Alphabet`], with per-node counters,
+//! deletion and lexicographic prefix enumeration.
+//!
+//! ```
+//! use leetcode::trie::{Lowercase, Trie};
+//!
+//! let mut words: Trie<Lowercase> = Trie::new();
+//! for word in ["tea", "ten", "to", "ten"] {
+//!     words.insert(word.bytes());
+//! }
+//! assert_eq!(words.count("ten".bytes()), 2);
+//! assert_eq!(words.count_prefix("te".bytes()), 3);
+//! assert!(words.remove("tea".bytes()));
+//! assert_eq!(words.keys_with_prefix("t".bytes()), [b"ten".to_vec(), b"to".to_vec()]);
+//! ```
+//!
+//! Over [`Bits`], integers are stored most significant bit first, which gives
+//! greedy maximum and minimum XOR queries:
+//!
+//! ```
+//! use leetcode::trie::{Bits, Trie};
+//!
+//! let mut nums: Trie<Bits> = Trie::new();
+//! for x in [3, 10, 5, 25] {
+//!     nums.insert(Bits::msb_first(x, 5));
+//! }
+//! assert_eq!(nums.max_xor(5, 5), Some(28));
+//! assert_eq!(nums.min_xor(8, 5), Some(2));
+//! ```
+
+use std::marker::PhantomData;
+
+/// Index of the root node.
+pub const ROOT: usize = 0;
+
+/// The symbols keys are spelled in, numbered so that index order is the
+/// lexicographic order of the keys.
+pub trait Alphabet {
+    type Symbol: Copy;
+    /// How a node stores its children.
+    type Children: Children;
+    /// The symbol's position in the alphabet.
+    fn index(symbol: Self::Symbol) -> usize;
+    /// The symbol at position `index`.
+    fn symbol(index: usize) -> Self::Symbol;
+}
+
+/// Child links of one node, keyed by symbol index. Node ids are never zero,
+/// since the root is nobody's child.
+pub trait Children: Default {
+    fn get(&self, index: usize) -> Option<usize>;
+    fn set(&mut self, index: usize, child: usize);
+    fn unset(&mut self, index: usize);
+    /// `(index, child)` pairs in increasing index order.
+    fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_;
+}
+
+/// A child slot for every symbol, for small alphabets.
+#[derive(Clone, Debug)]
+pub struct Dense<const N: usize>([u32; N]);
+
+impl<const N: usize> Default for Dense<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> Children for Dense<N> {
+    fn get(&self, index: usize) -> Option<usize> {
+        (self.0[index] != 0).then_some(self.0[index] as usize)
+    }
+    fn set(&mut self, index: usize, child: usize) {
+        self.0[index] = child as u32;
+    }
+    fn unset(&mut self, index: usize) {
+        self.0[index] = 0;
+    }
+    fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|&(_, &child)| child != 0)
+            .map(|(index, &child)| (index, child as usize))
+    }
+}
+
+/// Only the children present, sorted by index, for large alphabets.
+#[derive(Clone, Debug, Default)]
+pub struct Sparse(Vec<(u32, u32)>);
+
+impl Sparse {
+    fn position(&self, index: usize) -> Result<usize, usize> {
+        self.0.binary_search_by_key(&(index as u32), |&(i, _)| i)
+    }
+}
+
+impl Children for Sparse {
+    fn get(&self, index: usize) -> Option<usize> {
+        let at = self.position(index).ok()?;
+        Some(self.0[at].1 as usize)
+    }
+    fn set(&mut self, index: usize, child: usize) {
+        match self.position(index) {
+            Ok(at) => self.0[at].1 = child as u32,
+            Err(at) => self.0.insert(at, (index as u32, child as u32)),
+        }
+    }
+    fn unset(&mut self, index: usize) {
+        if let Ok(at) = self.position(index) {
+            self.0.remove(at);
+        }
+    }
+    fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.0
+            .iter()
+            .map(|&(i, child)| (i as usize, child as usize))
+    }
+}
+
+/// The lowercase letters `b'a'..=b'z'`.
+#[derive(Clone, Copy, Debug)]
+pub struct Lowercase;
+
+impl Alphabet for Lowercase {
+    type Symbol = u8;
+    type Children = Dense<26>;
+    fn index(symbol: u8) -> usize {
+        (symbol - b'a') as usize
+    }
+    fn symbol(index: usize) -> u8 {
+        b'a' + index as u8
+    }
+}
+
+/// Arbitrary bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct Bytes;
+
+impl Alphabet for Bytes {
+    type Symbol = u8;
+    type Children = Dense<256>;
+    fn index(symbol: u8) -> usize {
+        symbol as usize
+    }
+    fn symbol(index: usize) -> u8 {
+        index as u8
+    }
+}
+
+/// Binary digits, for keys that are fixed-width integers.
+#[derive(Clone, Copy, Debug)]
+pub struct Bits;
+
+impl Bits {
+    /// The low `width` bits of `x`, most significant first.
+    pub fn msb_first(x: u64, width: u32) -> impl Iterator<Item = bool> {
+        (0..width).rev().map(move |bit| (x >> bit) & 1 == 1)
+    }
+}
+
+impl Alphabet for Bits {
+    type Symbol = bool;
+    type Children = Dense<2>;
+    fn index(symbol: bool) -> usize {
+        symbol as usize
+    }
+    fn symbol(index: usize) -> bool {
+        index == 1
+    }
+}
+
+/// Pairs of bytes, such as a word's `i`-th character together with its
+/// `i`-th character from the end.
+#[derive(Clone, Copy, Debug)]
+pub struct Pairs;
+
+impl Alphabet for Pairs {
+    type Symbol = (u8, u8);
+    type Children = Sparse;
+    fn index((a, b): (u8, u8)) -> usize {
+        (a as usize) << 8 | b as usize
+    }
+    fn symbol(index: usize) -> (u8, u8) {
+        ((index >> 8) as u8, index as u8)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node<C> {
+    children: C,
+    /// Keys, counted with multiplicity, passing through this node.
+    passes: usize,
+    /// Keys ending at this node.
+    ends: usize,
+}
+
+/// A multiset of keys spelled over `A`, stored as a prefix tree in a single
+/// arena. Nodes freed by [`Trie::remove`] are reused by later insertions.
+#[derive(Clone, Debug)]
+pub struct Trie<A: Alphabet> {
+    nodes: Vec<Node<A::Children>>,
+    free: Vec<usize>,
+    alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet> Default for Trie<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Alphabet> Trie<A> {
+    /// An empty trie.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+            free: Vec::new(),
+            alphabet: PhantomData,
+        }
+    }
+
+    /// Number of keys, counted with multiplicity.
+    pub fn len(&self) -> usize {
+        self.nodes[ROOT].passes
+    }
+
+    /// Whether the trie holds no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds one copy of `key` and returns the node it ends at.
+    pub fn insert(&mut self, key: impl IntoIterator<Item = A::Symbol>) -> usize {
+        let mut node = ROOT;
+        self.nodes[node].passes += 1;
+        for symbol in key {
+            let index = A::index(symbol);
+            node = match self.nodes[node].children.get(index) {
+                Some(child) => child,
+                None => {
+                    let child = self.allocate();
+                    self.nodes[node].children.set(index, child);
+                    child
+                }
+            };
+            self.nodes[node].passes += 1;
+        }
+        self.nodes[node].ends += 1;
+        node
+    }
+
+    /// Removes one copy of `key`, returning `false` if it was absent. Nodes
+    /// that no longer lie on any key are unlinked and recycled.
+    pub fn remove(&mut self, key: impl IntoIterator<Item = A::Symbol>) -> bool {
+        let mut path = vec![(ROOT, 0)];
+        for symbol in key {
+            let (node, _) = path[path.len() - 1];
+            let index = A::index(symbol);
+            match self.nodes[node].children.get(index) {
+                Some(child) => path.push((child, index)),
+                None => return false,
+            }
+        }
+        let (end, _) = path[path.len() - 1];
+        if self.nodes[end].ends == 0 {
+            return false;
+        }
+        self.nodes[end].ends -= 1;
+        for (depth, &(node, index)) in path.iter().enumerate() {
+            self.nodes[node].passes -= 1;
+            if depth > 0 && self.nodes[node].passes == 0 {
+                // Everything below is on this path too, since it held only
+                // the removed key.
+                self.nodes[path[depth - 1].0].children.unset(index);
+                for &(node, _) in &path[depth..] {
+                    self.nodes[node] = Node::default();
+                    self.free.push(node);
+                }
+                break;
+            }
+        }
+        true
+    }
+
+    /// The node reached by spelling `prefix` from the root.
+    pub fn find(&self, prefix: impl IntoIterator<Item = A::Symbol>) -> Option<usize> {
+        prefix
+            .into_iter()
+            .try_fold(ROOT, |node, symbol| self.child(node, symbol))
+    }
+
+    /// Number of copies of `key`.
+    pub fn count(&self, key: impl IntoIterator<Item = A::Symbol>) -> usize {
+        self.find(key).map_or(0, |node| self.ends(node))
+    }
+
+    /// Whether `key` is present.
+    pub fn contains(&self, key: impl IntoIterator<Item = A::Symbol>) -> bool {
+        self.count(key) > 0
+    }
+
+    /// Number of keys, counted with multiplicity, that start with `prefix`.
+    pub fn count_prefix(&self, prefix: impl IntoIterator<Item = A::Symbol>) -> usize {
+        self.find(prefix).map_or(0, |node| self.passes(node))
+    }
+
+    /// The child of `node` along `symbol`.
+    pub fn child(&self, node: usize, symbol: A::Symbol) -> Option<usize> {
+        self.nodes[node].children.get(A::index(symbol))
+    }
+
+    /// The children of `node` with the symbols leading to them, in
+    /// lexicographic order.
+    pub fn children(&self, node: usize) -> impl Iterator<Item = (A::Symbol, usize)> + '_ {
+        self.nodes[node]
+            .children
+            .iter()
+            .map(|(index, child)| (A::symbol(index), child))
+    }
+
+    /// Number of keys ending at `node`.
+    pub fn ends(&self, node: usize) -> usize {
+        self.nodes[node].ends
+    }
+
+    /// Number of keys passing through or ending at `node`.
+    pub fn passes(&self, node: usize) -> usize {
+        self.nodes[node].passes
+    }
+
+    /// The nodes visited while spelling `key`, one per symbol, stopping
+    /// early where the trie has no continuation.
+    pub fn path(&self, key: impl IntoIterator<Item = A::Symbol>) -> impl Iterator<Item = usize> {
+        let mut node = ROOT;
+        key.into_iter().map_while(move |symbol| {
+            node = self.child(node, symbol)?;
+            Some(node)
+        })
+    }
+
+    /// The distinct keys starting with `prefix`, in lexicographic order.
+    pub fn keys_with_prefix(
+        &self,
+        prefix: impl IntoIterator<Item = A::Symbol>,
+    ) -> Vec<Vec<A::Symbol>> {
+        let mut key = Vec::new();
+        let mut node = ROOT;
+        for symbol in prefix {
+            match self.child(node, symbol) {
+                Some(child) => node = child,
+                None => return Vec::new(),
+            }
+            key.push(symbol);
+        }
+        let mut keys = Vec::new();
+        self.collect(node, &mut key, &mut keys);
+        keys
+    }
+
+    fn collect(&self, node: usize, key: &mut Vec<A::Symbol>, keys: &mut Vec<Vec<A::Symbol>>) {
+        if self.nodes[node].ends > 0 {
+            keys.push(key.clone());
+        }
+        for (symbol, child) in self.children(node) {
+            key.push(symbol);
+            self.collect(child, key, keys);
+            key.pop();
+        }
+    }
+
+    fn allocate(&mut self) -> usize {
+        self.free.pop().unwrap_or_else(|| {
+            self.nodes.push(Node::default());
+            self.nodes.len() - 1
+        })
+    }
+}
+
+impl Trie<Bits> {
+    /// The largest `x ^ y` over stored `width`-bit keys `y`, or `None` when
+    /// the trie is empty.
+    pub fn max_xor(&self, x: u64, width: u32) -> Option<u64> {
+        self.best_xor(x, width, true)
+    }
+
+    /// The smallest `x ^ y` over stored `width`-bit keys `y`, or `None` when
+    /// the trie is empty.
+    pub fn min_xor(&self, x: u64, width: u32) -> Option<u64> {
+        self.best_xor(x, width, false)
+    }
+
+    /// Greedily follows the bit that sets (`differ`) or clears each bit of
+    /// the XOR, from the most significant down.
+    fn best_xor(&self, x: u64, width: u32, differ: bool) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut node = ROOT;
+        let mut xor = 0;
+        for (bit, wanted) in (0..width).rev().zip(Bits::msb_first(x, width)) {
+            let preferred = wanted ^ differ;
+            node = match self.child(node, preferred) {
+                Some(child) => {
+                    xor |= u64::from(differ) << bit;
+                    child
+                }
+                None => {
+                    xor |= u64::from(!differ) << bit;
+                    self.child(node, !preferred)?
+                }
+            };
+        }
+        Some(xor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomSource, SeededRng};
+
+    #[test]
+    fn counts_keys_and_prefixes() {
+        let mut trie: Trie<Lowercase> = Trie::new();
+        assert!(trie.is_empty());
+        trie.insert("app".bytes());
+        trie.insert("apple".bytes());
+        trie.insert("app".bytes());
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.count("app".bytes()), 2);
+        assert!(!trie.contains("appl".bytes()));
+        assert_eq!(trie.count_prefix("appl".bytes()), 1);
+        assert_eq!(trie.count_prefix("".bytes()), 3);
+        assert_eq!(trie.count_prefix("b".bytes()), 0);
+        let ends: Vec<usize> = trie.path("apples".bytes()).map(|n| trie.ends(n)).collect();
+        assert_eq!(ends, [0, 0, 2, 0, 1]);
+    }
+
+    #[test]
+    fn removal_recycles_nodes() {
+        let mut trie: Trie<Bytes> = Trie::new();
+        trie.insert(*b"abc");
+        trie.insert(*b"abd");
+        assert!(!trie.remove(*b"ab"));
+        assert!(!trie.remove(*b"abx"));
+        assert!(trie.remove(*b"abc"));
+        assert!(!trie.remove(*b"abc"));
+        assert_eq!(trie.find(*b"abc"), None);
+        assert_eq!(trie.count_prefix(*b"ab"), 1);
+        let nodes = trie.nodes.len();
+        trie.insert(*b"abe");
+        assert_eq!(trie.nodes.len(), nodes);
+        assert!(trie.remove(*b"abd"));
+        assert!(trie.remove(*b"abe"));
+        assert!(trie.is_empty());
+        assert_eq!(trie.children(ROOT).count(), 0);
+    }
+
+    #[test]
+    fn enumerates_keys_in_lexicographic_order() {
+        let mut trie: Trie<Lowercase> = Trie::new();
+        for word in [
+            "banana", "b", "apple", "band", "ban", "apple", "", "bandana",
+        ] {
+            trie.insert(word.bytes());
+        }
+        let strings = |keys: Vec<Vec<u8>>| -> Vec<String> {
+            keys.into_iter()
+                .map(|k| String::from_utf8(k).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            strings(trie.keys_with_prefix("ban".bytes())),
+            ["ban", "banana", "band", "bandana"]
+        );
+        assert_eq!(
+            strings(trie.keys_with_prefix("".bytes())),
+            ["", "apple", "b", "ban", "banana", "band", "bandana"]
+        );
+        assert!(trie.keys_with_prefix("c".bytes()).is_empty());
+    }
+
+    #[test]
+    fn pairs_use_sparse_children() {
+        let mut trie: Trie<Pairs> = Trie::new();
+        trie.insert([(b'z', b'a'), (b'a', b'a')]);
+        trie.insert([(b'a', b'z')]);
+        trie.insert([(b'a', b'b')]);
+        assert_eq!(
+            trie.keys_with_prefix([]),
+            [
+                vec![(b'a', b'b')],
+                vec![(b'a', b'z')],
+                vec![(b'z', b'a'), (b'a', b'a')]
+            ]
+        );
+        assert!(trie.remove([(b'a', b'z')]));
+        assert_eq!(trie.children(ROOT).count(), 2);
+    }
+
+    #[test]
+    fn xor_queries_match_brute_force() {
+        let mut rng = SeededRng::new(21);
+        let width = 6;
+        let mut trie: Trie<Bits> = Trie::new();
+        let mut stored: Vec<u64> = Vec::new();
+        assert_eq!(trie.max_xor(5, width), None);
+        for _ in 0..500 {
+            if !stored.is_empty() && rng.chance(0.4) {
+                let y = stored.swap_remove(rng.in_range(0..stored.len()));
+                assert!(trie.remove(Bits::msb_first(y, width)));
+            } else {
+                let y = rng.in_range(0..1 << width);
+                trie.insert(Bits::msb_first(y, width));
+                stored.push(y);
+            }
+            let x = rng.in_range(0..1 << width);
+            let xors = stored.iter().map(|&y| x ^ y);
+            assert_eq!(trie.max_xor(x, width), xors.clone().max());
+            assert_eq!(trie.min_xor(x, width), xors.min());
+        }
+    }
+}
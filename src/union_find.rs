@@ -0,0 +1,282 @@
+//! Disjoint-set forest with union by size and path compression, optionally
+//! weighted by a group-valued potential between each element and its root.
+//!
+//! ```
+//! use leetcode::union_find::UnionFind;
+//!
+//! let mut uf = UnionFind::new(5);
+//! assert!(uf.union(0, 1));
+//! assert!(uf.union(1, 2));
+//! assert!(!uf.union(0, 2));
+//! assert_eq!((uf.count(), uf.size(2)), (3, 3));
+//!
+//! let mut members: Vec<usize> = uf.members(1).collect();
+//! members.sort_unstable();
+//! assert_eq!(members, [0, 1, 2]);
+//! ```
+//!
+//! In weighted mode every union records how one element relates to the
+//! other, and [`UnionFind::relation`] composes those relations along the
+//! tree. With `f64` ratios, `a / b = 2` and `b / c = 3` give `a / c = 6`:
+//!
+//! ```
+//! use leetcode::union_find::UnionFind;
+//!
+//! let mut ratios = UnionFind::<f64>::weighted(3);
+//! ratios.union_with(0, 1, 2.0);
+//! ratios.union_with(1, 2, 3.0);
+//! assert_eq!(ratios.relation(0, 2), Some(6.0));
+//! assert_eq!(ratios.relation(2, 0), Some(1.0 / 6.0));
+//! ```
+
+/// A group whose values label the edges of a weighted [`UnionFind`].
+///
+/// `a.then(b)` is the relation obtained by following `a` and then `b`, so it
+/// need not be commutative.
+pub trait Potential: Copy {
+    /// The relation of an element to itself.
+    fn identity() -> Self;
+    /// Follows `self`, then `next`.
+    fn then(self, next: Self) -> Self;
+    /// The reverse relation, so that `p.then(p.inverse())` is the identity.
+    fn inverse(self) -> Self;
+}
+
+/// No potential: a plain disjoint-set forest.
+impl Potential for () {
+    fn identity() -> Self {}
+    fn then(self, _: Self) -> Self {}
+    fn inverse(self) -> Self {}
+}
+
+/// Ratios under multiplication, as in `a / b = 2.5`.
+impl Potential for f64 {
+    fn identity() -> Self {
+        1.0
+    }
+    fn then(self, next: Self) -> Self {
+        self * next
+    }
+    fn inverse(self) -> Self {
+        1.0 / self
+    }
+}
+
+macro_rules! impl_potential_int {
+    ($($ty:ty),*) => {$(
+        /// Offsets under addition, as in `a - b = 3`.
+        impl Potential for $ty {
+            fn identity() -> Self {
+                0
+            }
+            fn then(self, next: Self) -> Self {
+                self + next
+            }
+            fn inverse(self) -> Self {
+                -self
+            }
+        }
+    )*};
+}
+
+impl_potential_int!(i32, i64);
+
+/// Disjoint sets over the elements `0..n`.
+#[derive(Clone, Debug)]
+pub struct UnionFind<P: Potential = ()> {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// Relation of each element to its parent.
+    potential: Vec<P>,
+    /// Successor in a circular list through each set's members.
+    next: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self::weighted(n)
+    }
+}
+
+impl<P: Potential> UnionFind<P> {
+    /// `n` singleton sets, each element related to itself by the identity.
+    pub fn weighted(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            potential: vec![P::identity(); n],
+            next: (0..n).collect(),
+            components: n,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.components
+    }
+
+    /// Representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let parent = self.parent[x];
+        if parent == x {
+            return x;
+        }
+        let root = self.find(parent);
+        self.potential[x] = self.potential[x].then(self.potential[parent]);
+        self.parent[x] = root;
+        root
+    }
+
+    /// Whether `a` and `b` are in the same set.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Merges the sets of `a` and `b`, returning `false` when they were
+    /// already one set. In weighted mode this relates them by the identity.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        self.union_with(a, b, P::identity())
+    }
+
+    /// Merges the sets of `a` and `b`, recording that `a` relates to `b` by
+    /// `potential`. Returns `false`, leaving the forest unchanged, when they
+    /// were already one set, even if `potential` contradicts their existing
+    /// relation.
+    pub fn union_with(&mut self, a: usize, b: usize, potential: P) -> bool {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        // Relation of `ra` to `rb`: back from `ra` to `a`, then `a` to `b`,
+        // then `b` up to `rb`.
+        let link = self.potential[a]
+            .inverse()
+            .then(potential)
+            .then(self.potential[b]);
+        let (child, root, link) = if self.size[ra] < self.size[rb] {
+            (ra, rb, link)
+        } else {
+            (rb, ra, link.inverse())
+        };
+        self.parent[child] = root;
+        self.potential[child] = link;
+        self.size[root] += self.size[child];
+        self.next.swap(child, root);
+        self.components -= 1;
+        true
+    }
+
+    /// How `x` relates to the representative of its set.
+    pub fn potential(&mut self, x: usize) -> P {
+        self.find(x);
+        self.potential[x]
+    }
+
+    /// How `a` relates to `b`, or `None` when they are in different sets.
+    pub fn relation(&mut self, a: usize, b: usize) -> Option<P> {
+        if !self.connected(a, b) {
+            return None;
+        }
+        Some(self.potential[a].then(self.potential[b].inverse()))
+    }
+
+    /// The elements of the set containing `x`, starting with `x`, in time
+    /// proportional to the size of the set.
+    pub fn members(&self, x: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut current = Some(x);
+        std::iter::from_fn(move || {
+            let member = current?;
+            let next = self.next[member];
+            current = (next != x).then_some(next);
+            Some(member)
+        })
+    }
+
+    /// Every set, as its members.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.members(root).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(members: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut members: Vec<usize> = members.collect();
+        members.sort_unstable();
+        members
+    }
+
+    #[test]
+    fn tracks_components_and_sizes() {
+        let mut uf = UnionFind::new(6);
+        assert_eq!(uf.count(), 6);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert!(uf.union(1, 3));
+        assert!(!uf.union(0, 2));
+        assert_eq!(uf.count(), 3);
+        assert_eq!(uf.size(3), 4);
+        assert_eq!(uf.size(5), 1);
+        assert!(uf.connected(0, 3));
+        assert!(!uf.connected(0, 4));
+    }
+
+    #[test]
+    fn iterates_over_members() {
+        let mut uf = UnionFind::new(7);
+        for (a, b) in [(0, 2), (4, 6), (2, 4), (1, 5)] {
+            uf.union(a, b);
+        }
+        assert_eq!(sorted(uf.members(6)), [0, 2, 4, 6]);
+        assert_eq!(sorted(uf.members(5)), [1, 5]);
+        assert_eq!(uf.members(3).collect::<Vec<_>>(), [3]);
+        let mut groups = uf.groups();
+        groups.iter_mut().for_each(|g| g.sort_unstable());
+        groups.sort();
+        assert_eq!(groups, [vec![0, 2, 4, 6], vec![1, 5], vec![3]]);
+    }
+
+    #[test]
+    fn composes_additive_potentials() {
+        // Potentials as offsets: x - y.
+        let mut uf = UnionFind::<i64>::weighted(4);
+        assert!(uf.union_with(0, 1, 5));
+        assert!(uf.union_with(2, 3, -2));
+        assert!(uf.union_with(3, 1, 10));
+        assert_eq!(uf.relation(0, 2), Some(-3));
+        assert_eq!(uf.relation(2, 2), Some(0));
+        assert!(!uf.union_with(0, 2, 0));
+        assert_eq!(uf.relation(0, 2), Some(-3));
+    }
+
+    #[test]
+    fn unrelated_elements_have_no_relation() {
+        let mut uf = UnionFind::<f64>::weighted(3);
+        uf.union_with(0, 1, 4.0);
+        assert_eq!(uf.relation(0, 2), None);
+        assert_eq!(uf.relation(1, 0), Some(0.25));
+    }
+}